@@ -0,0 +1,136 @@
+//! # Benchmark results and baselines
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Result of a single benchmark, as reported by `cargo bench`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+  /// Median time of a single iteration in nanoseconds.
+  pub ns_per_iter: u64,
+  /// Deviation of iteration times in nanoseconds.
+  pub deviation: u64,
+}
+
+/// Results of benchmarks, stored in JSON as a baseline for subsequent runs.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+  /// Results of benchmarks ordered by benchmark names.
+  pub benchmarks: BTreeMap<String, BenchResult>,
+}
+
+impl Baseline {
+  /// Collects results of benchmarks from the output of `cargo bench`,
+  /// lines other than benchmark results are ignored.
+  ///
+  /// Benchmark results are reported in lines like:
+  /// ```text
+  /// test tests::bench_name ... bench:       1,234 ns/iter (+/- 56)
+  /// ```
+  pub fn from_bench_output(output: &str) -> Self {
+    let benchmarks = output.lines().filter_map(parse_bench_line).collect();
+    Self { benchmarks }
+  }
+
+  /// Compares results of benchmarks with this baseline, changes above the threshold
+  /// (in percents of the baseline time) are reported as regressions.
+  pub fn compare(&self, current: &Baseline, threshold: f64) -> Comparison {
+    let changes = current
+      .benchmarks
+      .iter()
+      .map(|(name, result)| {
+        let baseline = self.benchmarks.get(name).map(|baseline| baseline.ns_per_iter);
+        let change = baseline
+          .filter(|baseline| *baseline > 0)
+          .map(|baseline| (result.ns_per_iter as f64 - baseline as f64) * 100.0 / baseline as f64);
+        BenchChange {
+          name: name.clone(),
+          baseline,
+          current: result.ns_per_iter,
+          change,
+          is_regression: change.is_some_and(|change| change > threshold),
+        }
+      })
+      .collect();
+    let missing = self.benchmarks.keys().filter(|name| !current.benchmarks.contains_key(*name)).cloned().collect();
+    Comparison { changes, missing }
+  }
+}
+
+/// Change of a single benchmark result compared to the baseline.
+#[derive(Debug, Clone)]
+pub struct BenchChange {
+  /// Name of the benchmark.
+  pub name: String,
+  /// Baseline time of a single iteration in nanoseconds, `None` for new benchmarks.
+  pub baseline: Option<u64>,
+  /// Current time of a single iteration in nanoseconds.
+  pub current: u64,
+  /// Change of the time in percents of the baseline time.
+  pub change: Option<f64>,
+  /// Flag indicating if the change exceeds the threshold.
+  pub is_regression: bool,
+}
+
+/// Comparison of benchmark results with the baseline.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+  /// Changes of benchmark results, ordered by benchmark names.
+  pub changes: Vec<BenchChange>,
+  /// Names of benchmarks present in the baseline, but missing in current results.
+  pub missing: Vec<String>,
+}
+
+impl Comparison {
+  /// Returns changes exceeding the threshold.
+  pub fn regressions(&self) -> Vec<&BenchChange> {
+    self.changes.iter().filter(|change| change.is_regression).collect()
+  }
+}
+
+impl fmt::Display for Comparison {
+  /// Formats the comparison as a table, one line for each benchmark.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let width = self
+      .changes
+      .iter()
+      .map(|change| change.name.len())
+      .chain(self.missing.iter().map(|name| name.len()))
+      .max()
+      .unwrap_or(0);
+    for change in &self.changes {
+      let baseline = change.baseline.map_or("-".to_string(), |baseline| format!("{baseline} ns"));
+      let difference = change.change.map_or("new".to_string(), |change| format!("{change:+.2}%"));
+      let marker = if change.is_regression { "  REGRESSION" } else { "" };
+      writeln!(
+        f,
+        "{:width$}  {:>14}  {:>14}  {:>9}{marker}",
+        change.name,
+        baseline,
+        format!("{} ns", change.current),
+        difference
+      )?;
+    }
+    for name in &self.missing {
+      writeln!(f, "{name:width$}  missing")?;
+    }
+    Ok(())
+  }
+}
+
+/// Parses a line of `cargo bench` output reporting the result of a single benchmark.
+fn parse_bench_line(line: &str) -> Option<(String, BenchResult)> {
+  let rest = line.trim().strip_prefix("test ")?;
+  let (name, measurement) = rest.split_once(" ... bench:")?;
+  let (time, rest) = measurement.trim().split_once(" ns/iter")?;
+  let deviation = rest.trim().strip_prefix("(+/-")?.trim_end_matches(')').trim();
+  let number = |text: &str| text.replace([',', '_'], "").trim().parse::<f64>().ok().map(|number| number.round() as u64);
+  Some((
+    name.trim().to_string(),
+    BenchResult {
+      ns_per_iter: number(time)?,
+      deviation: number(deviation)?,
+    },
+  ))
+}
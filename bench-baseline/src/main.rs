@@ -0,0 +1,145 @@
+//! # **DMNTK** | Benchmark baseline tracking
+//!
+//! Stores results of benchmarks to JSON files and compares subsequent runs
+//! with stored baselines, to catch performance regressions locally:
+//!
+//! ```text
+//! cargo +nightly bench -p dmntk-model-evaluator | cargo run -p dmntk-bench-baseline -- save baseline.json
+//! cargo +nightly bench -p dmntk-model-evaluator | cargo run -p dmntk-bench-baseline -- check baseline.json --threshold 10
+//! ```
+
+mod baseline;
+
+#[cfg(test)]
+mod tests;
+
+use baseline::Baseline;
+use clap::{arg, command, ArgAction, ArgMatches, Command};
+use std::io::Read;
+use std::{fs, io};
+
+/// Default threshold of regressions, in percents of the baseline time.
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Exit code reported when regressions were found.
+const EXIT_CODE_REGRESSION: i32 = 1;
+
+/// Exit code reported when files could not be read or written.
+const EXIT_CODE_INVALID_FILE: i32 = 2;
+
+/// Main entrypoint of the benchmark baseline tracking.
+fn main() {
+  let exit_code = match cli().get_matches().subcommand() {
+    Some(("save", matches)) => save(matches),
+    Some(("check", matches)) => check(matches),
+    _ => 0,
+  };
+  std::process::exit(exit_code);
+}
+
+/// Returns the command-line interface.
+fn cli() -> Command {
+  command!()
+    .subcommand_required(true)
+    .arg_required_else_help(true)
+    .subcommand(
+      Command::new("save")
+        .about("Save benchmark results read from standard output of cargo bench as a baseline")
+        .arg(arg!(<BASELINE_FILE>).help("File where the baseline is written").required(true).index(1)),
+    )
+    .subcommand(
+      Command::new("check")
+        .about("Compare benchmark results read from standard output of cargo bench with the baseline")
+        .arg(
+          arg!(-t --threshold <PERCENT>)
+            .help("Maximum accepted slowdown in percents of the baseline time")
+            .value_parser(clap::value_parser!(f64))
+            .action(ArgAction::Set),
+        )
+        .arg(arg!(-w - -warn).help("Only warn about regressions, without failing").action(ArgAction::SetTrue))
+        .arg(
+          arg!(-u - -update)
+            .help("Update the baseline with current results when no regressions were found")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(arg!(<BASELINE_FILE>).help("File containing the baseline").required(true).index(1)),
+    )
+}
+
+/// Saves benchmark results as the baseline, returns the exit code.
+fn save(matches: &ArgMatches) -> i32 {
+  let baseline_file_name = matches.get_one::<String>("BASELINE_FILE").cloned().unwrap_or_default();
+  let Some(current) = read_bench_output() else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  if !write_baseline(&baseline_file_name, &current) {
+    return EXIT_CODE_INVALID_FILE;
+  }
+  println!("saved {} benchmark results to `{baseline_file_name}`", current.benchmarks.len());
+  0
+}
+
+/// Compares benchmark results with the baseline, returns the exit code.
+fn check(matches: &ArgMatches) -> i32 {
+  let baseline_file_name = matches.get_one::<String>("BASELINE_FILE").cloned().unwrap_or_default();
+  let threshold = matches.get_one::<f64>("threshold").copied().unwrap_or(DEFAULT_THRESHOLD);
+  let baseline = match fs::read_to_string(&baseline_file_name)
+    .map_err(|reason| reason.to_string())
+    .and_then(|json| serde_json::from_str::<Baseline>(&json).map_err(|reason| reason.to_string()))
+  {
+    Ok(baseline) => baseline,
+    Err(reason) => {
+      eprintln!("loading baseline `{baseline_file_name}` failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  let Some(current) = read_bench_output() else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  let comparison = baseline.compare(&current, threshold);
+  print!("{comparison}");
+  let regressions = comparison.regressions();
+  if regressions.is_empty() {
+    println!("no regressions above {threshold}%");
+    if matches.get_flag("update") && !write_baseline(&baseline_file_name, &current) {
+      return EXIT_CODE_INVALID_FILE;
+    }
+    return 0;
+  }
+  if matches.get_flag("warn") {
+    eprintln!("warning: {} benchmark(s) regressed above {threshold}%", regressions.len());
+    0
+  } else {
+    eprintln!("error: {} benchmark(s) regressed above {threshold}%", regressions.len());
+    EXIT_CODE_REGRESSION
+  }
+}
+
+/// Reads benchmark results from standard input.
+fn read_bench_output() -> Option<Baseline> {
+  let mut output = String::new();
+  if let Err(reason) = io::stdin().read_to_string(&mut output) {
+    eprintln!("reading benchmark results failed with reason: {reason}");
+    return None;
+  }
+  let current = Baseline::from_bench_output(&output);
+  if current.benchmarks.is_empty() {
+    eprintln!("no benchmark results found in standard input");
+    return None;
+  }
+  Some(current)
+}
+
+/// Writes the baseline in JSON format, returns `true` on success.
+fn write_baseline(baseline_file_name: &str, baseline: &Baseline) -> bool {
+  match serde_json::to_string_pretty(baseline)
+    .map_err(|reason| reason.to_string())
+    .and_then(|json| fs::write(baseline_file_name, json).map_err(|reason| reason.to_string()))
+  {
+    Ok(()) => true,
+    Err(reason) => {
+      eprintln!("writing baseline `{baseline_file_name}` failed with reason: {reason}");
+      false
+    }
+  }
+}
@@ -0,0 +1,63 @@
+use crate::baseline::{Baseline, BenchResult};
+
+const BENCH_OUTPUT: &str = r#"
+running 3 tests
+test tests::bench_addition     ... bench:         125 ns/iter (+/- 3)
+test tests::bench_decision     ... bench:      12,340 ns/iter (+/- 1,020)
+test tests::bench_parsing      ... bench:       1,000.50 ns/iter (+/- 12.25)
+test tests::ignored_test       ... ignored
+
+test result: ok. 0 passed; 0 failed; 1 ignored; 0 measured; 3 filtered out; finished in 2.31s
+"#;
+
+fn result(ns_per_iter: u64) -> BenchResult {
+  BenchResult { ns_per_iter, deviation: 0 }
+}
+
+#[test]
+fn test_parse_bench_output() {
+  let baseline = Baseline::from_bench_output(BENCH_OUTPUT);
+  assert_eq!(3, baseline.benchmarks.len());
+  assert_eq!(BenchResult { ns_per_iter: 125, deviation: 3 }, baseline.benchmarks["tests::bench_addition"]);
+  assert_eq!(
+    BenchResult {
+      ns_per_iter: 12340,
+      deviation: 1020
+    },
+    baseline.benchmarks["tests::bench_decision"]
+  );
+  assert_eq!(BenchResult { ns_per_iter: 1001, deviation: 12 }, baseline.benchmarks["tests::bench_parsing"]);
+}
+
+#[test]
+fn test_json_round_trip() {
+  let baseline = Baseline::from_bench_output(BENCH_OUTPUT);
+  let json = serde_json::to_string(&baseline).unwrap();
+  assert_eq!(baseline, serde_json::from_str::<Baseline>(&json).unwrap());
+}
+
+#[test]
+fn test_compare() {
+  let mut baseline = Baseline::default();
+  baseline.benchmarks.insert("faster".to_string(), result(100));
+  baseline.benchmarks.insert("slower".to_string(), result(100));
+  baseline.benchmarks.insert("within".to_string(), result(100));
+  baseline.benchmarks.insert("removed".to_string(), result(100));
+  let mut current = Baseline::default();
+  current.benchmarks.insert("faster".to_string(), result(50));
+  current.benchmarks.insert("slower".to_string(), result(125));
+  current.benchmarks.insert("within".to_string(), result(110));
+  current.benchmarks.insert("added".to_string(), result(10));
+  let comparison = baseline.compare(&current, 10.0);
+  let regressions = comparison.regressions();
+  assert_eq!(1, regressions.len());
+  assert_eq!("slower", regressions[0].name);
+  assert_eq!(Some(25.0), regressions[0].change);
+  assert_eq!(vec!["removed".to_string()], comparison.missing);
+  let text = comparison.to_string();
+  assert!(text.contains("added"), "{text}");
+  assert!(text.contains("new"), "{text}");
+  assert!(text.contains("-50.00%"), "{text}");
+  assert!(text.contains("+25.00%  REGRESSION"), "{text}");
+  assert!(text.contains("removed  missing"), "{text}");
+}
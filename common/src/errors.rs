@@ -1,5 +1,6 @@
 //! # Definition of the common error type
 
+use crate::{language, Language, Message};
 use std::{any, fmt};
 
 /// Common result type.
@@ -7,25 +8,77 @@ pub type Result<T, E = DmntkError> = std::result::Result<T, E>;
 
 /// Common trait to be implemented by structs defining a specific error.
 pub trait ToErrorMessage {
-  /// Convert error definition to message string.
-  fn message(self) -> String;
+  /// Convert error definition to message.
+  fn message(self) -> Message;
 }
 
 /// Error definition used by all components of **DMNTK** project.
-#[derive(Debug, PartialEq, Eq)]
-pub struct DmntkError(String);
+pub struct DmntkError {
+  /// Name of the error source.
+  source: String,
+  /// Message of the error, translated when displayed.
+  message: Message,
+  /// Text of the error in English.
+  text: String,
+}
 
 impl fmt::Display for DmntkError {
-  /// Implementation of [Display](fmt::Display) trait for [DmntkError].
+  /// Implementation of [Display](fmt::Display) trait for [DmntkError],
+  /// the message is translated into the selected language.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match language() {
+      Language::English => write!(f, "{}", self.text),
+      other => write!(f, "{}", self.translated(other)),
+    }
+  }
+}
+
+impl PartialEq for DmntkError {
+  /// Errors are equal when their texts in English are equal.
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
+impl Eq for DmntkError {}
+
+impl fmt::Debug for DmntkError {
+  /// Implementation of [Debug](fmt::Debug) trait for [DmntkError], the text is not translated.
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "{}", self.0)
+    f.debug_tuple("DmntkError").field(&self.text).finish()
   }
 }
 
 impl DmntkError {
   /// Creates a new [DmntkError] with specified source name and error message.
   pub fn new(source: &str, message: &str) -> Self {
-    Self(format!("<{source}> {message}"))
+    Self::with_message(source, message.into())
+  }
+
+  /// Creates a new [DmntkError] with specified source name and the message translated when displayed.
+  pub fn with_message(source: &str, message: Message) -> Self {
+    let text = format!("<{source}> {}", message.translated(Language::English));
+    Self {
+      source: source.to_string(),
+      message,
+      text,
+    }
+  }
+
+  /// Returns the text of the error translated into specified language,
+  /// messages of nested errors are translated as well.
+  pub fn translated(&self, language: Language) -> String {
+    format!("<{}> {}", self.source, self.message.translated(language))
+  }
+
+  /// Returns the text of the error as created, not translated into the selected language.
+  pub fn untranslated(&self) -> &str {
+    &self.text
+  }
+
+  /// Returns the name of the error source and the message of the error.
+  pub(crate) fn into_parts(self) -> (String, Message) {
+    (self.source, self.message)
   }
 }
 
@@ -36,7 +89,7 @@ where
   /// Converts any type that implements [ToErrorMessage] trait to [DmntkError].
   fn from(value: T) -> Self {
     let error_type_name = any::type_name::<T>().split("::").last().unwrap_or("UnknownError");
-    DmntkError::new(error_type_name, &value.message())
+    DmntkError::with_message(error_type_name, value.message())
   }
 }
 
@@ -49,6 +102,11 @@ mod tests {
     assert_eq!("<TestError> unexpected", format!("{}", DmntkError::new("TestError", "unexpected")));
   }
 
+  #[test]
+  fn test_untranslated() {
+    assert_eq!("<TestError> unexpected", DmntkError::new("TestError", "unexpected").untranslated());
+  }
+
   #[test]
   fn test_debug() {
     assert_eq!(r#"DmntkError("<TestError> unexpected")"#, format!("{:?}", DmntkError::new("TestError", "unexpected")));
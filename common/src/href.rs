@@ -59,20 +59,20 @@ impl TryFrom<&str> for HRef {
 }
 
 mod errors {
-  use crate::{DmntkError, ToErrorMessage};
+  use crate::{message, DmntkError, Message, ToErrorMessage};
 
   /// Errors reported by [HRef](crate::href::HRef).
   #[derive(ToErrorMessage)]
-  struct HRefError(String);
+  struct HRefError(Message);
 
   /// Creates an error indicating an invalid reference.
   pub fn err_invalid_reference(s: &str) -> DmntkError {
-    HRefError(format!("invalid reference: '{s}'")).into()
+    HRefError(message!("invalid reference: '{}'", s)).into()
   }
 
   /// Creates an error indicating the missing fragment.
   pub fn err_invalid_reference_no_fragment(s: &str) -> DmntkError {
-    HRefError(format!("no fragment in reference: '{s}'")).into()
+    HRefError(message!("no fragment in reference: '{}'", s)).into()
   }
 }
 
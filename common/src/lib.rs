@@ -9,7 +9,9 @@ mod errors;
 mod href;
 mod idents;
 mod jsonify;
+mod messages;
 mod namespace;
+mod similarity;
 mod uri;
 
 pub use ascii_ctrl::*;
@@ -18,5 +20,7 @@ pub use errors::{DmntkError, Result, ToErrorMessage};
 pub use href::HRef;
 pub use idents::gen_id;
 pub use jsonify::Jsonify;
+pub use messages::{language, set_language, Argument, Language, Message};
 pub use namespace::to_rdnn;
+pub use similarity::{closest_matches, did_you_mean, levenshtein, similarity};
 pub use uri::{to_uri, Uri};
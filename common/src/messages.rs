@@ -0,0 +1,910 @@
+//! # Message catalog
+//!
+//! Error messages are created from English templates in the catalog and their arguments,
+//! and translated into the selected language when errors are displayed: arguments are inserted
+//! in place of placeholders in the translated template, in the same order.
+//! Templates not found in the catalog and texts created without templates are displayed in English.
+//!
+//! The language is selected once for the whole process, usually from the configuration
+//! read when the application starts, English is the default language.
+
+use self::errors::*;
+use crate::{DmntkError, Result};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+/// Language of displayed messages.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Language {
+  /// English, messages are displayed as created.
+  #[default]
+  English,
+  /// Polish.
+  Polish,
+  /// German.
+  German,
+}
+
+impl FromStr for Language {
+  type Err = DmntkError;
+  /// Converts the language code, optionally followed by a region and encoding like in `pl_PL.UTF-8`, into [Language].
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let code = s.trim().split(['_', '-', '.']).next().unwrap_or_default();
+    match code.to_lowercase().as_str() {
+      "en" => Ok(Self::English),
+      "pl" => Ok(Self::Polish),
+      "de" => Ok(Self::German),
+      _ => Err(err_invalid_language(s)),
+    }
+  }
+}
+
+impl fmt::Display for Language {
+  /// Formats the language code.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Language::English => write!(f, "en"),
+      Language::Polish => write!(f, "pl"),
+      Language::German => write!(f, "de"),
+    }
+  }
+}
+
+/// Language of displayed messages, selected for the whole process.
+static LANGUAGE: AtomicU8 = AtomicU8::new(Language::English as u8);
+
+/// Selects the language of displayed messages.
+pub fn set_language(language: Language) {
+  LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+/// Returns the selected language of displayed messages.
+pub fn language() -> Language {
+  match LANGUAGE.load(Ordering::Relaxed) {
+    1 => Language::Polish,
+    2 => Language::German,
+    _ => Language::English,
+  }
+}
+
+/// Catalog of translated messages: English template, Polish template and German template.
+///
+/// Templates contain placeholders in the same order: `{}` is replaced with the argument unchanged,
+/// `{msg}` is replaced with the message of the nested error, translated.
+/// Every template is tested with the message created by its error constructor, in the crate defining it.
+/// Messages of errors reporting defects in **DMNTK** itself (like unexpected AST nodes) are not translated.
+const CATALOG: &[(&str, &str, &str)] = &[
+  // common
+  ("invalid reference: '{}'", "niepoprawne odwołanie: '{}'", "ungültiger Verweis: '{}'"),
+  ("no fragment in reference: '{}'", "brak fragmentu w odwołaniu: '{}'", "kein Fragment im Verweis: '{}'"),
+  (
+    "'{}' is not a valid language, accepted values are: 'en', 'pl', 'de'",
+    "'{}' nie jest poprawnym językiem, dozwolone wartości to: 'en', 'pl', 'de'",
+    "'{}' ist keine gültige Sprache, zulässige Werte sind: 'en', 'pl', 'de'",
+  ),
+  // dmntk-feel
+  ("invalid FEEL type name: {}", "niepoprawna nazwa typu FEEL: {}", "ungültiger FEEL-Typname: {}"),
+  (
+    "invalid value for retrieving with type check, type = '{}', value = '{}'",
+    "niepoprawna wartość do pobrania ze sprawdzeniem typu, typ = '{}', wartość = '{}'",
+    "ungültiger Wert für den Abruf mit Typprüfung, Typ = '{}', Wert = '{}'",
+  ),
+  (
+    "'{}' is not valid {} representation",
+    "'{}' nie jest poprawną reprezentacją {}",
+    "'{}' ist keine gültige Darstellung von {}",
+  ),
+  (
+    "'{}' is not a value containing context",
+    "'{}' nie jest wartością zawierającą kontekst",
+    "'{}' ist kein Wert, der einen Kontext enthält",
+  ),
+  (
+    "unknown built-in function name: {}",
+    "nieznana nazwa funkcji wbudowanej: {}",
+    "unbekannter Name einer eingebauten Funktion: {}",
+  ),
+  ("invalid attribute: {}", "niepoprawny atrybut: {}", "ungültiges Attribut: {}"),
+  ("missing attribute: {}", "brak atrybutu: {}", "fehlendes Attribut: {}"),
+  ("invalid JSON: {}", "niepoprawny JSON: {}", "ungültiges JSON: {}"),
+  ("expected JSON object", "oczekiwano obiektu JSON", "JSON-Objekt erwartet"),
+  ("invalid CBOR document: {}", "niepoprawny dokument CBOR: {}", "ungültiges CBOR-Dokument: {}"),
+  ("expected CBOR map", "oczekiwano mapy CBOR", "CBOR-Map erwartet"),
+  ("invalid query '{}': {}", "niepoprawne zapytanie '{}': {}", "ungültige Abfrage '{}': {}"),
+  (
+    "strict evaluation failed: {}",
+    "ścisła ewaluacja nie powiodła się: {}",
+    "strikte Auswertung fehlgeschlagen: {}",
+  ),
+  (
+    "'{}' is not a valid conformance level, accepted values are: '1', '2', '3', 'CL1', 'CL2', 'CL3'",
+    "'{}' nie jest poprawnym poziomem zgodności, dozwolone wartości to: '1', '2', '3', 'CL1', 'CL2', 'CL3'",
+    "'{}' ist keine gültige Konformitätsstufe, zulässige Werte sind: '1', '2', '3', 'CL1', 'CL2', 'CL3'",
+  ),
+  // dmntk-feel-number
+  ("invalid number literal '{}'", "niepoprawny literał liczbowy '{}'", "ungültiges Zahlenliteral '{}'"),
+  ("number conversion failed", "konwersja liczby nie powiodła się", "Zahlenkonvertierung fehlgeschlagen"),
+  // dmntk-feel-temporal
+  ("invalid date {}-{}-{}", "niepoprawna data {}-{}-{}", "ungültiges Datum {}-{}-{}"),
+  ("invalid date literal '{}'", "niepoprawny literał daty '{}'", "ungültiges Datumsliteral '{}'"),
+  ("invalid time literal '{}'", "niepoprawny literał czasu '{}'", "ungültiges Zeitliteral '{}'"),
+  (
+    "invalid date and time literal '{}'",
+    "niepoprawny literał daty i czasu '{}'",
+    "ungültiges Datums- und Zeitliteral '{}'",
+  ),
+  (
+    "conversion from FEEL date '{}' to DateTime<FixedOffset> failed, see issue #? for details",
+    "konwersja daty FEEL '{}' na DateTime<FixedOffset> nie powiodła się, szczegóły w zgłoszeniu #?",
+    "Konvertierung des FEEL-Datums '{}' in DateTime<FixedOffset> fehlgeschlagen, Details siehe Issue #?",
+  ),
+  (
+    "invalid years and months literal '{}'",
+    "niepoprawny literał lat i miesięcy '{}'",
+    "ungültiges Jahre-und-Monate-Literal '{}'",
+  ),
+  (
+    "invalid time-zone offset '{}'",
+    "niepoprawne przesunięcie strefy czasowej '{}'",
+    "ungültiger Zeitzonenversatz '{}'",
+  ),
+  (
+    "invalid date and time duration literal: {}",
+    "niepoprawny literał okresu dni i czasu: {}",
+    "ungültiges Tage-und-Zeit-Dauerliteral: {}",
+  ),
+  // dmntk-feel-parser
+  ("unexpected end of file", "nieoczekiwany koniec pliku", "unerwartetes Dateiende"),
+  (
+    "expected hex digit but encountered '{}'",
+    "oczekiwano cyfry szesnastkowej, napotkano '{}'",
+    "Hexadezimalziffer erwartet, aber '{}' gefunden",
+  ),
+  (
+    "value is out of allowed Unicode range 0x0000..0x10FFFF : {}",
+    "wartość jest poza dozwolonym zakresem Unicode 0x0000..0x10FFFF : {}",
+    "Wert liegt außerhalb des zulässigen Unicode-Bereichs 0x0000..0x10FFFF : {}",
+  ),
+  (
+    "surrogate value is out of allowed range 0xD800..0xDFFF : {}",
+    "wartość surogatu jest poza dozwolonym zakresem 0xD800..0xDFFF : {}",
+    "Surrogatwert liegt außerhalb des zulässigen Bereichs 0xD800..0xDFFF : {}",
+  ),
+  (
+    "expected `FEEL` name on input but found `{}`",
+    "oczekiwano nazwy `FEEL` na wejściu, znaleziono `{}`",
+    "`FEEL`-Name in der Eingabe erwartet, aber `{}` gefunden",
+  ),
+  ("syntax error: {}", "błąd składni: {}", "Syntaxfehler: {}"),
+  // dmntk-feel-evaluator
+  (
+    "expected FEEL context as an input",
+    "oczekiwano kontekstu FEEL na wejściu",
+    "FEEL-Kontext als Eingabe erwartet",
+  ),
+  (
+    "expected positional or named parameter",
+    "oczekiwano parametru pozycyjnego lub nazwanego",
+    "positioneller oder benannter Parameter erwartet",
+  ),
+  (
+    "evaluation budget exhausted, maximum number of {} ({}) exceeded",
+    "wyczerpano budżet ewaluacji, przekroczono maksymalną liczbę {} ({})",
+    "Auswertungsbudget erschöpft, maximale Anzahl von {} ({}) überschritten",
+  ),
+  // dmntk-model
+  (
+    "invalid decision table orientation: {}",
+    "niepoprawna orientacja tabeli decyzyjnej: {}",
+    "ungültige Ausrichtung der Entscheidungstabelle: {}",
+  ),
+  (
+    "invalid decision table hit policy: {}",
+    "niepoprawna polityka trafień tabeli decyzyjnej: {}",
+    "ungültige Trefferrichtlinie der Entscheidungstabelle: {}",
+  ),
+  (
+    "element with identifier or name '{}' not found",
+    "nie znaleziono elementu o identyfikatorze lub nazwie '{}'",
+    "Element mit Kennung oder Name '{}' nicht gefunden",
+  ),
+  (
+    "'{}' is not a valid function kind, accepted values are: 'FEEL', 'Java', 'PMML'",
+    "'{}' nie jest poprawnym rodzajem funkcji, dozwolone wartości to: 'FEEL', 'Java', 'PMML'",
+    "'{}' ist keine gültige Funktionsart, zulässige Werte sind: 'FEEL', 'Java', 'PMML'",
+  ),
+  (
+    "'{}' is not a valid hit policy, allowed values are: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'",
+    "'{}' nie jest poprawną polityką trafień, dozwolone wartości to: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'",
+    "'{}' ist keine gültige Trefferrichtlinie, zulässige Werte sind: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'",
+  ),
+  (
+    "'{}' is not a valid aggregation, allowed values are: 'COUNT', 'SUM', 'MIN', 'MAX'",
+    "'{}' nie jest poprawną agregacją, dozwolone wartości to: 'COUNT', 'SUM', 'MIN', 'MAX'",
+    "'{}' ist keine gültige Aggregation, zulässige Werte sind: 'COUNT', 'SUM', 'MIN', 'MAX'",
+  ),
+  (
+    "conversion to valid color value failed with reason: {msg}",
+    "konwersja na poprawną wartość koloru nie powiodła się, przyczyna: {msg}",
+    "Konvertierung in einen gültigen Farbwert fehlgeschlagen, Ursache: {msg}",
+  ),
+  (
+    "conversion to valid double value failed with reason: {msg}",
+    "konwersja na poprawną wartość typu double nie powiodła się, przyczyna: {msg}",
+    "Konvertierung in einen gültigen Double-Wert fehlgeschlagen, Ursache: {msg}",
+  ),
+  (
+    "required child node '{}' in parent node '{}' is missing",
+    "brak wymaganego węzła podrzędnego '{}' w węźle nadrzędnym '{}'",
+    "erforderlicher Kindknoten '{}' im Elternknoten '{}' fehlt",
+  ),
+  (
+    "required input expression in decision table's input clause is missing",
+    "brak wymaganego wyrażenia wejściowego w kolumnie wejściowej tabeli decyzyjnej",
+    "erforderlicher Eingabeausdruck in der Eingabespalte der Entscheidungstabelle fehlt",
+  ),
+  ("required expression instance is missing", "brak wymaganego wyrażenia", "erforderlicher Ausdruck fehlt"),
+  (
+    "number of elements in a row differs from the number of columns defined in a relation",
+    "liczba elementów w wierszu różni się od liczby kolumn zdefiniowanych w relacji",
+    "Anzahl der Elemente in einer Zeile weicht von der Anzahl der in der Relation definierten Spalten ab",
+  ),
+  (
+    "parsing model from XML failed with reason: {msg}",
+    "parsowanie modelu z XML nie powiodło się, przyczyna: {msg}",
+    "Einlesen des Modells aus XML fehlgeschlagen, Ursache: {msg}",
+  ),
+  (
+    "unexpected XML node, expected: {}, actual: {}",
+    "nieoczekiwany węzeł XML, oczekiwano: {}, napotkano: {}",
+    "unerwarteter XML-Knoten, erwartet: {}, tatsächlich: {}",
+  ),
+  (
+    "expected value for mandatory attribute '{}' in node {}",
+    "oczekiwano wartości obowiązkowego atrybutu '{}' w węźle {}",
+    "Wert für Pflichtattribut '{}' im Knoten {} erwartet",
+  ),
+  (
+    "expected mandatory child node '{}' in parent node {}",
+    "oczekiwano obowiązkowego węzła podrzędnego '{}' w węźle nadrzędnym {}",
+    "obligatorischer Kindknoten '{}' im Elternknoten {} erwartet",
+  ),
+  (
+    "expected mandatory text content in node '{}'",
+    "oczekiwano obowiązkowej treści tekstowej w węźle '{}'",
+    "obligatorischer Textinhalt im Knoten '{}' erwartet",
+  ),
+  (
+    "cyclic dependency between item definitions",
+    "cykliczna zależność między definicjami typów",
+    "zyklische Abhängigkeit zwischen Typdefinitionen",
+  ),
+  (
+    "cyclic dependency between decision requirements: {}",
+    "cykliczna zależność między wymaganiami decyzji: {}",
+    "zyklische Abhängigkeit zwischen Entscheidungsanforderungen: {}",
+  ),
+  (
+    "variable name `{}` of {} collides with variable name of {}",
+    "nazwa zmiennej `{}` elementu {} koliduje z nazwą zmiennej elementu {}",
+    "Variablenname `{}` von {} kollidiert mit dem Variablennamen von {}",
+  ),
+  (
+    "line {}, column {}, element {}: {msg}",
+    "wiersz {}, kolumna {}, element {}: {msg}",
+    "Zeile {}, Spalte {}, Element {}: {msg}",
+  ),
+  ("missing DMN namespace", "brak przestrzeni nazw DMN", "DMN-Namensraum fehlt"),
+  (
+    "unsupported DMN namespace `{}`",
+    "nieobsługiwana przestrzeń nazw DMN `{}`",
+    "nicht unterstützter DMN-Namensraum `{}`",
+  ),
+  (
+    "expected element `definitions`, actual `{}`",
+    "oczekiwano elementu `definitions`, napotkano `{}`",
+    "Element `definitions` erwartet, tatsächlich `{}`",
+  ),
+  ("unexpected element `{}` in `{}`", "nieoczekiwany element `{}` w `{}`", "unerwartetes Element `{}` in `{}`"),
+  ("missing required attribute `{}`", "brak wymaganego atrybutu `{}`", "erforderliches Attribut `{}` fehlt"),
+  (
+    "invalid value `{}` of attribute `{}`, expected one of: {}",
+    "niepoprawna wartość `{}` atrybutu `{}`, oczekiwano jednej z: {}",
+    "ungültiger Wert `{}` des Attributs `{}`, erwartet wird einer von: {}",
+  ),
+  (
+    "element `{}` may contain only text",
+    "element `{}` może zawierać tylko tekst",
+    "Element `{}` darf nur Text enthalten",
+  ),
+  (
+    "{} in {} is not allowed at conformance level {}",
+    "{} w {} nie jest dozwolone na poziomie zgodności {}",
+    "{} in {} ist auf der Konformitätsstufe {} nicht zulässig",
+  ),
+  // dmntk-model-evaluator
+  (
+    "no business knowledge model with reference: '{}#{}'",
+    "brak modelu wiedzy biznesowej o odwołaniu: '{}#{}'",
+    "kein Geschäftswissensmodell mit Verweis: '{}#{}'",
+  ),
+  ("empty literal expression", "puste wyrażenie literałowe", "leerer Literalausdruck"),
+  (
+    "empty encapsulated logic in business knowledge model",
+    "pusta logika w modelu wiedzy biznesowej",
+    "leere gekapselte Logik im Geschäftswissensmodell",
+  ),
+  (
+    "invalid item definition type for '{}'",
+    "niepoprawny typ definicji typu dla '{}'",
+    "ungültiger Typ der Typdefinition für '{}'",
+  ),
+  (
+    "unsupported FEEL type: {} in {}",
+    "nieobsługiwany typ FEEL: {} w {}",
+    "nicht unterstützter FEEL-Typ: {} in {}",
+  ),
+  ("empty FEEL type", "pusty typ FEEL", "leerer FEEL-Typ"),
+  ("empty function definition body", "pusta treść definicji funkcji", "leerer Rumpf der Funktionsdefinition"),
+  ("invalid model bundle: {msg}", "niepoprawny pakiet modeli: {msg}", "ungültiges Modellpaket: {msg}"),
+  (
+    "unsupported model bundle version: {}, expected version: {}",
+    "nieobsługiwana wersja pakietu modeli: {}, oczekiwana wersja: {}",
+    "nicht unterstützte Version des Modellpakets: {}, erwartete Version: {}",
+  ),
+  (
+    "output values must be declared for every output of decision table with hit policy {}",
+    "wartości wyjściowe muszą być zadeklarowane dla każdej kolumny wyjściowej tabeli decyzyjnej z polityką trafień {}",
+    "Ausgabewerte müssen für jede Ausgabe der Entscheidungstabelle mit Trefferrichtlinie {} deklariert werden",
+  ),
+  (
+    "decision table '{}' has no output clauses",
+    "tabela decyzyjna '{}' nie ma kolumn wyjściowych",
+    "Entscheidungstabelle '{}' hat keine Ausgabespalten",
+  ),
+  (
+    "rule {} of decision table '{}' has {} {} entries, expected {}",
+    "reguła {} tabeli decyzyjnej '{}' ma {} wpisów rodzaju {}, oczekiwano {}",
+    "Regel {} der Entscheidungstabelle '{}' hat {} Einträge vom Typ {}, erwartet {}",
+  ),
+  (
+    "invocable '{}' not found in namespace '{}'",
+    "nie znaleziono elementu wywoływalnego '{}' w przestrzeni nazw '{}'",
+    "aufrufbares Element '{}' im Namensraum '{}' nicht gefunden",
+  ),
+  (
+    "types of invocable '{}' in namespace '{}' are not defined",
+    "typy elementu wywoływalnego '{}' w przestrzeni nazw '{}' nie są zdefiniowane",
+    "Typen des aufrufbaren Elements '{}' im Namensraum '{}' sind nicht definiert",
+  ),
+  (
+    "invocable '{}' in namespace '{}' is not a decision service",
+    "element wywoływalny '{}' w przestrzeni nazw '{}' nie jest usługą decyzyjną",
+    "aufrufbares Element '{}' im Namensraum '{}' ist kein Entscheidungsdienst",
+  ),
+  (
+    "missing input data required by invocable '{}': {}",
+    "brak danych wejściowych wymaganych przez element wywoływalny '{}': {}",
+    "fehlende Eingabedaten, die vom aufrufbaren Element '{}' benötigt werden: {}",
+  ),
+  (
+    "invalid default value of input data '{}': {msg}",
+    "niepoprawna wartość domyślna danych wejściowych '{}': {msg}",
+    "ungültiger Standardwert der Eingabedaten '{}': {msg}",
+  ),
+  (
+    "invalid value of constant '{}': {msg}",
+    "niepoprawna wartość stałej '{}': {msg}",
+    "ungültiger Wert der Konstante '{}': {msg}",
+  ),
+  (
+    "generating allowed value of input data '{}' failed",
+    "generowanie dozwolonej wartości danych wejściowych '{}' nie powiodło się",
+    "Erzeugen eines zulässigen Werts der Eingabedaten '{}' fehlgeschlagen",
+  ),
+  // dmntk-recognizer
+  (
+    "expected characters not found: {}",
+    "nie znaleziono oczekiwanych znaków: {}",
+    "erwartete Zeichen nicht gefunden: {}",
+  ),
+  (
+    "character '{}' is not allowed in {}",
+    "znak '{}' nie jest dozwolony w {}",
+    "Zeichen '{}' ist in {} nicht zulässig",
+  ),
+  (
+    "rectangle is not closed, start point: {}, end point: {}",
+    "prostokąt nie jest zamknięty, punkt początkowy: {}, punkt końcowy: {}",
+    "Rechteck ist nicht geschlossen, Startpunkt: {}, Endpunkt: {}",
+  ),
+  (
+    "region not found, rect: {}",
+    "nie znaleziono obszaru, prostokąt: {}",
+    "Bereich nicht gefunden, Rechteck: {}",
+  ),
+  ("plane is empty", "płaszczyzna jest pusta", "Ebene ist leer"),
+  (
+    "not a region cell in plane: {}",
+    "komórka nie należy do obszaru płaszczyzny: {}",
+    "keine Bereichszelle in der Ebene: {}",
+  ),
+  (
+    "plane row is out of range",
+    "wiersz płaszczyzny jest poza zakresem",
+    "Zeile der Ebene liegt außerhalb des Bereichs",
+  ),
+  (
+    "plane no main double crossing",
+    "płaszczyzna nie ma głównego podwójnego skrzyżowania",
+    "Ebene hat keine doppelte Hauptkreuzung",
+  ),
+  (
+    "plane column is out of range",
+    "kolumna płaszczyzny jest poza zakresem",
+    "Spalte der Ebene liegt außerhalb des Bereichs",
+  ),
+  (
+    "plane invalid rule number: {}",
+    "niepoprawny numer reguły w płaszczyźnie: {}",
+    "ungültige Regelnummer in der Ebene: {}",
+  ),
+  ("expected no rule numbers present", "oczekiwano braku numerów reguł", "keine Regelnummern erwartet"),
+  ("invalid input expressions", "niepoprawne wyrażenia wejściowe", "ungültige Eingabeausdrücke"),
+  ("invalid output expressions", "niepoprawne wyrażenia wyjściowe", "ungültige Ausgabeausdrücke"),
+  ("no output clause", "brak kolumny wyjściowej", "keine Ausgabespalte"),
+  (
+    "expected right-after rule numbers placement",
+    "oczekiwano numerów reguł umieszczonych bezpośrednio po prawej",
+    "Regelnummern direkt rechts erwartet",
+  ),
+  (
+    "expected left-below rule numbers placement",
+    "oczekiwano numerów reguł umieszczonych na dole po lewej",
+    "Regelnummern links unten erwartet",
+  ),
+  (
+    "expected bottom-left hit policy placement",
+    "oczekiwano polityki trafień umieszczonej na dole po lewej",
+    "Trefferrichtlinie unten links erwartet",
+  ),
+  (
+    "expected top-left hit policy placement",
+    "oczekiwano polityki trafień umieszczonej na górze po lewej",
+    "Trefferrichtlinie oben links erwartet",
+  ),
+  (
+    "recognizing cross-tab decision tables is not yet implemented",
+    "rozpoznawanie krzyżowych tabel decyzyjnych nie jest jeszcze zaimplementowane",
+    "Erkennen von Kreuztabellen-Entscheidungstabellen ist noch nicht implementiert",
+  ),
+  (
+    "too many rows in input clause",
+    "zbyt wiele wierszy w kolumnie wejściowej",
+    "zu viele Zeilen in der Eingabespalte",
+  ),
+  (
+    "too many rows in output clause",
+    "zbyt wiele wierszy w kolumnie wyjściowej",
+    "zu viele Zeilen in der Ausgabespalte",
+  ),
+  ("invalid size: {}", "niepoprawny rozmiar: {}", "ungültige Größe: {}"),
+  // dmntk-workspace
+  (
+    "invocable not found: '{}'",
+    "nie znaleziono elementu wywoływalnego: '{}'",
+    "aufrufbares Element nicht gefunden: '{}'",
+  ),
+  ("tenant not found: '{}'", "nie znaleziono dzierżawcy: '{}'", "Mandant nicht gefunden: '{}'"),
+  (
+    "invalid configuration of tenant '{}': {msg}",
+    "niepoprawna konfiguracja dzierżawcy '{}': {msg}",
+    "ungültige Konfiguration des Mandanten '{}': {msg}",
+  ),
+  ("invalid namespace: '{}'", "niepoprawna przestrzeń nazw: '{}'", "ungültiger Namensraum: '{}'"),
+  (
+    "namespace is already served by models loaded from workspace directory: '{}'",
+    "przestrzeń nazw jest już obsługiwana przez modele wczytane z katalogu przestrzeni roboczej: '{}'",
+    "Namensraum wird bereits von Modellen aus dem Arbeitsbereichsverzeichnis bedient: '{}'",
+  ),
+  ("deployment not found: '{}'", "nie znaleziono wdrożenia: '{}'", "Bereitstellung nicht gefunden: '{}'"),
+  (
+    "deployment '{}' is imported by deployed models: {}",
+    "wdrożenie '{}' jest importowane przez wdrożone modele: {}",
+    "Bereitstellung '{}' wird von bereitgestellten Modellen importiert: {}",
+  ),
+  (
+    "opening audit file '{}' failed with reason: {msg}",
+    "otwarcie pliku audytu '{}' nie powiodło się, przyczyna: {msg}",
+    "Öffnen der Audit-Datei '{}' fehlgeschlagen, Ursache: {msg}",
+  ),
+  (
+    "storing audit record failed with reason: {msg}",
+    "zapisanie rekordu audytu nie powiodło się, przyczyna: {msg}",
+    "Speichern des Audit-Datensatzes fehlgeschlagen, Ursache: {msg}",
+  ),
+  ("invalid audit record: {msg}", "niepoprawny rekord audytu: {msg}", "ungültiger Audit-Datensatz: {msg}"),
+  (
+    "canary not found: '{}'",
+    "nie znaleziono wdrożenia kanarkowego: '{}'",
+    "Canary-Bereitstellung nicht gefunden: '{}'",
+  ),
+  (
+    "canary requires a deployed model with namespace: '{}'",
+    "wdrożenie kanarkowe wymaga wdrożonego modelu z przestrzenią nazw: '{}'",
+    "Canary-Bereitstellung erfordert ein bereitgestelltes Modell mit Namensraum: '{}'",
+  ),
+  (
+    "invalid canary percentage: {}, expected value from 0 to 100",
+    "niepoprawny procent wdrożenia kanarkowego: {}, oczekiwano wartości od 0 do 100",
+    "ungültiger Canary-Prozentsatz: {}, erwartet wird ein Wert von 0 bis 100",
+  ),
+  (
+    "decision service '{}' has no input parameters named: {}",
+    "usługa decyzyjna '{}' nie ma parametrów wejściowych o nazwach: {}",
+    "Entscheidungsdienst '{}' hat keine Eingabeparameter mit den Namen: {}",
+  ),
+  (
+    "invalid GraphQL query: {} at line {}, column {}",
+    "niepoprawne zapytanie GraphQL: {} w wierszu {}, kolumnie {}",
+    "ungültige GraphQL-Abfrage: {} in Zeile {}, Spalte {}",
+  ),
+  (
+    "GraphQL operation not found: '{}'",
+    "nie znaleziono operacji GraphQL: '{}'",
+    "GraphQL-Operation nicht gefunden: '{}'",
+  ),
+  (
+    "GraphQL query contains multiple operations, operation name is required",
+    "zapytanie GraphQL zawiera wiele operacji, wymagana jest nazwa operacji",
+    "GraphQL-Abfrage enthält mehrere Operationen, Operationsname ist erforderlich",
+  ),
+  ("invalid GraphQL request: {msg}", "niepoprawne żądanie GraphQL: {msg}", "ungültige GraphQL-Anfrage: {msg}"),
+  (
+    "field '{}' is not defined in type '{}'",
+    "pole '{}' nie jest zdefiniowane w typie '{}'",
+    "Feld '{}' ist im Typ '{}' nicht definiert",
+  ),
+  (
+    "argument '{}' is not defined in field '{}'",
+    "argument '{}' nie jest zdefiniowany w polu '{}'",
+    "Argument '{}' ist im Feld '{}' nicht definiert",
+  ),
+  (
+    "fields can not be selected from scalar value of '{}'",
+    "nie można wybierać pól z wartości skalarnej '{}'",
+    "Felder können nicht aus dem Skalarwert von '{}' ausgewählt werden",
+  ),
+  ("invalid selected path: '{}'", "niepoprawna wybrana ścieżka: '{}'", "ungültiger ausgewählter Pfad: '{}'"),
+  (
+    "selected path not found in result: '{}'",
+    "nie znaleziono wybranej ścieżki w wyniku: '{}'",
+    "ausgewählter Pfad im Ergebnis nicht gefunden: '{}'",
+  ),
+  // dmntk-evaluator
+  (
+    "unsupported type {} of column '{}'",
+    "nieobsługiwany typ {} kolumny '{}'",
+    "nicht unterstützter Typ {} der Spalte '{}'",
+  ),
+  (
+    "unsupported type {} of result column",
+    "nieobsługiwany typ {} kolumny wyników",
+    "nicht unterstützter Typ {} der Ergebnisspalte",
+  ),
+  (
+    "building record batch failed with reason: {msg}",
+    "budowanie paczki rekordów nie powiodło się, przyczyna: {msg}",
+    "Erstellen des Datensatzstapels fehlgeschlagen, Ursache: {msg}",
+  ),
+  (
+    "processing Parquet file failed with reason: {msg}",
+    "przetwarzanie pliku Parquet nie powiodło się, przyczyna: {msg}",
+    "Verarbeitung der Parquet-Datei fehlgeschlagen, Ursache: {msg}",
+  ),
+  // dmntk-server
+  (
+    "expression too long, length {} exceeds the maximum length {}",
+    "wyrażenie jest za długie, długość {} przekracza maksymalną długość {}",
+    "Ausdruck zu lang, Länge {} überschreitet die maximale Länge {}",
+  ),
+  (
+    "invalid multipart request: {}",
+    "niepoprawne żądanie wieloczęściowe: {}",
+    "ungültige mehrteilige Anfrage: {}",
+  ),
+  (
+    "multipart request contains no part named 'model' and no uploaded file",
+    "żądanie wieloczęściowe nie zawiera części o nazwie 'model' ani przesłanego pliku",
+    "mehrteilige Anfrage enthält weder einen Teil namens 'model' noch eine hochgeladene Datei",
+  ),
+  ("model is not encoded in UTF-8", "model nie jest zakodowany w UTF-8", "Modell ist nicht in UTF-8 kodiert"),
+];
+
+/// Placeholder replaced with the argument unchanged.
+const PLACEHOLDER_VALUE: &str = "{}";
+
+/// Placeholder replaced with the argument translated, used for messages of nested errors.
+const PLACEHOLDER_MESSAGE: &str = "{msg}";
+
+/// Creates a [Message] from the English template in the catalog and arguments replacing its placeholders, in order.
+///
+/// Arguments are displayed the same in every language, unless they are messages themselves.
+///
+/// ```
+/// use dmntk_common::{message, Language};
+///
+/// let message = message!("invocable '{}' not found in namespace '{}'", "Fee", "https://dmntk.io");
+/// assert_eq!("invocable 'Fee' not found in namespace 'https://dmntk.io'", message.translated(Language::English));
+/// assert_eq!("nie znaleziono elementu wywoływalnego 'Fee' w przestrzeni nazw 'https://dmntk.io'", message.translated(Language::Polish));
+/// ```
+#[macro_export]
+macro_rules! message {
+  ($template:literal $(, $argument:expr)* $(,)?) => {
+    $crate::Message::Template($template, vec![$($crate::Argument::to_argument(&$argument)),*])
+  };
+}
+
+/// Message displayed in the selected language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+  /// Text displayed the same in every language.
+  Text(String),
+  /// English template from the catalog, with arguments replacing its placeholders.
+  Template(&'static str, Vec<Message>),
+  /// Message of the nested error, preceded by the name of the error source.
+  Error(String, Box<Message>),
+}
+
+impl Message {
+  /// Returns the text of the message translated into specified language.
+  /// Templates not found in the catalog are displayed in English.
+  pub fn translated(&self, language: Language) -> String {
+    let mut text = String::new();
+    self.write(language, &mut text);
+    text
+  }
+
+  /// Appends the text of the message translated into specified language.
+  fn write(&self, language: Language, text: &mut String) {
+    match self {
+      Message::Text(value) => text.push_str(value),
+      Message::Template(english, arguments) => {
+        let template = match language {
+          Language::English => english,
+          Language::Polish => catalog().get(english).map_or(english, |(polish, _)| polish),
+          Language::German => catalog().get(english).map_or(english, |(_, german)| german),
+        };
+        let (literals, _) = split_template(template);
+        text.push_str(literals[0]);
+        for (literal, argument) in literals[1..].iter().zip(arguments.iter()) {
+          argument.write(language, text);
+          text.push_str(literal);
+        }
+      }
+      Message::Error(source, message) => {
+        text.push('<');
+        text.push_str(source);
+        text.push_str("> ");
+        message.write(language, text);
+      }
+    }
+  }
+}
+
+impl From<&str> for Message {
+  /// Converts the text into [Message] displayed the same in every language.
+  fn from(value: &str) -> Self {
+    Self::Text(value.to_string())
+  }
+}
+
+impl From<String> for Message {
+  /// Converts the text into [Message] displayed the same in every language.
+  fn from(value: String) -> Self {
+    Self::Text(value)
+  }
+}
+
+impl From<&String> for Message {
+  /// Converts the text into [Message] displayed the same in every language.
+  fn from(value: &String) -> Self {
+    Self::Text(value.clone())
+  }
+}
+
+impl From<DmntkError> for Message {
+  /// Converts the error into [Message] displayed with the name of the error source.
+  fn from(value: DmntkError) -> Self {
+    let (source, message) = value.into_parts();
+    Self::Error(source, Box::new(message))
+  }
+}
+
+/// Conversion of arguments of the [message] macro.
+pub trait Argument {
+  /// Converts the argument into [Message].
+  fn to_argument(&self) -> Message;
+}
+
+impl<T: fmt::Display + ?Sized> Argument for T {
+  /// Arguments displayed with [Display](fmt::Display) are not translated.
+  fn to_argument(&self) -> Message {
+    Message::Text(self.to_string())
+  }
+}
+
+impl Argument for Message {
+  /// Messages used as arguments are translated.
+  fn to_argument(&self) -> Message {
+    self.clone()
+  }
+}
+
+/// Returns Polish and German templates indexed by English templates.
+fn catalog() -> &'static HashMap<&'static str, (&'static str, &'static str)> {
+  static TEMPLATES: OnceLock<HashMap<&'static str, (&'static str, &'static str)>> = OnceLock::new();
+  TEMPLATES.get_or_init(|| CATALOG.iter().map(|(english, polish, german)| (*english, (*polish, *german))).collect())
+}
+
+/// Splits the template into literal texts and placeholders, placeholders are `true` for nested messages.
+/// There is always one literal text more than placeholders.
+fn split_template(template: &str) -> (Vec<&str>, Vec<bool>) {
+  let mut literals = vec![];
+  let mut placeholders = vec![];
+  let mut rest = template;
+  loop {
+    let value = rest.find(PLACEHOLDER_VALUE).map(|position| (position, PLACEHOLDER_VALUE));
+    let message = rest.find(PLACEHOLDER_MESSAGE).map(|position| (position, PLACEHOLDER_MESSAGE));
+    match value.into_iter().chain(message).min() {
+      Some((position, placeholder)) => {
+        literals.push(&rest[..position]);
+        placeholders.push(placeholder == PLACEHOLDER_MESSAGE);
+        rest = &rest[position + placeholder.len()..];
+      }
+      None => {
+        literals.push(rest);
+        return (literals, placeholders);
+      }
+    }
+  }
+}
+
+mod errors {
+  use crate::{DmntkError, Message, ToErrorMessage};
+
+  /// Errors reported by the message catalog.
+  #[derive(ToErrorMessage)]
+  struct LanguageError(Message);
+
+  /// Creates an error indicating an invalid language.
+  pub fn err_invalid_language(s: &str) -> DmntkError {
+    LanguageError(message!("'{}' is not a valid language, accepted values are: 'en', 'pl', 'de'", s)).into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_language() {
+    assert_eq!(Language::English, Language::default());
+    assert_eq!(Language::Polish, "pl".parse::<Language>().unwrap());
+    assert_eq!(Language::German, "de_DE.UTF-8".parse::<Language>().unwrap());
+    assert_eq!(Language::English, " EN-us ".parse::<Language>().unwrap());
+    assert_eq!("pl", Language::Polish.to_string());
+    assert_eq!(
+      "<LanguageError> 'fr' is not a valid language, accepted values are: 'en', 'pl', 'de'",
+      "fr".parse::<Language>().unwrap_err().to_string()
+    );
+  }
+
+  #[test]
+  fn test_translated() {
+    let message = message!("invocable '{}' not found in namespace '{}'", "Fee", "https://dmntk.io");
+    assert_eq!("invocable 'Fee' not found in namespace 'https://dmntk.io'", message.translated(Language::English));
+    assert_eq!(
+      "nie znaleziono elementu wywoływalnego 'Fee' w przestrzeni nazw 'https://dmntk.io'",
+      message.translated(Language::Polish)
+    );
+    assert_eq!(
+      "aufrufbares Element 'Fee' im Namensraum 'https://dmntk.io' nicht gefunden",
+      message.translated(Language::German)
+    );
+    assert_eq!("leerer Literalausdruck", message!("empty literal expression").translated(Language::German));
+    // templates not found in the catalog and texts are displayed in English
+    assert_eq!("not in catalog {}", message!("not in catalog {}", "{}").translated(Language::Polish));
+    assert_eq!("empty literal expression", Message::from("empty literal expression").translated(Language::Polish));
+  }
+
+  #[test]
+  fn test_translated_nested() {
+    let nested = DmntkError::with_message(
+      "ModelParserError",
+      message!("required child node '{}' in parent node '{}' is missing", "text", "inputEntry"),
+    );
+    let message = message!("parsing model from XML failed with reason: {msg}", Message::from(nested));
+    assert_eq!(
+      "parsing model from XML failed with reason: <ModelParserError> required child node 'text' in parent node 'inputEntry' is missing",
+      message.translated(Language::English)
+    );
+    assert_eq!(
+      "parsowanie modelu z XML nie powiodło się, przyczyna: <ModelParserError> brak wymaganego węzła podrzędnego 'text' w węźle nadrzędnym 'inputEntry'",
+      message.translated(Language::Polish)
+    );
+    // arguments are not translated, even when they look like messages from the catalog
+    assert_eq!(
+      "Syntaxfehler: unexpected end of file",
+      message!("syntax error: {}", "unexpected end of file").translated(Language::German)
+    );
+    assert_eq!(
+      "Einlesen des Modells aus XML fehlgeschlagen, Ursache: unexpected end of file",
+      message!("parsing model from XML failed with reason: {msg}", Message::from("unexpected end of file")).translated(Language::German)
+    );
+  }
+
+  #[test]
+  fn test_translated_overlapping_templates() {
+    // English text of this message matches also the template "invalid date {}-{}-{}"
+    assert_eq!(
+      "niepoprawny literał daty '2021-01-01x'",
+      message!("invalid date literal '{}'", "2021-01-01x").translated(Language::Polish)
+    );
+    // English text of this message matches also the template "invalid date literal '{}'"
+    assert_eq!(
+      "niepoprawna data literal '2021-01-01x'",
+      message!("invalid date {}-{}-{}", "literal '2021", "01", "01x'").translated(Language::Polish)
+    );
+    assert_eq!(
+      "niepoprawna data 2021-13-01",
+      message!("invalid date {}-{}-{}", 2021, 13, "01").translated(Language::Polish)
+    );
+    // arguments containing placeholders and literal texts of the template are inserted unchanged
+    let nested = DmntkError::with_message("ModelEvaluatorError", message!("empty literal expression"));
+    assert_eq!(
+      "niepoprawna wartość stałej '{}': x': <ModelEvaluatorError> puste wyrażenie literałowe",
+      message!("invalid value of constant '{}': {msg}", "{}': x", Message::from(nested)).translated(Language::Polish)
+    );
+  }
+
+  #[test]
+  fn test_catalog() {
+    for (english, polish, german) in CATALOG {
+      let (_, placeholders) = split_template(english);
+      assert_eq!(placeholders, split_template(polish).1, "{english}");
+      assert_eq!(placeholders, split_template(german).1, "{english}");
+      assert_eq!(1, CATALOG.iter().filter(|(other, _, _)| other == english).count(), "{english}");
+    }
+  }
+
+  #[test]
+  fn test_common_messages() {
+    let error = crate::HRef::try_from("").unwrap_err();
+    assert_eq!("<HRefError> brak fragmentu w odwołaniu: ''", error.translated(Language::Polish));
+    assert_eq!("<HRefError> kein Fragment im Verweis: ''", error.translated(Language::German));
+    let error = crate::HRef::try_from("documents?name=Introduction#id").unwrap_err();
+    assert_eq!("<HRefError> niepoprawne odwołanie: 'documents?name=Introduction#id'", error.translated(Language::Polish));
+    assert_eq!("<HRefError> ungültiger Verweis: 'documents?name=Introduction#id'", error.translated(Language::German));
+    let error = crate::to_uri("https::\\/dmntk.io").unwrap_err();
+    assert_eq!("<UriError> niepoprawne odwołanie: 'https::\\/dmntk.io'", error.translated(Language::Polish));
+    let error = "fr".parse::<Language>().unwrap_err();
+    assert_eq!(
+      "<LanguageError> 'fr' nie jest poprawnym językiem, dozwolone wartości to: 'en', 'pl', 'de'",
+      error.translated(Language::Polish)
+    );
+    assert_eq!(
+      "<LanguageError> 'fr' ist keine gültige Sprache, zulässige Werte sind: 'en', 'pl', 'de'",
+      error.translated(Language::German)
+    );
+  }
+
+  #[test]
+  fn test_split_template() {
+    assert_eq!((vec!["x ", " y ", ""], vec![false, true]), split_template("x {} y {msg}"));
+    assert_eq!((vec!["x"], vec![]), split_template("x"));
+  }
+}
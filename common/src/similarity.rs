@@ -0,0 +1,131 @@
+//! # Similarity of texts
+
+/// Returns the Levenshtein edit distance between two texts.
+///
+/// The distance is the minimal number of single character
+/// insertions, deletions or substitutions required to change one text into the other.
+///
+/// # Examples
+///
+/// ```
+/// use dmntk_common::levenshtein;
+///
+/// assert_eq!(0, levenshtein("decision", "decision"));
+/// assert_eq!(1, levenshtein("decision", "decisions"));
+/// assert_eq!(3, levenshtein("kitten", "sitting"));
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+  let b_chars = b.chars().collect::<Vec<char>>();
+  let mut row = (0..=b_chars.len()).collect::<Vec<usize>>();
+  for (i, a_ch) in a.chars().enumerate() {
+    let mut diagonal = row[0];
+    row[0] = i + 1;
+    for (j, b_ch) in b_chars.iter().enumerate() {
+      let above = row[j + 1];
+      row[j + 1] = if a_ch == *b_ch { diagonal } else { 1 + diagonal.min(above).min(row[j]) };
+      diagonal = above;
+    }
+  }
+  row[b_chars.len()]
+}
+
+/// Returns the similarity of two texts as a value in range `0.0..=1.0`,
+/// where `1.0` means identical texts and `0.0` means completely different texts.
+///
+/// # Examples
+///
+/// ```
+/// use dmntk_common::similarity;
+///
+/// assert_eq!(1.0, similarity("decision", "decision"));
+/// assert_eq!(0.0, similarity("abc", "xyz"));
+/// assert_eq!(0.75, similarity("date", "data"));
+/// ```
+pub fn similarity(a: &str, b: &str) -> f64 {
+  let max_len = a.chars().count().max(b.chars().count());
+  if max_len == 0 {
+    return 1.0;
+  }
+  1.0 - levenshtein(a, b) as f64 / max_len as f64
+}
+
+/// Maximum number of suggestions returned by [closest_matches].
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Returns candidates close enough to the text to be suggested as its corrections,
+/// ordered from the closest one; at most three candidates are returned.
+///
+/// A candidate is close enough when it differs from the text, and the edit distance
+/// between them does not exceed one third of the length of the candidate.
+///
+/// # Examples
+///
+/// ```
+/// use dmntk_common::closest_matches;
+///
+/// assert_eq!(vec!["Amount"], closest_matches("Amont", ["Amount", "Rate", "Account"]));
+/// assert!(closest_matches("Term", ["Amount", "Rate"]).is_empty());
+/// ```
+pub fn closest_matches<'a>(text: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+  let mut matches = candidates
+    .into_iter()
+    .map(|candidate| (levenshtein(text, candidate), candidate))
+    .filter(|(distance, candidate)| *distance > 0 && *distance <= candidate.chars().count() / 3)
+    .collect::<Vec<(usize, &str)>>();
+  matches.sort();
+  matches.dedup();
+  matches.into_iter().take(MAX_SUGGESTIONS).map(|(_, candidate)| candidate).collect()
+}
+
+/// Returns the suffix of the message suggesting the closest matching candidates,
+/// e.g. `, did you mean 'Amount'?`, or an empty string when no candidate is close enough.
+///
+/// # Examples
+///
+/// ```
+/// use dmntk_common::did_you_mean;
+///
+/// assert_eq!(", did you mean 'sum'?", did_you_mean("sun", ["sum", "count"]));
+/// assert_eq!(", did you mean 'Date' or 'Rate'?", did_you_mean("Bate", ["Date", "Rate"]));
+/// assert_eq!("", did_you_mean("Term", ["Amount"]));
+/// ```
+pub fn did_you_mean<'a>(text: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+  let matches = closest_matches(text, candidates);
+  if matches.is_empty() {
+    String::new()
+  } else {
+    format!(
+      ", did you mean {}?",
+      matches.iter().map(|candidate| format!("'{candidate}'")).collect::<Vec<String>>().join(" or ")
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_levenshtein() {
+    assert_eq!(0, levenshtein("", ""));
+    assert_eq!(3, levenshtein("", "abc"));
+    assert_eq!(3, levenshtein("abc", ""));
+    assert_eq!(1, levenshtein("flaw", "flow"));
+    assert_eq!(2, levenshtein("flaw", "lawn"));
+    assert_eq!(2, levenshtein("żółw", "żółty"));
+  }
+
+  #[test]
+  fn test_similarity() {
+    assert_eq!(1.0, similarity("", ""));
+    assert_eq!(0.5, similarity("ab", "ax"));
+  }
+
+  #[test]
+  fn test_closest_matches() {
+    assert_eq!(vec!["Amount", "Amounts"], closest_matches("Amont", ["Amounts", "Amount", "Amount", "Rate"]));
+    assert!(closest_matches("b", ["a", "b"]).is_empty());
+    assert!(closest_matches("x", Vec::<&str>::new()).is_empty());
+    assert!(closest_matches("Monthly Payment", ["Monthly Fee"]).is_empty());
+  }
+}
@@ -19,15 +19,15 @@ pub fn to_uri(value: &str) -> Result<Uri> {
 }
 
 mod errors {
-  use crate::{DmntkError, ToErrorMessage};
+  use crate::{message, DmntkError, Message, ToErrorMessage};
 
   /// Errors reported by [Uri](crate::uri::Uri).
   #[derive(ToErrorMessage)]
-  struct UriError(String);
+  struct UriError(Message);
 
   /// Creates an error indicating an invalid URI.
   pub fn err_invalid_uri(s: &str) -> DmntkError {
-    UriError(format!("invalid reference: '{s}'")).into()
+    UriError(message!("invalid reference: '{}'", s)).into()
   }
 }
 
@@ -1,15 +1,25 @@
 //! # Command-line actions
 
+use crate::batch::*;
+use crate::bench::*;
+use crate::completions::*;
 use crate::examples::*;
+use crate::formats::*;
+use crate::replay::*;
+#[cfg(feature = "kafka")]
+use crate::stream::*;
 use clap::{arg, command, crate_description, crate_version, ArgAction, ArgMatches, Command};
 use difference::Changeset;
 use dmntk_common::*;
+use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
 use dmntk_feel::FeelScope;
 use dmntk_feel_parser::ast_tree;
+use dmntk_model::{DmnElement, NamedElement};
 use once_cell::sync::Lazy;
-use std::fs;
 use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
+use std::{fs, thread};
 
 /// Automatic color selection flag.
 const COLOR_MODE_AUTO: &str = "auto";
@@ -38,6 +48,38 @@ static DEFAULT_DMN: Lazy<String> = Lazy::new(|| "unknown.dmn".to_string());
 /// Default invocable file name.
 static DEFAULT_INVOCABLE: Lazy<String> = Lazy::new(|| "unknown".to_string());
 
+/// Default maximum number of displayed search results.
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+/// Default seed of generated input data.
+const DEFAULT_SEED: u64 = 1;
+
+/// Default Kafka brokers in streaming evaluation.
+#[cfg(feature = "kafka")]
+const DEFAULT_KAFKA_BROKERS: &str = "localhost:9092";
+
+/// Default consumer group in streaming evaluation.
+#[cfg(feature = "kafka")]
+const DEFAULT_KAFKA_GROUP: &str = "dmntk";
+
+/// Default number of concurrent workers in load tests.
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Default duration of load tests.
+const DEFAULT_BENCH_DURATION: Duration = Duration::from_secs(10);
+
+/// Exit code reported when the evaluated invocable returned an error.
+const EXIT_CODE_EVALUATION_FAILED: i32 = 1;
+
+/// Exit code reported when models, input data or the result could not be read or written.
+const EXIT_CODE_INVALID_FILE: i32 = 2;
+
+/// Interval of checking modification times of watched files.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Default format of evaluation results.
+static DEFAULT_FORMAT: Lazy<String> = Lazy::new(|| FORMAT_JSON.to_string());
+
 /// Default color.
 static DEFAULT_COLOR: Lazy<String> = Lazy::new(|| "auto".to_string());
 
@@ -61,6 +103,8 @@ enum Action {
     String,
     /// Name of the file containing FEEL expression to be evaluated.
     String,
+    /// Requested format of the result with optional query selecting its part.
+    OutputSettings,
   ),
   /// Test FEEL expression.
   TestFeelExpression(
@@ -93,6 +137,8 @@ enum Action {
     String,
     /// Name of the file containing decision table definitions to be evaluated (Unicode format).
     String,
+    /// Requested format of the result with optional query selecting its part.
+    OutputSettings,
   ),
   /// Test decision table.
   TestDecisionTable(
@@ -123,6 +169,8 @@ enum Action {
     String,
     /// Requested color mode.
     ColorMode,
+    /// Flag indicating if the model should be validated against DMN schema before parsing.
+    bool,
   ),
   /// Evaluate DMN model.
   EvaluateDmnModel(
@@ -132,6 +180,8 @@ enum Action {
     String,
     /// Name of the invocable to be evaluated.
     String,
+    /// Requested format of the result with optional query selecting its part.
+    OutputSettings,
   ),
   /// Test DMN model.
   TestDmnModel(
@@ -150,7 +200,175 @@ enum Action {
   ExportDmnModel(
     /// Name of the file containing DMN model.
     String,
-    /// Output HTML file name.
+    /// Output file name.
+    String,
+    /// Flag indicating if the decision requirements graph should be exported in DOT format.
+    bool,
+  ),
+  /// Search DMN models.
+  SearchDmnModels(
+    /// Searched text.
+    String,
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Maximum number of displayed results.
+    usize,
+  ),
+  /// Analyze the impact of changing an element of DMN models.
+  AnalyzeDmnImpact(
+    /// Identifier or name of the changed element.
+    String,
+    /// Names of the files containing DMN models.
+    Vec<String>,
+  ),
+  /// Lint DMN models.
+  LintDmnModels(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Flag indicating if models should be validated and results printed in SARIF format.
+    bool,
+  ),
+  /// Estimate complexity of decisions in DMN models.
+  EstimateDmnComplexity(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+  ),
+  /// Build DMN models and print build statistics.
+  PrintBuildStatistics(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+  ),
+  /// Verify DMN model before deployment.
+  VerifyDmnModel(
+    /// Name of the file containing DMN model.
+    String,
+    /// Optional name of the file containing scenario tests.
+    Option<String>,
+    /// Optional name of the invocable evaluated in scenario tests.
+    Option<String>,
+  ),
+  /// Run self-tests embedded in DMN models.
+  RunDmnSelfTests(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Flag indicating if only test summary will be printed.
+    bool,
+    /// Requested color mode.
+    ColorMode,
+  ),
+  /// Evaluate invocable with JSON input data.
+  EvaluateInvocable(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Optional namespace of the invocable, defaults to the namespace of the first model.
+    Option<String>,
+    /// Name of the invocable to be evaluated.
+    String,
+    /// Optional name of the file containing input data in JSON format, defaults to standard input.
+    Option<String>,
+    /// Optional name of the output file, defaults to standard output.
+    Option<String>,
+    /// Requested format of the result with optional query selecting its part.
+    OutputSettings,
+    /// Flag indicating if the invocable is re-evaluated whenever any of the files changes.
+    bool,
+  ),
+  /// List invocables available in DMN models.
+  ListInvocables(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Optional namespace of listed invocables, defaults to the namespace of the first model.
+    Option<String>,
+  ),
+  /// Simulate invocable for all combinations of candidate input values.
+  SimulateInvocable(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Optional namespace of the invocable, defaults to the namespace of the first model.
+    Option<String>,
+    /// Name of the simulated invocable.
+    String,
+    /// Name of the file containing candidate input values in JSON format.
+    String,
+    /// Optional name of the output CSV file, defaults to standard output.
+    Option<String>,
+  ),
+  /// Generate random input data of invocable.
+  GenerateInputData(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Optional namespace of the invocable, defaults to the namespace of the first model.
+    Option<String>,
+    /// Name of the invocable.
+    String,
+    /// Seed of the first generated input data.
+    u64,
+    /// Number of generated input data.
+    usize,
+    /// Optional name of the output JSON file, defaults to standard output.
+    Option<String>,
+  ),
+  /// Run load test of an invocable deployed on a server.
+  BenchmarkHttp(
+    /// URL of the server.
+    String,
+    /// Path of the invocable on the server.
+    String,
+    /// Optional name of the file containing input data in JSON format.
+    Option<String>,
+    /// Number of concurrent workers sending requests.
+    usize,
+    /// Duration of the load test.
+    Duration,
+  ),
+  /// Evaluate invocable for messages consumed from Kafka topic.
+  #[cfg(feature = "kafka")]
+  StreamEvaluation(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Optional namespace of the invocable, defaults to the namespace of the first model.
+    Option<String>,
+    /// Name of the evaluated invocable.
+    String,
+    /// Settings of Kafka topics and clients.
+    StreamSettings,
+  ),
+  /// Evaluate invocable for records of newline-delimited JSON.
+  EvaluateBatch(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Optional namespace of the invocable, defaults to the namespace of the first model.
+    Option<String>,
+    /// Name of the evaluated invocable.
+    String,
+    /// Name of the file containing input data in newline-delimited JSON format.
+    String,
+    /// Optional name of the output file, defaults to standard output.
+    Option<String>,
+    /// Number of parallel workers.
+    usize,
+    /// Optional name of the file where the profile of evaluations is written in folded stacks format.
+    Option<String>,
+  ),
+  /// Replay audited evaluations against decision models.
+  ReplayAudit(
+    /// Name of the file containing audit records.
+    String,
+    /// Names of the files containing replayed DMN models.
+    Vec<String>,
+    /// Optional namespace of invocables, defaults to the recorded namespace or the namespace of the first model.
+    Option<String>,
+  ),
+  /// Generate shell completion script.
+  GenerateCompletion(
+    /// Name of the shell.
+    String,
+  ),
+  /// Build DMN model bundle.
+  BuildDmnBundle(
+    /// Names of the files containing DMN models.
+    Vec<String>,
+    /// Name of the output bundle file.
     String,
   ),
   /// Start DMNTK as a service.
@@ -161,6 +379,8 @@ enum Action {
     Option<String>,
     /// Optional directory containing models to be loaded on start.
     Option<String>,
+    /// Optional directory containing directories of tenants.
+    Option<String>,
     /// Requested color mode.
     ColorMode,
     /// Flag indicating if more detailed information should be displayed during startup.
@@ -177,15 +397,16 @@ enum Action {
 
 /// Executes command-line action.
 pub async fn do_action() -> std::io::Result<()> {
+  set_language(dmntk_server::get_language());
   match get_cli_action() {
     Action::ParseFeelExpression(ctx_file_name, feel_file_name, color) => {
       // parse FEEL expression
       parse_feel_expression(&ctx_file_name, &feel_file_name, color);
       Ok(())
     }
-    Action::EvaluateFeelExpression(input_file_name, feel_file_name) => {
+    Action::EvaluateFeelExpression(input_file_name, feel_file_name, output) => {
       //
-      evaluate_feel_expression(&input_file_name, &feel_file_name);
+      evaluate_feel_expression(&input_file_name, &feel_file_name, &output);
       Ok(())
     }
     Action::TestFeelExpression(test_file_name, feel_file_name, summary_only, color) => {
@@ -203,9 +424,9 @@ pub async fn do_action() -> std::io::Result<()> {
       parse_decision_table(&dectab_file_name);
       Ok(())
     }
-    Action::EvaluateDecisionTable(input_file_name, dectab_file_name) => {
+    Action::EvaluateDecisionTable(input_file_name, dectab_file_name, output) => {
       //
-      evaluate_decision_table(&input_file_name, &dectab_file_name);
+      evaluate_decision_table(&input_file_name, &dectab_file_name, &output);
       Ok(())
     }
     Action::TestDecisionTable(test_file_name, dectab_file_name, summary_only, color) => {
@@ -223,14 +444,14 @@ pub async fn do_action() -> std::io::Result<()> {
       recognize_decision_table(&dectab_file_name);
       Ok(())
     }
-    Action::ParseDmnModel(dmn_file_name, color) => {
+    Action::ParseDmnModel(dmn_file_name, color, schema) => {
       //
-      parse_dmn_model(&dmn_file_name, color);
+      parse_dmn_model(&dmn_file_name, color, schema);
       Ok(())
     }
-    Action::EvaluateDmnModel(dmn_file_name, ctx_file_name, invocable_name) => {
+    Action::EvaluateDmnModel(dmn_file_name, ctx_file_name, invocable_name, output) => {
       //
-      evaluate_dmn_model(&dmn_file_name, &ctx_file_name, &invocable_name);
+      evaluate_dmn_model(&dmn_file_name, &ctx_file_name, &invocable_name, &output);
       Ok(())
     }
     Action::TestDmnModel(test_file_name, dmn_file_name, invocable_name, summary_only, color) => {
@@ -238,14 +459,144 @@ pub async fn do_action() -> std::io::Result<()> {
       test_dmn_model(&test_file_name, &dmn_file_name, &invocable_name, summary_only, color);
       Ok(())
     }
-    Action::ExportDmnModel(dmn_file_name, html_file_name) => {
+    Action::ExportDmnModel(dmn_file_name, output_file_name, dot) => {
+      //
+      export_dmn_model(&dmn_file_name, &output_file_name, dot);
+      Ok(())
+    }
+    Action::SearchDmnModels(query, dmn_file_names, limit) => {
+      //
+      search_dmn_models(&query, &dmn_file_names, limit);
+      Ok(())
+    }
+    Action::AnalyzeDmnImpact(id, dmn_file_names) => {
+      //
+      analyze_dmn_impact(&id, &dmn_file_names);
+      Ok(())
+    }
+    Action::LintDmnModels(dmn_file_names, sarif) => {
+      //
+      if sarif {
+        lint_dmn_models_sarif(&dmn_file_names);
+      } else {
+        lint_dmn_models(&dmn_file_names);
+      }
+      Ok(())
+    }
+    Action::EstimateDmnComplexity(dmn_file_names) => {
+      //
+      estimate_dmn_complexity(&dmn_file_names);
+      Ok(())
+    }
+    Action::PrintBuildStatistics(dmn_file_names) => {
+      //
+      print_build_statistics(&dmn_file_names);
+      Ok(())
+    }
+    Action::VerifyDmnModel(dmn_file_name, opt_test_file_name, opt_invocable_name) => {
+      // exit code reports the outcome of the verification to CI pipelines
+      let exit_code = verify_dmn_model(&dmn_file_name, opt_test_file_name, opt_invocable_name);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::RunDmnSelfTests(dmn_file_names, summary_only, color) => {
+      //
+      run_dmn_self_tests(&dmn_file_names, summary_only, color);
+      Ok(())
+    }
+    Action::EvaluateInvocable(dmn_file_names, opt_namespace, invocable_name, Some(input_file_name), opt_output_file_name, output, true) => {
+      // watching ends only when the process is interrupted
+      watch_invocable(&dmn_file_names, opt_namespace, &invocable_name, &input_file_name, opt_output_file_name, &output);
+      Ok(())
+    }
+    Action::EvaluateInvocable(dmn_file_names, opt_namespace, invocable_name, opt_input_file_name, opt_output_file_name, output, _) => {
+      // exit code reports the outcome of the evaluation to shell pipelines
+      let exit_code = evaluate_invocable(&dmn_file_names, opt_namespace, &invocable_name, opt_input_file_name, opt_output_file_name, &output);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::ListInvocables(dmn_file_names, opt_namespace) => {
+      //
+      let exit_code = list_invocables(&dmn_file_names, opt_namespace);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::SimulateInvocable(dmn_file_names, opt_namespace, invocable_name, spec_file_name, opt_output_file_name) => {
+      // exit code reports the outcome of the simulation to shell pipelines
+      let exit_code = simulate_invocable(&dmn_file_names, opt_namespace, &invocable_name, &spec_file_name, opt_output_file_name);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::GenerateInputData(dmn_file_names, opt_namespace, invocable_name, seed, count, opt_output_file_name) => {
+      // exit code reports the outcome of the generation to shell pipelines
+      let exit_code = generate_input_data(&dmn_file_names, opt_namespace, &invocable_name, seed, count, opt_output_file_name);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::BenchmarkHttp(url, invocable_path, opt_input_file_name, concurrency, duration) => {
+      // exit code reports the outcome of the load test to shell pipelines
+      let exit_code = benchmark_http(&url, &invocable_path, opt_input_file_name, concurrency, duration);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    #[cfg(feature = "kafka")]
+    Action::StreamEvaluation(dmn_file_names, opt_namespace, invocable_name, settings) => {
+      // streaming ends only when the process is interrupted or Kafka clients fail
+      let exit_code = stream_evaluation(&dmn_file_names, opt_namespace, &invocable_name, &settings);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::EvaluateBatch(dmn_file_names, opt_namespace, invocable_name, input_file_name, opt_output_file_name, parallel, opt_profile_file_name) => {
+      // exit code reports the outcome of the batch to shell pipelines
+      let exit_code = evaluate_batch(
+        &dmn_file_names,
+        opt_namespace,
+        &invocable_name,
+        &input_file_name,
+        opt_output_file_name,
+        parallel,
+        opt_profile_file_name,
+      );
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::ReplayAudit(audit_file_name, dmn_file_names, opt_namespace) => {
+      // exit code reports differences in results to shell pipelines
+      let exit_code = replay_audit(&audit_file_name, &dmn_file_names, opt_namespace);
+      if exit_code != 0 {
+        std::process::exit(exit_code);
+      }
+      Ok(())
+    }
+    Action::GenerateCompletion(shell) => {
+      // print the completion script to be sourced by the shell
+      print!("{}", completion_script(cli(), &shell));
+      Ok(())
+    }
+    Action::BuildDmnBundle(dmn_file_names, bundle_file_name) => {
       //
-      export_dmn_model(&dmn_file_name, &html_file_name);
+      build_dmn_bundle(&dmn_file_names, &bundle_file_name);
       Ok(())
     }
-    Action::StartService(opt_host, opt_port, opt_dir, color, verbose) => {
+    Action::StartService(opt_host, opt_port, opt_dir, opt_tenants_dir, color, verbose) => {
       // start DMNTK as a service (REST server)
-      dmntk_server::start_server(opt_host, opt_port, opt_dir, color.into(), verbose).await
+      dmntk_server::start_server(opt_host, opt_port, opt_dir, opt_tenants_dir, color.into(), verbose).await
     }
     Action::SaveExamples(root_dir) => {
       // save the examples in the specified root directory
@@ -258,8 +609,8 @@ pub async fn do_action() -> std::io::Result<()> {
   }
 }
 
-/// Parses CLI argument matches.
-fn get_matches() -> ArgMatches {
+/// Returns the definition of command-line arguments.
+fn cli() -> Command {
   command!()
     // pfe
     .subcommand(
@@ -281,6 +632,20 @@ fn get_matches() -> ArgMatches {
       Command::new("efe")
         .about("Evaluate FEEL Expression")
         .display_order(4)
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of the result")
+            .value_parser([FORMAT_JSON, FORMAT_YAML, FORMAT_FEEL, FORMAT_PRETTY, FORMAT_TABLE])
+            .default_value(FORMAT_FEEL)
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(
+          arg!(--select <QUERY>)
+            .help("Query selecting the printed part of the result, like `customer.orders[amount > 100].id`")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
         .arg(arg!(<INPUT_FILE>).help("File containing input data for evaluated FEEL expression").required(true).index(1))
         .arg(arg!(<FEEL_FILE>).help("File containing FEEL expression to be evaluated").required(true).index(2)),
     )
@@ -331,6 +696,12 @@ fn get_matches() -> ArgMatches {
             .action(ArgAction::Set)
             .display_order(1),
         )
+        .arg(
+          arg!(-s - -schema)
+            .help("Validate the model against DMN schema before parsing")
+            .action(ArgAction::SetTrue)
+            .display_order(2),
+        )
         .arg(arg!(<DMN_FILE>).help("File containing DMN model to be parsed").required(true).index(1)),
     )
     // edm
@@ -345,6 +716,20 @@ fn get_matches() -> ArgMatches {
             .required(true)
             .display_order(1),
         )
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of the result")
+            .value_parser([FORMAT_JSON, FORMAT_YAML, FORMAT_FEEL, FORMAT_PRETTY, FORMAT_TABLE])
+            .default_value(FORMAT_JSON)
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(--select <QUERY>)
+            .help("Query selecting the printed part of the result, like `customer.orders[amount > 100].id`")
+            .action(ArgAction::Set)
+            .display_order(3),
+        )
         .arg(arg!(<INPUT_FILE>).help("File containing input data for evaluated DMN model").required(true).index(1))
         .arg(arg!(<DMN_FILE>).help("File containing DMN model to be evaluated").required(true).index(2)),
     )
@@ -381,29 +766,116 @@ fn get_matches() -> ArgMatches {
       Command::new("xdm")
         .about("eXport DMN Model")
         .display_order(11)
-        .arg(arg!(<DMN_FILE>).help("File containing DMN model to be exported to HTML").required(true).index(1))
-        .arg(arg!(<HTML_FILE>).help("Output HTML file").required(true).index(2)),
+        .arg(
+          arg!(-d - -dot)
+            .help("Export decision requirements graph in DOT format instead of HTML")
+            .action(ArgAction::SetTrue)
+            .display_order(1),
+        )
+        .arg(arg!(<DMN_FILE>).help("File containing DMN model to be exported").required(true).index(1))
+        .arg(arg!(<OUTPUT_FILE>).help("Output HTML or DOT file").required(true).index(2)),
     )
-    // pdt
+    // sdm
     .subcommand(
-      Command::new("pdt")
-        .about("Parse Decision Table")
-        .display_order(6)
-        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be parsed").required(true).index(1)),
+      Command::new("sdm")
+        .about("Search DMN Models")
+        .visible_alias("search")
+        .display_order(16)
+        .arg(
+          arg!(-l --limit <NUMBER>)
+            .help("Maximum number of displayed results")
+            .value_parser(clap::value_parser!(usize))
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(arg!(<QUERY>).help("Name, identifier or label of the searched element").required(true).index(1))
+        .arg(arg!(<DMN_FILES>).help("Files containing searched DMN models").required(true).num_args(1..).index(2)),
     )
-    // edt
+    // bdm
     .subcommand(
-      Command::new("edt")
-        .about("Evaluate Decision Table")
-        .display_order(3)
-        .arg(arg!(<INPUT_FILE>).help("File containing input data for evaluated decision table").required(true).index(1))
-        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be evaluated").required(true).index(2)),
+      Command::new("bdm")
+        .about("Build DMN model Bundle")
+        .visible_alias("build")
+        .display_order(17)
+        .arg(
+          arg!(-o --output <OUTPUT_FILE>)
+            .help("Output bundle file")
+            .required(true)
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing bundled DMN models").required(true).num_args(1..).index(1)),
     )
-    // tdt
+    // idm
     .subcommand(
-      Command::new("tdt")
-        .about("Test Decision Table")
-        .display_order(9)
+      Command::new("idm")
+        .about("analyze Impact of changes in DMN Models")
+        .visible_alias("impact")
+        .display_order(18)
+        .arg(
+          arg!(<ID>)
+            .help("Identifier of the changed element, or name of the changed item definition")
+            .required(true)
+            .index(1),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing analyzed DMN models").required(true).num_args(1..).index(2)),
+    )
+    // ldm
+    .subcommand(
+      Command::new("ldm")
+        .about("Lint DMN Models")
+        .visible_alias("lint")
+        .display_order(23)
+        .arg(
+          arg!(--sarif)
+            .help("Validate models against DMN schema and print results in SARIF format")
+            .action(ArgAction::SetTrue)
+            .display_order(1),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing linted DMN models").required(true).num_args(1..).index(1)),
+    )
+    // cdm
+    .subcommand(
+      Command::new("cdm")
+        .about("estimate Complexity of decisions in DMN Models")
+        .visible_alias("complexity")
+        .display_order(29)
+        .arg(arg!(<DMN_FILES>).help("Files containing analyzed DMN models").required(true).num_args(1..).index(1)),
+    )
+    // build-stats
+    .subcommand(
+      Command::new("build-stats")
+        .about("print BUILD STATisticS of DMN models")
+        .display_order(30)
+        .arg(arg!(<DMN_FILES>).help("Files containing built DMN models").required(true).num_args(1..).index(1)),
+    )
+    // verify
+    .subcommand(
+      Command::new("verify")
+        .about("VERIFY DMN model before deployment")
+        .display_order(31)
+        .arg(
+          arg!(-t --test <TEST_FILE>)
+            .help("File containing scenario tests")
+            .action(ArgAction::Set)
+            .requires("invocable")
+            .display_order(1),
+        )
+        .arg(
+          arg!(-i --invocable <NAME>)
+            .help("Name of the invocable (decision, bkm, decision service) evaluated in scenario tests")
+            .action(ArgAction::Set)
+            .requires("test")
+            .display_order(2),
+        )
+        .arg(arg!(<DMN_FILE>).help("File containing verified DMN model").required(true).index(1)),
+    )
+    // udm
+    .subcommand(
+      Command::new("udm")
+        .about("run Unit tests embedded in DMN Models")
+        .visible_alias("test")
+        .display_order(19)
         .arg(
           arg!(-s - -summary)
             .help("Display only summary after completing all tests")
@@ -417,54 +889,417 @@ fn get_matches() -> ArgMatches {
             .action(ArgAction::Set)
             .display_order(2),
         )
-        .arg(arg!(<TEST_FILE>).help("File containing test cases for tested decision table").required(true).index(1))
-        .arg(arg!(<DECTAB_FILE>).help("File containing FEEL expression to be tested").required(true).index(2)),
-    )
-    // xdt
-    .subcommand(
-      Command::new("xdt")
-        .about("eXport Decision Table")
-        .display_order(12)
-        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be exported to HTML").required(true).index(1))
-        .arg(arg!(<HTML_FILE>).help("Output HTML file").required(true).index(2)),
-    )
-    // rdt
-    .subcommand(
-      Command::new("rdt")
-        .about("Recognize Decision Table")
-        .display_order(14)
-        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be recognized").required(true).index(1)),
+        .arg(arg!(<DMN_FILES>).help("Files containing tested DMN models").required(true).num_args(1..).index(1)),
     )
-    // srv
+    // evi
     .subcommand(
-      Command::new("srv")
-        .about("Run DMNTK as a service")
-        .display_order(1)
-        .arg(arg!(-H --host <HOST>).help("Host name").action(ArgAction::Set).display_order(1))
-        .arg(arg!(-P --port <PORT>).help("Port number").action(ArgAction::Set).display_order(2))
-        .arg(arg!(-D --dir <DIR>).help("Directory where DMN files are searched").action(ArgAction::Set).display_order(3))
+      Command::new("evi")
+        .about("EValuate Invocable with JSON input data")
+        .display_order(20)
         .arg(
-          arg!(-v - -verbose)
-            .help("Displays model deployment details during startup")
+          arg!(-i --invocable <NAME>)
+            .help("Name of the invocable (decision, bkm, decision service) to be evaluated")
+            .action(ArgAction::Set)
+            .required_unless_present("list")
+            .display_order(1),
+        )
+        .arg(
+          arg!(-l - -list)
+            .help("List names of invocables available in models instead of evaluating")
             .action(ArgAction::SetTrue)
-            .display_order(4),
+            .conflicts_with_all(["invocable", "input", "output"])
+            .display_order(7),
         )
         .arg(
-          arg!(-c --color <WHEN>)
-            .help("Control when colored output is used")
-            .value_parser([COLOR_MODE_AUTO, COLOR_MODE_ALWAYS, COLOR_MODE_NEVER])
+          arg!(-w - -watch)
+            .help("Re-evaluate the invocable whenever the model files or the input file change")
+            .action(ArgAction::SetTrue)
+            .requires("input")
+            .conflicts_with("list")
+            .display_order(8),
+        )
+        .arg(
+          arg!(-n --namespace <NAMESPACE>)
+            .help("Namespace of the invocable, defaults to the namespace of the first model")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(--input <FILE>)
+            .help("File containing input data in JSON format, read from standard input when not specified")
+            .action(ArgAction::Set)
+            .display_order(3),
+        )
+        .arg(
+          arg!(-o --output <FILE>)
+            .help("File where the result is written, written to standard output when not specified")
             .action(ArgAction::Set)
             .display_order(4),
-        ),
-    )
-    // exs
-    .subcommand(
-      Command::new("exs")
-        .about("Save examples")
+        )
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of the result")
+            .value_parser([FORMAT_JSON, FORMAT_YAML, FORMAT_FEEL, FORMAT_PRETTY, FORMAT_TABLE])
+            .default_value(FORMAT_JSON)
+            .action(ArgAction::Set)
+            .display_order(5),
+        )
+        .arg(
+          arg!(--select <QUERY>)
+            .help("Query selecting the printed part of the result, like `customer.orders[amount > 100].id`")
+            .action(ArgAction::Set)
+            .display_order(6),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing DMN models").required(true).num_args(1..).index(1)),
+    )
+    // sim
+    .subcommand(
+      Command::new("sim")
+        .about("SIMulate invocable for all combinations of candidate input values")
+        .display_order(22)
+        .arg(
+          arg!(-i --invocable <NAME>)
+            .help("Name of the invocable (decision, bkm, decision service) to be simulated")
+            .action(ArgAction::Set)
+            .required(true)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-n --namespace <NAMESPACE>)
+            .help("Namespace of the invocable, defaults to the namespace of the first model")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(-s --spec <FILE>)
+            .help("File containing lists of candidate values of inputs in JSON format")
+            .action(ArgAction::Set)
+            .required(true)
+            .display_order(3),
+        )
+        .arg(
+          arg!(-o --output <FILE>)
+            .help("File where the CSV truth table is written, written to standard output when not specified")
+            .action(ArgAction::Set)
+            .display_order(4),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing DMN models").required(true).num_args(1..).index(1)),
+    )
+    // gen-data
+    .subcommand(
+      Command::new("gen-data")
+        .about("GENerate random input DATA of invocable")
+        .display_order(24)
+        .arg(
+          arg!(-i --invocable <NAME>)
+            .help("Name of the invocable (decision, bkm, decision service) for which the input data are generated")
+            .action(ArgAction::Set)
+            .required(true)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-n --namespace <NAMESPACE>)
+            .help("Namespace of the invocable, defaults to the namespace of the first model")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(--seed <NUMBER>)
+            .help("Seed of the random generator, the same seed gives the same input data")
+            .value_parser(clap::value_parser!(u64))
+            .action(ArgAction::Set)
+            .display_order(3),
+        )
+        .arg(
+          arg!(--count <NUMBER>)
+            .help("Number of generated input data, more than one are written as JSON array")
+            .value_parser(clap::value_parser!(usize))
+            .action(ArgAction::Set)
+            .display_order(4),
+        )
+        .arg(
+          arg!(-o --output <FILE>)
+            .help("File where the generated input data are written, written to standard output when not specified")
+            .action(ArgAction::Set)
+            .display_order(5),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing DMN models").required(true).num_args(1..).index(1)),
+    )
+    // bench-http
+    .subcommand(
+      Command::new("bench-http")
+        .about("BENCHmark invocable deployed on a server over HTTP")
+        .display_order(25)
+        .arg(
+          arg!(-i --invocable <PATH>)
+            .help("Path of the invocable on the server, e.g. io/dmntk/compliance/Loan approval")
+            .action(ArgAction::Set)
+            .required(true)
+            .display_order(1),
+        )
+        .arg(
+          arg!(--input <FILE>)
+            .help("File containing input data in JSON format, empty context is sent when not specified")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(-c --concurrency <NUMBER>)
+            .help("Number of concurrent workers sending requests")
+            .value_parser(clap::value_parser!(usize))
+            .action(ArgAction::Set)
+            .display_order(3),
+        )
+        .arg(
+          arg!(-d --duration <DURATION>)
+            .help("Duration of the load test, e.g. 500ms, 60s, 5m")
+            .value_parser(parse_duration)
+            .action(ArgAction::Set)
+            .display_order(4),
+        )
+        .arg(arg!(<URL>).help("URL of the server, e.g. http://127.0.0.1:22022").required(true).index(1)),
+    )
+    // batch
+    .subcommand(
+      Command::new("batch")
+        .about("evaluate invocable for records of newline-delimited JSON in BATCH")
+        .display_order(27)
+        .arg(
+          arg!(-i --invocable <NAME>)
+            .help("Name of the invocable (decision, bkm, decision service) to be evaluated")
+            .action(ArgAction::Set)
+            .required(true)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-n --namespace <NAMESPACE>)
+            .help("Namespace of the invocable, defaults to the namespace of the first model")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(
+          arg!(--input <FILE>)
+            .help("File containing input data in newline-delimited JSON format, one record per line")
+            .action(ArgAction::Set)
+            .required(true)
+            .display_order(3),
+        )
+        .arg(
+          arg!(-o --output <FILE>)
+            .help("File where results are written in newline-delimited JSON format, written to standard output when not specified")
+            .action(ArgAction::Set)
+            .display_order(4),
+        )
+        .arg(
+          arg!(-p --parallel <NUMBER>)
+            .help("Number of parallel workers, defaults to the number of available cores")
+            .value_parser(clap::value_parser!(usize))
+            .action(ArgAction::Set)
+            .display_order(5),
+        )
+        .arg(
+          arg!(--profile <FILE>)
+            .help("File where the profile of evaluations is written in folded stacks format, the summary is written to standard error")
+            .action(ArgAction::Set)
+            .display_order(6),
+        )
+        .arg(arg!(<DMN_FILES>).help("Files containing DMN models").required(true).num_args(1..).index(1)),
+    )
+    // replay
+    .subcommand(
+      Command::new("replay")
+        .about("REPLAY audited evaluations against DMN models and report differences in results")
+        .display_order(28)
+        .arg(
+          arg!(-a --against <DMN_FILES>)
+            .help("Files containing DMN models the audited evaluations are replayed against")
+            .action(ArgAction::Set)
+            .num_args(1..)
+            .required(true)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-n --namespace <NAMESPACE>)
+            .help("Namespace of invocables, defaults to the recorded namespace or the namespace of the first model")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(arg!(<AUDIT_FILE>).help("File containing audit records, one record per line").required(true).index(1)),
+    )
+    // pdt
+    .subcommand(
+      Command::new("pdt")
+        .about("Parse Decision Table")
+        .display_order(6)
+        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be parsed").required(true).index(1)),
+    )
+    // edt
+    .subcommand(
+      Command::new("edt")
+        .about("Evaluate Decision Table")
+        .display_order(3)
+        .arg(
+          arg!(-f --format <FORMAT>)
+            .help("Format of the result")
+            .value_parser([FORMAT_JSON, FORMAT_YAML, FORMAT_FEEL, FORMAT_PRETTY, FORMAT_TABLE])
+            .default_value(FORMAT_JSON)
+            .action(ArgAction::Set)
+            .display_order(1),
+        )
+        .arg(
+          arg!(--select <QUERY>)
+            .help("Query selecting the printed part of the result, like `customer.orders[amount > 100].id`")
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(arg!(<INPUT_FILE>).help("File containing input data for evaluated decision table").required(true).index(1))
+        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be evaluated").required(true).index(2)),
+    )
+    // tdt
+    .subcommand(
+      Command::new("tdt")
+        .about("Test Decision Table")
+        .display_order(9)
+        .arg(
+          arg!(-s - -summary)
+            .help("Display only summary after completing all tests")
+            .action(ArgAction::SetTrue)
+            .display_order(1),
+        )
+        .arg(
+          arg!(-c --color <WHEN>)
+            .help("Control when colored output is used")
+            .value_parser([COLOR_MODE_AUTO, COLOR_MODE_ALWAYS, COLOR_MODE_NEVER])
+            .action(ArgAction::Set)
+            .display_order(2),
+        )
+        .arg(arg!(<TEST_FILE>).help("File containing test cases for tested decision table").required(true).index(1))
+        .arg(arg!(<DECTAB_FILE>).help("File containing FEEL expression to be tested").required(true).index(2)),
+    )
+    // xdt
+    .subcommand(
+      Command::new("xdt")
+        .about("eXport Decision Table")
+        .display_order(12)
+        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be exported to HTML").required(true).index(1))
+        .arg(arg!(<HTML_FILE>).help("Output HTML file").required(true).index(2)),
+    )
+    // rdt
+    .subcommand(
+      Command::new("rdt")
+        .about("Recognize Decision Table")
+        .display_order(14)
+        .arg(arg!(<DECTAB_FILE>).help("File containing decision table to be recognized").required(true).index(1)),
+    )
+    // srv
+    .subcommand(
+      Command::new("srv")
+        .about("Run DMNTK as a service")
+        .display_order(1)
+        .arg(arg!(-H --host <HOST>).help("Host name").action(ArgAction::Set).display_order(1))
+        .arg(arg!(-P --port <PORT>).help("Port number").action(ArgAction::Set).display_order(2))
+        .arg(arg!(-D --dir <DIR>).help("Directory where DMN files are searched").action(ArgAction::Set).display_order(3))
+        .arg(
+          arg!(-v - -verbose)
+            .help("Displays model deployment details during startup")
+            .action(ArgAction::SetTrue)
+            .display_order(4),
+        )
+        .arg(
+          arg!(-c --color <WHEN>)
+            .help("Control when colored output is used")
+            .value_parser([COLOR_MODE_AUTO, COLOR_MODE_ALWAYS, COLOR_MODE_NEVER])
+            .action(ArgAction::Set)
+            .display_order(4),
+        )
+        .arg(
+          arg!(-T --tenants <DIR>)
+            .help("Directory containing directories of tenants")
+            .action(ArgAction::Set)
+            .display_order(5),
+        ),
+    )
+    // exs
+    .subcommand(
+      Command::new("exs")
+        .about("Save examples")
         .display_order(15)
         .arg(arg!(<DIR>).help("Directory where examples are saved").action(ArgAction::Set).required(true).index(1)),
     )
-    .get_matches()
+    // cpl
+    .subcommand(
+      Command::new("cpl").about("generate shell ComPLetion script").display_order(21).arg(
+        arg!(<SHELL>)
+          .help("Shell for which the completion script is generated")
+          .value_parser([SHELL_BASH, SHELL_ZSH, SHELL_FISH])
+          .required(true)
+          .index(1),
+      ),
+    )
+    // stream
+    .subcommands(stream_command())
+}
+
+/// Returns the subcommand for streaming evaluation, available with `kafka` feature.
+#[cfg(feature = "kafka")]
+fn stream_command() -> Option<Command> {
+  Some(
+    Command::new("stream")
+      .about("STREAM evaluation of invocable for messages consumed from Kafka topic")
+      .display_order(26)
+      .arg(
+        arg!(-i --invocable <NAME>)
+          .help("Name of the invocable (decision, bkm, decision service) to be evaluated")
+          .action(ArgAction::Set)
+          .required(true)
+          .display_order(1),
+      )
+      .arg(
+        arg!(-n --namespace <NAMESPACE>)
+          .help("Namespace of the invocable, defaults to the namespace of the first model")
+          .action(ArgAction::Set)
+          .display_order(2),
+      )
+      .arg(
+        arg!(-b --brokers <HOSTS>)
+          .help("Comma-separated list of Kafka brokers")
+          .action(ArgAction::Set)
+          .display_order(3),
+      )
+      .arg(arg!(-g --group <GROUP>).help("Identifier of the consumer group").action(ArgAction::Set).display_order(4))
+      .arg(
+        arg!(--"input-topic" <TOPIC>)
+          .help("Topic with input data in JSON format")
+          .action(ArgAction::Set)
+          .required(true)
+          .display_order(5),
+      )
+      .arg(
+        arg!(--"output-topic" <TOPIC>)
+          .help("Topic where results in JSON format are produced")
+          .action(ArgAction::Set)
+          .required(true)
+          .display_order(6),
+      )
+      .arg(
+        arg!(--"dlq-topic" <TOPIC>)
+          .help("Topic where messages that could not be evaluated are produced, skipped when not specified")
+          .action(ArgAction::Set)
+          .display_order(7),
+      )
+      .arg(arg!(<DMN_FILES>).help("Files containing DMN models").required(true).num_args(1..).index(1)),
+  )
+}
+
+/// Returns no subcommand for streaming evaluation, when `kafka` feature is disabled.
+#[cfg(not(feature = "kafka"))]
+fn stream_command() -> Option<Command> {
+  None
+}
+
+/// Parses CLI argument matches.
+fn get_matches() -> ArgMatches {
+  cli().get_matches()
 }
 
 /// Checks the list of arguments passed from the command line
@@ -484,6 +1319,10 @@ fn get_cli_action() -> Action {
       return Action::EvaluateFeelExpression(
         matches.get_one::<String>("INPUT_FILE").unwrap_or(&DEFAULT_CTX).to_string(),
         matches.get_one::<String>("FEEL_FILE").unwrap_or(&DEFAULT_FEEL).to_string(),
+        OutputSettings::new(
+          matches.get_one::<String>("format").unwrap_or(&DEFAULT_FORMAT).to_string().into(),
+          matches.get_one::<String>("select").cloned(),
+        ),
       );
     }
     // test FEEL expression subcommand
@@ -512,6 +1351,10 @@ fn get_cli_action() -> Action {
       return Action::EvaluateDecisionTable(
         matches.get_one::<String>("INPUT_FILE").unwrap_or(&DEFAULT_CTX).to_string(),
         matches.get_one::<String>("DECTAB_FILE").unwrap_or(&DEFAULT_DTB).to_string(),
+        OutputSettings::new(
+          matches.get_one::<String>("format").unwrap_or(&DEFAULT_FORMAT).to_string().into(),
+          matches.get_one::<String>("select").cloned(),
+        ),
       );
     }
     // test decision table subcommand
@@ -539,6 +1382,7 @@ fn get_cli_action() -> Action {
       return Action::ParseDmnModel(
         matches.get_one::<String>("DMN_FILE").unwrap_or(&DEFAULT_DMN).to_string(),
         matches.get_one::<String>("color").unwrap_or(&DEFAULT_COLOR).to_string().into(),
+        matches.get_flag("schema"),
       );
     }
     // evaluate DMN model subcommand
@@ -547,6 +1391,10 @@ fn get_cli_action() -> Action {
         matches.get_one::<String>("INPUT_FILE").unwrap_or(&DEFAULT_CTX).to_string(),
         matches.get_one::<String>("DMN_FILE").unwrap_or(&DEFAULT_DMN).to_string(),
         matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        OutputSettings::new(
+          matches.get_one::<String>("format").unwrap_or(&DEFAULT_FORMAT).to_string().into(),
+          matches.get_one::<String>("select").cloned(),
+        ),
       );
     }
     // test DMN model subcommand
@@ -563,15 +1411,162 @@ fn get_cli_action() -> Action {
     Some(("xdm", matches)) => {
       return Action::ExportDmnModel(
         matches.get_one::<String>("DMN_FILE").unwrap_or(&DEFAULT_DMN).to_string(),
-        matches.get_one::<String>("HTML_FILE").unwrap_or(&DEFAULT_HTML).to_string(),
+        matches.get_one::<String>("OUTPUT_FILE").unwrap_or(&DEFAULT_HTML).to_string(),
+        matches.get_flag("dot"),
+      );
+    }
+    // search DMN models subcommand
+    Some(("sdm", matches)) => {
+      return Action::SearchDmnModels(
+        matches.get_one::<String>("QUERY").cloned().unwrap_or_default(),
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_one::<usize>("limit").copied().unwrap_or(DEFAULT_SEARCH_LIMIT),
+      );
+    }
+    // build DMN model bundle subcommand
+    Some(("bdm", matches)) => {
+      return Action::BuildDmnBundle(
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_one::<String>("output").cloned().unwrap_or_default(),
+      );
+    }
+    // analyze impact of changes in DMN models subcommand
+    Some(("idm", matches)) => {
+      return Action::AnalyzeDmnImpact(
+        matches.get_one::<String>("ID").cloned().unwrap_or_default(),
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+      );
+    }
+    // lint DMN models subcommand
+    Some(("ldm", matches)) => {
+      return Action::LintDmnModels(matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(), matches.get_flag("sarif"));
+    }
+    // estimate complexity of DMN models subcommand
+    Some(("cdm", matches)) => {
+      return Action::EstimateDmnComplexity(matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect());
+    }
+    // print build statistics subcommand
+    Some(("build-stats", matches)) => {
+      return Action::PrintBuildStatistics(matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect());
+    }
+    // verify DMN model subcommand
+    Some(("verify", matches)) => {
+      return Action::VerifyDmnModel(
+        matches.get_one::<String>("DMN_FILE").cloned().unwrap_or_default(),
+        matches.get_one::<String>("test").cloned(),
+        matches.get_one::<String>("invocable").cloned(),
+      );
+    }
+    // run unit tests embedded in DMN models subcommand
+    Some(("udm", matches)) => {
+      return Action::RunDmnSelfTests(
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_flag("summary"),
+        matches.get_one::<String>("color").unwrap_or(&DEFAULT_COLOR).to_string().into(),
+      );
+    }
+    // evaluate invocable subcommand
+    Some(("evi", matches)) => {
+      if matches.get_flag("list") {
+        return Action::ListInvocables(
+          matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+          matches.get_one::<String>("namespace").cloned(),
+        );
+      }
+      return Action::EvaluateInvocable(
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_one::<String>("namespace").cloned(),
+        matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        matches.get_one::<String>("input").cloned(),
+        matches.get_one::<String>("output").cloned(),
+        OutputSettings::new(
+          matches.get_one::<String>("format").unwrap_or(&DEFAULT_FORMAT).to_string().into(),
+          matches.get_one::<String>("select").cloned(),
+        ),
+        matches.get_flag("watch"),
+      );
+    }
+    // simulate invocable subcommand
+    Some(("sim", matches)) => {
+      return Action::SimulateInvocable(
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_one::<String>("namespace").cloned(),
+        matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        matches.get_one::<String>("spec").cloned().unwrap_or_default(),
+        matches.get_one::<String>("output").cloned(),
+      );
+    }
+    // generate input data subcommand
+    Some(("gen-data", matches)) => {
+      return Action::GenerateInputData(
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_one::<String>("namespace").cloned(),
+        matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        matches.get_one::<u64>("seed").copied().unwrap_or(DEFAULT_SEED),
+        matches.get_one::<usize>("count").copied().unwrap_or(1),
+        matches.get_one::<String>("output").cloned(),
+      );
+    }
+    // benchmark over HTTP subcommand
+    Some(("bench-http", matches)) => {
+      return Action::BenchmarkHttp(
+        matches.get_one::<String>("URL").cloned().unwrap_or_default(),
+        matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        matches.get_one::<String>("input").cloned(),
+        matches.get_one::<usize>("concurrency").copied().unwrap_or(DEFAULT_CONCURRENCY),
+        matches.get_one::<Duration>("duration").copied().unwrap_or(DEFAULT_BENCH_DURATION),
+      );
+    }
+    // streaming evaluation subcommand
+    #[cfg(feature = "kafka")]
+    Some(("stream", matches)) => {
+      return Action::StreamEvaluation(
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_one::<String>("namespace").cloned(),
+        matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        StreamSettings {
+          brokers: matches.get_one::<String>("brokers").cloned().unwrap_or_else(|| DEFAULT_KAFKA_BROKERS.to_string()),
+          group_id: matches.get_one::<String>("group").cloned().unwrap_or_else(|| DEFAULT_KAFKA_GROUP.to_string()),
+          input_topic: matches.get_one::<String>("input-topic").cloned().unwrap_or_default(),
+          output_topic: matches.get_one::<String>("output-topic").cloned().unwrap_or_default(),
+          opt_dlq_topic: matches.get_one::<String>("dlq-topic").cloned(),
+        },
+      );
+    }
+    // batch evaluation subcommand
+    Some(("batch", matches)) => {
+      return Action::EvaluateBatch(
+        matches.get_many::<String>("DMN_FILES").unwrap_or_default().cloned().collect(),
+        matches.get_one::<String>("namespace").cloned(),
+        matches.get_one::<String>("invocable").unwrap_or(&DEFAULT_INVOCABLE).to_string(),
+        matches.get_one::<String>("input").cloned().unwrap_or_default(),
+        matches.get_one::<String>("output").cloned(),
+        matches
+          .get_one::<usize>("parallel")
+          .copied()
+          .unwrap_or_else(|| thread::available_parallelism().map(|parallelism| parallelism.get()).unwrap_or(1)),
+        matches.get_one::<String>("profile").cloned(),
+      );
+    }
+    // replay audited evaluations subcommand
+    Some(("replay", matches)) => {
+      return Action::ReplayAudit(
+        matches.get_one::<String>("AUDIT_FILE").cloned().unwrap_or_default(),
+        matches.get_many::<String>("against").unwrap_or_default().cloned().collect(),
+        matches.get_one::<String>("namespace").cloned(),
       );
     }
+    // generate shell completion script subcommand
+    Some(("cpl", matches)) => {
+      return Action::GenerateCompletion(matches.get_one::<String>("SHELL").cloned().unwrap_or_default());
+    }
     // start server subcommand
     Some(("srv", matches)) => {
       return Action::StartService(
         matches.get_one::<String>("host").map(|host| host.to_string()),
         matches.get_one::<String>("port").map(|port| port.to_string()),
         matches.get_one::<String>("dir").map(|dir| dir.to_string()),
+        matches.get_one::<String>("tenants").map(|dir| dir.to_string()),
         matches.get_one::<String>("color").unwrap_or(&DEFAULT_COLOR).to_string().into(),
         matches.get_flag("verbose"),
       );
@@ -594,11 +1589,17 @@ fn parse_feel_expression(ctx_file_name: &str, feel_file_name: &str, color_mode:
   match fs::read_to_string(feel_file_name) {
     Ok(feel_expression) => match fs::read_to_string(ctx_file_name) {
       Ok(context_definition) => match dmntk_evaluator::evaluate_context(&FeelScope::default(), &context_definition) {
-        Ok(ctx) => match dmntk_feel_parser::parse_expression(&ctx.into(), &feel_expression, false) {
+        Ok(ctx) => match dmntk_feel_parser::parse_expression(&ctx.clone().into(), &feel_expression, false) {
           Ok(ast_root_node) => {
             println!("    AST:{}", ast_tree(&ast_root_node, &color_mode).trim_end());
           }
-          Err(reason) => eprintln!("parsing expression failed with reason: {reason}"),
+          Err(reason) => {
+            eprintln!("parsing expression failed with reason: {reason}");
+            let (_, syntax_errors) = dmntk_feel_parser::parse_expression_with_recovery(&ctx.into(), &feel_expression);
+            for syntax_error in syntax_errors {
+              eprintln!("  {syntax_error}");
+            }
+          }
         },
         Err(reason) => eprintln!("evaluating context failed with reason: {reason}"),
       },
@@ -613,14 +1614,14 @@ fn parse_feel_expression(ctx_file_name: &str, feel_file_name: &str, color_mode:
 }
 
 /// Evaluates `FEEL` expression loaded from file and prints the result to standard output.
-fn evaluate_feel_expression(ctx_file_name: &str, feel_file_name: &str) {
+fn evaluate_feel_expression(ctx_file_name: &str, feel_file_name: &str, output: &OutputSettings) {
   match fs::read_to_string(feel_file_name) {
     Ok(textual_expression) => match fs::read_to_string(ctx_file_name) {
       Ok(context_definition) => match dmntk_evaluator::evaluate_context(&FeelScope::default(), &context_definition) {
         Ok(ctx) => match dmntk_feel_parser::parse_expression(&ctx.clone().into(), &textual_expression, false) {
           Ok(ast_root_node) => match dmntk_evaluator::evaluate(&ctx.into(), &ast_root_node) {
             Ok(result) => {
-              println!("{result}");
+              println!("{}", output.render(&result));
             }
             Err(reason) => {
               eprintln!("evaluating expression failed with reason: {reason}")
@@ -693,7 +1694,7 @@ fn parse_decision_table(dectab_file_name: &str) {
 }
 
 /// Evaluates context and decision table loaded from files.
-fn evaluate_decision_table(input_file_name: &str, dectab_file_name: &str) {
+fn evaluate_decision_table(input_file_name: &str, dectab_file_name: &str, output: &OutputSettings) {
   let input_file_content = match fs::read_to_string(input_file_name) {
     Ok(input_file_content) => input_file_content,
     Err(reason) => {
@@ -715,23 +1716,10 @@ fn evaluate_decision_table(input_file_name: &str, dectab_file_name: &str) {
       return;
     }
   };
-  let decision_table = match dmntk_recognizer::recognize_decision_table(&dtb_file_content, false) {
-    Ok(decision_table) => decision_table,
-    Err(reason) => {
-      eprintln!("building decision table failed with reason: {reason}");
-      return;
-    }
-  };
-  let scope = input_data.into();
-  let evaluator = match dmntk_evaluator::build_decision_table_evaluator(&scope, &decision_table) {
-    Ok(evaluator) => evaluator,
-    Err(reason) => {
-      eprintln!("building decision table evaluator failed with reason: {reason}");
-      return;
-    }
-  };
-  let result = evaluator(&scope) as Value;
-  println!("{}", result.jsonify());
+  match dmntk_evaluator::evaluate_decision_table(&dtb_file_content, &input_data) {
+    Ok(result) => println!("{}", output.render(&result)),
+    Err(reason) => eprintln!("evaluating decision table failed with reason: {reason}"),
+  }
 }
 
 /// Tests decision table loaded from file.
@@ -815,14 +1803,26 @@ fn recognize_decision_table(dtb_file_name: &str) {
 }
 
 /// Parses DMN model loaded from XML file and prints ASCII report.
-fn parse_dmn_model(dmn_file_name: &str, color_mode: ColorMode) {
+/// When requested, the model is validated against DMN schema before parsing.
+fn parse_dmn_model(dmn_file_name: &str, color_mode: ColorMode, schema: bool) {
   match fs::read_to_string(dmn_file_name) {
-    Ok(dmn_file_content) => match &dmntk_model::parse(&dmn_file_content) {
-      Ok(definitions) => {
-        dmntk_gendoc::print_model(definitions, color_mode);
+    Ok(dmn_file_content) => {
+      if schema {
+        let violations = dmntk_model::schema_violations(&dmn_file_content);
+        for reason in &violations {
+          eprintln!("validating model file failed with reason: {reason}");
+        }
+        if !violations.is_empty() {
+          return;
+        }
       }
-      Err(reason) => eprintln!("parsing model file failed with reason: {reason}"),
-    },
+      match &dmntk_model::parse(&dmn_file_content) {
+        Ok(definitions) => {
+          dmntk_gendoc::print_model(definitions, color_mode);
+        }
+        Err(reason) => eprintln!("parsing model file failed with reason: {reason}"),
+      }
+    }
     Err(reason) => {
       eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason:?}")
     }
@@ -830,7 +1830,7 @@ fn parse_dmn_model(dmn_file_name: &str, color_mode: ColorMode) {
 }
 
 /// Evaluates DMN model loaded from XML file.
-fn evaluate_dmn_model(input_file_name: &str, dmn_file_name: &str, invocable_name: &str) {
+fn evaluate_dmn_model(input_file_name: &str, dmn_file_name: &str, invocable_name: &str, output: &OutputSettings) {
   match fs::read_to_string(dmn_file_name) {
     Ok(dmn_file_content) => match fs::read_to_string(input_file_name) {
       Ok(input_file_content) => match dmntk_evaluator::evaluate_context(&FeelScope::default(), &input_file_content) {
@@ -840,7 +1840,7 @@ fn evaluate_dmn_model(input_file_name: &str, dmn_file_name: &str, invocable_name
             match dmntk_evaluator::ModelEvaluator::new(&[definitions]) {
               Ok(model_evaluator) => {
                 let result = model_evaluator.evaluate_invocable(&namespace, invocable_name, &input_data);
-                println!("{}", result.jsonify())
+                println!("{}", output.render(&result))
               }
               Err(reason) => eprintln!("building model evaluator failed with reason: {reason}"),
             }
@@ -904,14 +1904,18 @@ fn test_dmn_model(test_file_name: &str, dmn_file_name: &str, invocable_name: &st
   display_test_summary(passed, failed, summary_only, color_mode);
 }
 
-/// Exports DMN model loaded from `XML` file to `HTML` output file.
-fn export_dmn_model(dmn_file_name: &str, html_file_name: &str) {
+/// Exports DMN model loaded from `XML` file to `HTML` or `DOT` output file.
+fn export_dmn_model(dmn_file_name: &str, output_file_name: &str, dot: bool) {
   match fs::read_to_string(dmn_file_name) {
     Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
       Ok(definitions) => {
-        let html_output = dmntk_gendoc::dmn_model_to_html(&definitions);
-        if let Err(reason) = fs::write(html_file_name, html_output) {
-          println!("writing output HTML file `{html_file_name}` failed with reason: {reason}")
+        let output = if dot {
+          dmntk_gendoc::dmn_model_to_dot(&definitions)
+        } else {
+          dmntk_gendoc::dmn_model_to_html(&definitions)
+        };
+        if let Err(reason) = fs::write(output_file_name, output) {
+          println!("writing output file `{output_file_name}` failed with reason: {reason}")
         }
       }
       Err(reason) => eprintln!("ERROR: {reason}"),
@@ -922,6 +1926,704 @@ fn export_dmn_model(dmn_file_name: &str, html_file_name: &str) {
   }
 }
 
+/// Searches DRG elements in DMN models loaded from XML files and prints the ranked results.
+fn search_dmn_models(query: &str, dmn_file_names: &[String], limit: usize) {
+  let mut definitions = vec![];
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+        Ok(parsed_definitions) => definitions.push(parsed_definitions),
+        Err(reason) => eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}"),
+      },
+      Err(reason) => eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}"),
+    }
+  }
+  for result in dmntk_model::search(&definitions, query).iter().take(limit) {
+    let drg_element = result.drg_element();
+    println!(
+      "{:.2}  {} `{}` (id: {}, namespace: {})",
+      result.score(),
+      drg_element.kind(),
+      drg_element.name(),
+      drg_element.id(),
+      result.definitions().namespace()
+    );
+  }
+}
+
+/// Prints decisions and decision services affected by changing the element of DMN models loaded from XML files.
+fn analyze_dmn_impact(id: &str, dmn_file_names: &[String]) {
+  let mut definitions = vec![];
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+        Ok(parsed_definitions) => definitions.push(parsed_definitions),
+        Err(reason) => eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}"),
+      },
+      Err(reason) => eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}"),
+    }
+  }
+  match dmntk_model::impact_analysis(&definitions, id) {
+    Ok(nodes) => {
+      for node in nodes {
+        println!("{} `{}` (id: {}, namespace: {})", node.kind(), node.name(), node.id(), node.namespace());
+      }
+    }
+    Err(reason) => eprintln!("impact analysis failed with reason: {reason}"),
+  }
+}
+
+/// Lints DMN models loaded from XML files and prints warnings prefixed with file names.
+fn lint_dmn_models(dmn_file_names: &[String]) {
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+        Ok(definitions) => {
+          for warning in dmntk_model::lint(&definitions) {
+            println!("{dmn_file_name}: {warning}");
+          }
+        }
+        Err(reason) => eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}"),
+      },
+      Err(reason) => eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}"),
+    }
+  }
+}
+
+/// Validates and lints DMN models loaded from XML files, prints all results as a single SARIF document.
+///
+/// Models are validated against DMN schema, models valid against schema are parsed and linted.
+fn lint_dmn_models_sarif(dmn_file_names: &[String]) {
+  let mut report = dmntk_model::SarifReport::new();
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => {
+        let violations = dmntk_model::schema_violations(&dmn_file_content);
+        if !violations.is_empty() {
+          for reason in &violations {
+            report.add_schema_violation(dmn_file_name, reason);
+          }
+          continue;
+        }
+        match dmntk_model::parse(&dmn_file_content) {
+          Ok(definitions) => report.add_lint_warnings(dmn_file_name, &dmntk_model::lint(&definitions)),
+          Err(reason) => report.add_validation_error(dmn_file_name, &reason),
+        }
+      }
+      Err(reason) => eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}"),
+    }
+  }
+  println!("{}", report.jsonify());
+}
+
+/// Estimates complexity of decisions in DMN models loaded from XML files,
+/// prints estimations and findings prefixed with file names.
+fn estimate_dmn_complexity(dmn_file_names: &[String]) {
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+        Ok(definitions) => {
+          for estimation in dmntk_model::estimate_complexity(&definitions) {
+            println!("{dmn_file_name}: {estimation}");
+            for finding in estimation.findings() {
+              println!("{dmn_file_name}:   {finding}");
+            }
+          }
+        }
+        Err(reason) => eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}"),
+      },
+      Err(reason) => eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}"),
+    }
+  }
+}
+
+/// Verifies DMN model loaded from XML file, optionally running scenario tests loaded from test file,
+/// prints the verification report. Returns non-zero exit code when the model did not pass verification.
+fn verify_dmn_model(dmn_file_name: &str, opt_test_file_name: Option<String>, opt_invocable_name: Option<String>) -> i32 {
+  let dmn_file_content = match fs::read_to_string(dmn_file_name) {
+    Ok(dmn_file_content) => dmn_file_content,
+    Err(reason) => {
+      eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}");
+      return 1;
+    }
+  };
+  let mut options = dmntk_evaluator::VerificationOptions::default().with_conformance_level(dmntk_server::get_conformance_level());
+  if let (Some(test_file_name), Some(invocable_name)) = (opt_test_file_name, opt_invocable_name) {
+    let test_file_content = match fs::read_to_string(&test_file_name) {
+      Ok(test_file_content) => test_file_content,
+      Err(reason) => {
+        eprintln!("loading test file `{test_file_name}` failed with reason: {reason}");
+        return 1;
+      }
+    };
+    match dmntk_evaluator::Scenario::from_test_file(&invocable_name, &test_file_content) {
+      Ok(scenarios) => options = options.with_scenarios(scenarios),
+      Err(reason) => {
+        eprintln!("evaluating test file failed with reason: {reason}");
+        return 1;
+      }
+    }
+  }
+  let report = dmntk_evaluator::verify_model(&dmn_file_content, &options);
+  println!("{report}");
+  if report.passed() {
+    0
+  } else {
+    1
+  }
+}
+
+/// Builds a model evaluator from DMN models loaded from XML files and prints build statistics,
+/// preceded by the duration of parsing models.
+fn print_build_statistics(dmn_file_names: &[String]) {
+  let started = Instant::now();
+  let mut definitions = vec![];
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+        Ok(parsed_definitions) => definitions.push(parsed_definitions),
+        Err(reason) => {
+          eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}");
+          return;
+        }
+      },
+      Err(reason) => {
+        eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}");
+        return;
+      }
+    }
+  }
+  let parsing_duration = started.elapsed();
+  match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => {
+      println!("parsing [ms]: {:.3}", parsing_duration.as_secs_f64() * 1000.0);
+      print!("{}", model_evaluator.build_statistics());
+    }
+    Err(reason) => eprintln!("building model evaluator failed with reason: {reason}"),
+  }
+}
+
+/// Runs self-tests embedded in DMN models loaded from XML files.
+///
+/// Self-tests are decisions with names starting with `test` followed by a space,
+/// a self-test passes when its decision evaluates to `true`.
+fn run_dmn_self_tests(dmn_file_names: &[String], summary_only: bool, color_mode: ColorMode) {
+  let mut definitions = vec![];
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+        Ok(parsed_definitions) => definitions.push(parsed_definitions),
+        Err(reason) => {
+          eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}");
+          return;
+        }
+      },
+      Err(reason) => {
+        eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}");
+        return;
+      }
+    }
+  }
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return;
+    }
+  };
+  let color_red = color_red!(color_mode);
+  let color_green = color_green!(color_mode);
+  let color_reset = color_reset!(color_mode);
+  let mut passed = 0_usize;
+  let mut failed = 0_usize;
+  for result in model_evaluator.run_self_tests() {
+    if result.passed() {
+      passed += 1;
+      if !summary_only {
+        println!("test `{}` ... {color_green}ok{color_reset}", result.name());
+      }
+    } else {
+      failed += 1;
+      if !summary_only {
+        println!("test `{}` ... {color_red}FAILED{color_reset}", result.name());
+        println!("    {color_green}expected{color_reset}: true");
+        println!("      {color_red}actual{color_reset}: {}", result.value());
+      }
+    }
+  }
+  display_test_summary(passed, failed, summary_only, color_mode);
+}
+
+/// Loads and parses DMN models from XML files, errors are reported to standard error.
+fn load_dmn_models(dmn_file_names: &[String]) -> Option<Vec<dmntk_model::Definitions>> {
+  let mut definitions = vec![];
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => match dmntk_model::parse(&dmn_file_content) {
+        Ok(parsed_definitions) => definitions.push(parsed_definitions),
+        Err(reason) => {
+          eprintln!("parsing model file `{dmn_file_name}` failed with reason: {reason}");
+          return None;
+        }
+      },
+      Err(reason) => {
+        eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}");
+        return None;
+      }
+    }
+  }
+  Some(definitions)
+}
+
+/// Prints names of invocables available in DMN models, one per line, returns the exit code.
+///
+/// The output is used by shell completion scripts for completing names of invocables.
+fn list_invocables(dmn_file_names: &[String], opt_namespace: Option<String>) -> i32 {
+  let Some(definitions) = load_dmn_models(dmn_file_names) else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  let namespace = opt_namespace.unwrap_or_else(|| definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default());
+  match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => {
+      let mut invocable_names = model_evaluator
+        .invocables()
+        .list()
+        .into_iter()
+        .filter(|(invocable_namespace, _)| *invocable_namespace == namespace)
+        .map(|(_, invocable_name)| invocable_name)
+        .collect::<Vec<String>>();
+      invocable_names.sort();
+      for invocable_name in invocable_names {
+        println!("{invocable_name}");
+      }
+      0
+    }
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      EXIT_CODE_INVALID_FILE
+    }
+  }
+}
+
+/// Simulates the invocable for all combinations of candidate input values and writes
+/// the truth table in CSV format to the output file or standard output, returns the exit code.
+fn simulate_invocable(dmn_file_names: &[String], opt_namespace: Option<String>, invocable_name: &str, spec_file_name: &str, opt_output_file_name: Option<String>) -> i32 {
+  let Some(definitions) = load_dmn_models(dmn_file_names) else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  let candidates = match fs::read_to_string(spec_file_name)
+    .map_err(|reason| reason.to_string())
+    .and_then(|json| dmntk_feel::json::context_from_json(&json).map_err(|reason| reason.to_string()))
+  {
+    Ok(candidates) => candidates,
+    Err(reason) => {
+      eprintln!("loading simulation spec `{spec_file_name}` failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  let namespace = opt_namespace.unwrap_or_else(|| definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default());
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  // candidate values are coerced one by one, like input data provided as JSON
+  let mut coerced_candidates = FeelContext::default();
+  for (name, value) in candidates.iter() {
+    let items = match value {
+      Value::List(items) => items.clone(),
+      other => vec![other.clone()],
+    };
+    let coerced_items = items
+      .into_iter()
+      .map(|item| {
+        let mut input_data = FeelContext::default();
+        input_data.set_entry(name, item);
+        let (mut coerced_input_data, _) = model_evaluator.coerce_json_input(&namespace, &input_data);
+        coerced_input_data.remove_entry(name).unwrap_or(Value::Null(None))
+      })
+      .collect();
+    coerced_candidates.set_entry(name, Value::List(coerced_items));
+  }
+  let simulation = match model_evaluator.simulate_invocable(&namespace, invocable_name, &coerced_candidates) {
+    Ok(simulation) => simulation,
+    Err(reason) => {
+      eprintln!("simulating invocable `{invocable_name}` failed with reason: {reason}");
+      return EXIT_CODE_EVALUATION_FAILED;
+    }
+  };
+  match opt_output_file_name {
+    Some(output_file_name) => {
+      if let Err(reason) = fs::write(&output_file_name, simulation.to_csv()) {
+        eprintln!("writing output file `{output_file_name}` failed with reason: {reason}");
+        return EXIT_CODE_INVALID_FILE;
+      }
+    }
+    None => print!("{}", simulation.to_csv()),
+  }
+  0
+}
+
+/// Evaluates the invocable for messages consumed from Kafka topic, returns the exit code.
+#[cfg(feature = "kafka")]
+fn stream_evaluation(dmn_file_names: &[String], opt_namespace: Option<String>, invocable_name: &str, settings: &StreamSettings) -> i32 {
+  let Some(definitions) = load_dmn_models(dmn_file_names) else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  let namespace = opt_namespace.unwrap_or_else(|| definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default());
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  match run_stream(model_evaluator, &namespace, invocable_name, settings) {
+    Ok(()) => 0,
+    Err(reason) => {
+      eprintln!("{reason}");
+      EXIT_CODE_EVALUATION_FAILED
+    }
+  }
+}
+
+/// Evaluates the invocable for records of newline-delimited JSON and writes results
+/// to the output file or standard output, returns the exit code.
+///
+/// The summary and records that could not be evaluated are reported to standard error,
+/// [EXIT_CODE_EVALUATION_FAILED] is returned when any of the records could not be evaluated.
+/// When the name of the profile file is given, evaluations are profiled, the profile is written
+/// to this file in folded stacks format and the summary of the profile is reported to standard error.
+fn evaluate_batch(
+  dmn_file_names: &[String],
+  opt_namespace: Option<String>,
+  invocable_name: &str,
+  input_file_name: &str,
+  opt_output_file_name: Option<String>,
+  parallel: usize,
+  opt_profile_file_name: Option<String>,
+) -> i32 {
+  let Some(definitions) = load_dmn_models(dmn_file_names) else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  let input = match fs::read_to_string(input_file_name) {
+    Ok(input) => input,
+    Err(reason) => {
+      eprintln!("loading input file `{input_file_name}` failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  let namespace = opt_namespace.unwrap_or_else(|| definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default());
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  let report = run_batch(&model_evaluator, &namespace, invocable_name, &input, parallel, opt_profile_file_name.is_some());
+  if let (Some(profile_file_name), Some(profile)) = (opt_profile_file_name, report.profile()) {
+    if let Err(reason) = fs::write(&profile_file_name, profile.folded()) {
+      eprintln!("writing profile file `{profile_file_name}` failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+    eprint!("{profile}");
+  }
+  match opt_output_file_name {
+    Some(output_file_name) => {
+      if let Err(reason) = fs::write(&output_file_name, report.results()) {
+        eprintln!("writing output file `{output_file_name}` failed with reason: {reason}");
+        return EXIT_CODE_INVALID_FILE;
+      }
+    }
+    None => print!("{}", report.results()),
+  }
+  for (line_number, reason) in report.errors() {
+    eprintln!("evaluating record in line {line_number} failed with reason: {reason}");
+  }
+  eprintln!("{report}");
+  if report.errors().is_empty() {
+    0
+  } else {
+    EXIT_CODE_EVALUATION_FAILED
+  }
+}
+
+/// Replays audited evaluations against DMN models, reports differences in results
+/// to standard output and the summary to standard error, returns the exit code.
+///
+/// [EXIT_CODE_EVALUATION_FAILED] is returned when any result differs from the recorded result
+/// or any of the audit records could not be parsed.
+fn replay_audit(audit_file_name: &str, dmn_file_names: &[String], opt_namespace: Option<String>) -> i32 {
+  let Some(definitions) = load_dmn_models(dmn_file_names) else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  let audit = match fs::read_to_string(audit_file_name) {
+    Ok(audit) => audit,
+    Err(reason) => {
+      eprintln!("loading audit file `{audit_file_name}` failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  let default_namespace = definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default();
+  let (namespaces, default_namespace) = match opt_namespace {
+    Some(namespace) => (vec![], namespace),
+    None => (definitions.iter().map(|definitions| definitions.namespace().to_string()).collect(), default_namespace),
+  };
+  let report = run_replay(&model_evaluator, &namespaces, &default_namespace, &audit);
+  for difference in report.differences() {
+    println!("{difference}");
+  }
+  for (line_number, reason) in report.errors() {
+    eprintln!("parsing audit record in line {line_number} failed with reason: {reason}");
+  }
+  eprintln!("{report}");
+  if report.differences().is_empty() && report.errors().is_empty() {
+    0
+  } else {
+    EXIT_CODE_EVALUATION_FAILED
+  }
+}
+
+/// Generates random input data of the invocable and writes them in JSON format
+/// to the output file or standard output, returns the exit code.
+///
+/// Input data are generated with consecutive seeds, starting from the specified seed.
+fn generate_input_data(dmn_file_names: &[String], opt_namespace: Option<String>, invocable_name: &str, seed: u64, count: usize, opt_output_file_name: Option<String>) -> i32 {
+  let Some(definitions) = load_dmn_models(dmn_file_names) else {
+    return EXIT_CODE_INVALID_FILE;
+  };
+  let namespace = opt_namespace.unwrap_or_else(|| definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default());
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return EXIT_CODE_INVALID_FILE;
+    }
+  };
+  let mut generated = vec![];
+  for offset in 0..count as u64 {
+    match model_evaluator.generate_input_data(&namespace, invocable_name, seed.wrapping_add(offset)) {
+      Ok(input_data) => generated.push(Value::Context(input_data)),
+      Err(reason) => {
+        eprintln!("generating input data of invocable `{invocable_name}` failed with reason: {reason}");
+        return EXIT_CODE_EVALUATION_FAILED;
+      }
+    }
+  }
+  let json = if generated.len() == 1 {
+    generated[0].jsonify()
+  } else {
+    Value::List(generated).jsonify()
+  };
+  match opt_output_file_name {
+    Some(output_file_name) => {
+      if let Err(reason) = fs::write(&output_file_name, json) {
+        eprintln!("writing output file `{output_file_name}` failed with reason: {reason}");
+        return EXIT_CODE_INVALID_FILE;
+      }
+    }
+    None => println!("{json}"),
+  }
+  0
+}
+
+/// Runs the load test of the invocable deployed on the server and prints the report, returns the exit code.
+///
+/// Returns [EXIT_CODE_EVALUATION_FAILED] when none of the requests succeeded.
+fn benchmark_http(url: &str, invocable_path: &str, opt_input_file_name: Option<String>, concurrency: usize, duration: Duration) -> i32 {
+  let body = match opt_input_file_name {
+    Some(input_file_name) => match fs::read_to_string(&input_file_name) {
+      Ok(body) => body,
+      Err(reason) => {
+        eprintln!("loading input file `{input_file_name}` failed with reason: {reason}");
+        return EXIT_CODE_INVALID_FILE;
+      }
+    },
+    None => "{}".to_string(),
+  };
+  let endpoint = format!("{}/evaluate/{}", url.trim_end_matches('/'), invocable_path.trim_start_matches('/'));
+  println!("benchmarking {endpoint} with {concurrency} workers for {:.3} s", duration.as_secs_f64());
+  let report = run_http_benchmark(&endpoint, &body, concurrency, duration);
+  println!("{report}");
+  if report.successes() == 0 {
+    return EXIT_CODE_EVALUATION_FAILED;
+  }
+  0
+}
+
+/// Evaluates the invocable with input data in JSON format, returns the exit code.
+///
+/// Returns [EXIT_CODE_EVALUATION_FAILED] when the invocable evaluates to `null` with a reason
+/// (e.g. the invocable was not found) and [EXIT_CODE_INVALID_FILE] when any of the files
+/// could not be read, parsed or written.
+fn evaluate_invocable(
+  dmn_file_names: &[String],
+  opt_namespace: Option<String>,
+  invocable_name: &str,
+  opt_input_file_name: Option<String>,
+  opt_output_file_name: Option<String>,
+  output: &OutputSettings,
+) -> i32 {
+  let result = match evaluate_invocable_value(dmn_file_names, opt_namespace.as_deref(), invocable_name, opt_input_file_name.as_deref()) {
+    Ok(result) => result,
+    Err(exit_code) => return exit_code,
+  };
+  let exit_code = evaluation_exit_code(invocable_name, &result);
+  if !write_invocable_result(&result, opt_output_file_name.as_deref(), output) {
+    return EXIT_CODE_INVALID_FILE;
+  }
+  exit_code
+}
+
+/// Evaluates the invocable with input data in JSON format read from file or standard input.
+/// Input data values are coerced to declared types, performed coercions are reported to standard error.
+///
+/// Errors are reported to standard error, and the exit code is returned as an error.
+fn evaluate_invocable_value(dmn_file_names: &[String], opt_namespace: Option<&str>, invocable_name: &str, opt_input_file_name: Option<&str>) -> Result<Value, i32> {
+  let Some(definitions) = load_dmn_models(dmn_file_names) else {
+    return Err(EXIT_CODE_INVALID_FILE);
+  };
+  let input_content = match opt_input_file_name {
+    Some(input_file_name) => fs::read_to_string(input_file_name),
+    None => std::io::read_to_string(std::io::stdin()),
+  };
+  let input_data = match input_content
+    .map_err(|reason| reason.to_string())
+    .and_then(|json| dmntk_feel::json::context_from_json(&json).map_err(|reason| reason.to_string()))
+  {
+    Ok(input_data) => input_data,
+    Err(reason) => {
+      eprintln!("loading input data `{}` failed with reason: {reason}", opt_input_file_name.unwrap_or("<stdin>"));
+      return Err(EXIT_CODE_INVALID_FILE);
+    }
+  };
+  let namespace = opt_namespace
+    .map(|namespace| namespace.to_string())
+    .unwrap_or_else(|| definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default());
+  let model_evaluator = match dmntk_evaluator::ModelEvaluator::new(&definitions) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      eprintln!("building model evaluator failed with reason: {reason}");
+      return Err(EXIT_CODE_INVALID_FILE);
+    }
+  };
+  let (input_data, coercions) = model_evaluator.coerce_json_input(&namespace, &input_data);
+  for coercion in coercions {
+    eprintln!("input data coercion: {coercion}");
+  }
+  Ok(model_evaluator.evaluate_invocable(&namespace, invocable_name, &input_data))
+}
+
+/// Returns the exit code for the result of the invocable, the reason of failure is reported to standard error.
+fn evaluation_exit_code(invocable_name: &str, result: &Value) -> i32 {
+  match result {
+    Value::Null(Some(reason)) => {
+      eprintln!("evaluating invocable `{invocable_name}` failed with reason: {reason}");
+      EXIT_CODE_EVALUATION_FAILED
+    }
+    _ => 0,
+  }
+}
+
+/// Writes the result of the invocable to the output file or standard output, returns `false` when writing failed.
+fn write_invocable_result(result: &Value, opt_output_file_name: Option<&str>, output: &OutputSettings) -> bool {
+  match opt_output_file_name {
+    Some(output_file_name) => {
+      if let Err(reason) = fs::write(output_file_name, output.render(result)) {
+        eprintln!("writing output file `{output_file_name}` failed with reason: {reason}");
+        return false;
+      }
+    }
+    None => println!("{}", output.render(result)),
+  }
+  true
+}
+
+/// Re-evaluates the invocable whenever any of the model files or the input file changes.
+///
+/// The first result is printed in full, subsequent results are printed as a difference
+/// to the previous result. Errors are reported and watching continues, so the files
+/// may be freely edited. Watching ends when the process is interrupted.
+fn watch_invocable(
+  dmn_file_names: &[String],
+  opt_namespace: Option<String>,
+  invocable_name: &str,
+  input_file_name: &str,
+  opt_output_file_name: Option<String>,
+  output: &OutputSettings,
+) {
+  let watched_file_names = dmn_file_names
+    .iter()
+    .map(|name| name.as_str())
+    .chain(std::iter::once(input_file_name))
+    .collect::<Vec<&str>>();
+  let modification_times = || {
+    watched_file_names
+      .iter()
+      .map(|file_name| fs::metadata(file_name).and_then(|metadata| metadata.modified()).ok())
+      .collect::<Vec<Option<SystemTime>>>()
+  };
+  let mut last_modification_times = None;
+  let mut last_output: Option<String> = None;
+  loop {
+    let current_modification_times = modification_times();
+    if last_modification_times.as_ref() != Some(&current_modification_times) {
+      last_modification_times = Some(current_modification_times);
+      if let Ok(result) = evaluate_invocable_value(dmn_file_names, opt_namespace.as_deref(), invocable_name, Some(input_file_name)) {
+        evaluation_exit_code(invocable_name, &result);
+        if let Some(output_file_name) = &opt_output_file_name {
+          write_invocable_result(&result, Some(output_file_name), output);
+        }
+        let rendered = output.render(&result);
+        match &last_output {
+          None => println!("{rendered}"),
+          Some(previous_output) if *previous_output == rendered => println!("result unchanged"),
+          Some(previous_output) => {
+            // multi-line outputs are compared line by line, single-line outputs word by word
+            let split = if previous_output.contains('\n') || rendered.contains('\n') { "\n" } else { " " };
+            println!("{}", Changeset::new(previous_output, &rendered, split));
+          }
+        }
+        last_output = Some(rendered);
+      }
+    }
+    thread::sleep(WATCH_INTERVAL);
+  }
+}
+
+/// Builds a bundle from DMN models and saves it to the output file.
+fn build_dmn_bundle(dmn_file_names: &[String], bundle_file_name: &str) {
+  let mut models = vec![];
+  for dmn_file_name in dmn_file_names {
+    match fs::read_to_string(dmn_file_name) {
+      Ok(dmn_file_content) => models.push(dmn_file_content),
+      Err(reason) => {
+        eprintln!("loading model file `{dmn_file_name}` failed with reason: {reason}");
+        return;
+      }
+    }
+  }
+  let models = models.iter().map(|model| model.as_str()).collect::<Vec<&str>>();
+  match dmntk_evaluator::build_bundle(&models) {
+    Ok(bundle) => {
+      if let Err(reason) = fs::write(bundle_file_name, bundle) {
+        eprintln!("writing bundle file `{bundle_file_name}` failed with reason: {reason}")
+      }
+    }
+    Err(reason) => eprintln!("building bundle failed with reason: {reason}"),
+  }
+}
+
 /// Generates examples in current directory.
 fn generate_examples(root_dir: &str) -> std::io::Result<()> {
   let create_dir = |root_dir: &str, child_dir: &str| -> std::io::Result<()> {
@@ -0,0 +1,146 @@
+//! # Batch evaluation of invocables
+//!
+//! Evaluates the invocable for records of newline-delimited JSON (one context of input
+//! data per line), using multiple workers. Results are written in the order of records,
+//! one line per record, records that could not be evaluated produce `null` lines and
+//! are reported with their line numbers. Evaluations may be profiled, each worker
+//! collects its own profile and profiles of all workers are merged.
+
+use dmntk_common::Jsonify;
+use dmntk_evaluator::{EvaluationListener, EvaluationProfile, EvaluationProfiler, ModelEvaluator};
+use dmntk_feel::values::Value;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use std::{fmt, thread};
+
+/// Results of the batch evaluation.
+pub struct BatchReport {
+  /// Results in JSON format, in the order of records.
+  results: Vec<String>,
+  /// Line numbers of records that could not be evaluated, with reasons.
+  errors: Vec<(usize, String)>,
+  /// Total time of the evaluation.
+  elapsed: Duration,
+  /// Profile of evaluations, when profiled.
+  profile: Option<EvaluationProfile>,
+}
+
+impl BatchReport {
+  /// Returns results in newline-delimited JSON format.
+  pub fn results(&self) -> String {
+    self.results.iter().map(|result| format!("{result}\n")).collect()
+  }
+
+  /// Returns line numbers of records that could not be evaluated, with reasons.
+  pub fn errors(&self) -> &[(usize, String)] {
+    &self.errors
+  }
+
+  /// Returns the profile of evaluations, when profiled.
+  pub fn profile(&self) -> Option<&EvaluationProfile> {
+    self.profile.as_ref()
+  }
+}
+
+impl fmt::Display for BatchReport {
+  /// Formats the summary of the batch evaluation.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let seconds = self.elapsed.as_secs_f64();
+    let throughput = if seconds > 0.0 { self.results.len() as f64 / seconds } else { 0.0 };
+    write!(
+      f,
+      "evaluated {} records in {seconds:.3} s ({throughput:.2} records/s), {} errors",
+      self.results.len(),
+      self.errors.len()
+    )
+  }
+}
+
+/// Evaluates the invocable for each non-empty line of input data with specified number of workers,
+/// evaluations are profiled when `profiled` is `true`.
+pub fn run_batch(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str, input: &str, parallel: usize, profiled: bool) -> BatchReport {
+  let started = Instant::now();
+  let records = input
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| (index + 1, line))
+    .collect::<Vec<(usize, &str)>>();
+  let next = AtomicUsize::new(0);
+  let mut profile = profiled.then(EvaluationProfile::default);
+  let mut outcomes = thread::scope(|scope| {
+    let workers = (0..parallel.max(1))
+      .map(|_| {
+        scope.spawn(|| {
+          let profiler = profiled.then(|| Rc::new(EvaluationProfiler::new()));
+          let mut outcomes = vec![];
+          loop {
+            let index = next.fetch_add(1, Ordering::Relaxed);
+            let Some((_, record)) = records.get(index) else {
+              break;
+            };
+            let outcome = match &profiler {
+              Some(profiler) => evaluate_profiled_record(model_evaluator, namespace, invocable_name, record, profiler),
+              None => evaluate_record(model_evaluator, namespace, invocable_name, record),
+            };
+            outcomes.push((index, outcome));
+          }
+          (outcomes, profiler.map(|profiler| profiler.profile()))
+        })
+      })
+      .collect::<Vec<_>>();
+    let mut outcomes = vec![];
+    for worker in workers {
+      let (mut worker_outcomes, worker_profile) = worker.join().unwrap_or_default();
+      outcomes.append(&mut worker_outcomes);
+      if let (Some(profile), Some(worker_profile)) = (&mut profile, worker_profile) {
+        profile.merge(&worker_profile);
+      }
+    }
+    outcomes
+  });
+  outcomes.sort_unstable_by_key(|(index, _)| *index);
+  let mut results = vec![];
+  let mut errors = vec![];
+  for (index, outcome) in outcomes {
+    match outcome {
+      Ok(result) => results.push(result),
+      Err(reason) => {
+        results.push(Value::Null(None).jsonify());
+        errors.push((records[index].0, reason));
+      }
+    }
+  }
+  BatchReport {
+    results,
+    errors,
+    elapsed: started.elapsed(),
+    profile,
+  }
+}
+
+/// Evaluates the invocable with input data in JSON format, returns the result in JSON format.
+/// Invalid input data and results being `null` with a reason are reported as errors.
+pub fn evaluate_record(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str, json: &str) -> Result<String, String> {
+  let input_data = dmntk_feel::json::context_from_json(json).map_err(|reason| reason.to_string())?;
+  let (coerced_input_data, _) = model_evaluator.coerce_json_input(namespace, &input_data);
+  record_result(model_evaluator.evaluate_invocable(namespace, invocable_name, &coerced_input_data))
+}
+
+/// Evaluates the invocable with input data in JSON format like [evaluate_record],
+/// the evaluation is profiled with specified profiler.
+fn evaluate_profiled_record(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str, json: &str, profiler: &Rc<EvaluationProfiler>) -> Result<String, String> {
+  let input_data = dmntk_feel::json::context_from_json(json).map_err(|reason| reason.to_string())?;
+  let (coerced_input_data, _) = model_evaluator.coerce_json_input(namespace, &input_data);
+  let listener = Rc::clone(profiler) as Rc<dyn EvaluationListener>;
+  record_result(model_evaluator.evaluate_invocable_with_listener(namespace, invocable_name, &coerced_input_data, listener))
+}
+
+/// Converts the result of the evaluation into JSON format, results being `null` with a reason are reported as errors.
+fn record_result(result: Value) -> Result<String, String> {
+  match result {
+    Value::Null(Some(reason)) => Err(reason.to_string()),
+    result => Ok(result.jsonify()),
+  }
+}
@@ -0,0 +1,169 @@
+//! # Load testing of deployed servers
+//!
+//! Sends evaluation requests of a single invocable to a running **DMNTK** server
+//! from concurrent workers, for the specified duration. Each worker sends the next
+//! request as soon as the previous one is answered. Latencies of all requests are
+//! collected and summarized as percentiles, together with the overall throughput.
+
+use std::time::{Duration, Instant};
+use std::{fmt, thread};
+
+/// Timeout of a single evaluation request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Percentiles of latencies presented in the benchmark report.
+const PERCENTILES: [usize; 4] = [50, 90, 95, 99];
+
+/// Parses the duration given as a number followed by an optional unit:
+/// `ms` (milliseconds), `s` (seconds, the default), `m` (minutes) or `h` (hours).
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+  let text = text.trim();
+  let split_at = text.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(text.len());
+  let (digits, unit) = text.split_at(split_at);
+  let value = digits.parse::<u64>().map_err(|_| format!("invalid duration '{text}', expected e.g. 500ms, 60s, 5m"))?;
+  let duration = match unit {
+    "ms" => Duration::from_millis(value),
+    "" | "s" => Duration::from_secs(value),
+    "m" => Duration::from_secs(value.saturating_mul(60)),
+    "h" => Duration::from_secs(value.saturating_mul(3600)),
+    other => return Err(format!("invalid duration unit '{other}', expected one of: ms, s, m, h")),
+  };
+  if duration.is_zero() {
+    return Err("duration must be greater than zero".to_string());
+  }
+  Ok(duration)
+}
+
+/// Results of the benchmark.
+pub struct BenchReport {
+  /// Latencies of successful requests, sorted in ascending order.
+  latencies: Vec<Duration>,
+  /// Number of failed requests.
+  failures: usize,
+  /// Message describing the first failure.
+  first_failure: Option<String>,
+  /// Total time of the benchmark.
+  elapsed: Duration,
+}
+
+impl BenchReport {
+  /// Returns the number of successful requests.
+  pub fn successes(&self) -> usize {
+    self.latencies.len()
+  }
+
+  /// Returns the number of successful requests per second.
+  fn throughput(&self) -> f64 {
+    let seconds = self.elapsed.as_secs_f64();
+    if seconds > 0.0 {
+      self.latencies.len() as f64 / seconds
+    } else {
+      0.0
+    }
+  }
+
+  /// Returns the latency percentile, using the nearest-rank method.
+  fn percentile(&self, percentile: usize) -> Option<Duration> {
+    let rank = (percentile * self.latencies.len()).div_ceil(100).max(1);
+    self.latencies.get(rank - 1).copied()
+  }
+}
+
+impl fmt::Display for BenchReport {
+  /// Formats the report presented after the benchmark completes.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let millis = |latency: Option<Duration>| latency.map_or("-".to_string(), |latency| format!("{:.3} ms", latency.as_secs_f64() * 1000.0));
+    writeln!(f, "requests:    {}", self.latencies.len() + self.failures)?;
+    writeln!(f, "successes:   {}", self.latencies.len())?;
+    writeln!(f, "failures:    {}", self.failures)?;
+    writeln!(f, "duration:    {:.3} s", self.elapsed.as_secs_f64())?;
+    writeln!(f, "throughput:  {:.2} req/s", self.throughput())?;
+    writeln!(f, "latency min: {}", millis(self.latencies.first().copied()))?;
+    for percentile in PERCENTILES {
+      writeln!(f, "latency p{percentile}: {}", millis(self.percentile(percentile)))?;
+    }
+    write!(f, "latency max: {}", millis(self.latencies.last().copied()))?;
+    if let Some(first_failure) = &self.first_failure {
+      write!(f, "\nfirst failure: {first_failure}")?;
+    }
+    Ok(())
+  }
+}
+
+/// Results collected by a single worker.
+#[derive(Default)]
+struct WorkerResults {
+  /// Latencies of successful requests.
+  latencies: Vec<Duration>,
+  /// Number of failed requests.
+  failures: usize,
+  /// Message describing the first failure.
+  first_failure: Option<String>,
+}
+
+/// Sends evaluation requests with the same body to the endpoint from concurrent workers,
+/// until the duration elapses. Requests answered with an error are counted as failures.
+pub fn run_http_benchmark(endpoint: &str, body: &str, concurrency: usize, duration: Duration) -> BenchReport {
+  let started = Instant::now();
+  let deadline = started + duration;
+  let results = thread::scope(|scope| {
+    let workers = (0..concurrency.max(1)).map(|_| scope.spawn(|| run_worker(endpoint, body, deadline))).collect::<Vec<_>>();
+    workers.into_iter().map(|worker| worker.join().unwrap_or_default()).collect::<Vec<WorkerResults>>()
+  });
+  let elapsed = started.elapsed();
+  let mut latencies = vec![];
+  let mut failures = 0;
+  let mut first_failure = None;
+  for worker_results in results {
+    latencies.extend(worker_results.latencies);
+    failures += worker_results.failures;
+    first_failure = first_failure.or(worker_results.first_failure);
+  }
+  latencies.sort_unstable();
+  BenchReport {
+    latencies,
+    failures,
+    first_failure,
+    elapsed,
+  }
+}
+
+/// Sends requests one after another until the deadline.
+fn run_worker(endpoint: &str, body: &str, deadline: Instant) -> WorkerResults {
+  let mut results = WorkerResults::default();
+  let client = match reqwest::blocking::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+    Ok(client) => client,
+    Err(reason) => {
+      results.failures += 1;
+      results.first_failure = Some(reason.to_string());
+      return results;
+    }
+  };
+  while Instant::now() < deadline {
+    let request_started = Instant::now();
+    let outcome = client
+      .post(endpoint)
+      .header("Content-Type", "application/json")
+      .body(body.to_string())
+      .send()
+      .and_then(|response| response.error_for_status())
+      .and_then(|response| response.text())
+      .map_err(|reason| reason.to_string())
+      .and_then(|text| {
+        // evaluation errors are reported by the server with success status
+        if text.trim_start().starts_with(r#"{"errors""#) {
+          Err(text)
+        } else {
+          Ok(())
+        }
+      });
+    match outcome {
+      Ok(()) => results.latencies.push(request_started.elapsed()),
+      Err(reason) => {
+        results.failures += 1;
+        results.first_failure.get_or_insert(reason);
+      }
+    }
+  }
+  results
+}
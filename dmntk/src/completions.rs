@@ -0,0 +1,217 @@
+//! # Shell completion scripts
+//!
+//! Completion scripts are generated from the definition of command-line arguments,
+//! so they always match subcommands and options of the current version.
+//! Names of invocables are completed by listing invocables from models
+//! already present on the command line.
+
+use clap::{Arg, Command};
+
+/// Bash shell flag.
+pub const SHELL_BASH: &str = "bash";
+
+/// Zsh shell flag.
+pub const SHELL_ZSH: &str = "zsh";
+
+/// Fish shell flag.
+pub const SHELL_FISH: &str = "fish";
+
+/// Identifier of the argument containing the name of the invocable.
+const INVOCABLE_ARG_ID: &str = "invocable";
+
+/// Returns the completion script for specified shell.
+pub fn completion_script(mut command: Command, shell: &str) -> String {
+  command.build();
+  match shell {
+    SHELL_ZSH => zsh_script(&command),
+    SHELL_FISH => fish_script(&command),
+    _ => bash_script(&command),
+  }
+}
+
+/// Returns options (non-positional arguments) of the command.
+fn options(command: &Command) -> impl Iterator<Item = &Arg> {
+  command.get_arguments().filter(|arg| !arg.is_positional() && !arg.is_hide_set())
+}
+
+/// Returns all names of the option, prefixed with dashes.
+fn option_names(arg: &Arg) -> Vec<String> {
+  arg
+    .get_short()
+    .map(|short| format!("-{short}"))
+    .into_iter()
+    .chain(arg.get_long().map(|long| format!("--{long}")))
+    .collect()
+}
+
+/// Returns names of possible values of the option.
+fn possible_values(arg: &Arg) -> Vec<String> {
+  arg.get_possible_values().iter().map(|value| value.get_name().to_string()).collect()
+}
+
+/// Returns `true` when the option takes a value.
+fn takes_value(arg: &Arg) -> bool {
+  arg.get_action().takes_values()
+}
+
+/// Returns the help text of the option, or the description of the subcommand.
+fn description(text: Option<&clap::builder::StyledStr>) -> String {
+  text.map(|text| text.to_string()).unwrap_or_default()
+}
+
+/// Returns the completion script for bash.
+fn bash_script(command: &Command) -> String {
+  let name = command.get_name();
+  let mut words = vec![];
+  let mut cases = String::new();
+  for subcommand in command.get_subcommands() {
+    let mut names = vec![subcommand.get_name()];
+    names.extend(subcommand.get_visible_aliases());
+    words.extend(names.iter().map(|name| name.to_string()));
+    let mut option_cases = String::new();
+    for arg in options(subcommand).filter(|arg| takes_value(arg)) {
+      let values = possible_values(arg);
+      let reply = if arg.get_id() == INVOCABLE_ARG_ID {
+        format!(r#"local IFS=$'\n'; COMPREPLY=($(compgen -W "$({name} evi --list $(printf '%s\n' "${{COMP_WORDS[@]}}" | grep '\.dmn$') 2>/dev/null)" -- "${{cur}}"))"#)
+      } else if !values.is_empty() {
+        format!(r#"COMPREPLY=($(compgen -W "{}" -- "${{cur}}"))"#, values.join(" "))
+      } else {
+        r#"COMPREPLY=($(compgen -f -- "${cur}"))"#.to_string()
+      };
+      option_cases.push_str(&format!("        {})\n          {reply}\n          return 0\n          ;;\n", option_names(arg).join("|")));
+    }
+    let flags = options(subcommand).flat_map(option_names).collect::<Vec<String>>().join(" ");
+    cases.push_str(&format!("    {})\n      case \"${{prev}}\" in\n{option_cases}      esac\n", names.join("|")));
+    cases.push_str(&format!(
+      "      if [[ \"${{cur}}\" == -* ]]; then\n        COMPREPLY=($(compgen -W \"{flags}\" -- \"${{cur}}\"))\n      else\n        COMPREPLY=($(compgen -f -- \"${{cur}}\"))\n      fi\n      ;;\n"
+    ));
+  }
+  words.extend(options(command).flat_map(option_names));
+  format!(
+    r#"_{name}() {{
+  local cur="${{COMP_WORDS[COMP_CWORD]}}"
+  local prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+  if [[ ${{COMP_CWORD}} -eq 1 ]]; then
+    COMPREPLY=($(compgen -W "{}" -- "${{cur}}"))
+    return 0
+  fi
+  case "${{COMP_WORDS[1]}}" in
+{cases}  esac
+}}
+complete -o filenames -F _{name} {name}
+"#,
+    words.join(" ")
+  )
+}
+
+/// Returns the text escaped for use in descriptions of zsh completions.
+fn zsh_escaped(text: &str) -> String {
+  text.replace('\'', r"'\''").replace('[', r"\[").replace(']', r"\]").replace(':', r"\:")
+}
+
+/// Returns the completion script for zsh.
+fn zsh_script(command: &Command) -> String {
+  let name = command.get_name();
+  let mut commands = vec![];
+  let mut cases = String::new();
+  for subcommand in command.get_subcommands() {
+    let about = zsh_escaped(&description(subcommand.get_about()));
+    let mut names = vec![subcommand.get_name()];
+    names.extend(subcommand.get_visible_aliases());
+    commands.extend(names.iter().map(|name| format!("'{name}[{about}]'")));
+    let mut specs = vec![];
+    for arg in options(subcommand) {
+      let names = option_names(arg);
+      let help = zsh_escaped(&description(arg.get_help()));
+      let value = if !takes_value(arg) {
+        String::new()
+      } else if arg.get_id() == INVOCABLE_ARG_ID {
+        format!(":NAME:{{_values 'invocable' ${{(f)\"$({name} evi --list ${{(M)words:#*.dmn}} 2>/dev/null)\"}}}}")
+      } else {
+        let values = possible_values(arg);
+        if values.is_empty() {
+          ":FILE:_files".to_string()
+        } else {
+          format!(":VALUE:({})", values.join(" "))
+        }
+      };
+      let spec = if names.len() > 1 {
+        format!("'({})'{{{}}}'[{help}]{value}'", names.join(" "), names.join(","))
+      } else {
+        format!("'{}[{help}]{value}'", names.join(""))
+      };
+      specs.push(spec);
+    }
+    specs.push("'*:file:_files'".to_string());
+    cases.push_str(&format!(
+      "        {})\n          _arguments \\\n            {}\n          ;;\n",
+      names.join("|"),
+      specs.join(" \\\n            ")
+    ));
+  }
+  format!(
+    r#"#compdef {name}
+
+_{name}() {{
+  local line state
+  _arguments -C '1: :->commands' '*:: :->arguments'
+  case $state in
+    commands)
+      _values 'command' \
+        {}
+      ;;
+    arguments)
+      case $line[1] in
+{cases}      esac
+      ;;
+  esac
+}}
+
+_{name} "$@"
+"#,
+    commands.join(" \\\n        ")
+  )
+}
+
+/// Returns the text escaped for use in double-quoted fish strings.
+fn fish_escaped(text: &str) -> String {
+  text.replace('\\', r"\\").replace('"', r#"\""#).replace('$', r"\$")
+}
+
+/// Returns the completion script for fish.
+fn fish_script(command: &Command) -> String {
+  let name = command.get_name();
+  let mut lines = vec![];
+  for subcommand in command.get_subcommands() {
+    let about = fish_escaped(&description(subcommand.get_about()));
+    let mut names = vec![subcommand.get_name()];
+    names.extend(subcommand.get_visible_aliases());
+    for subcommand_name in &names {
+      lines.push(format!(r#"complete -c {name} -n "__fish_use_subcommand" -f -a "{subcommand_name}" -d "{about}""#));
+    }
+    let condition = format!("__fish_seen_subcommand_from {}", names.join(" "));
+    for arg in options(subcommand) {
+      let mut line = format!(r#"complete -c {name} -n "{condition}""#);
+      if let Some(short) = arg.get_short() {
+        line.push_str(&format!(" -s {short}"));
+      }
+      if let Some(long) = arg.get_long() {
+        line.push_str(&format!(" -l {long}"));
+      }
+      line.push_str(&format!(r#" -d "{}""#, fish_escaped(&description(arg.get_help()))));
+      if takes_value(arg) {
+        let values = possible_values(arg);
+        if arg.get_id() == INVOCABLE_ARG_ID {
+          line.push_str(&format!(r#" -r -f -a "({name} evi --list (commandline -opc | string match '*.dmn') 2>/dev/null)""#));
+        } else if !values.is_empty() {
+          line.push_str(&format!(r#" -r -f -a "{}""#, values.join(" ")));
+        } else {
+          line.push_str(" -r -F");
+        }
+      }
+      lines.push(line);
+    }
+  }
+  lines.push(String::new());
+  lines.join("\n")
+}
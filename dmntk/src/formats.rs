@@ -0,0 +1,247 @@
+//! # Output formats of evaluation results
+
+use dmntk_common::{ColorMode, Jsonify};
+use dmntk_feel::values::Value;
+use dmntk_feel::PrettyPrinter;
+use std::io::{self, IsTerminal};
+
+/// JSON output format flag.
+pub const FORMAT_JSON: &str = "json";
+
+/// YAML output format flag.
+pub const FORMAT_YAML: &str = "yaml";
+
+/// FEEL literal output format flag.
+pub const FORMAT_FEEL: &str = "feel";
+
+/// Pretty-printed FEEL output format flag.
+pub const FORMAT_PRETTY: &str = "pretty";
+
+/// Text table output format flag.
+pub const FORMAT_TABLE: &str = "table";
+
+/// Output formats of evaluation results.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OutputFormat {
+  /// Machine-readable JSON.
+  Json,
+  /// Machine-readable YAML.
+  Yaml,
+  /// FEEL literal, the same as used in input files.
+  Feel,
+  /// Human-oriented FEEL literal, with nested values in separate lines.
+  Pretty,
+  /// Human-oriented text table.
+  Table,
+}
+
+impl From<String> for OutputFormat {
+  /// Converts a string into [OutputFormat], unknown formats are converted into JSON.
+  fn from(value: String) -> Self {
+    match value.to_lowercase().trim() {
+      FORMAT_YAML => Self::Yaml,
+      FORMAT_FEEL => Self::Feel,
+      FORMAT_PRETTY => Self::Pretty,
+      FORMAT_TABLE => Self::Table,
+      _ => Self::Json,
+    }
+  }
+}
+
+/// Settings of printing evaluation results.
+#[derive(Debug, Clone)]
+pub struct OutputSettings {
+  /// Format of printed results.
+  format: OutputFormat,
+  /// Optional query selecting the printed part of results.
+  opt_query: Option<String>,
+}
+
+impl OutputSettings {
+  /// Creates output settings with specified format and optional query.
+  pub fn new(format: OutputFormat, opt_query: Option<String>) -> Self {
+    Self { format, opt_query }
+  }
+
+  /// Returns the part of the value selected by the query, formatted in requested format.
+  ///
+  /// Invalid query is reported as `null` with the reason, like other evaluation errors.
+  pub fn render(&self, value: &Value) -> String {
+    match &self.opt_query {
+      Some(query) => match value.select(query) {
+        Ok(selected) => format_value(&selected, self.format),
+        Err(reason) => format_value(&Value::Null(Some(reason.to_string())), self.format),
+      },
+      None => format_value(value, self.format),
+    }
+  }
+}
+
+/// Returns the value formatted in specified output format.
+fn format_value(value: &Value, format: OutputFormat) -> String {
+  match format {
+    OutputFormat::Json => value.jsonify(),
+    OutputFormat::Yaml => yaml_lines(value).join("\n"),
+    OutputFormat::Feel => value.to_string(),
+    OutputFormat::Pretty => {
+      // colors are used only when printing to terminal
+      let color_mode = if io::stdout().is_terminal() { ColorMode::On } else { ColorMode::Off };
+      PrettyPrinter::default().with_color_mode(color_mode).print(value)
+    }
+    OutputFormat::Table => table(value),
+  }
+}
+
+/// Returns `true` when the value is a non-empty context or non-empty list.
+fn is_nested(value: &Value) -> bool {
+  match value {
+    Value::Context(ctx) => !ctx.is_empty(),
+    Value::List(items) => !items.is_empty(),
+    _ => false,
+  }
+}
+
+/// Returns lines of the value formatted as YAML.
+fn yaml_lines(value: &Value) -> Vec<String> {
+  let mut lines = vec![];
+  match value {
+    Value::Context(ctx) if !ctx.is_empty() => {
+      for (name, value) in ctx.iter() {
+        let key = yaml_key(&name.to_string());
+        if is_nested(value) {
+          lines.push(format!("{key}:"));
+          lines.extend(yaml_lines(value).into_iter().map(|line| format!("  {line}")));
+        } else {
+          lines.push(format!("{key}: {}", yaml_scalar(value)));
+        }
+      }
+    }
+    Value::List(items) if !items.is_empty() => {
+      for item in items {
+        if is_nested(item) {
+          // the first line of nested value follows the dash, remaining lines are aligned with it
+          for (index, line) in yaml_lines(item).into_iter().enumerate() {
+            lines.push(format!("{} {line}", if index == 0 { "-" } else { " " }));
+          }
+        } else {
+          lines.push(format!("- {}", yaml_scalar(item)));
+        }
+      }
+    }
+    other => lines.push(yaml_scalar(other)),
+  }
+  lines
+}
+
+/// Returns the scalar value formatted as YAML.
+fn yaml_scalar(value: &Value) -> String {
+  match value {
+    Value::Null(_) => "null".to_string(),
+    Value::Boolean(_) | Value::Number(_) => value.jsonify(),
+    Value::String(s) => yaml_quoted(s),
+    Value::Context(_) => "{}".to_string(),
+    Value::List(_) => "[]".to_string(),
+    other => yaml_quoted(&other.to_string()),
+  }
+}
+
+/// Returns the key of the mapping, quoted only when it is not a valid plain YAML scalar.
+fn yaml_key(key: &str) -> String {
+  let plain = key.starts_with(|ch: char| ch.is_alphabetic()) && key.chars().all(|ch| ch.is_alphanumeric() || matches!(ch, ' ' | '_' | '-' | '.'));
+  if plain {
+    key.to_string()
+  } else {
+    yaml_quoted(key)
+  }
+}
+
+/// Returns the text as double-quoted YAML scalar.
+fn yaml_quoted(text: &str) -> String {
+  let mut quoted = String::with_capacity(text.len() + 2);
+  quoted.push('"');
+  for ch in text.chars() {
+    match ch {
+      '"' => quoted.push_str(r#"\""#),
+      '\\' => quoted.push_str(r"\\"),
+      '\n' => quoted.push_str(r"\n"),
+      '\r' => quoted.push_str(r"\r"),
+      '\t' => quoted.push_str(r"\t"),
+      ch if ch.is_control() => quoted.push_str(&format!(r"\x{:02x}", ch as u32)),
+      ch => quoted.push(ch),
+    }
+  }
+  quoted.push('"');
+  quoted
+}
+
+/// Returns the value formatted as a text table.
+///
+/// Lists of contexts are displayed with a column for each entry name, contexts are displayed
+/// with names and values in two columns, lists of other values in a single column.
+/// Values that do not fit in this layout are displayed as FEEL literals.
+fn table(value: &Value) -> String {
+  match value {
+    Value::List(items) if !items.is_empty() && items.iter().all(|item| matches!(item, Value::Context(_))) => {
+      let mut header = vec![];
+      for item in items {
+        if let Value::Context(ctx) = item {
+          for name in ctx.iter().map(|(name, _)| name.to_string()) {
+            if !header.contains(&name) {
+              header.push(name);
+            }
+          }
+        }
+      }
+      let rows = items
+        .iter()
+        .map(|item| {
+          header
+            .iter()
+            .map(|name| match item {
+              Value::Context(ctx) => ctx
+                .iter()
+                .find(|(entry_name, _)| entry_name.to_string() == *name)
+                .map(|(_, value)| cell(value))
+                .unwrap_or_default(),
+              _ => String::new(),
+            })
+            .collect()
+        })
+        .collect::<Vec<Vec<String>>>();
+      text_table(header, rows)
+    }
+    Value::List(items) if !items.is_empty() => text_table(vec!["value".to_string()], items.iter().map(|item| vec![cell(item)]).collect()),
+    Value::Context(ctx) if !ctx.is_empty() => text_table(
+      vec!["name".to_string(), "value".to_string()],
+      ctx.iter().map(|(name, value)| vec![name.to_string(), cell(value)]).collect(),
+    ),
+    other => cell(other),
+  }
+}
+
+/// Returns the value displayed in a table cell, strings are displayed without quotes.
+fn cell(value: &Value) -> String {
+  match value {
+    Value::String(s) => s.to_string(),
+    other => other.to_string(),
+  }
+}
+
+/// Returns the text table with specified header and rows, columns are aligned to the widest cell.
+fn text_table(header: Vec<String>, rows: Vec<Vec<String>>) -> String {
+  let mut widths = header.iter().map(|name| name.chars().count()).collect::<Vec<usize>>();
+  for row in &rows {
+    for (width, text) in widths.iter_mut().zip(row) {
+      *width = (*width).max(text.chars().count());
+    }
+  }
+  let line = |cells: &[String]| {
+    let cells = cells.iter().zip(&widths).map(|(text, width)| format!(" {text:<width$} ")).collect::<Vec<String>>();
+    format!("|{}|", cells.join("|"))
+  };
+  let separator = format!("+{}+", widths.iter().map(|width| "-".repeat(width + 2)).collect::<Vec<String>>().join("+"));
+  let mut lines = vec![separator.clone(), line(&header), separator.clone()];
+  lines.extend(rows.iter().map(|row| line(row)));
+  lines.push(separator);
+  lines.join("\n")
+}
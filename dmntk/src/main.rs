@@ -1,7 +1,14 @@
 //! # **DMNTK** | Decision Model and Notation Toolkit
 
 mod actions;
+mod batch;
+mod bench;
+mod completions;
 mod examples;
+mod formats;
+mod replay;
+#[cfg(feature = "kafka")]
+mod stream;
 
 /// Main entrypoint of **DMNTK**.
 #[actix_web::main]
@@ -0,0 +1,113 @@
+//! # Replay of audited evaluations
+//!
+//! Re-evaluates evaluations recorded in audit files (one audit record per line)
+//! against another version of decision models and reports records for which
+//! the result differs from the recorded one, so changes of decision logic
+//! may be analyzed before they are deployed.
+//!
+//! Invocables are evaluated in the namespace of the audit record when replayed models
+//! define it, otherwise in the namespace of the first replayed model.
+//! Results being `null` are equal, independently of the reason of the `null` value.
+
+use dmntk_evaluator::ModelEvaluator;
+use dmntk_feel::values::Value;
+use dmntk_workspace::AuditRecord;
+use std::fmt;
+
+/// Audited evaluation for which the result differs from the recorded result.
+pub struct ReplayDifference {
+  /// Number of the line in the audit file.
+  pub line_number: usize,
+  /// Audit record of the original evaluation.
+  pub record: AuditRecord,
+  /// Result of the replayed evaluation.
+  pub actual: Value,
+}
+
+impl fmt::Display for ReplayDifference {
+  /// Formats the difference with the recorded and the replayed result.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "line {}, record {}, invocable `{}`: expected {}, actual {}",
+      self.line_number,
+      self.record.id(),
+      self.record.invocable_name(),
+      self.record.result(),
+      self.actual
+    )
+  }
+}
+
+/// Results of replaying audited evaluations.
+pub struct ReplayReport {
+  /// Number of replayed records.
+  replayed: usize,
+  /// Records for which the result differs from the recorded result.
+  differences: Vec<ReplayDifference>,
+  /// Line numbers of records that could not be parsed, with reasons.
+  errors: Vec<(usize, String)>,
+}
+
+impl ReplayReport {
+  /// Returns records for which the result differs from the recorded result.
+  pub fn differences(&self) -> &[ReplayDifference] {
+    &self.differences
+  }
+
+  /// Returns line numbers of records that could not be parsed, with reasons.
+  pub fn errors(&self) -> &[(usize, String)] {
+    &self.errors
+  }
+}
+
+impl fmt::Display for ReplayReport {
+  /// Formats the summary of the replay.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "replayed {} records, {} unchanged, {} differences, {} errors",
+      self.replayed,
+      self.replayed - self.differences.len(),
+      self.differences.len(),
+      self.errors.len()
+    )
+  }
+}
+
+/// Replays each non-empty line of the audit file with specified model evaluator.
+///
+/// Namespaces defined by replayed models are used to select the namespace of evaluated invocables,
+/// records from other namespaces are evaluated in the default namespace.
+pub fn run_replay(model_evaluator: &ModelEvaluator, namespaces: &[String], default_namespace: &str, audit: &str) -> ReplayReport {
+  let mut report = ReplayReport {
+    replayed: 0,
+    differences: vec![],
+    errors: vec![],
+  };
+  for (index, line) in audit.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+    let record = match line.parse::<AuditRecord>() {
+      Ok(record) => record,
+      Err(reason) => {
+        report.errors.push((index + 1, reason.to_string()));
+        continue;
+      }
+    };
+    let namespace = if namespaces.iter().any(|namespace| namespace == record.namespace()) {
+      record.namespace()
+    } else {
+      default_namespace
+    };
+    let actual = model_evaluator.evaluate_invocable(namespace, record.invocable_name(), record.input_data());
+    report.replayed += 1;
+    let unchanged = matches!((record.result(), &actual), (Value::Null(_), Value::Null(_))) || record.result() == &actual;
+    if !unchanged {
+      report.differences.push(ReplayDifference {
+        line_number: index + 1,
+        record,
+        actual,
+      });
+    }
+  }
+  report
+}
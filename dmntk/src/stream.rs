@@ -0,0 +1,184 @@
+//! # Streaming evaluation of invocables
+//!
+//! Consumes messages containing input data in JSON format from a Kafka topic,
+//! evaluates the invocable for each message and produces results in JSON format
+//! to the output topic, keyed like consumed messages. Messages that could not be
+//! evaluated are routed to the dead letter topic, with the reason in the message
+//! header, or reported and skipped when no dead letter topic is configured.
+//!
+//! Offsets of consumed messages are committed only after all produced messages are
+//! delivered, so each message is evaluated at least once. When the producer queue
+//! is full, sending is retried until the queue drains; when any produced message
+//! could not be delivered, offsets are not committed and the stream stops.
+
+use crate::batch::evaluate_record;
+use dmntk_evaluator::ModelEvaluator;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer};
+use rdkafka::error::{KafkaError, RDKafkaErrorCode};
+use rdkafka::message::{DeliveryResult, Header, Message, OwnedHeaders};
+use rdkafka::producer::{BaseRecord, Producer, ProducerContext, ThreadedProducer};
+use rdkafka::ClientContext;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Name of the header holding the reason of the failure in dead letter messages.
+const ERROR_HEADER: &str = "dmntk-error";
+
+/// Number of processed messages after which offsets are committed.
+const COMMIT_INTERVAL: usize = 100;
+
+/// Timeout of polling for consumed messages.
+const POLL_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Timeout of delivering produced messages before committing offsets.
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Pause before retrying to send a message when the producer queue is full.
+const QUEUE_FULL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Settings of the streaming evaluation.
+pub struct StreamSettings {
+  /// Comma-separated list of Kafka brokers.
+  pub brokers: String,
+  /// Identifier of the consumer group.
+  pub group_id: String,
+  /// Name of the topic with input data.
+  pub input_topic: String,
+  /// Name of the topic where results are produced.
+  pub output_topic: String,
+  /// Optional name of the topic where messages that could not be evaluated are produced.
+  pub opt_dlq_topic: Option<String>,
+}
+
+/// Evaluates the invocable for each consumed message, until the process is interrupted
+/// or any of the Kafka clients fails.
+pub fn run_stream(model_evaluator: Arc<ModelEvaluator>, namespace: &str, invocable_name: &str, settings: &StreamSettings) -> Result<(), String> {
+  let consumer: BaseConsumer = ClientConfig::new()
+    .set("bootstrap.servers", &settings.brokers)
+    .set("group.id", &settings.group_id)
+    .set("enable.auto.commit", "false")
+    .set("auto.offset.reset", "earliest")
+    .create()
+    .map_err(|reason| format!("creating Kafka consumer failed with reason: {reason}"))?;
+  let producer: ThreadedProducer<DeliveryReports> = ClientConfig::new()
+    .set("bootstrap.servers", &settings.brokers)
+    .create_with_context(DeliveryReports::default())
+    .map_err(|reason| format!("creating Kafka producer failed with reason: {reason}"))?;
+  consumer
+    .subscribe(&[&settings.input_topic])
+    .map_err(|reason| format!("subscribing to topic `{}` failed with reason: {reason}", settings.input_topic))?;
+  let mut uncommitted = 0;
+  loop {
+    match consumer.poll(POLL_TIMEOUT) {
+      Some(Ok(message)) => {
+        let key = message.key().unwrap_or_default();
+        let payload = message.payload().unwrap_or_default();
+        let outcome = evaluate_message(&model_evaluator, namespace, invocable_name, payload);
+        let record = match &outcome {
+          Ok(result) => Some(BaseRecord::to(&settings.output_topic).key(key).payload(result.as_bytes())),
+          Err(reason) => match &settings.opt_dlq_topic {
+            Some(dlq_topic) => {
+              let headers = OwnedHeaders::new().insert(Header {
+                key: ERROR_HEADER,
+                value: Some(reason.as_bytes()),
+              });
+              Some(BaseRecord::to(dlq_topic).key(key).payload(payload).headers(headers))
+            }
+            None => {
+              eprintln!(
+                "evaluating message at offset {} of partition {} failed with reason: {reason}",
+                message.offset(),
+                message.partition()
+              );
+              None
+            }
+          },
+        };
+        if let Some(record) = record {
+          send(&producer, record)?;
+        }
+        uncommitted += 1;
+        if uncommitted >= COMMIT_INTERVAL {
+          commit(&consumer, &producer)?;
+          uncommitted = 0;
+        }
+      }
+      Some(Err(reason)) => return Err(format!("consuming message failed with reason: {reason}")),
+      None => {
+        if uncommitted > 0 {
+          commit(&consumer, &producer)?;
+          uncommitted = 0;
+        }
+      }
+    }
+  }
+}
+
+/// Evaluates the invocable with input data from the message payload, returns the result in JSON format.
+fn evaluate_message(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str, payload: &[u8]) -> Result<String, String> {
+  let json = std::str::from_utf8(payload).map_err(|reason| format!("invalid UTF-8 payload: {reason}"))?;
+  evaluate_record(model_evaluator, namespace, invocable_name, json)
+}
+
+/// Sends the message, retrying while the producer queue is full.
+fn send(producer: &ThreadedProducer<DeliveryReports>, mut record: BaseRecord<'_, [u8], [u8]>) -> Result<(), String> {
+  loop {
+    match producer.send(record) {
+      Ok(()) => return Ok(()),
+      Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), returned)) => {
+        record = returned;
+        thread::sleep(QUEUE_FULL_BACKOFF);
+      }
+      Err((reason, _)) => return Err(format!("producing message failed with reason: {reason}")),
+    }
+  }
+}
+
+/// Delivers all produced messages and commits offsets of consumed messages,
+/// offsets are not committed when any of the produced messages was not delivered.
+fn commit(consumer: &BaseConsumer, producer: &ThreadedProducer<DeliveryReports>) -> Result<(), String> {
+  producer
+    .flush(FLUSH_TIMEOUT)
+    .map_err(|reason| format!("delivering messages failed with reason: {reason}"))?;
+  if let Some((failed, reason)) = producer.context().take_failures() {
+    return Err(format!("delivering {failed} message(s) failed, offsets were not committed, last reason: {reason}"));
+  }
+  consumer
+    .commit_consumer_state(CommitMode::Sync)
+    .map_err(|reason| format!("committing offsets failed with reason: {reason}"))
+}
+
+/// Producer context collecting failed deliveries of produced messages.
+#[derive(Default)]
+struct DeliveryReports {
+  /// Number of messages that could not be delivered since the last check.
+  failed: AtomicU64,
+  /// Reason of the last failed delivery.
+  reason: Mutex<Option<String>>,
+}
+
+impl DeliveryReports {
+  /// Returns the number of failed deliveries and the reason of the last one,
+  /// or `None` when all messages were delivered since the last check.
+  fn take_failures(&self) -> Option<(u64, String)> {
+    let failed = self.failed.swap(0, Ordering::Relaxed);
+    let reason = self.reason.lock().unwrap().take();
+    (failed > 0).then(|| (failed, reason.unwrap_or_default()))
+  }
+}
+
+impl ClientContext for DeliveryReports {}
+
+impl ProducerContext for DeliveryReports {
+  type DeliveryOpaque = ();
+
+  fn delivery(&self, delivery_result: &DeliveryResult<'_>, _: Self::DeliveryOpaque) {
+    if let Err((reason, _)) = delivery_result {
+      *self.reason.lock().unwrap() = Some(reason.to_string());
+      self.failed.fetch_add(1, Ordering::Relaxed);
+    }
+  }
+}
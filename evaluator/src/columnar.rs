@@ -0,0 +1,278 @@
+//! # Columnar batch evaluation
+//!
+//! Evaluates an invocable for every row of an Arrow [RecordBatch] (or Parquet file),
+//! columns are mapped to input data with the same names. Column values are converted
+//! to FEEL values once per column, input data of each row are built from converted values.
+//!
+//! Two columns are appended to the evaluated batch: the result column named after
+//! the invocable and the error column (named after the invocable, followed by ` error`),
+//! holding reasons of failed evaluations. The type of the result column is inferred
+//! from results, unless explicitly specified:
+//! - `Boolean` when all results are booleans,
+//! - `Int64` when all results are integer numbers,
+//! - `Float64` when all results are numbers,
+//! - `Utf8` otherwise, strings are written as they are, other values in JSON format.
+
+use crate::errors::*;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{Array, ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use dmntk_common::{Jsonify, Result};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelNumber, Name};
+use dmntk_feel_temporal::FeelDate;
+use dmntk_model_evaluator::ModelEvaluator;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Evaluates the invocable for every row of the batch, returns the batch with appended result and error columns.
+///
+/// When the type of the result column is not specified, it is inferred from results.
+/// Results not matching the type of the result column are reported in the error column.
+pub fn evaluate_record_batch(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str, batch: &RecordBatch, result_type: Option<&DataType>) -> Result<RecordBatch> {
+  let schema = batch.schema();
+  let names = schema.fields().iter().map(|field| Name::from(field.name().as_str())).collect::<Vec<Name>>();
+  let columns = schema
+    .fields()
+    .iter()
+    .zip(batch.columns())
+    .map(|(field, array)| column_values(field.name(), array))
+    .collect::<Result<Vec<Vec<Value>>>>()?;
+  let mut results = Vec::with_capacity(batch.num_rows());
+  let mut errors = Vec::with_capacity(batch.num_rows());
+  for row in 0..batch.num_rows() {
+    let mut input_data = FeelContext::default();
+    for (name, values) in names.iter().zip(&columns) {
+      input_data.set_entry(name, values[row].clone());
+    }
+    let (coerced_input_data, _) = model_evaluator.coerce_json_input(namespace, &input_data);
+    match model_evaluator.evaluate_invocable(namespace, invocable_name, &coerced_input_data) {
+      Value::Null(Some(reason)) => {
+        results.push(Value::Null(None));
+        errors.push(Some(reason.to_string()));
+      }
+      result => {
+        results.push(result);
+        errors.push(None);
+      }
+    }
+  }
+  let result_type = result_type.cloned().unwrap_or_else(|| infer_result_type(&results));
+  let result_array = result_column(&results, &result_type, &mut errors)?;
+  let error_column_name = format!("{invocable_name} error");
+  let mut fields = schema.fields().iter().cloned().collect::<Vec<_>>();
+  fields.push(Arc::new(Field::new(invocable_name, result_type, true)));
+  fields.push(Arc::new(Field::new(error_column_name, DataType::Utf8, true)));
+  let mut arrays = batch.columns().to_vec();
+  arrays.push(result_array);
+  arrays.push(Arc::new(StringArray::from(errors)));
+  RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).map_err(|reason| err_building_record_batch_failed(reason.to_string()))
+}
+
+/// Evaluates the invocable for every row of the Parquet file, writes rows with appended
+/// result and error columns to the output Parquet file, returns the number of evaluated rows.
+///
+/// The type of the result column is inferred from results in the first batch.
+#[cfg(feature = "parquet")]
+pub fn evaluate_parquet_file(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str, input: &std::path::Path, output: &std::path::Path) -> Result<usize> {
+  use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+  use parquet::arrow::ArrowWriter;
+  use std::fs::File;
+  let parquet_err = |reason: &dyn std::fmt::Display| err_parquet_failed(reason.to_string());
+  let input_file = File::open(input).map_err(|reason| parquet_err(&reason))?;
+  let reader = ParquetRecordBatchReaderBuilder::try_new(input_file)
+    .and_then(|builder| builder.build())
+    .map_err(|reason| parquet_err(&reason))?;
+  let mut writer: Option<ArrowWriter<File>> = None;
+  let mut result_type = None;
+  let mut rows = 0;
+  for batch in reader {
+    let batch = batch.map_err(|reason| parquet_err(&reason))?;
+    let evaluated_batch = evaluate_record_batch(model_evaluator, namespace, invocable_name, &batch, result_type.as_ref())?;
+    if result_type.is_none() {
+      result_type = evaluated_batch.schema().field_with_name(invocable_name).ok().map(|field| field.data_type().clone());
+    }
+    if writer.is_none() {
+      let output_file = File::create(output).map_err(|reason| parquet_err(&reason))?;
+      writer = Some(ArrowWriter::try_new(output_file, evaluated_batch.schema(), None).map_err(|reason| parquet_err(&reason))?);
+    }
+    if let Some(writer) = writer.as_mut() {
+      writer.write(&evaluated_batch).map_err(|reason| parquet_err(&reason))?;
+    }
+    rows += evaluated_batch.num_rows();
+  }
+  if let Some(writer) = writer {
+    writer.close().map_err(|reason| parquet_err(&reason))?;
+  }
+  Ok(rows)
+}
+
+/// Converts values of the column into FEEL values.
+fn column_values(column_name: &str, array: &ArrayRef) -> Result<Vec<Value>> {
+  let values = |value: &dyn Fn(usize) -> Value| (0..array.len()).map(|row| if array.is_null(row) { Value::Null(None) } else { value(row) }).collect();
+  Ok(match array.data_type() {
+    DataType::Null => vec![Value::Null(None); array.len()],
+    DataType::Boolean => values(&|row| Value::Boolean(array.as_boolean().value(row))),
+    DataType::Int8 => values(&|row| Value::Number(array.as_primitive::<Int8Type>().value(row).into())),
+    DataType::Int16 => values(&|row| Value::Number(array.as_primitive::<Int16Type>().value(row).into())),
+    DataType::Int32 => values(&|row| Value::Number(array.as_primitive::<Int32Type>().value(row).into())),
+    DataType::Int64 => values(&|row| Value::Number(array.as_primitive::<Int64Type>().value(row).into())),
+    DataType::UInt8 => values(&|row| Value::Number(array.as_primitive::<UInt8Type>().value(row).into())),
+    DataType::UInt16 => values(&|row| Value::Number(array.as_primitive::<UInt16Type>().value(row).into())),
+    DataType::UInt32 => values(&|row| Value::Number(array.as_primitive::<UInt32Type>().value(row).into())),
+    DataType::UInt64 => values(&|row| Value::Number(array.as_primitive::<UInt64Type>().value(row).into())),
+    DataType::Float32 => values(&|row| float_value(array.as_primitive::<Float32Type>().value(row) as f64)),
+    DataType::Float64 => values(&|row| float_value(array.as_primitive::<Float64Type>().value(row))),
+    DataType::Decimal128(_, _) => values(&|row| number_value(&array.as_primitive::<Decimal128Type>().value_as_string(row))),
+    DataType::Utf8 => values(&|row| Value::String(array.as_string::<i32>().value(row).into())),
+    DataType::LargeUtf8 => values(&|row| Value::String(array.as_string::<i64>().value(row).into())),
+    DataType::Date32 => values(&|row| date_value(array.as_primitive::<Date32Type>().value(row))),
+    DataType::Struct(fields) => {
+      let struct_array = array.as_struct();
+      let children = fields
+        .iter()
+        .zip(struct_array.columns())
+        .map(|(field, child)| Ok((Name::from(field.name().as_str()), column_values(field.name(), child)?)))
+        .collect::<Result<Vec<(Name, Vec<Value>)>>>()?;
+      values(&|row| {
+        let mut ctx = FeelContext::default();
+        for (name, child_values) in &children {
+          ctx.set_entry(name, child_values[row].clone());
+        }
+        Value::Context(ctx)
+      })
+    }
+    DataType::List(_) => {
+      let list_array = array.as_list::<i32>();
+      let items = (0..array.len())
+        .map(|row| column_values(column_name, &list_array.value(row)))
+        .collect::<Result<Vec<Vec<Value>>>>()?;
+      values(&|row| Value::List(items[row].clone()))
+    }
+    DataType::LargeList(_) => {
+      let list_array = array.as_list::<i64>();
+      let items = (0..array.len())
+        .map(|row| column_values(column_name, &list_array.value(row)))
+        .collect::<Result<Vec<Vec<Value>>>>()?;
+      values(&|row| Value::List(items[row].clone()))
+    }
+    other => return Err(err_unsupported_column_type(column_name, &other.to_string())),
+  })
+}
+
+/// Converts the floating-point number into FEEL number, not finite numbers are converted into `null`.
+fn float_value(number: f64) -> Value {
+  if number.is_finite() {
+    number_value(&number.to_string())
+  } else {
+    Value::Null(None)
+  }
+}
+
+/// Converts the text of a number into FEEL number.
+fn number_value(text: &str) -> Value {
+  FeelNumber::from_str(text).map(Value::Number).unwrap_or(Value::Null(None))
+}
+
+/// Converts the number of days since UNIX epoch into FEEL date.
+fn date_value(days: i32) -> Value {
+  // conversion of days into civil date, proleptic Gregorian calendar
+  let z = days as i64 + 719_468;
+  let era = z.div_euclid(146_097);
+  let day_of_era = z.rem_euclid(146_097);
+  let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let mp = (5 * day_of_year + 2) / 153;
+  let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+  let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+  FeelDate::new_opt(year, month, day).map(Value::Date).unwrap_or(Value::Null(None))
+}
+
+/// Infers the type of the result column from results.
+fn infer_result_type(results: &[Value]) -> DataType {
+  let mut present = results.iter().filter(|value| !matches!(value, Value::Null(_))).peekable();
+  if present.peek().is_none() {
+    return DataType::Utf8;
+  }
+  let present = present.collect::<Vec<&Value>>();
+  if present.iter().all(|value| matches!(value, Value::Boolean(_))) {
+    DataType::Boolean
+  } else if present
+    .iter()
+    .all(|value| matches!(value, Value::Number(number) if number.is_integer() && i64::try_from(number).is_ok()))
+  {
+    DataType::Int64
+  } else if present.iter().all(|value| matches!(value, Value::Number(_))) {
+    DataType::Float64
+  } else {
+    DataType::Utf8
+  }
+}
+
+/// Builds the result column of specified type, results not matching the type are reported as errors.
+fn result_column(results: &[Value], result_type: &DataType, errors: &mut [Option<String>]) -> Result<ArrayRef> {
+  let mut mismatch = |row: usize, value: &Value| {
+    errors[row].get_or_insert_with(|| format!("result {value} does not match column type {result_type}"));
+  };
+  Ok(match result_type {
+    DataType::Boolean => Arc::new(BooleanArray::from(
+      results
+        .iter()
+        .enumerate()
+        .map(|(row, value)| match value {
+          Value::Boolean(value) => Some(*value),
+          Value::Null(_) => None,
+          other => {
+            mismatch(row, other);
+            None
+          }
+        })
+        .collect::<Vec<Option<bool>>>(),
+    )),
+    DataType::Int64 => Arc::new(Int64Array::from(
+      results
+        .iter()
+        .enumerate()
+        .map(|(row, value)| match value {
+          Value::Number(number) if number.is_integer() => i64::try_from(number).ok().or_else(|| {
+            mismatch(row, value);
+            None
+          }),
+          Value::Null(_) => None,
+          other => {
+            mismatch(row, other);
+            None
+          }
+        })
+        .collect::<Vec<Option<i64>>>(),
+    )),
+    DataType::Float64 => Arc::new(Float64Array::from(
+      results
+        .iter()
+        .enumerate()
+        .map(|(row, value)| match value {
+          Value::Number(number) => number.to_string().parse::<f64>().ok(),
+          Value::Null(_) => None,
+          other => {
+            mismatch(row, other);
+            None
+          }
+        })
+        .collect::<Vec<Option<f64>>>(),
+    )),
+    DataType::Utf8 => Arc::new(StringArray::from(
+      results
+        .iter()
+        .map(|value| match value {
+          Value::String(text) => Some(text.to_string()),
+          Value::Null(_) => None,
+          other => Some(other.jsonify()),
+        })
+        .collect::<Vec<Option<String>>>(),
+    )),
+    other => return Err(err_unsupported_result_type(&other.to_string())),
+  })
+}
@@ -0,0 +1,44 @@
+//! # Quick evaluation of decision tables
+//!
+//! Evaluates a decision table defined in Unicode text format, the same format
+//! as accepted by the recognizer, directly with input data, without wrapping
+//! the decision table in a DMN model. Useful for unit-testing decision tables
+//! maintained as text files.
+
+use dmntk_common::Result;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::FeelScope;
+
+/// Evaluates the decision table defined in Unicode text format with specified input data.
+///
+/// Errors are reported when the decision table can not be recognized or its evaluator
+/// can not be built, evaluation failures are reported as `null` values with reasons.
+///
+/// # Example
+///
+/// ```
+/// use dmntk_evaluator::{evaluate_context, evaluate_decision_table};
+/// use dmntk_feel::FeelScope;
+///
+/// let decision_table = r#"
+///  ┌───┬────────────┬───────╥──────┐
+///  │ U │  Customer  │ Order ║      │
+///  ╞═══╪════════════╪═══════╬══════╡
+///  │ 1 │ "Business" │  <10  ║ 0.10 │
+///  ├───┼────────────┼───────╫──────┤
+///  │ 2 │ "Business" │ >=10  ║ 0.15 │
+///  ├───┼────────────┼───────╫──────┤
+///  │ 3 │ "Private"  │   -   ║ 0.05 │
+///  └───┴────────────┴───────╨──────┘
+/// "#;
+/// let input_data = evaluate_context(&FeelScope::default(), r#"{Customer: "Business", Order: 12}"#).unwrap();
+/// let result = evaluate_decision_table(decision_table, &input_data).unwrap();
+/// assert_eq!("0.15", result.to_string());
+/// ```
+pub fn evaluate_decision_table(text: &str, input_data: &FeelContext) -> Result<Value> {
+  let decision_table = dmntk_recognizer::recognize_decision_table(text, false)?;
+  let scope: FeelScope = input_data.clone().into();
+  let evaluator = dmntk_model_evaluator::build_decision_table_evaluator(&scope, &decision_table)?;
+  Ok(evaluator(&scope))
+}
@@ -0,0 +1,24 @@
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
+
+/// Errors related to columnar batch evaluation.
+#[derive(ToErrorMessage)]
+struct ColumnarEvaluatorError(Message);
+
+pub fn err_unsupported_column_type(column_name: &str, data_type: &str) -> DmntkError {
+  ColumnarEvaluatorError(message!("unsupported type {} of column '{}'", data_type, column_name)).into()
+}
+
+pub fn err_unsupported_result_type(data_type: &str) -> DmntkError {
+  ColumnarEvaluatorError(message!("unsupported type {} of result column", data_type)).into()
+}
+
+pub fn err_building_record_batch_failed(reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  ColumnarEvaluatorError(message!("building record batch failed with reason: {msg}", reason)).into()
+}
+
+#[cfg(feature = "parquet")]
+pub fn err_parquet_failed(reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  ColumnarEvaluatorError(message!("processing Parquet file failed with reason: {msg}", reason)).into()
+}
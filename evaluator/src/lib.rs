@@ -1,5 +1,32 @@
+#[cfg(feature = "arrow")]
+#[macro_use]
+extern crate dmntk_macros;
+
+#[cfg(feature = "arrow")]
+mod columnar;
+mod decision_tables;
+#[cfg(feature = "arrow")]
+mod errors;
 mod test_files;
+mod verification;
+
+#[cfg(test)]
+mod tests;
 
-pub use dmntk_feel_evaluator::{evaluate, evaluate_context, evaluate_equals, evaluate_max, evaluate_min, evaluate_sum};
-pub use dmntk_model_evaluator::{build_decision_table_evaluator, ModelEvaluator};
+pub use decision_tables::evaluate_decision_table;
+pub use dmntk_feel::{ConformanceLevel, EvaluationMode, ScopeBuilder, ScopeLayer};
+pub use dmntk_feel_evaluator::{
+  evaluate, evaluate_context, evaluate_equals, evaluate_max, evaluate_min, evaluate_sum, evaluate_with_budget, evaluate_with_environment, Budget, EnvironmentResolver,
+  OsEnvironment,
+};
+pub use dmntk_model_evaluator::{
+  build_bundle, build_decision_table_evaluator, load_bundle, BuildStatistics, EvaluationListener, EvaluationProfile, EvaluationProfiler, FiredRule, ModelEvaluator, ProfileEntry,
+  SelfTestResult,
+};
 pub use test_files::evaluate_test_cases;
+pub use verification::{verify_model, Finding, Scenario, Severity, VerificationOptions, VerificationReport, VerificationStep};
+
+#[cfg(feature = "parquet")]
+pub use columnar::evaluate_parquet_file;
+#[cfg(feature = "arrow")]
+pub use columnar::evaluate_record_batch;
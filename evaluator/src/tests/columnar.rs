@@ -0,0 +1,94 @@
+use crate::ModelEvaluator;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int64Type};
+use arrow_array::{Array, ArrayRef, BinaryArray, Float64Array, Int64Array, RecordBatch, StringArray};
+use arrow_schema::DataType;
+use std::sync::Arc;
+
+fn model_evaluator(model: &str) -> (Arc<ModelEvaluator>, String) {
+  let definitions = dmntk_model::parse(model).unwrap();
+  let namespace = definitions.namespace().to_string();
+  (ModelEvaluator::new(&[definitions]).unwrap(), namespace)
+}
+
+fn record_batch(name: &str, array: ArrayRef) -> RecordBatch {
+  RecordBatch::try_from_iter([(name, array)]).unwrap()
+}
+
+#[test]
+fn _0001() {
+  let (model_evaluator, namespace) = model_evaluator(dmntk_examples::DMN_2_0002);
+  let batch = record_batch("Monthly Salary", Arc::new(Int64Array::from(vec![Some(1000), None, Some(2500)])));
+  let evaluated = crate::evaluate_record_batch(&model_evaluator, &namespace, "Yearly Salary", &batch, None).unwrap();
+  assert_eq!(3, evaluated.num_columns());
+  let schema = evaluated.schema();
+  assert_eq!(&DataType::Int64, schema.field_with_name("Yearly Salary").unwrap().data_type());
+  let results = evaluated.column(1).as_primitive::<Int64Type>();
+  assert_eq!(vec![Some(12000), None, Some(30000)], results.iter().collect::<Vec<Option<i64>>>());
+  let errors = evaluated.column_by_name("Yearly Salary error").unwrap().as_string::<i32>();
+  assert!(errors.is_valid(1));
+  assert!(errors.is_null(0) && errors.is_null(2));
+}
+
+#[test]
+fn _0002() {
+  let (model_evaluator, namespace) = model_evaluator(dmntk_examples::DMN_2_0002);
+  let batch = record_batch("Monthly Salary", Arc::new(Float64Array::from(vec![1000.5, 0.1])));
+  let evaluated = crate::evaluate_record_batch(&model_evaluator, &namespace, "Yearly Salary", &batch, None).unwrap();
+  let results = evaluated.column(1).as_primitive::<Float64Type>();
+  assert_eq!(vec![Some(12006.0), Some(1.2)], results.iter().collect::<Vec<Option<f64>>>());
+}
+
+#[test]
+fn _0003() {
+  let (model_evaluator, namespace) = model_evaluator(dmntk_examples::DMN_2_0001);
+  let batch = record_batch("Full Name", Arc::new(StringArray::from(vec!["John Doe", "Jane Doe"])));
+  let evaluated = crate::evaluate_record_batch(&model_evaluator, &namespace, "Greeting Message", &batch, None).unwrap();
+  let results = evaluated.column(1).as_string::<i32>();
+  assert_eq!(vec![Some("Hello John Doe"), Some("Hello Jane Doe")], results.iter().collect::<Vec<Option<&str>>>());
+}
+
+#[test]
+fn _0004() {
+  let (model_evaluator, namespace) = model_evaluator(dmntk_examples::DMN_2_0002);
+  let batch = record_batch("Monthly Salary", Arc::new(Int64Array::from(vec![1000])));
+  let evaluated = crate::evaluate_record_batch(&model_evaluator, &namespace, "Yearly Salary", &batch, Some(&DataType::Boolean)).unwrap();
+  assert!(evaluated.column(1).is_null(0));
+  let errors = evaluated.column(2).as_string::<i32>();
+  assert_eq!("result 12000 does not match column type Boolean", errors.value(0));
+}
+
+#[test]
+fn _0005() {
+  let (model_evaluator, namespace) = model_evaluator(dmntk_examples::DMN_2_0002);
+  let batch = record_batch("Monthly Salary", Arc::new(BinaryArray::from_vec(vec![b"1000"])));
+  assert_eq!(
+    "<ColumnarEvaluatorError> unsupported type Binary of column 'Monthly Salary'",
+    crate::evaluate_record_batch(&model_evaluator, &namespace, "Yearly Salary", &batch, None)
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn _0006() {
+  use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+  use parquet::arrow::ArrowWriter;
+  use std::fs::File;
+  let (model_evaluator, namespace) = model_evaluator(dmntk_examples::DMN_2_0002);
+  let dir = std::env::temp_dir().join(format!("dmntk-columnar-{}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+  let input = dir.join("input.parquet");
+  let output = dir.join("output.parquet");
+  let batch = record_batch("Monthly Salary", Arc::new(Int64Array::from(vec![1000, 2000])));
+  let mut writer = ArrowWriter::try_new(File::create(&input).unwrap(), batch.schema(), None).unwrap();
+  writer.write(&batch).unwrap();
+  writer.close().unwrap();
+  assert_eq!(2, crate::evaluate_parquet_file(&model_evaluator, &namespace, "Yearly Salary", &input, &output).unwrap());
+  let mut reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&output).unwrap()).unwrap().build().unwrap();
+  let evaluated = reader.next().unwrap().unwrap();
+  let results = evaluated.column_by_name("Yearly Salary").unwrap().as_primitive::<Int64Type>();
+  assert_eq!(vec![Some(12000), Some(24000)], results.iter().collect::<Vec<Option<i64>>>());
+  std::fs::remove_dir_all(&dir).unwrap();
+}
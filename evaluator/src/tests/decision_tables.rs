@@ -0,0 +1,32 @@
+use crate::{evaluate_context, evaluate_decision_table};
+use dmntk_examples::decision_tables::H_000210;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::FeelScope;
+
+/// Returns the context evaluated from `FEEL` text.
+fn context(text: &str) -> FeelContext {
+  evaluate_context(&FeelScope::default(), text).unwrap()
+}
+
+#[test]
+fn _0001() {
+  let result = evaluate_decision_table(H_000210, &context(r#"{Customer: "Business", Order: -3.23}"#)).unwrap();
+  assert_eq!("0.10", result.to_string());
+}
+
+#[test]
+fn _0002() {
+  let result = evaluate_decision_table(H_000210, &context(r#"{Customer: "Private", Order: 101}"#)).unwrap();
+  assert_eq!("0.05", result.to_string());
+}
+
+#[test]
+fn _0003() {
+  let result = evaluate_decision_table(H_000210, &context(r#"{Customer: "Government", Order: 10}"#)).unwrap();
+  assert!(result.is_null());
+}
+
+#[test]
+fn _0004() {
+  assert!(evaluate_decision_table("not a decision table", &FeelContext::default()).is_err());
+}
@@ -0,0 +1,48 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use dmntk_common::{DmntkError, Language};
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_unsupported_column_type("Amount", "Binary"),
+    "<ColumnarEvaluatorError> nieobsługiwany typ Binary kolumny 'Amount'",
+    "<ColumnarEvaluatorError> nicht unterstützter Typ Binary der Spalte 'Amount'",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_unsupported_result_type("Binary"),
+    "<ColumnarEvaluatorError> nieobsługiwany typ Binary kolumny wyników",
+    "<ColumnarEvaluatorError> nicht unterstützter Typ Binary der Ergebnisspalte",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_building_record_batch_failed("invalid schema"),
+    "<ColumnarEvaluatorError> budowanie paczki rekordów nie powiodło się, przyczyna: invalid schema",
+    "<ColumnarEvaluatorError> Erstellen des Datensatzstapels fehlgeschlagen, Ursache: invalid schema",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_parquet_failed("invalid footer"),
+    "<ColumnarEvaluatorError> przetwarzanie pliku Parquet nie powiodło się, przyczyna: invalid footer",
+    "<ColumnarEvaluatorError> Verarbeitung der Parquet-Datei fehlgeschlagen, Ursache: invalid footer",
+  );
+}
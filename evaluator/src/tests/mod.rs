@@ -0,0 +1,43 @@
+use std::process::Command;
+
+#[cfg(feature = "arrow")]
+mod columnar;
+mod decision_tables;
+#[cfg(feature = "arrow")]
+mod messages;
+mod verification;
+
+/// Crates that must not be required when the evaluator is built without default features.
+const EXCLUDED_CRATES: [&str; 8] = ["actix-web", "clap", "dmntk-examples", "dmntk-server", "hyper", "reqwest", "rustls", "tokio"];
+
+/// Returns the names of crates the evaluator depends on, when built with specified arguments.
+fn dependencies(args: &[&str]) -> Vec<String> {
+  let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+  let output = Command::new(cargo)
+    .current_dir(env!("CARGO_MANIFEST_DIR"))
+    .args(["tree", "--offline", "-p", "dmntk-evaluator", "-e", "normal", "--prefix", "none"])
+    .args(args)
+    .output()
+    .expect("running cargo tree failed");
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+  String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .filter_map(|line| line.split_whitespace().next().map(|name| name.to_string()))
+    .collect()
+}
+
+#[test]
+fn test_minimal_build() {
+  let dependencies = dependencies(&["--no-default-features"]);
+  assert!(dependencies.contains(&"dmntk-model-evaluator".to_string()));
+  for excluded in EXCLUDED_CRATES {
+    assert!(!dependencies.contains(&excluded.to_string()), "unexpected dependency: {excluded}");
+  }
+}
+
+#[test]
+fn test_default_build() {
+  let dependencies = dependencies(&[]);
+  assert!(dependencies.contains(&"reqwest".to_string()));
+  assert!(!dependencies.contains(&"actix-web".to_string()));
+}
@@ -0,0 +1,67 @@
+use crate::{verify_model, Scenario, Severity, VerificationOptions, VerificationStep};
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_null, FeelScope};
+
+#[test]
+fn _0001() {
+  let report = verify_model(dmntk_examples::DMN_2_0001, &VerificationOptions::default());
+  assert!(report.passed());
+  assert_eq!(0, report.count(Severity::Error));
+  assert!(report.skipped_steps().is_empty());
+  assert_eq!(Some(Severity::Info), report.max_severity());
+  assert_eq!("[info] tests: 0 of 0 self-test(s) passed, 0 of 0 scenario(s) passed", report.findings()[0].to_string());
+  assert!(report.to_string().ends_with("verification passed: 0 error(s), 0 warning(s)"));
+}
+
+#[test]
+fn _0002() {
+  let report = verify_model("<definitions", &VerificationOptions::default());
+  assert!(!report.passed());
+  assert_eq!(VerificationStep::Schema, report.findings()[0].step());
+  assert_eq!(VerificationStep::Validation, report.findings()[1].step());
+  assert_eq!(&[VerificationStep::Lint, VerificationStep::TypeCheck, VerificationStep::Tests], report.skipped_steps());
+  assert!(report.to_string().contains("[skipped] type check"));
+}
+
+#[test]
+fn _0003() {
+  let xml = dmntk_examples::DMN_2_0001.replacen(r#"typeRef="string""#, r#"typeRef="tFullName""#, 1);
+  let report = verify_model(&xml, &VerificationOptions::default().with_schema(false));
+  assert!(!report.passed());
+  let finding = report.findings().iter().find(|finding| finding.step() == VerificationStep::TypeCheck).unwrap();
+  assert_eq!(Severity::Error, finding.severity());
+  assert!(finding.element_id().is_some());
+  assert!(finding.message().starts_with("unknown type `tFullName` of "), "{}", finding.message());
+}
+
+#[test]
+fn _0004() {
+  let scenarios = vec![
+    Scenario::new(
+      "Greeting Message",
+      crate::evaluate_context(&FeelScope::default(), r#"{Full Name: "John Doe"}"#).unwrap(),
+      Value::String("Hello John Doe".into()),
+    ),
+    Scenario::new(
+      "Greeting Message",
+      crate::evaluate_context(&FeelScope::default(), r#"{Full Name: "Jane"}"#).unwrap(),
+      value_null!(),
+    ),
+  ];
+  let report = verify_model(dmntk_examples::DMN_2_0001, &VerificationOptions::default().with_scenarios(scenarios));
+  assert!(!report.passed());
+  assert_eq!(1, report.count(Severity::Error));
+  assert_eq!(
+    r#"[error] tests: scenario 2 of `Greeting Message` failed, expected value is null, actual value is "Hello Jane""#,
+    report.findings()[0].to_string()
+  );
+  assert_eq!("0 of 0 self-test(s) passed, 1 of 2 scenario(s) passed", report.findings()[1].message());
+}
+
+#[test]
+fn _0005() {
+  let scenarios = Scenario::from_test_file("Greeting Message", r#"% { Full Name: "John Doe" }, "Hello John Doe""#).unwrap();
+  let report = verify_model(dmntk_examples::DMN_2_0001, &VerificationOptions::default().with_scenarios(scenarios));
+  assert!(report.passed());
+  assert_eq!("[info] tests: 0 of 0 self-test(s) passed, 1 of 1 scenario(s) passed", report.findings()[0].to_string());
+}
@@ -0,0 +1,425 @@
+//! # Verification of decision models before deployment
+//!
+//! Runs all checks of a decision model in a single pass and aggregates their findings
+//! into one report, to be used as a gate in deployment pipelines. Verification steps:
+//!
+//! - validation against DMN schema,
+//! - parsing and semantic validation, including syntax of all `FEEL` expressions,
+//! - linting,
+//! - type checking: resolving type references and building the model evaluator,
+//! - running self-tests embedded in the model and scenario tests provided in options.
+//!
+//! Steps following a failed parsing or type checking are skipped, because they require
+//! a valid model or a built model evaluator. The model passes verification when no errors are reported.
+
+use crate::test_files::evaluate_test_cases;
+use dmntk_common::Result;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{is_built_in_type_name, ConformanceLevel};
+use dmntk_model::{Definitions, DmnElement, DrgElement, Expression, ItemDefinition, NamedElement, RequiredVariable, SyntaxChecker};
+use dmntk_model_evaluator::ModelEvaluator;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// Names of simple types used in type references, in addition to names of `FEEL` built-in types.
+const SIMPLE_TYPE_NAMES: [&str; 3] = ["dateTime", "dayTimeDuration", "yearMonthDuration"];
+
+/// Severity of the finding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+  /// Information not requiring any action.
+  Info,
+  /// Model can be deployed, but should be improved.
+  Warning,
+  /// Model must not be deployed.
+  Error,
+}
+
+impl fmt::Display for Severity {
+  /// Formats the name of the severity.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Severity::Info => write!(f, "info"),
+      Severity::Warning => write!(f, "warning"),
+      Severity::Error => write!(f, "error"),
+    }
+  }
+}
+
+/// Step of the verification.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VerificationStep {
+  /// Validation against DMN schema.
+  Schema,
+  /// Parsing and semantic validation.
+  Validation,
+  /// Linting.
+  Lint,
+  /// Type checking.
+  TypeCheck,
+  /// Running self-tests and scenario tests.
+  Tests,
+}
+
+impl fmt::Display for VerificationStep {
+  /// Formats the name of the step.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      VerificationStep::Schema => write!(f, "schema"),
+      VerificationStep::Validation => write!(f, "validation"),
+      VerificationStep::Lint => write!(f, "lint"),
+      VerificationStep::TypeCheck => write!(f, "type check"),
+      VerificationStep::Tests => write!(f, "tests"),
+    }
+  }
+}
+
+/// Finding reported by a verification step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+  /// Step that reported the finding.
+  step: VerificationStep,
+  /// Severity of the finding.
+  severity: Severity,
+  /// Identifier of the element the finding refers to, when known.
+  element_id: Option<String>,
+  /// Message describing the finding.
+  message: String,
+}
+
+impl Finding {
+  /// Returns the step that reported the finding.
+  pub fn step(&self) -> VerificationStep {
+    self.step
+  }
+
+  /// Returns the severity of the finding.
+  pub fn severity(&self) -> Severity {
+    self.severity
+  }
+
+  /// Returns the identifier of the element the finding refers to, when known.
+  pub fn element_id(&self) -> Option<&str> {
+    self.element_id.as_deref()
+  }
+
+  /// Returns the message describing the finding.
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+}
+
+impl fmt::Display for Finding {
+  /// Formats the finding with its severity and step.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "[{}] {}: {}", self.severity, self.step, self.message)
+  }
+}
+
+/// Scenario test: the invocable evaluated with input data is expected to return specified value.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+  /// Name of the evaluated invocable.
+  invocable_name: String,
+  /// Input data of the evaluation.
+  input_data: FeelContext,
+  /// Expected result of the evaluation.
+  expected: Value,
+}
+
+impl Scenario {
+  /// Creates a scenario test of the invocable with specified name.
+  pub fn new(invocable_name: &str, input_data: FeelContext, expected: Value) -> Self {
+    Self {
+      invocable_name: invocable_name.to_string(),
+      input_data,
+      expected,
+    }
+  }
+
+  /// Creates scenario tests of the invocable with specified name from test cases
+  /// loaded from test file, see [evaluate_test_cases](crate::evaluate_test_cases).
+  pub fn from_test_file(invocable_name: &str, test_file_content: &str) -> Result<Vec<Self>> {
+    Ok(
+      evaluate_test_cases(test_file_content)?
+        .into_iter()
+        .map(|(input_data, expected)| Self::new(invocable_name, input_data, expected))
+        .collect(),
+    )
+  }
+}
+
+/// Options of the verification.
+#[derive(Debug, Clone)]
+pub struct VerificationOptions {
+  /// Flag indicating if the model is validated against DMN schema.
+  schema: bool,
+  /// Flag indicating if the model is linted.
+  lint: bool,
+  /// Flag indicating if lint warnings are reported as errors.
+  warnings_as_errors: bool,
+  /// Conformance level the model is built at.
+  conformance_level: ConformanceLevel,
+  /// Scenario tests run after building the model evaluator.
+  scenarios: Vec<Scenario>,
+}
+
+impl Default for VerificationOptions {
+  /// Creates options running all verification steps, without scenario tests.
+  fn default() -> Self {
+    Self {
+      schema: true,
+      lint: true,
+      warnings_as_errors: false,
+      conformance_level: ConformanceLevel::default(),
+      scenarios: vec![],
+    }
+  }
+}
+
+impl VerificationOptions {
+  /// Sets the flag indicating if the model is validated against DMN schema.
+  pub fn with_schema(mut self, schema: bool) -> Self {
+    self.schema = schema;
+    self
+  }
+
+  /// Sets the flag indicating if the model is linted.
+  pub fn with_lint(mut self, lint: bool) -> Self {
+    self.lint = lint;
+    self
+  }
+
+  /// Sets the flag indicating if lint warnings are reported as errors.
+  pub fn with_warnings_as_errors(mut self, warnings_as_errors: bool) -> Self {
+    self.warnings_as_errors = warnings_as_errors;
+    self
+  }
+
+  /// Sets the conformance level the model is built at.
+  pub fn with_conformance_level(mut self, conformance_level: ConformanceLevel) -> Self {
+    self.conformance_level = conformance_level;
+    self
+  }
+
+  /// Adds scenario tests.
+  pub fn with_scenarios(mut self, scenarios: Vec<Scenario>) -> Self {
+    self.scenarios.extend(scenarios);
+    self
+  }
+}
+
+/// Report of the verification.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VerificationReport {
+  /// Findings in the order of reporting.
+  findings: Vec<Finding>,
+  /// Steps skipped because of failures of preceding steps.
+  skipped_steps: Vec<VerificationStep>,
+}
+
+impl VerificationReport {
+  /// Returns findings in the order of reporting.
+  pub fn findings(&self) -> &[Finding] {
+    &self.findings
+  }
+
+  /// Returns steps skipped because of failures of preceding steps.
+  pub fn skipped_steps(&self) -> &[VerificationStep] {
+    &self.skipped_steps
+  }
+
+  /// Returns the number of findings with specified severity.
+  pub fn count(&self, severity: Severity) -> usize {
+    self.findings.iter().filter(|finding| finding.severity == severity).count()
+  }
+
+  /// Returns the highest severity of reported findings, if any.
+  pub fn max_severity(&self) -> Option<Severity> {
+    self.findings.iter().map(|finding| finding.severity).max()
+  }
+
+  /// Returns `true` when no errors were reported.
+  pub fn passed(&self) -> bool {
+    self.count(Severity::Error) == 0
+  }
+
+  /// Adds a finding.
+  fn add(&mut self, step: VerificationStep, severity: Severity, element_id: Option<&str>, message: String) {
+    self.findings.push(Finding {
+      step,
+      severity,
+      element_id: element_id.map(|id| id.to_string()),
+      message,
+    });
+  }
+
+  /// Marks specified steps as skipped.
+  fn skip(&mut self, steps: &[VerificationStep]) {
+    self.skipped_steps.extend_from_slice(steps);
+  }
+}
+
+impl fmt::Display for VerificationReport {
+  /// Formats findings, one per line, followed by the summary.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for finding in &self.findings {
+      writeln!(f, "{finding}")?;
+    }
+    for step in &self.skipped_steps {
+      writeln!(f, "[skipped] {step}")?;
+    }
+    write!(
+      f,
+      "verification {}: {} error(s), {} warning(s)",
+      if self.passed() { "passed" } else { "failed" },
+      self.count(Severity::Error),
+      self.count(Severity::Warning)
+    )
+  }
+}
+
+/// Verifies the decision model loaded from XML document, runs all steps enabled in options.
+pub fn verify_model(xml: &str, options: &VerificationOptions) -> VerificationReport {
+  let mut report = VerificationReport::default();
+  // validation against schema
+  if options.schema {
+    for reason in dmntk_model::schema_violations(xml) {
+      report.add(VerificationStep::Schema, Severity::Error, None, reason.to_string());
+    }
+  }
+  // parsing and semantic validation
+  let definitions = match dmntk_model::parse(xml) {
+    Ok(definitions) => definitions,
+    Err(reason) => {
+      report.add(VerificationStep::Validation, Severity::Error, None, reason.to_string());
+      report.skip(&[VerificationStep::Lint, VerificationStep::TypeCheck, VerificationStep::Tests]);
+      return report;
+    }
+  };
+  for diagnostic in SyntaxChecker::new().check(&definitions) {
+    report.add(VerificationStep::Validation, Severity::Error, Some(diagnostic.element_id()), diagnostic.to_string());
+  }
+  // linting
+  if options.lint {
+    let severity = if options.warnings_as_errors { Severity::Error } else { Severity::Warning };
+    for warning in dmntk_model::lint(&definitions) {
+      report.add(VerificationStep::Lint, severity, Some(warning.element_id()), warning.to_string());
+    }
+  }
+  // type checking
+  check_type_refs(&definitions, &mut report);
+  let namespace = definitions.namespace().to_string();
+  let model_evaluator = match ModelEvaluator::new_at_conformance_level(&[definitions], options.conformance_level) {
+    Ok(model_evaluator) => model_evaluator,
+    Err(reason) => {
+      report.add(VerificationStep::TypeCheck, Severity::Error, None, format!("building model evaluator failed: {reason}"));
+      report.skip(&[VerificationStep::Tests]);
+      return report;
+    }
+  };
+  // self-tests and scenario tests
+  run_tests(&model_evaluator, &namespace, &options.scenarios, &mut report);
+  report
+}
+
+/// Checks if type references of variables and item definitions refer to built-in types or item definitions defined in the model.
+///
+/// Type references with namespace prefixes of imported models are not checked.
+fn check_type_refs(definitions: &Definitions, report: &mut VerificationReport) {
+  let type_names = definitions
+    .item_definitions()
+    .iter()
+    .map(|item_definition| item_definition.name())
+    .collect::<HashSet<&str>>();
+  let mut check = |type_ref: &str, element_id: &str, location: String| {
+    let type_ref = type_ref.trim();
+    let is_known = is_built_in_type_name(type_ref) || SIMPLE_TYPE_NAMES.contains(&type_ref) || type_names.contains(type_ref) || type_ref.contains('.');
+    if !is_known {
+      report.add(
+        VerificationStep::TypeCheck,
+        Severity::Error,
+        Some(element_id),
+        format!("unknown type `{type_ref}` of {location}"),
+      );
+    }
+  };
+  for drg_element in definitions.drg_elements() {
+    let location = format!("{} `{}`", drg_element.kind(), drg_element.name());
+    match drg_element {
+      DrgElement::Decision(decision) => check(decision.variable().type_ref(), drg_element.id(), location),
+      DrgElement::InputData(input_data) => check(input_data.variable().type_ref(), drg_element.id(), location),
+      DrgElement::BusinessKnowledgeModel(bkm) => {
+        check(bkm.variable().type_ref(), drg_element.id(), location.clone());
+        if let Some(function_definition) = bkm.encapsulated_logic() {
+          for parameter in function_definition.formal_parameters() {
+            check(parameter.type_ref(), drg_element.id(), format!("parameter `{}` of {location}", parameter.name()));
+          }
+        }
+      }
+      DrgElement::DecisionService(decision_service) => check(decision_service.variable().type_ref(), drg_element.id(), location),
+      DrgElement::KnowledgeSource(_) => {}
+    }
+  }
+  for item_definition in definitions.item_definitions() {
+    check_item_definition_type_refs(item_definition, item_definition.name(), &mut check);
+  }
+}
+
+/// Checks type references of the item definition and all its components.
+fn check_item_definition_type_refs(item_definition: &ItemDefinition, path: &str, check: &mut impl FnMut(&str, &str, String)) {
+  if let Some(type_ref) = item_definition.type_ref() {
+    check(type_ref, item_definition.id(), format!("item definition `{path}`"));
+  }
+  for item_component in item_definition.item_components() {
+    check_item_definition_type_refs(item_component, &format!("{path}.{}", item_component.name()), check);
+  }
+}
+
+/// Runs self-tests embedded in the model and scenario tests.
+fn run_tests(model_evaluator: &Arc<ModelEvaluator>, namespace: &str, scenarios: &[Scenario], report: &mut VerificationReport) {
+  let self_test_results = model_evaluator.run_self_tests();
+  for self_test_result in &self_test_results {
+    if !self_test_result.passed() {
+      report.add(
+        VerificationStep::Tests,
+        Severity::Error,
+        None,
+        format!("self-test `{}` failed, actual value is {}", self_test_result.name(), self_test_result.value()),
+      );
+    }
+  }
+  let mut passed = 0;
+  for (index, scenario) in scenarios.iter().enumerate() {
+    let actual = model_evaluator.evaluate_invocable(namespace, &scenario.invocable_name, &scenario.input_data);
+    if dmntk_feel_evaluator::evaluate_equals(&actual, &scenario.expected) {
+      passed += 1;
+    } else {
+      report.add(
+        VerificationStep::Tests,
+        Severity::Error,
+        None,
+        format!(
+          "scenario {} of `{}` failed, expected value is {}, actual value is {}",
+          index + 1,
+          scenario.invocable_name,
+          scenario.expected,
+          actual
+        ),
+      );
+    }
+  }
+  let self_tests_passed = self_test_results.iter().filter(|self_test_result| self_test_result.passed()).count();
+  report.add(
+    VerificationStep::Tests,
+    Severity::Info,
+    None,
+    format!(
+      "{self_tests_passed} of {} self-test(s) passed, {passed} of {} scenario(s) passed",
+      self_test_results.len(),
+      scenarios.len()
+    ),
+  );
+}
@@ -0,0 +1,60 @@
+#![feature(test)]
+
+//! Compare the results with and without `closures` feature:
+//!
+//! ```text
+//! cargo +nightly bench --bench heavy_expression
+//! cargo +nightly bench --bench heavy_expression --features closures
+//! ```
+
+extern crate test;
+
+use dmntk_feel::values::Value;
+use dmntk_feel::{scope, value_number, FeelNumber, FeelScope};
+use dmntk_feel_evaluator::{prepare, BuildContext};
+use test::Bencher;
+
+#[bench]
+fn feel_evaluator_heavy_expression_0001(b: &mut Bencher) {
+  let scope = scope!();
+  scope.set_value(&"a".into(), value_number!(3));
+  scope.set_value(&"b".into(), value_number!(4));
+  scope.set_value(&"c".into(), value_number!(5));
+  let input = r#"(a * a + b * b - c * c) + (a + b + c) * 2 - (a * b * c) / 10 + (a - b) * (b - c) * (c - a)"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(20), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_heavy_expression_0002(b: &mut Bencher) {
+  let scope = scope!();
+  scope.set_value(&"score".into(), value_number!(72));
+  scope.set_value(&"income".into(), value_number!(48000));
+  let input = r#"if score > 90 and income > 50000 then "A" else if score > 70 or income > 40000 then (if score * 2 > 140 then "B" else "C") else "D""#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(Value::String("B".into()), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_heavy_expression_0003(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"(1 + 2) * (3 + 4) - 10 / 4 + 2 ** 8 - (7 * 6 - 5)"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(2375, 1), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_heavy_expression_0004(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"sum(for i in 1..100 return if i * 3 > 150 and i - 7 < 90 or i = 1 then i * 2 - 1 else -i)"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(5049), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
@@ -0,0 +1,55 @@
+#![feature(test)]
+
+//! Evaluation of expressions building many intermediate lists and contexts,
+//! used to measure the cost of allocating and dropping intermediate values:
+//!
+//! ```text
+//! cargo +nightly bench --bench intermediate_values
+//! ```
+
+extern crate test;
+
+use dmntk_feel::values::Value;
+use dmntk_feel::{scope, value_number, FeelScope};
+use dmntk_feel_evaluator::{prepare, BuildContext};
+use test::Bencher;
+
+#[bench]
+fn feel_evaluator_intermediate_values_0001(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"sum(for i in 1..100 return i * 2)"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(10100), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_intermediate_values_0002(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"count((for i in 1..100 return {id: i, even: even(i), square: i * i})[even = true and square > 100])"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(45), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_intermediate_values_0003(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"sum(flatten(for i in 1..20 return for j in 1..20 return [i, j])) + count(distinct values(for i in 1..100 return modulo(i, 7)))"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(8407), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_intermediate_values_0004(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"sum((for x in 1..50 return {value: x, doubled: x * 2})[value > 45].doubled)"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(480), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
@@ -0,0 +1,37 @@
+#![feature(test)]
+
+//! Evaluation of expressions producing many string values, used to measure the cost
+//! of cloning string values. The impact on complete models is measured with compatibility
+//! benchmarks of models using string values:
+//!
+//! ```text
+//! cargo +nightly bench --bench strings
+//! cargo +nightly bench -p dmntk-model-evaluator --bench main -- level_2 dmn_3_0002
+//! ```
+
+extern crate test;
+
+use dmntk_feel::values::Value;
+use dmntk_feel::{scope, value_number, FeelScope};
+use dmntk_feel_evaluator::{prepare, BuildContext};
+use test::Bencher;
+
+#[bench]
+fn feel_evaluator_strings_0001(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"for i in 1..1000 return if modulo(i, 2) = 0 then "High" else "Low""#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert!(matches!(evaluator(&scope), Value::List(items) if items.len() == 1000));
+  b.iter(|| evaluator(&scope));
+}
+
+#[bench]
+fn feel_evaluator_strings_0002(b: &mut Bencher) {
+  let scope = scope!();
+  let input = r#"count(for i in 1..1000 return "classification label")"#;
+  let node = dmntk_feel_parser::parse_expression(&scope, input, false).unwrap();
+  let evaluator = prepare(&BuildContext::default(), &node).unwrap();
+  assert_eq!(value_number!(1000), evaluator(&scope));
+  b.iter(|| evaluator(&scope));
+}
@@ -430,7 +430,7 @@ pub fn concatenate(values: &[Value]) -> Value {
 pub fn contains(input_string_value: &Value, match_string_value: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(match_string) = match_string_value {
-      Value::Boolean(input_string.contains(match_string))
+      Value::Boolean(input_string.contains(match_string.as_str()))
     } else {
       invalid_argument_type!("contains", "string", match_string_value.type_of())
     }
@@ -707,7 +707,7 @@ pub fn during(value1: &Value, value2: &Value) -> Value {
 pub fn ends_with(input_string_value: &Value, match_string_value: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(match_string) = match_string_value {
-      Value::Boolean(input_string.ends_with(match_string))
+      Value::Boolean(input_string.ends_with(match_string.as_str()))
     } else {
       invalid_argument_type!("ends with", "string", match_string_value.type_of())
     }
@@ -716,6 +716,15 @@ pub fn ends_with(input_string_value: &Value, match_string_value: &Value) -> Valu
   }
 }
 
+/// Returns the value of the environment variable provided by registered resolver.
+pub fn env(name_value: &Value) -> Value {
+  if let Value::String(name) = name_value {
+    crate::environment::resolve(name.as_str())
+  } else {
+    invalid_argument_type!("env", "string", name_value.type_of())
+  }
+}
+
 /// Returns true if number is even, false if it is odd.
 pub fn even(number_value: &Value) -> Value {
   if let Value::Number(number) = number_value {
@@ -883,6 +892,17 @@ pub fn finished_by(value1: &Value, value2: &Value) -> Value {
   }
 }
 
+/// Returns the first day of the month of the date.
+#[cfg(feature = "calendar")]
+pub fn first_day_of_month(value: &Value) -> Value {
+  let opt_date = match value {
+    Value::Date(date) => date.first_day_of_month(),
+    Value::DateTime(date_time) => date_time.date().first_day_of_month(),
+    _ => return invalid_argument_type!("first day of month", "date, date and time", value.type_of()),
+  };
+  opt_date.map(Value::Date).unwrap_or_else(|| value_null!("[first day of month] no first day of month"))
+}
+
 /// Returns new list with flattened nested lists.
 pub fn flatten(value: &Value) -> Value {
   if let Value::List(_) = value {
@@ -923,7 +943,7 @@ pub fn get_entries(context: &Value) -> Value {
     let mut entries = vec![];
     ctx.get_entries().iter().for_each(|(name, value)| {
       let mut key_value_pair = FeelContext::default();
-      key_value_pair.set_entry(&name_key, Value::String(name.to_string()));
+      key_value_pair.set_entry(&name_key, Value::String(name.to_string().into()));
       key_value_pair.set_entry(&name_value, (**value).clone());
       entries.push(Value::Context(key_value_pair));
     });
@@ -936,7 +956,7 @@ pub fn get_entries(context: &Value) -> Value {
 pub fn get_value(context: &Value, key: &Value) -> Value {
   if let Value::Context(ctx) = context {
     if let Value::String(entry_key) = key {
-      let name = Name::from(entry_key.to_owned());
+      let name = Name::from(entry_key.to_string());
       if let Some(entry_value) = ctx.get_entry(&name) {
         entry_value.clone()
       } else {
@@ -1115,6 +1135,17 @@ pub fn is(value1: &Value, value2: &Value) -> Value {
   }
 }
 
+/// Returns the last day of the month of the date.
+#[cfg(feature = "calendar")]
+pub fn last_day_of_month(value: &Value) -> Value {
+  let opt_date = match value {
+    Value::Date(date) => date.last_day_of_month(),
+    Value::DateTime(date_time) => date_time.date().last_day_of_month(),
+    _ => return invalid_argument_type!("last day of month", "date, date and time", value.type_of()),
+  };
+  opt_date.map(Value::Date).unwrap_or_else(|| value_null!("[last day of month] no last day of month"))
+}
+
 /// Returns `true` when the list contain the specified element.
 pub fn list_contains(list: &Value, element: &Value) -> Value {
   if let Value::List(items) = list {
@@ -1144,17 +1175,9 @@ pub fn log(number: &Value) -> Value {
 /// Returns lower-cased string.
 pub fn lower_case(input_string_value: &Value) -> Value {
   match input_string_value {
-    Value::List(items) => {
-      if items.len() == 1 {
-        if let Value::String(input_string) = &items[0] {
-          return Value::String(input_string.to_lowercase());
-        }
-      }
-    }
-    Value::String(input_string) => return Value::String(input_string.to_lowercase()),
-    _ => {}
+    Value::String(input_string) => Value::String(input_string.to_lowercase().into()),
+    other => invalid_argument_type!("lower case", "string", other.type_of()),
   }
-  invalid_argument_type!("lower case", "string", input_string_value.type_of())
 }
 
 /// Returns `true` when the input matches the regexp pattern.
@@ -1423,8 +1446,8 @@ pub fn mode(values: &[Value]) -> Value {
     Ordering::Equal => x.1.partial_cmp(&y.1).unwrap_or(Ordering::Equal),
     other => other,
   });
-  // there is minimum one element in the list, so unwrap is ok
-  let max = mode.first().unwrap().0;
+  // there is minimum one element in the list, so the first frequency is always present
+  let max = mode.first().map_or(0, |(count, _)| *count);
   // return items with maximum frequency
   Value::List(mode.iter().filter_map(|(c, v)| if *c == max { Some(Value::Number(*v)) } else { None }).collect())
 }
@@ -1488,7 +1511,7 @@ pub fn number(from: &Value, grouping_separator: &Value, decimal_separator: &Valu
       // prepare grouping separator from Value::String ot VALUE_NULL
       let grouping_sep = match grouping_separator {
         Value::String(s) => match s.as_str() {
-          " " | "." | "," => Some((*s).clone()),
+          " " | "." | "," => Some(s.to_string()),
           _ => return value_null!("[core::number] grouping separator must be space, period, comma or null"),
         },
         Value::Null(_) => None,
@@ -1497,7 +1520,7 @@ pub fn number(from: &Value, grouping_separator: &Value, decimal_separator: &Valu
       // prepare decimal separator from Value::String ot VALUE_NULL
       let decimal_sep = match decimal_separator {
         Value::String(s) => match s.as_str() {
-          "." | "," => Some((*s).clone()),
+          "." | "," => Some(s.to_string()),
           _ => return value_null!("[core::number] decimal separator must be period, comma or null"),
         },
         Value::Null(_) => None,
@@ -1526,7 +1549,7 @@ pub fn number(from: &Value, grouping_separator: &Value, decimal_separator: &Valu
         }
       }
       // try to convert an input parameter without replacing
-      convert(value.clone())
+      convert(value.to_string())
     }
     _ => invalid_argument_type!("number", "string", from.type_of()),
   }
@@ -1740,6 +1763,19 @@ pub fn product(values: &[Value]) -> Value {
   Value::Number(list.iter().fold(FeelNumber::one(), |acc, n| acc * (*n)))
 }
 
+/// Returns the quarter of the year (1..4) of the date.
+#[cfg(feature = "calendar")]
+pub fn quarter_of_year(value: &Value) -> Value {
+  let opt_quarter = match value {
+    Value::Date(date) => date.quarter_of_year(),
+    Value::DateTime(date_time) => date_time.date().quarter_of_year(),
+    _ => return invalid_argument_type!("quarter of year", "date, date and time", value.type_of()),
+  };
+  opt_quarter
+    .map(|quarter| value_number!(quarter))
+    .unwrap_or_else(|| value_null!("[quarter of year] no quarter of year"))
+}
+
 /// ???
 pub fn remove(list: &Value, position_value: &Value) -> Value {
   if let Value::List(mut items) = list.clone() {
@@ -1804,17 +1840,17 @@ pub fn replace(input_string_value: &Value, pattern_string_value: &Value, replace
           if flags.is_empty() {
             if let Ok(re) = Regex::new(&patt) {
               let result = re.replace_all(input_string.as_str(), repl.as_str()).to_string();
-              return Value::String(result);
+              return Value::String(result.into());
             }
           } else if let Ok(re) = Regex::new(format!("(?{flags}){patt}").as_str()) {
             let result = re.replace_all(input_string.as_str(), repl.as_str()).to_string();
-            return Value::String(result);
+            return Value::String(result.into());
           }
         }
         // replace without any flags
         if let Ok(re) = Regex::new(pattern_string) {
           let result = re.replace_all(input_string.as_str(), repl.as_str()).to_string();
-          Value::String(result)
+          Value::String(result.into())
         } else {
           value_null!("replace: invalid pattern")
         }
@@ -1874,7 +1910,7 @@ pub fn split(input_string_value: &Value, delimiter_string_value: &Value) -> Valu
   if let Value::String(input_string) = input_string_value {
     if let Value::String(delimiter_string) = delimiter_string_value {
       if let Ok(re) = Regex::new(delimiter_string) {
-        return Value::List(re.split(input_string).map(|s| Value::String(s.to_string())).collect());
+        return Value::List(re.split(input_string).map(|s| Value::String(s.to_string().into())).collect());
       } else {
         value_null!("split: invalid delimiter")
       }
@@ -2103,7 +2139,7 @@ pub fn starts(value1: &Value, value2: &Value) -> Value {
 pub fn starts_with(input_string_value: &Value, match_string_value: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(match_string) = match_string_value {
-      Value::Boolean(input_string.starts_with(match_string))
+      Value::Boolean(input_string.starts_with(match_string.as_str()))
     } else {
       invalid_argument_type!("starts with", "string", match_string_value.type_of())
     }
@@ -2149,7 +2185,7 @@ pub fn string(value: &Value) -> Value {
   match value {
     Value::Null(_) => value_null!(),
     Value::String(s) => Value::String(s.clone()),
-    other => Value::String(other.to_feel_string()),
+    other => Value::String(other.to_feel_string().into()),
   }
 }
 
@@ -2352,10 +2388,10 @@ pub fn substring(input_string_value: &Value, start_position_value: &Value, lengt
 pub fn substring_after(input_string_value: &Value, match_input_string: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(match_string) = match_input_string {
-      if let Some(index) = input_string.find(match_string) {
-        Value::String(input_string[match_string.len() + index..].to_string())
+      if let Some(index) = input_string.find(match_string.as_str()) {
+        Value::String(input_string[match_string.len() + index..].to_string().into())
       } else {
-        Value::String("".to_string())
+        Value::String("".into())
       }
     } else {
       value_null!("substring after: expected string, actual match type is: {}", match_input_string.type_of())
@@ -2369,10 +2405,10 @@ pub fn substring_after(input_string_value: &Value, match_input_string: &Value) -
 pub fn substring_before(input_string_value: &Value, match_input_string: &Value) -> Value {
   if let Value::String(input_string) = input_string_value {
     if let Value::String(match_string) = match_input_string {
-      if let Some(index) = input_string.find(match_string) {
-        Value::String(input_string[..index].to_string())
+      if let Some(index) = input_string.find(match_string.as_str()) {
+        Value::String(input_string[..index].to_string().into())
       } else {
-        Value::String("".to_string())
+        Value::String("".into())
       }
     } else {
       value_null!("substring before: expected string, actual match type is: {}", match_input_string.type_of())
@@ -2404,15 +2440,15 @@ pub fn time_3(hour_value: &Value, minute_value: &Value, second_value: &Value) ->
         if (0..24).contains(hour) {
           if (0..60).contains(minute) {
             if (0..60).contains(second) {
-              // unwraps below are safe, value ranges are checked above
               let seconds = second.trunc();
               let nanoseconds = (second.frac() * FeelNumber::billion()).trunc();
-              let h = hour.try_into().unwrap();
-              let m = minute.try_into().unwrap();
-              let s = seconds.try_into().unwrap();
-              let n = nanoseconds.try_into().unwrap();
-              let feel_time = FeelTime::local_opt(h, m, s, n).unwrap();
-              Value::Time(feel_time)
+              match (hour.try_into(), minute.try_into(), seconds.try_into(), nanoseconds.try_into()) {
+                (Ok(h), Ok(m), Ok(s), Ok(n)) => match FeelTime::local_opt(h, m, s, n) {
+                  Some(feel_time) => Value::Time(feel_time),
+                  None => value_null!("invalid time {}:{}:{}", hour, minute, second),
+                },
+                _ => value_null!("invalid time {}:{}:{}", hour, minute, second),
+              }
             } else {
               value_null!("second must be 0..59, current value is {}", second)
             }
@@ -2441,14 +2477,19 @@ pub fn time_4(hour_value: &Value, minute_value: &Value, second_value: &Value, of
           if (0..60).contains(minute) {
             if (0..60).contains(second) {
               let seconds = second.trunc();
-              // unwraps below are safe, value ranges are checked above
-              let h = hour.try_into().unwrap();
-              let m = minute.try_into().unwrap();
-              let s = seconds.try_into().unwrap();
-              let n = (second.frac() * FeelNumber::billion()).trunc().try_into().unwrap();
+              let nanoseconds = (second.frac() * FeelNumber::billion()).trunc();
+              let (Ok(h), Ok(m), Ok(s), Ok(n)) = (hour.try_into(), minute.try_into(), seconds.try_into(), nanoseconds.try_into()) else {
+                return value_null!("core", "time_4", "invalid time {}:{}:{}", hour, minute, second);
+              };
               match offset_value {
-                Value::DaysAndTimeDuration(offset) => Value::Time(FeelTime::offset_opt(h, m, s, n, offset.as_seconds() as i32).unwrap()),
-                Value::Null(_) => Value::Time(FeelTime::local_opt(h, m, s, n).unwrap()),
+                Value::DaysAndTimeDuration(offset) => match i32::try_from(offset.as_seconds()).ok().and_then(|offset| FeelTime::offset_opt(h, m, s, n, offset)) {
+                  Some(feel_time) => Value::Time(feel_time),
+                  None => value_null!("core", "time_4", "invalid time offset: {}", offset),
+                },
+                Value::Null(_) => match FeelTime::local_opt(h, m, s, n) {
+                  Some(feel_time) => Value::Time(feel_time),
+                  None => value_null!("core", "time_4", "invalid time {}:{}:{}", hour, minute, second),
+                },
                 _ => value_null!("expected days and time duration or null, current offset type is {}", offset_value.type_of()),
               }
             } else {
@@ -2491,17 +2532,9 @@ pub fn union(lists: &[Value]) -> Value {
 /// Returns upper-cased string.
 pub fn upper_case(input_string_value: &Value) -> Value {
   match input_string_value {
-    Value::List(items) => {
-      if items.len() == 1 {
-        if let Value::String(input_string) = &items[0] {
-          return Value::String(input_string.to_uppercase());
-        }
-      }
-    }
-    Value::String(input_string) => return Value::String(input_string.to_uppercase()),
-    _ => {}
+    Value::String(input_string) => Value::String(input_string.to_uppercase().into()),
+    other => invalid_argument_type!("upper case", "string", other.type_of()),
   }
-  invalid_argument_type!("upper case", "string", input_string_value.type_of())
 }
 
 /// Returns the ISO week number of the year.
@@ -1,3 +1,47 @@
 pub mod core;
 pub mod named;
 pub mod positional;
+
+use dmntk_feel::bif::Bif;
+use dmntk_feel::conformance_level;
+use dmntk_feel::values::Value;
+use std::borrow::Cow;
+
+/// Returns `true` when singleton lists passed as arguments to the built-in function
+/// are implicitly converted to their only items.
+///
+/// Conversion is performed when none of the function's parameters accepts a list,
+/// and only at conformance levels allowing implicit conversions.
+fn unwraps_singleton_lists(bif: &Bif) -> bool {
+  bif.has_single_value_parameters() && conformance_level().allows_implicit_conversions()
+}
+
+/// Returns the only item of the singleton list, other values are returned unchanged.
+fn unwrap_singleton_list(value: &Value) -> Value {
+  match value {
+    Value::List(items) if items.len() == 1 => items[0].clone(),
+    other => other.clone(),
+  }
+}
+
+/// Returns positional arguments of the built-in function after implicit conversions.
+fn converted_positional<'a>(bif: &Bif, arguments: &'a [Value]) -> Cow<'a, [Value]> {
+  if unwraps_singleton_lists(bif) && arguments.iter().any(|argument| matches!(argument, Value::List(items) if items.len() == 1)) {
+    Cow::Owned(arguments.iter().map(unwrap_singleton_list).collect())
+  } else {
+    Cow::Borrowed(arguments)
+  }
+}
+
+/// Returns named arguments of the built-in function after implicit conversions.
+fn converted_named<'a>(bif: &Bif, arguments: &'a Value) -> Cow<'a, Value> {
+  match arguments {
+    Value::NamedParameters(parameters) if unwraps_singleton_lists(bif) => Cow::Owned(Value::NamedParameters(
+      parameters
+        .iter()
+        .map(|(name, (value, position))| (name.clone(), (unwrap_singleton_list(value), *position)))
+        .collect(),
+    )),
+    _ => Cow::Borrowed(arguments),
+  }
+}
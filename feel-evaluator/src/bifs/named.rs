@@ -1,4 +1,4 @@
-use crate::bifs::core;
+use crate::bifs::{converted_named, core};
 use dmntk_feel::bif::Bif;
 use dmntk_feel::values::Value;
 use dmntk_feel::{value_null, Name};
@@ -22,6 +22,7 @@ static NAME_LIST: Lazy<Name> = Lazy::new(|| Name::from("list"));
 static NAME_MATCH: Lazy<Name> = Lazy::new(|| Name::from("match"));
 static NAME_MONTH: Lazy<Name> = Lazy::new(|| Name::from("month"));
 static NAME_MINUTE: Lazy<Name> = Lazy::new(|| Name::from("minute"));
+static NAME_NAME: Lazy<Name> = Lazy::new(|| Name::from("name"));
 static NAME_N: Lazy<Name> = Lazy::new(|| Name::from("n"));
 static NAME_M: Lazy<Name> = Lazy::new(|| Name::from("m"));
 static NAME_NEGAND: Lazy<Name> = Lazy::new(|| Name::from("negand"));
@@ -65,6 +66,7 @@ macro_rules! parameter_not_found {
 }
 
 pub fn evaluate_bif(bif: Bif, parameters: &NamedParameters) -> Value {
+  let parameters = &*converted_named(&bif, parameters);
   match bif {
     Bif::Abs => bif_abs(parameters),
     Bif::After => bif_after(parameters),
@@ -86,10 +88,13 @@ pub fn evaluate_bif(bif: Bif, parameters: &NamedParameters) -> Value {
     Bif::Duration => bif_duration(parameters),
     Bif::During => bif_during(parameters),
     Bif::EndsWith => bif_ends_with(parameters),
+    Bif::Env => bif_env(parameters),
     Bif::Even => bif_even(parameters),
     Bif::Exp => bif_exp(parameters),
     Bif::FinishedBy => bif_finished_by(parameters),
     Bif::Finishes => bif_finishes(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::FirstDayOfMonth => bif_first_day_of_month(parameters),
     Bif::Flatten => bif_flatten(parameters),
     Bif::Floor => bif_floor(parameters),
     Bif::GetEntries => bif_get_entries(parameters),
@@ -98,6 +103,8 @@ pub fn evaluate_bif(bif: Bif, parameters: &NamedParameters) -> Value {
     Bif::IndexOf => bif_index_of(parameters),
     Bif::InsertBefore => bif_insert_before(parameters),
     Bif::Is => bif_is(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::LastDayOfMonth => bif_last_day_of_month(parameters),
     Bif::ListContains => bif_list_contains(parameters),
     Bif::Log => bif_log(parameters),
     Bif::LoweCase => bif_lower_case(parameters),
@@ -118,6 +125,8 @@ pub fn evaluate_bif(bif: Bif, parameters: &NamedParameters) -> Value {
     Bif::OverlapsAfter => bif_overlaps_after(parameters),
     Bif::OverlapsBefore => bif_overlaps_before(parameters),
     Bif::Product => bif_product(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::QuarterOfYear => bif_quarter_of_year(parameters),
     Bif::Remove => bif_remove(parameters),
     Bif::Replace => bif_replace(parameters),
     Bif::Reverse => bif_reverse(parameters),
@@ -340,6 +349,14 @@ fn bif_ends_with(parameters: &NamedParameters) -> Value {
   }
 }
 
+fn bif_env(parameters: &NamedParameters) -> Value {
+  if let Some((name_value, _)) = get_param(parameters, &NAME_NAME) {
+    core::env(name_value)
+  } else {
+    parameter_not_found!(NAME_NAME)
+  }
+}
+
 fn bif_even(parameters: &NamedParameters) -> Value {
   if let Some((value, _)) = get_param(parameters, &NAME_NUMBER) {
     core::even(value)
@@ -376,6 +393,15 @@ fn bif_finishes(parameters: &NamedParameters) -> Value {
   }
 }
 
+#[cfg(feature = "calendar")]
+fn bif_first_day_of_month(parameters: &NamedParameters) -> Value {
+  if let Some((value, _)) = get_param(parameters, &NAME_DATE) {
+    core::first_day_of_month(value)
+  } else {
+    parameter_not_found!(NAME_DATE)
+  }
+}
+
 fn bif_flatten(parameters: &NamedParameters) -> Value {
   if let Some((value, _)) = get_param(parameters, &NAME_LIST) {
     core::flatten(value)
@@ -468,6 +494,15 @@ fn bif_is(parameters: &NamedParameters) -> Value {
   }
 }
 
+#[cfg(feature = "calendar")]
+fn bif_last_day_of_month(parameters: &NamedParameters) -> Value {
+  if let Some((value, _)) = get_param(parameters, &NAME_DATE) {
+    core::last_day_of_month(value)
+  } else {
+    parameter_not_found!(NAME_DATE)
+  }
+}
+
 fn bif_list_contains(parameters: &NamedParameters) -> Value {
   if let Some((list_value, _)) = get_param(parameters, &NAME_LIST) {
     if let Some((match_value, _)) = get_param(parameters, &NAME_MATCH) {
@@ -672,6 +707,15 @@ fn bif_product(parameters: &NamedParameters) -> Value {
   }
 }
 
+#[cfg(feature = "calendar")]
+fn bif_quarter_of_year(parameters: &NamedParameters) -> Value {
+  if let Some((value, _)) = get_param(parameters, &NAME_DATE) {
+    core::quarter_of_year(value)
+  } else {
+    parameter_not_found!(NAME_DATE)
+  }
+}
+
 fn bif_remove(parameters: &NamedParameters) -> Value {
   if let Some((list, _)) = get_param(parameters, &NAME_LIST) {
     if let Some((position, _)) = get_param(parameters, &NAME_POSITION) {
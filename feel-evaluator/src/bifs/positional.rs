@@ -1,10 +1,11 @@
-use crate::bifs::core;
+use crate::bifs::{converted_positional, core};
 use crate::macros::invalid_number_of_parameters;
 use dmntk_feel::bif::Bif;
 use dmntk_feel::value_null;
 use dmntk_feel::values::Value;
 
 pub fn evaluate_bif(bif: Bif, parameters: &[Value]) -> Value {
+  let parameters = &*converted_positional(&bif, parameters);
   match bif {
     Bif::Abs => bif_abs(parameters),
     Bif::After => bif_after(parameters),
@@ -26,10 +27,13 @@ pub fn evaluate_bif(bif: Bif, parameters: &[Value]) -> Value {
     Bif::Duration => bif_duration(parameters),
     Bif::During => bif_during(parameters),
     Bif::EndsWith => bif_ends_with(parameters),
+    Bif::Env => bif_env(parameters),
     Bif::Even => bif_even(parameters),
     Bif::Exp => bif_exp(parameters),
     Bif::FinishedBy => bif_finished_by(parameters),
     Bif::Finishes => bif_finishes(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::FirstDayOfMonth => bif_first_day_of_month(parameters),
     Bif::Flatten => bif_flatten(parameters),
     Bif::Floor => bif_floor(parameters),
     Bif::GetEntries => bif_get_entries(parameters),
@@ -38,6 +42,8 @@ pub fn evaluate_bif(bif: Bif, parameters: &[Value]) -> Value {
     Bif::IndexOf => bif_index_of(parameters),
     Bif::InsertBefore => bif_insert_before(parameters),
     Bif::Is => bif_is(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::LastDayOfMonth => bif_last_day_of_month(parameters),
     Bif::ListContains => bif_list_contains(parameters),
     Bif::Log => bif_log(parameters),
     Bif::LoweCase => bif_lower_case(parameters),
@@ -58,6 +64,8 @@ pub fn evaluate_bif(bif: Bif, parameters: &[Value]) -> Value {
     Bif::OverlapsAfter => bif_overlaps_after(parameters),
     Bif::OverlapsBefore => bif_overlaps_before(parameters),
     Bif::Product => bif_product(parameters),
+    #[cfg(feature = "calendar")]
+    Bif::QuarterOfYear => bif_quarter_of_year(parameters),
     Bif::Remove => bif_remove(parameters),
     Bif::Replace => bif_replace(parameters),
     Bif::Reverse => bif_reverse(parameters),
@@ -235,6 +243,13 @@ fn bif_ends_with(parameters: &[Value]) -> Value {
   }
 }
 
+fn bif_env(parameters: &[Value]) -> Value {
+  match parameters.len() {
+    1 => core::env(&parameters[0]),
+    n => invalid_number_of_parameters!(1, n),
+  }
+}
+
 fn bif_even(parameters: &[Value]) -> Value {
   match parameters.len() {
     1 => core::even(&parameters[0]),
@@ -263,6 +278,14 @@ fn bif_finishes(parameters: &[Value]) -> Value {
   }
 }
 
+#[cfg(feature = "calendar")]
+fn bif_first_day_of_month(parameters: &[Value]) -> Value {
+  match parameters.len() {
+    1 => core::first_day_of_month(&parameters[0]),
+    n => invalid_number_of_parameters!(1, n),
+  }
+}
+
 fn bif_flatten(parameters: &[Value]) -> Value {
   match parameters.len() {
     1 => core::flatten(&parameters[0]),
@@ -320,6 +343,14 @@ fn bif_is(parameters: &[Value]) -> Value {
   }
 }
 
+#[cfg(feature = "calendar")]
+fn bif_last_day_of_month(parameters: &[Value]) -> Value {
+  match parameters.len() {
+    1 => core::last_day_of_month(&parameters[0]),
+    n => invalid_number_of_parameters!(1, n),
+  }
+}
+
 fn bif_list_contains(parameters: &[Value]) -> Value {
   match parameters.len() {
     2 => core::list_contains(&parameters[0], &parameters[1]),
@@ -485,6 +516,14 @@ fn bif_product(parameters: &[Value]) -> Value {
   }
 }
 
+#[cfg(feature = "calendar")]
+fn bif_quarter_of_year(parameters: &[Value]) -> Value {
+  match parameters.len() {
+    1 => core::quarter_of_year(&parameters[0]),
+    n => invalid_number_of_parameters!(1, n),
+  }
+}
+
 fn bif_remove(parameters: &[Value]) -> Value {
   match parameters.len() {
     2 => core::remove(&parameters[0], &parameters[1]),
@@ -0,0 +1,151 @@
+//! # Evaluation budget
+//!
+//! Evaluation budget limits the resources consumed while evaluating `FEEL` expressions,
+//! protecting the evaluator from malicious or pathological models.
+//! When any limit is exceeded, the remaining part of the evaluation is cut short
+//! and [evaluate_with_budget] reports a resource-exhausted error.
+
+use crate::errors::err_resource_exhausted;
+use dmntk_common::Result;
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// Limits of resources that may be consumed during a single evaluation.
+///
+/// Limits that are not set are not checked.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Budget {
+  /// Maximum number of evaluation steps (iterations and function invocations).
+  max_steps: Option<usize>,
+  /// Maximum number of created list elements.
+  max_list_elements: Option<usize>,
+  /// Maximum number of created context entries.
+  max_context_entries: Option<usize>,
+  /// Maximum duration of the evaluation, checked at each evaluation step.
+  max_duration: Option<Duration>,
+}
+
+impl Budget {
+  /// Sets the maximum number of evaluation steps (iterations and function invocations).
+  pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+    self.max_steps = Some(max_steps);
+    self
+  }
+
+  /// Sets the maximum number of created list elements.
+  pub fn with_max_list_elements(mut self, max_list_elements: usize) -> Self {
+    self.max_list_elements = Some(max_list_elements);
+    self
+  }
+
+  /// Sets the maximum number of created context entries.
+  pub fn with_max_context_entries(mut self, max_context_entries: usize) -> Self {
+    self.max_context_entries = Some(max_context_entries);
+    self
+  }
+
+  /// Sets the maximum duration of the evaluation, checked at each evaluation step.
+  pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+    self.max_duration = Some(max_duration);
+    self
+  }
+}
+
+/// Resources consumed during the evaluation.
+#[derive(Default)]
+struct Consumption {
+  /// Number of evaluation steps.
+  steps: usize,
+  /// Number of created list elements.
+  list_elements: usize,
+  /// Number of created context entries.
+  context_entries: usize,
+  /// Moment when the evaluation started.
+  started: Option<Instant>,
+  /// Description and limit of the exhausted resource, if any.
+  exhausted: Option<(&'static str, usize)>,
+}
+
+thread_local! {
+  /// Budget and consumption of the evaluation running in the current thread.
+  static ACTIVE_BUDGET: RefCell<Option<(Budget, Consumption)>> = const { RefCell::new(None) };
+}
+
+/// Runs the evaluation within specified budget.
+///
+/// Returns the result of the evaluation, or a resource-exhausted error
+/// when any of the limits defined in the budget was exceeded.
+pub fn evaluate_with_budget<T>(budget: Budget, evaluation: impl FnOnce() -> T) -> Result<T> {
+  let consumption = Consumption {
+    started: budget.max_duration.map(|_| Instant::now()),
+    ..Default::default()
+  };
+  let previous = ACTIVE_BUDGET.with(|active| active.replace(Some((budget, consumption))));
+  let result = evaluation();
+  let current = ACTIVE_BUDGET.with(|active| active.replace(previous));
+  if let Some((
+    _,
+    Consumption {
+      exhausted: Some((resource, limit)),
+      ..
+    },
+  )) = current
+  {
+    return Err(err_resource_exhausted(resource, limit));
+  }
+  Ok(result)
+}
+
+/// Consumes a single evaluation step, returns `false` when the budget is exhausted.
+pub(crate) fn consume_step() -> bool {
+  consume(Resource::Steps, 1)
+}
+
+/// Consumes specified number of list elements, returns `false` when the budget is exhausted.
+pub(crate) fn consume_list_elements(count: usize) -> bool {
+  consume(Resource::ListElements, count)
+}
+
+/// Consumes specified number of context entries, returns `false` when the budget is exhausted.
+pub(crate) fn consume_context_entries(count: usize) -> bool {
+  consume(Resource::ContextEntries, count)
+}
+
+/// Resources limited by the budget.
+enum Resource {
+  Steps,
+  ListElements,
+  ContextEntries,
+}
+
+/// Consumes specified amount of the resource, returns `false` when the budget is exhausted.
+fn consume(resource: Resource, count: usize) -> bool {
+  ACTIVE_BUDGET.with(|active| {
+    let mut active = active.borrow_mut();
+    let Some((budget, consumption)) = active.as_mut() else {
+      return true;
+    };
+    if consumption.exhausted.is_some() {
+      return false;
+    }
+    if let (Resource::Steps, Some(max_duration), Some(started)) = (&resource, budget.max_duration, consumption.started) {
+      if started.elapsed() > max_duration {
+        consumption.exhausted = Some(("evaluation milliseconds", max_duration.as_millis() as usize));
+        return false;
+      }
+    }
+    let (consumed, opt_limit, description) = match resource {
+      Resource::Steps => (&mut consumption.steps, budget.max_steps, "evaluation steps"),
+      Resource::ListElements => (&mut consumption.list_elements, budget.max_list_elements, "list elements"),
+      Resource::ContextEntries => (&mut consumption.context_entries, budget.max_context_entries, "context entries"),
+    };
+    *consumed = consumed.saturating_add(count);
+    match opt_limit {
+      Some(limit) if *consumed > limit => {
+        consumption.exhausted = Some((description, limit));
+        false
+      }
+      _ => true,
+    }
+  })
+}
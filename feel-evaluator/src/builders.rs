@@ -1,29 +1,48 @@
-use crate::bifs;
 use crate::errors::*;
+#[cfg(feature = "java")]
 use crate::evaluator_java::evaluate_external_java_function;
 use crate::evaluator_pmml::evaluate_external_pmml_function;
+#[cfg(not(feature = "closures"))]
+use crate::ir::Program;
 use crate::iterations::{EveryExpressionEvaluator, ForExpressionEvaluator, SomeExpressionEvaluator};
 use crate::macros::invalid_argument_type;
-use dmntk_common::Result;
-use dmntk_feel::bif::Bif;
+use crate::{bifs, budget, comparison};
+use dmntk_common::{did_you_mean, Result};
+use dmntk_feel::bif::{Bif, BIF_NAMES};
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::{Value, Values, VALUE_FALSE, VALUE_TRUE};
-use dmntk_feel::{value_null, Evaluator, FeelNumber, FeelScope, FeelType, FunctionBody, Name, QualifiedName};
+use dmntk_feel::{report_violation, value_null, Evaluator, FeelNumber, FeelScope, FeelString, FeelType, FunctionBody, Name, QualifiedName, StringInterner};
 use dmntk_feel_parser::{AstNode, ClosureBuilder};
 use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
 use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashSet};
-use std::ops::Deref;
 use std::str::FromStr;
 use std::sync::Arc;
 
 /// Build context.
+///
+/// Evaluators built using the same build context share
+/// the content of identical string literals.
 #[derive(Default)]
 pub struct BuildContext {
-  // Currently not used, but left here for future extensions.
+  /// Interner of string literals.
+  interner: RefCell<StringInterner>,
+}
+
+impl BuildContext {
+  /// Returns interned string with specified content.
+  pub(crate) fn intern(&self, s: &str) -> FeelString {
+    self.interner.borrow_mut().intern(s)
+  }
 }
 
 pub fn build_evaluator(bx: &BuildContext, node: &AstNode) -> Result<Evaluator> {
+  #[cfg(not(feature = "closures"))]
+  if let Some(program) = Program::compile(bx, node) {
+    return Ok(Box::new(move |scope: &FeelScope| program.run(scope)));
+  }
   match node {
     AstNode::Add(lhs, rhs) => build_add(bx, lhs, rhs),
     AstNode::And(lhs, rhs) => build_and(bx, lhs, rhs),
@@ -102,108 +121,117 @@ pub fn build_evaluator(bx: &BuildContext, node: &AstNode) -> Result<Evaluator> {
 fn build_add(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Number(lh + rh),
-        value @ Value::Null(_) => value,
-        _ => value_null!("incompatible types in addition: {}({}) + {}({})", lhv, lhv.type_of(), rhv, rhv.type_of()),
-      },
-      Value::String(mut lh) => {
-        if let Value::String(rh) = rhv {
-          lh.push_str(&rh);
-          Value::String(lh)
+  Ok(Box::new(move |scope: &FeelScope| eval_add(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates addition of two values.
+pub(crate) fn eval_add(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => Value::Number(lh + rh),
+      value @ Value::Null(_) => value,
+      _ => value_null!("incompatible types in addition: {}({}) + {}({})", lhv, lhv.type_of(), rhv, rhv.type_of()),
+    },
+    Value::String(lh) => {
+      if let Value::String(rh) = rhv {
+        let mut result = String::with_capacity(lh.len() + rh.len());
+        result.push_str(&lh);
+        result.push_str(&rh);
+        Value::String(result.into())
+      } else {
+        value_null!("expected string as a second argument in addition")
+      }
+    }
+    Value::Date(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(result) = lh + rh {
+          Value::Date(result)
         } else {
-          value_null!("expected string as a second argument in addition")
+          value_null!("invalid result while adding days and time duration to date")
         }
       }
-      Value::Date(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(result) = lh + rh {
-            Value::Date(result)
-          } else {
-            value_null!("invalid result while adding days and time duration to date")
-          }
-        }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(a) = lh + rh {
-            Value::Date(a)
-          } else {
-            value_null!("invalid result while adding years and months duration to date")
-          }
-        }
-        other => {
-          invalid_argument_type!("add", "years and months duration", other.type_of())
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(a) = lh + rh {
+          Value::Date(a)
+        } else {
+          value_null!("invalid result while adding years and months duration to date")
         }
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(a) = lh + rh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding days and time duration to date and time")
-          }
+      }
+      other => {
+        invalid_argument_type!("add", "years and months duration", other.type_of())
+      }
+    },
+    Value::DateTime(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(a) = lh + rh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding days and time duration to date and time")
         }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(a) = lh + rh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding years and months duration to date and time")
-          }
+      }
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(a) = lh + rh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding years and months duration to date and time")
         }
-        other => invalid_argument_type!("add", "days and time duration, years and months duration", other.type_of()),
-      },
-      Value::Time(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Time(lh + rh),
-        other => invalid_argument_type!("add", "days and time duration", other.type_of()),
+      }
+      other => invalid_argument_type!("add", "days and time duration, years and months duration", other.type_of()),
+    },
+    Value::Time(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => Value::Time(lh + rh),
+      other => invalid_argument_type!("add", "days and time duration", other.type_of()),
+    },
+    Value::DaysAndTimeDuration(lh) => match rhv {
+      Value::DaysAndTimeDuration(rh) => match lh.checked_add(&rh) {
+        Some(result) => Value::DaysAndTimeDuration(result),
+        None => value_null!("[addition] duration out of range: {} + {}", lh, rh),
       },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::DaysAndTimeDuration(lh + rh),
-        Value::Date(rh) => {
-          if let Some(result) = rh + lh {
-            Value::Date(result)
-          } else {
-            value_null!("invalid result while adding date to days and time duration")
-          }
+      Value::Date(rh) => {
+        if let Some(result) = rh + lh {
+          Value::Date(result)
+        } else {
+          value_null!("invalid result while adding date to days and time duration")
         }
-        Value::DateTime(rh) => {
-          if let Some(a) = rh + lh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding date and time to days and time duration")
-          }
+      }
+      Value::DateTime(rh) => {
+        if let Some(a) = rh + lh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding date and time to days and time duration")
         }
-        Value::Time(rh) => Value::Time(rh + lh),
-        other => invalid_argument_type!("add", "days and time duration, date and time", other.type_of()),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::Date(rh) => {
-          if let Some(a) = rh + lh {
-            Value::Date(a)
-          } else {
-            value_null!("invalid result while adding date to years and months duration")
-          }
+      }
+      Value::Time(rh) => Value::Time(rh + lh),
+      other => invalid_argument_type!("add", "days and time duration, date and time", other.type_of()),
+    },
+    Value::YearsAndMonthsDuration(lh) => match rhv {
+      Value::Date(rh) => {
+        if let Some(a) = rh + lh {
+          Value::Date(a)
+        } else {
+          value_null!("invalid result while adding date to years and months duration")
         }
-        Value::DateTime(rh) => {
-          if let Some(a) = rh + lh {
-            Value::DateTime(a)
-          } else {
-            value_null!("invalid result while adding date and time to years and months duration")
-          }
+      }
+      Value::DateTime(rh) => {
+        if let Some(a) = rh + lh {
+          Value::DateTime(a)
+        } else {
+          value_null!("invalid result while adding date and time to years and months duration")
         }
-        Value::YearsAndMonthsDuration(rh) => Value::YearsAndMonthsDuration(lh + rh),
-        other => invalid_argument_type!("add", "years and months duration, date and time", other.type_of()),
+      }
+      Value::YearsAndMonthsDuration(rh) => match lh.checked_add(&rh) {
+        Some(result) => Value::YearsAndMonthsDuration(result),
+        None => value_null!("[addition] duration out of range: {} + {}", lh, rh),
       },
-      value @ Value::Null(_) => value,
-      other => invalid_argument_type!(
-        "add",
-        "number, string, date and time, days and time duration, years and months duration, null",
-        other.type_of()
-      ),
-    }
-  }))
+      other => invalid_argument_type!("add", "years and months duration, date and time", other.type_of()),
+    },
+    value @ Value::Null(_) => value,
+    other => invalid_argument_type!(
+      "add",
+      "number, string, date and time, days and time duration, years and months duration, null",
+      other.type_of()
+    ),
+  }
 }
 
 /// Builds evaluator of temporal expression after `@` (at) literal.
@@ -356,32 +384,36 @@ fn build_boolean(_bx: &BuildContext, lhs: bool) -> Result<Evaluator> {
 fn build_and(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Boolean(lh) => match rhv {
-        Value::Boolean(rh) => Value::Boolean(lh && rh),
-        _ => {
-          if lh {
-            value_null!()
-          } else {
-            Value::Boolean(false)
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_and(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates conjunction of two values.
+/// The null result is tagged with the reason of the first null operand.
+pub(crate) fn eval_and(lhv: Value, rhv: Value) -> Value {
+  let cause = null_cause([&lhv, &rhv]);
+  let value = match lhv {
+    Value::Boolean(lh) => match rhv {
+      Value::Boolean(rh) => Value::Boolean(lh && rh),
+      _ => {
+        if lh {
+          value_null!()
+        } else {
+          Value::Boolean(false)
         }
-      },
-      _ => match rhv {
-        Value::Boolean(rh) => {
-          if rh {
-            value_null!()
-          } else {
-            Value::Boolean(false)
-          }
+      }
+    },
+    _ => match rhv {
+      Value::Boolean(rh) => {
+        if rh {
+          value_null!()
+        } else {
+          Value::Boolean(false)
         }
-        _ => value_null!(),
-      },
-    }
-  }))
+      }
+      _ => value_null!(),
+    },
+  };
+  value.caused_by(cause.as_deref())
 }
 
 fn build_context(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator> {
@@ -390,6 +422,9 @@ fn build_context(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator> {
     evaluators.push(build_evaluator(bx, node)?);
   }
   Ok(Box::new(move |scope: &FeelScope| {
+    if !budget::consume_context_entries(evaluators.len()) {
+      return value_null!("evaluation budget exhausted");
+    }
     let mut evaluated_ctx = FeelContext::default();
     // prepare special context in scope, used for already evaluated context entries
     scope.push(FeelContext::default());
@@ -482,71 +517,72 @@ fn build_context_type_entry_key(_bx: &BuildContext, lhs: &Name) -> Result<Evalua
 fn build_div(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => {
-          if rh.abs() == FeelNumber::zero() {
-            value_null!("[division] division by zero")
-          } else {
-            Value::Number(lh / rh)
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_div(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates division of two values.
+pub(crate) fn eval_div(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => {
+        if rh.abs() == FeelNumber::zero() {
+          value_null!("[division] division by zero")
+        } else {
+          Value::Number(lh / rh)
         }
-        _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-      },
-      Value::DaysAndTimeDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          if rh.is_zero() {
-            value_null!("[division] division by zero")
+      }
+      _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
+    },
+    Value::DaysAndTimeDuration(ref lh) => match rhv {
+      Value::Number(rh) => {
+        if rh.is_zero() {
+          value_null!("[division] division by zero")
+        } else {
+          let lv = FeelNumber::from(lh.as_nanos()) / rh;
+          if let Ok(v) = FeelNumber::try_into(lv) {
+            Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
           } else {
-            let lv = FeelNumber::from(lh.as_nanos()) / rh;
-            if let Ok(v) = FeelNumber::try_into(lv) {
-              Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
-            } else {
-              value_null!("[division] error: {} / {}", lhv, rhv)
-            }
+            value_null!("[division] error: {} / {}", lhv, rhv)
           }
         }
-        Value::DaysAndTimeDuration(rh) => {
-          if rh.as_nanos() == 0 {
-            value_null!("[division] division by zero")
-          } else {
-            let lvl = FeelNumber::from(lh.as_nanos());
-            let rvl = FeelNumber::from(rh.as_nanos());
-            Value::Number(lvl / rvl)
-          }
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        if rh.as_nanos() == 0 {
+          value_null!("[division] division by zero")
+        } else {
+          let lvl = FeelNumber::from(lh.as_nanos());
+          let rvl = FeelNumber::from(rh.as_nanos());
+          Value::Number(lvl / rvl)
         }
-        _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-      },
-      Value::YearsAndMonthsDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          if rh.is_zero() {
-            value_null!("[division] division by zero")
+      }
+      _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
+    },
+    Value::YearsAndMonthsDuration(ref lh) => match rhv {
+      Value::Number(rh) => {
+        if rh.is_zero() {
+          value_null!("[division] division by zero")
+        } else {
+          let vl = FeelNumber::from(lh.as_months()) / rh;
+          if let Ok(v) = FeelNumber::try_into(vl) {
+            Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
           } else {
-            let vl = FeelNumber::from(lh.as_months()) / rh;
-            if let Ok(v) = FeelNumber::try_into(vl) {
-              Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
-            } else {
-              value_null!("[division] error: {} / {}", lhv, rhv)
-            }
+            value_null!("[division] error: {} / {}", lhv, rhv)
           }
         }
-        Value::YearsAndMonthsDuration(rh) => {
-          if rh.as_months() == 0 {
-            value_null!("[division] division by zero")
-          } else {
-            let lvl = FeelNumber::from(lh.as_months());
-            let rvl = FeelNumber::from(rh.as_months());
-            Value::Number(lvl / rvl)
-          }
+      }
+      Value::YearsAndMonthsDuration(rh) => {
+        if rh.as_months() == 0 {
+          value_null!("[division] division by zero")
+        } else {
+          let lvl = FeelNumber::from(lh.as_months());
+          let rvl = FeelNumber::from(rh.as_months());
+          Value::Number(lvl / rvl)
         }
-        _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-      },
+      }
       _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
-    }
-  }))
+    },
+    _ => value_null!("[division] incompatible types: {} / {}", lhv, rhv),
+  }
 }
 
 fn build_expression_list(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator> {
@@ -566,23 +602,24 @@ fn build_expression_list(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator
 fn build_exp(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    if let Value::Number(lh) = lhv {
-      if let Value::Number(rh) = rhv {
-        if let Some(result) = lh.pow(&rh) {
-          Value::Number(result)
-        } else {
-          value_null!("exponentiation result is not a finite number")
-        }
+  Ok(Box::new(move |scope: &FeelScope| eval_exp(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates exponentiation of two values.
+pub(crate) fn eval_exp(lhv: Value, rhv: Value) -> Value {
+  if let Value::Number(lh) = lhv {
+    if let Value::Number(rh) = rhv {
+      if let Some(result) = lh.pow(&rh) {
+        Value::Number(result)
       } else {
-        value_null!("exponentiation exponent is not a number")
+        value_null!("exponentiation result is not a finite number")
       }
     } else {
-      value_null!("exponentiation base is not a number")
+      value_null!("exponentiation exponent is not a number")
     }
-  }))
+  } else {
+    value_null!("exponentiation base is not a number")
+  }
 }
 
 fn build_feel_type(_bx: &BuildContext, lhs: &FeelType) -> Result<Evaluator> {
@@ -640,11 +677,9 @@ fn build_filter(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evalu
                       return value_null!("index is out of range 1..2⁶⁴: {}", index.to_string());
                     }
                   };
-                  if n > 0 && n <= list_size {
-                    // unwrap below is safe, index `n` is checked above, `values` variable is immutable
-                    values.get(n - 1).unwrap().to_owned()
-                  } else {
-                    value_null!("index in filter is out of range [1..{}], actual index is {}", list_size, n)
+                  match n.checked_sub(1).and_then(|position| values.get(position)) {
+                    Some(value) => value.to_owned(),
+                    None => value_null!("index in filter is out of range [1..{}], actual index is {}", list_size, n),
                   }
                 } else {
                   let n = {
@@ -654,11 +689,9 @@ fn build_filter(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evalu
                       return value_null!("index is out of range 1..2⁶⁴: {}", index.to_string());
                     }
                   };
-                  if n > 0 && n <= list_size {
-                    // unwrap below is safe, index `n` is checked above, `values` variable is immutable
-                    values.get(list_size - n).unwrap().to_owned()
-                  } else {
-                    value_null!("index in filter is out of range [-{}..-1], actual index is -{}", list_size, n)
+                  match list_size.checked_sub(n).filter(|_| n > 0).and_then(|position| values.get(position)) {
+                    Some(value) => value.to_owned(),
+                    None => value_null!("index in filter is out of range [-{}..-1], actual index is -{}", list_size, n),
                   }
                 }
               } else {
@@ -802,8 +835,8 @@ fn build_external_function_body(bx: &BuildContext, lhs: &AstNode) -> Result<Eval
         if let Some(Value::Context(java_mapping)) = mapping_information.get_entry(&"java".into()) {
           return if let Some(Value::String(class_name)) = java_mapping.get_entry(&"class".into()) {
             if let Some(Value::String(method_signature)) = java_mapping.get_entry(&"method signature".into()) {
-              let java_class_name = class_name.to_owned();
-              let java_method_signature = method_signature.to_owned();
+              let java_class_name = class_name.to_string();
+              let java_method_signature = method_signature.to_string();
               let java_evaluator = Box::new(move |_: &FeelScope| Value::ExternalJavaFunction(java_class_name.clone(), java_method_signature.clone())) as Evaluator;
               let lhe = Arc::new(java_evaluator);
               Value::FunctionBody(FunctionBody::External(lhe), true)
@@ -817,8 +850,8 @@ fn build_external_function_body(bx: &BuildContext, lhs: &AstNode) -> Result<Eval
         if let Some(Value::Context(pmml_mapping)) = mapping_information.get_entry(&"pmml".into()) {
           return if let Some(Value::String(document)) = pmml_mapping.get_entry(&"document".into()) {
             if let Some(Value::String(model_name)) = pmml_mapping.get_entry(&"model".into()) {
-              let pmml_document = document.to_owned();
-              let pmml_model_name = model_name.to_owned();
+              let pmml_document = document.to_string();
+              let pmml_model_name = model_name.to_string();
               let pmml_evaluator = Box::new(move |_: &FeelScope| Value::ExternalPmmlFunction(pmml_document.clone(), pmml_model_name.clone())) as Evaluator;
               let lhe = Arc::new(pmml_evaluator);
               Value::FunctionBody(FunctionBody::External(lhe), true)
@@ -868,15 +901,16 @@ fn build_function_definition(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) ->
 fn build_eq(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    if let Some(result) = eval_ternary_equality(&lhv, &rhv) {
-      Value::Boolean(result)
-    } else {
-      value_null!("equal err '{}' =?= '{}'", lhv, rhv)
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_eq(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates equality comparison of two values.
+pub(crate) fn eval_eq(lhv: Value, rhv: Value) -> Value {
+  if let Some(result) = comparison::equals(&lhv, &rhv) {
+    Value::Boolean(result)
+  } else {
+    value_null!("equal err '{}' =?= '{}'", lhv, rhv)
+  }
 }
 
 fn build_evaluated_expression(bx: &BuildContext, lhs: &AstNode) -> Result<Evaluator> {
@@ -925,10 +959,13 @@ fn build_function_invocation_with_positional_parameters(bx: &BuildContext, lhs:
   }
   let function_evaluator = build_evaluator(bx, lhs)?;
   Ok(Box::new(move |scope: &FeelScope| {
+    if !budget::consume_step() {
+      return value_null!("evaluation budget exhausted");
+    }
     let function = function_evaluator(scope);
     let args = argument_evaluators.iter().map(|evaluator| evaluator(scope)).collect::<Vec<Value>>();
     match function {
-      Value::BuiltInFunction(bif) => bifs::positional::evaluate_bif(bif, &args),
+      Value::BuiltInFunction(bif) => bifs::positional::evaluate_bif(bif, &args).caused_by(null_cause(&args).as_deref()),
       Value::FunctionDefinition(params, body, external, _, closure_ctx, result_type) => {
         if external {
           eval_external_function_with_positional_parameters(scope, &args, &params, &body, result_type)
@@ -945,10 +982,19 @@ fn build_function_invocation_with_named_parameters(bx: &BuildContext, lhs: &AstN
   let function_evaluator = build_evaluator(bx, lhs)?;
   let arguments_evaluator = build_evaluator(bx, rhs)?;
   Ok(Box::new(move |scope: &FeelScope| {
+    if !budget::consume_step() {
+      return value_null!("evaluation budget exhausted");
+    }
     let function = function_evaluator(scope);
     let args = arguments_evaluator(scope);
     match function {
-      Value::BuiltInFunction(bif) => bifs::named::evaluate_bif(bif, &args),
+      Value::BuiltInFunction(bif) => {
+        let cause = match &args {
+          Value::NamedParameters(parameters) => null_cause(parameters.values().map(|(value, _)| value)),
+          _ => None,
+        };
+        bifs::named::evaluate_bif(bif, &args).caused_by(cause.as_deref())
+      }
       Value::FunctionDefinition(params, body, external, _, closure_ctx, result_type) => {
         if external {
           eval_external_function_with_named_parameters(scope, &args, &params, &body, result_type)
@@ -986,81 +1032,27 @@ fn build_function_type(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Resul
 fn build_ge(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh >= rh),
-        _ => value_null!("eval_greater_or_equal_years_and_months_duration"),
-      },
-      _ => value_null!("eval_greater_or_equal"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_ge(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates greater than or equal comparison of two values.
+pub(crate) fn eval_ge(lhv: Value, rhv: Value) -> Value {
+  eval_ordering("greater or equal", ">=", "eval_greater_or_equal", &lhv, &rhv, Ordering::is_ge)
 }
 
 fn build_gt(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_then_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh > rh),
-        _ => value_null!("eval_greater_years_and_months_duration"),
-      },
-      _ => value_null!("eval_greater_then"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_gt(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates greater than comparison of two values.
+pub(crate) fn eval_gt(lhv: Value, rhv: Value) -> Value {
+  let name = match lhv {
+    Value::DaysAndTimeDuration(_) | Value::YearsAndMonthsDuration(_) => "eval_greater",
+    _ => "eval_greater_then",
+  };
+  eval_ordering("greater than", ">", name, &lhv, &rhv, Ordering::is_gt)
 }
 
 fn build_if(bx: &BuildContext, lhs: &AstNode, mhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
@@ -1075,23 +1067,15 @@ fn build_if(bx: &BuildContext, lhs: &AstNode, mhs: &AstNode, rhs: &AstNode) -> R
 }
 
 fn build_in(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
+  if rhs.uses_input_value() {
+    return build_in_with_input_value(bx, lhs, rhs);
+  }
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
   Ok(Box::new(move |scope: &FeelScope| {
     let lhv = lhe(scope);
     let rhv = rhe(scope);
     match rhv {
-      inner @ Value::Null(_)
-      | inner @ Value::Number(_)
-      | inner @ Value::String(_)
-      | inner @ Value::Boolean(_)
-      | inner @ Value::Date(_)
-      | inner @ Value::Time(_)
-      | inner @ Value::DateTime(_)
-      | inner @ Value::YearsAndMonthsDuration(_)
-      | inner @ Value::DaysAndTimeDuration(_)
-      | inner @ Value::Context(_) => eval_in_equal(&lhv, &inner),
-      Value::Range(l, l_closed, r, r_closed) => eval_in_range(&lhv, &l, l_closed, &r, r_closed),
       Value::List(r_inner) => {
         if let Value::List(l_inner) = lhv {
           eval_in_list_in_list(&l_inner, &r_inner)
@@ -1101,16 +1085,46 @@ fn build_in(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator
       }
       Value::ExpressionList(inner) => eval_in_list(&lhv, &inner),
       Value::NegatedCommaList(inner) => eval_in_negated_list(&lhv, &inner),
-      Value::UnaryLess(inner) => eval_in_unary_less(&lhv, inner.borrow()),
-      Value::UnaryLessOrEqual(inner) => eval_in_unary_less_or_equal(&lhv, inner.borrow()),
-      Value::UnaryGreater(inner) => eval_in_unary_greater(&lhv, inner.borrow()),
-      Value::UnaryGreaterOrEqual(inner) => eval_in_unary_greater_or_equal(&lhv, inner.borrow()),
       Value::Irrelevant => VALUE_TRUE,
-      _ => value_null!("unexpected argument type in 'in' operator: {}", rhv.type_of()),
+      other => eval_in_item(&lhv, &other).unwrap_or_else(|| value_null!("unexpected argument type in 'in' operator: {}", other.type_of())),
     }
   }))
 }
 
+/// Builds the `in` operator for unary tests referencing the input value with `?`.
+///
+/// While evaluating unary tests, the tested value is bound to the name `?`.
+/// Unary test referencing `?` and evaluated to a boolean value is satisfied when this value is `true`,
+/// like `contains(?, "abc")` or `? > a + b`, and is not satisfied when evaluated to `null`.
+/// All other unary tests are compared with the tested value as usual.
+fn build_in_with_input_value(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
+  let lhe = build_evaluator(bx, lhs)?;
+  let (items, negated) = match rhs {
+    AstNode::ExpressionList(items) => (items.as_slice(), false),
+    AstNode::NegatedList(items) => (items.as_slice(), true),
+    other => (std::slice::from_ref(other), false),
+  };
+  let mut evaluators = vec![];
+  for item in items {
+    evaluators.push((item.uses_input_value(), build_evaluator(bx, item)?));
+  }
+  let input_name = Name::from("?");
+  Ok(Box::new(move |scope: &FeelScope| {
+    let lhv = lhe(scope);
+    let mut ctx = FeelContext::default();
+    ctx.set_entry(&input_name, lhv.clone());
+    scope.push(ctx);
+    let satisfied = evaluators.iter().any(|(uses_input_value, evaluator)| match evaluator(scope) {
+      Value::Boolean(result) if *uses_input_value => result,
+      Value::Null(_) if *uses_input_value => false,
+      Value::Irrelevant => true,
+      rhv => eval_in_list(&lhv, &[rhv]).is_true(),
+    });
+    scope.pop();
+    Value::Boolean(satisfied != negated)
+  }))
+}
+
 fn build_interval_end(bx: &BuildContext, lhs: &AstNode, rhs: &bool) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let closed = *rhs;
@@ -1141,66 +1155,19 @@ fn build_instance_of(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<
     let rhv = rhe(scope);
     if let Value::FeelType(feel_type) = rhv {
       match lhv {
-        Value::Number { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::Number => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::String { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::String => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::Boolean { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::Boolean => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::Date { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::Date => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::DateTime { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::DateTime => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::Time { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::Time => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::YearsAndMonthsDuration { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::YearsAndMonthsDuration => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::DaysAndTimeDuration { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          FeelType::DaysAndTimeDuration => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        Value::Null { .. } => match feel_type {
-          FeelType::Null => VALUE_TRUE,
-          _ => VALUE_FALSE,
-        },
-        value @ Value::Range { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          expected => Value::Boolean(value.type_of() == expected),
-        },
-        value @ Value::List { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          expected => Value::Boolean(value.type_of().instance_of(&expected)),
-        },
-        value @ Value::Context { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          expected => Value::Boolean(value.type_of().instance_of(&expected)),
-        },
-        value @ Value::FunctionDefinition { .. } => match feel_type {
-          FeelType::Any => VALUE_TRUE,
-          expected => Value::Boolean(value.type_of() == expected),
-        },
+        Value::Null { .. } => Value::Boolean(feel_type.is_null()),
+        value @ (Value::Number { .. }
+        | Value::String { .. }
+        | Value::Boolean { .. }
+        | Value::Date { .. }
+        | Value::DateTime { .. }
+        | Value::Time { .. }
+        | Value::YearsAndMonthsDuration { .. }
+        | Value::DaysAndTimeDuration { .. }
+        | Value::Range { .. }
+        | Value::List { .. }
+        | Value::Context { .. }
+        | Value::FunctionDefinition { .. }) => Value::Boolean(value.type_of().instance_of(&feel_type)),
         other => value_null!("invalid value in 'instance of' operator: {}", other),
       }
     } else {
@@ -1212,81 +1179,23 @@ fn build_instance_of(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<
 fn build_le(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh <= rh),
-        _ => value_null!("eval_less_or_equal_years_and_months_duration"),
-      },
-      _ => value_null!("eval_less_or_equal"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_le(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates less than or equal comparison of two values.
+pub(crate) fn eval_le(lhv: Value, rhv: Value) -> Value {
+  eval_ordering("less or equal", "<=", "eval_less_or_equal", &lhv, &rhv, Ordering::is_le)
 }
 
 fn build_lt(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_number"),
-      },
-      Value::String(lh) => match rhv {
-        Value::String(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_string"),
-      },
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_date"),
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::DateTime(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_date_time"),
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_time"),
-      },
-      Value::DaysAndTimeDuration(lh) => match rhv {
-        Value::DaysAndTimeDuration(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_days_and_time_duration"),
-      },
-      Value::YearsAndMonthsDuration(lh) => match rhv {
-        Value::YearsAndMonthsDuration(rh) => Value::Boolean(lh < rh),
-        _ => value_null!("eval_less_then_years_and_months_duration"),
-      },
-      _ => value_null!("eval_less_then"),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_lt(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates less than comparison of two values.
+pub(crate) fn eval_lt(lhv: Value, rhv: Value) -> Value {
+  eval_ordering("less than", "<", "eval_less_then", &lhv, &rhv, Ordering::is_lt)
 }
 
 fn build_list(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator> {
@@ -1295,6 +1204,9 @@ fn build_list(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator> {
     evaluators.push(build_evaluator(bx, node)?);
   }
   Ok(Box::new(move |scope: &FeelScope| {
+    if !budget::consume_list_elements(evaluators.len()) {
+      return value_null!("evaluation budget exhausted");
+    }
     let mut values = vec![];
     for evaluator in &evaluators {
       values.push(evaluator(scope))
@@ -1318,68 +1230,82 @@ fn build_list_type(bx: &BuildContext, lhs: &AstNode) -> Result<Evaluator> {
 fn build_mul(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => match rhv {
-        Value::Number(rh) => Value::Number(lh * rh),
-        Value::DaysAndTimeDuration(ref rh) => {
-          let val = lh * FeelNumber::from(rh.as_nanos());
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
-          } else {
-            value_null!("multiplication result is out of range of days and time duration")
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_mul(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates multiplication of two values.
+pub(crate) fn eval_mul(lhv: Value, rhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => match rhv {
+      Value::Number(rh) => Value::Number(lh * rh),
+      Value::DaysAndTimeDuration(ref rh) => {
+        let val = lh * FeelNumber::from(rh.as_nanos());
+        if let Ok(v) = FeelNumber::try_into(val) {
+          Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
+        } else {
+          value_null!("multiplication result is out of range of days and time duration")
         }
-        Value::YearsAndMonthsDuration(ref rh) => {
-          let val = lh * FeelNumber::from(rh.as_months());
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
-          } else {
-            value_null!("multiplication result is out of range of years and months duration")
-          }
+      }
+      Value::YearsAndMonthsDuration(ref rh) => {
+        let val = lh * FeelNumber::from(rh.as_months());
+        if let Ok(v) = FeelNumber::try_into(val) {
+          Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
+        } else {
+          value_null!("multiplication result is out of range of years and months duration")
         }
-        _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
-      },
-      Value::DaysAndTimeDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          let val = FeelNumber::from(lh.as_nanos()) * rh;
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
-          } else {
-            value_null!("multiplication result is out of range of days and time duration")
-          }
+      }
+      _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
+    },
+    Value::DaysAndTimeDuration(ref lh) => match rhv {
+      Value::Number(rh) => {
+        let val = FeelNumber::from(lh.as_nanos()) * rh;
+        if let Ok(v) = FeelNumber::try_into(val) {
+          Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_n(v))
+        } else {
+          value_null!("multiplication result is out of range of days and time duration")
         }
-        _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
-      },
-      Value::YearsAndMonthsDuration(ref lh) => match rhv {
-        Value::Number(rh) => {
-          let val = FeelNumber::from(lh.as_months()) * rh;
-          if let Ok(v) = FeelNumber::try_into(val) {
-            Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
-          } else {
-            value_null!("multiplication result is out of range of years and months duration")
-          }
+      }
+      _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
+    },
+    Value::YearsAndMonthsDuration(ref lh) => match rhv {
+      Value::Number(rh) => {
+        let val = FeelNumber::from(lh.as_months()) * rh;
+        if let Ok(v) = FeelNumber::try_into(val) {
+          Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(v))
+        } else {
+          value_null!("multiplication result is out of range of years and months duration")
         }
-        _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
-      },
-      value @ Value::Null(_) => value,
-      other => value_null!("unexpected value type in multiplication: {}", other.type_of()),
-    }
-  }))
-}
-
+      }
+      _ => value_null!("[multiplication] incompatible types: {} * {}", lhv, rhv),
+    },
+    value @ Value::Null(_) => value,
+    other => value_null!("unexpected value type in multiplication: {}", other.type_of()),
+  }
+}
+
 fn build_name(_bx: &BuildContext, name: Name) -> Result<Evaluator> {
-  Ok(Box::new(move |scope: &FeelScope| {
-    if let Some(value) = scope.get_value(&name) {
-      value
-    } else if let Ok(bif) = Bif::from_str(&name.to_string()) {
-      Value::BuiltInFunction(bif)
-    } else {
-      value_null!("context has no value for key '{}'", name)
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_name(scope, &name)))
+}
+
+/// Evaluates the value of a name, falling back to built-in function with the same name.
+pub(crate) fn eval_name(scope: &FeelScope, name: &Name) -> Value {
+  if let Some(value) = scope.get_value(name) {
+    value
+  } else if let Ok(bif) = Bif::from_str(&name.to_string()) {
+    Value::BuiltInFunction(bif)
+  } else {
+    report_violation(|| format!("context has no value for key '{name}'{}", name_suggestion(scope, name)));
+    value_null!("context has no value for key '{}'", name)
+  }
+}
+
+/// Returns the suggestion of names in scope and names of built-in functions
+/// similar to the name that has no value, or an empty string when there are no similar names.
+///
+/// Comparing names is costly, so suggestions are computed only for diagnostics of strict evaluation.
+fn name_suggestion(scope: &FeelScope, name: &Name) -> String {
+  let candidates = scope.names().iter().map(|name| name.to_string()).collect::<Vec<String>>();
+  did_you_mean(&name.to_string(), candidates.iter().map(String::as_str).chain(BIF_NAMES.iter().copied()))
 }
 
 fn build_named_parameter(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
@@ -1416,15 +1342,17 @@ fn build_named_parameters(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluato
 
 fn build_neg(bx: &BuildContext, lhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    match lhv {
-      Value::Number(lh) => Value::Number(-lh),
-      Value::DaysAndTimeDuration(lh) => Value::DaysAndTimeDuration(-lh),
-      Value::YearsAndMonthsDuration(lh) => Value::YearsAndMonthsDuration(-lh),
-      other => value_null!("unexpected type in arithmetic negation: {}", other.type_of()),
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_neg(lhe(scope))))
+}
+
+/// Evaluates arithmetic negation of a value.
+pub(crate) fn eval_neg(lhv: Value) -> Value {
+  match lhv {
+    Value::Number(lh) => Value::Number(-lh),
+    Value::DaysAndTimeDuration(lh) => Value::DaysAndTimeDuration(-lh),
+    Value::YearsAndMonthsDuration(lh) => Value::YearsAndMonthsDuration(-lh),
+    other => value_null!("unexpected type in arithmetic negation: {}", other.type_of()).caused_by(other.null_reason()),
+  }
 }
 
 fn build_negated_list(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator> {
@@ -1457,15 +1385,16 @@ fn build_numeric(_bx: &BuildContext, lhs: &str, rhs: &str) -> Result<Evaluator>
 fn build_nq(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    if let Some(result) = eval_ternary_equality(&lhv, &rhv) {
-      Value::Boolean(!result)
-    } else {
-      value_null!()
-    }
-  }))
+  Ok(Box::new(move |scope: &FeelScope| eval_nq(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates inequality comparison of two values.
+pub(crate) fn eval_nq(lhv: Value, rhv: Value) -> Value {
+  if let Some(result) = comparison::equals(&lhv, &rhv) {
+    Value::Boolean(!result)
+  } else {
+    value_null!()
+  }
 }
 
 /// Semantics of disjunction.
@@ -1485,32 +1414,36 @@ fn build_nq(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator
 fn build_or(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Boolean(lh) => match rhv {
-        Value::Boolean(rh) => Value::Boolean(lh || rh),
-        _ => {
-          if lh {
-            Value::Boolean(true)
-          } else {
-            value_null!()
-          }
+  Ok(Box::new(move |scope: &FeelScope| eval_or(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates disjunction of two values.
+/// The null result is tagged with the reason of the first null operand.
+pub(crate) fn eval_or(lhv: Value, rhv: Value) -> Value {
+  let cause = null_cause([&lhv, &rhv]);
+  let value = match lhv {
+    Value::Boolean(lh) => match rhv {
+      Value::Boolean(rh) => Value::Boolean(lh || rh),
+      _ => {
+        if lh {
+          Value::Boolean(true)
+        } else {
+          value_null!()
         }
-      },
-      _ => match rhv {
-        Value::Boolean(rh) => {
-          if rh {
-            Value::Boolean(true)
-          } else {
-            value_null!()
-          }
+      }
+    },
+    _ => match rhv {
+      Value::Boolean(rh) => {
+        if rh {
+          Value::Boolean(true)
+        } else {
+          value_null!()
         }
-        _ => value_null!(),
-      },
-    }
-  }))
+      }
+      _ => value_null!(),
+    },
+  };
+  value.caused_by(cause.as_deref())
 }
 
 fn build_out(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
@@ -1551,13 +1484,21 @@ fn build_qualified_name(bx: &BuildContext, lhs: &[AstNode]) -> Result<Evaluator>
     evaluators.push(build_evaluator(bx, node)?);
   }
   Ok(Box::new(move |scope: &FeelScope| {
-    let mut names = vec![];
+    let mut names = QualifiedName::default();
     for evaluator in &evaluators {
       if let Value::QualifiedNameSegment(name) = evaluator(scope) {
         names.push(name);
       }
     }
-    scope.search(&names).unwrap_or_else(|| value_null!("no value for qualified name"))
+    scope.search(&names).unwrap_or_else(|| {
+      report_violation(|| {
+        format!(
+          "no value for qualified name '{}'",
+          names.iter().map(|name| name.to_string()).collect::<Vec<String>>().join(".")
+        )
+      });
+      value_null!("no value for qualified name")
+    })
   }))
 }
 
@@ -1605,7 +1546,7 @@ fn get_property_from_value(value: Value, name: &Name) -> Value {
       }
       "timezone" => {
         if let Some(feel_time_zone) = date_time.feel_time_zone() {
-          Value::String(feel_time_zone)
+          Value::String(feel_time_zone.into())
         } else {
           value_null!("could not retrieve timezone for date and time")
         }
@@ -1625,7 +1566,7 @@ fn get_property_from_value(value: Value, name: &Name) -> Value {
       }
       "timezone" => {
         if let Some(feel_time_zone) = time.feel_time_zone() {
-          Value::String(feel_time_zone)
+          Value::String(feel_time_zone.into())
         } else {
           value_null!("could not retrieve timezone for time")
         }
@@ -1699,7 +1640,9 @@ fn build_qualified_name_from_path(node: &AstNode) -> Result<QualifiedName> {
 fn build_path(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let qualified_name = build_qualified_name_from_path(rhs)?;
   let mut property_path = qualified_name.clone();
-  let property_name = property_path.pop().unwrap();
+  let Some(property_name) = property_path.pop() else {
+    return Err(err_unexpected_ast_node(&format!("expected non-empty path, found: {rhs:?}")));
+  };
   let lhe = build_evaluator(bx, lhs)?;
   Ok(Box::new(move |scope: &FeelScope| {
     let lhv = lhe(scope);
@@ -1711,6 +1654,7 @@ fn build_path(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluat
         if let Some(value) = context.search_entry(&property_path) {
           return get_property_from_value(value.clone(), &property_name);
         }
+        report_violation(|| format!("context has no entry '{qualified_name}'"));
         value_null!("build_path: no entry {} in context: {}", qualified_name, context)
       }
       Value::List(items) => {
@@ -1791,98 +1735,148 @@ fn build_some(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluat
   }))
 }
 
-fn build_string(_bx: &BuildContext, lhs: &str) -> Result<Evaluator> {
-  let value = Value::String(lhs.to_string());
+fn build_string(bx: &BuildContext, lhs: &str) -> Result<Evaluator> {
+  let value = Value::String(bx.intern(lhs));
   Ok(Box::new(move |_: &FeelScope| value.clone()))
 }
 
 fn build_sub(bx: &BuildContext, lhs: &AstNode, rhs: &AstNode) -> Result<Evaluator> {
   let lhe = build_evaluator(bx, lhs)?;
   let rhe = build_evaluator(bx, rhs)?;
-  Ok(Box::new(move |scope: &FeelScope| {
-    let lhv = lhe(scope);
-    let rhv = rhe(scope);
-    match lhv {
-      Value::Number(lh) => {
-        if let Value::Number(rh) = rhv {
-          return Value::Number(lh - rh);
-        }
+  Ok(Box::new(move |scope: &FeelScope| eval_sub(lhe(scope), rhe(scope))))
+}
+
+/// Evaluates subtraction of two values.
+pub(crate) fn eval_sub(lhv: Value, rhv: Value) -> Value {
+  match lhv.clone() {
+    Value::Number(lh) => {
+      if let Value::Number(rh) = rhv.clone() {
+        return Value::Number(lh - rh);
       }
-      Value::Date(lh) => match rhv {
-        Value::Date(rh) => {
-          let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
-          let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
-          if let Some(result) = l - r {
-            return Value::DaysAndTimeDuration(result);
-          }
-        }
-        Value::DateTime(rh) => {
-          let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
-          if let Some(result) = l - rh {
-            return Value::DaysAndTimeDuration(result);
-          }
-        }
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(date) = lh - rh {
-            return Value::Date(date);
-          }
-        }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(date) = lh - rh {
-            return Value::Date(date);
-          }
+    }
+    Value::Date(lh) => match rhv.clone() {
+      Value::Date(rh) => {
+        let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
+        let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
+        if let Some(result) = l - r {
+          return Value::DaysAndTimeDuration(result);
         }
-        _ => {}
-      },
-      Value::Time(lh) => match rhv {
-        Value::Time(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DaysAndTimeDuration(result);
-          }
+      }
+      Value::DateTime(rh) => {
+        let l = FeelDateTime::new(lh, FeelTime::utc(0, 0, 0, 0));
+        if let Some(result) = l - rh {
+          return Value::DaysAndTimeDuration(result);
         }
-        Value::DaysAndTimeDuration(rh) => {
-          return Value::Time(lh - rh);
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(date) = lh - rh {
+          return Value::Date(date);
         }
-        _ => {}
-      },
-      Value::DateTime(lh) => match rhv {
-        Value::Date(rh) => {
-          let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
-          if let Some(result) = lh - r {
-            return Value::DaysAndTimeDuration(result);
-          }
+      }
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(date) = lh - rh {
+          return Value::Date(date);
         }
-        Value::DateTime(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DaysAndTimeDuration(result);
-          }
+      }
+      _ => {}
+    },
+    Value::Time(lh) => match rhv.clone() {
+      Value::Time(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DaysAndTimeDuration(result);
         }
-        Value::DaysAndTimeDuration(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DateTime(result);
-          }
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        return Value::Time(lh - rh);
+      }
+      _ => {}
+    },
+    Value::DateTime(lh) => match rhv.clone() {
+      Value::Date(rh) => {
+        let r = FeelDateTime::new(rh, FeelTime::utc(0, 0, 0, 0));
+        if let Some(result) = lh - r {
+          return Value::DaysAndTimeDuration(result);
         }
-        Value::YearsAndMonthsDuration(rh) => {
-          if let Some(result) = lh - rh {
-            return Value::DateTime(result);
-          }
+      }
+      Value::DateTime(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DaysAndTimeDuration(result);
         }
-        _ => {}
-      },
-      Value::DaysAndTimeDuration(lh) => {
-        if let Value::DaysAndTimeDuration(rh) = rhv {
-          return Value::DaysAndTimeDuration(lh - rh);
+      }
+      Value::DaysAndTimeDuration(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DateTime(result);
         }
       }
-      Value::YearsAndMonthsDuration(lh) => {
-        if let Value::YearsAndMonthsDuration(rh) = rhv {
-          return Value::YearsAndMonthsDuration(lh - rh);
+      Value::YearsAndMonthsDuration(rh) => {
+        if let Some(result) = lh - rh {
+          return Value::DateTime(result);
         }
       }
       _ => {}
+    },
+    Value::DaysAndTimeDuration(lh) => {
+      if let Value::DaysAndTimeDuration(rh) = &rhv {
+        return match lh.checked_sub(rh) {
+          Some(result) => Value::DaysAndTimeDuration(result),
+          None => value_null!("[subtraction] duration out of range: {} - {}", lhv, rhv),
+        };
+      }
     }
-    value_null!("[subtraction] incompatible types: {} - {}", lhe(scope) as Value, rhe(scope) as Value)
-  }))
+    Value::YearsAndMonthsDuration(lh) => {
+      if let Value::YearsAndMonthsDuration(rh) = &rhv {
+        return match lh.checked_sub(rh) {
+          Some(result) => Value::YearsAndMonthsDuration(result),
+          None => value_null!("[subtraction] duration out of range: {} - {}", lhv, rhv),
+        };
+      }
+    }
+    _ => {}
+  }
+  value_null!("[subtraction] incompatible types: {} - {}", lhv, rhv)
+}
+
+/// Evaluates a comparison of durations of different kinds.
+///
+/// Years and months durations and days and time durations are not comparable,
+/// so the result is always null. Returns `None` when values are not durations of different kinds.
+fn eval_mixed_durations_comparison(operation: &str, operator: &str, lhv: &Value, rhv: &Value) -> Option<Value> {
+  match (lhv, rhv) {
+    (Value::YearsAndMonthsDuration(_), Value::DaysAndTimeDuration(_)) | (Value::DaysAndTimeDuration(_), Value::YearsAndMonthsDuration(_)) => {
+      Some(value_null!("[{}] incompatible duration kinds: {} {} {}", operation, lhv, operator, rhv))
+    }
+    _ => None,
+  }
+}
+
+/// Evaluates the comparison of two values, satisfied when the ordering of values matches the predicate.
+/// When values are not comparable, the null value is tagged with the name of the comparison
+/// followed by the type of the left-side value.
+fn eval_ordering(operation: &str, operator: &str, name: &str, lhv: &Value, rhv: &Value, predicate: fn(Ordering) -> bool) -> Value {
+  if let Some(value) = eval_mixed_durations_comparison(operation, operator, lhv, rhv) {
+    return value;
+  }
+  let cause = null_cause([lhv, rhv]);
+  let value = match comparison::ordered(lhv, rhv, predicate) {
+    Some(result) => Value::Boolean(result),
+    None => match lhv {
+      Value::Number(_) => value_null!("{}_number", name),
+      Value::String(_) => value_null!("{}_string", name),
+      Value::Date(_) => value_null!("{}_date", name),
+      Value::DateTime(_) => value_null!("{}_date_time", name),
+      Value::Time(_) => value_null!("{}_time", name),
+      Value::DaysAndTimeDuration(_) => value_null!("{}_days_and_time_duration", name),
+      Value::YearsAndMonthsDuration(_) => value_null!("{}_years_and_months_duration", name),
+      _ => value_null!("{}", name),
+    },
+  };
+  value.caused_by(cause.as_deref())
+}
+
+/// Returns the tracing message of the first null value with a reason,
+/// used as the cause of null values produced from these values.
+fn null_cause<'a>(values: impl IntoIterator<Item = &'a Value>) -> Option<String> {
+  values.into_iter().find_map(|value| value.null_reason().map(|reason| reason.to_string()))
 }
 
 fn build_unary_ge(bx: &BuildContext, lhs: &AstNode) -> Result<Evaluator> {
@@ -1917,207 +1911,35 @@ fn build_unary_lt(bx: &BuildContext, lhs: &AstNode) -> Result<Evaluator> {
   }))
 }
 
-/// Evaluates ternary equality between two values.
-pub fn eval_ternary_equality(lhs: &Value, rhs: &Value) -> Option<bool> {
-  match lhs {
-    Value::Boolean(ls) => match rhs {
-      Value::Boolean(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::Number(ls) => match rhs {
-      Value::Number(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::String(ls) => match rhs {
-      Value::String(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::Context(ls) => match rhs {
-      Value::Context(rs) => {
-        if ls.keys().len() == rs.keys().len() {
-          for (key1, value1) in ls.deref() {
-            if let Some(value2) = rs.get_entry(key1) {
-              if let Some(equal) = eval_ternary_equality(value1, value2) {
-                if !equal {
-                  return Some(false); // values in entries are NOT EQUAL
-                }
-              } else {
-                // values in entries can not be compared
-                return None;
-              }
-            } else {
-              // there is no such key in the right-side context
-              return Some(false);
-            }
-          }
-          // contexts are EQUAL
-          return Some(true);
-        }
-        // contexts have different number of keys, so they are NOT EQUAL
-        Some(false)
-      }
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::Date(ls) => match rhs {
-      Value::Date(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::Time(ls) => match rhs {
-      Value::Time(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::DateTime(ls) => match rhs {
-      Value::DateTime(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::DaysAndTimeDuration(ls) => match rhs {
-      Value::DaysAndTimeDuration(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::YearsAndMonthsDuration(ls) => match rhs {
-      Value::YearsAndMonthsDuration(rs) => Some(*ls == *rs),
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    ls @ Value::Null(_) => match rhs {
-      rs @ Value::Null(_) => {
-        if (ls.is_invalid_coercion() && !rs.is_invalid_coercion()) || (!ls.is_invalid_coercion() && rs.is_invalid_coercion()) {
-          None
-        } else {
-          Some(true)
-        }
-      }
-      _ => None,
-    },
-    Value::List(ls) => match rhs {
-      Value::List(rs) => {
-        if ls.len() == rs.len() {
-          for (l, r) in ls.iter().zip(rs.iter()) {
-            if let Some(true) = eval_ternary_equality(l, r) {
-            } else {
-              return Some(false);
-            }
-          }
-          Some(true)
-        } else {
-          Some(false)
-        }
-      }
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    Value::UnaryGreater(end) => match rhs {
-      Value::Range(rs, cs, re, ce) => {
-        if !*cs && !*ce && re.is_null() {
-          eval_ternary_equality(end, rs)
-        } else {
-          Some(false)
-        }
-      }
-      _ => None,
-    },
-    Value::UnaryLess(end) => match rhs {
-      Value::Range(rs, cs, re, ce) => {
-        if !*cs && !*ce && rs.is_null() {
-          eval_ternary_equality(end, re)
-        } else {
-          Some(false)
-        }
-      }
-      _ => None,
-    },
-    Value::UnaryGreaterOrEqual(end) => match rhs {
-      Value::Range(rs, cs, re, ce) => {
-        if *cs && !*ce && re.is_null() {
-          eval_ternary_equality(end, rs)
-        } else {
-          Some(false)
-        }
-      }
-      _ => None,
-    },
-    Value::UnaryLessOrEqual(end) => match rhs {
-      Value::Range(rs, cs, re, ce) => {
-        if !*cs && *ce && rs.is_null() {
-          eval_ternary_equality(end, re)
-        } else {
-          Some(false)
-        }
-      }
-      _ => None,
-    },
-    Value::Range(r1s, c1s, r1e, c1e) => match rhs {
-      Value::Range(r2s, c2s, r2e, c2e) => {
-        if *c1s == *c2s && *c1e == *c2e {
-          if let Some(true) = eval_ternary_equality(r1s, r2s) {
-            return eval_ternary_equality(r1e, r2e);
-          }
-        }
-        Some(false)
-      }
-      Value::Null(_) => Some(false),
-      _ => None,
-    },
-    _ => None,
-  }
+/// Evaluates the `in` operator for a single unary test, returns `None` for unexpected unary tests.
+fn eval_in_item(left: &Value, item: &Value) -> Option<Value> {
+  Some(match item {
+    Value::Null(_)
+    | Value::String(_)
+    | Value::Number(_)
+    | Value::Boolean(_)
+    | Value::Date(_)
+    | Value::Time(_)
+    | Value::DateTime(_)
+    | Value::YearsAndMonthsDuration(_)
+    | Value::DaysAndTimeDuration(_)
+    | Value::Context(_) => eval_in_equal(left, item),
+    Value::UnaryLess(inner) => eval_in_unary("eval_in_unary_less", left, inner, Ordering::is_lt),
+    Value::UnaryLessOrEqual(inner) => eval_in_unary("eval_in_unary_less_or_equal", left, inner, Ordering::is_le),
+    Value::UnaryGreater(inner) => eval_in_unary("eval_in_unary_greater", left, inner, Ordering::is_gt),
+    Value::UnaryGreaterOrEqual(inner) => eval_in_unary("eval_in_unary_greater_or_equal", left, inner, Ordering::is_ge),
+    Value::List(inner) => eval_in_list(left, inner),
+    Value::Range(l, l_closed, r, r_closed) => eval_in_range(left, l, *l_closed, r, *r_closed),
+    _ => return None,
+  })
 }
 
 fn eval_in_list(left: &Value, items: &[Value]) -> Value {
   for item in items {
-    match item {
-      inner @ Value::Null(_)
-      | inner @ Value::String(_)
-      | inner @ Value::Number(_)
-      | inner @ Value::Boolean(_)
-      | inner @ Value::Date(_)
-      | inner @ Value::Time(_)
-      | inner @ Value::DateTime(_)
-      | inner @ Value::YearsAndMonthsDuration(_)
-      | inner @ Value::DaysAndTimeDuration(_)
-      | inner @ Value::Context(_) => {
-        if let Value::Boolean(true) = eval_in_equal(left, inner) {
-          return VALUE_TRUE;
-        }
-      }
-      Value::UnaryLess(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_less(left, inner.borrow()) {
-          return VALUE_TRUE;
-        }
-      }
-      Value::UnaryLessOrEqual(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_less_or_equal(left, inner.borrow()) {
-          return VALUE_TRUE;
-        }
-      }
-      Value::UnaryGreater(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_greater(left, inner.borrow()) {
-          return VALUE_TRUE;
-        }
-      }
-      Value::UnaryGreaterOrEqual(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_greater_or_equal(left, inner.borrow()) {
-          return VALUE_TRUE;
-        }
-      }
-      Value::List(inner) => {
-        if let Value::Boolean(true) = eval_in_list(left, inner) {
-          return VALUE_TRUE;
-        }
-      }
-      Value::Range(l, l_closed, r, r_closed) => {
-        if let Value::Boolean(true) = eval_in_range(left, l, *l_closed, r, *r_closed) {
-          return VALUE_TRUE;
-        }
-      }
-      _ => return value_null!("eval_in_list"),
+    match eval_in_item(left, item) {
+      Some(Value::Boolean(true)) => return VALUE_TRUE,
+      Some(_) => {}
+      None => return value_null!("eval_in_list"),
     }
   }
   VALUE_FALSE
@@ -2151,313 +1973,33 @@ fn eval_in_list_in_list(l_items: &[Value], r_items: &[Value]) -> Value {
 
 fn eval_in_negated_list(left: &Value, items: &[Value]) -> Value {
   for item in items {
-    match item {
-      inner @ Value::Null(_)
-      | inner @ Value::String(_)
-      | inner @ Value::Number(_)
-      | inner @ Value::Boolean(_)
-      | inner @ Value::Date(_)
-      | inner @ Value::Time(_)
-      | inner @ Value::DateTime(_)
-      | inner @ Value::DaysAndTimeDuration(_)
-      | inner @ Value::YearsAndMonthsDuration(_) => {
-        if let Value::Boolean(true) = eval_in_equal(left, inner) {
-          return Value::Boolean(false);
-        }
-      }
-      Value::UnaryLess(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_less(left, inner.borrow()) {
-          return Value::Boolean(false);
-        }
-      }
-      Value::UnaryLessOrEqual(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_less_or_equal(left, inner.borrow()) {
-          return Value::Boolean(false);
-        }
-      }
-      Value::UnaryGreater(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_greater(left, inner.borrow()) {
-          return Value::Boolean(false);
-        }
-      }
-      Value::UnaryGreaterOrEqual(inner) => {
-        if let Value::Boolean(true) = eval_in_unary_greater_or_equal(left, inner.borrow()) {
-          return Value::Boolean(false);
-        }
-      }
-      Value::List(inner) => {
-        if let Value::Boolean(true) = eval_in_list(left, inner) {
-          return Value::Boolean(false);
-        }
-      }
-      Value::Range(l, l_closed, r, r_closed) => {
-        if let Value::Boolean(true) = eval_in_range(left, l, *l_closed, r, *r_closed) {
-          return Value::Boolean(false);
-        }
-      }
-      other => return value_null!("unexpected type in negated list: {}", other.type_of()),
+    match eval_in_item(left, item) {
+      Some(Value::Boolean(true)) => return VALUE_FALSE,
+      Some(_) => {}
+      None => return value_null!("unexpected type in negated list: {}", item.type_of()),
     }
   }
-  Value::Boolean(true)
+  VALUE_TRUE
 }
 
 fn eval_in_range(lhv: &Value, l: &Value, l_closed: bool, r: &Value, r_closed: bool) -> Value {
-  match lhv {
-    Value::Number(value) => match l {
-      Value::Number(lv) => match r {
-        Value::Number(rv) => {
-          let l_ok = if l_closed { value >= lv } else { value > lv };
-          let r_ok = if r_closed { value <= rv } else { value < rv };
-          Value::Boolean(l_ok && r_ok)
-        }
-        _ => value_null!("eval_in_range"),
-      },
-      _ => value_null!("eval_in_range"),
-    },
-    Value::String(value) => match l {
-      Value::String(lv) => match r {
-        Value::String(rv) => {
-          let l_ok = if l_closed { value >= lv } else { value > lv };
-          let r_ok = if r_closed { value <= rv } else { value < rv };
-          Value::Boolean(l_ok && r_ok)
-        }
-        _ => value_null!("eval_in_range"),
-      },
-      _ => value_null!("eval_in_range"),
-    },
-    Value::Date(value) => match l {
-      Value::Date(lv) => match r {
-        Value::Date(rv) => {
-          let l_ok = if l_closed { value >= lv } else { value > lv };
-          let r_ok = if r_closed { value <= rv } else { value < rv };
-          Value::Boolean(l_ok && r_ok)
-        }
-        _ => value_null!("eval_in_range"),
-      },
-      _ => value_null!("eval_in_range"),
-    },
-    Value::Time(value) => match l {
-      Value::Time(lv) => match r {
-        Value::Time(rv) => {
-          let l_ok = if l_closed { value >= lv } else { value > lv };
-          let r_ok = if r_closed { value <= rv } else { value < rv };
-          Value::Boolean(l_ok && r_ok)
-        }
-        _ => value_null!("eval_in_range"),
-      },
-      _ => value_null!("eval_in_range"),
-    },
-    Value::DateTime(value) => match l {
-      Value::DateTime(lv) => match r {
-        Value::DateTime(rv) => {
-          let l_ok = if l_closed { value >= lv } else { value > lv };
-          let r_ok = if r_closed { value <= rv } else { value < rv };
-          Value::Boolean(l_ok && r_ok)
-        }
-        _ => value_null!("eval_in_range"),
-      },
-      _ => value_null!("eval_in_range"),
-    },
-    Value::YearsAndMonthsDuration(value) => match l {
-      Value::YearsAndMonthsDuration(lv) => match r {
-        Value::YearsAndMonthsDuration(rv) => {
-          let l_ok = if l_closed { value >= lv } else { value > lv };
-          let r_ok = if r_closed { value <= rv } else { value < rv };
-          Value::Boolean(l_ok && r_ok)
-        }
-        _ => value_null!("eval_in_range"),
-      },
-      _ => value_null!("eval_in_range"),
-    },
-    Value::DaysAndTimeDuration(value) => match l {
-      Value::DaysAndTimeDuration(lv) => match r {
-        Value::DaysAndTimeDuration(rv) => {
-          let l_ok = if l_closed { value >= lv } else { value > lv };
-          let r_ok = if r_closed { value <= rv } else { value < rv };
-          Value::Boolean(l_ok && r_ok)
-        }
-        _ => value_null!("eval_in_range"),
-      },
-      _ => value_null!("eval_in_range"),
-    },
-    _ => value_null!("eval_in_range"),
+  match comparison::in_range(lhv, l, l_closed, r, r_closed) {
+    Some(result) => Value::Boolean(result),
+    None => value_null!("eval_in_range"),
   }
 }
 
 fn eval_in_equal(left: &Value, right: &Value) -> Value {
-  if let Some(true) = eval_ternary_equality(left, right) {
-    VALUE_TRUE
-  } else {
-    VALUE_FALSE
-  }
+  Value::Boolean(comparison::equals(left, right) == Some(true))
 }
 
-fn eval_in_unary_less(left: &Value, right: &Value) -> Value {
-  match right {
-    Value::Number(r) => {
-      if let Value::Number(l) = left {
-        return Value::Boolean(*l < *r);
-      }
-    }
-    Value::String(r) => {
-      if let Value::String(l) = left {
-        return Value::Boolean(l < r);
-      }
-    }
-    Value::Date(r) => {
-      if let Value::Date(l) = left {
-        return Value::Boolean(l < r);
-      }
-    }
-    Value::Time(r) => {
-      if let Value::Time(l) = left {
-        return Value::Boolean(l < r);
-      }
-    }
-    Value::DateTime(r) => {
-      if let Value::DateTime(l) = left {
-        return Value::Boolean(l < r);
-      }
-    }
-    Value::YearsAndMonthsDuration(r) => {
-      if let Value::YearsAndMonthsDuration(l) = left {
-        return Value::Boolean(l < r);
-      }
-    }
-    Value::DaysAndTimeDuration(r) => {
-      if let Value::DaysAndTimeDuration(l) = left {
-        return Value::Boolean(l < r);
-      }
-    }
-    _ => {}
-  }
-  value_null!("eval_in_unary_less")
-}
-
-fn eval_in_unary_less_or_equal(left: &Value, right: &Value) -> Value {
-  match right {
-    Value::Number(r) => {
-      if let Value::Number(l) = left {
-        return Value::Boolean(*l <= *r);
-      }
-    }
-    Value::String(r) => {
-      if let Value::String(l) = left {
-        return Value::Boolean(l <= r);
-      }
-    }
-    Value::Date(r) => {
-      if let Value::Date(l) = left {
-        return Value::Boolean(l <= r);
-      }
-    }
-    Value::Time(r) => {
-      if let Value::Time(l) = left {
-        return Value::Boolean(l <= r);
-      }
-    }
-    Value::DateTime(r) => {
-      if let Value::DateTime(l) = left {
-        return Value::Boolean(l <= r);
-      }
-    }
-    Value::YearsAndMonthsDuration(r) => {
-      if let Value::YearsAndMonthsDuration(l) = left {
-        return Value::Boolean(l <= r);
-      }
-    }
-    Value::DaysAndTimeDuration(r) => {
-      if let Value::DaysAndTimeDuration(l) = left {
-        return Value::Boolean(l <= r);
-      }
-    }
-    _ => {}
-  }
-  value_null!("eval_in_unary_less_or_equal")
-}
-
-fn eval_in_unary_greater(left: &Value, right: &Value) -> Value {
-  match right {
-    Value::Number(r) => {
-      if let Value::Number(l) = left {
-        return Value::Boolean(*l > *r);
-      }
-    }
-    Value::String(r) => {
-      if let Value::String(l) = left {
-        return Value::Boolean(l > r);
-      }
-    }
-    Value::Date(r) => {
-      if let Value::Date(l) = left {
-        return Value::Boolean(l > r);
-      }
-    }
-    Value::Time(r) => {
-      if let Value::Time(l) = left {
-        return Value::Boolean(l > r);
-      }
-    }
-    Value::DateTime(r) => {
-      if let Value::DateTime(l) = left {
-        return Value::Boolean(l > r);
-      }
-    }
-    Value::YearsAndMonthsDuration(r) => {
-      if let Value::YearsAndMonthsDuration(l) = left {
-        return Value::Boolean(l > r);
-      }
-    }
-    Value::DaysAndTimeDuration(r) => {
-      if let Value::DaysAndTimeDuration(l) = left {
-        return Value::Boolean(l > r);
-      }
-    }
-    _ => {}
-  }
-  value_null!("eval_in_unary_greater")
-}
-
-fn eval_in_unary_greater_or_equal(left: &Value, right: &Value) -> Value {
-  match right {
-    Value::Number(r) => {
-      if let Value::Number(l) = left {
-        return Value::Boolean(*l >= *r);
-      }
-    }
-    Value::String(r) => {
-      if let Value::String(left_value) = left {
-        return Value::Boolean(left_value >= r);
-      }
-    }
-    Value::Date(r) => {
-      if let Value::Date(l) = left {
-        return Value::Boolean(l >= r);
-      }
-    }
-    Value::Time(r) => {
-      if let Value::Time(l) = left {
-        return Value::Boolean(l >= r);
-      }
-    }
-    Value::DateTime(r) => {
-      if let Value::DateTime(l) = left {
-        return Value::Boolean(l >= r);
-      }
-    }
-    Value::YearsAndMonthsDuration(r) => {
-      if let Value::YearsAndMonthsDuration(l) = left {
-        return Value::Boolean(l >= r);
-      }
-    }
-    Value::DaysAndTimeDuration(r) => {
-      if let Value::DaysAndTimeDuration(l) = left {
-        return Value::Boolean(l >= r);
-      }
-    }
-    _ => {}
+/// Evaluates unary comparison, satisfied when the ordering of the tested value
+/// and the endpoint matches the predicate.
+fn eval_in_unary(name: &str, left: &Value, right: &Value, predicate: fn(Ordering) -> bool) -> Value {
+  match comparison::ordered(left, right, predicate) {
+    Some(result) => Value::Boolean(result),
+    None => value_null!("{}", name),
   }
-  value_null!("eval_in_unary_greater_or_equal")
 }
 
 /// Evaluates function definition with positional parameters.
@@ -2559,7 +2101,10 @@ fn eval_external_function_with_named_parameters(scope: &FeelScope, args: &Value,
 /// Evaluates external function definition.
 fn eval_external_function_definition(scope: &FeelScope, arguments: &[Value], body: &FunctionBody, result_type: FeelType) -> Value {
   let result = match &body.evaluate(scope) {
+    #[cfg(feature = "java")]
     Value::ExternalJavaFunction(class_name, method_signature) => evaluate_external_java_function(class_name, method_signature, arguments),
+    #[cfg(not(feature = "java"))]
+    Value::ExternalJavaFunction(class_name, _) => value_null!("external Java function in class '{}' can not be evaluated, feature `java` is disabled", class_name),
     Value::ExternalPmmlFunction(document, model_name) => evaluate_external_pmml_function(document, model_name, arguments),
     other => value_null!("expected JAVA or PMML mapping, actual value is {}", other),
   };
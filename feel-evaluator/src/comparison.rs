@@ -0,0 +1,184 @@
+//! # Equality and comparison of FEEL values
+//!
+//! Semantics of equality (`=`, `!=`, equality tests in unary tests and decision tables):
+//!
+//! ```text
+//! left                       right                      result
+//! ─────────────────────────────────────────────────────────────────────────────────────────
+//! null                       null                       true
+//! null                       any other value            false
+//! any other value            null                       false
+//! boolean, number, string,
+//! time, duration             the same type              equality of values
+//! date                       date                       equality of dates
+//! date                       date and time              date is date and time at midnight UTC
+//! date and time              date                       date is date and time at midnight UTC
+//! date and time              date and time              equality of points in time
+//! list                       list                       same length and all items equal,
+//!                                                       null when compared items are not comparable
+//! context                    context                    same keys and all entries equal,
+//!                                                       null when compared entries are not comparable
+//! range                      range                      equal endpoints and closures
+//! unary comparison           range                      equal to the equivalent range
+//! any other combination                                 null
+//! ```
+//!
+//! Semantics of ordering (`<`, `<=`, `>`, `>=`, ranges and unary comparisons):
+//!
+//! ```text
+//! left                       right                      result
+//! ─────────────────────────────────────────────────────────────────────────────────────────
+//! number, string, time,
+//! days and time duration,
+//! years and months duration  the same type              ordering of values
+//! date                       date                       ordering of dates
+//! date                       date and time              date is date and time at midnight UTC
+//! date and time              date                       date is date and time at midnight UTC
+//! date and time              date and time              ordering of points in time
+//! any other combination                                 null
+//! ```
+
+use dmntk_feel::values::Value;
+use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelTime};
+use std::cmp::Ordering;
+use std::ops::Deref;
+
+/// Returns `Some(true)` when two values are equal, `Some(false)` when they are not equal
+/// and `None` when values can not be compared for equality.
+pub fn equals(lhs: &Value, rhs: &Value) -> Option<bool> {
+  match (lhs, rhs) {
+    (Value::Null(_), Value::Null(_)) => {
+      if lhs.is_invalid_coercion() != rhs.is_invalid_coercion() {
+        None
+      } else {
+        Some(true)
+      }
+    }
+    (Value::Null(_), _) => Some(false),
+    (Value::Boolean(ls), Value::Boolean(rs)) => Some(ls == rs),
+    (Value::Number(ls), Value::Number(rs)) => Some(ls == rs),
+    (Value::String(ls), Value::String(rs)) => Some(ls == rs),
+    (Value::Date(ls), Value::Date(rs)) => Some(ls == rs),
+    (Value::Date(ls), Value::DateTime(rs)) => Some(&at_midnight(ls) == rs),
+    (Value::DateTime(ls), Value::Date(rs)) => Some(ls == &at_midnight(rs)),
+    (Value::DateTime(ls), Value::DateTime(rs)) => Some(ls == rs),
+    (Value::Time(ls), Value::Time(rs)) => Some(ls == rs),
+    (Value::DaysAndTimeDuration(ls), Value::DaysAndTimeDuration(rs)) => Some(ls == rs),
+    (Value::YearsAndMonthsDuration(ls), Value::YearsAndMonthsDuration(rs)) => Some(ls == rs),
+    (Value::Context(ls), Value::Context(rs)) => {
+      if ls.keys().len() != rs.keys().len() {
+        return Some(false);
+      }
+      for (key, value) in ls.deref() {
+        let Some(other) = rs.get_entry(key) else {
+          return Some(false);
+        };
+        if !equals(value, other)? {
+          return Some(false);
+        }
+      }
+      Some(true)
+    }
+    (Value::List(ls), Value::List(rs)) => {
+      if ls.len() != rs.len() {
+        return Some(false);
+      }
+      for (l, r) in ls.iter().zip(rs.iter()) {
+        if !equals(l, r)? {
+          return Some(false);
+        }
+      }
+      Some(true)
+    }
+    (Value::Range(r1s, c1s, r1e, c1e), Value::Range(r2s, c2s, r2e, c2e)) => {
+      if c1s == c2s && c1e == c2e && equals(r1s, r2s) == Some(true) {
+        equals(r1e, r2e)
+      } else {
+        Some(false)
+      }
+    }
+    (Value::UnaryGreater(end), Value::Range(rs, cs, re, ce)) => unary_equals(end, rs, !*cs && !*ce && re.is_null()),
+    (Value::UnaryGreaterOrEqual(end), Value::Range(rs, cs, re, ce)) => unary_equals(end, rs, *cs && !*ce && re.is_null()),
+    (Value::UnaryLess(end), Value::Range(rs, cs, re, ce)) => unary_equals(end, re, !*cs && !*ce && rs.is_null()),
+    (Value::UnaryLessOrEqual(end), Value::Range(rs, cs, re, ce)) => unary_equals(end, re, !*cs && *ce && rs.is_null()),
+    (
+      Value::Boolean(_)
+      | Value::Number(_)
+      | Value::String(_)
+      | Value::Date(_)
+      | Value::DateTime(_)
+      | Value::Time(_)
+      | Value::DaysAndTimeDuration(_)
+      | Value::YearsAndMonthsDuration(_)
+      | Value::Context(_)
+      | Value::List(_)
+      | Value::Range(..),
+      Value::Null(_),
+    ) => Some(false),
+    _ => None,
+  }
+}
+
+/// Returns the ordering of two values, or `None` when values are not comparable
+/// or the ordering can not be determined.
+pub fn compare(lhs: &Value, rhs: &Value) -> Option<Ordering> {
+  match (lhs, rhs) {
+    (Value::Number(ls), Value::Number(rs)) => ls.partial_cmp(rs),
+    (Value::String(ls), Value::String(rs)) => ls.partial_cmp(rs),
+    (Value::Date(ls), Value::Date(rs)) => ls.partial_cmp(rs),
+    (Value::Date(ls), Value::DateTime(rs)) => at_midnight(ls).partial_cmp(rs),
+    (Value::DateTime(ls), Value::Date(rs)) => ls.partial_cmp(&at_midnight(rs)),
+    (Value::DateTime(ls), Value::DateTime(rs)) => ls.partial_cmp(rs),
+    (Value::Time(ls), Value::Time(rs)) => ls.partial_cmp(rs),
+    (Value::DaysAndTimeDuration(ls), Value::DaysAndTimeDuration(rs)) => ls.partial_cmp(rs),
+    (Value::YearsAndMonthsDuration(ls), Value::YearsAndMonthsDuration(rs)) => ls.partial_cmp(rs),
+    _ => None,
+  }
+}
+
+/// Returns `Some(true)` when the ordering of two values satisfies the predicate,
+/// `Some(false)` when it does not or when the ordering of comparable values can not be determined
+/// (like for date and time with and without time zone), and `None` when values are not comparable.
+pub fn ordered(lhs: &Value, rhs: &Value, predicate: fn(Ordering) -> bool) -> Option<bool> {
+  if is_comparable(lhs, rhs) {
+    Some(compare(lhs, rhs).is_some_and(predicate))
+  } else {
+    None
+  }
+}
+
+/// Returns `Some(true)` when the value belongs to the range with specified endpoints,
+/// or `None` when the value is not comparable with any of the endpoints.
+pub fn in_range(value: &Value, start: &Value, start_closed: bool, end: &Value, end_closed: bool) -> Option<bool> {
+  let start_ok = ordered(value, start, if start_closed { Ordering::is_ge } else { Ordering::is_gt })?;
+  let end_ok = ordered(value, end, if end_closed { Ordering::is_le } else { Ordering::is_lt })?;
+  Some(start_ok && end_ok)
+}
+
+/// Returns `true` when the types of two values are comparable.
+fn is_comparable(lhs: &Value, rhs: &Value) -> bool {
+  matches!(
+    (lhs, rhs),
+    (Value::Number(_), Value::Number(_))
+      | (Value::String(_), Value::String(_))
+      | (Value::Date(_) | Value::DateTime(_), Value::Date(_) | Value::DateTime(_))
+      | (Value::Time(_), Value::Time(_))
+      | (Value::DaysAndTimeDuration(_), Value::DaysAndTimeDuration(_))
+      | (Value::YearsAndMonthsDuration(_), Value::YearsAndMonthsDuration(_))
+  )
+}
+
+/// Compares the endpoint of unary comparison with the endpoint of the range,
+/// when the range has the same shape as the unary comparison.
+fn unary_equals(lhs: &Value, rhs: &Value, same_shape: bool) -> Option<bool> {
+  if same_shape {
+    equals(lhs, rhs)
+  } else {
+    Some(false)
+  }
+}
+
+/// Converts the date into date and time at midnight UTC.
+fn at_midnight(date: &FeelDate) -> FeelDateTime {
+  FeelDateTime::new(date.clone(), FeelTime::utc(0, 0, 0, 0))
+}
@@ -0,0 +1,88 @@
+//! # Environment of the evaluation
+//!
+//! Built-in function `env("NAME")` returns values of environment variables or secrets
+//! provided by the embedder, allowing parameterizing decisions by deployment environment
+//! without editing models. Values are resolved only by the resolver registered with
+//! [evaluate_with_environment], without registered resolver the function returns `null`.
+//! The operating system environment is never accessed directly, [OsEnvironment] exposes
+//! only explicitly allowed variables.
+
+use dmntk_feel::value_null;
+use dmntk_feel::values::Value;
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::rc::Rc;
+
+/// Resolver of environment variables and secrets provided by the embedder.
+pub trait EnvironmentResolver {
+  /// Returns the value of the variable with specified name, or `None` when the variable is not available.
+  fn resolve(&self, name: &str) -> Option<String>;
+}
+
+impl EnvironmentResolver for HashMap<String, String> {
+  /// Returns the value of the variable from the map.
+  fn resolve(&self, name: &str) -> Option<String> {
+    self.get(name).cloned()
+  }
+}
+
+/// Resolver of operating system environment variables, limited to allowed names.
+#[derive(Debug, Default, Clone)]
+pub struct OsEnvironment {
+  /// Names of variables that may be resolved.
+  allowed: BTreeSet<String>,
+}
+
+impl OsEnvironment {
+  /// Creates a resolver allowing access to variables with specified names.
+  pub fn new<I, S>(allowed: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    Self {
+      allowed: allowed.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+impl EnvironmentResolver for OsEnvironment {
+  /// Returns the value of the operating system environment variable, when allowed.
+  fn resolve(&self, name: &str) -> Option<String> {
+    if self.allowed.contains(name) {
+      std::env::var(name).ok()
+    } else {
+      None
+    }
+  }
+}
+
+thread_local! {
+  /// Resolver registered for the evaluation running in the current thread.
+  static ACTIVE_RESOLVER: RefCell<Option<Rc<dyn EnvironmentResolver>>> = const { RefCell::new(None) };
+}
+
+/// Runs the evaluation with specified resolver of environment variables.
+pub fn evaluate_with_environment<T>(resolver: Rc<dyn EnvironmentResolver>, evaluation: impl FnOnce() -> T) -> T {
+  /// Restores previously registered resolver also when the evaluation panics.
+  struct Guard(Option<Rc<dyn EnvironmentResolver>>);
+  impl Drop for Guard {
+    fn drop(&mut self) {
+      let previous = self.0.take();
+      ACTIVE_RESOLVER.with(|active| *active.borrow_mut() = previous);
+    }
+  }
+  let _guard = Guard(ACTIVE_RESOLVER.with(|active| active.replace(Some(resolver))));
+  evaluation()
+}
+
+/// Resolves the value of the environment variable with specified name.
+pub(crate) fn resolve(name: &str) -> Value {
+  match ACTIVE_RESOLVER.with(|active| active.borrow().clone()) {
+    Some(resolver) => match resolver.resolve(name) {
+      Some(value) => Value::String(value.into()),
+      None => value_null!("[env] variable '{}' is not available", name),
+    },
+    None => value_null!("[env] no environment resolver"),
+  }
+}
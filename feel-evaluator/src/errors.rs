@@ -1,25 +1,29 @@
-use dmntk_common::{DmntkError, ToErrorMessage};
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
 
 /// `FEEL` expressions evaluator errors.
 #[derive(ToErrorMessage)]
-struct FeelEvaluatorError(String);
+struct FeelEvaluatorError(Message);
 
 pub fn err_not_a_context() -> DmntkError {
-  FeelEvaluatorError("expected FEEL context as an input".to_string()).into()
+  FeelEvaluatorError(message!("expected FEEL context as an input")).into()
 }
 
 pub fn err_expected_positional_or_named_parameter() -> DmntkError {
-  FeelEvaluatorError("expected positional or named parameter".to_string()).into()
+  FeelEvaluatorError(message!("expected positional or named parameter")).into()
 }
 
 pub fn err_expected_ast_node_parameter_name(s: &str) -> DmntkError {
-  FeelEvaluatorError(format!("expected AstNode::ParameterName, actual node is {s}")).into()
+  FeelEvaluatorError(message!("expected AstNode::ParameterName, actual node is {}", s)).into()
 }
 
 pub fn err_expected_ast_node(expected: &str, actual: &str) -> DmntkError {
-  FeelEvaluatorError(format!("expected AST node {expected}, actual AST node is {actual}")).into()
+  FeelEvaluatorError(message!("expected AST node {}, actual AST node is {}", expected, actual)).into()
 }
 
 pub fn err_unexpected_ast_node(s: &str) -> DmntkError {
-  FeelEvaluatorError(format!("unexpected AST node in evaluator builder {s}")).into()
+  FeelEvaluatorError(message!("unexpected AST node in evaluator builder {}", s)).into()
+}
+
+pub fn err_resource_exhausted(resource: &str, limit: usize) -> DmntkError {
+  FeelEvaluatorError(message!("evaluation budget exhausted, maximum number of {} ({}) exceeded", resource, limit)).into()
 }
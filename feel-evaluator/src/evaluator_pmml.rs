@@ -8,6 +8,6 @@ pub fn evaluate_external_pmml_function(document: &str, model_name: &str, _argume
   match (document, model_name) {
     ("", _) => value_null!("PMML document not specified"),
     (_, "") => value_null!("PMML model name not specified"),
-    _ => Value::String(format!("PMML, document = {document}, model name = {model_name}")),
+    _ => Value::String(format!("PMML, document = {document}, model name = {model_name}").into()),
   }
 }
@@ -3,8 +3,9 @@ use crate::errors::err_not_a_context;
 use dmntk_common::Result;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
-use dmntk_feel::{Evaluator, FeelScope};
+use dmntk_feel::{Evaluator, FeelScope, Name};
 use dmntk_feel_parser::AstNode;
+use std::cell::Cell;
 
 /// Evaluates a [Value] from given [AstNode].
 pub fn evaluate(scope: &FeelScope, node: &AstNode) -> Result<Value> {
@@ -12,11 +13,23 @@ pub fn evaluate(scope: &FeelScope, node: &AstNode) -> Result<Value> {
   Ok(evaluator(scope))
 }
 
+thread_local! {
+  /// Number of evaluators prepared on the current thread.
+  static PREPARED_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
 /// Prepares an evaluator for given [AstNode].
 pub fn prepare(bx: &BuildContext, node: &AstNode) -> Result<Evaluator> {
+  PREPARED_COUNT.with(|count| count.set(count.get() + 1));
   crate::builders::build_evaluator(bx, node)
 }
 
+/// Returns the number of evaluators prepared so far on the current thread,
+/// the difference of two readings is the number of expressions compiled in between.
+pub fn prepared_count() -> usize {
+  PREPARED_COUNT.with(Cell::get)
+}
+
 /// Evaluates the sum of specified values.
 pub fn evaluate_sum(values: Vec<Value>) -> Value {
   crate::bifs::core::sum(&values)
@@ -34,7 +47,28 @@ pub fn evaluate_max(values: Vec<Value>) -> Value {
 
 /// Compares two values and returns `true` when the two `FEEL` values are equal.
 pub fn evaluate_equals(left: &Value, right: &Value) -> bool {
-  crate::builders::eval_ternary_equality(left, right).unwrap_or(false)
+  crate::comparison::equals(left, right).unwrap_or(false)
+}
+
+/// Evaluates unary tests against specified input value.
+///
+/// Unary tests have the same syntax as input entries of decision tables,
+/// like `< 10`, `[1..5]`, `"a","b"`, `not(1,2)` or `-`. The input value is available
+/// in unary tests under the name `?`, like in `? > 0` or `contains(?, "abc")`.
+/// Names used in unary tests are resolved in specified scope.
+/// Returns `true` when the input value satisfies the unary tests,
+/// `false` when it does not, or when the result is `null`.
+pub fn evaluate_unary_tests(input_value: &Value, tests: &str, scope: &FeelScope) -> Result<bool> {
+  let input_name = Name::from("?");
+  let unary_tests_node = dmntk_feel_parser::parse_unary_tests(scope, tests, false)?;
+  let node = AstNode::In(Box::new(AstNode::Name(input_name.clone())), Box::new(unary_tests_node));
+  let evaluator = crate::builders::build_evaluator(&BuildContext::default(), &node)?;
+  let mut ctx = FeelContext::default();
+  ctx.set_entry(&input_name, input_value.clone());
+  scope.push(ctx);
+  let value = evaluator(scope);
+  scope.pop();
+  Ok(value.is_true())
 }
 
 /// Evaluates a context from text containing `FEEL` expression.
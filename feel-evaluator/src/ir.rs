@@ -0,0 +1,209 @@
+//! # Intermediate representation of FEEL expressions
+//!
+//! Expressions built from literals, names, arithmetic operators, comparisons,
+//! conjunctions, disjunctions and `if` expressions are compiled into a compact tree
+//! of instructions, interpreted by a single `match` instead of calling a boxed closure
+//! for every node. Operators applied to constant operands are folded during compilation,
+//! operators applied to numbers are evaluated without moving values. Instructions share
+//! the semantics of operators with evaluators built from nested closures,
+//! so both backends always produce identical results.
+//!
+//! Expressions containing any other nodes are built as nested closures,
+//! but their compilable subexpressions are still compiled.
+//! Compilation is disabled when the crate is built with `closures` feature.
+
+use crate::builders::*;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_null, FeelNumber, FeelScope, Name};
+use dmntk_feel_parser::AstNode;
+
+/// Node of the intermediate representation.
+#[derive(Debug, Clone)]
+enum Instruction {
+  /// Constant value.
+  Const(Value),
+  /// Value of the name.
+  Load(Name),
+  /// Arithmetic negation.
+  Neg(Box<Instruction>),
+  /// Binary operator applied to two operands.
+  Binary(Operator, Box<Instruction>, Box<Instruction>),
+  /// Condition and branches of `if` expression.
+  If(Box<Instruction>, Box<Instruction>, Box<Instruction>),
+}
+
+impl Instruction {
+  /// Evaluates the instruction in specified scope.
+  fn run(&self, scope: &FeelScope) -> Value {
+    match self {
+      Instruction::Const(value) => value.clone(),
+      Instruction::Load(name) => eval_name(scope, name),
+      Instruction::Neg(lhs) => eval_neg(lhs.run(scope)),
+      Instruction::Binary(operator, lhs, rhs) => {
+        let lhv = lhs.run(scope);
+        if let Instruction::Const(rhv) = rhs.as_ref() {
+          if let (Value::Number(lh), Value::Number(rh)) = (&lhv, rhv) {
+            if let Some(value) = operator.apply_numbers(lh, rh) {
+              return value;
+            }
+          }
+          return operator.apply(lhv, rhv.clone());
+        }
+        operator.apply(lhv, rhs.run(scope))
+      }
+      Instruction::If(lhs, mhs, rhs) => match lhs.run(scope) {
+        Value::Boolean(true) => mhs.run(scope),
+        Value::Boolean(false) | Value::Null(_) => rhs.run(scope),
+        _ => value_null!("condition in 'if' expression is not a boolean value"),
+      },
+    }
+  }
+}
+
+/// Binary operators supported by the interpreter.
+#[derive(Debug, Copy, Clone)]
+enum Operator {
+  Add,
+  Sub,
+  Mul,
+  Div,
+  Exp,
+  Eq,
+  Nq,
+  Lt,
+  Le,
+  Gt,
+  Ge,
+  And,
+  Or,
+}
+
+impl Operator {
+  /// Applies the operator to specified values.
+  fn apply(self, lhv: Value, rhv: Value) -> Value {
+    if let (Value::Number(lh), Value::Number(rh)) = (&lhv, &rhv) {
+      if let Some(value) = self.apply_numbers(lh, rh) {
+        return value;
+      }
+    }
+    match self {
+      Operator::Add => eval_add(lhv, rhv),
+      Operator::Sub => eval_sub(lhv, rhv),
+      Operator::Mul => eval_mul(lhv, rhv),
+      Operator::Div => eval_div(lhv, rhv),
+      Operator::Exp => eval_exp(lhv, rhv),
+      Operator::Eq => eval_eq(lhv, rhv),
+      Operator::Nq => eval_nq(lhv, rhv),
+      Operator::Lt => eval_lt(lhv, rhv),
+      Operator::Le => eval_le(lhv, rhv),
+      Operator::Gt => eval_gt(lhv, rhv),
+      Operator::Ge => eval_ge(lhv, rhv),
+      Operator::And => eval_and(lhv, rhv),
+      Operator::Or => eval_or(lhv, rhv),
+    }
+  }
+
+  /// Applies the operator to two numbers, without moving values.
+  /// Returns `None` when the result must be evaluated by [Operator::apply].
+  fn apply_numbers(self, lh: &FeelNumber, rh: &FeelNumber) -> Option<Value> {
+    match self {
+      Operator::Add => Some(Value::Number(*lh + *rh)),
+      Operator::Sub => Some(Value::Number(*lh - *rh)),
+      Operator::Mul => Some(Value::Number(*lh * *rh)),
+      Operator::Eq => Some(Value::Boolean(lh == rh)),
+      Operator::Nq => Some(Value::Boolean(lh != rh)),
+      Operator::Lt => Some(Value::Boolean(lh < rh)),
+      Operator::Le => Some(Value::Boolean(lh <= rh)),
+      Operator::Gt => Some(Value::Boolean(lh > rh)),
+      Operator::Ge => Some(Value::Boolean(lh >= rh)),
+      _ => None,
+    }
+  }
+}
+
+/// Compiled expression.
+#[derive(Debug, Clone)]
+pub(crate) struct Program {
+  /// Root instruction of the program.
+  root: Instruction,
+}
+
+impl Program {
+  /// Compiles the expression represented by specified node.
+  ///
+  /// Returns `None` when the node is not an operator (evaluating literals
+  /// and names with closures is cheaper) or when the expression contains
+  /// nodes not supported by the interpreter.
+  pub fn compile(bx: &BuildContext, node: &AstNode) -> Option<Program> {
+    if !is_operator(node) {
+      return None;
+    }
+    Some(Program { root: compile(bx, node)? })
+  }
+
+  /// Runs the program in specified scope.
+  pub fn run(&self, scope: &FeelScope) -> Value {
+    self.root.run(scope)
+  }
+}
+
+/// Returns `true` when specified node is an operator supported by the interpreter.
+fn is_operator(node: &AstNode) -> bool {
+  matches!(
+    node,
+    AstNode::Add(..)
+      | AstNode::Sub(..)
+      | AstNode::Mul(..)
+      | AstNode::Div(..)
+      | AstNode::Exp(..)
+      | AstNode::Eq(..)
+      | AstNode::Nq(..)
+      | AstNode::Lt(..)
+      | AstNode::Le(..)
+      | AstNode::Gt(..)
+      | AstNode::Ge(..)
+      | AstNode::And(..)
+      | AstNode::Or(..)
+      | AstNode::Neg(..)
+      | AstNode::If(..)
+  )
+}
+
+/// Compiles specified node into instruction.
+fn compile(bx: &BuildContext, node: &AstNode) -> Option<Instruction> {
+  Some(match node {
+    AstNode::Null => Instruction::Const(Value::Null(None)),
+    AstNode::Boolean(value) => Instruction::Const(Value::Boolean(*value)),
+    AstNode::Numeric(lhs, rhs) => Instruction::Const(Value::Number(format!("{lhs}.{rhs}").parse::<FeelNumber>().ok()?)),
+    AstNode::String(value) => Instruction::Const(Value::String(bx.intern(value))),
+    AstNode::Name(name) => Instruction::Load(name.clone()),
+    AstNode::Neg(lhs) => match compile(bx, lhs)? {
+      Instruction::Const(value) => Instruction::Const(eval_neg(value)),
+      lhi => Instruction::Neg(Box::new(lhi)),
+    },
+    AstNode::Add(lhs, rhs) => compile_binary(bx, Operator::Add, lhs, rhs)?,
+    AstNode::Sub(lhs, rhs) => compile_binary(bx, Operator::Sub, lhs, rhs)?,
+    AstNode::Mul(lhs, rhs) => compile_binary(bx, Operator::Mul, lhs, rhs)?,
+    AstNode::Div(lhs, rhs) => compile_binary(bx, Operator::Div, lhs, rhs)?,
+    AstNode::Exp(lhs, rhs) => compile_binary(bx, Operator::Exp, lhs, rhs)?,
+    AstNode::Eq(lhs, rhs) => compile_binary(bx, Operator::Eq, lhs, rhs)?,
+    AstNode::Nq(lhs, rhs) => compile_binary(bx, Operator::Nq, lhs, rhs)?,
+    AstNode::Lt(lhs, rhs) => compile_binary(bx, Operator::Lt, lhs, rhs)?,
+    AstNode::Le(lhs, rhs) => compile_binary(bx, Operator::Le, lhs, rhs)?,
+    AstNode::Gt(lhs, rhs) => compile_binary(bx, Operator::Gt, lhs, rhs)?,
+    AstNode::Ge(lhs, rhs) => compile_binary(bx, Operator::Ge, lhs, rhs)?,
+    AstNode::And(lhs, rhs) => compile_binary(bx, Operator::And, lhs, rhs)?,
+    AstNode::Or(lhs, rhs) => compile_binary(bx, Operator::Or, lhs, rhs)?,
+    AstNode::If(lhs, mhs, rhs) => Instruction::If(Box::new(compile(bx, lhs)?), Box::new(compile(bx, mhs)?), Box::new(compile(bx, rhs)?)),
+    AstNode::EvaluatedExpression(lhs) => compile(bx, lhs)?,
+    _ => return None,
+  })
+}
+
+/// Compiles binary operator, folds the operator applied to constant operands.
+fn compile_binary(bx: &BuildContext, operator: Operator, lhs: &AstNode, rhs: &AstNode) -> Option<Instruction> {
+  Some(match (compile(bx, lhs)?, compile(bx, rhs)?) {
+    (Instruction::Const(lhv), Instruction::Const(rhv)) => Instruction::Const(operator.apply(lhv, rhv)),
+    (lhi, rhi) => Instruction::Binary(operator, Box::new(lhi), Box::new(rhi)),
+  })
+}
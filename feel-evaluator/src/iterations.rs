@@ -1,3 +1,4 @@
+use crate::budget;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::{Value, Values};
 use dmntk_feel::{Evaluator, FeelScope, Name};
@@ -80,6 +81,9 @@ impl FeelIterator {
           }
         }
         if !is_empty_iteration {
+          if !budget::consume_step() {
+            break 'outer;
+          }
           handler(&iteration_context);
         }
         let last_iteration_state_index = self.iteration_states.len() - 1;
@@ -162,7 +166,9 @@ impl ForExpressionEvaluator {
       scope.push(iteration_context.clone());
       let iteration_value = evaluator(scope);
       scope.pop();
-      results.push(iteration_value);
+      if budget::consume_list_elements(1) {
+        results.push(iteration_value);
+      }
     });
     results
   }
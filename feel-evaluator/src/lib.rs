@@ -4,16 +4,26 @@
 extern crate dmntk_macros;
 
 mod bifs;
+mod budget;
 mod builders;
+mod comparison;
+mod environment;
 mod errors;
+#[cfg(feature = "java")]
 mod evaluator_java;
 mod evaluator_pmml;
 mod evaluators;
+#[cfg(not(feature = "closures"))]
+mod ir;
 mod iterations;
 mod macros;
 
 #[cfg(test)]
 mod tests;
 
+pub use crate::budget::{evaluate_with_budget, Budget};
 pub use crate::builders::BuildContext;
-pub use crate::evaluators::{evaluate, evaluate_context, evaluate_context_node, evaluate_equals, evaluate_max, evaluate_min, evaluate_sum, prepare};
+pub use crate::environment::{evaluate_with_environment, EnvironmentResolver, OsEnvironment};
+pub use crate::evaluators::{
+  evaluate, evaluate_context, evaluate_context_node, evaluate_equals, evaluate_max, evaluate_min, evaluate_sum, evaluate_unary_tests, prepare, prepared_count,
+};
@@ -1,5 +1,5 @@
 use super::super::*;
-use dmntk_feel::scope;
+use dmntk_feel::{evaluate_at_conformance_level, scope, ConformanceLevel};
 
 #[test]
 fn _0001() {
@@ -104,3 +104,40 @@ fn _0015() {
 fn _0016() {
   te_null(false, &scope!(), r#"abs(1,2)"#, r#"expected 1 parameters, actual number of parameters is 2"#);
 }
+
+#[test]
+fn _0017() {
+  te_number(false, &scope!(), r#"abs([-1])"#, 1, 0);
+}
+
+#[test]
+fn _0018() {
+  te_null(
+    false,
+    &scope!(),
+    r#"abs([[-1]])"#,
+    r#"[core::abs] invalid argument type, expected number, actual type is list<number>"#,
+  );
+}
+
+#[test]
+fn _0019() {
+  te_null(
+    false,
+    &scope!(),
+    r#"abs([-1, 2])"#,
+    r#"[core::abs] invalid argument type, expected number, actual type is list<number>"#,
+  );
+}
+
+#[test]
+fn _0020() {
+  evaluate_at_conformance_level(ConformanceLevel::Level2, || {
+    te_null(
+      false,
+      &scope!(),
+      r#"abs([-1])"#,
+      r#"[core::abs] invalid argument type, expected number, actual type is list<number>"#,
+    );
+  });
+}
@@ -148,7 +148,12 @@ fn _0022() {
 
 #[test]
 fn _0023() {
-  te_null(false, &scope!(), r#"string(date and time("2016-12-24T23:59:00+14:01"))"#, r#""#);
+  te_null(
+    false,
+    &scope!(),
+    r#"string(date and time("2016-12-24T23:59:00+14:01"))"#,
+    r#"[core::date and time] invalid date or date and time '2016-12-24T23:59:00+14:01'"#,
+  );
 }
 
 #[test]
@@ -158,7 +163,12 @@ fn _0024() {
 
 #[test]
 fn _0025() {
-  te_null(false, &scope!(), r#"string(date and time("2016-12-24T23:59:00-14:01"))"#, r#""#);
+  te_null(
+    false,
+    &scope!(),
+    r#"string(date and time("2016-12-24T23:59:00-14:01"))"#,
+    r#"[core::date and time] invalid date or date and time '2016-12-24T23:59:00-14:01'"#,
+  );
 }
 
 #[test]
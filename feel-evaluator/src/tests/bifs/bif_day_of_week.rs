@@ -97,7 +97,7 @@ fn _0019() {
     false,
     &scope!(),
     r#"day of week(date(2019,2,30))"#,
-    r#"[core::day of week] invalid argument type, expected date, date and time, actual type is Null"#,
+    r#"[core::day of week] invalid argument type, expected date, date and time, actual type is Null; caused by: [core::date_3] invalid date y=2019 m=2 d=30"#,
   );
 }
 
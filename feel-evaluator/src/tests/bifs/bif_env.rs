@@ -0,0 +1,61 @@
+use super::super::*;
+use crate::{evaluate_with_environment, EnvironmentResolver, OsEnvironment};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Returns a resolver with a single variable.
+fn resolver() -> Rc<dyn EnvironmentResolver> {
+  Rc::new(HashMap::from([("REGION".to_string(), "eu-west".to_string())]))
+}
+
+#[test]
+fn _0001() {
+  let scope = &te_scope("{}");
+  te_null(false, scope, r#"env("REGION")"#, "[env] no environment resolver");
+}
+
+#[test]
+fn _0002() {
+  let scope = &te_scope("{}");
+  evaluate_with_environment(resolver(), || te_string(false, scope, r#"env("REGION")"#, "eu-west"));
+}
+
+#[test]
+fn _0003() {
+  let scope = &te_scope("{}");
+  evaluate_with_environment(resolver(), || te_string(false, scope, r#"env(name: "REGION")"#, "eu-west"));
+}
+
+#[test]
+fn _0004() {
+  let scope = &te_scope("{}");
+  evaluate_with_environment(resolver(), || te_null(false, scope, r#"env("ZONE")"#, "[env] variable 'ZONE' is not available"));
+}
+
+#[test]
+fn _0005() {
+  let scope = &te_scope("{}");
+  evaluate_with_environment(resolver(), || {
+    te_null(false, scope, r#"env(1)"#, "[core::env] invalid argument type, expected string, actual type is number")
+  });
+}
+
+#[test]
+fn _0006() {
+  let scope = &te_scope("{}");
+  evaluate_with_environment(resolver(), || te_null(false, scope, r#"env()"#, "expected 1 parameters, actual number of parameters is 0"));
+}
+
+#[test]
+fn _0007() {
+  let scope = &te_scope("{}");
+  let resolver = Rc::new(OsEnvironment::new(["CARGO_PKG_NAME"]));
+  evaluate_with_environment(resolver.clone(), || te_string(false, scope, r#"env("CARGO_PKG_NAME")"#, "dmntk-feel-evaluator"));
+  evaluate_with_environment(resolver, || te_null(false, scope, r#"env("PATH")"#, "[env] variable 'PATH' is not available"));
+}
+
+#[test]
+fn _0008() {
+  let scope = &te_scope(r#"{env: function(name) "shadowed " + name}"#);
+  te_string(false, scope, r#"env("REGION")"#, "shadowed REGION");
+}
@@ -227,7 +227,7 @@ fn _0043() {
     false,
     &scope!(),
     r#"is(Null,duration("P1D"))"#,
-    "[core::is] invalid argument type, expected scalar, actual type is Null",
+    "[core::is] invalid argument type, expected scalar, actual type is Null; caused by: context has no value for key 'Null'",
   );
 }
 
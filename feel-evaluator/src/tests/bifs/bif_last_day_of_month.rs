@@ -0,0 +1,52 @@
+use super::super::*;
+use dmntk_feel::scope;
+
+#[test]
+fn _0001() {
+  te_date(false, &scope!(), r#"last day of month(date(2019,9,17))"#, 2019, 9, 30);
+}
+
+#[test]
+fn _0002() {
+  te_date(false, &scope!(), r#"last day of month(date and time("2019-09-17T12:30:00"))"#, 2019, 9, 30);
+}
+
+#[test]
+fn _0003() {
+  te_date(false, &scope!(), r#"last day of month(date: date(2024,2,29))"#, 2024, 2, 29);
+}
+
+#[test]
+fn _0004() {
+  te_null(
+    false,
+    &scope!(),
+    r#"last day of month(10)"#,
+    r#"[core::last day of month] invalid argument type, expected date, date and time, actual type is number"#,
+  );
+}
+
+#[test]
+fn _0005() {
+  te_null(false, &scope!(), r#"last day of month(value: date(2019,9,17))"#, r#"parameter 'date' not found"#);
+}
+
+#[test]
+fn _0006() {
+  te_null(false, &scope!(), r#"last day of month()"#, r#"expected 1 parameters, actual number of parameters is 0"#);
+}
+
+#[test]
+fn _0007() {
+  te_date(false, &scope!(), r#"last day of month(date(2023,2,10))"#, 2023, 2, 28);
+}
+
+#[test]
+fn _0008() {
+  te_date(false, &scope!(), r#"last day of month(date(2000,2,10))"#, 2000, 2, 29);
+}
+
+#[test]
+fn _0009() {
+  te_date(false, &scope!(), r#"last day of month(date(1900,2,10))"#, 1900, 2, 28);
+}
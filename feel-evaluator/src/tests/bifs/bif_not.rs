@@ -57,3 +57,8 @@ fn _0009() {
 fn _0010() {
   te_null(false, &scope!(), "not(12)", r#"[core::not] invalid argument type, expected boolean, actual type is number"#);
 }
+
+#[test]
+fn _0012() {
+  te_bool(false, &scope!(), r#"not([true])"#, false);
+}
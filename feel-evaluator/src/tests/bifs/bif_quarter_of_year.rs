@@ -0,0 +1,47 @@
+use super::super::*;
+use dmntk_feel::scope;
+
+#[test]
+fn _0001() {
+  te_number(false, &scope!(), r#"quarter of year(date(2019,1,1))"#, 1, 0);
+}
+
+#[test]
+fn _0002() {
+  te_number(false, &scope!(), r#"quarter of year(date(2019,3,31))"#, 1, 0);
+}
+
+#[test]
+fn _0003() {
+  te_number(false, &scope!(), r#"quarter of year(date(2019,4,1))"#, 2, 0);
+}
+
+#[test]
+fn _0004() {
+  te_number(false, &scope!(), r#"quarter of year(date and time("2019-09-17T00:00:00"))"#, 3, 0);
+}
+
+#[test]
+fn _0005() {
+  te_number(false, &scope!(), r#"quarter of year(date: date(2019,12,31))"#, 4, 0);
+}
+
+#[test]
+fn _0006() {
+  te_null(
+    false,
+    &scope!(),
+    r#"quarter of year("2019-09-17")"#,
+    r#"[core::quarter of year] invalid argument type, expected date, date and time, actual type is string"#,
+  );
+}
+
+#[test]
+fn _0007() {
+  te_null(
+    false,
+    &scope!(),
+    r#"quarter of year(date(2019,1,1), 2)"#,
+    r#"expected 1 parameters, actual number of parameters is 2"#,
+  );
+}
@@ -100,3 +100,44 @@ fn _0019() {
 fn _0020() {
   te_null(false, &scope!(), "string(f:1.1)", r#"parameter 'from' not found"#);
 }
+
+#[test]
+fn _0021() {
+  let scope = &scope!();
+  te_string(false, scope, "string(-0)", "0");
+  te_string(false, scope, "string(-0.0)", "0");
+  te_string(false, scope, "string(-0.00)", "0");
+  te_string(false, scope, "string(0 * -1)", "0");
+  te_string(false, scope, "string(-1 * 0.0)", "0");
+  te_string(false, scope, "string(-0.5 * 0)", "0");
+}
+
+#[test]
+fn _0022() {
+  let scope = &scope!();
+  te_string(false, scope, "string(0.0)", "0.0");
+  te_string(false, scope, "string(1.10)", "1.10");
+  te_string(false, scope, "string(1 - 1.0)", "0");
+  te_string(false, scope, "string(2.5 * 2)", "5");
+  te_string(false, scope, "string(100 / 10)", "10");
+  te_string(false, scope, "string(10 / 4)", "2.5");
+  te_string(false, scope, "string(2 ** -3)", "0.125");
+  te_string(false, scope, "string(1 / 3)", "0.3333333333333333333333333333333333");
+  te_string(false, scope, "string(decimal(1 / 3, 2))", "0.33");
+}
+
+#[test]
+fn _0023() {
+  let scope = &scope!();
+  te_string(false, scope, "string(10 ** 50)", "100000000000000000000000000000000000000000000000000");
+  te_string(false, scope, "string(10 ** -20)", "0.00000000000000000001");
+  te_string(false, scope, r#"string(number("1E+30", null, null))"#, "1000000000000000000000000000000");
+  te_string(
+    false,
+    scope,
+    "string(0.000000000000000000000000000000000000000123)",
+    "0.000000000000000000000000000000000000000123",
+  );
+  te_string(false, scope, "string(123456789012345678901234567890123456789)", "123456789012345678901234567890123500000");
+  te_string(false, scope, "string(1000000000000000000000000000000000 * 1000)", "1000000000000000000000000000000000000");
+}
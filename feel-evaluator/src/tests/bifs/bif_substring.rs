@@ -1,5 +1,5 @@
 use super::super::*;
-use dmntk_feel::scope;
+use dmntk_feel::{evaluate_at_conformance_level, scope, ConformanceLevel};
 
 #[test]
 fn _0001() {
@@ -183,10 +183,22 @@ fn _0024() {
 
 #[test]
 fn _0025() {
-  te_null(
-    false,
-    &scope!(),
-    r#"substring(["homeless"], 9, 2)"#,
-    r#"sublist: expected string, actual value type is list<string>"#,
-  );
+  evaluate_at_conformance_level(ConformanceLevel::Level2, || {
+    te_null(
+      false,
+      &scope!(),
+      r#"substring(["homeless"], 9, 2)"#,
+      r#"sublist: expected string, actual value type is list<string>"#,
+    );
+  });
+}
+
+#[test]
+fn _0026() {
+  te_string(false, &scope!(), r#"substring(["homeless"], 3, 2)"#, "me");
+}
+
+#[test]
+fn _0027() {
+  te_string(false, &scope!(), r#"substring(string: ["homeless"], start position: [3])"#, "meless");
 }
@@ -1,5 +1,5 @@
 use super::super::*;
-use dmntk_feel::scope;
+use dmntk_feel::{evaluate_at_conformance_level, scope, ConformanceLevel};
 
 #[test]
 fn _0001() {
@@ -43,20 +43,34 @@ fn _0007() {
 
 #[test]
 fn _0008() {
-  te_null(
-    false,
-    &scope!(),
-    r#"substring after(["foobar"], "ob")"#,
-    r#"substring after: expected string, actual input type is: list<string>"#,
-  );
+  evaluate_at_conformance_level(ConformanceLevel::Level2, || {
+    te_null(
+      false,
+      &scope!(),
+      r#"substring after(["foobar"], "ob")"#,
+      r#"substring after: expected string, actual input type is: list<string>"#,
+    );
+  });
 }
 
 #[test]
 fn _0009() {
-  te_null(
-    false,
-    &scope!(),
-    r#"substring after("foobar", ["ob"])"#,
-    r#"substring after: expected string, actual match type is: list<string>"#,
-  );
+  evaluate_at_conformance_level(ConformanceLevel::Level2, || {
+    te_null(
+      false,
+      &scope!(),
+      r#"substring after("foobar", ["ob"])"#,
+      r#"substring after: expected string, actual match type is: list<string>"#,
+    );
+  });
+}
+
+#[test]
+fn _0010() {
+  te_string(false, &scope!(), r#"substring after(["foobar"], "ob")"#, "ar");
+}
+
+#[test]
+fn _0011() {
+  te_string(false, &scope!(), r#"substring after("foobar", ["ob"])"#, "ar");
 }
@@ -324,3 +324,18 @@ fn _0055() {
 fn _0056() {
   te_null(false, &scope!(), r#"time(23,59,45,@"P3000000000000000000D")"#, "");
 }
+
+#[test]
+fn _0057() {
+  te_null(false, &scope!(), r#"time(23,59,45,duration("PT19H"))"#, "[core::time_4] invalid time offset: PT19H");
+}
+
+#[test]
+fn _0058() {
+  te_time(
+    false,
+    &scope!(),
+    r#"time(23,59,45,duration("-PT11H"))"#,
+    FeelTime::offset_opt(23, 59, 45, 0, -39_600).unwrap(),
+  );
+}
@@ -1,5 +1,5 @@
 use super::super::*;
-use dmntk_feel::scope;
+use dmntk_feel::{evaluate_at_conformance_level, scope, ConformanceLevel};
 
 #[test]
 fn _0001() {
@@ -40,3 +40,20 @@ fn _0006() {
 fn _0007() {
   te_null(false, &scope!(), r#"upper case(s: "ABc")"#, r#"parameter 'string' not found"#);
 }
+
+#[test]
+fn _0008() {
+  te_string(false, &scope!(), r#"upper case(string: ["abc"])"#, "ABC");
+}
+
+#[test]
+fn _0009() {
+  evaluate_at_conformance_level(ConformanceLevel::Level2, || {
+    te_null(
+      false,
+      &scope!(),
+      r#"upper case(["abc"])"#,
+      r#"[core::upper case] invalid argument type, expected string, actual type is list<string>"#,
+    );
+  });
+}
@@ -20,10 +20,13 @@ mod bif_distinct_values;
 mod bif_duration;
 mod bif_during;
 mod bif_ends_with;
+mod bif_env;
 mod bif_even;
 mod bif_exp;
 mod bif_finished_by;
 mod bif_finishes;
+#[cfg(feature = "calendar")]
+mod bif_first_day_of_month;
 mod bif_flatten;
 mod bif_floor;
 mod bif_get_entries;
@@ -32,6 +35,8 @@ mod bif_includes;
 mod bif_index_of;
 mod bif_insert_before;
 mod bif_is;
+#[cfg(feature = "calendar")]
+mod bif_last_day_of_month;
 mod bif_list_contains;
 mod bif_log;
 mod bif_lower_case;
@@ -52,6 +57,8 @@ mod bif_overlaps;
 mod bif_overlaps_after;
 mod bif_overlaps_before;
 mod bif_product;
+#[cfg(feature = "calendar")]
+mod bif_quarter_of_year;
 mod bif_remove;
 mod bif_replace;
 mod bif_reverse;
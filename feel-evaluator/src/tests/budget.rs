@@ -0,0 +1,95 @@
+use super::*;
+use crate::{evaluate_with_budget, Budget};
+use std::time::Duration;
+
+/// Utility function that evaluates the expression within specified budget.
+fn te_budget(budget: Budget, input: &str) -> dmntk_common::Result<Value> {
+  let scope = &te_scope("{}");
+  let node = dmntk_feel_parser::parse_expression(scope, input, false).unwrap();
+  let evaluator = build_evaluator(&BuildContext::default(), &node).unwrap();
+  evaluate_with_budget(budget, || evaluator(scope))
+}
+
+#[test]
+fn _0001() {
+  let value = te_budget(Budget::default(), "for i in 1..100 return i * 2").unwrap();
+  assert!(matches!(value, Value::List(items) if items.len() == 100));
+}
+
+#[test]
+fn _0002() {
+  let value = te_budget(Budget::default().with_max_steps(100), "for i in 1..100 return i * 2").unwrap();
+  assert!(matches!(value, Value::List(items) if items.len() == 100));
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    "<FeelEvaluatorError> evaluation budget exhausted, maximum number of evaluation steps (99) exceeded",
+    te_budget(Budget::default().with_max_steps(99), "for i in 1..100 return i * 2").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    "<FeelEvaluatorError> evaluation budget exhausted, maximum number of evaluation steps (1000) exceeded",
+    te_budget(Budget::default().with_max_steps(1000), "for i in 1..1000000000, j in 1..1000000000 return i + j")
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_eq!(
+    "<FeelEvaluatorError> evaluation budget exhausted, maximum number of evaluation steps (10) exceeded",
+    te_budget(Budget::default().with_max_steps(10), "{f: function(n) if n = 0 then 0 else f(n - 1), r: f(100)}.r")
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_eq!(
+    "<FeelEvaluatorError> evaluation budget exhausted, maximum number of list elements (5) exceeded",
+    te_budget(Budget::default().with_max_list_elements(5), "[1, 2, 3, [4, 5, 6]]").unwrap_err().to_string()
+  );
+  assert!(te_budget(Budget::default().with_max_list_elements(7), "[1, 2, 3, [4, 5, 6]]").is_ok());
+}
+
+#[test]
+fn _0007() {
+  assert_eq!(
+    "<FeelEvaluatorError> evaluation budget exhausted, maximum number of context entries (2) exceeded",
+    te_budget(Budget::default().with_max_context_entries(2), "{a: 1, b: {c: 2}}").unwrap_err().to_string()
+  );
+  assert!(te_budget(Budget::default().with_max_context_entries(3), "{a: 1, b: {c: 2}}").is_ok());
+}
+
+#[test]
+fn _0008() {
+  // budget is not applied outside evaluate_with_budget
+  let scope = &te_scope("{}");
+  te_number(false, scope, "count(for i in 1..1000 return i)", 1000, 0);
+}
+
+#[test]
+fn _0009() {
+  assert_eq!(
+    "<FeelEvaluatorError> evaluation budget exhausted, maximum number of evaluation milliseconds (10) exceeded",
+    te_budget(
+      Budget::default().with_max_duration(Duration::from_millis(10)),
+      "for i in 1..1000000000, j in 1..1000000000 return i + j"
+    )
+    .unwrap_err()
+    .to_string()
+  );
+}
+
+#[test]
+fn _0010() {
+  let value = te_budget(Budget::default().with_max_duration(Duration::from_secs(60)), "for i in 1..100 return i * 2").unwrap();
+  assert!(matches!(value, Value::List(items) if items.len() == 100));
+}
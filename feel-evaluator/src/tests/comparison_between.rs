@@ -280,3 +280,8 @@ fn _0039() {
     "unexpected value type in 'between' operator: function<>->Any",
   );
 }
+
+#[test]
+fn _0040() {
+  te_bool(false, &scope!(), r#" 1 between (if 2 between 1 and 3 then 0 else 5) and 2 "#, true);
+}
@@ -350,3 +350,43 @@ fn _0064() {
 fn _0065() {
   te_null(false, &scope!(), r#" (1..2] = 10 "#, r#"equal err '(1..2]' =?= '10'"#);
 }
+
+#[test]
+fn _0066() {
+  te_bool(false, &scope!(), "null = 100", false);
+}
+
+#[test]
+fn _0067() {
+  te_bool(false, &scope!(), r#" @"2018-12-08" = @"2018-12-08T00:00:00Z" "#, true);
+}
+
+#[test]
+fn _0068() {
+  te_bool(false, &scope!(), r#" @"2018-12-08T00:00:00Z" = @"2018-12-08" "#, true);
+}
+
+#[test]
+fn _0069() {
+  te_bool(false, &scope!(), r#" @"2018-12-08" = @"2018-12-08T10:11:12Z" "#, false);
+}
+
+#[test]
+fn _0070() {
+  te_bool(false, &scope!(), r#"{a: [1, {b: @"2018-12-08"}]} = {a: [1, {b: @"2018-12-08"}]}"#, true);
+}
+
+#[test]
+fn _0071() {
+  te_bool(false, &scope!(), r#"[{a: 1}, {a: 2}] = [{a: 1}, {a: 3}]"#, false);
+}
+
+#[test]
+fn _0072() {
+  te_null(false, &scope!(), r#"[1, "a"] = [1, 2]"#, r#"equal err '[1, "a"]' =?= '[1, 2]'"#);
+}
+
+#[test]
+fn _0073() {
+  te_null(false, &scope!(), r#"{a: 1, b: "a"} = {a: 1, b: 2}"#, r#"equal err '{a: 1, b: "a"}' =?= '{a: 1, b: 2}'"#);
+}
@@ -225,3 +225,13 @@ fn _0044() {
 fn _0045() {
   te_bool(false, &scope!(), r#" @"P1Y" >= @"P2Y" "#, false);
 }
+
+#[test]
+fn _0046() {
+  te_bool(false, &scope!(), r#" @"2018-12-08" >= @"2018-12-08T00:00:00Z" "#, true);
+}
+
+#[test]
+fn _0047() {
+  te_bool(false, &scope!(), r#" @"2018-12-09T00:00:00Z" >= @"2018-12-08" "#, true);
+}
@@ -515,3 +515,18 @@ fn _0086() {
 fn _0087() {
   te_null(false, &scope!(), r#" 10 in [(function() 1)] "#, "eval_in_list");
 }
+
+#[test]
+fn _0088() {
+  te_bool(false, &scope!(), r#" @"2018-12-08T10:11:12Z" in [@"2018-12-08"..@"2018-12-09"] "#, true);
+}
+
+#[test]
+fn _0089() {
+  te_bool(false, &scope!(), r#" @"2018-12-08" in > @"2018-12-07T23:59:59Z" "#, true);
+}
+
+#[test]
+fn _0090() {
+  te_bool(false, &scope!(), r#" @"2018-12-08" in @"2018-12-08T00:00:00Z" "#, true);
+}
@@ -185,3 +185,18 @@ fn _0036() {
 fn _0037() {
   te_bool(false, &scope!(), r#" @"P1Y" < @"P2Y" "#, true);
 }
+
+#[test]
+fn _0038() {
+  te_bool(false, &scope!(), r#" @"2018-12-08" < @"2018-12-08T10:11:12Z" "#, true);
+}
+
+#[test]
+fn _0039() {
+  te_bool(false, &scope!(), r#" @"2018-12-08T10:11:12Z" < @"2018-12-08" "#, false);
+}
+
+#[test]
+fn _0040() {
+  te_null(false, &scope!(), r#" @"2018-12-08" < @"10:11:12" "#, "eval_less_then_date");
+}
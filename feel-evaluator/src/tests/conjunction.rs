@@ -137,3 +137,37 @@ fn test_0023() {
   let scope = &te_scope("{}");
   te_bool(false, scope, "(1 > 2) and (3 between 1 and 2) or true", true);
 }
+
+#[test]
+fn test_0024() {
+  let scope = &te_scope("{}");
+  te_null(false, scope, r#"true and (1 + "x")"#, r#"incompatible types in addition: 1(number) + "x"(string)"#);
+}
+
+#[test]
+fn test_0025() {
+  let scope = &te_scope("{}");
+  te_null(
+    false,
+    scope,
+    r#"true and (true and (1 + "x"))"#,
+    r#"incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn test_0026() {
+  let scope = &te_scope("{}");
+  te_null(
+    false,
+    scope,
+    r#"(false or (1 + "x")) and true"#,
+    r#"incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn test_0027() {
+  let scope = &te_scope("{}");
+  te_bool(false, scope, r#"false and (true and (1 + "x"))"#, false);
+}
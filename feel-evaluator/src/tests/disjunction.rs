@@ -137,3 +137,37 @@ fn test_0023() {
   let scope = &te_scope("{}");
   te_bool(false, scope, "((1 < 2) or (3 > 1)) or false", true);
 }
+
+#[test]
+fn test_0024() {
+  let scope = &te_scope("{}");
+  te_null(false, scope, r#"false or (1 + "x")"#, r#"incompatible types in addition: 1(number) + "x"(string)"#);
+}
+
+#[test]
+fn test_0025() {
+  let scope = &te_scope("{}");
+  te_null(
+    false,
+    scope,
+    r#"false or (false or (1 + "x"))"#,
+    r#"incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn test_0026() {
+  let scope = &te_scope("{}");
+  te_null(
+    false,
+    scope,
+    r#"(true and (1 + "x")) or false"#,
+    r#"incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn test_0027() {
+  let scope = &te_scope("{}");
+  te_bool(false, scope, r#"true or (false or (1 + "x"))"#, true);
+}
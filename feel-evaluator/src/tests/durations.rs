@@ -0,0 +1,201 @@
+use super::*;
+use dmntk_feel::scope;
+
+/// Number of cases checked for each property.
+const CASES: usize = 500;
+
+/// Simple deterministic pseudo-random generator (xorshift),
+/// so failing properties are reproducible between runs.
+struct Generator(u64);
+
+impl Generator {
+  /// Returns the next pseudo-random number in range `min..=max`.
+  fn next(&mut self, min: i64, max: i64) -> i64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    min + (self.0 % (max - min + 1) as u64) as i64
+  }
+
+  /// Returns the days and time duration literal with random components and its total number of seconds.
+  fn dt_duration(&mut self) -> (String, i64) {
+    let (days, hours, minutes, seconds) = (self.next(0, 400), self.next(0, 100), self.next(0, 200), self.next(0, 5000));
+    let sign = if self.next(0, 1) == 0 { 1 } else { -1 };
+    let total = sign * (days * SECONDS_IN_DAY + hours * SECONDS_IN_HOUR + minutes * SECONDS_IN_MINUTE + seconds);
+    let text = format!(r#"duration("{}P{days}DT{hours}H{minutes}M{seconds}S")"#, if sign < 0 { "-" } else { "" });
+    (text, total)
+  }
+
+  /// Returns the years and months duration literal with random components and its total number of months.
+  fn ym_duration(&mut self) -> (String, i64) {
+    let (years, months) = (self.next(0, 200), self.next(0, 100));
+    let sign = if self.next(0, 1) == 0 { 1 } else { -1 };
+    let total = sign * (years * 12 + months);
+    let text = format!(r#"duration("{}P{years}Y{months}M")"#, if sign < 0 { "-" } else { "" });
+    (text, total)
+  }
+}
+
+#[test]
+fn _0001() {
+  // days and time durations are normalized to total number of seconds
+  let mut generator = Generator(0x2545_F491_4F6C_DD1D);
+  for _ in 0..CASES {
+    let (text, total) = generator.dt_duration();
+    te_days_and_time_duration(false, &scope!(), &text, total < 0, total.abs(), 0);
+    te_bool(
+      false,
+      &scope!(),
+      &format!(r#"{text} = duration("{}PT{}S")"#, if total < 0 { "-" } else { "" }, total.abs()),
+      true,
+    );
+  }
+}
+
+#[test]
+fn _0002() {
+  // years and months durations are normalized to total number of months
+  let mut generator = Generator(0x9E37_79B9_7F4A_7C15);
+  for _ in 0..CASES {
+    let (text, total) = generator.ym_duration();
+    te_years_and_months_duration(false, &scope!(), &text, total / 12, total % 12);
+    te_bool(
+      false,
+      &scope!(),
+      &format!(r#"{text} = duration("{}P{}M")"#, if total < 0 { "-" } else { "" }, total.abs()),
+      true,
+    );
+  }
+}
+
+#[test]
+fn _0003() {
+  // normalized durations are printed in canonical form that is parsed back to equal duration
+  let mut generator = Generator(0xD1B5_4A32_D192_ED03);
+  for _ in 0..CASES {
+    let (dt_text, _) = generator.dt_duration();
+    te_bool(false, &scope!(), &format!(r#"duration(string({dt_text})) = {dt_text}"#), true);
+    let (ym_text, _) = generator.ym_duration();
+    te_bool(false, &scope!(), &format!(r#"duration(string({ym_text})) = {ym_text}"#), true);
+  }
+}
+
+#[test]
+fn _0004() {
+  // comparisons of durations of the same kind are consistent with normalized values
+  let mut generator = Generator(0x8CB9_2BA7_2F3D_8DD7);
+  for _ in 0..CASES {
+    let (a, a_total) = generator.dt_duration();
+    let (b, b_total) = generator.dt_duration();
+    te_bool(false, &scope!(), &format!("{a} < {b}"), a_total < b_total);
+    te_bool(false, &scope!(), &format!("{a} >= {b}"), a_total >= b_total);
+    let (a, a_total) = generator.ym_duration();
+    let (b, b_total) = generator.ym_duration();
+    te_bool(false, &scope!(), &format!("{a} > {b}"), a_total > b_total);
+    te_bool(false, &scope!(), &format!("{a} <= {b}"), a_total <= b_total);
+  }
+}
+
+#[test]
+fn _0005() {
+  // addition and subtraction of durations of the same kind are inverse operations
+  let mut generator = Generator(0x5851_F42D_4C95_7F2D);
+  for _ in 0..CASES {
+    let (a, a_total) = generator.dt_duration();
+    let (b, b_total) = generator.dt_duration();
+    let sum = a_total + b_total;
+    te_days_and_time_duration(false, &scope!(), &format!("{a} + {b}"), sum < 0, sum.abs(), 0);
+    te_bool(false, &scope!(), &format!("{a} + {b} - {b} = {a}"), true);
+    let (a, a_total) = generator.ym_duration();
+    let (b, b_total) = generator.ym_duration();
+    let sum = a_total + b_total;
+    te_years_and_months_duration(false, &scope!(), &format!("{a} + {b}"), sum / 12, sum % 12);
+    te_bool(false, &scope!(), &format!("{a} + {b} - {b} = {a}"), true);
+  }
+}
+
+#[test]
+fn _0006() {
+  // division of durations of the same kind is the inverse of multiplication by number
+  let mut generator = Generator(0x1405_7B7E_F767_814F);
+  for _ in 0..CASES {
+    let n = generator.next(-50, 50);
+    let (a, a_total) = generator.dt_duration();
+    if a_total != 0 {
+      te_number(false, &scope!(), &format!("{a} * {n} / {a}"), n, 0);
+    }
+    let (a, a_total) = generator.ym_duration();
+    if a_total != 0 {
+      te_number(false, &scope!(), &format!("{a} * {n} / {a}"), n, 0);
+    }
+  }
+}
+
+#[test]
+fn _0007() {
+  // operations on durations of different kinds always result in null
+  let mut generator = Generator(0xA076_1D64_78BD_642F);
+  for _ in 0..CASES {
+    let (dt, _) = generator.dt_duration();
+    let (ym, _) = generator.ym_duration();
+    for operator in ["+", "-", "*", "/", "=", "!=", "<", "<=", ">", ">="] {
+      te_bool(false, &scope!(), &format!("({dt} {operator} {ym}) = null"), true);
+      te_bool(false, &scope!(), &format!("({ym} {operator} {dt}) = null"), true);
+    }
+  }
+}
+
+#[test]
+fn _0008() {
+  te_null(
+    false,
+    &scope!(),
+    r#"duration("P1D") < duration("P1Y")"#,
+    "[less than] incompatible duration kinds: P1D < P1Y",
+  );
+}
+
+#[test]
+fn _0009() {
+  te_null(
+    false,
+    &scope!(),
+    r#"duration("P1Y") <= duration("P365D")"#,
+    "[less or equal] incompatible duration kinds: P1Y <= P365D",
+  );
+}
+
+#[test]
+fn _0010() {
+  te_null(
+    false,
+    &scope!(),
+    r#"duration("PT1H") > duration("P0M")"#,
+    "[greater than] incompatible duration kinds: PT1H > P0M",
+  );
+}
+
+#[test]
+fn _0011() {
+  te_null(
+    false,
+    &scope!(),
+    r#"duration("P2Y") >= duration("P2D")"#,
+    "[greater or equal] incompatible duration kinds: P2Y >= P2D",
+  );
+}
+
+#[test]
+fn _0012() {
+  te_null(false, &scope!(), r#"duration("P0D") / duration("PT0S")"#, "[division] division by zero");
+}
+
+#[test]
+fn _0013() {
+  te_years_and_months_duration(false, &scope!(), r#"duration("P1M") / 2"#, 0, 0);
+}
+
+#[test]
+fn _0014() {
+  te_bool(false, &scope!(), r#"duration("-P0D") = duration("PT0S")"#, true);
+}
@@ -93,3 +93,21 @@ fn _0011() {
     "expected built-in function name or function definition, actual is null(context has no value for key 'pull')",
   );
 }
+
+#[test]
+fn _0012() {
+  // names of built-in functions and functions in scope are not suggested in lenient mode
+  te_null(
+    false,
+    &scope!(),
+    r#"sume([1, 2])"#,
+    "expected built-in function name or function definition, actual is null(context has no value for key 'sume')",
+  );
+  let scope = &te_scope(r#"{discount: function(x) x * 0.9}"#);
+  te_null(
+    false,
+    scope,
+    r#"dicount(100)"#,
+    "expected built-in function name or function definition, actual is null(context has no value for key 'dicount')",
+  );
+}
@@ -0,0 +1,72 @@
+use crate::builders::build_evaluator;
+use crate::{evaluate_with_budget, Budget, BuildContext};
+use dmntk_feel::generators::{literal, FeelGenerator};
+use dmntk_feel::values::Value;
+use dmntk_feel::FeelScope;
+use std::panic;
+use std::time::Duration;
+
+/// Number of generated cases checked for each property.
+const CASES: u64 = 2_000;
+
+/// Returns the value of the expression, or `None` when the expression can not be parsed.
+fn evaluate(text: &str) -> Option<Value> {
+  let scope = FeelScope::default();
+  let node = dmntk_feel_parser::parse_expression(&scope, text, false).ok()?;
+  let evaluator = build_evaluator(&BuildContext::default(), &node).ok()?;
+  let budget = Budget::default()
+    .with_max_steps(10_000)
+    .with_max_list_elements(10_000)
+    .with_max_duration(Duration::from_secs(1));
+  Some(evaluate_with_budget(budget, || evaluator(&scope)).unwrap_or_else(|reason| Value::Null(Some(reason.to_string()))))
+}
+
+/// Returns the value of the expression, reporting the expression when parsing or evaluation fails.
+fn evaluate_checked(seed: u64, text: &str) -> Value {
+  match panic::catch_unwind(|| evaluate(text)) {
+    Ok(Some(value)) => value,
+    Ok(None) => panic!("parsing failed, seed: {seed}, expression: {text}"),
+    Err(_) => panic!("evaluation panicked, seed: {seed}, expression: {text}"),
+  }
+}
+
+#[test]
+fn _0001() {
+  // literals of generated values are parsed and evaluated back into the same values
+  for seed in 1..=CASES {
+    let value = FeelGenerator::new(seed).value();
+    let text = literal(&value);
+    assert_eq!(value, evaluate_checked(seed, &text), "seed: {seed}, literal: {text}");
+  }
+}
+
+#[test]
+fn _0002() {
+  // generated values conform to their own types
+  for seed in 1..=CASES {
+    let value = FeelGenerator::new(seed).value();
+    assert!(value.is_conformant(&value.type_of()), "seed: {seed}, value: {}", literal(&value));
+  }
+}
+
+#[test]
+fn _0003() {
+  // generated expressions are parsed and evaluated without panics,
+  // and evaluation results conform to their own types
+  for seed in 1..=CASES {
+    let text = FeelGenerator::new(seed).expression();
+    let value = evaluate_checked(seed, &text);
+    assert!(value.is_conformant(&value.type_of()), "seed: {seed}, expression: {text}, value: {value}");
+  }
+}
+
+#[test]
+fn _0004() {
+  // evaluation of generated expressions is deterministic
+  for seed in 1..=CASES {
+    let text = FeelGenerator::new(seed).with_max_depth(2).expression();
+    let first = evaluate_checked(seed, &text);
+    let second = evaluate_checked(seed, &text);
+    assert_eq!(first.to_string(), second.to_string(), "seed: {seed}, expression: {text}");
+  }
+}
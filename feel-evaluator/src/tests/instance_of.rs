@@ -418,3 +418,73 @@ fn _0070() {
   let result = crate::evaluate(&scope!(), &node);
   assert_eq!(r#"null(invalid value in 'instance of' operator: Irrelevant)"#, result.ok().unwrap().to_string());
 }
+
+#[test]
+fn _0071() {
+  te_bool(false, &scope!(), "[[1,2],[3]] instance of list<list<number>>", true);
+}
+
+#[test]
+fn _0072() {
+  te_bool(false, &scope!(), "[[1,2],[3]] instance of list<list<Any>>", true);
+}
+
+#[test]
+fn _0073() {
+  te_bool(false, &scope!(), "[[1,2],[3]] instance of list<list<string>>", false);
+}
+
+#[test]
+fn _0074() {
+  te_bool(false, &scope!(), "[] instance of list<number>", true);
+}
+
+#[test]
+fn _0075() {
+  te_bool(false, &scope!(), r#"[1,"a"] instance of list<Any>"#, true);
+}
+
+#[test]
+fn _0076() {
+  te_bool(false, &scope!(), r#"[1,"a"] instance of list<number>"#, false);
+}
+
+#[test]
+fn _0077() {
+  te_bool(false, &scope!(), "[1..10] instance of range<Any>", true);
+}
+
+#[test]
+fn _0078() {
+  te_bool(false, &scope!(), "{a: [1,2], b: true} instance of context<a: list<number>>", true);
+}
+
+#[test]
+fn _0079() {
+  te_bool(false, &scope!(), "{a: [1,2]} instance of context<a: list<string>>", false);
+}
+
+#[test]
+fn _0080() {
+  te_bool(false, &scope!(), "{a: {b: 1}} instance of context<a: context<b: number>>", true);
+}
+
+#[test]
+fn _0081() {
+  te_bool(false, &scope!(), "(function(a: number) a) instance of function<number>->Any", true);
+}
+
+#[test]
+fn _0082() {
+  te_bool(false, &scope!(), "(function(a: Any) 1) instance of function<string>->Any", true);
+}
+
+#[test]
+fn _0083() {
+  te_bool(false, &scope!(), "(function(a: number) a) instance of function<Any>->Any", false);
+}
+
+#[test]
+fn _0084() {
+  te_bool(false, &scope!(), "(function(a: number) a) instance of list<number>", false);
+}
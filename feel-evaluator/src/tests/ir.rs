@@ -0,0 +1,72 @@
+use super::*;
+use crate::ir::Program;
+
+fn compile(scope: &FeelScope, input: &str) -> Option<Program> {
+  let node = dmntk_feel_parser::parse_expression(scope, input, false).unwrap();
+  Program::compile(&BuildContext::default(), &node)
+}
+
+fn run(scope: &FeelScope, input: &str) -> Value {
+  compile(scope, input).unwrap().run(scope)
+}
+
+#[test]
+fn _0001() {
+  let scope = &te_scope("{}");
+  assert_eq!("6", run(scope, "1 + 2 + 3").to_string());
+  assert_eq!("-4", run(scope, "-(2 * 2)").to_string());
+  assert_eq!("true", run(scope, "2 ** 3 = 8").to_string());
+}
+
+#[test]
+fn _0002() {
+  let scope = &te_scope("{a: 10, b: 4}");
+  assert_eq!("6", run(scope, "a - b").to_string());
+  assert_eq!("2.5", run(scope, "a / b").to_string());
+  assert_eq!("true", run(scope, "a > b and b >= 4").to_string());
+  assert_eq!("false", run(scope, "a < b or b != 4").to_string());
+}
+
+#[test]
+fn _0003() {
+  let scope = &te_scope("{a: 10}");
+  assert_eq!(r#""big""#, run(scope, r#"if a > 5 then "big" else "small""#).to_string());
+  assert_eq!(r#""small""#, run(scope, r#"if a > 50 then "big" else "small""#).to_string());
+  assert_eq!(r#""small""#, run(scope, r#"if null then "big" else "small""#).to_string());
+  assert_eq!(
+    "null(condition in 'if' expression is not a boolean value)",
+    run(scope, r#"if a then "big" else "small""#).to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  // constant operands following a conditional branch must not be folded
+  let scope = &te_scope("{a: 10}");
+  assert_eq!("101", run(scope, "(if a > 5 then 100 else 200) + 1").to_string());
+  assert_eq!("201", run(scope, "(if a > 50 then 100 else 200) + 1").to_string());
+  assert_eq!("-200", run(scope, "-(if a > 50 then 100 else 200)").to_string());
+}
+
+#[test]
+fn _0005() {
+  let scope = &te_scope("{a: 10}");
+  assert_eq!("null(context has no value for key 'b')", run(scope, "a + b").to_string());
+  assert_eq!(r#"null([division] division by zero)"#, run(scope, "a / 0").to_string());
+}
+
+#[test]
+fn _0006() {
+  let scope = &te_scope("{a: 10}");
+  assert!(compile(scope, "a").is_none());
+  assert!(compile(scope, "1").is_none());
+  assert!(compile(scope, "abs(a) + 1").is_none());
+  assert!(compile(scope, "[1, 2] = [1, 2]").is_none());
+}
+
+#[test]
+fn _0007() {
+  let scope = &te_scope("{a: 10}");
+  te_number(false, scope, "abs(a - 15) + 1", 6, 0);
+  te_number(false, scope, "sum(for i in 1..3 return i * a + 1)", 63, 0);
+}
@@ -60,7 +60,7 @@ fn _0004() {
 #[test]
 fn _0005() {
   let mut iterator = FeelIterator::default();
-  let list = vec![Value::String("a".to_string()), Value::String("b".to_string()), Value::String("c".to_string())];
+  let list = vec![Value::String("a".into()), Value::String("b".into()), Value::String("c".into())];
   iterator.add_list("x".into(), list);
   let mut actual = vec![];
   iterator.run(|ctx| actual.push(Value::Context(ctx.clone())));
@@ -71,7 +71,7 @@ fn _0005() {
 #[test]
 fn _0006() {
   let mut iterator = FeelIterator::default();
-  let list1 = vec![Value::String("a".to_string()), Value::String("b".to_string()), Value::String("c".to_string())];
+  let list1 = vec![Value::String("a".into()), Value::String("b".into()), Value::String("c".into())];
   iterator.add_list("x".into(), list1);
   let list2 = vec![value_number!(1, 0), value_number!(2, 0), value_number!(3, 0)];
   iterator.add_list("y".into(), list2);
@@ -87,7 +87,7 @@ fn _0006() {
 #[test]
 fn _0007() {
   let mut iterator = FeelIterator::default();
-  let list_x = vec![Value::String("a".to_string()), Value::String("b".to_string())];
+  let list_x = vec![Value::String("a".into()), Value::String("b".into())];
   let list_y = vec![value_number!(1, 0), value_number!(2, 0), value_number!(3, 0)];
   let list_z = vec![value_number!(1, 0), value_number!(2, 0), value_number!(3, 0), value_number!(4, 0)];
   iterator.add_list("x".into(), list_x);
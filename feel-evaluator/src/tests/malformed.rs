@@ -0,0 +1,173 @@
+use super::*;
+use crate::{evaluate_with_budget, Budget};
+use dmntk_feel::scope;
+use std::panic;
+use std::time::Duration;
+
+/// Malformed expressions and expressions with values out of supported ranges.
+const CORPUS: &[&str] = &[
+  "",
+  "(",
+  ")",
+  "[1, 2",
+  "{a: }",
+  "{: 1}",
+  "1 +",
+  "if then else",
+  "for in return",
+  "some x in satisfies",
+  "function(",
+  "a.",
+  ".a",
+  "[1..",
+  "@",
+  r#"@"""#,
+  r#"@"xyz""#,
+  r#"@"P1Q""#,
+  r#"@"2020-13-45""#,
+  r#""unterminated"#,
+  "1 between and 2",
+  "1 instance of",
+  "null.a",
+  "null[1]",
+  "null(1)",
+  "x(1)",
+  "1(1)",
+  r#""a"(1)"#,
+  "{a: 1}.a.b.c.d",
+  "[1, 2, 3][99999999999999999999]",
+  "[1, 2, 3][-99999999999999999999]",
+  "[1, 2, 3][0.5]",
+  "[][1]",
+  "(function(x) x)(1, 2, 3)",
+  "(function(x) x)(y: 1)",
+  r#"time(10, 0, 0, duration("P100D"))"#,
+  r#"time(10, 0, 0, duration("-PT20H"))"#,
+  r#"time(23, 59, 59.999999999999, duration("PT14H"))"#,
+  r#"time(23, 59, 59, duration("P1000000000D"))"#,
+  "time(23.5, 0, 0)",
+  "time(-1, 0, 0)",
+  "date(2020, 13, 1)",
+  "date(2020, 2, 30)",
+  "date(99999999999, 1, 1)",
+  r#"time("24:00:00")"#,
+  r#"time("10:00:00@Invalid/Zone")"#,
+  r#"date and time("2020-01-01T10:00:00+99:00")"#,
+  r#"duration("P999999999999D")"#,
+  r#"duration("P99999999999Y")"#,
+  r#"duration("PT9223372036854775807S")"#,
+  r#"duration("PT9223372036854775807H")"#,
+  r#"duration("P106751DT23H47M16.854775808S")"#,
+  r#"duration("P9223372036854775807M") + duration("P9223372036854775807M")"#,
+  r#"duration("-P9223372036854775807M") - duration("P9223372036854775807M")"#,
+  r#"duration("P100000D") + duration("P100000D")"#,
+  r#"duration("-P100000D") - duration("P100000D")"#,
+  r#"duration("P100000D") * 1000"#,
+  r#"duration("P1000000000Y") * 100000000000"#,
+  r#"duration("P100000D") / 0.000001"#,
+  r#"date("2020-01-01") + duration("P768614336404564650Y")"#,
+  r#"date and time("2020-01-01T00:00:00") - duration("P768614336404564650Y")"#,
+  r#"time("10:00:00") + duration("P100000D")"#,
+  r#"date("-999999999-01-01") - date("999999999-12-31")"#,
+  r#"years and months duration(date("-999999999-01-01"), date("999999999-12-31"))"#,
+  r#"week of year(date(-999999999, 1, 1))"#,
+  r#"substring("abc", 99999999999999999999)"#,
+  r#"substring("abc", 1, -5)"#,
+  "sublist([1, 2, 3], 2, 99999999999)",
+  "insert before([1], 99999999999999999999, 2)",
+  "remove([1], -99999999999999999999)",
+  "list replace([1, 2], 99999999999, 3)",
+  "decimal(1, 99999999999)",
+  "round half even(1.5, -99999999999)",
+  "10 ** 99999999",
+  "1e6000 * 1e6000",
+  "0 ** -1",
+  "1 / 0",
+  "modulo(1, 0)",
+  "sqrt(-1)",
+  "log(0)",
+  "exp(1000000)",
+  r#"number("1e99999999999", null, null)"#,
+  r#"replace("abc", "(", "x")"#,
+  r#"matches("abc", "[")"#,
+  r#"matches("abc", "a", "xyz")"#,
+  r#"split("abc", "(")"#,
+  "mode([])",
+  "mean([])",
+  "stddev([1])",
+  r#"sort([3, 1, "a"])"#,
+  "sort([1, 2], function(x, y) x)",
+  r#"context put({}, [], 1)"#,
+  "context merge([1, 2])",
+  r#"range("[1..")"#,
+  "for i in 1..99999999999 return i",
+];
+
+/// Evaluates the expression within the limits of the budget,
+/// returns `true` when the expression was parsed and evaluated without panic.
+fn evaluate_without_panic(text: &str) -> bool {
+  panic::catch_unwind(|| {
+    let scope = scope!();
+    if let Ok(node) = dmntk_feel_parser::parse_expression(&scope, text, false) {
+      if let Ok(evaluator) = build_evaluator(&BuildContext::default(), &node) {
+        let budget = Budget::default()
+          .with_max_steps(10_000)
+          .with_max_list_elements(10_000)
+          .with_max_duration(Duration::from_secs(1));
+        let _ = evaluate_with_budget(budget, || evaluator(&scope));
+      }
+    }
+  })
+  .is_ok()
+}
+
+#[test]
+fn _0001() {
+  let panicking = CORPUS.iter().filter(|text| !evaluate_without_panic(text)).collect::<Vec<_>>();
+  assert!(panicking.is_empty(), "evaluation panicked for: {panicking:?}");
+}
+
+#[test]
+fn _0002() {
+  te_null(false, &scope!(), r#"time(10, 0, 0, duration("P100D"))"#, "[core::time_4] invalid time offset: P100D");
+}
+
+#[test]
+fn _0003() {
+  te_null(false, &scope!(), r#"time(10, 0, 0, duration("-PT20H"))"#, "[core::time_4] invalid time offset: -PT20H");
+}
+
+#[test]
+fn _0004() {
+  te_null(false, &scope!(), r#"duration("PT9223372036854775807S")"#, "duration");
+}
+
+#[test]
+fn _0005() {
+  te_null(
+    false,
+    &scope!(),
+    r#"duration("P9223372036854775807M") + duration("P9223372036854775807M")"#,
+    "[addition] duration out of range: P768614336404564650Y7M + P768614336404564650Y7M",
+  );
+}
+
+#[test]
+fn _0006() {
+  te_null(
+    false,
+    &scope!(),
+    r#"duration("-P100000D") - duration("P10000D")"#,
+    "[subtraction] duration out of range: -P100000D - P10000D",
+  );
+}
+
+#[test]
+fn _0007() {
+  te_null(
+    false,
+    &scope!(),
+    r#"duration("P100000D") + duration("P10000D")"#,
+    "[addition] duration out of range: P100000D + P10000D",
+  );
+}
@@ -0,0 +1,39 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use dmntk_common::{DmntkError, Language};
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_not_a_context(),
+    "<FeelEvaluatorError> oczekiwano kontekstu FEEL na wejściu",
+    "<FeelEvaluatorError> FEEL-Kontext als Eingabe erwartet",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_expected_positional_or_named_parameter(),
+    "<FeelEvaluatorError> oczekiwano parametru pozycyjnego lub nazwanego",
+    "<FeelEvaluatorError> positioneller oder benannter Parameter erwartet",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_resource_exhausted("steps", 100),
+    "<FeelEvaluatorError> wyczerpano budżet ewaluacji, przekroczono maksymalną liczbę steps (100)",
+    "<FeelEvaluatorError> Auswertungsbudget erschöpft, maximale Anzahl von steps (100) überschritten",
+  );
+}
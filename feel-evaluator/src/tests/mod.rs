@@ -8,6 +8,7 @@ use dmntk_feel_temporal::{Day, FeelDate, FeelDateTime, FeelDaysAndTimeDuration,
 mod addition;
 mod arithmetic_negation;
 mod bifs;
+mod budget;
 mod comments;
 mod comparison_between;
 mod comparison_eq;
@@ -21,6 +22,7 @@ mod conjunction;
 mod context;
 mod disjunction;
 mod division;
+mod durations;
 mod empty_input;
 mod every_expression;
 mod exponentiation;
@@ -30,17 +32,24 @@ mod for_expression;
 mod formal_parameters;
 mod function_definition;
 mod function_invocation;
+mod generated;
 mod if_expression;
 mod instance_of;
+#[cfg(not(feature = "closures"))]
+mod ir;
 mod iterations;
 mod join;
 mod literal_at;
 mod literal_boolean;
 mod literal_numeric;
+mod malformed;
+mod messages;
+mod mode;
 mod multiline;
 mod multiplication;
 mod name;
 mod negation;
+mod null_causes;
 mod out_operator;
 mod parentheses;
 mod path;
@@ -48,6 +57,7 @@ mod properties;
 mod range;
 mod satisfies;
 mod some_expression;
+mod strings;
 mod subtraction;
 mod types;
 mod unary_tests;
@@ -132,7 +142,7 @@ pub fn te_none(trace: bool, scope: &FeelScope, s: &str) {
 
 /// Utility function that tests evaluation to string value.
 fn te_string(trace: bool, scope: &FeelScope, s: &str, expected: &str) {
-  textual_expression(trace, scope, s, Value::String(expected.to_string()));
+  textual_expression(trace, scope, s, Value::String(expected.to_string().into()));
 }
 
 /// Utility function that tests evaluation of year and months duration.
@@ -0,0 +1,92 @@
+use super::*;
+use dmntk_feel::{evaluate_in_mode, EvaluationMode};
+
+/// Utility function that evaluates the expression in specified mode.
+fn te_mode(mode: EvaluationMode, context: &str, input: &str) -> dmntk_common::Result<Value> {
+  let scope = &te_scope(context);
+  let node = dmntk_feel_parser::parse_expression(scope, input, false).unwrap();
+  let evaluator = build_evaluator(&BuildContext::default(), &node).unwrap();
+  evaluate_in_mode(mode, || evaluator(scope))
+}
+
+#[test]
+fn _0001() {
+  // lenient mode is the default and propagates null values
+  assert_eq!(EvaluationMode::Lenient, EvaluationMode::default());
+  let value = te_mode(EvaluationMode::Lenient, "{a: 1}", "a + b").unwrap();
+  assert_eq!("null(context has no value for key 'b')", value.to_string());
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for key 'b'",
+    te_mode(EvaluationMode::Strict, "{a: 1}", "a + b").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!("3", te_mode(EvaluationMode::Strict, "{a: 1, b: 2}", "a + b").unwrap().to_string());
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no entry 'c'",
+    te_mode(EvaluationMode::Strict, "{a: {b: 1}}", "a.c").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: value of type string can not be coerced to type number",
+    te_mode(EvaluationMode::Strict, "{}", r#"{f: function(x: number) x + 1, r: f("one")}.r"#)
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  // all violations are reported, each only once
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for key 'x'; context has no value for key 'y'",
+    te_mode(EvaluationMode::Strict, "{}", "[x, y, x, y]").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  // nulls not caused by violations are accepted in strict mode
+  assert_eq!("null([division] division by zero)", te_mode(EvaluationMode::Strict, "{}", "1 / 0").unwrap().to_string());
+}
+
+#[test]
+fn _0008() {
+  // similar names are suggested in diagnostics
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for key 'Totl', did you mean 'Total'?",
+    te_mode(EvaluationMode::Strict, "{Total: 1}", "Totl").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0009() {
+  // names in scope and names of built-in functions are suggested in diagnostics
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for key 'Monthly Paymnet', did you mean 'Monthly Payment'?",
+    te_mode(EvaluationMode::Strict, "{Monthly Payment: 100, Amount: 1000}", "Monthly Paymnet")
+      .unwrap_err()
+      .to_string()
+  );
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for key 'Interest'",
+    te_mode(EvaluationMode::Strict, "{Monthly Payment: 100, Amount: 1000}", "Interest").unwrap_err().to_string()
+  );
+  assert!(te_mode(EvaluationMode::Strict, "{}", "sume([1, 2])")
+    .unwrap_err()
+    .to_string()
+    .contains("context has no value for key 'sume', did you mean 'sum'?"));
+}
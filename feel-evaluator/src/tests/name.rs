@@ -57,3 +57,12 @@ fn test_name() {
   te_number(false, scope, "fr**n*s", 126, 2);
   te_number(false, scope, "wh*t*v*r", 127, 2);
 }
+
+#[test]
+fn _0008() {
+  // similar names are not suggested in lenient mode
+  let scope = &te_scope("{Monthly Payment: 100, Amount: 1000}");
+  te_null(false, scope, "Amont", "context has no value for key 'Amont'");
+  te_null(false, scope, "Monthly Paymnet", "context has no value for key 'Monthly Paymnet'");
+  te_null(false, scope, "Interest", "context has no value for key 'Interest'");
+}
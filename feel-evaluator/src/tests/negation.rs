@@ -57,7 +57,7 @@ fn _0007() {
     false,
     &scope!(),
     r#"not(date("2022-02-01T22:01:23"))"#,
-    r#"[core::not] invalid argument type, expected boolean, actual type is Null"#,
+    r#"[core::not] invalid argument type, expected boolean, actual type is Null; caused by: [core::date] invalid date string '2022-02-01T22:01:23'"#,
   );
 }
 
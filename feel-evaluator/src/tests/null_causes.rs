@@ -0,0 +1,84 @@
+use super::*;
+use dmntk_feel::scope;
+
+#[test]
+fn _0001() {
+  te_null(
+    false,
+    &scope!(),
+    r#"abs(1 + "x")"#,
+    r#"[core::abs] invalid argument type, expected number, actual type is Null; caused by: incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn _0002() {
+  te_null(
+    false,
+    &scope!(),
+    r#"abs(n: 1 + "x")"#,
+    r#"[core::abs] invalid argument type, expected number, actual type is Null; caused by: incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn _0003() {
+  te_null(
+    false,
+    &scope!(),
+    r#"abs(abs(1 + "x"))"#,
+    r#"[core::abs] invalid argument type, expected number, actual type is Null; caused by: [core::abs] invalid argument type, expected number, actual type is Null; caused by: incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn _0004() {
+  te_null(
+    false,
+    &scope!(),
+    r#"{a: 1 + "x", b: a > 3}.b"#,
+    r#"eval_greater_then; caused by: incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn _0005() {
+  te_null(
+    false,
+    &scope!(),
+    r#"{a: 1 + "x", b: 3 <= a}.b"#,
+    r#"eval_less_or_equal_number; caused by: incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn _0006() {
+  te_null(
+    false,
+    &scope!(),
+    r#"{a: 1 + "x", b: -a}.b"#,
+    r#"unexpected type in arithmetic negation: Null; caused by: incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
+
+#[test]
+fn _0007() {
+  // null without reason has no cause
+  te_null(
+    false,
+    &scope!(),
+    r#"abs(null)"#,
+    r#"[core::abs] invalid argument type, expected number, actual type is Null"#,
+  );
+}
+
+#[test]
+fn _0008() {
+  // the original reason is propagated unchanged through operators propagating null
+  te_null(
+    false,
+    &scope!(),
+    r#"{a: 1 + "x", b: a * 2}.b"#,
+    r#"incompatible types in addition: 1(number) + "x"(string)"#,
+  );
+}
@@ -271,3 +271,25 @@ fn _0050() {
     r#"unexpected type in negated list: function<>->Any"#,
   );
 }
+
+#[test]
+fn _0051() {
+  let scope = te_scope("{ x: {a: 1} }");
+  satisfies(false, &scope, "x", r#""#, r#"not({a: 1}, {a: 2})"#, false);
+}
+
+#[test]
+fn _0052() {
+  let scope = te_scope("{ x: {a: 3} }");
+  satisfies(false, &scope, "x", r#""#, r#"not({a: 1}, {a: 2})"#, true);
+}
+
+#[test]
+fn _0053() {
+  satisfies(false, &scope!(), r#"@"2018-12-08T00:00:00Z""#, r#""#, r#"not(@"2018-12-08", @"2018-12-09")"#, false);
+}
+
+#[test]
+fn _0054() {
+  satisfies(false, &scope!(), r#"@"2018-12-08T10:11:12Z""#, r#""#, r#"[@"2018-12-08"..@"2018-12-09"), null"#, true);
+}
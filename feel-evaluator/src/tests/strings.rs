@@ -0,0 +1,36 @@
+use super::*;
+use dmntk_feel::FeelString;
+
+#[test]
+fn _0001() {
+  let scope = &te_scope("{}");
+  let node = dmntk_feel_parser::parse_expression(scope, r#"["High", "Low", "High"]"#, false).unwrap();
+  let evaluator = build_evaluator(&BuildContext::default(), &node).unwrap();
+  let Value::List(items) = evaluator(scope) else { panic!("expected list") };
+  let strings = items
+    .iter()
+    .map(|item| match item {
+      Value::String(s) => s.clone(),
+      _ => panic!("expected string"),
+    })
+    .collect::<Vec<FeelString>>();
+  assert!(FeelString::ptr_eq(&strings[0], &strings[2]));
+  assert!(!FeelString::ptr_eq(&strings[0], &strings[1]));
+}
+
+#[test]
+fn _0002() {
+  let scope = &te_scope("{}");
+  let node = dmntk_feel_parser::parse_expression(scope, r#"for i in 1..3 return "High""#, false).unwrap();
+  let evaluator = build_evaluator(&BuildContext::default(), &node).unwrap();
+  let Value::List(items) = evaluator(scope) else { panic!("expected list") };
+  let Value::String(first) = &items[0] else { panic!("expected string") };
+  let Value::String(last) = &items[2] else { panic!("expected string") };
+  assert!(FeelString::ptr_eq(first, last));
+}
+
+#[test]
+fn _0003() {
+  let scope = &te_scope("{}");
+  te_string(false, scope, r#""High" + "er""#, "Higher");
+}
@@ -50,3 +50,87 @@ fn test_0009() {
 fn test_0010() {
   valid_unary_tests(false, &scope!(), "not(1,2,3)");
 }
+
+/// Utility function that checks if the input value satisfies unary tests.
+fn satisfies(scope: &FeelScope, input: &str, tests: &str, expected: bool) {
+  let input_value = crate::evaluate(scope, &dmntk_feel_parser::parse_expression(scope, input, false).unwrap()).unwrap();
+  assert_eq!(expected, crate::evaluate_unary_tests(&input_value, tests, scope).unwrap(), "input: {input}, tests: {tests}");
+}
+
+#[test]
+fn test_0011() {
+  let scope = &scope!();
+  satisfies(scope, "5", "-", true);
+  satisfies(scope, "5", "5", true);
+  satisfies(scope, "5", "4,5,6", true);
+  satisfies(scope, "5", "4,6", false);
+  satisfies(scope, "5", "< 10", true);
+  satisfies(scope, "5", ">= 10", false);
+  satisfies(scope, "5", "[1..5]", true);
+  satisfies(scope, "5", "[1..5)", false);
+  satisfies(scope, "5", "not(1,2,3)", true);
+  satisfies(scope, "5", "not([1..10])", false);
+}
+
+#[test]
+fn test_0012() {
+  let scope = &scope!();
+  satisfies(scope, r#""gold""#, r#""gold","silver""#, true);
+  satisfies(scope, r#""bronze""#, r#""gold","silver""#, false);
+  satisfies(scope, r#"date("2024-05-01")"#, r#"[date("2024-01-01")..date("2024-12-31")]"#, true);
+  satisfies(scope, "null", "< 10", false);
+  satisfies(scope, "null", "null", true);
+}
+
+#[test]
+fn test_0013() {
+  // names are resolved in specified scope
+  let scope = &te_scope(r#"{limit: 100}"#);
+  satisfies(scope, "150", "> limit", true);
+  satisfies(scope, "50", "> limit", false);
+  satisfies(scope, "150", "[0..limit]", false);
+  // input value does not remain in the scope
+  assert!(scope.get_value(&"?".into()).is_none());
+}
+
+#[test]
+fn test_0014() {
+  assert_eq!(
+    "<ParserError> syntax error: [1..",
+    crate::evaluate_unary_tests(&dmntk_feel::values::Value::Null(None), "[1..", &scope!())
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+#[test]
+fn test_0015() {
+  // input value referenced with `?`
+  let scope = &te_scope(r#"{a: 10, b: 20}"#);
+  satisfies(scope, "50", "? > 10", true);
+  satisfies(scope, "5", "? > 10", false);
+  satisfies(scope, "50", "? > a + b", true);
+  satisfies(scope, "25", "? > a + b", false);
+  satisfies(scope, "5", "? < 10, ? > 20", true);
+  satisfies(scope, "15", "? < 10, ? > 20", false);
+  satisfies(scope, "15", "not(? < 10, ? > 20)", true);
+  satisfies(scope, "null", "? > 10", false);
+}
+
+#[test]
+fn test_0016() {
+  // input value referenced with `?` in function invocations
+  let scope = &scope!();
+  satisfies(scope, r#""xabcx""#, r#"contains(?, "abc")"#, true);
+  satisfies(scope, r#""xabx""#, r#"contains(?, "abc")"#, false);
+  satisfies(scope, r#""x-ray""#, r#"starts with(?, "x") and ? != "xy""#, true);
+  satisfies(scope, r#""xy""#, r#"starts with(?, "x") and ? != "xy""#, false);
+  satisfies(scope, "[1, 2, 3]", "count(?) = 3", true);
+  satisfies(scope, "[1, 2, 3]", "sum(for x in ? return x * 2) > 20", false);
+  satisfies(scope, r#""abc""#, r#"upper case(?) in ["ABC", "DEF"]"#, true);
+  // unary tests not evaluated to boolean values are compared with the input value as usual
+  satisfies(scope, "5", "7, ? * 1", true);
+  satisfies(scope, "5", "7, ? * 2", false);
+  satisfies(scope, "false", r#"true, not(?)"#, true);
+  satisfies(scope, "true", r#"false"#, false);
+}
@@ -0,0 +1,381 @@
+//! Reader of grammars written in the subset of `Bison` grammar format used by `FEEL` grammar.
+//!
+//! Symbols and rules are numbered the same way as `Bison` numbers them,
+//! so the generated parsing tables are interchangeable with tables generated by `Bison`:
+//!
+//! - tokens are numbered in the order of declaration, after `$end`, `error` and `$undefined`,
+//! - nonterminals are numbered in the order of definition, after `$accept`,
+//! - mid-rule actions are replaced with empty rules of nonterminals named `$@N`,
+//!   placed just before the rule containing the mid-rule action.
+
+use std::collections::HashMap;
+
+/// Name of the end of input token.
+pub const END: &str = "$end";
+
+/// Name of the error token.
+pub const ERROR: &str = "error";
+
+/// Name of the undefined token.
+pub const UNDEFINED: &str = "$undefined";
+
+/// Name of the start rule symbol.
+pub const ACCEPT: &str = "$accept";
+
+/// Associativity of the token.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Assoc {
+  /// Token has no precedence.
+  Undefined,
+  /// Token declared with `%left`.
+  Left,
+  /// Token declared with `%right`.
+  Right,
+  /// Token declared with `%nonassoc`.
+  Nonassoc,
+  /// Token declared with `%precedence`.
+  Precedence,
+}
+
+/// Grammar symbol.
+#[derive(Debug, Clone)]
+pub struct Symbol {
+  /// Name of the symbol.
+  pub name: String,
+  /// Precedence level of the token, zero when the token has no precedence.
+  pub prec: usize,
+  /// Associativity of the token.
+  pub assoc: Assoc,
+}
+
+/// Grammar rule.
+#[derive(Debug, Clone)]
+pub struct Rule {
+  /// Number of the symbol on the left-hand side of the rule.
+  pub lhs: usize,
+  /// Numbers of symbols on the right-hand side of the rule.
+  pub rhs: Vec<usize>,
+  /// Precedence level of the rule, zero when the rule has no precedence.
+  pub prec: usize,
+  /// Name of the semantic action executed when the rule is reduced.
+  pub action: Option<String>,
+}
+
+/// Grammar with numbered symbols and rules.
+#[derive(Debug)]
+pub struct Grammar {
+  /// All symbols, tokens first, then nonterminals.
+  pub symbols: Vec<Symbol>,
+  /// All rules, rule zero is `$accept: start $end`.
+  pub rules: Vec<Rule>,
+  /// Number of tokens.
+  pub n_tokens: usize,
+}
+
+impl Grammar {
+  /// Returns the number of all symbols.
+  pub fn n_symbols(&self) -> usize {
+    self.symbols.len()
+  }
+
+  /// Returns `true` when specified symbol is a token.
+  pub fn is_token(&self, symbol: usize) -> bool {
+    symbol < self.n_tokens
+  }
+
+  /// Returns the rule in the form used in comments, like `list: LEFT_BRACKET list_items`.
+  pub fn rule_text(&self, rule: usize) -> String {
+    let rule = &self.rules[rule];
+    let rhs = if rule.rhs.is_empty() {
+      "%empty".to_string()
+    } else {
+      rule.rhs.iter().map(|symbol| self.symbols[*symbol].name.as_str()).collect::<Vec<&str>>().join(" ")
+    };
+    format!("{}: {}", self.symbols[rule.lhs].name, rhs)
+  }
+}
+
+/// Component of the grammar rule, as written in grammar file.
+enum Component {
+  /// Name of the symbol.
+  Symbol(String),
+  /// Name of the semantic action.
+  Action(String),
+  /// Name of the token defining the precedence of the rule.
+  Prec(String),
+}
+
+/// Rule as written in grammar file.
+struct RawRule {
+  /// Name of the left-hand side symbol.
+  lhs: String,
+  /// Components of the right-hand side.
+  components: Vec<Component>,
+}
+
+/// Reads the grammar from the text in `Bison` compatible format.
+///
+/// Only declarations used by `FEEL` grammar are recognized: `%start`, `%token`, `%left`, `%right`,
+/// `%nonassoc` and `%precedence`. Semantic actions must be written as `{/* action_name */}`.
+pub fn read(input: &str) -> Grammar {
+  let mut sections = input.split("\n%%");
+  let declarations = sections.next().expect("no declarations section");
+  let rules_section = sections.next().expect("no rules section");
+  // read declarations
+  let mut start = None;
+  let mut symbols = vec![symbol(END), symbol(ERROR), symbol(UNDEFINED)];
+  let mut prec = 0;
+  for line in declarations.lines().map(str::trim).filter(|line| line.starts_with('%')) {
+    let mut words = line.split_whitespace();
+    let assoc = match words.next().unwrap_or_default() {
+      "%start" => {
+        start = words.next().map(str::to_string);
+        continue;
+      }
+      "%token" => Assoc::Undefined,
+      "%left" => Assoc::Left,
+      "%right" => Assoc::Right,
+      "%nonassoc" => Assoc::Nonassoc,
+      "%precedence" => Assoc::Precedence,
+      _ => continue,
+    };
+    if assoc != Assoc::Undefined {
+      prec += 1;
+    }
+    for name in words {
+      let index = match symbols.iter().position(|symbol| symbol.name == name) {
+        Some(index) => index,
+        None => {
+          symbols.push(symbol(name));
+          symbols.len() - 1
+        }
+      };
+      if assoc != Assoc::Undefined {
+        symbols[index].prec = prec;
+        symbols[index].assoc = assoc;
+      }
+    }
+  }
+  let start = start.expect("no start symbol");
+  let n_tokens = symbols.len();
+  // read rules
+  let raw_rules = read_rules(rules_section);
+  symbols.push(symbol(ACCEPT));
+  let mut numbers = symbols
+    .iter()
+    .enumerate()
+    .map(|(number, symbol)| (symbol.name.clone(), number))
+    .collect::<HashMap<String, usize>>();
+  let mut rules = vec![];
+  let mut mid_rule_count = 0;
+  // nonterminals are numbered in the order of definition, mid-rule nonterminals when created,
+  // so the left-hand side symbols and mid-rule symbols are numbered in the same pass
+  let mut pending = vec![];
+  for raw_rule in &raw_rules {
+    if !numbers.contains_key(&raw_rule.lhs) {
+      numbers.insert(raw_rule.lhs.clone(), symbols.len());
+      symbols.push(symbol(&raw_rule.lhs));
+    }
+    let mut rhs = vec![];
+    let mut action = None;
+    let mut rule_prec = None;
+    let last_action = raw_rule.components.iter().rposition(|component| !matches!(component, Component::Prec(_)));
+    for (index, component) in raw_rule.components.iter().enumerate() {
+      match component {
+        Component::Symbol(name) => rhs.push(name.clone()),
+        Component::Action(name) if Some(index) == last_action => action = Some(name.clone()),
+        Component::Action(name) => {
+          mid_rule_count += 1;
+          let mid_rule_name = format!("$@{mid_rule_count}");
+          numbers.insert(mid_rule_name.clone(), symbols.len());
+          symbols.push(symbol(&mid_rule_name));
+          pending.push((mid_rule_name.clone(), vec![], Some(name.clone()), None));
+          rhs.push(mid_rule_name);
+        }
+        Component::Prec(name) => rule_prec = Some(name.clone()),
+      }
+    }
+    pending.push((raw_rule.lhs.clone(), rhs, action, rule_prec));
+  }
+  // the start rule is the first one
+  let number = |name: &str| *numbers.get(name).unwrap_or_else(|| panic!("symbol `{name}` is not defined"));
+  rules.push(Rule {
+    lhs: number(ACCEPT),
+    rhs: vec![number(&start), number(END)],
+    prec: 0,
+    action: None,
+  });
+  for (lhs, rhs, action, rule_prec) in pending {
+    let rhs = rhs.iter().map(|name| number(name)).collect::<Vec<usize>>();
+    // the rule gets the precedence of the last token, unless specified with `%prec`
+    let prec = match rule_prec {
+      Some(name) => symbols[number(&name)].prec,
+      None => rhs.iter().rev().find(|symbol| **symbol < n_tokens).map(|symbol| symbols[*symbol].prec).unwrap_or_default(),
+    };
+    rules.push(Rule {
+      lhs: number(&lhs),
+      rhs,
+      prec,
+      action,
+    });
+  }
+  Grammar { symbols, rules, n_tokens }
+}
+
+/// Creates a symbol without precedence.
+fn symbol(name: &str) -> Symbol {
+  Symbol {
+    name: name.to_string(),
+    prec: 0,
+    assoc: Assoc::Undefined,
+  }
+}
+
+/// Reads rules from the rules section of the grammar file.
+fn read_rules(input: &str) -> Vec<RawRule> {
+  let mut raw_rules = vec![];
+  let mut lhs: Option<String> = None;
+  let mut components = vec![];
+  let mut words = words(input).into_iter().peekable();
+  while let Some(word) = words.next() {
+    match word.as_str() {
+      ":" => panic!("unexpected `:`"),
+      "|" | ";" => {
+        let name = lhs.clone().expect("no left-hand side of the rule");
+        raw_rules.push(RawRule {
+          lhs: name,
+          components: std::mem::take(&mut components),
+        });
+        if word == ";" {
+          lhs = None;
+        }
+      }
+      "%empty" => {}
+      "%prec" => components.push(Component::Prec(words.next().expect("no precedence token"))),
+      _ if word.starts_with('{') => components.push(Component::Action(word.trim_start_matches("{/*").trim_end_matches("*/}").trim().to_string())),
+      _ if lhs.is_none() => {
+        assert_eq!(Some(":"), words.next().as_deref(), "expected `:` after `{word}`");
+        lhs = Some(word);
+      }
+      _ => components.push(Component::Symbol(word)),
+    }
+  }
+  assert!(lhs.is_none(), "unterminated rule");
+  raw_rules
+}
+
+/// Splits the rules section into words, semantic actions are single words.
+fn words(input: &str) -> Vec<String> {
+  let mut words = vec![];
+  let mut chars = input.chars().peekable();
+  while let Some(ch) = chars.next() {
+    match ch {
+      _ if ch.is_whitespace() => {}
+      ':' | '|' | ';' => words.push(ch.to_string()),
+      '{' => {
+        let mut word = String::from(ch);
+        for ch in chars.by_ref() {
+          word.push(ch);
+          if ch == '}' {
+            break;
+          }
+        }
+        words.push(word);
+      }
+      _ => {
+        let mut word = String::from(ch);
+        while let Some(ch) = chars.next_if(|ch| !ch.is_whitespace() && !matches!(ch, ':' | '|' | ';' | '{')) {
+          word.push(ch);
+        }
+        words.push(word);
+      }
+    }
+  }
+  words
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const GRAMMAR: &str = r#"
+%start list
+
+%token NUM
+%token COMMA
+%left PLUS
+%left MUL
+
+%%
+
+list:
+    {/* list_begin */} items {/* list */}
+  ;
+
+items:
+    expr COMMA items
+  | expr
+  | %empty {/* items_empty */}
+  ;
+
+expr:
+    expr PLUS expr {/* addition */}
+  | expr MUL expr %prec PLUS
+  | NUM
+  ;
+
+%%
+"#;
+
+  #[test]
+  fn test_symbols() {
+    let grammar = read(GRAMMAR);
+    let names = grammar.symbols.iter().map(|symbol| symbol.name.as_str()).collect::<Vec<&str>>();
+    assert_eq!(
+      vec!["$end", "error", "$undefined", "NUM", "COMMA", "PLUS", "MUL", "$accept", "list", "$@1", "items", "expr"],
+      names
+    );
+    assert_eq!(7, grammar.n_tokens);
+    assert_eq!((1, Assoc::Left), (grammar.symbols[5].prec, grammar.symbols[5].assoc));
+    assert_eq!((2, Assoc::Left), (grammar.symbols[6].prec, grammar.symbols[6].assoc));
+  }
+
+  #[test]
+  fn test_rules() {
+    let grammar = read(GRAMMAR);
+    let rules = (0..grammar.rules.len()).map(|rule| grammar.rule_text(rule)).collect::<Vec<String>>();
+    assert_eq!(
+      vec![
+        "$accept: list $end",
+        "$@1: %empty",
+        "list: $@1 items",
+        "items: expr COMMA items",
+        "items: expr",
+        "items: %empty",
+        "expr: expr PLUS expr",
+        "expr: expr MUL expr",
+        "expr: NUM",
+      ],
+      rules
+    );
+    let actions = grammar.rules.iter().map(|rule| rule.action.as_deref()).collect::<Vec<Option<&str>>>();
+    assert_eq!(
+      vec![None, Some("list_begin"), Some("list"), None, None, Some("items_empty"), Some("addition"), None, None],
+      actions
+    );
+    let precedences = grammar.rules.iter().map(|rule| rule.prec).collect::<Vec<usize>>();
+    assert_eq!(vec![0, 0, 0, 0, 0, 0, 1, 1, 0], precedences);
+  }
+
+  #[test]
+  #[should_panic(expected = "symbol `term` is not defined")]
+  fn test_undefined_symbol() {
+    read("%start a\n%%\na: term ;\n%%\n");
+  }
+
+  #[test]
+  #[should_panic(expected = "no start symbol")]
+  fn test_no_start_symbol() {
+    read("%token A\n%%\na: A ;\n%%\n");
+  }
+}
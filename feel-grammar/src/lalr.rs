@@ -0,0 +1,925 @@
+//! Builder of `LALR(1)` parsing tables.
+//!
+//! The automaton is built with the same algorithms as used by `Bison` (version 3.8) for `LALR(1)`
+//! parsers: states are numbered in the order of creation, lookahead tokens are computed using
+//! the method of DeRemer and Pennello, shift/reduce conflicts are resolved using precedence
+//! and associativity of tokens and rules, unreachable states are removed, and the most common
+//! reduction becomes the default action of the state. Actions are packed into compressed tables
+//! in the format of `yacc.c` skeleton, so the generated tables are identical to tables generated by `Bison`.
+
+use crate::grammar::{Assoc, Grammar};
+use crate::tables::Tables;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt::Write as _;
+
+/// Action recorded for tokens being an explicit error (due to `%nonassoc`).
+const ACTION_ERROR: i64 = i64::MIN;
+
+/// Base of the vector having no entries in packed table.
+const BASE_NONE: i64 = i64::MIN;
+
+/// State of the `LR(0)` automaton.
+struct State {
+  /// Kernel items of the state.
+  kernel: Vec<usize>,
+  /// All items of the state (closure of the kernel).
+  items: Vec<usize>,
+  /// Transitions on symbols, ordered by symbol number, as pairs (symbol, target state).
+  transitions: Vec<(usize, usize)>,
+  /// Flags indicating disabled shift transitions.
+  disabled: Vec<bool>,
+  /// Rules reduced in this state, in the order of items.
+  reductions: Vec<usize>,
+  /// Lookahead tokens for each reduction, empty for consistent states.
+  lookaheads: Vec<BTreeSet<usize>>,
+  /// Flag indicating if the state needs no lookahead token to choose the action.
+  consistent: bool,
+  /// Tokens being an explicit error in this state.
+  errors: Vec<usize>,
+}
+
+/// Nonterminal transition (goto).
+#[derive(Copy, Clone)]
+struct Goto {
+  /// Source state.
+  from: usize,
+  /// Nonterminal symbol.
+  symbol: usize,
+  /// Target state.
+  to: usize,
+}
+
+/// Kind of the conflict.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConflictKind {
+  /// Both shifting the token and reducing a rule are possible.
+  ShiftReduce,
+  /// Reducing two or more rules is possible.
+  ReduceReduce,
+}
+
+/// Conflict not resolved by precedence and associativity.
+#[derive(Debug)]
+pub struct Conflict {
+  /// Kind of the conflict.
+  pub kind: ConflictKind,
+  /// Number of the state with the conflict.
+  pub state: usize,
+  /// Lookahead token.
+  pub token: usize,
+  /// Items of the state shifting the lookahead token.
+  pub shifts: Vec<usize>,
+  /// Rules reducible with the lookahead token.
+  pub reductions: Vec<usize>,
+  /// Shortest sequence of tokens leading to the state with the conflict.
+  pub example: Vec<usize>,
+}
+
+/// Builds parsing tables for specified grammar.
+pub fn build(grammar: &Grammar) -> Tables {
+  let (states, final_state) = automaton(grammar);
+  pack(grammar, &states, final_state)
+}
+
+/// Returns conflicts of the grammar not resolved by precedence and associativity, ordered by state and token.
+pub fn conflicts(grammar: &Grammar) -> Vec<Conflict> {
+  let (states, _) = automaton(grammar);
+  let items = Items::new(grammar);
+  let examples = examples(grammar, &states);
+  let mut conflicts = vec![];
+  for (state_number, state) in states.iter().enumerate().filter(|(_, state)| !state.consistent) {
+    for token in 0..grammar.n_tokens {
+      let shifts = state
+        .items
+        .iter()
+        .filter(|item| items.symbols[**item] == Some(token) && state.transitions.iter().any(|(symbol, _)| *symbol == token))
+        .cloned()
+        .collect::<Vec<usize>>();
+      let reductions = state
+        .reductions
+        .iter()
+        .zip(&state.lookaheads)
+        .filter(|(_, lookaheads)| lookaheads.contains(&token))
+        .map(|(rule_number, _)| *rule_number)
+        .collect::<Vec<usize>>();
+      if reductions.len() + usize::from(!shifts.is_empty()) > 1 {
+        conflicts.push(Conflict {
+          kind: if shifts.is_empty() { ConflictKind::ReduceReduce } else { ConflictKind::ShiftReduce },
+          state: state_number,
+          token,
+          shifts,
+          reductions,
+          example: examples[state_number].clone(),
+        });
+      }
+    }
+  }
+  conflicts
+}
+
+/// Returns the report of conflicts of the grammar, with example token sequences and rules involved.
+pub fn conflicts_report(grammar: &Grammar) -> String {
+  let items = Items::new(grammar);
+  let conflicts = conflicts(grammar);
+  let mut report = String::new();
+  for conflict in &conflicts {
+    let kind = match conflict.kind {
+      ConflictKind::ShiftReduce => "shift/reduce",
+      ConflictKind::ReduceReduce => "reduce/reduce",
+    };
+    let token = &grammar.symbols[conflict.token].name;
+    let _ = writeln!(report, "{kind} conflict in state {} on {token}", conflict.state);
+    let example = conflict.example.iter().map(|symbol| grammar.symbols[*symbol].name.as_str()).collect::<Vec<&str>>();
+    let _ = writeln!(report, "  example: {} \u{2022} {token}", example.join(" "));
+    for item in &conflict.shifts {
+      let _ = writeln!(report, "  shift:   {}", items.text(grammar, *item));
+    }
+    for rule_number in &conflict.reductions {
+      let _ = writeln!(
+        report,
+        "  reduce:  {}",
+        items.text(grammar, items.starts[*rule_number] + grammar.rules[*rule_number].rhs.len())
+      );
+    }
+  }
+  let count = |kind: ConflictKind| conflicts.iter().filter(|conflict| conflict.kind == kind).count();
+  let _ = writeln!(
+    report,
+    "{} shift/reduce conflicts, {} reduce/reduce conflicts",
+    count(ConflictKind::ShiftReduce),
+    count(ConflictKind::ReduceReduce)
+  );
+  report
+}
+
+/// Builds the `LALR(1)` automaton with resolved conflicts, returns states and the number of the final state.
+fn automaton(grammar: &Grammar) -> (Vec<State>, usize) {
+  let (mut states, final_state) = lr0(grammar);
+  lookaheads(grammar, &mut states);
+  resolve_conflicts(grammar, &mut states);
+  remove_unreachable(states, final_state)
+}
+
+/// Returns the shortest sequence of tokens leading to each state.
+fn examples(grammar: &Grammar, states: &[State]) -> Vec<Vec<usize>> {
+  // the shortest sequence of tokens derived from each symbol
+  let mut derived = (0..grammar.n_symbols())
+    .map(|symbol| grammar.is_token(symbol).then(|| vec![symbol]))
+    .collect::<Vec<Option<Vec<usize>>>>();
+  let mut changed = true;
+  while changed {
+    changed = false;
+    for rule in &grammar.rules {
+      let Some(tokens) = rule.rhs.iter().map(|symbol| derived[*symbol].clone()).collect::<Option<Vec<Vec<usize>>>>() else {
+        continue;
+      };
+      let tokens = tokens.concat();
+      if derived[rule.lhs].as_ref().is_none_or(|current| tokens.len() < current.len()) {
+        derived[rule.lhs] = Some(tokens);
+        changed = true;
+      }
+    }
+  }
+  // the shortest path of symbols from the initial state, expanded to tokens
+  let mut examples = vec![None; states.len()];
+  examples[0] = Some(vec![]);
+  let mut pending = VecDeque::from([0]);
+  while let Some(state_number) = pending.pop_front() {
+    for (symbol, to) in &states[state_number].transitions {
+      if examples[*to].is_none() {
+        let mut example = examples[state_number].clone().unwrap_or_default();
+        example.extend(derived[*symbol].clone().unwrap_or_default());
+        examples[*to] = Some(example);
+        pending.push_back(*to);
+      }
+    }
+  }
+  examples.into_iter().map(Option::unwrap_or_default).collect()
+}
+
+/// Positions of items in rules; item `starts[r] + k` is the rule `r` with dot after `k` symbols.
+struct Items {
+  /// Symbol after the dot for each item, `None` when the dot is at the end of the rule.
+  symbols: Vec<Option<usize>>,
+  /// Rule of each item.
+  rules: Vec<usize>,
+  /// Index of the first item of each rule.
+  starts: Vec<usize>,
+}
+
+impl Items {
+  /// Creates items for all rules of the grammar.
+  fn new(grammar: &Grammar) -> Self {
+    let mut items = Self {
+      symbols: vec![],
+      rules: vec![],
+      starts: vec![],
+    };
+    for (rule_number, rule) in grammar.rules.iter().enumerate() {
+      items.starts.push(items.symbols.len());
+      for symbol in &rule.rhs {
+        items.symbols.push(Some(*symbol));
+        items.rules.push(rule_number);
+      }
+      items.symbols.push(None);
+      items.rules.push(rule_number);
+    }
+    items
+  }
+
+  /// Returns the item in the form used in reports, like `list: LEFT_BRACKET • list_items`.
+  fn text(&self, grammar: &Grammar, item: usize) -> String {
+    let rule = &grammar.rules[self.rules[item]];
+    let position = item - self.starts[self.rules[item]];
+    let mut symbols = rule.rhs.iter().map(|symbol| grammar.symbols[*symbol].name.as_str()).collect::<Vec<&str>>();
+    symbols.insert(position, "\u{2022}");
+    format!("{}: {}", grammar.symbols[rule.lhs].name, symbols.join(" "))
+  }
+}
+
+/// Builds the `LR(0)` automaton, returns states and the number of the final state.
+fn lr0(grammar: &Grammar) -> (Vec<State>, usize) {
+  let items = Items::new(grammar);
+  let n_symbols = grammar.n_symbols();
+  // rules that may appear in the closure of each nonterminal (firsts are reflexive and transitive)
+  let mut firsts = (0..n_symbols).map(|symbol| BTreeSet::from([symbol])).collect::<Vec<BTreeSet<usize>>>();
+  for rule in &grammar.rules {
+    if let Some(first) = rule.rhs.first().filter(|first| !grammar.is_token(**first)) {
+      firsts[rule.lhs].insert(*first);
+    }
+  }
+  transitive_closure(&mut firsts);
+  let closure_rules = firsts
+    .iter()
+    .map(|firsts| {
+      grammar
+        .rules
+        .iter()
+        .enumerate()
+        .filter(|(_, rule)| firsts.contains(&rule.lhs))
+        .map(|(rule_number, _)| rule_number)
+        .collect::<BTreeSet<usize>>()
+    })
+    .collect::<Vec<BTreeSet<usize>>>();
+  let mut states = vec![new_state(vec![0])];
+  let mut cores = HashMap::from([(vec![0], 0)]);
+  let mut final_state = 0;
+  let mut current = 0;
+  while current < states.len() {
+    // closure, items are ordered by the position in rules
+    let mut item_set = states[current].kernel.iter().cloned().collect::<BTreeSet<usize>>();
+    for item in &states[current].kernel {
+      if let Some(symbol) = items.symbols[*item].filter(|symbol| !grammar.is_token(*symbol)) {
+        item_set.extend(closure_rules[symbol].iter().map(|rule_number| items.starts[*rule_number]));
+      }
+    }
+    // reductions
+    states[current].reductions = item_set.iter().filter(|item| items.symbols[**item].is_none()).map(|item| items.rules[*item]).collect();
+    // kernels of next states
+    let mut kernels = BTreeSet::new();
+    let mut next_kernels = HashMap::<usize, Vec<usize>>::new();
+    for item in &item_set {
+      if let Some(symbol) = items.symbols[*item] {
+        kernels.insert(symbol);
+        next_kernels.entry(symbol).or_default().push(item + 1);
+      }
+    }
+    let mut transitions = vec![];
+    for symbol in kernels {
+      let kernel = next_kernels.remove(&symbol).unwrap();
+      let target = match cores.get(&kernel) {
+        Some(target) => *target,
+        None => {
+          let target = states.len();
+          if symbol == 0 {
+            final_state = target;
+          }
+          cores.insert(kernel.clone(), target);
+          states.push(new_state(kernel));
+          target
+        }
+      };
+      transitions.push((symbol, target));
+    }
+    let state = &mut states[current];
+    state.items = item_set.into_iter().collect();
+    state.disabled = vec![false; transitions.len()];
+    state.transitions = transitions;
+    current += 1;
+  }
+  (states, final_state)
+}
+
+/// Creates a new state with specified kernel.
+fn new_state(kernel: Vec<usize>) -> State {
+  State {
+    kernel,
+    items: vec![],
+    transitions: vec![],
+    disabled: vec![],
+    reductions: vec![],
+    lookaheads: vec![],
+    consistent: false,
+    errors: vec![],
+  }
+}
+
+/// Extends each set with sets of its members, until no set changes.
+fn transitive_closure(sets: &mut [BTreeSet<usize>]) {
+  let mut changed = true;
+  while changed {
+    changed = false;
+    for index in 0..sets.len() {
+      let members = sets[index].iter().cloned().collect::<Vec<usize>>();
+      for member in members {
+        if member != index {
+          let additional = sets[member].difference(&sets[index]).cloned().collect::<Vec<usize>>();
+          if !additional.is_empty() {
+            sets[index].extend(additional);
+            changed = true;
+          }
+        }
+      }
+    }
+  }
+}
+
+/// Returns all nonterminal transitions, ordered by nonterminal, then by the source state.
+fn gotos(grammar: &Grammar, states: &[State]) -> Vec<Goto> {
+  let mut gotos = states
+    .iter()
+    .enumerate()
+    .flat_map(|(from, state)| {
+      state
+        .transitions
+        .iter()
+        .filter(|(symbol, _)| !grammar.is_token(*symbol))
+        .map(move |(symbol, to)| Goto { from, symbol: *symbol, to: *to })
+    })
+    .collect::<Vec<Goto>>();
+  gotos.sort_by_key(|goto| (goto.symbol, goto.from));
+  gotos
+}
+
+/// Computes lookahead tokens of reductions in states that are not consistent.
+fn lookaheads(grammar: &Grammar, states: &mut [State]) {
+  // nullable nonterminals
+  let mut nullable = vec![false; grammar.n_symbols()];
+  let mut changed = true;
+  while changed {
+    changed = false;
+    for rule in &grammar.rules {
+      if !nullable[rule.lhs] && rule.rhs.iter().all(|symbol| nullable[*symbol]) {
+        nullable[rule.lhs] = true;
+        changed = true;
+      }
+    }
+  }
+  // consistent states need no lookahead
+  for state in states.iter_mut() {
+    let shifts = state.transitions.first().is_some_and(|(symbol, _)| grammar.is_token(*symbol));
+    state.consistent = !(state.reductions.len() > 1 || (state.reductions.len() == 1 && shifts));
+  }
+  let gotos = gotos(grammar, states);
+  let goto_index = gotos
+    .iter()
+    .enumerate()
+    .map(|(index, goto)| ((goto.from, goto.symbol), index))
+    .collect::<HashMap<(usize, usize), usize>>();
+  let target = |state: usize, symbol: usize| states[state].transitions.iter().find(|(s, _)| *s == symbol).map(|(_, to)| *to).unwrap();
+  // direct reads and reads relation
+  let mut follows = vec![BTreeSet::new(); gotos.len()];
+  let mut relations = vec![BTreeSet::new(); gotos.len()];
+  for (index, goto) in gotos.iter().enumerate() {
+    for (symbol, _) in &states[goto.to].transitions {
+      if grammar.is_token(*symbol) {
+        follows[index].insert(*symbol);
+      } else if nullable[*symbol] {
+        relations[index].insert(goto_index[&(goto.to, *symbol)]);
+      }
+    }
+  }
+  // includes relation and lookback
+  let mut lookbacks = HashMap::<(usize, usize), Vec<usize>>::new();
+  for (index, goto) in gotos.iter().enumerate() {
+    for (rule_number, rule) in grammar.rules.iter().enumerate().filter(|(_, rule)| rule.lhs == goto.symbol) {
+      let mut state = goto.from;
+      for (position, symbol) in rule.rhs.iter().enumerate() {
+        if !grammar.is_token(*symbol) && rule.rhs[position + 1..].iter().all(|symbol| nullable[*symbol]) {
+          relations[goto_index[&(state, *symbol)]].insert(index);
+        }
+        state = target(state, *symbol);
+      }
+      lookbacks.entry((state, rule_number)).or_default().push(index);
+    }
+  }
+  // follow sets are the unions of direct reads over reads and includes relations
+  let mut changed = true;
+  while changed {
+    changed = false;
+    for index in 0..gotos.len() {
+      for related in relations[index].iter().cloned().collect::<Vec<usize>>() {
+        if related != index {
+          let additional = follows[related].difference(&follows[index]).cloned().collect::<Vec<usize>>();
+          if !additional.is_empty() {
+            follows[index].extend(additional);
+            changed = true;
+          }
+        }
+      }
+    }
+  }
+  for (state_number, state) in states.iter_mut().enumerate() {
+    if !state.consistent {
+      state.lookaheads = state
+        .reductions
+        .iter()
+        .map(|rule_number| {
+          lookbacks
+            .get(&(state_number, *rule_number))
+            .map(|indexes| indexes.iter().flat_map(|index| follows[*index].iter().cloned()).collect())
+            .unwrap_or_default()
+        })
+        .collect();
+    }
+  }
+}
+
+/// Resolves shift/reduce conflicts using precedence and associativity.
+/// Unresolved conflicts are resolved in favor of shifting, and in favor
+/// of the first rule for reduce/reduce conflicts, when actions are packed.
+fn resolve_conflicts(grammar: &Grammar, states: &mut [State]) {
+  for state in states.iter_mut() {
+    if state.consistent {
+      continue;
+    }
+    let mut shifts = state
+      .transitions
+      .iter()
+      .filter(|(symbol, _)| grammar.is_token(*symbol))
+      .map(|(symbol, _)| *symbol)
+      .collect::<BTreeSet<usize>>();
+    for index in 0..state.reductions.len() {
+      let rule_prec = grammar.rules[state.reductions[index]].prec;
+      if rule_prec == 0 || state.lookaheads[index].is_disjoint(&shifts) {
+        continue;
+      }
+      for token in 0..grammar.n_tokens {
+        let symbol = &grammar.symbols[token];
+        if !state.lookaheads[index].contains(&token) || !shifts.contains(&token) || symbol.prec == 0 {
+          continue;
+        }
+        let (shift, reduce) = if symbol.prec < rule_prec {
+          (false, true)
+        } else if symbol.prec > rule_prec {
+          (true, false)
+        } else {
+          match symbol.assoc {
+            Assoc::Right => (true, false),
+            Assoc::Left => (false, true),
+            Assoc::Nonassoc => {
+              state.errors.push(token);
+              (false, false)
+            }
+            _ => (true, true),
+          }
+        };
+        if !shift {
+          shifts.remove(&token);
+          if let Some(position) = state.transitions.iter().position(|(symbol, _)| *symbol == token) {
+            state.disabled[position] = true;
+          }
+        }
+        if !reduce {
+          state.lookaheads[index].remove(&token);
+        }
+      }
+    }
+  }
+}
+
+/// Removes states that are not reachable after disabling shifts, remaining states are renumbered preserving their order.
+fn remove_unreachable(states: Vec<State>, final_state: usize) -> (Vec<State>, usize) {
+  let mut reachable = vec![false; states.len()];
+  let mut pending = vec![0];
+  while let Some(state_number) = pending.pop() {
+    if !reachable[state_number] {
+      reachable[state_number] = true;
+      let state = &states[state_number];
+      pending.extend(state.transitions.iter().zip(&state.disabled).filter(|(_, disabled)| !**disabled).map(|((_, to), _)| *to));
+    }
+  }
+  let mut old_to_new = vec![0; states.len()];
+  let mut count = 0;
+  for (old, reachable) in reachable.iter().enumerate() {
+    if *reachable {
+      old_to_new[old] = count;
+      count += 1;
+    }
+  }
+  let states = states
+    .into_iter()
+    .zip(reachable)
+    .filter(|(_, reachable)| *reachable)
+    .map(|(mut state, _)| {
+      let (transitions, disabled) = state
+        .transitions
+        .iter()
+        .zip(&state.disabled)
+        .filter(|(_, disabled)| !**disabled)
+        .map(|((symbol, to), _)| ((*symbol, old_to_new[*to]), false))
+        .unzip();
+      state.transitions = transitions;
+      state.disabled = disabled;
+      state
+    })
+    .collect();
+  (states, old_to_new[final_state])
+}
+
+/// Vectors of actions packed into compressed table.
+struct Packing {
+  /// Source (token or state) of each entry in vectors.
+  froms: Vec<Vec<i64>>,
+  /// Target (action or state) of each entry in vectors.
+  tos: Vec<Vec<i64>>,
+  /// Number of entries in vectors.
+  tally: Vec<usize>,
+  /// Distance between the first and the last entry in vectors.
+  width: Vec<i64>,
+  /// Packed table.
+  table: Vec<i64>,
+  /// Check table.
+  check: Vec<i64>,
+  /// Bases of packed vectors, in packing order.
+  pos: Vec<i64>,
+  /// Index of the first empty entry in the table.
+  low_zero: usize,
+  /// Index of the last used entry in the table.
+  high: usize,
+}
+
+impl Packing {
+  /// Stores the vector of non-default actions or gotos.
+  fn save(&mut self, vector: usize, entries: Vec<(i64, i64)>) {
+    if let (Some(first), Some(last)) = (entries.first(), entries.last()) {
+      self.tally[vector] = entries.len();
+      self.width[vector] = last.0 - first.0 + 1;
+      (self.froms[vector], self.tos[vector]) = entries.into_iter().unzip();
+    }
+  }
+
+  /// Returns vector numbers of non-empty vectors, ordered by decreasing width, then by decreasing tally.
+  fn order(&self) -> Vec<usize> {
+    let mut order: Vec<usize> = vec![];
+    for vector in (0..self.tally.len()).filter(|vector| self.tally[*vector] > 0) {
+      let position = order
+        .iter()
+        .position(|other| self.width[*other] < self.width[vector] || (self.width[*other] == self.width[vector] && self.tally[*other] < self.tally[vector]))
+        .unwrap_or(order.len());
+      order.insert(position, vector);
+    }
+    order
+  }
+
+  /// Returns the state having exactly the same actions as the vector at specified position, if already packed.
+  fn matching_state(&self, order: &[usize], position: usize, n_states: usize) -> Option<usize> {
+    let vector = order[position];
+    if vector >= n_states {
+      return None;
+    }
+    for previous in order[..position].iter().rev() {
+      if self.width[*previous] != self.width[vector] || self.tally[*previous] != self.tally[vector] {
+        return None;
+      }
+      if self.froms[*previous] == self.froms[vector] && self.tos[*previous] == self.tos[vector] {
+        return Some(*previous);
+      }
+    }
+    None
+  }
+
+  /// Packs the vector into the table, returns the base of the vector.
+  fn pack_vector(&mut self, vector: usize) -> i64 {
+    let froms = &self.froms[vector];
+    let mut base = self.low_zero as i64 - froms[0];
+    loop {
+      let fits = froms.iter().all(|from| {
+        let location = (base + from) as usize;
+        location >= self.table.len() || self.table[location] == 0
+      }) && !self.pos.contains(&base);
+      if fits {
+        for (from, to) in froms.iter().zip(&self.tos[vector]) {
+          let location = (base + from) as usize;
+          if location >= self.table.len() {
+            self.table.resize(location + 1, 0);
+            self.check.resize(location + 1, -1);
+          }
+          self.table[location] = *to;
+          self.check[location] = *from;
+          self.high = self.high.max(location);
+        }
+        while self.low_zero < self.table.len() && self.table[self.low_zero] != 0 {
+          self.low_zero += 1;
+        }
+        return base;
+      }
+      base += 1;
+    }
+  }
+}
+
+/// Replaces the marker with the value lower than all other negative values, returns that value.
+fn remap_minimum(values: &mut [i64], marker: i64) -> i64 {
+  let minimum = values.iter().filter(|value| **value != marker).fold(0, |minimum, value| minimum.min(*value)) - 1;
+  values.iter_mut().filter(|value| **value == marker).for_each(|value| *value = minimum);
+  minimum
+}
+
+/// Computes actions of all states and packs them into parsing tables.
+fn pack(grammar: &Grammar, states: &[State], final_state: usize) -> Tables {
+  let n_states = states.len();
+  let n_vectors = n_states + grammar.n_symbols() - grammar.n_tokens;
+  let mut packing = Packing {
+    froms: vec![vec![]; n_vectors],
+    tos: vec![vec![]; n_vectors],
+    tally: vec![0; n_vectors],
+    width: vec![0; n_vectors],
+    table: vec![],
+    check: vec![],
+    pos: vec![],
+    low_zero: 0,
+    high: 0,
+  };
+  // actions of states
+  let mut def_act = vec![];
+  for (state_number, state) in states.iter().enumerate() {
+    let reduce = |rule_number: usize| -(rule_number as i64) - 1;
+    let mut actions = vec![0; grammar.n_tokens];
+    // the first rule has precedence in reduce/reduce conflicts
+    for (index, lookaheads) in state.lookaheads.iter().enumerate().rev() {
+      for token in lookaheads {
+        actions[*token] = reduce(state.reductions[index]);
+      }
+    }
+    // shifts have precedence over reductions
+    for (symbol, to) in state.transitions.iter().filter(|(symbol, _)| grammar.is_token(*symbol)) {
+      actions[*symbol] = *to as i64;
+    }
+    for token in &state.errors {
+      actions[*token] = ACTION_ERROR;
+    }
+    let mut default_reduction = None;
+    if state.consistent {
+      default_reduction = state.reductions.first().cloned();
+    } else {
+      let mut max = 0;
+      for rule_number in &state.reductions {
+        let count = actions.iter().filter(|action| **action == reduce(*rule_number)).count();
+        if count > max {
+          max = count;
+          default_reduction = Some(*rule_number);
+        }
+      }
+      if let Some(rule_number) = default_reduction {
+        actions.iter_mut().filter(|action| **action == reduce(rule_number)).for_each(|action| *action = 0);
+      }
+    }
+    if default_reduction.is_none() {
+      actions.iter_mut().filter(|action| **action == ACTION_ERROR).for_each(|action| *action = 0);
+    }
+    def_act.push(default_reduction.map(|rule_number| rule_number as i64 + 1).unwrap_or_default());
+    let entries = actions
+      .iter()
+      .enumerate()
+      .filter(|(_, action)| **action != 0)
+      .map(|(token, action)| (token as i64, *action))
+      .collect();
+    packing.save(state_number, entries);
+  }
+  // gotos of nonterminals, the most common target becomes the default
+  let gotos = gotos(grammar, states);
+  let mut def_goto = vec![];
+  for symbol in grammar.n_tokens..grammar.n_symbols() {
+    let gotos = gotos.iter().filter(|goto| goto.symbol == symbol).collect::<Vec<&Goto>>();
+    let mut counts = vec![0; n_states];
+    gotos.iter().for_each(|goto| counts[goto.to] += 1);
+    let mut default_state = 0;
+    let mut max = 0;
+    for (state_number, count) in counts.iter().enumerate() {
+      if *count > max {
+        max = *count;
+        default_state = state_number;
+      }
+    }
+    def_goto.push(default_state as i64);
+    let entries = gotos
+      .iter()
+      .filter(|goto| goto.to != default_state)
+      .map(|goto| (goto.from as i64, goto.to as i64))
+      .collect();
+    packing.save(n_states + symbol - grammar.n_tokens, entries);
+  }
+  // pack vectors
+  let order = packing.order();
+  let mut base = vec![BASE_NONE; n_vectors];
+  for position in 0..order.len() {
+    let place = match packing.matching_state(&order, position, n_states) {
+      Some(state_number) => base[state_number],
+      None => packing.pack_vector(order[position]),
+    };
+    packing.pos.push(place);
+    base[order[position]] = place;
+  }
+  let pact_n_inf = remap_minimum(&mut base, BASE_NONE);
+  let mut table = packing.table[..=packing.high].to_vec();
+  let table_n_inf = remap_minimum(&mut table, ACTION_ERROR);
+  let p_goto = base.split_off(n_states);
+  // translation of token numbers returned by lexer into symbol numbers
+  let mut translate = vec![2; 256 + grammar.n_tokens - 1];
+  translate[0] = 0;
+  for symbol in 1..grammar.n_tokens {
+    translate[255 + symbol] = symbol as i64;
+  }
+  Tables {
+    token_types: grammar.symbols[3..grammar.n_tokens].iter().map(|symbol| symbol.name.clone()).collect(),
+    yy_pact_n_inf: pact_n_inf,
+    yy_table_n_inf: table_n_inf,
+    yy_final: final_state as i64,
+    yy_last: packing.high as i64,
+    yy_n_tokens: grammar.n_tokens as i64,
+    yy_translate: translate,
+    yy_pact: base,
+    yy_def_act: def_act,
+    yy_p_goto: p_goto,
+    yy_def_goto: def_goto,
+    yy_table: table,
+    yy_check: packing.check[..=packing.high].to_vec(),
+    yy_r1: [0].into_iter().chain(grammar.rules.iter().map(|rule| rule.lhs as i64)).collect(),
+    yy_r2: [0].into_iter().chain(grammar.rules.iter().map(|rule| rule.rhs.len() as i64)).collect(),
+    actions: grammar
+      .rules
+      .iter()
+      .enumerate()
+      .filter_map(|(rule_number, rule)| rule.action.as_ref().map(|action| (rule_number as i64 + 1, grammar.rule_text(rule_number), action.clone())))
+      .collect(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::grammar::read;
+
+  /// Parses the sequence of symbols using generated tables, returns numbers of reduced rules.
+  fn parse(tables: &Tables, tokens: &[i64]) -> Option<Vec<i64>> {
+    let mut stack = vec![0];
+    let mut reduced = vec![];
+    let mut tokens = tokens.iter().cloned().chain([0]).peekable();
+    loop {
+      let state = *stack.last().unwrap();
+      if state == tables.yy_final {
+        return Some(reduced);
+      }
+      let token = *tokens.peek().unwrap();
+      let pact = tables.yy_pact[state as usize];
+      let index = pact + token;
+      let mut rule = tables.yy_def_act[state as usize];
+      if pact != tables.yy_pact_n_inf && (0..=tables.yy_last).contains(&index) && tables.yy_check[index as usize] == token {
+        match tables.yy_table[index as usize] {
+          action if action == tables.yy_table_n_inf => return None,
+          action if action > 0 => {
+            stack.push(action);
+            tokens.next();
+            continue;
+          }
+          action => rule = -action,
+        }
+      }
+      if rule == 0 {
+        return None;
+      }
+      reduced.push(rule);
+      stack.truncate(stack.len() - tables.yy_r2[rule as usize] as usize);
+      let top = *stack.last().unwrap();
+      let lhs = (tables.yy_r1[rule as usize] - tables.yy_n_tokens) as usize;
+      let index = tables.yy_p_goto[lhs] + top;
+      stack.push(if (0..=tables.yy_last).contains(&index) && tables.yy_check[index as usize] == top {
+        tables.yy_table[index as usize]
+      } else {
+        tables.yy_def_goto[lhs]
+      });
+    }
+  }
+
+  const GRAMMAR: &str = r#"
+%start expr
+
+%token NUM
+%left PLUS
+%right EXP
+%nonassoc EQ
+
+%%
+
+expr:
+    expr PLUS expr {/* addition */}
+  | expr EXP expr {/* exponentiation */}
+  | expr EQ expr {/* comparison */}
+  | NUM {/* number */}
+  ;
+
+%%
+"#;
+
+  const NUM: i64 = 3;
+  const PLUS: i64 = 4;
+  const EXP: i64 = 5;
+  const EQ: i64 = 6;
+
+  #[test]
+  fn test_left_associativity() {
+    let tables = build(&read(GRAMMAR));
+    // (1 + 2) + 3
+    assert_eq!(Some(vec![5, 5, 2, 5, 2]), parse(&tables, &[NUM, PLUS, NUM, PLUS, NUM]));
+  }
+
+  #[test]
+  fn test_right_associativity() {
+    let tables = build(&read(GRAMMAR));
+    // 1 ^ (2 ^ 3)
+    assert_eq!(Some(vec![5, 5, 5, 3, 3]), parse(&tables, &[NUM, EXP, NUM, EXP, NUM]));
+    // (1 ^ 2) + 3
+    assert_eq!(Some(vec![5, 5, 3, 5, 2]), parse(&tables, &[NUM, EXP, NUM, PLUS, NUM]));
+  }
+
+  #[test]
+  fn test_non_associativity() {
+    let tables = build(&read(GRAMMAR));
+    assert_eq!(Some(vec![5, 5, 4]), parse(&tables, &[NUM, EQ, NUM]));
+    assert_eq!(None, parse(&tables, &[NUM, EQ, NUM, EQ, NUM]));
+    assert_eq!(None, parse(&tables, &[NUM, PLUS]));
+  }
+
+  #[test]
+  fn test_actions() {
+    let tables = build(&read(GRAMMAR));
+    assert_eq!(4, tables.actions.len());
+    assert_eq!((2, "expr: expr PLUS expr".to_string(), "addition".to_string()), tables.actions[0]);
+    assert_eq!(vec![0, 2, 3, 3, 3, 1], tables.yy_r2);
+  }
+
+  const AMBIGUOUS_GRAMMAR: &str = r#"
+%start expr
+
+%token NUM
+%token PLUS
+
+%%
+
+expr:
+    expr PLUS expr
+  | NUM
+  | term
+  ;
+
+term:
+    NUM
+  ;
+
+%%
+"#;
+
+  #[test]
+  fn test_conflicts() {
+    let grammar = read(AMBIGUOUS_GRAMMAR);
+    let conflicts = conflicts(&grammar);
+    let kinds = conflicts.iter().map(|conflict| (conflict.kind, conflict.token)).collect::<Vec<(ConflictKind, usize)>>();
+    assert_eq!(
+      vec![(ConflictKind::ReduceReduce, 0), (ConflictKind::ReduceReduce, 4), (ConflictKind::ShiftReduce, 4)],
+      kinds
+    );
+    assert_eq!(vec![3, 4, 3], conflicts[2].example);
+    assert!(super::conflicts(&read(GRAMMAR)).is_empty());
+  }
+
+  #[test]
+  fn test_conflicts_report() {
+    let report = conflicts_report(&read(AMBIGUOUS_GRAMMAR));
+    let expected = r#"reduce/reduce conflict in state 1 on $end
+  example: NUM • $end
+  reduce:  expr: NUM •
+  reduce:  term: NUM •
+reduce/reduce conflict in state 1 on PLUS
+  example: NUM • PLUS
+  reduce:  expr: NUM •
+  reduce:  term: NUM •
+shift/reduce conflict in state 6 on PLUS
+  example: NUM PLUS NUM • PLUS
+  shift:   expr: expr • PLUS expr
+  reduce:  expr: expr PLUS expr •
+1 shift/reduce conflicts, 2 reduce/reduce conflicts
+"#;
+    assert_eq!(expected, report);
+  }
+}
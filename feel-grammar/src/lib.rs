@@ -1,6 +1,8 @@
 //! `FEEL` grammar definition and `LALR` parsing tables generator.
 
-mod extractor;
 mod generator;
+mod grammar;
+mod lalr;
+mod tables;
 
-pub use generator::lalr_rust_tables;
+pub use generator::{lalr_conflicts, lalr_rust_tables};
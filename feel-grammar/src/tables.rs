@@ -0,0 +1,173 @@
+//! Parsing tables and the generator of their source code in `Rust` language.
+
+use convert_case::{Case, Casing};
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+const HEADER: &str = r#"
+//! Parsing tables generated from `FEEL` grammar by `LALR` parsing tables generator.
+//! This file was generated by dedicated tool, do not modify manually.
+"#;
+
+const USE_CLAUSE: &str = r#"
+use dmntk_common::Result;
+"#;
+
+/// Parsing tables in the format used by `yacc.c` skeleton of `Bison` parser generator.
+pub struct Tables {
+  /// Names of tokens, in the order of token numbers starting from 258.
+  pub token_types: Vec<String>,
+  /// Value in `YY_PACT` marking states with default action only.
+  pub yy_pact_n_inf: i64,
+  /// Value in `YY_TABLE` marking explicit syntax errors.
+  pub yy_table_n_inf: i64,
+  /// Number of the final state.
+  pub yy_final: i64,
+  /// Index of the last entry in `YY_TABLE`.
+  pub yy_last: i64,
+  /// Number of tokens.
+  pub yy_n_tokens: i64,
+  /// Symbol numbers of token numbers returned by lexer.
+  pub yy_translate: Vec<i64>,
+  /// Indexes in `YY_TABLE` of actions of states.
+  pub yy_pact: Vec<i64>,
+  /// Default reductions of states.
+  pub yy_def_act: Vec<i64>,
+  /// Indexes in `YY_TABLE` of gotos of nonterminals.
+  pub yy_p_goto: Vec<i64>,
+  /// Default gotos of nonterminals.
+  pub yy_def_goto: Vec<i64>,
+  /// Packed actions and gotos.
+  pub yy_table: Vec<i64>,
+  /// Checks of entries in `YY_TABLE`.
+  pub yy_check: Vec<i64>,
+  /// Symbol numbers of left-hand sides of rules.
+  pub yy_r1: Vec<i64>,
+  /// Lengths of right-hand sides of rules.
+  pub yy_r2: Vec<i64>,
+  /// Semantic actions as tuples (rule number, rule text, action name).
+  pub actions: Vec<(i64, String, String)>,
+}
+
+impl Tables {
+  /// Generates the source code of parsing tables in `Rust` language.
+  pub fn to_rust(&self) -> String {
+    let mut output = String::with_capacity(10_000);
+    // generate the file header
+    {
+      output.push_str(HEADER);
+    }
+    // generate the use clause
+    {
+      output.push_str(USE_CLAUSE);
+    }
+    // generate token types
+    {
+      output.push_str("\n/// Types of tokens returned by lexer.\n");
+      output.push_str("#[derive(Clone)]\n");
+      output.push_str("pub enum TokenType {\n");
+      output.push_str("  YyEmpty = -2,\n  YyEof = 0,\n  YyError = 256,\n  YyUndef = 257,\n");
+      for (index, name) in self.token_types.iter().enumerate() {
+        let name = name.to_uppercase().replace("YY", "YY_").to_case(Case::UpperCamel);
+        if !name.starts_with("Prec") {
+          let _ = writeln!(output, "  {name} = {},", index + 258);
+        }
+      }
+      output.push_str("}\n")
+    }
+    // generate symbol kinds
+    {
+      output.push_str("\n/// Kinds of symbols recognized by parser.\n");
+      output.push_str("#[allow(clippy::enum_variant_names)]\n");
+      output.push_str("pub enum SymbolKind {\n");
+      let _ = writeln!(output, "  YyEmpty = -2,\n  YyEof = 0,\n  YyError = 1,\n  YyUndef = 2,\n  YyAccept = {},", self.yy_n_tokens);
+      output.push_str("}\n")
+    }
+    {
+      // generate constants
+      output.push_str("\n///\n");
+      let _ = writeln!(output, "pub const YY_PACT_N_INF: i16 = {};", self.yy_pact_n_inf);
+      output.push_str("\n///\n");
+      let _ = writeln!(output, "pub const YY_TABLE_N_INF: i16 = {};", self.yy_table_n_inf);
+      output.push_str("\n///\n");
+      let _ = writeln!(output, "pub const YY_FINAL: usize = {};", self.yy_final);
+      output.push_str("\n///\n");
+      let _ = writeln!(output, "pub const YY_LAST: i16 = {};", self.yy_last);
+      output.push_str("\n///\n");
+      let _ = writeln!(output, "pub const YY_N_TOKENS: usize = {};", self.yy_n_tokens);
+    }
+    // generate tables
+    {
+      output.push_str("\n/// `YY_TRANSLATE[TOKEN-NUM]` - symbol number corresponding to TOKEN-NUM as returned by lexer.\n");
+      write_table(&mut output, "YY_TRANSLATE", &self.yy_translate);
+      output.push_str("\n/// `YY_PACT[STATE-NUM]` - index in YY_TABLE of the portion describing STATE-NUM.\n");
+      write_table(&mut output, "YY_PACT", &self.yy_pact);
+      output.push_str("\n/// `YY_DEF_ACT[STATE-NUM]` - default reduction number in state STATE-NUM.\n");
+      output.push_str("/// Performed when YY_TABLE does not specify something else to do.\n");
+      output.push_str("/// Zero means the default is an error.\n");
+      write_table(&mut output, "YY_DEF_ACT", &self.yy_def_act);
+      output.push_str("\n/// `YY_P_GOTO[NTERM-NUM]`\n");
+      write_table(&mut output, "YY_P_GOTO", &self.yy_p_goto);
+      output.push_str("\n/// `YY_DEF_GOTO[NTERM-NUM]`\n");
+      write_table(&mut output, "YY_DEF_GOTO", &self.yy_def_goto);
+      output.push_str("\n/// `YY_TABLE[YY_PACT[STATE-NUM]]` - what to do in state STATE-NUM.\n");
+      output.push_str("/// If positive, shift that token.\n");
+      output.push_str("/// If negative, reduce the rule whose number is the opposite.\n");
+      output.push_str("/// If `YY_TABLE_N_INF`, syntax error.\n");
+      write_table(&mut output, "YY_TABLE", &self.yy_table);
+      output.push_str("\n/// ???\n");
+      write_table(&mut output, "YY_CHECK", &self.yy_check);
+      output.push_str("\n/// `YY_R1[YYN]` - symbol number of symbol that rule YYN derives.\n");
+      write_table(&mut output, "YY_R1", &self.yy_r1);
+      output.push_str("\n/// `YY_R2[YYN]` - number of symbols on the right hand side of rule YYN.\n");
+      write_table(&mut output, "YY_R2", &self.yy_r2);
+    }
+    // generate reduce actions trait
+    {
+      output.push_str("\n///Trait for reduce action definitions.\n");
+      output.push_str("pub trait ReduceActions {\n");
+      let rule_names = self.actions.iter().map(|(_, _, name)| name.clone()).collect::<HashSet<String>>();
+      let mut sorted_rule_names = rule_names.iter().collect::<Vec<&String>>();
+      sorted_rule_names.sort();
+      for rule_name in sorted_rule_names {
+        let _ = writeln!(output, "  fn action_{rule_name}(&mut self) -> Result<()>;");
+      }
+      output.push_str("}\n");
+    }
+    // generate actions
+    {
+      output.push_str("\n/// Calls requested reduce action.\n");
+      output.push_str("pub fn reduce(reduce_actions: &mut impl ReduceActions, rule_number: i16) -> Result<()> {\n");
+      output.push_str("  match rule_number {\n");
+      for (num, comment, rule) in &self.actions {
+        let _ = writeln!(output, "    {num} => reduce_actions.action_{rule}(), // {comment}");
+      }
+      output.push_str("    _ => Ok(()),\n");
+      output.push_str("  }\n");
+      output.push_str("}\n");
+    }
+    output
+  }
+}
+
+/// Writes the table with elements of the smallest type holding all values, like `Bison` does.
+fn write_table(output: &mut String, name: &str, values: &[i64]) {
+  let min = values.iter().cloned().min().unwrap_or_default();
+  let max = values.iter().cloned().max().unwrap_or_default();
+  let table_type = if -127 <= min && max <= 127 {
+    "i8"
+  } else if 0 <= min && max <= 255 {
+    "u8"
+  } else if -32767 <= min && max <= 32767 {
+    "i16"
+  } else if 0 <= min && max <= 65535 {
+    "u16"
+  } else {
+    "i32"
+  };
+  let _ = writeln!(output, "pub const {name}: [{table_type}; {}] = [", values.len());
+  for value in values {
+    let _ = write!(output, "{value}, ");
+  }
+  output.push_str("\n];\n");
+}
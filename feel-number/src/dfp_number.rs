@@ -342,7 +342,8 @@ impl Display for FeelNumber {
   /// Converts [FeelNumber] to human readable string.
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     let s = bid128_to_string(self.0, flags!());
-    let negative = s.starts_with('-');
+    // negative zero is presented without sign
+    let negative = s.starts_with('-') && !self.is_zero();
     let mut split = s[1..].split('E');
     let (sb, sa) = split.next().zip(split.next()).unwrap(); // unwrap is ok, there is always E present
     let exponent = sa.parse::<isize>().unwrap(); // unwrap is ok, there is always correct exponent present
@@ -352,10 +353,9 @@ impl Display for FeelNumber {
       if digit_count <= decimal_points {
         let before = "0".to_string();
         let mut after = "0".repeat(decimal_points - digit_count);
+        after.push_str(sb);
         if self.1 {
-          after.push_str(sb.trim_end_matches('0'));
-        } else {
-          after.push_str(sb);
+          after = after.trim_end_matches('0').to_string();
         }
         (before, after)
       } else {
@@ -0,0 +1,26 @@
+use dmntk_common::{DmntkError, Language};
+use dmntk_feel_number::FeelNumber;
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn test_messages_001() {
+  assert_translated(
+    "1234a5".parse::<FeelNumber>().unwrap_err(),
+    "<FeelNumberError> niepoprawny literał liczbowy '1234a5'",
+    "<FeelNumberError> ungültiges Zahlenliteral '1234a5'",
+  );
+}
+
+#[test]
+fn test_messages_002() {
+  assert_translated(
+    u8::try_from(&FeelNumber::from(300)).unwrap_err(),
+    "<FeelNumberError> konwersja liczby nie powiodła się",
+    "<FeelNumberError> Zahlenkonvertierung fehlgeschlagen",
+  );
+}
@@ -6,3 +6,28 @@ use dmntk_feel_number::FeelNumber;
 fn test_minus_zero_001() {
   eqs!("0", FeelNumber::new(-0, 0).to_string());
 }
+
+#[test]
+fn test_minus_zero_002() {
+  eqs!("0", -num!(0));
+}
+
+#[test]
+fn test_minus_zero_003() {
+  eqs!("0", -FeelNumber::new(0, 2));
+}
+
+#[test]
+fn test_minus_zero_004() {
+  eqs!("0", num!(-1) * FeelNumber::new(0, 1));
+}
+
+#[test]
+fn test_minus_zero_005() {
+  eqs!("0", FeelNumber::new(0, 1) * num!(-1));
+}
+
+#[test]
+fn test_minus_zero_006() {
+  assert_eq!("0.00", format!("{:.2}", -num!(0)));
+}
@@ -51,3 +51,47 @@ fn test_to_string_009() {
 fn test_to_string_010() {
   assert_eq!("  50.1", format!("{:>6}", num!(50.1)));
 }
+
+#[test]
+fn test_to_string_011() {
+  eqs!("0.00001", FeelNumber::new(1, 5) / num!(1));
+}
+
+#[test]
+fn test_to_string_012() {
+  eqs!("1000000000000000000000000000000", "1E+30".parse::<FeelNumber>().unwrap());
+}
+
+#[test]
+fn test_to_string_013() {
+  eqs!("0.000000000000000000000000000000000000000123", "1.23E-40".parse::<FeelNumber>().unwrap());
+}
+
+#[test]
+fn test_to_string_014() {
+  let s = "1E+6000".parse::<FeelNumber>().unwrap().to_string();
+  assert_eq!(6001, s.len());
+  assert!(s.starts_with("10") && s.chars().skip(1).all(|ch| ch == '0'));
+}
+
+#[test]
+fn test_to_string_015() {
+  let s = "1E-6000".parse::<FeelNumber>().unwrap().to_string();
+  assert_eq!(6002, s.len());
+  assert!(s.starts_with("0.0") && s.ends_with("01"));
+}
+
+#[test]
+fn test_to_string_016() {
+  eqs!("-0.125", num!(-0.125));
+}
+
+#[test]
+fn test_to_string_017() {
+  eqs!("123456789012345678901234567890123500000", num!(123456789012345678901234567890123456789));
+}
+
+#[test]
+fn test_to_string_018() {
+  eqs!("0.05", FeelNumber::new(500, 4) / num!(1));
+}
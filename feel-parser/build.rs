@@ -1,4 +1,4 @@
-use dmntk_feel_grammar::lalr_rust_tables;
+use dmntk_feel_grammar::{lalr_conflicts, lalr_rust_tables};
 
 fn main() {
   if std::env::var("CARGO_FEATURE_PARSING_TABLES").is_ok() {
@@ -6,4 +6,11 @@ fn main() {
     // when executed with --features=parsing-tables
     lalr_rust_tables("./src/lalr.rs");
   }
+  if std::env::var("CARGO_FEATURE_GRAMMAR_CONFLICTS").is_ok() {
+    // report conflicts in FEEL grammar as build warnings
+    // when executed with --features=grammar-conflicts
+    for line in lalr_conflicts().lines() {
+      println!("cargo:warning={line}");
+    }
+  }
 }
@@ -308,6 +308,254 @@ impl AstNode {
     println!("{output}");
     output
   }
+
+  /// Returns the normalized copy of this node.
+  ///
+  /// Normalized nodes may be compared to check if two expressions are syntactically
+  /// equivalent, regardless of their formatting. Whitespace, comments and parentheses
+  /// are already not present in the AST, so the normalization only unifies
+  /// the notation of numeric literals, like `1`, `01` and `1.00`.
+  pub fn normalized(&self) -> AstNode {
+    match self {
+      AstNode::Add(lhs, rhs) => AstNode::Add(n(lhs), n(rhs)),
+      AstNode::And(lhs, rhs) => AstNode::And(n(lhs), n(rhs)),
+      AstNode::At(mid) => AstNode::At(mid.clone()),
+      AstNode::Between(lhs, mid, rhs) => AstNode::Between(n(lhs), n(mid), n(rhs)),
+      AstNode::Boolean(mid) => AstNode::Boolean(*mid),
+      AstNode::CommaList(items) => AstNode::CommaList(n_items(items)),
+      AstNode::Context(items) => AstNode::Context(n_items(items)),
+      AstNode::ContextEntry(lhs, rhs) => AstNode::ContextEntry(n(lhs), n(rhs)),
+      AstNode::ContextEntryKey(mid) => AstNode::ContextEntryKey(mid.clone()),
+      AstNode::ContextType(items) => AstNode::ContextType(n_items(items)),
+      AstNode::ContextTypeEntry(lhs, rhs) => AstNode::ContextTypeEntry(n(lhs), n(rhs)),
+      AstNode::ContextTypeEntryKey(mid) => AstNode::ContextTypeEntryKey(mid.clone()),
+      AstNode::Div(lhs, rhs) => AstNode::Div(n(lhs), n(rhs)),
+      AstNode::Eq(lhs, rhs) => AstNode::Eq(n(lhs), n(rhs)),
+      AstNode::EvaluatedExpression(mid) => AstNode::EvaluatedExpression(n(mid)),
+      AstNode::Every(lhs, rhs) => AstNode::Every(n(lhs), n(rhs)),
+      AstNode::Exp(lhs, rhs) => AstNode::Exp(n(lhs), n(rhs)),
+      AstNode::ExpressionList(items) => AstNode::ExpressionList(n_items(items)),
+      AstNode::FeelType(mid) => AstNode::FeelType(mid.clone()),
+      AstNode::Filter(lhs, rhs) => AstNode::Filter(n(lhs), n(rhs)),
+      AstNode::For(lhs, rhs) => AstNode::For(n(lhs), n(rhs)),
+      AstNode::FormalParameter(lhs, rhs) => AstNode::FormalParameter(n(lhs), n(rhs)),
+      AstNode::FormalParameters(items) => AstNode::FormalParameters(n_items(items)),
+      AstNode::FunctionBody(lhs, external) => AstNode::FunctionBody(n(lhs), *external),
+      AstNode::FunctionDefinition(lhs, rhs) => AstNode::FunctionDefinition(n(lhs), n(rhs)),
+      AstNode::FunctionInvocation(lhs, rhs) => AstNode::FunctionInvocation(n(lhs), n(rhs)),
+      AstNode::FunctionType(lhs, rhs) => AstNode::FunctionType(n(lhs), n(rhs)),
+      AstNode::Ge(lhs, rhs) => AstNode::Ge(n(lhs), n(rhs)),
+      AstNode::Gt(lhs, rhs) => AstNode::Gt(n(lhs), n(rhs)),
+      AstNode::If(lhs, mid, rhs) => AstNode::If(n(lhs), n(mid), n(rhs)),
+      AstNode::In(lhs, rhs) => AstNode::In(n(lhs), n(rhs)),
+      AstNode::InstanceOf(lhs, rhs) => AstNode::InstanceOf(n(lhs), n(rhs)),
+      AstNode::IntervalEnd(lhs, closed) => AstNode::IntervalEnd(n(lhs), *closed),
+      AstNode::IntervalStart(lhs, closed) => AstNode::IntervalStart(n(lhs), *closed),
+      AstNode::Irrelevant => AstNode::Irrelevant,
+      AstNode::IterationContexts(items) => AstNode::IterationContexts(n_items(items)),
+      AstNode::IterationContextSingle(lhs, rhs) => AstNode::IterationContextSingle(n(lhs), n(rhs)),
+      AstNode::IterationContextRange(lhs, mid, rhs) => AstNode::IterationContextRange(n(lhs), n(mid), n(rhs)),
+      AstNode::Le(lhs, rhs) => AstNode::Le(n(lhs), n(rhs)),
+      AstNode::List(items) => AstNode::List(n_items(items)),
+      AstNode::ListType(lhs) => AstNode::ListType(n(lhs)),
+      AstNode::Lt(lhs, rhs) => AstNode::Lt(n(lhs), n(rhs)),
+      AstNode::Mul(lhs, rhs) => AstNode::Mul(n(lhs), n(rhs)),
+      AstNode::Name(mid) => AstNode::Name(mid.clone()),
+      AstNode::NamedParameter(lhs, rhs) => AstNode::NamedParameter(n(lhs), n(rhs)),
+      AstNode::NamedParameters(items) => AstNode::NamedParameters(n_items(items)),
+      AstNode::Neg(mid) => AstNode::Neg(n(mid)),
+      AstNode::NegatedList(items) => AstNode::NegatedList(n_items(items)),
+      AstNode::Nq(lhs, rhs) => AstNode::Nq(n(lhs), n(rhs)),
+      AstNode::Null => AstNode::Null,
+      AstNode::Numeric(lhs, rhs) => {
+        let before = lhs.trim_start_matches('0');
+        let after = rhs.trim_end_matches('0');
+        AstNode::Numeric(if before.is_empty() { "0" } else { before }.to_string(), after.to_string())
+      }
+      AstNode::Or(lhs, rhs) => AstNode::Or(n(lhs), n(rhs)),
+      AstNode::Out(lhs, rhs) => AstNode::Out(n(lhs), n(rhs)),
+      AstNode::ParameterName(mid) => AstNode::ParameterName(mid.clone()),
+      AstNode::ParameterTypes(items) => AstNode::ParameterTypes(n_items(items)),
+      AstNode::Path(lhs, rhs) => AstNode::Path(n(lhs), n(rhs)),
+      AstNode::PositionalParameters(items) => AstNode::PositionalParameters(n_items(items)),
+      AstNode::QualifiedName(items) => AstNode::QualifiedName(n_items(items)),
+      AstNode::QualifiedNameSegment(mid) => AstNode::QualifiedNameSegment(mid.clone()),
+      AstNode::QuantifiedContext(lhs, rhs) => AstNode::QuantifiedContext(n(lhs), n(rhs)),
+      AstNode::QuantifiedContexts(items) => AstNode::QuantifiedContexts(n_items(items)),
+      AstNode::Range(lhs, rhs) => AstNode::Range(n(lhs), n(rhs)),
+      AstNode::RangeType(lhs) => AstNode::RangeType(n(lhs)),
+      AstNode::Satisfies(mid) => AstNode::Satisfies(n(mid)),
+      AstNode::Some(lhs, rhs) => AstNode::Some(n(lhs), n(rhs)),
+      AstNode::String(mid) => AstNode::String(mid.clone()),
+      AstNode::Sub(lhs, rhs) => AstNode::Sub(n(lhs), n(rhs)),
+      AstNode::UnaryGe(mid) => AstNode::UnaryGe(n(mid)),
+      AstNode::UnaryGt(mid) => AstNode::UnaryGt(n(mid)),
+      AstNode::UnaryLe(mid) => AstNode::UnaryLe(n(mid)),
+      AstNode::UnaryLt(mid) => AstNode::UnaryLt(n(mid)),
+    }
+  }
+
+  /// Returns references to child nodes, in the order of appearance in this node.
+  pub fn children(&self) -> Vec<&AstNode> {
+    match self {
+      AstNode::Add(lhs, rhs)
+      | AstNode::And(lhs, rhs)
+      | AstNode::ContextEntry(lhs, rhs)
+      | AstNode::ContextTypeEntry(lhs, rhs)
+      | AstNode::Div(lhs, rhs)
+      | AstNode::Eq(lhs, rhs)
+      | AstNode::Every(lhs, rhs)
+      | AstNode::Exp(lhs, rhs)
+      | AstNode::Filter(lhs, rhs)
+      | AstNode::For(lhs, rhs)
+      | AstNode::FormalParameter(lhs, rhs)
+      | AstNode::FunctionDefinition(lhs, rhs)
+      | AstNode::FunctionInvocation(lhs, rhs)
+      | AstNode::FunctionType(lhs, rhs)
+      | AstNode::Ge(lhs, rhs)
+      | AstNode::Gt(lhs, rhs)
+      | AstNode::In(lhs, rhs)
+      | AstNode::InstanceOf(lhs, rhs)
+      | AstNode::IterationContextSingle(lhs, rhs)
+      | AstNode::Le(lhs, rhs)
+      | AstNode::Lt(lhs, rhs)
+      | AstNode::Mul(lhs, rhs)
+      | AstNode::NamedParameter(lhs, rhs)
+      | AstNode::Nq(lhs, rhs)
+      | AstNode::Or(lhs, rhs)
+      | AstNode::Out(lhs, rhs)
+      | AstNode::Path(lhs, rhs)
+      | AstNode::QuantifiedContext(lhs, rhs)
+      | AstNode::Range(lhs, rhs)
+      | AstNode::Some(lhs, rhs)
+      | AstNode::Sub(lhs, rhs) => vec![lhs, rhs],
+      AstNode::Between(lhs, mid, rhs) | AstNode::If(lhs, mid, rhs) | AstNode::IterationContextRange(lhs, mid, rhs) => vec![lhs, mid, rhs],
+      AstNode::EvaluatedExpression(mid)
+      | AstNode::FunctionBody(mid, _)
+      | AstNode::IntervalEnd(mid, _)
+      | AstNode::IntervalStart(mid, _)
+      | AstNode::ListType(mid)
+      | AstNode::Neg(mid)
+      | AstNode::RangeType(mid)
+      | AstNode::Satisfies(mid)
+      | AstNode::UnaryGe(mid)
+      | AstNode::UnaryGt(mid)
+      | AstNode::UnaryLe(mid)
+      | AstNode::UnaryLt(mid) => vec![mid],
+      AstNode::CommaList(items)
+      | AstNode::Context(items)
+      | AstNode::ContextType(items)
+      | AstNode::ExpressionList(items)
+      | AstNode::FormalParameters(items)
+      | AstNode::IterationContexts(items)
+      | AstNode::List(items)
+      | AstNode::NamedParameters(items)
+      | AstNode::NegatedList(items)
+      | AstNode::ParameterTypes(items)
+      | AstNode::PositionalParameters(items)
+      | AstNode::QualifiedName(items)
+      | AstNode::QuantifiedContexts(items) => items.iter().collect(),
+      AstNode::At(_)
+      | AstNode::Boolean(_)
+      | AstNode::ContextEntryKey(_)
+      | AstNode::ContextTypeEntryKey(_)
+      | AstNode::FeelType(_)
+      | AstNode::Irrelevant
+      | AstNode::Name(_)
+      | AstNode::Null
+      | AstNode::Numeric(_, _)
+      | AstNode::ParameterName(_)
+      | AstNode::QualifiedNameSegment(_)
+      | AstNode::String(_) => vec![],
+    }
+  }
+
+  /// Returns `true` when this node or any of its descendants is the name `?`,
+  /// referencing the input value in unary tests.
+  pub fn uses_input_value(&self) -> bool {
+    match self {
+      AstNode::Name(name) | AstNode::QualifiedNameSegment(name) => name.to_string() == "?",
+      AstNode::Add(lhs, rhs)
+      | AstNode::And(lhs, rhs)
+      | AstNode::ContextEntry(lhs, rhs)
+      | AstNode::ContextTypeEntry(lhs, rhs)
+      | AstNode::Div(lhs, rhs)
+      | AstNode::Eq(lhs, rhs)
+      | AstNode::Every(lhs, rhs)
+      | AstNode::Exp(lhs, rhs)
+      | AstNode::Filter(lhs, rhs)
+      | AstNode::For(lhs, rhs)
+      | AstNode::FormalParameter(lhs, rhs)
+      | AstNode::FunctionDefinition(lhs, rhs)
+      | AstNode::FunctionInvocation(lhs, rhs)
+      | AstNode::FunctionType(lhs, rhs)
+      | AstNode::Ge(lhs, rhs)
+      | AstNode::Gt(lhs, rhs)
+      | AstNode::In(lhs, rhs)
+      | AstNode::InstanceOf(lhs, rhs)
+      | AstNode::IterationContextSingle(lhs, rhs)
+      | AstNode::Le(lhs, rhs)
+      | AstNode::Lt(lhs, rhs)
+      | AstNode::Mul(lhs, rhs)
+      | AstNode::NamedParameter(lhs, rhs)
+      | AstNode::Nq(lhs, rhs)
+      | AstNode::Or(lhs, rhs)
+      | AstNode::Out(lhs, rhs)
+      | AstNode::Path(lhs, rhs)
+      | AstNode::QuantifiedContext(lhs, rhs)
+      | AstNode::Range(lhs, rhs)
+      | AstNode::Some(lhs, rhs)
+      | AstNode::Sub(lhs, rhs) => lhs.uses_input_value() || rhs.uses_input_value(),
+      AstNode::Between(lhs, mid, rhs) | AstNode::If(lhs, mid, rhs) | AstNode::IterationContextRange(lhs, mid, rhs) => {
+        lhs.uses_input_value() || mid.uses_input_value() || rhs.uses_input_value()
+      }
+      AstNode::EvaluatedExpression(mid)
+      | AstNode::FunctionBody(mid, _)
+      | AstNode::IntervalEnd(mid, _)
+      | AstNode::IntervalStart(mid, _)
+      | AstNode::ListType(mid)
+      | AstNode::Neg(mid)
+      | AstNode::RangeType(mid)
+      | AstNode::Satisfies(mid)
+      | AstNode::UnaryGe(mid)
+      | AstNode::UnaryGt(mid)
+      | AstNode::UnaryLe(mid)
+      | AstNode::UnaryLt(mid) => mid.uses_input_value(),
+      AstNode::CommaList(items)
+      | AstNode::Context(items)
+      | AstNode::ContextType(items)
+      | AstNode::ExpressionList(items)
+      | AstNode::FormalParameters(items)
+      | AstNode::IterationContexts(items)
+      | AstNode::List(items)
+      | AstNode::NamedParameters(items)
+      | AstNode::NegatedList(items)
+      | AstNode::ParameterTypes(items)
+      | AstNode::PositionalParameters(items)
+      | AstNode::QualifiedName(items)
+      | AstNode::QuantifiedContexts(items) => items.iter().any(AstNode::uses_input_value),
+      AstNode::At(_)
+      | AstNode::Boolean(_)
+      | AstNode::ContextEntryKey(_)
+      | AstNode::ContextTypeEntryKey(_)
+      | AstNode::FeelType(_)
+      | AstNode::Irrelevant
+      | AstNode::Null
+      | AstNode::Numeric(_, _)
+      | AstNode::ParameterName(_)
+      | AstNode::String(_) => false,
+    }
+  }
+}
+
+/// Returns normalized copy of the boxed node.
+fn n(node: &AstNode) -> Box<AstNode> {
+  Box::new(node.normalized())
+}
+
+/// Returns normalized copies of multiple nodes.
+fn n_items(items: &[AstNode]) -> Vec<AstNode> {
+  items.iter().map(AstNode::normalized).collect()
 }
 
 /// Returns ASCII tree representation of the specified node.
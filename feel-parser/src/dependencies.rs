@@ -0,0 +1,222 @@
+//! Implementation of the collector of names referenced by expressions.
+//!
+//! Names bound inside the expression (context entries, function parameters, iteration
+//! and quantified variables, `partial` and `item`) and names of built-in functions are
+//! not reported. Paths are reported with all segments, e.g. `Applicant.age`. Names used
+//! in filter conditions may refer to entries of filtered contexts, so they are reported
+//! like names of variables.
+
+use crate::AstNode;
+use dmntk_feel::bif::is_built_in_function_name;
+use dmntk_feel::{Name, QualifiedName};
+use std::collections::BTreeSet;
+
+/// Returns names and paths of variables referenced by the expression,
+/// but not defined in this expression.
+pub fn dependencies(node: &AstNode) -> BTreeSet<QualifiedName> {
+  let mut collector = DependencyCollector::default();
+  collector.visit(node);
+  collector.names
+}
+
+/// Collector of names referenced by expressions.
+#[derive(Default)]
+struct DependencyCollector {
+  /// Names bound in the currently visited part of the expression.
+  bound_names: Vec<Name>,
+  /// Collected names.
+  names: BTreeSet<QualifiedName>,
+}
+
+impl DependencyCollector {
+  /// Visits a single AST node.
+  fn visit(&mut self, node: &AstNode) {
+    match node {
+      AstNode::Add(lhs, rhs)
+      | AstNode::And(lhs, rhs)
+      | AstNode::Div(lhs, rhs)
+      | AstNode::Eq(lhs, rhs)
+      | AstNode::Exp(lhs, rhs)
+      | AstNode::Ge(lhs, rhs)
+      | AstNode::Gt(lhs, rhs)
+      | AstNode::In(lhs, rhs)
+      | AstNode::Le(lhs, rhs)
+      | AstNode::Lt(lhs, rhs)
+      | AstNode::Mul(lhs, rhs)
+      | AstNode::Nq(lhs, rhs)
+      | AstNode::Or(lhs, rhs)
+      | AstNode::Out(lhs, rhs)
+      | AstNode::Range(lhs, rhs)
+      | AstNode::Sub(lhs, rhs) => {
+        self.visit(lhs);
+        self.visit(rhs);
+      }
+      AstNode::Between(lhs, mhs, rhs) | AstNode::If(lhs, mhs, rhs) => {
+        self.visit(lhs);
+        self.visit(mhs);
+        self.visit(rhs);
+      }
+      AstNode::EvaluatedExpression(lhs)
+      | AstNode::IntervalEnd(lhs, _)
+      | AstNode::IntervalStart(lhs, _)
+      | AstNode::Neg(lhs)
+      | AstNode::Satisfies(lhs)
+      | AstNode::UnaryGe(lhs)
+      | AstNode::UnaryGt(lhs)
+      | AstNode::UnaryLe(lhs)
+      | AstNode::UnaryLt(lhs) => self.visit(lhs),
+      AstNode::CommaList(items)
+      | AstNode::ExpressionList(items)
+      | AstNode::List(items)
+      | AstNode::NamedParameters(items)
+      | AstNode::NegatedList(items)
+      | AstNode::PositionalParameters(items) => items.iter().for_each(|item| self.visit(item)),
+      AstNode::At(_)
+      | AstNode::Boolean(_)
+      | AstNode::ContextEntryKey(_)
+      | AstNode::ContextType(_)
+      | AstNode::ContextTypeEntry(_, _)
+      | AstNode::ContextTypeEntryKey(_)
+      | AstNode::FeelType(_)
+      | AstNode::FormalParameter(_, _)
+      | AstNode::FormalParameters(_)
+      | AstNode::FunctionType(_, _)
+      | AstNode::Irrelevant
+      | AstNode::IterationContexts(_)
+      | AstNode::IterationContextSingle(_, _)
+      | AstNode::IterationContextRange(_, _, _)
+      | AstNode::ListType(_)
+      | AstNode::Null
+      | AstNode::Numeric(_, _)
+      | AstNode::ParameterName(_)
+      | AstNode::ParameterTypes(_)
+      | AstNode::QualifiedName(_)
+      | AstNode::QualifiedNameSegment(_)
+      | AstNode::QuantifiedContext(_, _)
+      | AstNode::QuantifiedContexts(_)
+      | AstNode::RangeType(_)
+      | AstNode::String(_) => {}
+      AstNode::Context(entries) => self.bound(|collector| {
+        for entry in entries {
+          if let AstNode::ContextEntry(key, value) = entry {
+            collector.visit(value);
+            if let AstNode::ContextEntryKey(name) = key.as_ref() {
+              collector.bound_names.push(name.clone());
+            }
+          }
+        }
+      }),
+      AstNode::ContextEntry(_, value) => self.visit(value),
+      AstNode::Every(contexts, satisfies) | AstNode::Some(contexts, satisfies) => self.bound(|collector| {
+        if let AstNode::QuantifiedContexts(items) = contexts.as_ref() {
+          for item in items {
+            if let AstNode::QuantifiedContext(variable, expression) = item {
+              collector.visit(expression);
+              collector.bind(variable);
+            }
+          }
+        }
+        collector.visit(satisfies);
+      }),
+      AstNode::Filter(lhs, rhs) => {
+        self.visit(lhs);
+        self.bound(|collector| {
+          collector.bound_names.push(Name::from("item"));
+          collector.visit(rhs);
+        });
+      }
+      AstNode::For(contexts, body) => self.bound(|collector| {
+        if let AstNode::IterationContexts(items) = contexts.as_ref() {
+          for item in items {
+            match item {
+              AstNode::IterationContextSingle(variable, expression) => {
+                collector.visit(expression);
+                collector.bind(variable);
+              }
+              AstNode::IterationContextRange(variable, start, end) => {
+                collector.visit(start);
+                collector.visit(end);
+                collector.bind(variable);
+              }
+              _ => {}
+            }
+          }
+        }
+        collector.bound_names.push(Name::from("partial"));
+        collector.visit(body);
+      }),
+      AstNode::FunctionBody(body, _) => self.visit(body),
+      AstNode::FunctionDefinition(parameters, body) => self.bound(|collector| {
+        if let AstNode::FormalParameters(items) = parameters.as_ref() {
+          for item in items {
+            if let AstNode::FormalParameter(name, _) = item {
+              collector.bind(name);
+            }
+          }
+        }
+        collector.visit(body);
+      }),
+      AstNode::FunctionInvocation(function, parameters) => {
+        match function.as_ref() {
+          AstNode::Name(name) if is_built_in_function_name(&name.to_string()) && !self.is_bound(name) => {}
+          other => self.visit(other),
+        }
+        self.visit(parameters);
+      }
+      AstNode::InstanceOf(lhs, _) => self.visit(lhs),
+      AstNode::Name(name) => self.reference(vec![name.clone()]),
+      AstNode::NamedParameter(_, value) => self.visit(value),
+      AstNode::Path(lhs, rhs) => {
+        if let Some(segments) = path_segments(node) {
+          self.reference(segments);
+        } else {
+          self.visit(lhs);
+          if path_segments(rhs).is_none() {
+            self.visit(rhs);
+          }
+        }
+      }
+    }
+  }
+
+  /// Adds the name of the variable to bound names.
+  fn bind(&mut self, variable: &AstNode) {
+    if let AstNode::Name(name) | AstNode::ParameterName(name) = variable {
+      self.bound_names.push(name.clone());
+    }
+  }
+
+  /// Calls the visitor, names bound by the visitor are released afterwards.
+  fn bound(&mut self, visitor: impl FnOnce(&mut Self)) {
+    let length = self.bound_names.len();
+    visitor(self);
+    self.bound_names.truncate(length);
+  }
+
+  /// Returns `true` when the name is bound in the currently visited part of the expression.
+  fn is_bound(&self, name: &Name) -> bool {
+    self.bound_names.contains(name)
+  }
+
+  /// Collects referenced name or path, unless its first segment is bound.
+  fn reference(&mut self, segments: Vec<Name>) {
+    if let Some(first) = segments.first() {
+      if !self.is_bound(first) && first.to_string() != "?" {
+        self.names.insert(segments.into());
+      }
+    }
+  }
+}
+
+/// Returns segments of the path built only from names.
+fn path_segments(node: &AstNode) -> Option<Vec<Name>> {
+  match node {
+    AstNode::Name(name) => Some(vec![name.clone()]),
+    AstNode::Path(lhs, rhs) => {
+      let mut segments = path_segments(lhs)?;
+      segments.append(&mut path_segments(rhs)?);
+      Some(segments)
+    }
+    _ => None,
+  }
+}
@@ -1,4 +1,4 @@
-//! Parsing tables extracted from files generated by `Bison` for `C` language.
+//! Parsing tables generated from `FEEL` grammar by `LALR` parsing tables generator.
 //! This file was generated by dedicated tool, do not modify manually.
 
 use dmntk_common::Result;
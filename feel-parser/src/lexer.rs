@@ -82,6 +82,7 @@ pub enum TokenValue {
 }
 
 /// FEEL lexer.
+#[derive(Clone)]
 pub struct Lexer<'lexer> {
   /// Parsing scope.
   scope: &'lexer ParsingScope,
@@ -91,18 +92,21 @@ pub struct Lexer<'lexer> {
   input: Vec<char>,
   /// Current cursor position in input vector.
   position: usize,
+  /// Position of the first character of the last read token in input vector.
+  token_start: usize,
   /// Flag indicating if the unary tests rule is the starting point.
   /// This flag is used to identify `not` keyword,
   /// which otherwise would be recognized as a name.
   /// Token `not` is a keyword at the very beginning of the unary tests rule,
   /// in all other contexts it is just a name.
   unary_tests: bool,
-  /// Flag indicating if the `between` keyword was encountered.
-  /// When this flag is set, the next `and` token is returned as `band` keyword,
+  /// Number of encountered `between` keywords still waiting for their `and`.
+  /// When this number is not zero, the next `and` token is returned as `band` keyword,
   /// otherwise it is returned as `and`. This allows to disambiguate the `and`
   /// operator used in between clause from conjunction.
-  /// After consuming first `and` as `band` this flag is cleared by the lexer.
-  between: bool,
+  /// After consuming `and` as `band` this number is decremented by the lexer,
+  /// so `between` clauses nested in operands of other `between` clauses are recognized.
+  between: usize,
   /// ???
   type_name: bool,
   /// ???
@@ -118,8 +122,9 @@ impl<'lexer> Lexer<'lexer> {
       start_token_type: Some(start_token_type),
       input: input.chars().collect(),
       position: 0,
+      token_start: 0,
       unary_tests: false,
-      between: false,
+      between: 0,
       type_name: false,
       till_in: false,
     }
@@ -130,7 +135,7 @@ impl<'lexer> Lexer<'lexer> {
   }
 
   pub fn set_between(&mut self) {
-    self.between = true;
+    self.between += 1;
   }
 
   pub fn set_type_name(&mut self) {
@@ -153,6 +158,27 @@ impl<'lexer> Lexer<'lexer> {
     self.scope.set_name(name.to_owned());
   }
 
+  /// Returns positions of the first character of the last read token and the character following it.
+  pub fn token_span(&self) -> (usize, usize) {
+    (self.token_start, self.position.max(self.token_start))
+  }
+
+  /// Skips specified number of characters on input, used to skip characters not recognized as tokens.
+  pub fn skip(&mut self, count: usize) {
+    self.position = (self.position + count).min(self.input.len());
+  }
+
+  /// Moves the cursor back to specified position, used to read again the rest of the name
+  /// after its first part was recognized as a misspelled keyword.
+  pub fn rewind(&mut self, position: usize) {
+    self.position = position.min(self.position);
+  }
+
+  /// Returns the type of the next token without consuming it.
+  pub fn peek_token_type(&self) -> i16 {
+    self.clone().next_token().map_or(TokenType::YyError as i16, |(token_type, _)| token_type as i16)
+  }
+
   /// Returns the next token from input.
   pub fn next_token(&mut self) -> Result<(TokenType, TokenValue)> {
     if let Some(start_token_type) = self.start_token_type.clone() {
@@ -240,12 +266,12 @@ impl<'lexer> Lexer<'lexer> {
         self.position += 4;
         Ok((TokenType::Boolean, TokenValue::Boolean(true)))
       }
-      ['a', 'n', 'd', WS, _, _, _, _, _, _, _, _] if !self.between => {
+      ['a', 'n', 'd', WS, _, _, _, _, _, _, _, _] if self.between == 0 => {
         self.position += 3;
         Ok((TokenType::And, TokenValue::And))
       }
-      ['a', 'n', 'd', WS, _, _, _, _, _, _, _, _] if self.between => {
-        self.between = false;
+      ['a', 'n', 'd', WS, _, _, _, _, _, _, _, _] if self.between > 0 => {
+        self.between -= 1;
         self.position += 3;
         Ok((TokenType::BetweenAnd, TokenValue::BetweenAnd))
       }
@@ -396,6 +422,7 @@ impl<'lexer> Lexer<'lexer> {
         break;
       }
     }
+    self.token_start = self.position;
     let mut buffer: [char; BUF_SIZE] = [WS; BUF_SIZE];
     for (offset, value) in buffer.iter_mut().enumerate() {
       if let Some(ch) = self.char_at(offset) {
@@ -525,6 +552,15 @@ impl<'lexer> Lexer<'lexer> {
 
   /// Consumes a name.
   fn consume_name(&mut self) -> Result<(TokenType, TokenValue)> {
+    // ------------------------------------------------------------------------
+    // tweak with the name `?` referencing the input value in unary tests,
+    // when not followed by name part character, it is always a single-part name,
+    // so keywords and names following `?` are not swallowed by the longest name
+    // ------------------------------------------------------------------------
+    if self.peek_character() == '?' && !self.is_next_name_part_char() {
+      self.position += 1;
+      return Ok((TokenType::Name, TokenValue::Name(Name::from("?"))));
+    }
     // collection of all name parts
     let mut parts = vec![];
     // currently parsed name part
@@ -902,6 +938,15 @@ fn is_additional_name_symbol(ch: char) -> bool {
   matches!(ch, '.' | '/' | '-' | '\'' | '+' | '*')
 }
 
+/// Returns `true` when the specified input is a valid name.
+/// The name starts with name start character, followed by name part characters,
+/// additional name symbols and whitespace separating name parts.
+/// Specification: 10.3.1.2 Grammar rules, p.120, grammar rules 27-30.
+pub fn is_name(input: &str) -> bool {
+  let mut chars = input.trim().chars();
+  chars.next().is_some_and(is_name_start_char) && chars.all(|ch| is_name_part_char(ch) || is_additional_name_symbol(ch) || is_whitespace(ch))
+}
+
 /// Returns `true` when the specified character is name start character.
 /// Specification: 10.3.1.2 Grammar rules, p.120, grammar rule 28.
 fn is_name_start_char(ch: char) -> bool {
@@ -6,10 +6,12 @@ extern crate dmntk_macros;
 mod ast;
 mod closure;
 mod context;
+mod dependencies;
 mod errors;
 mod lalr;
 mod lexer;
 mod parser;
+mod recovery;
 mod scope;
 
 #[cfg(test)]
@@ -17,19 +19,55 @@ mod tests;
 
 pub use ast::{ast_tree, AstNode};
 pub use closure::ClosureBuilder;
+pub use dependencies::dependencies;
+pub use lexer::is_name;
+pub use recovery::SyntaxError;
 pub use scope::ParsingScope;
 
 use crate::errors::*;
 use crate::lalr::TokenType;
 use crate::parser::Parser;
 use dmntk_common::Result;
-use dmntk_feel::{FeelScope, Name};
+use dmntk_feel::{FeelScope, Name, QualifiedName};
+use std::collections::BTreeSet;
 
 /// Parses an `expression` as defined in grammar rule `1`.
 pub fn parse_expression(scope: &FeelScope, input: &str, trace: bool) -> Result<AstNode> {
   Parser::new(&scope.into(), TokenType::StartExpression, input, trace).parse()
 }
 
+/// Checks if two expressions, both parsed as defined in grammar rule `1`,
+/// are syntactically equivalent, regardless of formatting.
+///
+/// Expressions are equivalent when their normalized abstract syntax trees are equal,
+/// so differences in whitespace, comments, redundant parentheses
+/// and notation of numeric literals are ignored.
+pub fn equivalent_expressions(scope: &FeelScope, lhs: &str, rhs: &str) -> Result<bool> {
+  let lhs_node = parse_expression(scope, lhs, false)?;
+  let rhs_node = parse_expression(scope, rhs, false)?;
+  Ok(lhs_node.normalized() == rhs_node.normalized())
+}
+
+/// Parses an `expression` as defined in grammar rule `1` and returns names and paths
+/// of variables referenced by this expression, see [dependencies] for details.
+pub fn expression_dependencies(scope: &FeelScope, input: &str) -> Result<BTreeSet<QualifiedName>> {
+  Ok(dependencies(&parse_expression(scope, input, false)?))
+}
+
+/// Parses an `expression` as defined in grammar rule `1`, recovering from syntax errors.
+///
+/// Returns the node built from the input repaired as described in [SyntaxError] reports,
+/// when the input could be repaired, and all syntax errors found in the input.
+pub fn parse_expression_with_recovery(scope: &FeelScope, input: &str) -> (Option<AstNode>, Vec<SyntaxError>) {
+  Parser::new(&scope.into(), TokenType::StartExpression, input, false).parse_with_recovery()
+}
+
+/// Parses `unary tests` as defined in grammar rule `17`, recovering from syntax errors,
+/// see [parse_expression_with_recovery] for details.
+pub fn parse_unary_tests_with_recovery(scope: &FeelScope, input: &str) -> (Option<AstNode>, Vec<SyntaxError>) {
+  Parser::new(&scope.into(), TokenType::StartUnaryTests, input, false).parse_with_recovery()
+}
+
 /// Parses a `textual expression` as defined in grammar rule `2`.
 pub fn parse_textual_expression(scope: &FeelScope, input: &str, trace: bool) -> Result<AstNode> {
   Parser::new(&scope.into(), TokenType::StartTextualExpression, input, trace).parse()
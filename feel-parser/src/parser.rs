@@ -3,6 +3,7 @@
 use crate::errors::*;
 use crate::lalr::*;
 use crate::lexer::*;
+use crate::recovery::*;
 use crate::scope::ParsingScope;
 use crate::AstNode;
 use dmntk_common::Result;
@@ -66,6 +67,20 @@ pub struct Parser<'parser> {
   yy_value_stack: Vec<TokenValue>,
   /// AST node stack.
   yy_node_stack: Vec<AstNode>,
+  /// Positions of the first character of the lookahead token and the character following it.
+  yy_span: (usize, usize),
+  /// Flag indicating if the last shifted token was a comma, not followed by any reduction.
+  yy_comma: bool,
+  /// Flag indicating whether the parser recovers from syntax errors.
+  yy_recover: bool,
+  /// Syntax errors reported while parsing with recovery.
+  yy_errors: Vec<SyntaxError>,
+  /// Token read before inserting the missing token, processed after the inserted token is shifted.
+  yy_pending: Option<(i16, TokenValue, (usize, usize))>,
+  /// Flag indicating if the last syntax error skipped the unexpected token and no token was shifted since.
+  yy_skipping: bool,
+  /// Flag indicating if the parser stopped recovering from syntax errors.
+  yy_aborted: bool,
 }
 
 impl<'parser> Parser<'parser> {
@@ -86,6 +101,32 @@ impl<'parser> Parser<'parser> {
       yy_state_stack: vec![0],
       yy_value_stack: vec![TokenValue::YyEmpty],
       yy_node_stack: vec![],
+      yy_span: (0, 0),
+      yy_comma: false,
+      yy_recover: false,
+      yy_errors: vec![],
+      yy_pending: None,
+      yy_skipping: false,
+      yy_aborted: false,
+    }
+  }
+
+  /// Parses the input recovering from syntax errors, see [recovery](crate::recovery) for details.
+  ///
+  /// Returns the node built from the repaired input, when the input could be repaired,
+  /// and all syntax errors found in the input.
+  pub fn parse_with_recovery(&mut self) -> (Option<AstNode>, Vec<SyntaxError>) {
+    self.yy_recover = true;
+    let result = self.parse();
+    let mut errors = std::mem::take(&mut self.yy_errors);
+    match result {
+      Ok(node) => (Some(node), errors),
+      Err(reason) => {
+        if !self.yy_aborted {
+          errors.push(SyntaxError::new(self.yy_span.0, self.yy_span.1, reason.to_string(), vec![], None));
+        }
+        (None, errors)
+      }
     }
   }
 
@@ -110,10 +151,26 @@ impl<'parser> Parser<'parser> {
           }
           // not known, so get a lookahead token if don't already have one
           if self.yy_char == TokenType::YyEmpty as i16 {
-            let (token_type, opt_token_value) = self.yy_lexer.next_token()?;
-            self.yy_char = token_type as i16;
+            if let Some((yy_char, yy_value, yy_span)) = self.yy_pending.take() {
+              self.yy_char = yy_char;
+              self.yy_value = yy_value;
+              self.yy_span = yy_span;
+            } else {
+              let (token_type, opt_token_value) = self.yy_lexer.next_token()?;
+              self.yy_char = token_type as i16;
+              self.yy_value = opt_token_value;
+              self.yy_span = self.yy_lexer.token_span();
+            }
             self.yy_token = SymbolKind::YyEmpty as i16;
-            self.yy_value = opt_token_value;
+            if self.accept_trailing_comma() {
+              action = Action::NewState;
+              continue;
+            }
+            // when recovering from errors, report the error before any reduction with unexpected token
+            if self.yy_recover && self.yy_char != TokenType::YyError as i16 && !accepts(&self.yy_state_stack, &[self.yy_char]) {
+              action = Action::Error;
+              continue;
+            }
             trace!(self, "  lexer: yy_char={}", self.yy_char);
             trace!(self, "  lexer: yy_value={:?}", self.yy_value);
           }
@@ -171,6 +228,8 @@ impl<'parser> Parser<'parser> {
           trace!(self, "  state_stack={:?}", self.yy_state_stack);
           trace!(self, "  value_stack={:?}", self.yy_value_stack);
           trace!(self, "  node_stack={:?}", self.yy_node_stack);
+          self.yy_comma = self.yy_char == TokenType::Comma as i16;
+          self.yy_skipping = false;
           self.yy_char = TokenType::YyEmpty as i16;
           self.yy_value = TokenValue::YyEmpty;
           action = Action::NewState;
@@ -192,6 +251,7 @@ impl<'parser> Parser<'parser> {
           }
           // keep yy_len = 0
           self.yy_len = 0;
+          self.yy_comma = false;
           let yy_lhs = (YY_R1[self.yy_n as usize] as usize) - YY_N_TOKENS;
           let top_state = self.yy_state_stack[self.yy_state_stack.len() - 1] as i16;
           let yy_i = YY_P_GOTO[yy_lhs] + top_state;
@@ -213,6 +273,10 @@ impl<'parser> Parser<'parser> {
         Action::Error => {
           trace!(self, "\nERROR");
           self.yy_token = SymbolKind::YyError as i16;
+          if self.yy_recover && self.recover() {
+            action = Action::NewState;
+            continue;
+          }
           return Err(err_syntax_error(self.input));
         }
         Action::Error1 => {
@@ -236,6 +300,126 @@ impl<'parser> Parser<'parser> {
   }
 }
 
+impl Parser<'_> {
+  /// Accepts a comma directly followed by closing parenthesis, bracket or brace, by removing the shifted comma,
+  /// when the closing token and the token following it are accepted only without the comma.
+  /// The token following the closing token is checked, because the right bracket after a comma
+  /// may also begin an interval, like in `[1, ]2..3]]`.
+  /// Returns `true` when the comma was removed.
+  fn accept_trailing_comma(&mut self) -> bool {
+    let closing = [TokenType::RightParen, TokenType::RightBracket, TokenType::RightBrace];
+    if !self.yy_comma || !closing.into_iter().any(|token_type| token_type as i16 == self.yy_char) {
+      return false;
+    }
+    let tokens = [self.yy_char, self.yy_lexer.peek_token_type()];
+    let without_comma = &self.yy_state_stack[..self.yy_state_stack.len() - 1];
+    if accepts(&self.yy_state_stack, &tokens) || !accepts(without_comma, &tokens) {
+      return false;
+    }
+    trace!(self, "  accepted trailing comma");
+    self.yy_state_stack.pop();
+    self.yy_value_stack.pop();
+    self.yy_state = self.yy_state_stack[self.yy_state_stack.len() - 1];
+    self.yy_comma = false;
+    true
+  }
+
+  /// Reports the syntax error and repairs the input, so parsing can continue.
+  /// Returns `false` when the input can not be repaired.
+  fn recover(&mut self) -> bool {
+    if self.yy_errors.len() >= MAX_SYNTAX_ERRORS {
+      self.yy_aborted = true;
+      return false;
+    }
+    let (start, mut end) = self.yy_span;
+    let is_undefined = self.yy_char == TokenType::YyUndef as i16;
+    if is_undefined {
+      end = start + 1;
+    }
+    let text = self.input.chars().skip(start).take(end - start).collect::<String>();
+    let found = if self.yy_char == TokenType::YyEof as i16 {
+      token_name(self.yy_char).to_string()
+    } else {
+      format!("`{text}`")
+    };
+    let expected = || {
+      let mut names = expected_tokens(&self.yy_state_stack)
+        .into_iter()
+        .map(token_name)
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+      names.dedup();
+      names
+    };
+    // replace a name misspelling an expected keyword
+    if let TokenValue::Name(name) = &self.yy_value {
+      let name = name.to_string();
+      let word = name.split_whitespace().next().unwrap_or_default();
+      if let Some(keyword) = misspelled_keyword(&self.yy_state_stack, word) {
+        trace!(self, "  replaced misspelled keyword");
+        let word_end = start + word.chars().count();
+        let error = SyntaxError::new(start, word_end, format!("unexpected `{word}`"), expected(), Some(token_name(keyword).to_string()));
+        self.yy_errors.push(error);
+        self.yy_lexer.rewind(word_end);
+        self.yy_char = keyword;
+        self.yy_value = token_value(keyword);
+        return true;
+      }
+    }
+    // replace operators borrowed from other languages
+    if is_undefined {
+      let rest = self.input.chars().skip(start).take(2).collect::<String>();
+      let replacement = match rest.as_str() {
+        "&&" => Some(TokenType::And as i16),
+        "||" => Some(TokenType::Or as i16),
+        _ => None,
+      };
+      if let Some(operator) = replacement.filter(|operator| accepts(&self.yy_state_stack, &[*operator])) {
+        trace!(self, "  replaced operator");
+        let error = SyntaxError::new(start, start + 2, format!("unexpected `{rest}`"), expected(), Some(token_name(operator).to_string()));
+        self.yy_errors.push(error);
+        self.yy_lexer.skip(2);
+        self.yy_char = operator;
+        self.yy_value = token_value(operator);
+        return true;
+      }
+    }
+    // insert a missing token
+    if let Some(token_type) = insertion(&self.yy_state_stack, self.yy_char) {
+      trace!(self, "  inserted missing token");
+      let error = SyntaxError::new(start, end, format!("unexpected {found}"), vec![token_name(token_type).to_string()], None);
+      self.yy_errors.push(error);
+      self.yy_pending = Some((self.yy_char, self.yy_value.clone(), self.yy_span));
+      self.yy_char = token_type;
+      self.yy_value = token_value(token_type);
+      return true;
+    }
+    // unexpected end of input can not be skipped
+    if self.yy_char == TokenType::YyEof as i16 {
+      self.yy_errors.push(SyntaxError::new(start, end, format!("unexpected {found}"), expected(), None));
+      self.yy_aborted = true;
+      return false;
+    }
+    // skip the unexpected token
+    trace!(self, "  skipped unexpected token");
+    if is_undefined {
+      self.yy_lexer.skip(1);
+    }
+    match self.yy_errors.last_mut() {
+      Some(error) if self.yy_skipping => error.extend(end),
+      _ if self.yy_char == TokenType::Eq as i16 && start > 0 && self.input.chars().nth(start - 1) == Some('=') => {
+        let error = SyntaxError::new(start - 1, end, "unexpected `==`".to_string(), vec![], Some(token_name(self.yy_char).to_string()));
+        self.yy_errors.push(error);
+      }
+      _ => self.yy_errors.push(SyntaxError::new(start, end, format!("unexpected {found}"), expected(), None)),
+    }
+    self.yy_skipping = true;
+    self.yy_char = TokenType::YyEmpty as i16;
+    self.yy_value = TokenValue::YyEmpty;
+    true
+  }
+}
+
 impl<'parser> ReduceActions for Parser<'parser> {
   fn action_addition(&mut self) -> Result<()> {
     trace_action!(self, "addition");
@@ -0,0 +1,338 @@
+//! # Recovery from syntax errors
+//!
+//! When parsing with recovery, the parser does not stop at the first syntax error.
+//! Each error is reported together with expected tokens and, when possible, with a suggestion,
+//! then the input is repaired locally and parsing continues, so multiple syntax errors
+//! are reported in one pass. Repairs are tried in the following order:
+//!
+//! - a name misspelling an expected keyword (like `thne` instead of `then`) is replaced with the keyword,
+//! - operators borrowed from other languages (`&&`, `||`) are replaced with `and` and `or`,
+//! - a missing closing bracket, separator or keyword (like `)`, `,` or `else`) is inserted,
+//! - otherwise the unexpected token is skipped.
+//!
+//! Repairs are verified by simulating the parser automaton, so a repair is applied only
+//! when the parser can continue with the repaired input.
+
+use crate::lalr::*;
+use crate::lexer::TokenValue;
+use dmntk_common::levenshtein;
+use std::fmt;
+
+/// Maximum number of reported syntax errors, parsing stops after reaching this limit.
+pub const MAX_SYNTAX_ERRORS: usize = 25;
+
+/// Maximum number of expected tokens listed in the message of the syntax error.
+const MAX_LISTED_EXPECTED: usize = 4;
+
+/// Syntax error reported while parsing with recovery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+  /// Offset of the first character of the unexpected token, in characters.
+  start: usize,
+  /// Offset of the character following the unexpected token, in characters.
+  end: usize,
+  /// Description of the error.
+  message: String,
+  /// Names of expected tokens.
+  expected: Vec<String>,
+  /// Suggested replacement of the unexpected token.
+  suggestion: Option<String>,
+}
+
+impl SyntaxError {
+  /// Creates a new syntax error.
+  pub(crate) fn new(start: usize, end: usize, message: String, expected: Vec<String>, suggestion: Option<String>) -> Self {
+    Self {
+      start,
+      end,
+      message,
+      expected,
+      suggestion,
+    }
+  }
+
+  /// Returns the offset of the first character of the unexpected token, in characters.
+  pub fn start(&self) -> usize {
+    self.start
+  }
+
+  /// Returns the offset of the character following the unexpected token, in characters.
+  pub fn end(&self) -> usize {
+    self.end
+  }
+
+  /// Returns the description of the error.
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// Returns names of expected tokens.
+  pub fn expected(&self) -> &[String] {
+    &self.expected
+  }
+
+  /// Returns the suggested replacement of the unexpected token.
+  pub fn suggestion(&self) -> Option<&str> {
+    self.suggestion.as_deref()
+  }
+
+  /// Extends the error to the end of the next skipped token.
+  pub(crate) fn extend(&mut self, end: usize) {
+    self.end = end;
+  }
+}
+
+impl fmt::Display for SyntaxError {
+  /// Formats the syntax error with its location, listing expected tokens when there are only a few.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}..{}: {}", self.start, self.end, self.message)?;
+    if !self.expected.is_empty() && self.expected.len() <= MAX_LISTED_EXPECTED {
+      write!(f, ", expected {}", self.expected.join(" or "))?;
+    }
+    if let Some(suggestion) = &self.suggestion {
+      write!(f, ", did you mean {suggestion}?")?;
+    }
+    Ok(())
+  }
+}
+
+/// Tokens that may be inserted to repair the input, they have no semantic value.
+const INSERTABLE: [TokenType; 9] = [
+  TokenType::RightParen,
+  TokenType::RightBracket,
+  TokenType::RightBrace,
+  TokenType::Comma,
+  TokenType::Colon,
+  TokenType::Then,
+  TokenType::Else,
+  TokenType::Return,
+  TokenType::Satisfies,
+];
+
+/// Keywords replacing misspelled names.
+const KEYWORDS: [TokenType; 6] = [TokenType::Then, TokenType::Else, TokenType::Return, TokenType::Satisfies, TokenType::And, TokenType::Or];
+
+/// Terminal tokens listed as expected tokens.
+const TERMINALS: [TokenType; 50] = [
+  TokenType::YyEof,
+  TokenType::At,
+  TokenType::Not,
+  TokenType::Colon,
+  TokenType::Comma,
+  TokenType::Every,
+  TokenType::For,
+  TokenType::LeftBrace,
+  TokenType::Null,
+  TokenType::RightArrow,
+  TokenType::Of,
+  TokenType::List,
+  TokenType::Range,
+  TokenType::Context,
+  TokenType::Then,
+  TokenType::Function,
+  TokenType::External,
+  TokenType::If,
+  TokenType::RightBrace,
+  TokenType::RightBracket,
+  TokenType::RightParen,
+  TokenType::Return,
+  TokenType::Ellipsis,
+  TokenType::Some,
+  TokenType::Numeric,
+  TokenType::String,
+  TokenType::Boolean,
+  TokenType::Satisfies,
+  TokenType::Else,
+  TokenType::Or,
+  TokenType::And,
+  TokenType::Eq,
+  TokenType::Nq,
+  TokenType::Lt,
+  TokenType::Le,
+  TokenType::Gt,
+  TokenType::Ge,
+  TokenType::Between,
+  TokenType::BetweenAnd,
+  TokenType::In,
+  TokenType::Minus,
+  TokenType::Plus,
+  TokenType::Mul,
+  TokenType::Div,
+  TokenType::Exp,
+  TokenType::Instance,
+  TokenType::Name,
+  TokenType::BuiltInTypeName,
+  TokenType::LeftParen,
+  TokenType::LeftBracket,
+];
+
+/// Returns the name of the token used in messages of syntax errors.
+pub fn token_name(token_type: i16) -> &'static str {
+  match token_type {
+    t if t == TokenType::YyEof as i16 => "end of input",
+    t if t == TokenType::At as i16 => "`@`",
+    t if t == TokenType::Not as i16 => "`not`",
+    t if t == TokenType::Colon as i16 => "`:`",
+    t if t == TokenType::Comma as i16 => "`,`",
+    t if t == TokenType::Every as i16 => "`every`",
+    t if t == TokenType::For as i16 => "`for`",
+    t if t == TokenType::LeftBrace as i16 => "`{`",
+    t if t == TokenType::Null as i16 => "`null`",
+    t if t == TokenType::RightArrow as i16 => "`->`",
+    t if t == TokenType::Of as i16 => "`of`",
+    t if t == TokenType::List as i16 => "`list`",
+    t if t == TokenType::Range as i16 => "`range`",
+    t if t == TokenType::Context as i16 => "`context`",
+    t if t == TokenType::Then as i16 => "`then`",
+    t if t == TokenType::Function as i16 => "`function`",
+    t if t == TokenType::External as i16 => "`external`",
+    t if t == TokenType::If as i16 => "`if`",
+    t if t == TokenType::RightBrace as i16 => "`}`",
+    t if t == TokenType::RightBracket as i16 => "`]`",
+    t if t == TokenType::RightParen as i16 => "`)`",
+    t if t == TokenType::Return as i16 => "`return`",
+    t if t == TokenType::Ellipsis as i16 => "`..`",
+    t if t == TokenType::Some as i16 => "`some`",
+    t if t == TokenType::Numeric as i16 => "number",
+    t if t == TokenType::String as i16 => "string",
+    t if t == TokenType::Boolean as i16 => "boolean",
+    t if t == TokenType::Satisfies as i16 => "`satisfies`",
+    t if t == TokenType::Else as i16 => "`else`",
+    t if t == TokenType::Or as i16 => "`or`",
+    t if t == TokenType::And as i16 || t == TokenType::BetweenAnd as i16 => "`and`",
+    t if t == TokenType::Eq as i16 => "`=`",
+    t if t == TokenType::Nq as i16 => "`!=`",
+    t if t == TokenType::Lt as i16 => "`<`",
+    t if t == TokenType::Le as i16 => "`<=`",
+    t if t == TokenType::Gt as i16 => "`>`",
+    t if t == TokenType::Ge as i16 => "`>=`",
+    t if t == TokenType::Between as i16 => "`between`",
+    t if t == TokenType::In as i16 => "`in`",
+    t if t == TokenType::Minus as i16 => "`-`",
+    t if t == TokenType::Plus as i16 => "`+`",
+    t if t == TokenType::Mul as i16 => "`*`",
+    t if t == TokenType::Div as i16 => "`/`",
+    t if t == TokenType::Exp as i16 => "`**`",
+    t if t == TokenType::Instance as i16 => "`instance`",
+    t if t == TokenType::Name as i16 || t == TokenType::NameDateTime as i16 => "name",
+    t if t == TokenType::BuiltInTypeName as i16 => "type name",
+    t if t == TokenType::LeftParen as i16 => "`(`",
+    t if t == TokenType::LeftBracket as i16 => "`[`",
+    t if t == TokenType::Dot as i16 => "`.`",
+    _ => "token",
+  }
+}
+
+/// Returns the semantic value of the token without a value, used when the token is inserted or substituted.
+pub fn token_value(token_type: i16) -> TokenValue {
+  match token_type {
+    t if t == TokenType::RightParen as i16 => TokenValue::RightParen,
+    t if t == TokenType::RightBracket as i16 => TokenValue::RightBracket,
+    t if t == TokenType::RightBrace as i16 => TokenValue::RightBrace,
+    t if t == TokenType::Comma as i16 => TokenValue::Comma,
+    t if t == TokenType::Colon as i16 => TokenValue::Colon,
+    t if t == TokenType::Then as i16 => TokenValue::Then,
+    t if t == TokenType::Else as i16 => TokenValue::Else,
+    t if t == TokenType::Return as i16 => TokenValue::Return,
+    t if t == TokenType::Satisfies as i16 => TokenValue::Satisfies,
+    t if t == TokenType::And as i16 => TokenValue::And,
+    t if t == TokenType::Or as i16 => TokenValue::Or,
+    _ => TokenValue::YyEmpty,
+  }
+}
+
+/// Returns the kind of the symbol recognized by the parser for specified token type.
+pub fn symbol_kind(token_type: i16) -> i16 {
+  if token_type <= TokenType::YyEof as i16 {
+    SymbolKind::YyEof as i16
+  } else {
+    YY_TRANSLATE[token_type as usize] as i16
+  }
+}
+
+/// Returns types of tokens accepted by the parser with specified state stack.
+pub fn expected_tokens(state_stack: &[usize]) -> Vec<i16> {
+  TERMINALS
+    .iter()
+    .map(|token_type| token_type.clone() as i16)
+    .filter(|token_type| accepts(state_stack, &[*token_type]))
+    .collect()
+}
+
+/// Returns the type of the token that may be inserted before the lookahead token,
+/// so the parser with specified state stack can continue.
+pub fn insertion(state_stack: &[usize], lookahead: i16) -> Option<i16> {
+  INSERTABLE
+    .iter()
+    .map(|token_type| token_type.clone() as i16)
+    .find(|token_type| accepts(state_stack, &[*token_type, lookahead]))
+}
+
+/// Returns the type of the keyword misspelled by specified name, when the keyword is accepted
+/// by the parser with specified state stack. Misspelled keywords differ by at most two characters,
+/// but not more than a half of the keyword.
+pub fn misspelled_keyword(state_stack: &[usize], name: &str) -> Option<i16> {
+  KEYWORDS
+    .iter()
+    .map(|token_type| token_type.clone() as i16)
+    .filter(|token_type| accepts(state_stack, &[*token_type]))
+    .map(|token_type| (token_type, levenshtein(name, token_name(token_type).trim_matches('`'))))
+    .filter(|(token_type, distance)| *distance > 0 && *distance <= 2 && *distance * 2 <= token_name(*token_type).trim_matches('`').len())
+    .min_by_key(|(_, distance)| *distance)
+    .map(|(token_type, _)| token_type)
+}
+
+/// Returns `true` when the parser with specified state stack accepts specified sequence of tokens.
+///
+/// The parser automaton is simulated without executing semantic actions.
+pub fn accepts(state_stack: &[usize], token_types: &[i16]) -> bool {
+  let mut stack = state_stack.to_vec();
+  for token_type in token_types {
+    let symbol = symbol_kind(*token_type);
+    loop {
+      let Some(state) = stack.last().cloned() else {
+        return false;
+      };
+      if state == YY_FINAL {
+        return true;
+      }
+      let mut rule = 0;
+      let pact = YY_PACT[state];
+      if pact != YY_PACT_N_INF {
+        let index = pact + symbol;
+        if (0..=YY_LAST).contains(&index) && YY_CHECK[index as usize] == symbol {
+          let action = YY_TABLE[index as usize];
+          if action > 0 {
+            stack.push(action as usize);
+            break;
+          }
+          if action == YY_TABLE_N_INF {
+            return false;
+          }
+          rule = -action;
+        }
+      }
+      if rule == 0 {
+        rule = YY_DEF_ACT[state] as i16;
+        if rule == 0 {
+          return false;
+        }
+      }
+      let len = YY_R2[rule as usize] as usize;
+      if len >= stack.len() {
+        return false;
+      }
+      stack.truncate(stack.len() - len);
+      let lhs = (YY_R1[rule as usize] as usize) - YY_N_TOKENS;
+      let top_state = stack[stack.len() - 1] as i16;
+      let index = YY_P_GOTO[lhs] + top_state;
+      let next_state = if (0..=YY_LAST).contains(&index) && YY_CHECK[index as usize] == top_state {
+        YY_TABLE[index as usize] as usize
+      } else {
+        YY_DEF_GOTO[lhs] as usize
+      };
+      stack.push(next_state);
+    }
+  }
+  true
+}
@@ -1387,3 +1387,13 @@ fn test_node_unary_lt() {
     node,
   );
 }
+
+#[test]
+fn test_node_children() {
+  let node = &AstNode::If(b_num!("1"), Box::new(AstNode::List(vec![_num!("2"), _num!("3")])), Box::new(AstNode::Null));
+  let children = node.children();
+  assert_eq!(3, children.len());
+  assert_eq!(r#"List([Numeric("2", ""), Numeric("3", "")])"#, format!("{:?}", children[1]));
+  assert_eq!(2, children[1].children().len());
+  assert!(children[2].children().is_empty());
+}
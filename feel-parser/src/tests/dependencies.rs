@@ -0,0 +1,83 @@
+use crate::expression_dependencies;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelScope, FeelType};
+
+/// Returns dependencies of the expression in a scope with sample variables, joined in a string.
+fn dependencies(input: &str) -> String {
+  let mut applicant = FeelContext::default();
+  applicant.set_entry(&"age".into(), Value::FeelType(FeelType::Number));
+  applicant.set_entry(&"name".into(), Value::FeelType(FeelType::String));
+  let mut ctx = FeelContext::default();
+  ctx.set_entry(&"Applicant".into(), Value::Context(applicant));
+  for name in ["Monthly Salary", "limit", "rates", "x", "y"] {
+    ctx.set_entry(&name.into(), Value::FeelType(FeelType::Any));
+  }
+  let scope: FeelScope = ctx.into();
+  expression_dependencies(&scope, input)
+    .unwrap()
+    .iter()
+    .map(|name| name.to_string())
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+#[test]
+fn _0001() {
+  assert_eq!("", dependencies("1 + 2"));
+}
+
+#[test]
+fn _0002() {
+  assert_eq!("Monthly Salary", dependencies("Monthly Salary * 12"));
+}
+
+#[test]
+fn _0003() {
+  assert_eq!("Applicant.age, limit", dependencies("if Applicant.age > limit then Applicant.age else limit"));
+}
+
+#[test]
+fn _0004() {
+  assert_eq!("limit", dependencies("{ a: 10, b: a + limit }"));
+}
+
+#[test]
+fn _0005() {
+  assert_eq!("rates, x", dependencies("for r in rates return r * x"));
+}
+
+#[test]
+fn _0006() {
+  assert_eq!("rates, y", dependencies("every r in rates satisfies r > y"));
+}
+
+#[test]
+fn _0007() {
+  assert_eq!("rates, x", dependencies("rates[item > x]"));
+}
+
+#[test]
+fn _0008() {
+  assert_eq!("y", dependencies("function(x) x + y"));
+}
+
+#[test]
+fn _0009() {
+  assert_eq!("rates", dependencies("sum(rates) + count(rates)"));
+}
+
+#[test]
+fn _0010() {
+  assert_eq!("x, y", dependencies(r#"(x instance of number) and date("2024-01-01") < date(y)"#));
+}
+
+#[test]
+fn _0011() {
+  assert_eq!("rates", dependencies("for i in 1..count(rates) return if i = 1 then rates[i] else partial[-1] + rates[i]"));
+}
+
+#[test]
+fn _0012() {
+  assert_eq!("Applicant", dependencies("{ f: function(a) a.age, r: f(Applicant) }.r"));
+}
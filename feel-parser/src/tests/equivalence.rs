@@ -0,0 +1,51 @@
+use crate::equivalent_expressions;
+use dmntk_feel::FeelScope;
+
+fn equivalent(lhs: &str, rhs: &str) -> bool {
+  equivalent_expressions(&FeelScope::default(), lhs, rhs).unwrap()
+}
+
+#[test]
+fn _0001() {
+  assert!(equivalent("1+2", "  1 +   2 "));
+}
+
+#[test]
+fn _0002() {
+  assert!(equivalent("(a + b) * c", "(a+b)*c"));
+}
+
+#[test]
+fn _0003() {
+  assert!(equivalent("a + b", "(a + b)"));
+  assert!(equivalent("1 + (2 * 3)", "1 + 2 * 3"));
+}
+
+#[test]
+fn _0004() {
+  assert!(!equivalent("(1 + 2) * 3", "1 + 2 * 3"));
+}
+
+#[test]
+fn _0005() {
+  assert!(equivalent("1.50 + 002", "1.5 + 2"));
+  assert!(equivalent("0.5", ".5"));
+  assert!(equivalent("10", "10.0"));
+  assert!(!equivalent("10", "1"));
+}
+
+#[test]
+fn _0006() {
+  assert!(equivalent("if a > 1 then \"yes\" else \"no\"", "if a>1 /* check */ then \"yes\"\n else \"no\" // end"));
+}
+
+#[test]
+fn _0007() {
+  assert!(!equivalent("\"yes\"", "\"Yes\""));
+  assert!(!equivalent("a - b", "b - a"));
+}
+
+#[test]
+fn _0008() {
+  assert!(equivalent_expressions(&FeelScope::default(), "1 +", "1").is_err());
+}
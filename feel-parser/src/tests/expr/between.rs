@@ -19,3 +19,28 @@ fn _0001() {
     false,
   );
 }
+
+#[test]
+fn _0002() {
+  let scope = scope!();
+  accept(
+    &scope,
+    StartExpression,
+    "1 between (2 between 1 and 3) and 4",
+    r#"
+       Between
+       ├─ Numeric
+       │  └─ `1.`
+       ├─ Between
+       │  ├─ Numeric
+       │  │  └─ `2.`
+       │  ├─ Numeric
+       │  │  └─ `1.`
+       │  └─ Numeric
+       │     └─ `3.`
+       └─ Numeric
+          └─ `4.`
+    "#,
+    false,
+  );
+}
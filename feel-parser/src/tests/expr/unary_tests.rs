@@ -180,3 +180,52 @@ fn _00010() {
     Parser::new(&scope, StartUnaryTests, "(1,2,3,4)", false).parse()
   );
 }
+
+#[test]
+fn _0011() {
+  let scope = scope!();
+  accept(
+    &scope,
+    StartUnaryTests,
+    r#"? in [1,2]"#,
+    r#"
+       ExpressionList
+       └─ In
+          ├─ Name
+          │  └─ `?`
+          └─ List
+             ├─ Numeric
+             │  └─ `1.`
+             └─ Numeric
+                └─ `2.`
+    "#,
+    false,
+  );
+}
+
+#[test]
+fn _0012() {
+  let scope = scope!();
+  accept(
+    &scope,
+    StartUnaryTests,
+    r#"every x in ? satisfies x > 1"#,
+    r#"
+       ExpressionList
+       └─ Every
+          ├─ QuantifiedContexts
+          │  └─ QuantifiedContext
+          │     ├─ Name
+          │     │  └─ `x`
+          │     └─ Name
+          │        └─ `?`
+          └─ Satisfies
+             └─ Gt
+                ├─ Name
+                │  └─ `x`
+                └─ Numeric
+                   └─ `1.`
+    "#,
+    false,
+  );
+}
@@ -0,0 +1,58 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use dmntk_common::{DmntkError, Language};
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(err_unexpected_eof(), "<LexerError> nieoczekiwany koniec pliku", "<LexerError> unerwartetes Dateiende");
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_expected_hex_digit('x'),
+    "<LexerError> oczekiwano cyfry szesnastkowej, napotkano 'x'",
+    "<LexerError> Hexadezimalziffer erwartet, aber 'x' gefunden",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_unicode_value_out_of_range(0x110000),
+    "<LexerError> wartość jest poza dozwolonym zakresem Unicode 0x0000..0x10FFFF : 110000",
+    "<LexerError> Wert liegt außerhalb des zulässigen Unicode-Bereichs 0x0000..0x10FFFF : 110000",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_unicode_surrogate_out_of_range(0xE000),
+    "<LexerError> wartość surogatu jest poza dozwolonym zakresem 0xD800..0xDFFF : E000",
+    "<LexerError> Surrogatwert liegt außerhalb des zulässigen Bereichs 0xD800..0xDFFF : E000",
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_translated(
+    err_not_a_feel_name("1a"),
+    "<ParserError> oczekiwano nazwy `FEEL` na wejściu, znaleziono `1a`",
+    "<ParserError> `FEEL`-Name in der Eingabe erwartet, aber `1a` gefunden",
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_translated(err_syntax_error("1 +"), "<ParserError> błąd składni: 1 +", "<ParserError> Syntaxfehler: 1 +");
+}
@@ -2,7 +2,12 @@ mod ast;
 mod bifs;
 mod closure;
 mod context;
+mod dependencies;
+mod equivalence;
 mod expr;
+mod messages;
+mod names;
+mod recovery;
 
 /// Creates a parsing scope.
 macro_rules! scope {
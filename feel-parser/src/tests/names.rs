@@ -0,0 +1,24 @@
+use crate::is_name;
+
+#[test]
+fn _0001() {
+  assert!(is_name("Amount"));
+  assert!(is_name("Monthly Salary"));
+  assert!(is_name("Decision 1"));
+  assert!(is_name("?"));
+  assert!(is_name("_private"));
+  assert!(is_name("Pre-bureau risk category"));
+  assert!(is_name("Applicant's income/expenses"));
+  assert!(is_name("Größe"));
+}
+
+#[test]
+fn _0002() {
+  assert!(!is_name(""));
+  assert!(!is_name("   "));
+  assert!(!is_name("1st decision"));
+  assert!(!is_name("Amount (EUR)"));
+  assert!(!is_name("Amount, total"));
+  assert!(!is_name("Amount:"));
+  assert!(!is_name("\"Amount\""));
+}
@@ -0,0 +1,111 @@
+use crate::{parse_expression, parse_expression_with_recovery, parse_unary_tests, parse_unary_tests_with_recovery};
+use dmntk_feel::FeelScope;
+
+/// Parses the expression with recovery and returns reported syntax errors.
+fn errors(input: &str) -> Vec<String> {
+  let (node, errors) = parse_expression_with_recovery(&FeelScope::default(), input);
+  assert!(node.is_some(), "{input}");
+  errors.iter().map(|error| error.to_string()).collect()
+}
+
+/// Checks if the expression with trailing commas is parsed like the expression without them.
+fn trailing(input: &str, expected: &str) {
+  let scope = FeelScope::default();
+  assert_eq!(
+    parse_expression(&scope, expected, false).unwrap(),
+    parse_expression(&scope, input, false).unwrap(),
+    "{input}"
+  );
+}
+
+#[test]
+fn _0001() {
+  trailing("[1, 2, ]", "[1, 2]");
+  trailing("{a: 1, b: 2,}", "{a: 1, b: 2}");
+  trailing("sum(1, 2,)", "sum(1, 2)");
+  trailing("sum(list: [1],)", "sum(list: [1])");
+  trailing("function(a, b,) a + b", "function(a, b) a + b");
+  trailing("[[1, 2,], [3,],]", "[[1, 2], [3]]");
+}
+
+#[test]
+fn _0002() {
+  // right bracket after comma still begins an interval
+  trailing("[1, ]2..3]]", "[1, ]2..3]]");
+  trailing("[1, ]2..3], ]", "[1, ]2..3]]");
+  let scope = FeelScope::default();
+  assert_eq!(
+    parse_unary_tests(&scope, "not(1, 2)", false).unwrap(),
+    parse_unary_tests(&scope, "not(1, 2,)", false).unwrap()
+  );
+  assert!(parse_expression(&scope, "[1, 2,,]", false).is_err());
+  assert!(parse_expression(&scope, "[,]", false).is_err());
+}
+
+#[test]
+fn _0003() {
+  assert!(errors("if a > 1 then 2 else 3").is_empty());
+  assert_eq!(vec!["9..13: unexpected `thne`, did you mean `then`?"], errors("if a > 1 thne 2 else 3"));
+  assert_eq!(vec!["16..20: unexpected `esle`, did you mean `else`?"], errors("if a > 1 then 2 esle 3"));
+  assert_eq!(vec!["15..21: unexpected `retrun`, did you mean `return`?"], errors("for x in [1,2] retrun x"));
+  assert_eq!(vec!["14..23: unexpected `satisfeis`, did you mean `satisfies`?"], errors("some x in [1] satisfeis x > 1"));
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(vec!["2..4: unexpected `&&`, did you mean `and`?"], errors("a && b"));
+  assert_eq!(vec!["2..4: unexpected `||`, did you mean `or`?"], errors("a || b"));
+  assert_eq!(vec!["2..4: unexpected `==`, did you mean `=`?"], errors("a == b"));
+}
+
+#[test]
+fn _0005() {
+  assert_eq!(vec!["8..8: unexpected end of input, expected `)`"], errors("sum(1, 2"));
+  assert_eq!(vec!["6..6: unexpected end of input, expected `]`"], errors("[1,2,3"));
+  assert_eq!(vec!["3..4: unexpected `2`, expected `,`", "5..6: unexpected `3`, expected `,`"], errors("[1 2 3]"));
+  assert_eq!(vec!["6..7: unexpected `b`, expected `,`"], errors("{a: 1 b: 2}"));
+}
+
+#[test]
+fn _0006() {
+  assert_eq!(vec!["4..5: unexpected `*`"], errors("1 + * 2"));
+  assert_eq!(vec!["6..8: unexpected `)`"], errors("1 + 2 )) + 3"));
+  assert_eq!(vec!["5..6: unexpected `,`"], errors("[1,2,,]"));
+}
+
+#[test]
+fn _0007() {
+  let input = "if a && b > 1 thne [1 2] esle sum(1, 2";
+  assert_eq!(
+    vec![
+      "5..7: unexpected `&&`, did you mean `and`?",
+      "14..18: unexpected `thne`, did you mean `then`?",
+      "22..23: unexpected `2`, expected `,`",
+      "25..29: unexpected `esle`, did you mean `else`?",
+      "38..38: unexpected end of input, expected `)`",
+    ],
+    errors(input)
+  );
+}
+
+#[test]
+fn _0008() {
+  let (node, errors) = parse_expression_with_recovery(&FeelScope::default(), "if a > 1 then 1");
+  assert!(node.is_none());
+  assert_eq!(1, errors.len());
+  assert_eq!(15, errors[0].start());
+  assert_eq!(15, errors[0].end());
+  assert_eq!("unexpected end of input", errors[0].message());
+  assert!(errors[0].expected().contains(&"`else`".to_string()));
+  assert_eq!(None, errors[0].suggestion());
+}
+
+#[test]
+fn _0009() {
+  let (node, errors) = parse_unary_tests_with_recovery(&FeelScope::default(), "<1, >2 3");
+  assert!(node.is_some());
+  assert_eq!(
+    vec!["7..8: unexpected `3`, expected `,`"],
+    errors.iter().map(|error| error.to_string()).collect::<Vec<String>>()
+  );
+}
@@ -279,6 +279,21 @@ impl FeelDate {
     }
   }
 
+  /// Returns the quarter of the year (1..4) this date belongs to.
+  pub fn quarter_of_year(&self) -> Option<u8> {
+    (1..=12).contains(&self.1).then(|| ((self.1 - 1) / 3 + 1) as u8)
+  }
+
+  /// Returns the first day of the month this date belongs to.
+  pub fn first_day_of_month(&self) -> Option<Self> {
+    Self::new_opt(self.0, self.1, 1)
+  }
+
+  /// Returns the last day of the month this date belongs to.
+  pub fn last_day_of_month(&self) -> Option<Self> {
+    last_day_of_month(self.0, self.1).and_then(|day| Self::new_opt(self.0, self.1, day))
+  }
+
   pub fn as_tuple(&self) -> (Year, Month, Day) {
     (self.0, self.1, self.2)
   }
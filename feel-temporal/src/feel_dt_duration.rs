@@ -80,6 +80,16 @@ impl FeelDaysAndTimeDuration {
   pub fn is_negative(&self) -> bool {
     self.0 < 0
   }
+
+  /// Returns the sum of durations, or `None` when the result is out of range.
+  pub fn checked_add(&self, rhs: &FeelDaysAndTimeDuration) -> Option<Self> {
+    self.0.checked_add(rhs.0).map(Self)
+  }
+
+  /// Returns the subtraction of durations, or `None` when the result is out of range.
+  pub fn checked_sub(&self, rhs: &FeelDaysAndTimeDuration) -> Option<Self> {
+    self.0.checked_sub(rhs.0).map(Self)
+  }
 }
 
 impl ops::Add<FeelDaysAndTimeDuration> for FeelDaysAndTimeDuration {
@@ -185,45 +195,46 @@ impl TryFrom<&str> for FeelDaysAndTimeDuration {
   fn try_from(value: &str) -> Result<Self, Self::Error> {
     if let Some(captures) = RE_DAYS_AND_TIME.captures(value) {
       let mut is_valid = false;
+      let mut is_overflow = false;
       let mut nanoseconds = 0_i64;
-      if let Some(days_match) = captures.name("days") {
-        if let Ok(days_u64) = days_match.as_str().parse::<u64>() {
-          if let Ok(days) = <u64 as TryInto<i64>>::try_into(days_u64) {
-            if let Some(a) = days.checked_mul(NANOSECONDS_IN_DAY) {
-              nanoseconds += a;
-              is_valid = true;
+      for (name, multiplier) in [
+        ("days", NANOSECONDS_IN_DAY),
+        ("hours", NANOSECONDS_IN_HOUR),
+        ("minutes", NANOSECONDS_IN_MINUTE),
+        ("seconds", NANOSECONDS_IN_SECOND),
+      ] {
+        if let Some(component_match) = captures.name(name) {
+          if let Ok(component) = component_match.as_str().parse::<u64>() {
+            // components too large to be represented in nanoseconds make the whole literal invalid
+            match i64::try_from(component)
+              .ok()
+              .and_then(|component| component.checked_mul(multiplier))
+              .and_then(|component| nanoseconds.checked_add(component))
+            {
+              Some(total) => {
+                nanoseconds = total;
+                is_valid = true;
+              }
+              None => is_overflow = true,
             }
           }
         }
       }
-      if let Some(hours_match) = captures.name("hours") {
-        if let Ok(hours) = hours_match.as_str().parse::<u64>() {
-          nanoseconds += (hours as i64) * NANOSECONDS_IN_HOUR;
-          is_valid = true;
-        }
-      }
-      if let Some(minutes_match) = captures.name("minutes") {
-        if let Ok(minutes) = minutes_match.as_str().parse::<u64>() {
-          nanoseconds += (minutes as i64) * NANOSECONDS_IN_MINUTE;
-          is_valid = true;
-        }
-      }
-      if let Some(seconds_match) = captures.name("seconds") {
-        if let Ok(seconds) = seconds_match.as_str().parse::<u64>() {
-          nanoseconds += (seconds as i64) * NANOSECONDS_IN_SECOND;
-          is_valid = true;
-        }
-      }
       if let Some(fractional_match) = captures.name("fractional") {
         if let Ok(fractional) = fractional_match.as_str().parse::<f64>() {
-          nanoseconds += (fractional * NANOSECONDS_IN_SECOND as f64).trunc() as i64;
-          is_valid = true;
+          match nanoseconds.checked_add((fractional * NANOSECONDS_IN_SECOND as f64).trunc() as i64) {
+            Some(total) => {
+              nanoseconds = total;
+              is_valid = true;
+            }
+            None => is_overflow = true,
+          }
         }
       }
       if captures.name("sign").is_some() {
         nanoseconds = -nanoseconds;
       }
-      if is_valid {
+      if is_valid && !is_overflow {
         return Ok(FeelDaysAndTimeDuration(nanoseconds));
       }
     }
@@ -56,6 +56,16 @@ impl FeelYearsAndMonthsDuration {
   pub fn is_negative(&self) -> bool {
     self.0 < 0
   }
+
+  /// Returns the sum of durations, or `None` when the result is out of range.
+  pub fn checked_add(&self, rhs: &FeelYearsAndMonthsDuration) -> Option<Self> {
+    self.0.checked_add(rhs.0).map(Self)
+  }
+
+  /// Returns the subtraction of durations, or `None` when the result is out of range.
+  pub fn checked_sub(&self, rhs: &FeelYearsAndMonthsDuration) -> Option<Self> {
+    self.0.checked_sub(rhs.0).map(Self)
+  }
 }
 
 impl ops::Add<FeelYearsAndMonthsDuration> for FeelYearsAndMonthsDuration {
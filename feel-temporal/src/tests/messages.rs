@@ -0,0 +1,95 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use crate::FeelDate;
+use dmntk_common::{DmntkError, Language};
+use dmntk_feel_number::FeelNumber;
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_invalid_date(FeelNumber::from(2021), FeelNumber::from(13), FeelNumber::from(1)),
+    "<TemporalError> niepoprawna data 2021-13-1",
+    "<TemporalError> ungültiges Datum 2021-13-1",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_invalid_feel_date(FeelDate::new(2021, 2, 30)),
+    "<TemporalError> niepoprawna data 2021-2-30",
+    "<TemporalError> ungültiges Datum 2021-2-30",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_invalid_date_literal("2021-13-01"),
+    "<TemporalError> niepoprawny literał daty '2021-13-01'",
+    "<TemporalError> ungültiges Datumsliteral '2021-13-01'",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_invalid_time_literal("25:00:00"),
+    "<TemporalError> niepoprawny literał czasu '25:00:00'",
+    "<TemporalError> ungültiges Zeitliteral '25:00:00'",
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_translated(
+    err_invalid_date_time_literal("2021-01-01T25:00:00"),
+    "<TemporalError> niepoprawny literał daty i czasu '2021-01-01T25:00:00'",
+    "<TemporalError> ungültiges Datums- und Zeitliteral '2021-01-01T25:00:00'",
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_translated(
+    err_date_time_conversion_failed("2021-01-01"),
+    "<TemporalError> konwersja daty FEEL '2021-01-01' na DateTime<FixedOffset> nie powiodła się, szczegóły w zgłoszeniu #?",
+    "<TemporalError> Konvertierung des FEEL-Datums '2021-01-01' in DateTime<FixedOffset> fehlgeschlagen, Details siehe Issue #?",
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_translated(
+    err_invalid_years_and_months_duration_literal("P1D"),
+    "<TemporalError> niepoprawny literał lat i miesięcy 'P1D'",
+    "<TemporalError> ungültiges Jahre-und-Monate-Literal 'P1D'",
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_translated(
+    err_invalid_time_zone_offset(90000),
+    "<TemporalError> niepoprawne przesunięcie strefy czasowej '90000'",
+    "<TemporalError> ungültiger Zeitzonenversatz '90000'",
+  );
+}
+
+#[test]
+fn _0009() {
+  assert_translated(
+    err_invalid_date_and_time_duration_literal("P1Y".to_string()),
+    "<TemporalError> niepoprawny literał okresu dni i czasu: P1Y",
+    "<TemporalError> ungültiges Tage-und-Zeit-Dauerliteral: P1Y",
+  );
+}
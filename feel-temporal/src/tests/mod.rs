@@ -1,3 +1,4 @@
 mod feel_date;
 mod feel_date_time;
 mod feel_time;
+mod messages;
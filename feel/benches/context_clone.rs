@@ -0,0 +1,42 @@
+#![feature(test)]
+
+extern crate test;
+
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+use test::Bencher;
+
+/// Creates a context with specified number of entries, each entry contains a nested context.
+fn context(entry_count: usize) -> FeelContext {
+  let mut ctx = FeelContext::default();
+  for i in 0..entry_count {
+    let mut nested = FeelContext::default();
+    nested.set_entry(&"value".into(), value_number!(i as i64));
+    nested.set_entry(&"label".into(), Value::String(format!("label {i}").into()));
+    ctx.set_entry(&Name::from(format!("entry {i}").as_str()), Value::Context(nested));
+  }
+  ctx
+}
+
+#[bench]
+fn feel_context_clone_0001(b: &mut Bencher) {
+  let ctx = context(10);
+  b.iter(|| ctx.clone());
+}
+
+#[bench]
+fn feel_context_clone_0002(b: &mut Bencher) {
+  let ctx = context(1000);
+  b.iter(|| ctx.clone());
+}
+
+#[bench]
+fn feel_context_clone_0003(b: &mut Bencher) {
+  let ctx = context(1000);
+  b.iter(|| {
+    let mut cloned = ctx.clone();
+    cloned.set_entry(&"result".into(), value_number!(1));
+    cloned
+  });
+}
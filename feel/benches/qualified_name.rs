@@ -0,0 +1,32 @@
+#![feature(test)]
+
+extern crate test;
+
+use dmntk_feel::{Name, QualifiedName};
+use test::Bencher;
+
+#[bench]
+fn feel_qualified_name_0001(b: &mut Bencher) {
+  let name: Name = "a".into();
+  b.iter(|| QualifiedName::new(&[&name]));
+}
+
+#[bench]
+fn feel_qualified_name_0002(b: &mut Bencher) {
+  let name_a: Name = "a".into();
+  let name_b: Name = "b".into();
+  b.iter(|| QualifiedName::new(&[&name_a, &name_b]));
+}
+
+#[bench]
+fn feel_qualified_name_0003(b: &mut Bencher) {
+  let name: Name = "a".into();
+  let qname = QualifiedName::new(&[&name]);
+  b.iter(|| qname.clone());
+}
+
+#[bench]
+fn feel_qualified_name_0004(b: &mut Bencher) {
+  let name: Name = "a.b".into();
+  b.iter(|| QualifiedName::from(name.clone()));
+}
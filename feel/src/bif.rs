@@ -27,10 +27,13 @@ pub enum Bif {
   Duration,
   During,
   EndsWith,
+  Env,
   Even,
   Exp,
   FinishedBy,
   Finishes,
+  #[cfg(feature = "calendar")]
+  FirstDayOfMonth,
   Flatten,
   Floor,
   GetEntries,
@@ -39,6 +42,8 @@ pub enum Bif {
   IndexOf,
   InsertBefore,
   Is,
+  #[cfg(feature = "calendar")]
+  LastDayOfMonth,
   ListContains,
   Log,
   LoweCase,
@@ -59,6 +64,8 @@ pub enum Bif {
   OverlapsAfter,
   OverlapsBefore,
   Product,
+  #[cfg(feature = "calendar")]
+  QuarterOfYear,
   Remove,
   Replace,
   Reverse,
@@ -83,6 +90,91 @@ pub enum Bif {
   YearsAndMonthsDuration,
 }
 
+/// Names of all built-in functions.
+pub const BIF_NAMES: &[&str] = &[
+  "abs",
+  "after",
+  "all",
+  "any",
+  "append",
+  "before",
+  "ceiling",
+  "coincides",
+  "concatenate",
+  "contains",
+  "count",
+  "date",
+  "date and time",
+  "day of week",
+  "day of year",
+  "decimal",
+  "distinct values",
+  "duration",
+  "during",
+  "ends with",
+  "env",
+  "even",
+  "exp",
+  "finished by",
+  "finishes",
+  #[cfg(feature = "calendar")]
+  "first day of month",
+  "flatten",
+  "floor",
+  "get entries",
+  "get value",
+  "includes",
+  "index of",
+  "insert before",
+  "is",
+  #[cfg(feature = "calendar")]
+  "last day of month",
+  "list contains",
+  "log",
+  "lower case",
+  "matches",
+  "max",
+  "mean",
+  "median",
+  "meets",
+  "met by",
+  "min",
+  "mode",
+  "modulo",
+  "month of year",
+  "not",
+  "number",
+  "odd",
+  "overlaps",
+  "overlaps after",
+  "overlaps before",
+  "product",
+  #[cfg(feature = "calendar")]
+  "quarter of year",
+  "remove",
+  "replace",
+  "reverse",
+  "sort",
+  "split",
+  "sqrt",
+  "started by",
+  "starts",
+  "starts with",
+  "stddev",
+  "string",
+  "string length",
+  "sublist",
+  "substring",
+  "substring after",
+  "substring before",
+  "sum",
+  "time",
+  "union",
+  "upper case",
+  "week of year",
+  "years and months duration",
+];
+
 impl FromStr for Bif {
   type Err = DmntkError;
   /// Converts a string into corresponding enumeration variant of [Bif].
@@ -108,10 +200,13 @@ impl FromStr for Bif {
       "duration" => Ok(Self::Duration),
       "during" => Ok(Self::During),
       "ends with" => Ok(Self::EndsWith),
+      "env" => Ok(Self::Env),
       "even" => Ok(Self::Even),
       "exp" => Ok(Self::Exp),
       "finished by" => Ok(Self::FinishedBy),
       "finishes" => Ok(Self::Finishes),
+      #[cfg(feature = "calendar")]
+      "first day of month" => Ok(Self::FirstDayOfMonth),
       "flatten" => Ok(Self::Flatten),
       "floor" => Ok(Self::Floor),
       "get entries" => Ok(Self::GetEntries),
@@ -120,6 +215,8 @@ impl FromStr for Bif {
       "index of" => Ok(Self::IndexOf),
       "insert before" => Ok(Self::InsertBefore),
       "is" => Ok(Self::Is),
+      #[cfg(feature = "calendar")]
+      "last day of month" => Ok(Self::LastDayOfMonth),
       "list contains" => Ok(Self::ListContains),
       "log" => Ok(Self::Log),
       "lower case" => Ok(Self::LoweCase),
@@ -140,6 +237,8 @@ impl FromStr for Bif {
       "overlaps after" => Ok(Self::OverlapsAfter),
       "overlaps before" => Ok(Self::OverlapsBefore),
       "product" => Ok(Self::Product),
+      #[cfg(feature = "calendar")]
+      "quarter of year" => Ok(Self::QuarterOfYear),
       "remove" => Ok(Self::Remove),
       "replace" => Ok(Self::Replace),
       "reverse" => Ok(Self::Reverse),
@@ -179,6 +278,50 @@ impl Bif {
       }
     }
   }
+
+  /// Returns `true` when none of the parameters of this function accepts a list,
+  /// so singleton lists passed as arguments may be implicitly converted to their only item.
+  pub fn has_single_value_parameters(&self) -> bool {
+    match self {
+      Bif::Abs
+      | Bif::Ceiling
+      | Bif::Contains
+      | Bif::Date
+      | Bif::DateAndTime
+      | Bif::DayOfWeek
+      | Bif::DayOfYear
+      | Bif::Decimal
+      | Bif::Duration
+      | Bif::EndsWith
+      | Bif::Env
+      | Bif::Even
+      | Bif::Exp
+      | Bif::Floor
+      | Bif::Log
+      | Bif::LoweCase
+      | Bif::Matches
+      | Bif::Modulo
+      | Bif::MonthOfYear
+      | Bif::Not
+      | Bif::Number
+      | Bif::Odd
+      | Bif::Replace
+      | Bif::Split
+      | Bif::Sqrt
+      | Bif::StartsWith
+      | Bif::StringLength
+      | Bif::Substring
+      | Bif::SubstringAfter
+      | Bif::SubstringBefore
+      | Bif::Time
+      | Bif::UpperCase
+      | Bif::WeekOfYear
+      | Bif::YearsAndMonthsDuration => true,
+      #[cfg(feature = "calendar")]
+      Bif::FirstDayOfMonth | Bif::LastDayOfMonth | Bif::QuarterOfYear => true,
+      _ => false,
+    }
+  }
 }
 
 /// Returns `true` when the specified name is a built-in function name.
@@ -0,0 +1,401 @@
+//! # Conversion between FEEL values and CBOR documents
+//!
+//! Values are encoded in CBOR (RFC 8949) with the same structure as in JSON:
+//! contexts are encoded as maps with text keys, lists as arrays, and temporal values,
+//! ranges and null values with details are encoded as text strings.
+//! Integer numbers are encoded as integers, other numbers are encoded as decimal fractions
+//! (tag 4), so no precision is lost. Mantissas not fitting into 64 bits are encoded as bignums.
+//!
+//! Decoding accepts integers, bignums, decimal fractions, floating-point numbers, text strings,
+//! arrays, maps with text keys, booleans, null and undefined, definite and indefinite lengths.
+//! Other tags are ignored, only the tagged content is decoded. Nesting of arrays, maps and tags
+//! is limited to [MAX_NESTING_DEPTH] levels, so malicious documents can not exhaust the stack.
+
+use crate::context::FeelContext;
+use crate::errors::*;
+use crate::values::Value;
+use crate::{FeelNumber, Name};
+use dmntk_common::Result;
+use std::str::FromStr;
+
+/// Major type of unsigned integers.
+const MAJOR_UNSIGNED: u8 = 0;
+/// Major type of negative integers.
+const MAJOR_NEGATIVE: u8 = 1;
+/// Major type of byte strings.
+const MAJOR_BYTES: u8 = 2;
+/// Major type of text strings.
+const MAJOR_TEXT: u8 = 3;
+/// Major type of arrays.
+const MAJOR_ARRAY: u8 = 4;
+/// Major type of maps.
+const MAJOR_MAP: u8 = 5;
+/// Major type of tagged items.
+const MAJOR_TAG: u8 = 6;
+/// Major type of simple values and floating-point numbers.
+const MAJOR_SIMPLE: u8 = 7;
+/// Tag of positive bignums.
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+/// Tag of negative bignums.
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+/// Tag of decimal fractions.
+const TAG_DECIMAL_FRACTION: u64 = 4;
+/// Additional information denoting indefinite length.
+const INDEFINITE: u8 = 31;
+/// Byte terminating items of indefinite length.
+const BREAK: u8 = 0xFF;
+/// Maximum nesting depth of decoded data items.
+pub const MAX_NESTING_DEPTH: usize = 128;
+
+/// Converts the FEEL value into CBOR document.
+pub fn value_to_cbor(value: &Value) -> Vec<u8> {
+  let mut bytes = vec![];
+  encode(value, &mut bytes);
+  bytes
+}
+
+/// Converts the CBOR document into FEEL value.
+pub fn value_from_cbor(bytes: &[u8]) -> Result<Value> {
+  let mut decoder = Decoder { bytes, position: 0, depth: 0 };
+  let value = decoder.value()?;
+  if decoder.position < bytes.len() {
+    return Err(err_invalid_cbor("unexpected data after the end of the document"));
+  }
+  Ok(value)
+}
+
+/// Converts the CBOR document into FEEL context, the document must be a CBOR map.
+pub fn context_from_cbor(bytes: &[u8]) -> Result<FeelContext> {
+  match value_from_cbor(bytes)? {
+    Value::Context(context) => Ok(context),
+    _ => Err(err_cbor_map_expected()),
+  }
+}
+
+/// Encodes the value, appending bytes to the output.
+fn encode(value: &Value, output: &mut Vec<u8>) {
+  match value {
+    Value::Null(None) => output.push(0xF6),
+    Value::Boolean(false) => output.push(0xF4),
+    Value::Boolean(true) => output.push(0xF5),
+    Value::Number(number) => encode_number(number, output),
+    Value::String(s) => encode_text(s.as_ref(), output),
+    Value::ExpressionList(items) | Value::List(items) => {
+      encode_head(MAJOR_ARRAY, items.len() as u64, output);
+      for item in items {
+        encode(item, output);
+      }
+    }
+    Value::Context(context) => {
+      encode_head(MAJOR_MAP, context.len() as u64, output);
+      for (name, entry_value) in context.iter() {
+        encode_text(&name.to_string(), output);
+        encode(entry_value, output);
+      }
+    }
+    other => encode_text(&other.to_string(), output),
+  }
+}
+
+/// Encodes the number as an integer, or as a decimal fraction when it is not an integer.
+fn encode_number(number: &FeelNumber, output: &mut Vec<u8>) {
+  if number.is_integer() {
+    if let Ok(n) = i64::try_from(number) {
+      encode_integer(n as i128, output);
+      return;
+    }
+  }
+  // debug representation contains the sign, coefficient and exponent, like `-12345E-3`
+  let text = format!("{number:?}");
+  let (coefficient, exponent) = text.split_once('E').unwrap_or((&text, "0"));
+  let negative = coefficient.starts_with('-');
+  let coefficient = coefficient.trim_start_matches(['+', '-']).parse::<u128>().unwrap_or_default();
+  let exponent = exponent.parse::<i128>().unwrap_or_default();
+  encode_head(MAJOR_TAG, TAG_DECIMAL_FRACTION, output);
+  encode_head(MAJOR_ARRAY, 2, output);
+  encode_integer(exponent, output);
+  match (u64::try_from(coefficient), negative) {
+    (Ok(n), false) => encode_head(MAJOR_UNSIGNED, n, output),
+    (Ok(n), true) if n > 0 => encode_head(MAJOR_NEGATIVE, n - 1, output),
+    (Ok(_), true) => encode_head(MAJOR_UNSIGNED, 0, output),
+    (Err(_), false) => encode_bignum(TAG_POSITIVE_BIGNUM, coefficient, output),
+    (Err(_), true) => encode_bignum(TAG_NEGATIVE_BIGNUM, coefficient - 1, output),
+  }
+}
+
+/// Encodes the integer as unsigned or negative integer.
+fn encode_integer(n: i128, output: &mut Vec<u8>) {
+  if n >= 0 {
+    encode_head(MAJOR_UNSIGNED, n as u64, output);
+  } else {
+    encode_head(MAJOR_NEGATIVE, (-1 - n) as u64, output);
+  }
+}
+
+/// Encodes the bignum with the specified tag, leading zero bytes are omitted.
+fn encode_bignum(tag: u64, n: u128, output: &mut Vec<u8>) {
+  let bytes = n.to_be_bytes();
+  let skipped = bytes.iter().take_while(|byte| **byte == 0).count();
+  encode_head(MAJOR_TAG, tag, output);
+  encode_head(MAJOR_BYTES, (bytes.len() - skipped) as u64, output);
+  output.extend_from_slice(&bytes[skipped..]);
+}
+
+/// Encodes the text string.
+fn encode_text(text: &str, output: &mut Vec<u8>) {
+  encode_head(MAJOR_TEXT, text.len() as u64, output);
+  output.extend_from_slice(text.as_bytes());
+}
+
+/// Encodes the initial byte of the item with its argument in the shortest form.
+fn encode_head(major: u8, argument: u64, output: &mut Vec<u8>) {
+  let major = major << 5;
+  if argument < 24 {
+    output.push(major | argument as u8);
+  } else if argument <= u8::MAX as u64 {
+    output.push(major | 24);
+    output.push(argument as u8);
+  } else if argument <= u16::MAX as u64 {
+    output.push(major | 25);
+    output.extend_from_slice(&(argument as u16).to_be_bytes());
+  } else if argument <= u32::MAX as u64 {
+    output.push(major | 26);
+    output.extend_from_slice(&(argument as u32).to_be_bytes());
+  } else {
+    output.push(major | 27);
+    output.extend_from_slice(&argument.to_be_bytes());
+  }
+}
+
+/// Decoder of CBOR documents.
+struct Decoder<'a> {
+  /// Decoded bytes.
+  bytes: &'a [u8],
+  /// Position of the next byte to be decoded.
+  position: usize,
+  /// Nesting depth of the currently decoded data item.
+  depth: usize,
+}
+
+impl Decoder<'_> {
+  /// Decodes the next data item, reports an error when nesting is too deep.
+  fn value(&mut self) -> Result<Value> {
+    if self.depth == MAX_NESTING_DEPTH {
+      return Err(err_invalid_cbor(&format!("nesting depth exceeds the maximum of {MAX_NESTING_DEPTH}")));
+    }
+    self.depth += 1;
+    let value = self.item();
+    self.depth -= 1;
+    value
+  }
+
+  /// Decodes the next data item, nested data items are decoded with [Decoder::value].
+  fn item(&mut self) -> Result<Value> {
+    let (major, info) = self.head()?;
+    match major {
+      MAJOR_UNSIGNED => Ok(Value::Number(FeelNumber::from(self.argument(info)?))),
+      MAJOR_NEGATIVE => Ok(Value::Number(negative_number(self.argument(info)?))),
+      MAJOR_BYTES => Err(err_invalid_cbor("byte strings are not supported")),
+      MAJOR_TEXT => Ok(Value::String(self.text(info)?.into())),
+      MAJOR_ARRAY => {
+        let mut items = vec![];
+        if info == INDEFINITE {
+          while !self.is_break()? {
+            items.push(self.value()?);
+          }
+        } else {
+          for _ in 0..self.argument(info)? {
+            items.push(self.value()?);
+          }
+        }
+        Ok(Value::List(items))
+      }
+      MAJOR_MAP => {
+        let mut context = FeelContext::default();
+        if info == INDEFINITE {
+          while !self.is_break()? {
+            self.entry(&mut context)?;
+          }
+        } else {
+          for _ in 0..self.argument(info)? {
+            self.entry(&mut context)?;
+          }
+        }
+        Ok(Value::Context(context))
+      }
+      MAJOR_TAG => match self.argument(info)? {
+        TAG_POSITIVE_BIGNUM => Ok(Value::Number(parse_number(&self.bignum()?.to_string())?)),
+        TAG_NEGATIVE_BIGNUM => Ok(Value::Number(parse_number(&format!("-{}", self.bignum()?))? - FeelNumber::one())),
+        TAG_DECIMAL_FRACTION => self.decimal_fraction(),
+        _ => self.value(),
+      },
+      MAJOR_SIMPLE => self.simple(info),
+      _ => Err(err_invalid_cbor(&format!("invalid major type {major}"))),
+    }
+  }
+
+  /// Decodes simple values and floating-point numbers.
+  fn simple(&mut self, info: u8) -> Result<Value> {
+    let float = match info {
+      20 => return Ok(Value::Boolean(false)),
+      21 => return Ok(Value::Boolean(true)),
+      22 | 23 => return Ok(Value::Null(None)),
+      25 => half_to_f64(u16::from_be_bytes(self.array::<2>()?)),
+      26 => f32::from_be_bytes(self.array::<4>()?) as f64,
+      27 => f64::from_be_bytes(self.array::<8>()?),
+      _ => return Err(err_invalid_cbor(&format!("unsupported simple value {info}"))),
+    };
+    if float.is_finite() {
+      Ok(Value::Number(parse_number(&float.to_string())?))
+    } else {
+      Err(err_invalid_cbor("infinite numbers and NaN are not supported"))
+    }
+  }
+
+  /// Decodes the entry of the map, the key must be a text string.
+  fn entry(&mut self, context: &mut FeelContext) -> Result<()> {
+    let (major, info) = self.head()?;
+    if major != MAJOR_TEXT {
+      return Err(err_invalid_cbor("map keys must be text strings"));
+    }
+    let name = self.text(info)?;
+    let value = self.value()?;
+    context.set_entry(&Name::from(name.as_str()), value);
+    Ok(())
+  }
+
+  /// Decodes the decimal fraction, being an array of an exponent and a mantissa.
+  fn decimal_fraction(&mut self) -> Result<Value> {
+    let (major, info) = self.head()?;
+    if major != MAJOR_ARRAY || info != 2 {
+      return Err(err_invalid_cbor("decimal fraction must be an array of two integers"));
+    }
+    let exponent = self.integer()?;
+    let mantissa = self.integer()?;
+    Ok(Value::Number(parse_number(&format!("{mantissa}E{exponent}"))?))
+  }
+
+  /// Decodes an integer or a bignum.
+  fn integer(&mut self) -> Result<i128> {
+    let (major, info) = self.head()?;
+    match major {
+      MAJOR_UNSIGNED => Ok(self.argument(info)? as i128),
+      MAJOR_NEGATIVE => Ok(-1 - self.argument(info)? as i128),
+      MAJOR_TAG => match self.argument(info)? {
+        TAG_POSITIVE_BIGNUM => i128::try_from(self.bignum()?).map_err(|_| err_invalid_cbor("bignum is too large")),
+        TAG_NEGATIVE_BIGNUM => i128::try_from(self.bignum()?).map(|n| -1 - n).map_err(|_| err_invalid_cbor("bignum is too large")),
+        _ => Err(err_invalid_cbor("integer expected")),
+      },
+      _ => Err(err_invalid_cbor("integer expected")),
+    }
+  }
+
+  /// Decodes the content of a bignum, being a byte string.
+  fn bignum(&mut self) -> Result<u128> {
+    let (major, info) = self.head()?;
+    if major != MAJOR_BYTES || info == INDEFINITE {
+      return Err(err_invalid_cbor("bignum must be a byte string"));
+    }
+    let length = self.argument(info)? as usize;
+    if length > 16 {
+      return Err(err_invalid_cbor("bignum is too large"));
+    }
+    Ok(self.take(length)?.iter().fold(0_u128, |n, byte| (n << 8) | *byte as u128))
+  }
+
+  /// Decodes the text string, chunks of the text with indefinite length are concatenated.
+  fn text(&mut self, info: u8) -> Result<String> {
+    if info == INDEFINITE {
+      let mut text = String::new();
+      while !self.is_break()? {
+        let (major, info) = self.head()?;
+        if major != MAJOR_TEXT || info == INDEFINITE {
+          return Err(err_invalid_cbor("chunks of text strings must be text strings with definite length"));
+        }
+        text.push_str(&self.text(info)?);
+      }
+      return Ok(text);
+    }
+    let length = self.argument(info)? as usize;
+    let bytes = self.take(length)?;
+    String::from_utf8(bytes.to_vec()).map_err(|_| err_invalid_cbor("text string is not valid UTF-8"))
+  }
+
+  /// Decodes the initial byte of the item, returns the major type and additional information.
+  fn head(&mut self) -> Result<(u8, u8)> {
+    let byte = self.take(1)?[0];
+    Ok((byte >> 5, byte & 0x1F))
+  }
+
+  /// Decodes the argument of the item, based on additional information.
+  fn argument(&mut self, info: u8) -> Result<u64> {
+    match info {
+      0..=23 => Ok(info as u64),
+      24 => Ok(self.take(1)?[0] as u64),
+      25 => Ok(u16::from_be_bytes(self.array::<2>()?) as u64),
+      26 => Ok(u32::from_be_bytes(self.array::<4>()?) as u64),
+      27 => Ok(u64::from_be_bytes(self.array::<8>()?)),
+      _ => Err(err_invalid_cbor(&format!("invalid additional information {info}"))),
+    }
+  }
+
+  /// Returns `true` and consumes the byte when the next byte terminates items of indefinite length.
+  fn is_break(&mut self) -> Result<bool> {
+    match self.bytes.get(self.position) {
+      Some(&BREAK) => {
+        self.position += 1;
+        Ok(true)
+      }
+      Some(_) => Ok(false),
+      None => Err(err_invalid_cbor("unexpected end of the document")),
+    }
+  }
+
+  /// Takes the array of the specified number of bytes.
+  fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+    let mut array = [0; N];
+    array.copy_from_slice(self.take(N)?);
+    Ok(array)
+  }
+
+  /// Takes the specified number of bytes.
+  fn take(&mut self, length: usize) -> Result<&[u8]> {
+    let end = self.position.checked_add(length).filter(|end| *end <= self.bytes.len());
+    let Some(end) = end else {
+      return Err(err_invalid_cbor("unexpected end of the document"));
+    };
+    let bytes = &self.bytes[self.position..end];
+    self.position = end;
+    Ok(bytes)
+  }
+}
+
+/// Returns the number encoded as negative integer with the specified argument.
+fn negative_number(argument: u64) -> FeelNumber {
+  match i64::try_from(argument) {
+    Ok(n) => FeelNumber::from(-1 - n),
+    Err(_) => -FeelNumber::from(argument) - FeelNumber::one(),
+  }
+}
+
+/// Parses the number from text.
+fn parse_number(text: &str) -> Result<FeelNumber> {
+  FeelNumber::from_str(text).map_err(|_| err_invalid_cbor(&format!("invalid number {text}")))
+}
+
+/// Converts the half-precision floating-point number into double-precision.
+fn half_to_f64(half: u16) -> f64 {
+  let exponent = (half >> 10) & 0x1F;
+  let mantissa = (half & 0x3FF) as f64;
+  let value = match exponent {
+    0 => mantissa * 2_f64.powi(-24),
+    31 if mantissa == 0.0 => f64::INFINITY,
+    31 => f64::NAN,
+    _ => (mantissa + 1024.0) * 2_f64.powi(exponent as i32 - 25),
+  };
+  if half & 0x8000 != 0 {
+    -value
+  } else {
+    value
+  }
+}
@@ -0,0 +1,74 @@
+//! # Conformance level
+//!
+//! DMN defines three conformance levels. Level 3 covers the full FEEL language,
+//! including implicit conversions between single values and singleton lists.
+//! Lower levels restrict models to simpler constructs, implicit conversions
+//! are not performed when evaluating at these levels.
+
+use crate::errors::err_invalid_conformance_level;
+use dmntk_common::DmntkError;
+use std::cell::Cell;
+use std::fmt;
+use std::str::FromStr;
+
+/// DMN conformance level.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConformanceLevel {
+  /// Conformance level 1, decision requirements diagrams with decision logic not executable.
+  Level1,
+  /// Conformance level 2, decision logic expressed in S-FEEL.
+  Level2,
+  /// Conformance level 3, decision logic expressed in full FEEL.
+  #[default]
+  Level3,
+}
+
+impl ConformanceLevel {
+  /// Returns `true` when implicit conversions between single values
+  /// and singleton lists are performed at this conformance level.
+  pub fn allows_implicit_conversions(&self) -> bool {
+    *self == ConformanceLevel::Level3
+  }
+}
+
+impl fmt::Display for ConformanceLevel {
+  /// Formats the conformance level as its number.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ConformanceLevel::Level1 => write!(f, "1"),
+      ConformanceLevel::Level2 => write!(f, "2"),
+      ConformanceLevel::Level3 => write!(f, "3"),
+    }
+  }
+}
+
+impl FromStr for ConformanceLevel {
+  type Err = DmntkError;
+  /// Converts the number of the conformance level, optionally prefixed with `CL`, into [ConformanceLevel].
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim().trim_start_matches("CL") {
+      "1" => Ok(Self::Level1),
+      "2" => Ok(Self::Level2),
+      "3" => Ok(Self::Level3),
+      _ => Err(err_invalid_conformance_level(s)),
+    }
+  }
+}
+
+thread_local! {
+  /// Conformance level of the evaluation running in the current thread.
+  static CONFORMANCE_LEVEL: Cell<ConformanceLevel> = const { Cell::new(ConformanceLevel::Level3) };
+}
+
+/// Runs the evaluation at specified conformance level.
+pub fn evaluate_at_conformance_level<T>(level: ConformanceLevel, evaluation: impl FnOnce() -> T) -> T {
+  let previous = CONFORMANCE_LEVEL.with(|current| current.replace(level));
+  let result = evaluation();
+  CONFORMANCE_LEVEL.with(|current| current.set(previous));
+  result
+}
+
+/// Returns the conformance level of the evaluation running in the current thread.
+pub fn conformance_level() -> ConformanceLevel {
+  CONFORMANCE_LEVEL.with(|current| current.get())
+}
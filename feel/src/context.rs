@@ -11,13 +11,18 @@ use std::collections::btree_map::Iter;
 use std::collections::BTreeMap;
 use std::fmt;
 use std::ops::Deref;
+use std::sync::Arc;
 
 /// Type alias for context entries.
 type FeelContextEntries = BTreeMap<Name, Value>;
 
 /// The FEEL context.
+///
+/// Entries are shared between clones of the context and copied only when
+/// a shared context is modified, so cloning contexts when pushing them to scopes
+/// or capturing them in closures does not copy entries.
 #[derive(Debug, Clone, PartialEq, Default)]
-pub struct FeelContext(FeelContextEntries);
+pub struct FeelContext(Arc<FeelContextEntries>);
 
 impl Deref for FeelContext {
   type Target = FeelContextEntries;
@@ -112,17 +117,17 @@ impl FeelContext {
 
   /// Sets a value for specified entry name.
   pub fn set_entry(&mut self, name: &Name, value: Value) {
-    self.0.insert(name.clone(), value);
+    Arc::make_mut(&mut self.0).insert(name.clone(), value);
   }
 
   /// Removes a value of an entry with specified name.
   pub fn remove_entry(&mut self, name: &Name) -> Option<Value> {
-    self.0.remove(name)
+    Arc::make_mut(&mut self.0).remove(name)
   }
 
   /// Sets a null value for specified entry.
   pub fn set_null(&mut self, name: Name) {
-    self.0.insert(name, value_null!());
+    Arc::make_mut(&mut self.0).insert(name, value_null!());
   }
 
   /// Returns a list of all [FeelContext] entries.
@@ -156,22 +161,26 @@ impl FeelContext {
   }
 
   pub fn zip(&mut self, other: &FeelContext) {
-    for (name, value) in &other.0 {
-      self.0.insert(name.clone(), value.clone());
+    if other.0.is_empty() {
+      return;
+    }
+    let entries = Arc::make_mut(&mut self.0);
+    for (name, value) in other.0.iter() {
+      entries.insert(name.clone(), value.clone());
     }
   }
 
   pub fn overwrite(&mut self, other: &FeelContext) {
-    for (name, value) in &other.0 {
+    for (name, value) in other.0.iter() {
       if self.0.contains_key(name) {
-        self.0.insert(name.clone(), value.clone());
+        Arc::make_mut(&mut self.0).insert(name.clone(), value.clone());
       }
     }
   }
 
   //TODO refactor the name, this operation is not moving, it is like prefixing
   pub fn move_entry(&mut self, name: Name, parent: Name) {
-    if let Some(value) = self.0.remove(&name) {
+    if let Some(value) = Arc::make_mut(&mut self.0).remove(&name) {
       self.create_entries(&[parent, name], value);
     }
   }
@@ -215,12 +224,12 @@ impl FeelContext {
     // if tail is empty, then insert the value under
     // specified key in current context and return
     if tail.is_empty() {
-      self.0.insert(key, value);
+      Arc::make_mut(&mut self.0).insert(key, value);
       return;
     }
     // if there is a context under the specified key,
     // then insert value to this context and return
-    if let Some(Value::Context(sub_ctx)) = self.0.get_mut(&key) {
+    if let Some(Value::Context(sub_ctx)) = Arc::make_mut(&mut self.0).get_mut(&key) {
       sub_ctx.create_entries(tail, value);
       return;
     }
@@ -228,7 +237,7 @@ impl FeelContext {
     // to newly created context
     let mut sub_ctx = FeelContext::default();
     sub_ctx.create_entries(tail, value);
-    self.0.insert(key, sub_ctx.into());
+    Arc::make_mut(&mut self.0).insert(key, sub_ctx.into());
   }
 
   /// Deep search for a value pointed by names.
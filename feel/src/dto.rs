@@ -193,7 +193,7 @@ impl TryFrom<&SimpleDto> for Value {
     if let Some(typ) = &value.typ {
       if let Some(text) = &value.text {
         return match typ.as_str() {
-          XSD_STRING => Ok(Value::String(text.clone())),
+          XSD_STRING => Ok(Value::String(text.into())),
           XSD_INTEGER => Ok(Value::try_from_xsd_integer(text)?),
           XSD_DECIMAL => Ok(Value::try_from_xsd_decimal(text)?),
           XSD_DOUBLE => Ok(Value::try_from_xsd_double(text)?),
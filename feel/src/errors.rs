@@ -1,85 +1,144 @@
 //! # Error definitions
 
 use crate::values::Value;
-use dmntk_common::{DmntkError, ToErrorMessage};
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
 
 /// Definition of errors raised in `types` module.
 #[derive(ToErrorMessage)]
-struct TypesError(String);
+struct TypesError(Message);
 
 /// Creates an invalid `FEEL` type name error.
 pub fn err_invalid_feel_type_name(s: &str) -> DmntkError {
-  TypesError(format!("invalid FEEL type name: {s}")).into()
+  TypesError(message!("invalid FEEL type name: {}", s)).into()
 }
 
 /// Creates an error indicating value non conformant with type.
 pub fn err_invalid_value_for_retrieving_using_feel_type(s1: &str, s2: &str) -> DmntkError {
-  TypesError(format!("invalid value for retrieving with type check, type = '{s1}', value = '{s2}'")).into()
+  TypesError(message!("invalid value for retrieving with type check, type = '{}', value = '{}'", s1, s2)).into()
 }
 
 /// Value errors.
 #[derive(ToErrorMessage)]
-struct ValueError(String);
+struct ValueError(Message);
 
 /// Error used when parsed text is not acceptable `xsd:integer` representation.
 pub fn err_invalid_xsd_integer(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:integer representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:integer")).into()
 }
 /// Error used when parsed text is not acceptable `xsd:decimal` representation.
 pub fn err_invalid_xsd_decimal(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:decimal representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:decimal")).into()
 }
 /// Error used when parsed text is not acceptable `xsd:double` representation.
 pub fn err_invalid_xsd_double(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:double representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:double")).into()
 }
 /// Error used when parsed text is not acceptable `xsd:boolean` representation.
 pub fn err_invalid_xsd_boolean(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:boolean representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:boolean")).into()
 }
 /// Error used when parsed text is not acceptable `xsd:date` representation.
 pub fn err_invalid_xsd_date(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:date representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:date")).into()
 }
 /// Error used when parsed text is not acceptable `xsd:time` representation.
 pub fn err_invalid_xsd_time(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:time representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:time")).into()
 }
 /// Error used when parsed text is not acceptable `xsd:dateTime` representation.
 pub fn err_invalid_xsd_date_time(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:dateTime representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:dateTime")).into()
 }
 /// Error used when parsed text is not acceptable `xsd:duration` representation.
 pub fn err_invalid_xsd_duration(text: &str) -> DmntkError {
-  ValueError(format!("'{text}' is not valid xsd:duration representation")).into()
+  ValueError(message!("'{}' is not valid {} representation", text, "xsd:duration")).into()
 }
 
 /// Context errors.
 #[derive(ToErrorMessage)]
-struct ContextError(String);
+struct ContextError(Message);
 
 /// Creates an instance of `value is not a context` error.
 pub fn err_value_is_not_a_context(value: &Value) -> DmntkError {
-  ContextError(format!("'{value}' is not a value containing context")).into()
+  ContextError(message!("'{}' is not a value containing context", value)).into()
 }
 
 /// Built-in functions errors.
 #[derive(ToErrorMessage)]
-struct BifError(String);
+struct BifError(Message);
 
 /// Creates an instance of `UnknownFunctionName` error.
 pub fn err_unknown_function_name(name: &str) -> DmntkError {
-  BifError(format!("unknown built-in function name: {name}")).into()
+  BifError(message!("unknown built-in function name: {}", name)).into()
 }
 
 /// DTO errors.
 #[derive(ToErrorMessage)]
-struct DtoError(String);
+struct DtoError(Message);
 
 pub fn err_invalid_attribute(description: &str) -> DmntkError {
-  DtoError(format!("invalid attribute: {description}")).into()
+  DtoError(message!("invalid attribute: {}", description)).into()
 }
 
 pub fn err_missing_attribute(name: &str) -> DmntkError {
-  DtoError(format!("missing attribute: {name}")).into()
+  DtoError(message!("missing attribute: {}", name)).into()
+}
+
+/// JSON conversion errors.
+#[derive(ToErrorMessage)]
+struct JsonError(Message);
+
+/// Error used when the converted document is not valid JSON.
+pub fn err_invalid_json(reason: &str) -> DmntkError {
+  JsonError(message!("invalid JSON: {}", reason)).into()
+}
+
+/// Error used when the converted JSON document is not an object.
+pub fn err_json_object_expected() -> DmntkError {
+  JsonError(message!("expected JSON object")).into()
+}
+
+/// CBOR conversion errors.
+#[derive(ToErrorMessage)]
+struct CborError(Message);
+
+/// Error used when the CBOR document is not valid or contains unsupported items.
+pub fn err_invalid_cbor(reason: &str) -> DmntkError {
+  CborError(message!("invalid CBOR document: {}", reason)).into()
+}
+
+/// Error used when the CBOR document does not contain a map.
+pub fn err_cbor_map_expected() -> DmntkError {
+  CborError(message!("expected CBOR map")).into()
+}
+
+/// Query errors.
+#[derive(ToErrorMessage)]
+struct QueryError(Message);
+
+/// Error used when the query selecting parts of a value is not valid.
+pub fn err_invalid_query(query: &str, reason: &str) -> DmntkError {
+  QueryError(message!("invalid query '{}': {}", query, reason)).into()
+}
+
+/// Evaluation mode errors.
+#[derive(ToErrorMessage)]
+struct EvaluationModeError(Message);
+
+/// Error used when violations were reported during strict evaluation.
+pub fn err_strict_evaluation_failed(diagnostics: &[String]) -> DmntkError {
+  EvaluationModeError(message!("strict evaluation failed: {}", diagnostics.join("; "))).into()
+}
+
+/// Conformance level errors.
+#[derive(ToErrorMessage)]
+struct ConformanceLevelError(Message);
+
+/// Error used when the text does not denote a valid conformance level.
+pub fn err_invalid_conformance_level(s: &str) -> DmntkError {
+  ConformanceLevelError(message!(
+    "'{}' is not a valid conformance level, accepted values are: '1', '2', '3', 'CL1', 'CL2', 'CL3'",
+    s
+  ))
+  .into()
 }
@@ -0,0 +1,269 @@
+//! # Random FEEL values and expressions
+//!
+//! Generators of random FEEL values and syntactically valid FEEL expressions,
+//! used for property-based testing of the parser and the evaluator.
+//! Generated sequences depend only on the seed, so every failure found
+//! with a generator can be reproduced by running it again with the same seed.
+
+use crate::context::FeelContext;
+use crate::strings::ToFeelString;
+use crate::values::Value;
+use crate::{FeelNumber, FeelType, Name};
+use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
+
+/// Default maximum nesting level of generated values and expressions.
+const DEFAULT_MAX_DEPTH: usize = 3;
+
+/// Names of entries in generated contexts.
+const ENTRY_NAMES: [&str; 6] = ["a", "b", "c", "amount", "total", "name"];
+
+/// Names of variables in generated iterations and quantified expressions.
+const VARIABLE_NAMES: [&str; 3] = ["x", "y", "z"];
+
+/// Names of simple built-in types used in generated `instance of` expressions.
+const TYPE_NAMES: [&str; 8] = [
+  "number",
+  "string",
+  "boolean",
+  "date",
+  "time",
+  "date and time",
+  "days and time duration",
+  "years and months duration",
+];
+
+/// Names and number of parameters of built-in functions used in generated invocations.
+const FUNCTIONS: [(&str, usize); 20] = [
+  ("abs", 1),
+  ("ceiling", 1),
+  ("floor", 1),
+  ("not", 1),
+  ("string", 1),
+  ("string length", 1),
+  ("upper case", 1),
+  ("lower case", 1),
+  ("count", 1),
+  ("sum", 1),
+  ("min", 1),
+  ("max", 1),
+  ("reverse", 1),
+  ("flatten", 1),
+  ("distinct values", 1),
+  ("day of week", 1),
+  ("modulo", 2),
+  ("contains", 2),
+  ("append", 2),
+  ("substring", 2),
+];
+
+/// Generator of random FEEL values and expressions.
+pub struct FeelGenerator {
+  /// State of the pseudo-random number generator.
+  state: u64,
+  /// Maximum nesting level of generated values and expressions.
+  max_depth: usize,
+}
+
+impl FeelGenerator {
+  /// Creates a generator initialized with specified seed.
+  pub fn new(seed: u64) -> Self {
+    Self {
+      // the state of xorshift generator must not be zero
+      state: seed.max(1),
+      max_depth: DEFAULT_MAX_DEPTH,
+    }
+  }
+
+  /// Sets the maximum nesting level of generated values and expressions.
+  pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+    self.max_depth = max_depth;
+    self
+  }
+
+  /// Returns random value, possibly containing nested lists and contexts.
+  pub fn value(&mut self) -> Value {
+    self.value_at(0)
+  }
+
+  /// Returns random syntactically valid FEEL expression.
+  pub fn expression(&mut self) -> String {
+    self.expression_at(0)
+  }
+
+  /// Returns random value conforming to specified type,
+  /// values of types other than simple types, lists and contexts are `null`.
+  pub fn value_of_type(&mut self, feel_type: &FeelType) -> Value {
+    match feel_type {
+      FeelType::Any => self.value(),
+      FeelType::Boolean => Value::Boolean(self.next(0, 1) == 1),
+      FeelType::Number => Value::Number(FeelNumber::new(self.next(-100_000, 100_000), self.next(0, 3) as i32)),
+      FeelType::String => Value::String(self.text().into()),
+      FeelType::Date => Value::Date(FeelDate::new(self.next(1900, 2100) as i32, self.next(1, 12) as u32, self.next(1, 28) as u32)),
+      FeelType::Time => Value::Time(FeelTime::local(self.next(0, 23) as u8, self.next(0, 59) as u8, self.next(0, 59) as u8, 0)),
+      FeelType::DateTime => Value::DateTime(FeelDateTime::local(
+        self.next(1900, 2100) as i32,
+        self.next(1, 12) as u32,
+        self.next(1, 28) as u32,
+        self.next(0, 23) as u8,
+        self.next(0, 59) as u8,
+        self.next(0, 59) as u8,
+        0,
+      )),
+      FeelType::DaysAndTimeDuration => Value::DaysAndTimeDuration(FeelDaysAndTimeDuration::from_s(self.next(-10_000_000, 10_000_000))),
+      FeelType::YearsAndMonthsDuration => Value::YearsAndMonthsDuration(FeelYearsAndMonthsDuration::from_m(self.next(-1_000, 1_000))),
+      FeelType::List(item_type) => Value::List((0..self.next(0, 4)).map(|_| self.value_of_type(item_type)).collect()),
+      FeelType::Context(entry_types) => {
+        let mut ctx = FeelContext::default();
+        for (name, entry_type) in entry_types {
+          let value = self.value_of_type(entry_type);
+          ctx.set_entry(name, value);
+        }
+        Value::Context(ctx)
+      }
+      _ => Value::Null(None),
+    }
+  }
+
+  /// Returns random integer in range `min..=max`.
+  pub fn integer(&mut self, min: i64, max: i64) -> i64 {
+    self.next(min, max)
+  }
+
+  /// Returns the next pseudo-random number in range `min..=max`.
+  fn next(&mut self, min: i64, max: i64) -> i64 {
+    self.state ^= self.state << 13;
+    self.state ^= self.state >> 7;
+    self.state ^= self.state << 17;
+    min + (self.state % (max - min + 1) as u64) as i64
+  }
+
+  /// Returns randomly chosen element of the slice, the slice must not be empty.
+  pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+    &items[self.next(0, items.len() as i64 - 1) as usize]
+  }
+
+  /// Returns random value nested at specified depth.
+  fn value_at(&mut self, depth: usize) -> Value {
+    let kinds = if depth < self.max_depth { 12 } else { 10 };
+    match self.next(0, kinds - 1) {
+      0 => Value::Null(None),
+      1 => self.value_of_type(&FeelType::Boolean),
+      2 | 3 => self.value_of_type(&FeelType::Number),
+      4 => self.value_of_type(&FeelType::String),
+      5 => self.value_of_type(&FeelType::Date),
+      6 => self.value_of_type(&FeelType::Time),
+      7 => self.value_of_type(&FeelType::DateTime),
+      8 => self.value_of_type(&FeelType::DaysAndTimeDuration),
+      9 => self.value_of_type(&FeelType::YearsAndMonthsDuration),
+      10 => Value::List((0..self.next(0, 4)).map(|_| self.value_at(depth + 1)).collect()),
+      _ => {
+        let mut ctx = FeelContext::default();
+        for _ in 0..self.next(0, 4) {
+          let name = Name::from(*self.choose(&ENTRY_NAMES));
+          let value = self.value_at(depth + 1);
+          ctx.set_entry(&name, value);
+        }
+        Value::Context(ctx)
+      }
+    }
+  }
+
+  /// Returns random text containing letters, digits and spaces.
+  fn text(&mut self) -> String {
+    const CHARACTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ";
+    (0..self.next(0, 12)).map(|_| *self.choose(CHARACTERS) as char).collect()
+  }
+
+  /// Returns random endpoint of a range or lower bound of `between` clause,
+  /// limited to simple literals other than `null`, numeric literals are not negative.
+  fn endpoint(&mut self) -> String {
+    loop {
+      match self.value_at(self.max_depth) {
+        Value::Null(_) => {}
+        Value::Number(number) => return number.abs().to_string(),
+        other => return literal(&other),
+      }
+    }
+  }
+
+  /// Returns random expression nested at specified depth.
+  fn expression_at(&mut self, depth: usize) -> String {
+    if depth >= self.max_depth || self.next(0, 3) == 0 {
+      return literal(&self.value_at(self.max_depth));
+    }
+    let depth = depth + 1;
+    // compound expressions are parenthesized, so names followed by operators
+    // are never read as multi-word names, and precedence of operators does not matter
+    let expression = match self.next(0, 15) {
+      0 => {
+        let operator = self.choose(&["+", "-", "*", "/", "**"]);
+        format!("{} {operator} {}", self.expression_at(depth), self.expression_at(depth))
+      }
+      1 => {
+        let operator = self.choose(&["=", "!=", "<", "<=", ">", ">="]);
+        format!("{} {operator} {}", self.expression_at(depth), self.expression_at(depth))
+      }
+      2 => {
+        let operator = self.choose(&["and", "or"]);
+        format!("{} {operator} {}", self.expression_at(depth), self.expression_at(depth))
+      }
+      3 => format!("if {} then {} else {}", self.expression_at(depth), self.expression_at(depth), self.expression_at(depth)),
+      4 => format!("[{}]", (0..self.next(0, 3)).map(|_| self.expression_at(depth)).collect::<Vec<String>>().join(", ")),
+      5 => {
+        let entries = (0..self.next(1, 3))
+          .map(|index| format!("{}: {}", ENTRY_NAMES[index as usize], self.expression_at(depth)))
+          .collect::<Vec<String>>();
+        format!("{{{}}}.{}", entries.join(", "), ENTRY_NAMES[self.next(0, entries.len() as i64 - 1) as usize])
+      }
+      6 => format!("{}[{}]", self.expression_at(depth), self.expression_at(depth)),
+      7 => {
+        let variable = self.choose(&VARIABLE_NAMES);
+        format!("for {variable} in {} return [{variable}, {}]", self.expression_at(depth), self.expression_at(depth))
+      }
+      8 => {
+        let variable = self.choose(&VARIABLE_NAMES);
+        let quantifier = self.choose(&["some", "every"]);
+        format!(
+          "{quantifier} {variable} in {} satisfies {variable} = {}",
+          self.expression_at(depth),
+          self.expression_at(depth)
+        )
+      }
+      9 => {
+        // conjunction in the lower bound would be read as `and` of the `between` clause
+        format!("{} between {} and {}", self.expression_at(depth), self.endpoint(), self.expression_at(depth))
+      }
+      10 => {
+        let (open, close) = (self.choose(&["[", "(", "]"]), self.choose(&["]", ")", "["]));
+        format!("{} in {open}{}..{}{close}", self.expression_at(depth), self.endpoint(), self.endpoint())
+      }
+      11 => format!("{} instance of {}", self.expression_at(depth), self.choose(&TYPE_NAMES)),
+      12 => format!("-{}", self.expression_at(depth)),
+      13 => format!("(function(x, y) x + y)({}, {})", self.expression_at(depth), self.expression_at(depth)),
+      _ => {
+        let (name, parameter_count) = *self.choose(&FUNCTIONS);
+        let arguments = (0..parameter_count).map(|_| self.expression_at(depth)).collect::<Vec<String>>();
+        format!("{name}({})", arguments.join(", "))
+      }
+    };
+    format!("({expression})")
+  }
+}
+
+/// Returns the FEEL expression that evaluates to specified value.
+pub fn literal(value: &Value) -> String {
+  match value {
+    Value::Null(_) => "null".to_string(),
+    Value::Number(number) if number.is_negative() => format!("-{}", number.abs()),
+    Value::Date(date) => format!(r#"date("{date}")"#),
+    Value::Time(time) => format!(r#"time("{time}")"#),
+    Value::DateTime(date_time) => format!(r#"date and time("{date_time}")"#),
+    Value::DaysAndTimeDuration(_) | Value::YearsAndMonthsDuration(_) => format!(r#"duration("{value}")"#),
+    Value::List(items) => format!("[{}]", items.iter().map(literal).collect::<Vec<String>>().join(", ")),
+    Value::Context(ctx) => format!(
+      "{{{}}}",
+      ctx.iter().map(|(name, value)| format!("{name}: {}", literal(value))).collect::<Vec<String>>().join(", ")
+    ),
+    other => other.to_feel_string(),
+  }
+}
@@ -0,0 +1,146 @@
+//! # Conversion of JSON documents into FEEL values
+//!
+//! JSON objects are converted into contexts, arrays into lists,
+//! and numbers are converted into FEEL numbers from their textual representation.
+//! JSON has no representation of temporal values, so they remain strings,
+//! until they are coerced to the declared type with [coerce_json_value].
+
+use crate::context::FeelContext;
+use crate::errors::*;
+use crate::values::Value;
+use crate::{FeelNumber, FeelType, Name};
+use dmntk_common::Result;
+use dmntk_feel_temporal::{FeelDate, FeelDateTime, FeelDaysAndTimeDuration, FeelTime, FeelYearsAndMonthsDuration};
+use std::fmt;
+use std::str::FromStr;
+
+/// Coercion of a value converted from JSON, performed to conform to the declared type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonCoercion {
+  /// Path of the coerced value, context entries are separated with dots, list items are indexed from 1.
+  path: String,
+  /// Type of the value before coercion.
+  from_type: FeelType,
+  /// Type of the value after coercion.
+  to_type: FeelType,
+}
+
+impl fmt::Display for JsonCoercion {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}: {} coerced to {}", self.path, self.from_type, self.to_type)
+  }
+}
+
+impl JsonCoercion {
+  /// Returns the path of the coerced value.
+  pub fn path(&self) -> &str {
+    &self.path
+  }
+
+  /// Returns the type of the value before coercion.
+  pub fn from_type(&self) -> &FeelType {
+    &self.from_type
+  }
+
+  /// Returns the type of the value after coercion.
+  pub fn to_type(&self) -> &FeelType {
+    &self.to_type
+  }
+}
+
+/// Converts the JSON document into FEEL value.
+pub fn value_from_json(json: &str) -> Result<Value> {
+  let json_value = serde_json::from_str::<serde_json::Value>(json).map_err(|reason| err_invalid_json(&reason.to_string()))?;
+  convert(&json_value)
+}
+
+/// Converts the JSON document into FEEL context, the document must be a JSON object.
+pub fn context_from_json(json: &str) -> Result<FeelContext> {
+  match value_from_json(json)? {
+    Value::Context(context) => Ok(context),
+    _ => Err(err_json_object_expected()),
+  }
+}
+
+/// Converts the JSON value into FEEL value.
+fn convert(json_value: &serde_json::Value) -> Result<Value> {
+  Ok(match json_value {
+    serde_json::Value::Null => Value::Null(None),
+    serde_json::Value::Bool(value) => Value::Boolean(*value),
+    serde_json::Value::Number(number) => Value::Number(FeelNumber::from_str(&number.to_string())?),
+    serde_json::Value::String(text) => Value::String(text.clone().into()),
+    serde_json::Value::Array(items) => Value::List(items.iter().map(convert).collect::<Result<Vec<Value>>>()?),
+    serde_json::Value::Object(entries) => {
+      let mut context = FeelContext::default();
+      for (name, json_value) in entries {
+        context.set_entry(&Name::from(name.as_str()), convert(json_value)?);
+      }
+      Value::Context(context)
+    }
+  })
+}
+
+/// Coerces the value converted from JSON to the declared type, returns the coerced value
+/// and the list of performed coercions, `path` is the name of the coerced value.
+///
+/// Strings are parsed into numbers, booleans, dates, times, dates and times and durations,
+/// numbers and booleans are converted into strings, contexts and lists are coerced entry by entry.
+/// Values that can not be coerced are returned unchanged, so the evaluation reports them as usual.
+pub fn coerce_json_value(value: &Value, target_type: &FeelType, path: &str) -> (Value, Vec<JsonCoercion>) {
+  let mut coercions = vec![];
+  let value = coerce(value, target_type, path, &mut coercions);
+  (value, coercions)
+}
+
+/// Coerces the value to the declared type, collecting performed coercions.
+fn coerce(value: &Value, target_type: &FeelType, path: &str, coercions: &mut Vec<JsonCoercion>) -> Value {
+  match (value, target_type) {
+    (Value::Context(context), FeelType::Context(entry_types)) => {
+      let mut coerced = context.clone();
+      for (name, entry_type) in entry_types {
+        if let Some(entry_value) = context.get_entry(name) {
+          coerced.set_entry(name, coerce(entry_value, entry_type, &format!("{path}.{name}"), coercions));
+        }
+      }
+      Value::Context(coerced)
+    }
+    (Value::List(items), FeelType::List(item_type)) => Value::List(
+      items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| coerce(item, item_type, &format!("{path}[{}]", index + 1), coercions))
+        .collect(),
+    ),
+    (value, target_type) => {
+      let Some(coerced) = coerce_scalar(value, target_type) else {
+        return value.clone();
+      };
+      coercions.push(JsonCoercion {
+        path: path.to_string(),
+        from_type: value.type_of(),
+        to_type: target_type.clone(),
+      });
+      coerced
+    }
+  }
+}
+
+/// Coerces the scalar value to the declared type, returns `None` when no coercion was performed.
+fn coerce_scalar(value: &Value, target_type: &FeelType) -> Option<Value> {
+  match (value, target_type) {
+    (Value::String(text), FeelType::Number) => FeelNumber::from_str(text.trim()).ok().map(Value::Number),
+    (Value::String(text), FeelType::Boolean) => match text.trim() {
+      "true" => Some(Value::Boolean(true)),
+      "false" => Some(Value::Boolean(false)),
+      _ => None,
+    },
+    (Value::String(text), FeelType::Date) => FeelDate::from_str(text).ok().map(Value::Date),
+    (Value::String(text), FeelType::Time) => FeelTime::from_str(text).ok().map(Value::Time),
+    (Value::String(text), FeelType::DateTime) => FeelDateTime::try_from(text.as_str()).ok().map(Value::DateTime),
+    (Value::String(text), FeelType::DaysAndTimeDuration) => FeelDaysAndTimeDuration::try_from(text.as_str()).ok().map(Value::DaysAndTimeDuration),
+    (Value::String(text), FeelType::YearsAndMonthsDuration) => FeelYearsAndMonthsDuration::try_from(text.as_str()).ok().map(Value::YearsAndMonthsDuration),
+    (Value::Number(number), FeelType::String) => Some(Value::String(number.to_string().into())),
+    (Value::Boolean(value), FeelType::String) => Some(Value::String(value.to_string().into())),
+    _ => None,
+  }
+}
@@ -4,14 +4,22 @@
 extern crate dmntk_macros;
 
 pub mod bif;
+pub mod cbor;
 pub mod closure;
+mod conformance;
 pub mod context;
 pub mod dto;
 mod errors;
 mod evaluator;
 mod function;
+#[cfg(feature = "generators")]
+pub mod generators;
+pub mod json;
+mod mode;
 mod names;
+mod pretty;
 mod qualified_names;
+pub mod query;
 mod scope;
 mod strings;
 mod types;
@@ -20,11 +28,14 @@ pub mod values;
 #[cfg(test)]
 mod tests;
 
+pub use conformance::{conformance_level, evaluate_at_conformance_level, ConformanceLevel};
 pub use dmntk_feel_number::FeelNumber;
 pub use evaluator::Evaluator;
 pub use function::FunctionBody;
+pub use mode::{evaluate_in_mode, report_violation, EvaluationMode};
 pub use names::Name;
+pub use pretty::PrettyPrinter;
 pub use qualified_names::QualifiedName;
-pub use scope::FeelScope;
-pub use strings::ToFeelString;
+pub use scope::{FeelScope, ScopeBuilder, ScopeLayer};
+pub use strings::{FeelString, StringInterner, ToFeelString};
 pub use types::*;
@@ -0,0 +1,55 @@
+//! # Evaluation mode
+//!
+//! In lenient mode (the default) type coercion failures and missing context entries
+//! silently produce `null` values, which propagate through the rest of the evaluation.
+//! In strict mode the same situations are reported as violations, and the evaluation
+//! run by [evaluate_in_mode] fails with diagnostics describing all of them.
+
+use crate::errors::err_strict_evaluation_failed;
+use dmntk_common::Result;
+use std::cell::RefCell;
+
+/// Mode of handling type coercion failures and missing context entries.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum EvaluationMode {
+  /// Coercion failures and missing context entries evaluate to `null`.
+  #[default]
+  Lenient,
+  /// Coercion failures and missing context entries fail the evaluation.
+  Strict,
+}
+
+thread_local! {
+  /// Diagnostics collected by the strict evaluation running in the current thread.
+  static STRICT_DIAGNOSTICS: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Runs the evaluation in specified mode.
+///
+/// In strict mode returns an error with diagnostics of all violations
+/// reported during the evaluation, if any.
+pub fn evaluate_in_mode<T>(mode: EvaluationMode, evaluation: impl FnOnce() -> T) -> Result<T> {
+  let diagnostics = match mode {
+    EvaluationMode::Lenient => None,
+    EvaluationMode::Strict => Some(vec![]),
+  };
+  let previous = STRICT_DIAGNOSTICS.with(|active| active.replace(diagnostics));
+  let result = evaluation();
+  let current = STRICT_DIAGNOSTICS.with(|active| active.replace(previous));
+  match current {
+    Some(diagnostics) if !diagnostics.is_empty() => Err(err_strict_evaluation_failed(&diagnostics)),
+    _ => Ok(result),
+  }
+}
+
+/// Reports a violation of strict evaluation, the diagnostic is built only in strict mode.
+pub fn report_violation(diagnostic: impl FnOnce() -> String) {
+  STRICT_DIAGNOSTICS.with(|active| {
+    if let Some(diagnostics) = active.borrow_mut().as_mut() {
+      let diagnostic = diagnostic();
+      if !diagnostics.contains(&diagnostic) {
+        diagnostics.push(diagnostic);
+      }
+    }
+  });
+}
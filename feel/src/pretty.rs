@@ -0,0 +1,156 @@
+//! # Pretty printer of FEEL values
+//!
+//! Large nested contexts and lists are printed with each entry in a separate line,
+//! indented according to the nesting level. The depth of printed nesting levels
+//! and the number of printed list elements may be limited, so huge values
+//! remain readable. Scalar values are printed as FEEL literals.
+
+use crate::values::Value;
+use dmntk_common::{ColorMode, ColorPalette};
+
+/// Default number of spaces in a single indentation level.
+const DEFAULT_INDENT: usize = 2;
+
+/// Configurable pretty printer of FEEL values.
+#[derive(Clone)]
+pub struct PrettyPrinter {
+  /// Number of spaces in a single indentation level.
+  indent: usize,
+  /// Maximum number of printed nesting levels, deeper values are elided.
+  max_depth: Option<usize>,
+  /// Maximum number of printed list elements, remaining elements are elided.
+  max_list_items: Option<usize>,
+  /// Color palette used for printing values.
+  colors: ColorPalette,
+}
+
+impl Default for PrettyPrinter {
+  /// Creates a pretty printer without limits and colors.
+  fn default() -> Self {
+    Self {
+      indent: DEFAULT_INDENT,
+      max_depth: None,
+      max_list_items: None,
+      colors: ColorMode::Off.into(),
+    }
+  }
+}
+
+impl PrettyPrinter {
+  /// Sets the number of spaces in a single indentation level.
+  pub fn with_indent(mut self, indent: usize) -> Self {
+    self.indent = indent;
+    self
+  }
+
+  /// Sets the maximum number of printed nesting levels.
+  pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+    self.max_depth = Some(max_depth);
+    self
+  }
+
+  /// Sets the maximum number of printed list elements.
+  pub fn with_max_list_items(mut self, max_list_items: usize) -> Self {
+    self.max_list_items = Some(max_list_items);
+    self
+  }
+
+  /// Sets the color mode used for printing values.
+  pub fn with_color_mode(mut self, color_mode: ColorMode) -> Self {
+    self.colors = color_mode.into();
+    self
+  }
+
+  /// Returns the value formatted by this printer.
+  pub fn print(&self, value: &Value) -> String {
+    let mut output = String::new();
+    self.write_value(&mut output, value, 0);
+    output
+  }
+
+  /// Writes the value nested at specified depth.
+  fn write_value(&self, output: &mut String, value: &Value, depth: usize) {
+    match value {
+      Value::Context(ctx) if !ctx.is_empty() => {
+        if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+          output.push_str("{…}");
+          return;
+        }
+        output.push_str("{\n");
+        let count = ctx.len();
+        for (index, (name, value)) in ctx.iter().enumerate() {
+          self.write_indent(output, depth + 1);
+          output.push_str(&format!("{}{name}{}: ", self.colors.blue(), self.colors.reset()));
+          self.write_value(output, value, depth + 1);
+          output.push_str(if index + 1 < count { ",\n" } else { "\n" });
+        }
+        self.write_indent(output, depth);
+        output.push('}');
+      }
+      Value::List(items) if !items.is_empty() => {
+        if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+          output.push_str("[…]");
+          return;
+        }
+        let printed_count = self.max_list_items.map_or(items.len(), |max_list_items| max_list_items.min(items.len()));
+        let elided = self.elided(items.len() - printed_count);
+        if items.iter().all(|item| !is_nested(item)) {
+          // lists of simple values are printed in a single line
+          let mut texts = items[..printed_count].iter().map(|item| self.scalar(item)).collect::<Vec<String>>();
+          texts.extend(elided);
+          output.push_str(&format!("[{}]", texts.join(", ")));
+          return;
+        }
+        output.push_str("[\n");
+        for (index, item) in items[..printed_count].iter().enumerate() {
+          self.write_indent(output, depth + 1);
+          self.write_value(output, item, depth + 1);
+          output.push_str(if index + 1 < printed_count || elided.is_some() { ",\n" } else { "\n" });
+        }
+        if let Some(elided) = elided {
+          self.write_indent(output, depth + 1);
+          output.push_str(&elided);
+          output.push('\n');
+        }
+        self.write_indent(output, depth);
+        output.push(']');
+      }
+      other => output.push_str(&self.scalar(other)),
+    }
+  }
+
+  /// Writes the indentation of specified nesting level.
+  fn write_indent(&self, output: &mut String, depth: usize) {
+    output.push_str(&" ".repeat(depth * self.indent));
+  }
+
+  /// Returns the note about elided list elements, if any.
+  fn elided(&self, count: usize) -> Option<String> {
+    (count > 0).then(|| format!("{}… {count} more{}", self.colors.white(), self.colors.reset()))
+  }
+
+  /// Returns the colored FEEL literal of a simple value.
+  fn scalar(&self, value: &Value) -> String {
+    let color = match value {
+      Value::String(_) => self.colors.green(),
+      Value::Number(_) => self.colors.cyan(),
+      Value::Boolean(_) | Value::Null(_) => self.colors.magenta(),
+      Value::Context(_) | Value::List(_) => "",
+      _ => self.colors.yellow(),
+    };
+    if color.is_empty() {
+      value.to_string()
+    } else {
+      format!("{color}{value}{}", self.colors.reset())
+    }
+  }
+}
+
+/// Returns `true` when the value is a non-empty context or non-empty list.
+fn is_nested(value: &Value) -> bool {
+  match value {
+    Value::Context(ctx) => !ctx.is_empty(),
+    Value::List(items) => !items.is_empty(),
+    _ => false,
+  }
+}
@@ -1,17 +1,22 @@
 //! `FEEL` qualified names.
 
 use crate::Name;
+use smallvec::SmallVec;
 use std::fmt;
 use std::ops::Deref;
 
+/// Names building the qualified name, most qualified names have one or two names,
+/// so they are stored without allocating a separate buffer.
+type QualifiedNameParts = SmallVec<[Name; 2]>;
+
 /// FEEL `QualifiedName`.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct QualifiedName(Vec<Name>);
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct QualifiedName(QualifiedNameParts);
 
 impl QualifiedName {
   /// Creates a [QualifiedName] from [Names](Name).
   pub fn new(names: &[&Name]) -> Self {
-    Self(names.iter().map(|&v| v.clone()).collect::<Vec<Name>>())
+    Self(names.iter().map(|&v| v.clone()).collect::<QualifiedNameParts>())
   }
 }
 
@@ -23,13 +28,18 @@ impl fmt::Display for QualifiedName {
 }
 
 impl Deref for QualifiedName {
-  type Target = Vec<Name>;
+  type Target = [Name];
   fn deref(&self) -> &Self::Target {
     &self.0
   }
 }
 
 impl QualifiedName {
+  /// Returns names building this [QualifiedName].
+  pub fn as_slice(&self) -> &[Name] {
+    &self.0
+  }
+
   /// Appends this [QualifiedName] with a given [Name].
   pub fn push(&mut self, name: Name) {
     self.0.push(name);
@@ -56,11 +66,11 @@ impl From<Name> for QualifiedName {
 impl From<Vec<Name>> for QualifiedName {
   /// Converts a vector of names into qualified name.
   fn from(value: Vec<Name>) -> Self {
-    let mut names = vec![];
-    for name in value {
-      let mut sub_names = name.to_string().split('.').map(Name::from).collect::<Vec<Name>>();
-      names.append(&mut sub_names);
-    }
-    Self(names)
+    Self(
+      value
+        .iter()
+        .flat_map(|name| name.to_string().split('.').map(Name::from).collect::<QualifiedNameParts>())
+        .collect(),
+    )
   }
 }
@@ -0,0 +1,190 @@
+//! # Path queries over FEEL values
+//!
+//! A query is a sequence of segments separated by dots, like `customer.orders[amount > 100].id`.
+//! Each segment consists of an optional entry name followed by any number of filters in brackets.
+//!
+//! - An entry name selects the entry of a context; applied to a list,
+//!   it selects the entry from each element of the list.
+//! - A filter containing an integer selects the element of a list,
+//!   elements are numbered from 1, negative numbers count from the end of the list.
+//! - A filter containing a comparison `name op literal` selects elements of a list
+//!   being contexts with the entry satisfying the comparison. Supported operators
+//!   are `=`, `!=`, `<`, `<=`, `>` and `>=`, literals are numbers, strings in double quotes,
+//!   `true`, `false` and `null`.
+//!
+//! Selecting parts that do not exist results in `null`, like in FEEL path expressions.
+
+use crate::errors::*;
+use crate::values::Value;
+use crate::{FeelNumber, Name};
+use dmntk_common::Result;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Comparison operators used in filters.
+const OPERATORS: [&str; 6] = ["!=", "<=", ">=", "=", "<", ">"];
+
+/// Filter applied to a list.
+enum Filter {
+  /// Selects the element at specified position, numbered from 1.
+  Index(i64),
+  /// Selects elements having an entry with specified name satisfying the comparison.
+  Comparison(Name, &'static str, Value),
+}
+
+/// Single segment of the query.
+struct Segment {
+  /// Optional name of the selected entry.
+  name: Option<Name>,
+  /// Filters applied after selecting the entry.
+  filters: Vec<Filter>,
+}
+
+impl Value {
+  /// Returns the part of this value selected by the query, like `customer.orders[amount > 100].id`.
+  ///
+  /// See [query](crate::query) module for the syntax of queries.
+  pub fn select(&self, query: &str) -> Result<Value> {
+    let segments = parse_query(query)?;
+    let mut value = self.clone();
+    for segment in &segments {
+      if let Some(name) = &segment.name {
+        value = select_entry(&value, name);
+      }
+      for filter in &segment.filters {
+        value = apply_filter(&value, filter);
+      }
+    }
+    Ok(value)
+  }
+}
+
+/// Parses the query into segments.
+fn parse_query(query: &str) -> Result<Vec<Segment>> {
+  let mut segments = vec![];
+  let mut rest = query.trim();
+  if rest.is_empty() {
+    return Err(err_invalid_query(query, "empty query"));
+  }
+  loop {
+    let name_end = rest.find(['.', '[']).unwrap_or(rest.len());
+    let name = rest[..name_end].trim();
+    rest = &rest[name_end..];
+    let mut filters = vec![];
+    while let Some(after_bracket) = rest.strip_prefix('[') {
+      let Some(filter_end) = after_bracket.find(']') else {
+        return Err(err_invalid_query(query, "missing closing bracket"));
+      };
+      filters.push(parse_filter(query, after_bracket[..filter_end].trim())?);
+      rest = after_bracket[filter_end + 1..].trim_start();
+    }
+    if name.is_empty() && filters.is_empty() {
+      return Err(err_invalid_query(query, "empty segment"));
+    }
+    segments.push(Segment {
+      name: (!name.is_empty()).then(|| Name::from(name)),
+      filters,
+    });
+    match rest.strip_prefix('.') {
+      Some(next) => rest = next,
+      None if rest.is_empty() => return Ok(segments),
+      None => return Err(err_invalid_query(query, &format!("unexpected text '{rest}'"))),
+    }
+  }
+}
+
+/// Parses the content of the filter.
+fn parse_filter(query: &str, text: &str) -> Result<Filter> {
+  if let Ok(index) = text.parse::<i64>() {
+    return if index == 0 {
+      Err(err_invalid_query(query, "list elements are numbered from 1"))
+    } else {
+      Ok(Filter::Index(index))
+    };
+  }
+  // the first operator in the text is used, two-character operators take precedence
+  let Some((position, operator)) = OPERATORS
+    .iter()
+    .filter_map(|operator| text.find(operator).map(|position| (position, *operator)))
+    .min_by_key(|(position, operator)| (*position, usize::MAX - operator.len()))
+  else {
+    return Err(err_invalid_query(query, &format!("invalid filter '{text}'")));
+  };
+  let name = text[..position].trim();
+  if name.is_empty() {
+    return Err(err_invalid_query(query, &format!("missing entry name in filter '{text}'")));
+  }
+  let literal = parse_literal(query, text[position + operator.len()..].trim())?;
+  Ok(Filter::Comparison(Name::from(name), operator, literal))
+}
+
+/// Parses the literal compared in the filter.
+fn parse_literal(query: &str, text: &str) -> Result<Value> {
+  match text {
+    "true" => Ok(Value::Boolean(true)),
+    "false" => Ok(Value::Boolean(false)),
+    "null" => Ok(Value::Null(None)),
+    _ => {
+      if let Some(string) = text.strip_prefix('"').and_then(|text| text.strip_suffix('"')) {
+        Ok(Value::String(string.to_string().into()))
+      } else {
+        FeelNumber::from_str(text)
+          .map(Value::Number)
+          .map_err(|_| err_invalid_query(query, &format!("invalid literal '{text}'")))
+      }
+    }
+  }
+}
+
+/// Selects the entry with specified name from a context, or from each context in a list.
+fn select_entry(value: &Value, name: &Name) -> Value {
+  match value {
+    Value::Context(ctx) => ctx.get_entry(name).cloned().unwrap_or(Value::Null(None)),
+    Value::List(items) => Value::List(items.iter().map(|item| select_entry(item, name)).collect()),
+    _ => Value::Null(None),
+  }
+}
+
+/// Applies the filter to a list, other values are filtered like single-element lists.
+fn apply_filter(value: &Value, filter: &Filter) -> Value {
+  let items = match value {
+    Value::List(items) => items.clone(),
+    other => vec![other.clone()],
+  };
+  match filter {
+    Filter::Index(index) => {
+      let position = if *index > 0 { *index - 1 } else { items.len() as i64 + *index };
+      usize::try_from(position)
+        .ok()
+        .and_then(|position| items.get(position).cloned())
+        .unwrap_or(Value::Null(None))
+    }
+    Filter::Comparison(name, operator, literal) => Value::List(
+      items
+        .into_iter()
+        .filter(|item| match item {
+          Value::Context(ctx) => ctx.get_entry(name).is_some_and(|entry| satisfies(entry, operator, literal)),
+          _ => false,
+        })
+        .collect(),
+    ),
+  }
+}
+
+/// Returns `true` when the comparison of the value with the literal is satisfied.
+fn satisfies(value: &Value, operator: &str, literal: &Value) -> bool {
+  let ordering = match (value, literal) {
+    (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+    (Value::String(a), Value::String(b)) => Some(a.as_str().cmp(b.as_str())),
+    (a, b) => (a == b).then_some(Ordering::Equal),
+  };
+  match operator {
+    "=" => ordering == Some(Ordering::Equal),
+    "!=" => ordering != Some(Ordering::Equal),
+    "<" => ordering == Some(Ordering::Less),
+    "<=" => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+    ">" => ordering == Some(Ordering::Greater),
+    ">=" => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+    _ => false,
+  }
+}
@@ -5,6 +5,7 @@ use crate::values::Value;
 use crate::{Name, QualifiedName};
 use dmntk_common::Jsonify;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// This macro creates a default scope.
@@ -95,6 +96,11 @@ impl FeelScope {
     None
   }
 
+  /// Returns names of entries in all contexts, from the top to the bottom of the stack.
+  pub fn names(&self) -> Vec<Name> {
+    self.stack.borrow().iter().rev().flat_map(|context| context.iter().map(|(name, _)| name.clone())).collect()
+  }
+
   /// Searches for a value under so called `qualified` name build from
   /// multiple names passed as an argument.
   pub fn search(&self, names: &[Name]) -> Option<Value> {
@@ -130,3 +136,79 @@ impl FeelScope {
     }
   }
 }
+
+/// Layer of the scope built with [ScopeBuilder].
+///
+/// Layers are ordered from the outermost to the innermost, entries in inner layers
+/// shadow entries with the same names in outer layers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScopeLayer {
+  /// Values shared by all models in the workspace.
+  Globals,
+  /// Variables of the evaluated model.
+  Model,
+  /// Input data of the evaluation request.
+  Inputs,
+}
+
+/// Builder of [FeelScope] layering workspace globals, model variables and request inputs.
+#[derive(Debug, Default, Clone)]
+pub struct ScopeBuilder {
+  /// Contexts of layers.
+  layers: BTreeMap<ScopeLayer, FeelContext>,
+}
+
+impl ScopeBuilder {
+  /// Creates a builder with no layers.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds entries of the context to specified layer, replacing entries with the same names.
+  pub fn layer(mut self, layer: ScopeLayer, ctx: &FeelContext) -> Self {
+    self.layers.entry(layer).or_default().zip(ctx);
+    self
+  }
+
+  /// Adds entries of the context to the layer of workspace globals.
+  pub fn globals(self, ctx: &FeelContext) -> Self {
+    self.layer(ScopeLayer::Globals, ctx)
+  }
+
+  /// Adds entries of the context to the layer of model variables.
+  pub fn model(self, ctx: &FeelContext) -> Self {
+    self.layer(ScopeLayer::Model, ctx)
+  }
+
+  /// Adds entries of the context to the layer of request inputs.
+  pub fn inputs(self, ctx: &FeelContext) -> Self {
+    self.layer(ScopeLayer::Inputs, ctx)
+  }
+
+  /// Returns the context of specified layer, if present.
+  pub fn get(&self, layer: ScopeLayer) -> Option<&FeelContext> {
+    self.layers.get(&layer)
+  }
+
+  /// Builds the scope with one context for each present layer, the innermost layer on the top of the stack.
+  pub fn build(&self) -> FeelScope {
+    if self.layers.is_empty() {
+      return FeelScope::default();
+    }
+    let scope = FeelScope::new();
+    for ctx in self.layers.values() {
+      scope.push(ctx.clone());
+    }
+    scope
+  }
+
+  /// Returns a single context containing entries of all layers,
+  /// entries of inner layers replace entries with the same names of outer layers.
+  pub fn flatten(&self) -> FeelContext {
+    let mut flattened = FeelContext::default();
+    for ctx in self.layers.values() {
+      flattened.zip(ctx);
+    }
+    flattened
+  }
+}
@@ -1,7 +1,170 @@
 //! Trait for converting FEEL artifact into FEEL string.
+//!
+//! This module defines also the [FeelString], a cheaply clonable
+//! representation of the FEEL string value and the [StringInterner],
+//! that allows sharing the content of identical FEEL strings.
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
 
 /// Trait for converting FEEL artifact into FEEL string.
 pub trait ToFeelString {
   /// Converts FEEL artifact into FEEL string.
   fn to_feel_string(&self) -> String;
 }
+
+/// Immutable FEEL string value.
+///
+/// The content of the string is reference counted, so cloning a [FeelString]
+/// does not copy the characters, all clones share the same content.
+///
+/// # Examples
+///
+/// ```
+/// use dmntk_feel::FeelString;
+///
+/// let a = FeelString::from("High");
+/// let b = a.clone();
+/// assert_eq!("High", b.as_str());
+/// assert!(FeelString::ptr_eq(&a, &b));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FeelString(Arc<str>);
+
+impl FeelString {
+  /// Returns the content of this string as a string slice.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  /// Returns `true` when both strings share the same content.
+  pub fn ptr_eq(a: &FeelString, b: &FeelString) -> bool {
+    Arc::ptr_eq(&a.0, &b.0)
+  }
+}
+
+impl Deref for FeelString {
+  type Target = str;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl AsRef<str> for FeelString {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Borrow<str> for FeelString {
+  fn borrow(&self) -> &str {
+    &self.0
+  }
+}
+
+impl fmt::Display for FeelString {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl fmt::Debug for FeelString {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(&self.0, f)
+  }
+}
+
+impl From<String> for FeelString {
+  fn from(value: String) -> Self {
+    Self(value.into())
+  }
+}
+
+impl From<&str> for FeelString {
+  fn from(value: &str) -> Self {
+    Self(value.into())
+  }
+}
+
+impl From<&String> for FeelString {
+  fn from(value: &String) -> Self {
+    Self(value.as_str().into())
+  }
+}
+
+impl FromIterator<char> for FeelString {
+  fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+    Self(iter.into_iter().collect::<String>().into())
+  }
+}
+
+impl From<FeelString> for String {
+  fn from(value: FeelString) -> Self {
+    value.0.to_string()
+  }
+}
+
+impl PartialEq<str> for FeelString {
+  fn eq(&self, other: &str) -> bool {
+    self.as_str() == other
+  }
+}
+
+impl PartialEq<&str> for FeelString {
+  fn eq(&self, other: &&str) -> bool {
+    self.as_str() == *other
+  }
+}
+
+impl PartialEq<String> for FeelString {
+  fn eq(&self, other: &String) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+/// Interner of FEEL strings.
+///
+/// Interning the strings having the same content returns
+/// [FeelString] values sharing a single copy of the content.
+///
+/// # Examples
+///
+/// ```
+/// use dmntk_feel::{FeelString, StringInterner};
+///
+/// let mut interner = StringInterner::default();
+/// let a = interner.intern("High");
+/// let b = interner.intern("High");
+/// assert!(FeelString::ptr_eq(&a, &b));
+/// assert_eq!(1, interner.len());
+/// ```
+#[derive(Debug, Default)]
+pub struct StringInterner {
+  strings: HashSet<FeelString>,
+}
+
+impl StringInterner {
+  /// Returns the interned string with specified content.
+  pub fn intern(&mut self, s: &str) -> FeelString {
+    if let Some(interned) = self.strings.get(s) {
+      return interned.clone();
+    }
+    let interned = FeelString::from(s);
+    self.strings.insert(interned.clone());
+    interned
+  }
+
+  /// Returns the number of interned strings.
+  pub fn len(&self) -> usize {
+    self.strings.len()
+  }
+
+  /// Returns `true` when no strings were interned.
+  pub fn is_empty(&self) -> bool {
+    self.strings.is_empty()
+  }
+}
@@ -1,4 +1,4 @@
-use crate::bif::{is_built_in_date_time_function_name, is_built_in_function_name, Bif};
+use crate::bif::{is_built_in_date_time_function_name, is_built_in_function_name, Bif, BIF_NAMES};
 use std::str::FromStr;
 
 #[test]
@@ -33,6 +33,7 @@ fn test_built_in_function_from_string() {
   assert_eq!(Bif::Duration, Bif::from_str("duration").unwrap());
   assert_eq!(Bif::During, Bif::from_str("during").unwrap());
   assert_eq!(Bif::EndsWith, Bif::from_str("ends with").unwrap());
+  assert_eq!(Bif::Env, Bif::from_str("env").unwrap());
   assert_eq!(Bif::Even, Bif::from_str("even").unwrap());
   assert_eq!(Bif::Exp, Bif::from_str("exp").unwrap());
   assert_eq!(Bif::FinishedBy, Bif::from_str("finished by").unwrap());
@@ -88,6 +89,21 @@ fn test_built_in_function_from_string() {
   assert_eq!(Bif::YearsAndMonthsDuration, Bif::from_str("years and months duration").unwrap());
 }
 
+#[test]
+fn test_built_in_function_names() {
+  // every listed name is a name of a distinct built-in function
+  let mut discriminants = BIF_NAMES
+    .iter()
+    .map(|name| Bif::from_str(name).unwrap_or_else(|_| panic!("'{name}' is not a name of built-in function")) as usize)
+    .collect::<Vec<usize>>();
+  discriminants.sort_unstable();
+  discriminants.dedup();
+  assert_eq!(BIF_NAMES.len(), discriminants.len());
+  // every built-in function is listed, variants are numbered consecutively and the last one is listed
+  assert_eq!(discriminants.len(), Bif::YearsAndMonthsDuration as usize + 1);
+  assert_eq!(Some(&(Bif::YearsAndMonthsDuration as usize)), discriminants.last());
+}
+
 #[test]
 fn test_built_in_function_from_invalid_string() {
   assert_eq!("<BifError> unknown built-in function name: powering", Bif::from_str("powering").err().unwrap().to_string());
@@ -115,6 +131,7 @@ fn test_is_built_in_function_name() {
   assert!(is_built_in_function_name("duration"));
   assert!(is_built_in_function_name("during"));
   assert!(is_built_in_function_name("ends with"));
+  assert!(is_built_in_function_name("env"));
   assert!(is_built_in_function_name("even"));
   assert!(is_built_in_function_name("exp"));
   assert!(is_built_in_function_name("finished by"));
@@ -0,0 +1,114 @@
+use crate::cbor::{context_from_cbor, value_from_cbor, value_to_cbor, MAX_NESTING_DEPTH};
+use crate::json::value_from_json;
+use crate::values::Value;
+use crate::FeelNumber;
+use std::str::FromStr;
+
+/// Encodes the number and returns the encoded bytes.
+fn number_to_cbor(text: &str) -> Vec<u8> {
+  value_to_cbor(&Value::Number(FeelNumber::from_str(text).unwrap()))
+}
+
+/// Encodes the number, decodes it back, and returns its text.
+fn number_round_trip(text: &str) -> String {
+  value_from_cbor(&number_to_cbor(text)).unwrap().to_string()
+}
+
+#[test]
+fn test_simple_values() {
+  assert_eq!(vec![0xF6], value_to_cbor(&Value::Null(None)));
+  assert_eq!(vec![0xF5], value_to_cbor(&Value::Boolean(true)));
+  assert_eq!(vec![0x65, b'A', b'l', b'i', b'c', b'e'], value_to_cbor(&Value::String("Alice".into())));
+  assert_eq!(vec![0x0A], number_to_cbor("10"));
+  assert_eq!(vec![0x38, 0x63], number_to_cbor("-100"));
+  assert_eq!(vec![0x19, 0x03, 0xE8], number_to_cbor("1000"));
+  // 273.15 is the decimal fraction [-2, 27315]
+  assert_eq!(vec![0xC4, 0x82, 0x21, 0x19, 0x6A, 0xB3], number_to_cbor("273.15"));
+}
+
+#[test]
+fn test_numbers() {
+  assert_eq!("0", number_round_trip("0"));
+  assert_eq!("-1", number_round_trip("-1"));
+  assert_eq!("12.25", number_round_trip("12.25"));
+  assert_eq!("-0.0001", number_round_trip("-0.0001"));
+  assert_eq!("9223372036854775807", number_round_trip("9223372036854775807"));
+  assert_eq!("-9223372036854775808", number_round_trip("-9223372036854775808"));
+  assert_eq!("123456789012345678901234567890.1234", number_round_trip("123456789012345678901234567890.1234"));
+  assert_eq!("-123456789012345678901234567890.1234", number_round_trip("-123456789012345678901234567890.1234"));
+}
+
+#[test]
+fn test_nested_values() {
+  let value = value_from_json(r#"{"Applicant": {"Name": "Alice", "Age": 35, "Scores": [1, 2.5, null]}, "Routing Decision": false}"#).unwrap();
+  let context = context_from_cbor(&value_to_cbor(&value)).unwrap();
+  assert_eq!(
+    r#"{Applicant: {Age: 35, Name: "Alice", Scores: [1, 2.5, null]}, Routing Decision: false}"#,
+    context.to_string()
+  );
+}
+
+#[test]
+fn test_decoding() {
+  // floating-point numbers in half, single and double precision
+  assert_eq!("1.5", value_from_cbor(&[0xF9, 0x3E, 0x00]).unwrap().to_string());
+  assert_eq!("100000", value_from_cbor(&[0xFA, 0x47, 0xC3, 0x50, 0x00]).unwrap().to_string());
+  assert_eq!("-4.1", value_from_cbor(&[0xFB, 0xC0, 0x10, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66]).unwrap().to_string());
+  // bignum 18446744073709551616 and negative integer -18446744073709551616
+  assert_eq!("18446744073709551616", value_from_cbor(&[0xC2, 0x49, 0x01, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap().to_string());
+  assert_eq!(
+    "-18446744073709551616",
+    value_from_cbor(&[0x3B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap().to_string()
+  );
+  // indefinite length map with indefinite length text and array, undefined value, tagged date and time string
+  let bytes = [
+    0xBF, 0x61, b'a', 0x7F, 0x62, b'h', b'e', 0x63, b'l', b'l', b'o', 0xFF, 0x61, b'b', 0x9F, 0x01, 0xF7, 0xFF, 0x61, b'c', 0xC0, 0x61, b'x', 0xFF,
+  ];
+  assert_eq!(r#"{a: "hello", b: [1, null], c: "x"}"#, value_from_cbor(&bytes).unwrap().to_string());
+}
+
+#[test]
+fn test_errors() {
+  assert_eq!(
+    "<CborError> invalid CBOR document: unexpected end of the document",
+    value_from_cbor(&[0x82, 0x01]).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<CborError> invalid CBOR document: unexpected data after the end of the document",
+    value_from_cbor(&[0x01, 0x02]).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<CborError> invalid CBOR document: map keys must be text strings",
+    value_from_cbor(&[0xA1, 0x01, 0x02]).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<CborError> invalid CBOR document: byte strings are not supported",
+    value_from_cbor(&[0x41, 0x00]).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<CborError> invalid CBOR document: infinite numbers and NaN are not supported",
+    value_from_cbor(&[0xF9, 0x7C, 0x00]).unwrap_err().to_string()
+  );
+  assert_eq!("<CborError> expected CBOR map", context_from_cbor(&[0x80]).unwrap_err().to_string());
+}
+
+#[test]
+fn test_nesting_depth() {
+  let mut bytes = vec![0x81; MAX_NESTING_DEPTH - 1];
+  bytes.push(0x01);
+  assert_eq!(MAX_NESTING_DEPTH - 1, value_from_cbor(&bytes).unwrap().to_string().matches('[').count());
+  let mut bytes = vec![0x81; MAX_NESTING_DEPTH];
+  bytes.push(0x01);
+  assert_eq!(
+    "<CborError> invalid CBOR document: nesting depth exceeds the maximum of 128",
+    value_from_cbor(&bytes).unwrap_err().to_string()
+  );
+  let mut bytes = vec![0xC6; 100_000];
+  bytes.push(0x01);
+  assert_eq!(
+    "<CborError> invalid CBOR document: nesting depth exceeds the maximum of 128",
+    value_from_cbor(&bytes).unwrap_err().to_string()
+  );
+  let bytes = [0xA1, 0x61, b'a'].repeat(100_000);
+  assert!(value_from_cbor(&bytes).is_err());
+}
@@ -681,3 +681,22 @@ fn test_invalid_value_type() {
   let output = serde_json::to_string_pretty(&value_dto).unwrap();
   eq(expected, output);
 }
+
+#[test]
+fn test_simple_decimal_negative_zero() {
+  let expected = r#"
+    {
+      "simple": {
+        "type": "xsd:decimal",
+        "text": "0",
+        "isNil": false
+      },
+      "components": null,
+      "list": null
+    }
+  "#;
+  let value = Value::Number(-dmntk_feel_number::FeelNumber::new(0, 1));
+  let output_dto = ValueDto::try_from(&value).unwrap();
+  let output = serde_json::to_string_pretty(&output_dto).unwrap();
+  eq(expected, output);
+}
@@ -0,0 +1,95 @@
+use crate::json::{coerce_json_value, context_from_json, value_from_json};
+use crate::{FeelType, Name};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_simple_values() {
+  assert_eq!("null", value_from_json("null").unwrap().to_string());
+  assert_eq!("true", value_from_json("true").unwrap().to_string());
+  assert_eq!("12.25", value_from_json("12.25").unwrap().to_string());
+  assert_eq!(r#""Alice""#, value_from_json(r#""Alice""#).unwrap().to_string());
+}
+
+#[test]
+fn test_nested_values() {
+  let context = context_from_json(r#"{"Applicant": {"Name": "Alice", "Age": 35, "Scores": [1, 2.5, null]}, "Routing Decision": false}"#).unwrap();
+  assert_eq!(
+    r#"{Applicant: {Age: 35, Name: "Alice", Scores: [1, 2.5, null]}, Routing Decision: false}"#,
+    context.to_string()
+  );
+}
+
+#[test]
+fn test_errors() {
+  assert_eq!("<JsonError> expected JSON object", context_from_json("[1, 2]").unwrap_err().to_string());
+  assert_eq!(
+    "<JsonError> invalid JSON: EOF while parsing a value at line 1 column 5",
+    context_from_json(r#"{"a":"#).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn test_coerce_scalars() {
+  let coerce = |json: &str, target_type: &FeelType| {
+    let (value, coercions) = coerce_json_value(&value_from_json(json).unwrap(), target_type, "v");
+    (value.to_string(), coercions.iter().map(|coercion| coercion.to_string()).collect::<Vec<String>>())
+  };
+  assert_eq!(
+    ("2023-04-05".to_string(), vec!["v: string coerced to date".to_string()]),
+    coerce(r#""2023-04-05""#, &FeelType::Date)
+  );
+  assert_eq!(
+    ("10:11:12".to_string(), vec!["v: string coerced to time".to_string()]),
+    coerce(r#""10:11:12""#, &FeelType::Time)
+  );
+  assert_eq!(
+    ("2023-04-05T10:11:12".to_string(), vec!["v: string coerced to date and time".to_string()]),
+    coerce(r#""2023-04-05T10:11:12""#, &FeelType::DateTime)
+  );
+  assert_eq!(
+    ("P1DT2H".to_string(), vec!["v: string coerced to days and time duration".to_string()]),
+    coerce(r#""P1DT2H""#, &FeelType::DaysAndTimeDuration)
+  );
+  assert_eq!(
+    ("P1Y2M".to_string(), vec!["v: string coerced to years and months duration".to_string()]),
+    coerce(r#""P1Y2M""#, &FeelType::YearsAndMonthsDuration)
+  );
+  assert_eq!(
+    ("12.5".to_string(), vec!["v: string coerced to number".to_string()]),
+    coerce(r#""12.5""#, &FeelType::Number)
+  );
+  assert_eq!(
+    ("true".to_string(), vec!["v: string coerced to boolean".to_string()]),
+    coerce(r#""true""#, &FeelType::Boolean)
+  );
+  assert_eq!((r#""42""#.to_string(), vec!["v: number coerced to string".to_string()]), coerce("42", &FeelType::String));
+  // values already conformant and values that can not be coerced are not changed
+  assert_eq!(("42".to_string(), vec![]), coerce("42", &FeelType::Number));
+  assert_eq!((r#""yesterday""#.to_string(), vec![]), coerce(r#""yesterday""#, &FeelType::Date));
+  assert_eq!(("null".to_string(), vec![]), coerce("null", &FeelType::Date));
+}
+
+#[test]
+fn test_coerce_nested() {
+  let target_type = FeelType::Context(BTreeMap::from([
+    (Name::from("Born"), FeelType::Date),
+    (Name::from("Visits"), FeelType::List(Box::new(FeelType::DateTime))),
+  ]));
+  let value = value_from_json(r#"{"Name": "Alice", "Born": "1990-01-02", "Visits": ["2023-01-01T10:00:00", "2023-02-01T11:00:00"]}"#).unwrap();
+  let (value, coercions) = coerce_json_value(&value, &target_type, "Applicant");
+  assert_eq!(
+    r#"{Born: 1990-01-02, Name: "Alice", Visits: [2023-01-01T10:00:00, 2023-02-01T11:00:00]}"#,
+    value.to_string()
+  );
+  assert_eq!(
+    vec![
+      "Applicant.Born: string coerced to date",
+      "Applicant.Visits[1]: string coerced to date and time",
+      "Applicant.Visits[2]: string coerced to date and time"
+    ],
+    coercions.iter().map(|coercion| coercion.to_string()).collect::<Vec<String>>()
+  );
+  assert_eq!("Applicant.Born", coercions[0].path());
+  assert_eq!(&FeelType::String, coercions[0].from_type());
+  assert_eq!(&FeelType::Date, coercions[0].to_type());
+}
@@ -0,0 +1,132 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use crate::values::Value;
+use dmntk_common::{DmntkError, Language};
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_invalid_feel_type_name("numbr"),
+    "<TypesError> niepoprawna nazwa typu FEEL: numbr",
+    "<TypesError> ungültiger FEEL-Typname: numbr",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_invalid_value_for_retrieving_using_feel_type("number", "abc"),
+    "<TypesError> niepoprawna wartość do pobrania ze sprawdzeniem typu, typ = 'number', wartość = 'abc'",
+    "<TypesError> ungültiger Wert für den Abruf mit Typprüfung, Typ = 'number', Wert = 'abc'",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_invalid_xsd_integer("1.5"),
+    "<ValueError> '1.5' nie jest poprawną reprezentacją xsd:integer",
+    "<ValueError> '1.5' ist keine gültige Darstellung von xsd:integer",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_invalid_xsd_duration("P1X"),
+    "<ValueError> 'P1X' nie jest poprawną reprezentacją xsd:duration",
+    "<ValueError> 'P1X' ist keine gültige Darstellung von xsd:duration",
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_translated(
+    err_value_is_not_a_context(&Value::Boolean(true)),
+    "<ContextError> 'true' nie jest wartością zawierającą kontekst",
+    "<ContextError> 'true' ist kein Wert, der einen Kontext enthält",
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_translated(
+    err_unknown_function_name("summ"),
+    "<BifError> nieznana nazwa funkcji wbudowanej: summ",
+    "<BifError> unbekannter Name einer eingebauten Funktion: summ",
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_translated(err_invalid_attribute("nil"), "<DtoError> niepoprawny atrybut: nil", "<DtoError> ungültiges Attribut: nil");
+}
+
+#[test]
+fn _0008() {
+  assert_translated(err_missing_attribute("value"), "<DtoError> brak atrybutu: value", "<DtoError> fehlendes Attribut: value");
+}
+
+#[test]
+fn _0009() {
+  assert_translated(
+    err_invalid_json("EOF while parsing"),
+    "<JsonError> niepoprawny JSON: EOF while parsing",
+    "<JsonError> ungültiges JSON: EOF while parsing",
+  );
+}
+
+#[test]
+fn _0010() {
+  assert_translated(err_json_object_expected(), "<JsonError> oczekiwano obiektu JSON", "<JsonError> JSON-Objekt erwartet");
+}
+
+#[test]
+fn _0011() {
+  assert_translated(
+    err_invalid_cbor("unexpected end"),
+    "<CborError> niepoprawny dokument CBOR: unexpected end",
+    "<CborError> ungültiges CBOR-Dokument: unexpected end",
+  );
+}
+
+#[test]
+fn _0012() {
+  assert_translated(err_cbor_map_expected(), "<CborError> oczekiwano mapy CBOR", "<CborError> CBOR-Map erwartet");
+}
+
+#[test]
+fn _0013() {
+  assert_translated(
+    err_invalid_query("a..b", "empty step"),
+    "<QueryError> niepoprawne zapytanie 'a..b': empty step",
+    "<QueryError> ungültige Abfrage 'a..b': empty step",
+  );
+}
+
+#[test]
+fn _0014() {
+  assert_translated(
+    err_strict_evaluation_failed(&["a".to_string(), "b".to_string()]),
+    "<EvaluationModeError> ścisła ewaluacja nie powiodła się: a; b",
+    "<EvaluationModeError> strikte Auswertung fehlgeschlagen: a; b",
+  );
+}
+
+#[test]
+fn _0015() {
+  assert_translated(
+    err_invalid_conformance_level("4"),
+    "<ConformanceLevelError> '4' nie jest poprawnym poziomem zgodności, dozwolone wartości to: '1', '2', '3', 'CL1', 'CL2', 'CL3'",
+    "<ConformanceLevelError> '4' ist keine gültige Konformitätsstufe, zulässige Werte sind: '1', '2', '3', 'CL1', 'CL2', 'CL3'",
+  );
+}
@@ -1,9 +1,14 @@
 mod bif;
+mod cbor;
 mod context;
 mod dto;
 mod function;
+mod json;
+mod messages;
 mod names;
+mod pretty;
 mod qualified_names;
+mod query;
 mod scope;
 mod types;
 mod values;
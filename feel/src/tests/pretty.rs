@@ -0,0 +1,68 @@
+use crate::json::value_from_json;
+use crate::PrettyPrinter;
+use dmntk_common::ColorMode;
+
+const NESTED: &str = r#"{"Applicant": {"Name": "Alice", "Scores": [1, 2, 3, 4]}, "Offers": [{"Rate": 0.5}, {"Rate": 1.5}, {"Rate": 2.5}], "Empty": []}"#;
+
+#[test]
+fn test_default() {
+  let value = value_from_json(NESTED).unwrap();
+  let expected = r#"{
+  Applicant: {
+    Name: "Alice",
+    Scores: [1, 2, 3, 4]
+  },
+  Empty: [],
+  Offers: [
+    {
+      Rate: 0.5
+    },
+    {
+      Rate: 1.5
+    },
+    {
+      Rate: 2.5
+    }
+  ]
+}"#;
+  assert_eq!(expected, PrettyPrinter::default().print(&value));
+}
+
+#[test]
+fn test_limits() {
+  let value = value_from_json(NESTED).unwrap();
+  let expected = r#"{
+    Applicant: {
+        Name: "Alice",
+        Scores: […]
+    },
+    Empty: [],
+    Offers: [
+        {…},
+        {…},
+        … 1 more
+    ]
+}"#;
+  assert_eq!(expected, PrettyPrinter::default().with_indent(4).with_max_depth(2).with_max_list_items(2).print(&value));
+}
+
+#[test]
+fn test_colors() {
+  let value = value_from_json(r#"{"a": [true, "x"]}"#).unwrap();
+  assert_eq!(
+    "{\n  \u{1b}[34ma\u{1b}[0m: [\u{1b}[35mtrue\u{1b}[0m, \u{1b}[32m\"x\"\u{1b}[0m]\n}",
+    PrettyPrinter::default().with_color_mode(ColorMode::On).print(&value)
+  );
+}
+
+#[test]
+fn test_list_preview() {
+  let value = value_from_json("[1, 2, 3, 4, 5]").unwrap();
+  assert_eq!("[1, 2, … 3 more]", PrettyPrinter::default().with_max_list_items(2).print(&value));
+}
+
+#[test]
+fn test_scalar() {
+  let value = value_from_json("12.5").unwrap();
+  assert_eq!("12.5", PrettyPrinter::default().print(&value));
+}
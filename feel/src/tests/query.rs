@@ -0,0 +1,64 @@
+use crate::json::value_from_json;
+use crate::values::Value;
+
+const CUSTOMER: &str = r#"{
+  "customer": {
+    "name": "Alice",
+    "orders": [
+      {"id": "A1", "amount": 50, "status": "open"},
+      {"id": "A2", "amount": 150, "status": "closed"},
+      {"id": "A3", "amount": 250, "status": "open"}
+    ]
+  }
+}"#;
+
+fn select(query: &str) -> String {
+  value_from_json(CUSTOMER).unwrap().select(query).unwrap().to_string()
+}
+
+#[test]
+fn test_entries() {
+  assert_eq!(r#""Alice""#, select("customer.name"));
+  assert_eq!(r#"["A1", "A2", "A3"]"#, select("customer.orders.id"));
+  assert_eq!("null", select("customer.address"));
+  assert_eq!("null", select("customer.name.first"));
+}
+
+#[test]
+fn test_comparison_filters() {
+  assert_eq!(r#"["A2", "A3"]"#, select("customer.orders[amount > 100].id"));
+  assert_eq!(r#"["A1", "A2"]"#, select("customer.orders[amount <= 150].id"));
+  assert_eq!(r#"["A1", "A3"]"#, select(r#"customer.orders[status = "open"].id"#));
+  assert_eq!(r#"["A2"]"#, select(r#"customer.orders[status != "open"].id"#));
+  assert_eq!(r#"["A3"]"#, select(r#"customer.orders[status = "open"][amount >= 200].id"#));
+  assert_eq!("[]", select("customer.orders[amount > 1000].id"));
+}
+
+#[test]
+fn test_index_filters() {
+  assert_eq!(r#""A1""#, select("customer.orders[1].id"));
+  assert_eq!(r#""A3""#, select("customer.orders[-1].id"));
+  assert_eq!(r#""A3""#, select("customer.orders[amount > 100][2].id"));
+  assert_eq!("null", select("customer.orders[4]"));
+}
+
+#[test]
+fn test_invalid_queries() {
+  let value = Value::Null(None);
+  assert_eq!("<QueryError> invalid query '': empty query", value.select("").unwrap_err().to_string());
+  assert_eq!("<QueryError> invalid query 'a..b': empty segment", value.select("a..b").unwrap_err().to_string());
+  assert_eq!("<QueryError> invalid query 'a[1': missing closing bracket", value.select("a[1").unwrap_err().to_string());
+  assert_eq!(
+    "<QueryError> invalid query 'a[0]': list elements are numbered from 1",
+    value.select("a[0]").unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<QueryError> invalid query 'a[b ~ 1]': invalid filter 'b ~ 1'",
+    value.select("a[b ~ 1]").unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<QueryError> invalid query 'a[b > x]': invalid literal 'x'",
+    value.select("a[b > x]").unwrap_err().to_string()
+  );
+  assert_eq!("<QueryError> invalid query 'a[1]b': unexpected text 'b'", value.select("a[1]b").unwrap_err().to_string());
+}
@@ -1,6 +1,6 @@
 use crate::context::FeelContext;
 use crate::values::Value;
-use crate::{scope, value_number, FeelNumber, FeelScope, Name};
+use crate::{scope, value_number, FeelNumber, FeelScope, Name, ScopeBuilder, ScopeLayer};
 use dmntk_common::Jsonify;
 
 #[test]
@@ -184,3 +184,42 @@ fn test_insert_null() {
   scope.set_name(name_d);
   assert_eq!("[{a: 1}, {b: 2}, {b: null, c: null, d: null}]", scope.to_string());
 }
+
+#[test]
+fn test_scope_builder_empty() {
+  let builder = ScopeBuilder::new();
+  assert_eq!("[{}]", builder.build().to_string());
+  assert_eq!("{}", builder.flatten().to_string());
+  assert!(builder.get(ScopeLayer::Globals).is_none());
+}
+
+#[test]
+fn test_scope_builder_layers() {
+  let mut globals = FeelContext::default();
+  globals.set_entry(&Name::from("a"), value_number!(1));
+  globals.set_entry(&Name::from("b"), value_number!(2));
+  let mut model = FeelContext::default();
+  model.set_entry(&Name::from("b"), value_number!(3));
+  model.set_entry(&Name::from("c"), value_number!(4));
+  let mut inputs = FeelContext::default();
+  inputs.set_entry(&Name::from("c"), value_number!(5));
+  let builder = ScopeBuilder::new().inputs(&inputs).globals(&globals).model(&model);
+  assert_eq!("[{a: 1, b: 2}, {b: 3, c: 4}, {c: 5}]", builder.build().to_string());
+  assert_eq!("{a: 1, b: 3, c: 5}", builder.flatten().to_string());
+  assert_eq!("{b: 3, c: 4}", builder.get(ScopeLayer::Model).unwrap().to_string());
+  let scope = builder.build();
+  assert_eq!("1", scope.get_value(&Name::from("a")).unwrap().to_string());
+  assert_eq!("3", scope.get_value(&Name::from("b")).unwrap().to_string());
+  assert_eq!("5", scope.get_value(&Name::from("c")).unwrap().to_string());
+}
+
+#[test]
+fn test_scope_builder_same_layer() {
+  let mut first = FeelContext::default();
+  first.set_entry(&Name::from("a"), value_number!(1));
+  let mut second = FeelContext::default();
+  second.set_entry(&Name::from("a"), value_number!(2));
+  second.set_entry(&Name::from("b"), value_number!(3));
+  let builder = ScopeBuilder::new().layer(ScopeLayer::Inputs, &first).layer(ScopeLayer::Inputs, &second);
+  assert_eq!("[{a: 2, b: 3}]", builder.build().to_string());
+}
@@ -47,7 +47,7 @@ fn test_from_name() {
 fn test_get_value_checked() {
   let v_null = Value::Null(None);
   let v_boolean = Value::Boolean(true);
-  let v_string = Value::String("hello".to_owned());
+  let v_string = Value::String("hello".into());
   assert_eq!("null", T_ANY.get_value_checked(&v_null).unwrap().to_string());
   assert_eq!("true", T_ANY.get_value_checked(&v_boolean).unwrap().to_string());
   assert!(T_BOOLEAN.get_value_checked(&v_string).is_err());
@@ -283,6 +283,31 @@ fn test_type_conformance() {
   assert!(!T_YEARS_AND_MONTHS_DURATION.is_conformant(T_TIME));
 }
 
+#[test]
+fn test_type_instance_of() {
+  assert!(T_NUMBER.instance_of(T_NUMBER));
+  assert!(T_NUMBER.instance_of(T_ANY));
+  assert!(!T_NUMBER.instance_of(T_NULL));
+  assert!(T_NULL.instance_of(T_NULL));
+  assert!(!T_NULL.instance_of(T_ANY));
+  assert!(!T_NULL.instance_of(T_NUMBER));
+  assert!(T_LIST_A.instance_of(&T_LIST_D));
+  assert!(!T_LIST_D.instance_of(&T_LIST_A));
+  assert!(FeelType::list(T_NULL).instance_of(&T_LIST_A));
+  assert!(FeelType::list(&T_LIST_A).instance_of(&FeelType::list(&T_LIST_D)));
+  assert!(!FeelType::list(&T_LIST_A).instance_of(&FeelType::list(&T_LIST_B)));
+  assert!(T_CONTEXT_A_B.instance_of(&T_CONTEXT_A));
+  assert!(!T_CONTEXT_A.instance_of(&T_CONTEXT_A_B));
+  assert!(!T_CONTEXT_A.instance_of(&T_CONTEXT_C));
+  assert!(T_RANGE_A.instance_of(&FeelType::range(T_ANY)));
+  assert!(!T_RANGE_A.instance_of(&T_RANGE_B));
+  assert!(T_FUNCTION_F.instance_of(&T_FUNCTION_G));
+  assert!(!T_FUNCTION_G.instance_of(&T_FUNCTION_F));
+  assert!(!T_FUNCTION_C.instance_of(&T_FUNCTION_G));
+  assert!(T_FUNCTION_E.instance_of(&T_FUNCTION_D));
+  assert!(!T_FUNCTION_D.instance_of(&T_FUNCTION_E));
+}
+
 #[test]
 fn test_is_built_in_type_name() {
   assert!(is_built_in_type_name("Any"));
@@ -385,7 +410,7 @@ fn test_type_get_conformant_value() {
   let v_number_1 = value_number!(1);
   let v_number_2 = value_number!(2);
   // string
-  let v_string = Value::String("alpha".to_string());
+  let v_string = Value::String("alpha".into());
   // time
   let v_time = Value::Time(FeelTime::local_opt(9, 2, 0, 0).unwrap());
   // years and months duration
@@ -100,7 +100,7 @@ fn test_debug() {
     r#"Range(Number(+1E+0), false, Number(+1E+0), true)"#,
     Value::Range(b_number.clone(), false, b_number.clone(), true)
   );
-  eq_dbg!(r#"String("beta")"#, Value::String("beta".to_string()));
+  eq_dbg!(r#"String("beta")"#, Value::String("beta".into()));
   eq_dbg!(r#"Time(FeelTime(12, 13, 23, 0, Local))"#, Value::Time(v_time));
   eq_dbg!(r#"UnaryGreater(Number(+1E+0))"#, Value::UnaryGreater(b_number.clone()));
   eq_dbg!(r#"UnaryGreaterOrEqual(Number(+1E+0))"#, Value::UnaryGreaterOrEqual(b_number.clone()));
@@ -169,7 +169,7 @@ fn test_display() {
   eq_dsp!(r#"PositionalParameters"#, Value::PositionalParameters(Values::default()));
   eq_dsp!(r#"QualifiedNameSegment"#, Value::QualifiedNameSegment(name));
   eq_dsp!(r#"(1..1]"#, Value::Range(b_number.clone(), false, b_number.clone(), true));
-  eq_dsp!(r#""beta""#, Value::String("beta".to_string()));
+  eq_dsp!(r#""beta""#, Value::String("beta".into()));
   eq_dsp!(r#"12:13:23"#, Value::Time(v_time));
   eq_dsp!(r#"UnaryGreater(1)"#, Value::UnaryGreater(b_number.clone()));
   eq_dsp!(r#"UnaryGreaterOrEqual(1)"#, Value::UnaryGreaterOrEqual(b_number.clone()));
@@ -241,7 +241,7 @@ fn test_type_of() {
   eq_typ!(FeelType::Any, Value::QualifiedNameSegment(name));
   eq_typ!(FeelType::Range(Box::new(FeelType::Number)), Value::Range(b_number.clone(), false, b_number.clone(), true));
   eq_typ!(FeelType::Range(Box::new(FeelType::Any)), Value::Range(b_number.clone(), false, b_boolean, true));
-  eq_typ!(FeelType::String, Value::String("beta".to_string()));
+  eq_typ!(FeelType::String, Value::String("beta".into()));
   eq_typ!(FeelType::Time, Value::Time(v_time));
   eq_typ!(FeelType::Boolean, Value::UnaryGreater(b_number.clone()));
   eq_typ!(FeelType::Boolean, Value::UnaryGreaterOrEqual(b_number.clone()));
@@ -253,11 +253,11 @@ fn test_type_of() {
 #[test]
 fn test_type_of_list_with_context() {
   let mut ctx_a = FeelContext::default();
-  ctx_a.set_entry(&"a".into(), Value::String("alfa".to_string()));
-  ctx_a.set_entry(&"b".into(), Value::String("beta".to_string()));
+  ctx_a.set_entry(&"a".into(), Value::String("alfa".into()));
+  ctx_a.set_entry(&"b".into(), Value::String("beta".into()));
   let mut ctx_b = FeelContext::default();
-  ctx_b.set_entry(&"a".into(), Value::String("gamma".to_string()));
-  ctx_b.set_entry(&"b".into(), Value::String("gamma".to_string()));
+  ctx_b.set_entry(&"a".into(), Value::String("gamma".into()));
+  ctx_b.set_entry(&"b".into(), Value::String("gamma".into()));
   let list = Value::List(vec![Value::Context(ctx_a), Value::Context(ctx_b)]);
   assert_eq!("list<context<a: string, b: string>>", list.type_of().to_string());
 }
@@ -265,10 +265,10 @@ fn test_type_of_list_with_context() {
 #[test]
 fn test_type_of_list_with_context_and_nulls() {
   let mut ctx_a = FeelContext::default();
-  ctx_a.set_entry(&"a".into(), Value::String("alfa".to_string()));
-  ctx_a.set_entry(&"b".into(), Value::String("beta".to_string()));
+  ctx_a.set_entry(&"a".into(), Value::String("alfa".into()));
+  ctx_a.set_entry(&"b".into(), Value::String("beta".into()));
   let mut ctx_b = FeelContext::default();
-  ctx_b.set_entry(&"a".into(), Value::String("gamma".to_string()));
+  ctx_b.set_entry(&"a".into(), Value::String("gamma".into()));
   ctx_b.set_entry(&"b".into(), value_null!());
   let list = Value::List(vec![Value::Context(ctx_a), Value::Context(ctx_b)]);
   assert_eq!("list<context<a: string, b: string>>", list.type_of().to_string());
@@ -337,8 +337,8 @@ fn test_comparison() {
 
 #[test]
 fn test_value_to_feel_string() {
-  assert_eq!(r#""foo""#, Value::String("foo".to_string()).to_feel_string());
-  assert_eq!(r#""\"bar\"""#, Value::String("\"bar\"".to_string()).to_feel_string());
+  assert_eq!(r#""foo""#, Value::String("foo".into()).to_feel_string());
+  assert_eq!(r#""\"bar\"""#, Value::String("\"bar\"".into()).to_feel_string());
   assert_eq!(r#"{}"#, Value::Context(FeelContext::default()).to_feel_string());
   assert_eq!(r#"[]"#, Value::List(Values::default()).to_feel_string());
   assert_eq!(
@@ -353,7 +353,7 @@ fn test_jsonify() {
   assert_eq!(r#"true"#, Value::Boolean(true).jsonify());
   assert_eq!(r#"false"#, Value::Boolean(false).jsonify());
   assert_eq!(r#"1.23"#, Value::Number(FeelNumber::new(123, 2)).jsonify());
-  assert_eq!(r#""beta""#, Value::String("beta".to_string()).jsonify());
+  assert_eq!(r#""beta""#, Value::String("beta".into()).jsonify());
   assert_eq!(r#""2023-02-24""#, Value::Date(FeelDate::new(2023, 2, 24)).jsonify());
   assert_eq!(r#""18:35:12.000598677Z""#, Value::Time(FeelTime::utc(18, 35, 12, 598677)).jsonify());
   assert_eq!(
@@ -450,7 +450,7 @@ fn test_coerced() {
   let v_string = Value::String("a".into());
   let v_list_number_1 = Value::List(vec![value_number!(1, 0)]);
   let v_list_number_2 = Value::List(vec![value_number!(1, 0), value_number!(2, 0)]);
-  let v_list_string_1 = Value::List(vec![Value::String("A".to_string())]);
+  let v_list_string_1 = Value::List(vec![Value::String("A".into())]);
   let v_function_a = Value::FunctionDefinition(
     vec![(NAME_A.clone(), T_NUMBER.clone())],
     FunctionBody::LiteralExpression(Arc::new(Box::new(|_: &FeelScope| value_number!(1)))),
@@ -479,3 +479,36 @@ fn test_coerced() {
   assert_eq!(r#"null(after coercion)"#, v_irrelevant.coerced(T_NUMBER).to_string());
   assert_eq!(r#"null(after coercion)"#, v_context_d.coerced(&T_CONTEXT_A).to_string());
 }
+
+#[test]
+fn test_coerced_at_conformance_level_2() {
+  let v_number = value_number!(10);
+  let v_list_number_1 = Value::List(vec![value_number!(1, 0)]);
+  crate::evaluate_at_conformance_level(crate::ConformanceLevel::Level2, || {
+    assert_eq!(r#"null(after coercion)"#, v_number.coerced(&T_LIST_A).to_string());
+    assert_eq!(r#"null(after coercion)"#, v_list_number_1.coerced(T_NUMBER).to_string());
+    assert_eq!(r#"10"#, v_number.coerced(T_NUMBER).to_string());
+  });
+  assert_eq!(r#"1"#, v_list_number_1.coerced(T_NUMBER).to_string());
+}
+
+#[test]
+fn test_caused_by() {
+  let cause = value_null!("no such name: x");
+  assert_eq!(Some("no such name: x"), cause.null_reason());
+  assert_eq!(None, value_null!().null_reason());
+  assert_eq!(None, value_number!(1).null_reason());
+  let v = value_null!("invalid argument").caused_by(cause.null_reason());
+  assert_eq!("null(invalid argument; caused by: no such name: x)", v.to_string());
+  assert_eq!(vec!["invalid argument", "no such name: x"], v.null_causes());
+  let v = value_null!("invalid operand").caused_by(v.null_reason());
+  assert_eq!(vec!["invalid operand", "invalid argument", "no such name: x"], v.null_causes());
+  // null without reason takes the reason of the cause
+  assert_eq!("null(no such name: x)", value_null!().caused_by(cause.null_reason()).to_string());
+  // causes already present in the chain are not repeated
+  assert_eq!("null(no such name: x)", cause.clone().caused_by(cause.null_reason()).to_string());
+  // values other than null are not affected
+  assert_eq!("1", value_number!(1).caused_by(cause.null_reason()).to_string());
+  assert_eq!("null(invalid argument)", value_null!("invalid argument").caused_by(None).to_string());
+  assert!(value_number!(1).null_causes().is_empty());
+}
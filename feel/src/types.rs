@@ -340,11 +340,8 @@ impl FeelType {
               if !parameter_other.is_conformant(&parameters_self[i]) {
                 return false;
               }
-              if !result_self.is_conformant(result_other) {
-                return false;
-              }
             }
-            return true;
+            return result_self.is_conformant(result_other);
           }
         }
         return false;
@@ -354,31 +351,16 @@ impl FeelType {
   }
 
   /// Checks if this type is an instance of the other type.
+  ///
+  /// Types are compared structurally, like when checking the conformance of values
+  /// with item definitions, e.g. `list<number>` is an instance of `list<Any>`,
+  /// and a context with entries `a` and `b` is an instance of `context<a: Any>`.
+  /// Nested `null` types (like items of an empty list) are instances of any type,
+  /// while the `null` type itself is an instance of the `Null` type only.
   pub fn instance_of(&self, other: &FeelType) -> bool {
-    // list
-    if let FeelType::List(self_inner_type) = self {
-      if let FeelType::List(other_inner_type) = other {
-        return self_inner_type == other_inner_type || **other_inner_type == FeelType::Any;
-      }
-    }
-    // context
-    if let FeelType::Context(self_inner_type) = self {
-      if let FeelType::Context(other_inner_type) = other {
-        if self_inner_type == other_inner_type {
-          return true;
-        }
-        for (name, other_inner_feel_type) in other_inner_type {
-          if let Some(self_inner_feel_type) = self_inner_type.get(name) {
-            if !self_inner_feel_type.is_null() && self_inner_feel_type != other_inner_feel_type {
-              return false;
-            }
-          } else {
-            return false;
-          }
-        }
-        return true;
-      }
+    if self.is_null() {
+      return other.is_null();
     }
-    false
+    self.is_conformant(other)
   }
 }
@@ -2,10 +2,12 @@
 
 use crate::bif::Bif;
 use crate::closure::Closure;
+use crate::conformance::conformance_level;
 use crate::context::FeelContext;
 use crate::errors::*;
+use crate::mode::report_violation;
 use crate::names::Name;
-use crate::strings::ToFeelString;
+use crate::strings::{FeelString, ToFeelString};
 use crate::types::FeelType;
 use crate::FunctionBody;
 use dmntk_common::{Jsonify, Result};
@@ -61,10 +63,10 @@ macro_rules! value_number {
 #[macro_export]
 macro_rules! value_string {
   ($s:literal) => {{
-    Value::String($s.to_string())
+    Value::String($s.into())
   }};
   ($s:expr) => {{
-    Value::String($s)
+    Value::String($s.into())
   }};
 }
 
@@ -77,6 +79,9 @@ pub const VALUE_FALSE: Value = Value::Boolean(false);
 /// Constant indicating invalid coercion result.
 const INVALID_COERCION: &str = "after coercion";
 
+/// Separator of reasons in the cause chain of a null value.
+const CAUSE_SEPARATOR: &str = "; caused by: ";
+
 /// `FEEL` value.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
@@ -209,7 +214,7 @@ pub enum Value {
   Range(Box<Value>, bool, Box<Value>, bool),
 
   /// `String` value...
-  String(String),
+  String(FeelString),
 
   /// Value for storing time as [FeelTime].
   Time(FeelTime),
@@ -341,6 +346,41 @@ impl Value {
     matches!(self, Value::Number(_))
   }
 
+  /// Returns the tracing message of a null value, including the whole cause chain.
+  pub fn null_reason(&self) -> Option<&str> {
+    if let Value::Null(Some(message)) = self {
+      Some(message)
+    } else {
+      None
+    }
+  }
+
+  /// Returns the cause chain of a null value, starting from the reason
+  /// of this value and ending with the reason of the original null value.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use crate::dmntk_feel::{value_null, values::Value};
+  ///
+  /// let v = value_null!("[core::abs] invalid argument type").caused_by(Some("no such name: x"));
+  /// assert_eq!(vec!["[core::abs] invalid argument type", "no such name: x"], v.null_causes());
+  /// ```
+  pub fn null_causes(&self) -> Vec<&str> {
+    self.null_reason().map_or(vec![], |message| message.split(CAUSE_SEPARATOR).collect())
+  }
+
+  /// Appends the cause to the cause chain of a null value, other values are returned unchanged.
+  /// Null values without tracing message take the cause as their reason,
+  /// causes already present in the chain are not repeated.
+  pub fn caused_by(self, cause: Option<&str>) -> Value {
+    match (self, cause) {
+      (Value::Null(None), Some(cause)) => Value::Null(Some(cause.to_string())),
+      (Value::Null(Some(message)), Some(cause)) if !message.contains(cause) => Value::Null(Some(format!("{message}{CAUSE_SEPARATOR}{cause}"))),
+      (value, _) => value,
+    }
+  }
+
   /// Returns `true` when the value is of type [Value::Null] indicating invalid coercion.
   pub fn is_invalid_coercion(&self) -> bool {
     if let Value::Null(Some(message)) = self {
@@ -520,6 +560,7 @@ impl Value {
   ///      the value remains unchanged. Otherwise the result is null.
   ///
   /// All these conversion rules are implemented in this function.
+  /// Conversions to and from singleton lists are performed only at conformance level 3.
   ///
   pub fn coerced(&self, target_type: &FeelType) -> Value {
     if let Value::FunctionDefinition(_, _, _, _, _, _) = self {
@@ -533,25 +574,28 @@ impl Value {
     if self.is_conformant(target_type) {
       return self.clone();
     }
-    match self {
-      // from singleton list
-      Value::List(items) => {
-        if items.len() == 1 {
-          let value = items[0].clone();
-          if value.is_conformant(target_type) {
-            return value;
+    if conformance_level().allows_implicit_conversions() {
+      match self {
+        // from singleton list
+        Value::List(items) => {
+          if items.len() == 1 {
+            let value = items[0].clone();
+            if value.is_conformant(target_type) {
+              return value;
+            }
           }
         }
-      }
-      // to singleton list
-      value => {
-        if let FeelType::List(list_type) = target_type {
-          if value.is_conformant(list_type) {
-            return Value::List(vec![value.clone()]);
+        // to singleton list
+        value => {
+          if let FeelType::List(list_type) = target_type {
+            if value.is_conformant(list_type) {
+              return Value::List(vec![value.clone()]);
+            }
           }
         }
       }
     }
+    report_violation(|| format!("value of type {} can not be coerced to type {}", self.type_of(), target_type));
     value_null!(INVALID_COERCION)
   }
 
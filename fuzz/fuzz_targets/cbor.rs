@@ -0,0 +1,11 @@
+//! Fuzzing the decoder of CBOR documents.
+//!
+//! Run with: `cargo +nightly fuzz run cbor`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let _ = dmntk_feel::cbor::value_from_cbor(data);
+});
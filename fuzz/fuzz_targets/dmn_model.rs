@@ -0,0 +1,13 @@
+//! Fuzzing the parser of DMN models in XML format.
+//!
+//! Run with: `cargo +nightly fuzz run dmn_model`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(xml) = std::str::from_utf8(data) {
+    let _ = dmntk_model::parse(xml);
+  }
+});
@@ -0,0 +1,16 @@
+//! Fuzzing the parser of FEEL expressions.
+//!
+//! Run with: `cargo +nightly fuzz run feel_expression`
+
+#![no_main]
+
+use dmntk_feel::FeelScope;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(input) = std::str::from_utf8(data) {
+    let scope = FeelScope::default();
+    let _ = dmntk_feel_parser::parse_expression(&scope, input, false);
+    let _ = dmntk_feel_parser::parse_textual_expression(&scope, input, false);
+  }
+});
@@ -0,0 +1,14 @@
+//! Fuzzing the parser of FEEL unary tests.
+//!
+//! Run with: `cargo +nightly fuzz run feel_unary_tests`
+
+#![no_main]
+
+use dmntk_feel::FeelScope;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(input) = std::str::from_utf8(data) {
+    let _ = dmntk_feel_parser::parse_unary_tests(&FeelScope::default(), input, false);
+  }
+});
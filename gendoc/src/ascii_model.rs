@@ -18,6 +18,11 @@ const LABEL_ID: &str = "id";
 const LABEL_INPUT_DATA: &str = "Input data";
 const LABEL_KNOWLEDGE_SOURCES: &str = "Knowledge sources";
 const LABEL_LABEL: &str = "label";
+const LABEL_LOCATION_URI: &str = "location URI";
+const LABEL_OWNER: &str = "owner";
+const LABEL_REQUIRED_AUTHORITY: &str = "required authority";
+const LABEL_REQUIRED_DECISION: &str = "required decision";
+const LABEL_REQUIRED_INPUT: &str = "required input";
 const LABEL_IMPACTING_DECISIONS: &str = "impacting decisions";
 const LABEL_MODEL: &str = "Model";
 const LABEL_NAME: &str = "name";
@@ -218,6 +223,9 @@ fn write_knowledge_sources(w: &mut dyn Write, definitions: &Definitions, colors:
       .opt_child(build_label(knowledge_source.label(), colors))
       .opt_child(build_id(knowledge_source.opt_id(), colors))
       .opt_child(build_description(knowledge_source.description(), colors))
+      .opt_child(build_labeled_uri(LABEL_LOCATION_URI, knowledge_source.location_uri(), colors))
+      .opt_child(build_opt_labeled_text(LABEL_TYPE, knowledge_source.source_type(), colors.typ()))
+      .opt_child(build_labeled_href(LABEL_OWNER, knowledge_source.owner(), colors))
       .opt_child(build_extension_elements(knowledge_source.extension_elements(), colors))
       .opt_child(build_extension_attributes(knowledge_source.extension_attributes(), colors))
       .opt_child(build_authority_requirements(knowledge_source.authority_requirements(), colors))
@@ -349,6 +357,22 @@ fn build_href(href: &HRef, colors: &Colors) -> AsciiNode {
     .build()
 }
 
+/// Builds a leaf node containing a labeled reference.
+fn build_labeled_href(label: &str, opt_href: &Option<HRef>, colors: &Colors) -> Option<AsciiNode> {
+  opt_href.as_ref().map(|href| {
+    AsciiNode::leaf_builder()
+      .line(
+        AsciiLine::builder()
+          .text(label)
+          .colon_space()
+          .with_color("#", colors.href())
+          .with_color(href.id(), colors.href())
+          .build(),
+      )
+      .build()
+  })
+}
+
 /// Builds a leaf node containing an URI.
 fn build_uri(opt_text: &Option<String>, colors: &Colors) -> Option<AsciiNode> {
   build_opt_labeled_text(LABEL_URI, opt_text, colors.uri())
@@ -399,6 +423,9 @@ fn build_authority_requirements(authority_requirements: &Vec<AuthorityRequiremen
         .opt_child(build_id(authority_requirement.opt_id(), colors))
         .opt_child(build_label(authority_requirement.label(), colors))
         .opt_child(build_description(authority_requirement.description(), colors))
+        .opt_child(build_labeled_href(LABEL_REQUIRED_AUTHORITY, authority_requirement.required_authority(), colors))
+        .opt_child(build_labeled_href(LABEL_REQUIRED_DECISION, authority_requirement.required_decision(), colors))
+        .opt_child(build_labeled_href(LABEL_REQUIRED_INPUT, authority_requirement.required_input(), colors))
         .opt_child(build_extension_elements(authority_requirement.extension_elements(), colors))
         .opt_child(build_extension_attributes(authority_requirement.extension_attributes(), colors))
         .build();
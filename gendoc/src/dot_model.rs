@@ -0,0 +1,109 @@
+//! # Decision requirements graph in `DOT` format
+
+use dmntk_common::HRef;
+use dmntk_model::*;
+use std::fmt::Write;
+
+/// Style of the node representing a decision.
+const STYLE_DECISION: &str = "shape=box";
+/// Style of the node representing an input data.
+const STYLE_INPUT_DATA: &str = "shape=box,style=rounded";
+/// Style of the node representing a business knowledge model.
+const STYLE_BUSINESS_KNOWLEDGE_MODEL: &str = "shape=octagon";
+/// Style of the node representing a decision service.
+const STYLE_DECISION_SERVICE: &str = "shape=box,style=\"rounded,bold\"";
+/// Style of the node representing a knowledge source.
+const STYLE_KNOWLEDGE_SOURCE: &str = "shape=note";
+/// Style of the edge representing an information requirement (solid line with black arrow).
+const STYLE_INFORMATION_REQUIREMENT: &str = "arrowhead=normal";
+/// Style of the edge representing a knowledge requirement (dashed line with thin arrow).
+const STYLE_KNOWLEDGE_REQUIREMENT: &str = "style=dashed,arrowhead=vee";
+/// Style of the edge representing an authority requirement (dashed line with filled circular head).
+const STYLE_AUTHORITY_REQUIREMENT: &str = "style=dashed,arrowhead=dot";
+
+/// Generates the decision requirements graph of the DMN™ model in `DOT` format.
+///
+/// The edges are directed from the required element to the requiring element,
+/// just like the arrows in decision requirements diagrams.
+pub fn dmn_model_to_dot(definitions: &Definitions) -> String {
+  let mut output = String::new();
+  let _ = writeln!(output, "digraph {} {{", quote(definitions.name()));
+  for drg_element in definitions.drg_elements() {
+    match drg_element {
+      DrgElement::Decision(decision) => write_node(&mut output, decision.id(), decision.name(), STYLE_DECISION),
+      DrgElement::InputData(input_data) => write_node(&mut output, input_data.id(), input_data.name(), STYLE_INPUT_DATA),
+      DrgElement::BusinessKnowledgeModel(bkm) => write_node(&mut output, bkm.id(), bkm.name(), STYLE_BUSINESS_KNOWLEDGE_MODEL),
+      DrgElement::DecisionService(decision_service) => write_node(&mut output, decision_service.id(), decision_service.name(), STYLE_DECISION_SERVICE),
+      DrgElement::KnowledgeSource(knowledge_source) => write_node(&mut output, knowledge_source.id(), knowledge_source.name(), STYLE_KNOWLEDGE_SOURCE),
+    }
+  }
+  for drg_element in definitions.drg_elements() {
+    match drg_element {
+      DrgElement::Decision(decision) => {
+        write_information_requirements(&mut output, decision.id(), decision.information_requirements());
+        write_knowledge_requirements(&mut output, decision.id(), decision.knowledge_requirements());
+        write_authority_requirements(&mut output, decision.id(), decision.authority_requirements());
+      }
+      DrgElement::BusinessKnowledgeModel(bkm) => {
+        write_knowledge_requirements(&mut output, bkm.id(), bkm.knowledge_requirements());
+        write_authority_requirements(&mut output, bkm.id(), bkm.authority_requirements());
+      }
+      DrgElement::KnowledgeSource(knowledge_source) => {
+        write_authority_requirements(&mut output, knowledge_source.id(), knowledge_source.authority_requirements());
+      }
+      _ => {}
+    }
+  }
+  let _ = writeln!(output, "}}");
+  output
+}
+
+/// Writes a single node.
+fn write_node(w: &mut dyn Write, id: &str, name: &str, style: &str) {
+  let _ = writeln!(w, "  {} [label={},{}];", quote(id), quote(name), style);
+}
+
+/// Writes a single edge directed from the required element to the requiring element.
+fn write_edge(w: &mut dyn Write, href: &HRef, id: &str, style: &str) {
+  let _ = writeln!(w, "  {} -> {} [{}];", quote(href.id()), quote(id), style);
+}
+
+/// Writes edges representing information requirements.
+fn write_information_requirements(w: &mut dyn Write, id: &str, information_requirements: &[InformationRequirement]) {
+  for information_requirement in information_requirements {
+    for href in [information_requirement.required_decision(), information_requirement.required_input()]
+      .into_iter()
+      .flatten()
+    {
+      write_edge(w, href, id, STYLE_INFORMATION_REQUIREMENT);
+    }
+  }
+}
+
+/// Writes edges representing knowledge requirements.
+fn write_knowledge_requirements(w: &mut dyn Write, id: &str, knowledge_requirements: &[KnowledgeRequirement]) {
+  for knowledge_requirement in knowledge_requirements {
+    write_edge(w, knowledge_requirement.required_knowledge(), id, STYLE_KNOWLEDGE_REQUIREMENT);
+  }
+}
+
+/// Writes edges representing authority requirements.
+fn write_authority_requirements(w: &mut dyn Write, id: &str, authority_requirements: &[AuthorityRequirement]) {
+  for authority_requirement in authority_requirements {
+    for href in [
+      authority_requirement.required_authority(),
+      authority_requirement.required_decision(),
+      authority_requirement.required_input(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+      write_edge(w, href, id, STYLE_AUTHORITY_REQUIREMENT);
+    }
+  }
+}
+
+/// Returns the text enclosed in double quotes, with all special characters escaped.
+fn quote(text: &str) -> String {
+  format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
@@ -1,5 +1,6 @@
 mod ascii_model;
 mod defs;
+mod dot_model;
 mod generator;
 mod horizontal_decision_table;
 
@@ -7,4 +8,5 @@ mod horizontal_decision_table;
 mod tests;
 
 pub use ascii_model::print_model;
+pub use dot_model::dmn_model_to_dot;
 pub use generator::{decision_table_to_html, dmn_model_to_html};
@@ -0,0 +1,27 @@
+//! # Tests for generating decision requirements graph in DOT format
+
+use crate::dmn_model_to_dot;
+use dmntk_examples::*;
+
+#[test]
+fn _0001() {
+  let definitions = dmntk_model::parse(DMN_3_0087).expect("parsing model failed");
+  let dot = dmn_model_to_dot(&definitions);
+  assert!(dot.starts_with("digraph \"Chapter 11 Example\" {\n"));
+  assert!(dot.ends_with("}\n"));
+  // knowledge source node
+  assert!(dot.contains(r#"  "_989d137f-86ff-4249-813f-af67c08a2762" [label="Credit officer",shape=note];"#));
+  // information requirement
+  assert!(dot.contains(r#"  "_ca1e6032-12eb-428a-a80b-49028a88c0b5" -> "_4bd33d4a-741b-444a-968b-64e1841211e7" [arrowhead=normal];"#));
+  // knowledge requirement
+  assert!(dot.contains(r#"  "_a654be71-b54d-4d6e-90f0-ae505125e9a6" -> "_5b8356f3-2cf2-40e8-8f80-324937e8b276" [style=dashed,arrowhead=vee];"#));
+  // authority requirement
+  assert!(dot.contains(r#"  "_989d137f-86ff-4249-813f-af67c08a2762" -> "_4bd33d4a-741b-444a-968b-64e1841211e7" [style=dashed,arrowhead=dot];"#));
+}
+
+#[test]
+fn _0002() {
+  let definitions = dmntk_model::parse(DMN_2_0001).expect("parsing model failed");
+  let dot = dmn_model_to_dot(&definitions);
+  assert!(!dot.contains("arrowhead=dot"));
+}
@@ -1,6 +1,7 @@
 mod ascii_model;
 mod compatibility;
 mod decision_tables;
+mod dot_model;
 
 use std::fs;
 use std::fs::File;
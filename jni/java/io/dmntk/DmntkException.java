@@ -0,0 +1,11 @@
+package io.dmntk;
+
+/**
+ * Exception thrown when parsing or evaluating decision models or FEEL expressions fails.
+ */
+public class DmntkException extends RuntimeException {
+
+  public DmntkException(String message) {
+    super(message);
+  }
+}
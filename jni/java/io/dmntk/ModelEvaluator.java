@@ -0,0 +1,83 @@
+package io.dmntk;
+
+/**
+ * Evaluator of decision models, backed by the native DMNTK library.
+ *
+ * <p>Input data and results are passed as JSON strings. The native library
+ * {@code dmntk_jni} must be available in {@code java.library.path}.</p>
+ *
+ * <pre>{@code
+ * try (ModelEvaluator evaluator = new ModelEvaluator(Files.readString(Path.of("compliance.dmn")))) {
+ *   String result = evaluator.evaluate("Loan approval", "{\"Applicant\": {\"Age\": 42}}");
+ * }
+ * }</pre>
+ */
+public final class ModelEvaluator implements AutoCloseable {
+
+  static {
+    System.loadLibrary("dmntk_jni");
+  }
+
+  /** Handle of the native evaluator, zero when the evaluator is closed. */
+  private long handle;
+
+  /**
+   * Creates an evaluator of decision models given as XML documents.
+   *
+   * @throws DmntkException when any of the models can not be parsed
+   */
+  public ModelEvaluator(String... models) {
+    handle = create(models);
+  }
+
+  /**
+   * Evaluates the invocable from the namespace of the first model.
+   *
+   * @return the result of the evaluation in JSON format
+   * @throws DmntkException when the evaluation fails
+   */
+  public String evaluate(String invocableName, String inputJson) {
+    return evaluate(invocableName, inputJson, null);
+  }
+
+  /**
+   * Evaluates the invocable from the specified namespace,
+   * the namespace of the first model is used when the namespace is {@code null}.
+   *
+   * @return the result of the evaluation in JSON format
+   * @throws DmntkException when the evaluation fails
+   */
+  public synchronized String evaluate(String invocableName, String inputJson, String namespace) {
+    if (handle == 0) {
+      throw new IllegalStateException("model evaluator is closed");
+    }
+    return evaluate(handle, namespace, invocableName, inputJson);
+  }
+
+  /**
+   * Evaluates the FEEL expression in the context given in JSON format.
+   *
+   * @return the result of the evaluation in JSON format
+   * @throws DmntkException when the expression can not be parsed or evaluated
+   */
+  public static String evaluateFeel(String expression, String contextJson) {
+    return feel(expression, contextJson);
+  }
+
+  /** Releases the native evaluator. */
+  @Override
+  public synchronized void close() {
+    if (handle != 0) {
+      release(handle);
+      handle = 0;
+    }
+  }
+
+  private static native long create(String[] models);
+
+  private static native String evaluate(long handle, String namespace, String invocableName, String inputJson);
+
+  private static native String feel(String expression, String contextJson);
+
+  private static native void release(long handle);
+}
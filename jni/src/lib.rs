@@ -0,0 +1,141 @@
+//! # JNI bridge for **DMNTK**
+//!
+//! Native methods of the Java class `io.dmntk.ModelEvaluator` (see `java` directory).
+//! Input data and results are passed as JSON strings, input data are converted
+//! to FEEL contexts like input data in JSON format. Parsing and evaluation errors
+//! are thrown as `io.dmntk.DmntkException`.
+
+use dmntk_common::Jsonify;
+use dmntk_evaluator::ModelEvaluator;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::FeelScope;
+use dmntk_model::Definitions;
+use jni::objects::{JClass, JObjectArray, JString};
+use jni::sys::{jlong, jstring};
+use jni::JNIEnv;
+use std::ptr;
+use std::sync::Arc;
+
+/// Name of the Java exception class thrown on errors.
+const EXCEPTION_CLASS: &str = "io/dmntk/DmntkException";
+
+/// Native evaluator referenced by the handle held in Java objects.
+struct Bridge {
+  /// Evaluator of loaded decision models.
+  model_evaluator: Arc<ModelEvaluator>,
+  /// Namespace of the first loaded model, the default namespace of evaluated invocables.
+  namespace: String,
+}
+
+/// Creates the native evaluator of decision models, returns its handle.
+#[no_mangle]
+pub extern "system" fn Java_io_dmntk_ModelEvaluator_create<'local>(mut env: JNIEnv<'local>, _class: JClass<'local>, models: JObjectArray<'local>) -> jlong {
+  match create(&mut env, &models) {
+    Ok(bridge) => Box::into_raw(Box::new(bridge)) as jlong,
+    Err(reason) => throw(&mut env, reason, 0),
+  }
+}
+
+/// Evaluates the invocable with input data in JSON format, returns the result in JSON format.
+#[no_mangle]
+pub extern "system" fn Java_io_dmntk_ModelEvaluator_evaluate<'local>(
+  mut env: JNIEnv<'local>,
+  _class: JClass<'local>,
+  handle: jlong,
+  namespace: JString<'local>,
+  invocable_name: JString<'local>,
+  input_json: JString<'local>,
+) -> jstring {
+  // SAFETY: the handle was created by `create` and is not yet released, guarded by the Java class
+  let bridge = unsafe { &*(handle as *const Bridge) };
+  let result = (|| {
+    let namespace = if namespace.is_null() {
+      bridge.namespace.clone()
+    } else {
+      get_string(&mut env, &namespace)?
+    };
+    let invocable_name = get_string(&mut env, &invocable_name)?;
+    let input_data = context_from_json(&mut env, &input_json)?;
+    let (coerced_input_data, _) = bridge.model_evaluator.coerce_json_input(&namespace, &input_data);
+    value_to_json(&bridge.model_evaluator.evaluate_invocable(&namespace, &invocable_name, &coerced_input_data))
+  })();
+  to_jstring(&mut env, result)
+}
+
+/// Evaluates the FEEL expression in the context given in JSON format, returns the result in JSON format.
+#[no_mangle]
+pub extern "system" fn Java_io_dmntk_ModelEvaluator_feel<'local>(
+  mut env: JNIEnv<'local>,
+  _class: JClass<'local>,
+  expression: JString<'local>,
+  context_json: JString<'local>,
+) -> jstring {
+  let result = (|| {
+    let expression = get_string(&mut env, &expression)?;
+    let scope: FeelScope = context_from_json(&mut env, &context_json)?.into();
+    let node = dmntk_feel_parser::parse_expression(&scope, &expression, false).map_err(|reason| reason.to_string())?;
+    value_to_json(&dmntk_evaluator::evaluate(&scope, &node).map_err(|reason| reason.to_string())?)
+  })();
+  to_jstring(&mut env, result)
+}
+
+/// Releases the native evaluator.
+#[no_mangle]
+pub extern "system" fn Java_io_dmntk_ModelEvaluator_release<'local>(_env: JNIEnv<'local>, _class: JClass<'local>, handle: jlong) {
+  if handle != 0 {
+    // SAFETY: the handle was created by `create` and is released only once, guarded by the Java class
+    drop(unsafe { Box::from_raw(handle as *mut Bridge) });
+  }
+}
+
+/// Parses decision models and builds their evaluator.
+fn create(env: &mut JNIEnv, models: &JObjectArray) -> Result<Bridge, String> {
+  let length = env.get_array_length(models).map_err(|reason| reason.to_string())?;
+  let mut definitions: Vec<Definitions> = vec![];
+  for index in 0..length {
+    let model = JString::from(env.get_object_array_element(models, index).map_err(|reason| reason.to_string())?);
+    let model = get_string(env, &model)?;
+    definitions.push(dmntk_model::parse(&model).map_err(|reason| reason.to_string())?);
+  }
+  let namespace = definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default();
+  let model_evaluator = ModelEvaluator::new(&definitions).map_err(|reason| reason.to_string())?;
+  Ok(Bridge { model_evaluator, namespace })
+}
+
+/// Converts Java string into Rust string.
+fn get_string(env: &mut JNIEnv, text: &JString) -> Result<String, String> {
+  env.get_string(text).map(|text| text.into()).map_err(|reason| reason.to_string())
+}
+
+/// Converts input data in JSON format into FEEL context, `null` strings give empty contexts.
+fn context_from_json(env: &mut JNIEnv, json: &JString) -> Result<FeelContext, String> {
+  if json.is_null() {
+    return Ok(FeelContext::default());
+  }
+  let json = get_string(env, json)?;
+  dmntk_feel::json::context_from_json(&json).map_err(|reason| reason.to_string())
+}
+
+/// Converts the value into JSON format, values being `null` with a reason are reported as errors.
+fn value_to_json(value: &Value) -> Result<String, String> {
+  match value {
+    Value::Null(Some(reason)) => Err(reason.to_string()),
+    other => Ok(other.jsonify()),
+  }
+}
+
+/// Converts the result into Java string, errors are thrown as exceptions.
+fn to_jstring(env: &mut JNIEnv, result: Result<String, String>) -> jstring {
+  match result.and_then(|json| env.new_string(json).map_err(|reason| reason.to_string())) {
+    Ok(json) => json.into_raw(),
+    Err(reason) => throw(env, reason, ptr::null_mut()),
+  }
+}
+
+/// Throws the exception with specified message, returns the default value of the native method.
+fn throw<T>(env: &mut JNIEnv, reason: String, default: T) -> T {
+  // when throwing fails, an exception is already pending in the JVM
+  let _ = env.throw_new(EXCEPTION_CLASS, reason);
+  default
+}
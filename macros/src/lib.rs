@@ -12,8 +12,8 @@ pub fn to_error_message(input: TokenStream) -> TokenStream {
   let name = &input.ident;
   let expanded = quote! {
     impl ToErrorMessage for #name {
-      fn message(self) -> String {
-        self.0
+      fn message(self) -> Message {
+        self.0.into()
       }
     }
   };
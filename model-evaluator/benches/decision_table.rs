@@ -0,0 +1,87 @@
+//! # Decision table benchmarks
+//!
+//! Measures the evaluation time of large decision tables,
+//! with rules selected by the value of a single string input,
+//! and the time of building evaluators of such tables.
+
+#![feature(test)]
+
+extern crate test;
+
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::Name;
+use dmntk_model_evaluator::ModelEvaluator;
+use std::sync::Arc;
+use test::Bencher;
+
+/// Namespace of the generated model.
+const NAMESPACE: &str = "https://dmntk.io/decision-table";
+
+/// Generates a model with decision table having specified hit policy and number of rules.
+fn generate_model(hit_policy: &str, rule_count: usize) -> String {
+  let mut model = String::new();
+  model.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+  model.push_str(&format!(
+    r#"<definitions xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="{NAMESPACE}" name="decision-table" id="_decision_table">"#
+  ));
+  model.push_str(r#"<decision name="Result" id="_result"><variable name="Result" typeRef="number"/>"#);
+  model.push_str(r##"<informationRequirement id="_ir_code"><requiredInput href="#_code"/></informationRequirement>"##);
+  model.push_str(r##"<informationRequirement id="_ir_amount"><requiredInput href="#_amount"/></informationRequirement>"##);
+  model.push_str(&format!(r#"<decisionTable hitPolicy="{hit_policy}" id="_table">"#));
+  model.push_str(r#"<input id="_input_code"><inputExpression typeRef="string"><text>Code</text></inputExpression></input>"#);
+  model.push_str(r#"<input id="_input_amount"><inputExpression typeRef="number"><text>Amount</text></inputExpression></input>"#);
+  model.push_str(r#"<output id="_output" typeRef="number"/>"#);
+  for i in 0..rule_count {
+    model.push_str(&format!(
+      r#"<rule id="_rule_{i}"><inputEntry id="_ie_code_{i}"><text>"C{i}"</text></inputEntry><inputEntry id="_ie_amount_{i}"><text>&gt;= 0</text></inputEntry><outputEntry id="_oe_{i}"><text>{i} * 2</text></outputEntry></rule>"#
+    ));
+  }
+  model.push_str("</decisionTable></decision>");
+  model.push_str(r#"<inputData name="Code" id="_code"><variable name="Code" typeRef="string"/></inputData>"#);
+  model.push_str(r#"<inputData name="Amount" id="_amount"><variable name="Amount" typeRef="number"/></inputData>"#);
+  model.push_str("</definitions>");
+  model
+}
+
+/// Prepares the model evaluator and input data selecting the last rule.
+fn prepare(hit_policy: &str, rule_count: usize) -> (Arc<ModelEvaluator>, FeelContext) {
+  let definitions = [dmntk_model::parse(&generate_model(hit_policy, rule_count)).unwrap()];
+  let model_evaluator = ModelEvaluator::new(&definitions).unwrap();
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Code"), Value::String(format!("C{}", rule_count - 1).into()));
+  input_data.set_entry(&Name::from("Amount"), Value::Number(10.into()));
+  let expected = ((rule_count - 1) * 2).to_string();
+  assert_eq!(expected, model_evaluator.evaluate_invocable(NAMESPACE, "Result", &input_data).to_string());
+  (model_evaluator, input_data)
+}
+
+#[bench]
+fn bench_decision_table_first_1000_rules(b: &mut Bencher) {
+  let (model_evaluator, input_data) = prepare("FIRST", 1000);
+  b.iter(|| model_evaluator.evaluate_invocable(NAMESPACE, "Result", &input_data));
+}
+
+#[bench]
+fn bench_decision_table_unique_1000_rules(b: &mut Bencher) {
+  let (model_evaluator, input_data) = prepare("UNIQUE", 1000);
+  b.iter(|| model_evaluator.evaluate_invocable(NAMESPACE, "Result", &input_data));
+}
+
+#[bench]
+fn bench_decision_table_unique_5000_rules(b: &mut Bencher) {
+  let (model_evaluator, input_data) = prepare("UNIQUE", 5000);
+  b.iter(|| model_evaluator.evaluate_invocable(NAMESPACE, "Result", &input_data));
+}
+
+#[bench]
+fn bench_decision_table_collect_1000_rules(b: &mut Bencher) {
+  let (model_evaluator, input_data) = prepare("COLLECT", 1000);
+  b.iter(|| model_evaluator.evaluate_invocable(NAMESPACE, "Result", &input_data));
+}
+
+#[bench]
+fn bench_decision_table_build_5000_rules(b: &mut Bencher) {
+  let definitions = [dmntk_model::parse(&generate_model("UNIQUE", 5000)).unwrap()];
+  b.iter(|| ModelEvaluator::new(&definitions).unwrap());
+}
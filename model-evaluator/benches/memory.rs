@@ -0,0 +1,69 @@
+//! # Memory benchmarks
+//!
+//! Measures the time and the number of bytes allocated while building
+//! a model evaluator for a model with many decisions sharing
+//! the same complex item definition.
+
+#![feature(test)]
+
+extern crate test;
+
+use dmntk_model_evaluator::ModelEvaluator;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test::Bencher;
+
+/// Allocator counting the total number of allocated bytes.
+struct CountingAllocator;
+
+/// Total number of bytes allocated so far.
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Number of decisions in generated model.
+const DECISION_COUNT: usize = 200;
+
+/// Number of components in the shared item definition.
+const COMPONENT_COUNT: usize = 30;
+
+/// Generates a model with many decisions having the same complex type.
+fn generate_model() -> String {
+  let mut model = String::new();
+  model.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+  model.push_str(r#"<definitions xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="https://dmntk.io/memory" name="memory" id="_memory">"#);
+  model.push_str(r#"<itemDefinition name="tShared">"#);
+  for i in 0..COMPONENT_COUNT {
+    model.push_str(&format!(r#"<itemComponent name="component{i}"><typeRef>string</typeRef></itemComponent>"#));
+  }
+  model.push_str("</itemDefinition>");
+  for i in 0..DECISION_COUNT {
+    model.push_str(&format!(
+      r#"<decision name="Decision {i}" id="_decision_{i}"><variable name="Decision {i}" typeRef="tShared"/><literalExpression><text>null</text></literalExpression></decision>"#
+    ));
+  }
+  model.push_str("</definitions>");
+  model
+}
+
+#[bench]
+fn bench_build_shared_item_definition_types(b: &mut Bencher) {
+  let definitions = [dmntk_model::parse(&generate_model()).unwrap()];
+  let before = ALLOCATED.load(Ordering::Relaxed);
+  let _ = ModelEvaluator::new(&definitions).unwrap();
+  let allocated = ALLOCATED.load(Ordering::Relaxed) - before;
+  eprintln!("bytes allocated while building model evaluator: {allocated}");
+  b.iter(|| ModelEvaluator::new(&definitions).unwrap());
+}
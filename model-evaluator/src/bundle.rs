@@ -0,0 +1,111 @@
+//! # Model bundles
+//!
+//! Model bundle is a binary file containing one or more DMN™ models,
+//! that were verified to be successfully parsed and built into [ModelEvaluator].
+//!
+//! Bundle layout (all integers are little-endian):
+//!
+//! ```text
+//! magic          4 bytes    "DMNB"
+//! version        u16        bundle format version
+//! model count    u32        number of models in bundle
+//! models         repeated   u32 length followed by UTF-8 encoded model content
+//! ```
+//!
+//! Evaluators built by [ModelEvaluator] are closures, that can not be serialized,
+//! so the bundle contains validated model definitions and the evaluator
+//! is rebuilt when the bundle is loaded. Loading a bundle parses and builds
+//! all models again, so it takes as much time as loading the models directly;
+//! bundles guarantee only that bundled models are ready to be evaluated.
+
+use crate::errors::*;
+use crate::model_evaluator::ModelEvaluator;
+use dmntk_common::Result;
+use dmntk_model::Definitions;
+use std::sync::Arc;
+
+/// Magic bytes starting every model bundle.
+const BUNDLE_MAGIC: &[u8; 4] = b"DMNB";
+
+/// Current version of the bundle format.
+const BUNDLE_VERSION: u16 = 1;
+
+/// Builds a model bundle from the content of DMN™ models.
+///
+/// All models are parsed and the [ModelEvaluator] is built from them,
+/// so only models that are ready to be evaluated can be bundled.
+pub fn build_bundle(models: &[&str]) -> Result<Vec<u8>> {
+  let definitions = parse_models(models)?;
+  ModelEvaluator::new(&definitions)?;
+  let mut bundle = vec![];
+  bundle.extend_from_slice(BUNDLE_MAGIC);
+  bundle.extend_from_slice(&BUNDLE_VERSION.to_le_bytes());
+  bundle.extend_from_slice(&length_u32(models.len())?.to_le_bytes());
+  for model in models {
+    bundle.extend_from_slice(&length_u32(model.len())?.to_le_bytes());
+    bundle.extend_from_slice(model.as_bytes());
+  }
+  Ok(bundle)
+}
+
+/// Loads model definitions and [ModelEvaluator] from model bundle.
+pub fn load_bundle(bundle: &[u8]) -> Result<(Vec<Definitions>, Arc<ModelEvaluator>)> {
+  let mut reader = BundleReader { bundle, position: 0 };
+  if reader.read(BUNDLE_MAGIC.len())? != BUNDLE_MAGIC {
+    return Err(err_invalid_bundle("missing magic bytes"));
+  }
+  let version = u16::from_le_bytes(reader.read(2)?.try_into().unwrap_or_default());
+  if version != BUNDLE_VERSION {
+    return Err(err_unsupported_bundle_version(version, BUNDLE_VERSION));
+  }
+  let model_count = reader.read_u32()?;
+  let mut models = vec![];
+  for _ in 0..model_count {
+    let length = reader.read_u32()? as usize;
+    let model = std::str::from_utf8(reader.read(length)?).map_err(|reason| err_invalid_bundle(reason.to_string()))?;
+    models.push(model);
+  }
+  if reader.position != bundle.len() {
+    return Err(err_invalid_bundle("unexpected trailing bytes"));
+  }
+  let definitions = parse_models(&models)?;
+  let model_evaluator = ModelEvaluator::new(&definitions)?;
+  Ok((definitions, model_evaluator))
+}
+
+/// Converts the length into unsigned 32-bit integer stored in the bundle.
+pub(crate) fn length_u32(length: usize) -> Result<u32> {
+  u32::try_from(length).map_err(|_| err_invalid_bundle(format!("length {length} exceeds the maximum length {}", u32::MAX)))
+}
+
+/// Parses the content of DMN™ models.
+fn parse_models(models: &[&str]) -> Result<Vec<Definitions>> {
+  models.iter().map(|model| dmntk_model::parse(model)).collect()
+}
+
+/// Sequential reader of bundle bytes.
+struct BundleReader<'a> {
+  /// Bundle content.
+  bundle: &'a [u8],
+  /// Current reading position.
+  position: usize,
+}
+
+impl<'a> BundleReader<'a> {
+  /// Reads specified number of bytes.
+  fn read(&mut self, count: usize) -> Result<&'a [u8]> {
+    let end = self
+      .position
+      .checked_add(count)
+      .filter(|end| *end <= self.bundle.len())
+      .ok_or_else(|| err_invalid_bundle("unexpected end of bundle"))?;
+    let bytes = &self.bundle[self.position..end];
+    self.position = end;
+    Ok(bytes)
+  }
+
+  /// Reads unsigned 32-bit integer.
+  fn read_u32(&mut self) -> Result<u32> {
+    Ok(u32::from_le_bytes(self.read(4)?.try_into().unwrap_or_default()))
+  }
+}
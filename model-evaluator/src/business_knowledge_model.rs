@@ -2,6 +2,7 @@
 
 use crate::boxed_expressions::*;
 use crate::errors::*;
+use crate::listener::current_listener;
 use crate::model_builder::ModelBuilder;
 use crate::model_definitions::{DefBusinessKnowledgeModel, DefDefinitions, DefKey};
 use crate::model_evaluator::ModelEvaluator;
@@ -9,7 +10,7 @@ use dmntk_common::Result;
 use dmntk_feel::closure::Closure;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
-use dmntk_feel::{FeelScope, FeelType, FunctionBody, Name};
+use dmntk_feel::{Evaluator, FeelScope, FeelType, FunctionBody, Name};
 use dmntk_model::*;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -91,16 +92,14 @@ fn build_bkm_evaluator(
   bring_knowledge_requirements_into_context(definitions, business_knowledge_model.knowledge_requirements(), &mut local_context)?;
   //TODO verify the above line - there was no such example in models
   if let Some(expression_instance) = function_definition.body() {
-    let scope: FeelScope = local_context.into();
-    build_bkm_expression_instance_evaluator(
-      &scope,
-      formal_parameters,
-      expression_instance,
-      output_variable_name,
-      output_variable_type,
-      knowledge_requirements,
-      model_builder,
-    )
+    // named constants visible in this business knowledge model are captured in closure
+    let constants_ctx = model_builder.constants_evaluator().constants(business_knowledge_model.namespace()).clone();
+    let scope: FeelScope = constants_ctx.clone().into();
+    scope.push(local_context);
+    let function_body = build_bkm_function_body(&scope, &output_variable_name, expression_instance, model_builder)?;
+    let closure = Closure::default();
+    let function_definition = Value::FunctionDefinition(formal_parameters, function_body, false, closure, constants_ctx, output_variable_type);
+    build_bkm_evaluator_from_function_definition(output_variable_name, function_definition, knowledge_requirements)
   } else {
     let output_variable_name = business_knowledge_model.variable().name().clone();
     Ok(Box::new(move |_: &FeelContext, _: &FeelContext, _: &ModelEvaluator, _: &mut FeelContext| {
@@ -109,262 +108,40 @@ fn build_bkm_evaluator(
   }
 }
 
-fn build_bkm_expression_instance_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  expression_instance: &ExpressionInstance,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  match expression_instance {
-    ExpressionInstance::Context(context) => {
-      //
-      build_bkm_context_evaluator(
-        scope,                  //
-        formal_parameters,      //
-        context,                //
-        output_variable_name,   //
-        output_variable_type,   //
-        knowledge_requirements, //
-        model_builder,          //
-      )
-    }
+/// Builds the body of the function defined by business knowledge model.
+fn build_bkm_function_body(scope: &FeelScope, bkm_name: &Name, expression_instance: &ExpressionInstance, model_builder: &ModelBuilder) -> Result<FunctionBody> {
+  Ok(match expression_instance {
+    ExpressionInstance::Context(context) => FunctionBody::Context(Arc::new(observed(bkm_name, build_context_evaluator(scope, context, model_builder)?.0))),
     ExpressionInstance::DecisionTable(decision_table) => {
-      //
-      build_bkm_decision_table_evaluator(
-        scope,                  //
-        formal_parameters,      //
-        decision_table,         //
-        output_variable_name,   //
-        output_variable_type,   //
-        knowledge_requirements, //
-        model_builder,          //
-      )
-    }
-    ExpressionInstance::FunctionDefinition(function_definition) => {
-      //
-      build_bkm_function_definition_evaluator(
-        scope,                  //
-        formal_parameters,      //
-        function_definition,    //
-        output_variable_name,   //
-        output_variable_type,   //
-        knowledge_requirements, //
-        model_builder,          //
-      )
-    }
-    ExpressionInstance::Invocation(invocation) => {
-      //
-      build_bkm_invocation_evaluator(
-        scope,                  //
-        formal_parameters,      //
-        invocation,             //
-        output_variable_name,   //
-        output_variable_type,   //
-        knowledge_requirements, //
-        model_builder,          //
-      )
+      FunctionBody::DecisionTable(Arc::new(observed(bkm_name, build_decision_table_evaluator(scope, decision_table, model_builder)?.0)))
     }
-    ExpressionInstance::LiteralExpression(literal_expression) => {
-      //
-      build_bkm_literal_expression_evaluator(
-        scope,                  //
-        formal_parameters,      //
-        literal_expression,     //
-        output_variable_name,   //
-        output_variable_type,   //
-        knowledge_requirements, //
-        model_builder,          //
-      )
-    }
-    ExpressionInstance::List(list) => {
-      //
-      build_bkm_list_evaluator(
-        scope,                  //
-        formal_parameters,      //
-        list,                   //
-        output_variable_name,   //
-        output_variable_type,   //
-        knowledge_requirements, //
-        model_builder,          //
-      )
-    }
-    ExpressionInstance::Relation(relation) => {
-      //
-      build_bkm_relation_evaluator(
-        scope,                  //
-        formal_parameters,      //
-        relation,               //
-        output_variable_name,   //
-        output_variable_type,   //
-        knowledge_requirements, //
-        model_builder,          //
-      )
-    }
-  }
-}
-
-fn build_bkm_context_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  context: &Context,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  let (evaluator, _) = build_context_evaluator(scope, context, model_builder)?;
-  let closure = Closure::default();
-  let closure_ctx = FeelContext::default();
-  let function = Value::FunctionDefinition(
-    formal_parameters,
-    FunctionBody::Context(Arc::new(evaluator)),
-    false,
-    closure,
-    closure_ctx,
-    output_variable_type,
-  );
-  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
-}
-
-fn build_bkm_decision_table_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  decision_table: &DecisionTable,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  let (evaluator, _) = build_decision_table_evaluator(scope, decision_table, model_builder)?;
-  let closure = Closure::default();
-  let closure_ctx = FeelContext::default();
-  let function = Value::FunctionDefinition(
-    formal_parameters,
-    FunctionBody::DecisionTable(Arc::new(evaluator)),
-    false,
-    closure,
-    closure_ctx,
-    output_variable_type,
-  );
-  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
-}
-
-fn build_bkm_function_definition_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  function_definition: &FunctionDefinition,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  let (evaluator, _) = build_function_definition_evaluator(scope, function_definition, model_builder)?;
-  let closure = Closure::default();
-  let closure_ctx = FeelContext::default();
-  let function = Value::FunctionDefinition(
-    formal_parameters,
-    FunctionBody::FunctionDefinition(Arc::new(evaluator)),
-    false,
-    closure,
-    closure_ctx,
-    output_variable_type,
-  );
-  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
-}
-
-fn build_bkm_invocation_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  invocation: &Invocation,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  let (evaluator, _) = build_invocation_evaluator(scope, invocation, model_builder)?;
-  let closure = Closure::default();
-  let closure_ctx = FeelContext::default();
-  let function = Value::FunctionDefinition(
-    formal_parameters,
-    FunctionBody::Invocation(Arc::new(evaluator)),
-    false,
-    closure,
-    closure_ctx,
-    output_variable_type,
-  );
-  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
-}
-
-fn build_bkm_list_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  list: &List,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  let (evaluator, _) = build_list_evaluator(scope, list, model_builder)?;
-  let closure = Closure::default();
-  let closure_ctx = FeelContext::default();
-  let function = Value::FunctionDefinition(
-    formal_parameters,
-    FunctionBody::LiteralExpression(Arc::new(evaluator)),
-    false,
-    closure,
-    closure_ctx,
-    output_variable_type,
-  );
-  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
+    ExpressionInstance::FunctionDefinition(function_definition) => FunctionBody::FunctionDefinition(Arc::new(observed(
+      bkm_name,
+      build_function_definition_evaluator(scope, function_definition, model_builder)?.0,
+    ))),
+    ExpressionInstance::Invocation(invocation) => FunctionBody::Invocation(Arc::new(observed(bkm_name, build_invocation_evaluator(scope, invocation, model_builder)?.0))),
+    ExpressionInstance::LiteralExpression(literal_expression) => FunctionBody::LiteralExpression(Arc::new(observed(
+      bkm_name,
+      build_literal_expression_evaluator(scope, literal_expression, model_builder)?.0,
+    ))),
+    ExpressionInstance::List(list) => FunctionBody::LiteralExpression(Arc::new(observed(bkm_name, build_list_evaluator(scope, list, model_builder)?.0))),
+    ExpressionInstance::Relation(relation) => FunctionBody::Relation(Arc::new(observed(bkm_name, build_relation_evaluator(scope, relation, model_builder)?.0))),
+  })
 }
 
-fn build_bkm_literal_expression_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  literal_expression: &LiteralExpression,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  let (evaluator, _) = build_literal_expression_evaluator(scope, literal_expression, model_builder)?;
-  let closure = Closure::default();
-  let closure_ctx = FeelContext::default();
-  let function = Value::FunctionDefinition(
-    formal_parameters,
-    FunctionBody::LiteralExpression(Arc::new(evaluator)),
-    false,
-    closure,
-    closure_ctx,
-    output_variable_type,
-  );
-  build_bkm_evaluator_from_function_definition(output_variable_name, function, knowledge_requirements)
-}
-
-fn build_bkm_relation_evaluator(
-  scope: &FeelScope,
-  formal_parameters: Vec<(Name, FeelType)>,
-  relation: &Relation,
-  output_variable_name: Name,
-  output_variable_type: FeelType,
-  knowledge_requirements: Vec<DefKey>,
-  model_builder: &ModelBuilder,
-) -> Result<BusinessKnowledgeModelEvaluatorFn> {
-  let (evaluator, _) = build_relation_evaluator(scope, relation, model_builder)?;
-  let closure = Closure::default();
-  let closure_ctx = FeelContext::default();
-  let function_definition = Value::FunctionDefinition(
-    formal_parameters,
-    FunctionBody::Relation(Arc::new(evaluator)),
-    false,
-    closure,
-    closure_ctx,
-    output_variable_type,
-  );
-  build_bkm_evaluator_from_function_definition(output_variable_name, function_definition, knowledge_requirements)
+/// Wraps the evaluator of the function body, so the listener registered
+/// for the evaluation is notified when the business knowledge model is invoked.
+fn observed(bkm_name: &Name, evaluator: Evaluator) -> Evaluator {
+  let bkm_name = bkm_name.to_string();
+  Box::new(move |scope: &FeelScope| {
+    let Some(listener) = current_listener() else {
+      return evaluator(scope);
+    };
+    listener.bkm_started(&bkm_name);
+    let result = evaluator(scope);
+    listener.bkm_finished(&bkm_name, &result);
+    result
+  })
 }
 
 fn build_bkm_evaluator_from_function_definition(
@@ -0,0 +1,46 @@
+//! # Builder for named constants
+
+use crate::errors::err_invalid_constant;
+use crate::model_definitions::DefDefinitions;
+use dmntk_common::Result;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::ScopeBuilder;
+use std::collections::HashMap;
+
+/// Evaluator of named constants defined in models and in the workspace.
+#[derive(Default)]
+pub struct ConstantsEvaluator {
+  /// Constants visible in all models.
+  globals: FeelContext,
+  /// Constants visible in models, indexed by namespace, including constants visible in all models.
+  constants: HashMap<String, FeelContext>,
+}
+
+impl ConstantsEvaluator {
+  /// Evaluates constants defined in models, constants may refer to constants visible in all models
+  /// and to constants defined earlier in the same model.
+  pub fn new(definitions: &DefDefinitions, globals: &FeelContext) -> Result<Self> {
+    let mut models: HashMap<String, FeelContext> = HashMap::new();
+    for constant in definitions.constants() {
+      let model_ctx = models.entry(constant.namespace().to_string()).or_default();
+      let scope = ScopeBuilder::new().globals(globals).model(model_ctx).build();
+      let value = dmntk_feel_parser::parse_expression(&scope, constant.text(), false)
+        .and_then(|node| dmntk_feel_evaluator::evaluate(&scope, &node))
+        .map_err(|reason| err_invalid_constant(&constant.name().to_string(), reason))?;
+      model_ctx.set_entry(constant.name(), value);
+    }
+    let constants = models
+      .into_iter()
+      .map(|(namespace, model_ctx)| (namespace, ScopeBuilder::new().globals(globals).model(&model_ctx).flatten()))
+      .collect();
+    Ok(Self {
+      globals: globals.clone(),
+      constants,
+    })
+  }
+
+  /// Returns constants visible in expressions of the model with specified namespace.
+  pub fn constants(&self, namespace: &str) -> &FeelContext {
+    self.constants.get(namespace).unwrap_or(&self.globals)
+  }
+}
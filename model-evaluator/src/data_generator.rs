@@ -0,0 +1,214 @@
+//! # Generator of input data
+//!
+//! Fabricates random values of input data from item definitions referenced by their types.
+//! Values of simple types are chosen from allowed values when possible: literal values
+//! are taken as they are, numbers are chosen from ranges and comparisons. Generated values
+//! are checked by item definition evaluators, values that are not allowed are generated again.
+
+use crate::item_definition::evaluate_constants;
+use crate::model_definitions::{DefDefinitions, DefItemDefinition, DefKey};
+use crate::type_ref::type_ref_to_feel_type;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::generators::FeelGenerator;
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelNumber, FeelScope, FeelType, Name};
+use dmntk_feel_parser::AstNode;
+use dmntk_model::ItemDefinitionType;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Maximum nesting level of generated values, limits recursive item definitions.
+const MAX_DEPTH: usize = 8;
+
+/// Distance between generated numbers and the bound of comparisons like `< 10`.
+const HALF_RANGE_WIDTH: i64 = 1000;
+
+/// Hint for generating values of simple types, taken from allowed values.
+enum Hint {
+  /// Allowed literal value.
+  Value(Value),
+  /// Allowed range of numbers, with optional lower and upper bound and flags indicating if bounds are closed.
+  Range(Option<(FeelNumber, bool)>, Option<(FeelNumber, bool)>),
+}
+
+/// Shape of generated values.
+enum Shape {
+  /// Value of simple type, with hints taken from allowed values.
+  Simple(FeelType, Vec<Hint>),
+  /// Value of the type defined by referenced item definition.
+  Reference(DefKey),
+  /// Context with entries for item components, with flags indicating if components are optional.
+  Components(Vec<(Name, bool, Shape)>),
+  /// List of values.
+  Collection(Box<Shape>),
+  /// Function, generated as `null`.
+  Function,
+}
+
+/// Generator of input data values.
+#[derive(Default)]
+pub struct DataGenerator {
+  /// Shapes of values defined by item definitions.
+  shapes: Arc<HashMap<DefKey, Shape>>,
+}
+
+impl DataGenerator {
+  /// Creates a generator of values defined by item definitions.
+  pub fn new(definitions: &DefDefinitions) -> Self {
+    let constants = evaluate_constants(definitions);
+    let no_constants = FeelContext::default();
+    let mut shapes = HashMap::new();
+    for item_definition in definitions.item_definitions() {
+      let constants = constants.get(item_definition.namespace()).unwrap_or(&no_constants);
+      let def_key = DefKey::new(item_definition.namespace(), item_definition.name());
+      shapes.insert(def_key, shape(item_definition, constants));
+    }
+    Self { shapes: Arc::new(shapes) }
+  }
+
+  /// Generates a random value of the type with specified namespace and type reference.
+  pub fn generate(&self, namespace: &str, type_ref: &str, generator: &mut FeelGenerator) -> Value {
+    match type_ref_to_feel_type(type_ref) {
+      Some(feel_type) => generator.value_of_type(&feel_type),
+      None => self.generate_referenced(&DefKey::new(namespace, type_ref), generator, 0),
+    }
+  }
+
+  /// Generates a random value of the type defined by item definition with specified key.
+  fn generate_referenced(&self, def_key: &DefKey, generator: &mut FeelGenerator, depth: usize) -> Value {
+    match self.shapes.get(def_key) {
+      Some(shape) if depth < MAX_DEPTH => self.generate_shape(shape, generator, depth + 1),
+      _ => Value::Null(None),
+    }
+  }
+
+  /// Generates a random value of specified shape.
+  fn generate_shape(&self, shape: &Shape, generator: &mut FeelGenerator, depth: usize) -> Value {
+    match shape {
+      Shape::Simple(feel_type, hints) if hints.is_empty() => generator.value_of_type(feel_type),
+      Shape::Simple(feel_type, hints) => match generator.choose(hints) {
+        Hint::Value(value) => value.clone(),
+        Hint::Range(start, end) => number_in_range(start.as_ref(), end.as_ref(), generator).map_or_else(|| generator.value_of_type(feel_type), Value::Number),
+      },
+      Shape::Reference(def_key) => self.generate_referenced(def_key, generator, depth),
+      Shape::Components(components) => {
+        let mut ctx = FeelContext::default();
+        for (name, is_optional, component_shape) in components {
+          // optional components are present in about half of generated values
+          if !*is_optional || generator.integer(0, 1) == 1 {
+            let value = self.generate_shape(component_shape, generator, depth);
+            ctx.set_entry(name, value);
+          }
+        }
+        Value::Context(ctx)
+      }
+      Shape::Collection(item_shape) => Value::List((0..generator.integer(0, 4)).map(|_| self.generate_shape(item_shape, generator, depth)).collect()),
+      Shape::Function => Value::Null(None),
+    }
+  }
+}
+
+/// Builds the shape of values defined by item definition.
+fn shape(item_definition: &DefItemDefinition, constants: &FeelContext) -> Shape {
+  let components = || {
+    item_definition
+      .item_components()
+      .iter()
+      .map(|component| (component.feel_name().clone(), component.is_optional(), shape(component, constants)))
+      .collect()
+  };
+  match item_definition.item_definition_type() {
+    Ok(ItemDefinitionType::SimpleType(feel_type)) => Shape::Simple(feel_type, hints(item_definition, constants)),
+    Ok(ItemDefinitionType::ReferencedType(namespace, type_ref)) => Shape::Reference(DefKey::new(&namespace, &type_ref)),
+    Ok(ItemDefinitionType::ComponentType) => Shape::Components(components()),
+    Ok(ItemDefinitionType::CollectionOfSimpleType(feel_type)) => Shape::Collection(Box::new(Shape::Simple(feel_type, vec![]))),
+    Ok(ItemDefinitionType::CollectionOfReferencedType(namespace, type_ref)) => Shape::Collection(Box::new(Shape::Reference(DefKey::new(&namespace, &type_ref)))),
+    Ok(ItemDefinitionType::CollectionOfComponentType) => Shape::Collection(Box::new(Shape::Components(components()))),
+    Ok(ItemDefinitionType::FunctionType) | Err(_) => Shape::Function,
+  }
+}
+
+/// Returns hints taken from allowed values of the item definition,
+/// allowed values that can not be evaluated give no hints.
+fn hints(item_definition: &DefItemDefinition, constants: &FeelContext) -> Vec<Hint> {
+  let Some(text) = item_definition.allowed_values().as_ref().and_then(|unary_tests| unary_tests.text().as_ref()) else {
+    return vec![];
+  };
+  let scope = FeelScope::from(constants.clone());
+  let Ok(AstNode::ExpressionList(items)) = dmntk_feel_parser::parse_unary_tests(&scope, text, false) else {
+    return vec![];
+  };
+  let number = |node: &AstNode| match dmntk_feel_evaluator::evaluate(&scope, node) {
+    Ok(Value::Number(number)) => Some(number),
+    _ => None,
+  };
+  items
+    .iter()
+    .filter_map(|item| match item {
+      AstNode::UnaryLt(end) => number(end).map(|end| Hint::Range(None, Some((end, false)))),
+      AstNode::UnaryLe(end) => number(end).map(|end| Hint::Range(None, Some((end, true)))),
+      AstNode::UnaryGt(start) => number(start).map(|start| Hint::Range(Some((start, false)), None)),
+      AstNode::UnaryGe(start) => number(start).map(|start| Hint::Range(Some((start, true)), None)),
+      AstNode::Range(start, end) => match (start.as_ref(), end.as_ref()) {
+        (AstNode::IntervalStart(start, start_closed), AstNode::IntervalEnd(end, end_closed)) => {
+          Some(Hint::Range(Some((number(start)?, *start_closed)), Some((number(end)?, *end_closed))))
+        }
+        _ => None,
+      },
+      other => match dmntk_feel_evaluator::evaluate(&scope, other) {
+        Ok(Value::Null(_)) | Err(_) => None,
+        Ok(value) => Some(Hint::Value(value)),
+      },
+    })
+    .collect()
+}
+
+/// Returns a random number from the range, integers are preferred when both bounds are integers.
+/// Returns `None` when the range is empty.
+fn number_in_range(start: Option<&(FeelNumber, bool)>, end: Option<&(FeelNumber, bool)>, generator: &mut FeelGenerator) -> Option<FeelNumber> {
+  let width = FeelNumber::from(HALF_RANGE_WIDTH);
+  let (low, low_closed) = match (start, end) {
+    (Some((start, closed)), _) => (*start, *closed),
+    (None, Some((end, _))) => (*end - width, true),
+    (None, None) => return None,
+  };
+  let (high, high_closed) = match (start, end) {
+    (_, Some((end, closed))) => (*end, *closed),
+    (Some((start, _)), None) => (*start + width, true),
+    (None, None) => return None,
+  };
+  if low.is_integer() && high.is_integer() {
+    if let (Ok(low), Ok(high)) = (i64::try_from(low), i64::try_from(high)) {
+      let low = if low_closed { low } else { low.saturating_add(1) };
+      let high = if high_closed { high } else { high.saturating_sub(1) };
+      if low <= high && high.checked_sub(low).is_some_and(|distance| distance < i64::MAX) {
+        return Some(FeelNumber::from(generator.integer(low, high)));
+      }
+    }
+  }
+  // number between bounds, never equal to open bounds
+  let step = generator.integer(if low_closed { 0 } else { 1 }, if high_closed { 1000 } else { 999 });
+  let number = low + (high - low) * FeelNumber::from(step) / FeelNumber::from(1000);
+  (low < high || (low == high && low_closed && high_closed)).then_some(number)
+}
+
+/// Returns `true` when the generated value was accepted by item definition evaluators,
+/// values that are not allowed are replaced with `null` by item definition evaluators.
+pub fn is_accepted(generated: &Value, checked: &Value) -> bool {
+  match (generated, checked) {
+    (_, Value::Null(_)) => false,
+    (Value::Context(generated_ctx), Value::Context(checked_ctx)) => checked_ctx.iter().all(|(name, checked_value)| match generated_ctx.get_entry(name) {
+      Some(generated_value) => is_accepted(generated_value, checked_value),
+      // missing optional components are checked as `null` values
+      None => true,
+    }),
+    (Value::List(generated_items), Value::List(checked_items)) => {
+      generated_items.len() == checked_items.len()
+        && generated_items
+          .iter()
+          .zip(checked_items)
+          .all(|(generated_item, checked_item)| is_accepted(generated_item, checked_item))
+    }
+    _ => true,
+  }
+}
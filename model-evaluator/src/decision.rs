@@ -1,6 +1,7 @@
 //! # Builder for decision evaluators
 
 use crate::boxed_expressions::*;
+use crate::listener::current_listener;
 use crate::model_builder::ModelBuilder;
 use crate::model_definitions::*;
 use crate::model_evaluator::ModelEvaluator;
@@ -9,6 +10,7 @@ use dmntk_common::Result;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
 use dmntk_feel::{value_null, FeelScope, Name};
+use dmntk_model::ExpressionInstance;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -119,8 +121,12 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
     }
   }
 
+  // named constants visible in this decision, shadowed by requirements
+  let constants_ctx = model_builder.constants_evaluator().constants(def_decision.namespace()).clone();
+
   // prepare a scope and build expression instance evaluator
-  let scope: FeelScope = build_requirements_ctx.into();
+  let scope: FeelScope = constants_ctx.clone().into();
+  scope.push(build_requirements_ctx);
   scope.push(input_requirements_ctx.clone());
 
   // prepare expression instance for this decision
@@ -131,6 +137,28 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
     Box::new(move |_: &FeelScope| value_null!("no decision logic defined in decision"))
   };
 
+  // names referenced by the decision logic given as literal expression
+  let opt_dependencies = match def_decision.decision_logic() {
+    Some(ExpressionInstance::LiteralExpression(literal_expression)) => literal_expression
+      .text()
+      .as_ref()
+      .and_then(|text| dmntk_feel_parser::expression_dependencies(&scope, text).ok()),
+    _ => None,
+  };
+
+  // required decision is referenced by the name of its variable or by the name of the import
+  let is_referenced = |href: &DefHRef| {
+    let Some(dependencies) = &opt_dependencies else {
+      return true;
+    };
+    let opt_name = href.import_name().cloned().or_else(|| {
+      def_definitions
+        .decision_by_key(href.namespace(), href.id())
+        .map(|decision| decision.variable().name().clone())
+    });
+    opt_name.is_none_or(|name| dependencies.iter().any(|dependency| dependency.first() == Some(&name)))
+  };
+
   // prepare required knowledge, required decisions and required input data references
   let mut required_knowledge_references: Vec<(Option<Name>, DefKey)> = vec![];
   let mut required_decision_references: Vec<(Option<Name>, DefKey)> = vec![];
@@ -144,8 +172,11 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
 
   // required decisions and required input data
   for information_requirement in def_decision.information_requirements() {
+    // required decisions not referenced by the literal expression are not evaluated
     if let Some(href) = information_requirement.required_decision() {
-      required_decision_references.push((href.import_name().cloned(), href.into()))
+      if is_referenced(href) {
+        required_decision_references.push((href.import_name().cloned(), href.into()))
+      }
     }
     if let Some(href) = information_requirement.required_input() {
       required_input_data_references.push(href.into())
@@ -155,6 +186,12 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
   // build decision evaluator closure
   let decision_evaluator = Box::new(
     move |global_context: &FeelContext, input_data_ctx: &FeelContext, model_evaluator: &ModelEvaluator, output_data_ctx: &mut FeelContext| {
+      // notify the listener, if any, before evaluating requirements, so required decisions are nested
+      let listener = current_listener();
+      if let Some(listener) = &listener {
+        listener.decision_started(&output_variable_name.to_string());
+      }
+
       let business_knowledge_model_evaluator = model_evaluator.business_knowledge_model_evaluator();
       let decision_service_evaluator = model_evaluator.decision_service_evaluator();
       let decision_evaluator = model_evaluator.decision_evaluator();
@@ -222,6 +259,7 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
 
       // prepare the evaluation scope
       let scope: FeelScope = global_context.clone().into();
+      scope.push(constants_ctx.clone());
       scope.append(required_input_ctx.into());
 
       // evaluate the result
@@ -230,6 +268,14 @@ fn build_decision_evaluator(def_definitions: &DefDefinitions, def_decision: &Def
       // coerce the output value
       let coerced_decision_result = decision_result.coerced(&output_variable_type);
 
+      // report the cause chain of null result and the result to the listener, if any
+      if let Some(listener) = &listener {
+        if coerced_decision_result.null_reason().is_some() {
+          listener.null_produced(&output_variable_name.to_string(), &coerced_decision_result.null_causes());
+        }
+        listener.decision_finished(&output_variable_name.to_string(), &coerced_decision_result);
+      }
+
       // place the result under the name of the output variable
       output_data_ctx.set_entry(&output_variable_name, coerced_decision_result);
 
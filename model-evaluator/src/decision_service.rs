@@ -10,7 +10,7 @@ use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
 use dmntk_feel::{value_null, Evaluator, FeelScope, FeelType, Name};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, OnceLock};
 
 /// Type of closure that evaluates a decision service.
 ///
@@ -18,12 +18,17 @@ use std::sync::{Arc, RwLock};
 ///
 type DecisionServiceEvaluatorFn = Box<dyn Fn(&FeelContext, &FeelContext, &ModelEvaluator, &mut FeelContext) -> Name + Send + Sync>;
 
-type DecisionServiceEvaluatorEntry = (Variable, Vec<(Name, FeelType)>, DecisionServiceEvaluatorFn, Option<Evaluator>);
+/// Decision service evaluator entry.
+///
+/// The function definition evaluator (last element) requires a reference to fully built
+/// model evaluator, so it is set only once, just after the model evaluator is created.
+/// After that, the entry is immutable and may be read concurrently without locking.
+type DecisionServiceEvaluatorEntry = (Variable, Vec<(Name, FeelType)>, DecisionServiceEvaluatorFn, OnceLock<Evaluator>);
 
 /// Decision service evaluator.
 #[derive(Default)]
 pub struct DecisionServiceEvaluator {
-  evaluators: RwLock<HashMap<DefKey, DecisionServiceEvaluatorEntry>>,
+  evaluators: HashMap<DefKey, DecisionServiceEvaluatorEntry>,
 }
 
 impl DecisionServiceEvaluator {
@@ -39,38 +44,32 @@ impl DecisionServiceEvaluator {
       evaluators.insert(def_key.clone(), evaluator);
       model_builder.add_decision_service_invocable(namespace.to_string(), name, def_key);
     }
-    Ok(Self {
-      evaluators: RwLock::new(evaluators),
-    })
+    Ok(Self { evaluators })
   }
 
   /// Creates function definition evaluators for all decision service evaluators.
   pub fn build_function_definitions(&self, model_evaluator: &Arc<ModelEvaluator>) {
-    if let Ok(mut evaluators) = self.evaluators.write() {
-      let identifiers = evaluators.keys().cloned().collect::<Vec<DefKey>>();
-      for decision_service_id in identifiers {
-        let evaluator = Arc::clone(model_evaluator);
-        evaluators.entry(decision_service_id.clone()).and_modify(|entry| {
-          let output_variable_type = entry.0.feel_type().clone();
-          let body_evaluator = Box::new(move |scope: &FeelScope| {
-            let global_context = FeelContext::default();
-            let input_data = scope.peek().unwrap_or_default();
-            let mut output_data = FeelContext::default();
-            let decision_service_evaluator = evaluator.decision_service_evaluator();
-            let opt_out_variable_name = decision_service_evaluator.evaluate(&decision_service_id, &global_context, &input_data, &evaluator, &mut output_data);
-            if let Some(out_variable_name) = opt_out_variable_name {
-              if let Some(result_value) = output_data.get_entry(&out_variable_name) {
-                return result_value.clone();
-              }
-            }
-            value_null!()
-          });
-          let function_body = dmntk_feel::FunctionBody::DecisionService(Arc::new(body_evaluator));
-          let function_definition = Value::FunctionDefinition(entry.1.clone(), function_body, false, Closure::default(), FeelContext::default(), output_variable_type);
-          let decision_service_as_function_definition_evaluator = Box::new(move |_: &FeelScope| function_definition.clone());
-          entry.3 = Some(decision_service_as_function_definition_evaluator);
-        });
-      }
+    for (def_key, entry) in &self.evaluators {
+      let decision_service_id = def_key.clone();
+      let evaluator = Arc::clone(model_evaluator);
+      let output_variable_type = entry.0.feel_type().as_ref().clone();
+      let body_evaluator = Box::new(move |scope: &FeelScope| {
+        let global_context = FeelContext::default();
+        let input_data = scope.peek().unwrap_or_default();
+        let mut output_data = FeelContext::default();
+        let decision_service_evaluator = evaluator.decision_service_evaluator();
+        let opt_out_variable_name = decision_service_evaluator.evaluate(&decision_service_id, &global_context, &input_data, &evaluator, &mut output_data);
+        if let Some(out_variable_name) = opt_out_variable_name {
+          if let Some(result_value) = output_data.get_entry(&out_variable_name) {
+            return result_value.clone();
+          }
+        }
+        value_null!()
+      });
+      let function_body = dmntk_feel::FunctionBody::DecisionService(Arc::new(body_evaluator));
+      let function_definition = Value::FunctionDefinition(entry.1.clone(), function_body, false, Closure::default(), FeelContext::default(), output_variable_type);
+      let decision_service_as_function_definition_evaluator: Evaluator = Box::new(move |_: &FeelScope| function_definition.clone());
+      let _ = entry.3.set(decision_service_as_function_definition_evaluator);
     }
   }
 
@@ -83,26 +82,26 @@ impl DecisionServiceEvaluator {
     model_evaluator: &ModelEvaluator,
     output_data: &mut FeelContext,
   ) -> Option<Name> {
+    self.evaluators.get(def_key).map(|entry| entry.2(global_context, input_data, model_evaluator, output_data))
+  }
+
+  /// Returns formal parameters and the output type of the decision service with specified identifier.
+  pub fn signature(&self, def_key: &DefKey) -> Option<(Vec<(Name, FeelType)>, FeelType)> {
     self
       .evaluators
-      .read()
-      .ok()?
       .get(def_key)
-      .map(|entry| entry.2(global_context, input_data, model_evaluator, output_data))
+      .map(|(variable, formal_parameters, _, _)| (formal_parameters.clone(), variable.feel_type().as_ref().clone()))
   }
 
   /// Returns a decision service as function definition with specified identifier.
   pub fn evaluate_fd(&self, def_key: &DefKey, input_data: &FeelContext, output_data: &mut FeelContext) -> Option<Name> {
-    if let Ok(evaluators) = self.evaluators.read() {
-      if let Some((variable, _, _, Some(evaluator))) = evaluators.get(def_key) {
-        let scope: FeelScope = input_data.clone().into();
-        let function_definition = evaluator(&scope) as Value;
-        let output_variable_name = variable.name().clone();
-        output_data.set_entry(&output_variable_name, function_definition);
-        return Some(output_variable_name);
-      }
-    }
-    None
+    let (variable, _, _, evaluator) = self.evaluators.get(def_key)?;
+    let evaluator = evaluator.get()?;
+    let scope: FeelScope = input_data.clone().into();
+    let function_definition = evaluator(&scope) as Value;
+    let output_variable_name = variable.name().clone();
+    output_data.set_entry(&output_variable_name, function_definition);
+    Some(output_variable_name)
   }
 }
 
@@ -225,5 +224,5 @@ fn build_decision_service_evaluator(decision_service: &DefDecisionService, model
       output_variable_name.clone()
     },
   );
-  Ok((output_variable, formal_parameters, decision_service_evaluator, None))
+  Ok((output_variable, formal_parameters, decision_service_evaluator, OnceLock::new()))
 }
@@ -1,34 +1,223 @@
 //! Builder for decision table evaluators.
 
+use crate::errors::{err_decision_table_without_outputs, err_invalid_number_of_rule_entries, err_output_values_not_declared};
+use crate::listener::{current_listener, EvaluationListener, FiredRule};
 use dmntk_common::Result;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
-use dmntk_feel::{value_null, Evaluator, FeelScope, Name};
+use dmntk_feel::{value_null, Evaluator, FeelScope, FeelString, Name};
 use dmntk_feel_evaluator::BuildContext;
 use dmntk_feel_parser::AstNode;
-use dmntk_model::{BuiltinAggregator, DecisionTable, HitPolicy};
-use std::cmp::Ordering;
+use dmntk_model::{BuiltinAggregator, DecisionTable, DmnElement, HitPolicy};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Minimal number of rules in decision table, for which the discrimination tree is built.
+const MIN_COMPILED_RULES: usize = 16;
+
+/// Maximal number of nodes in the discrimination tree, rules exceeding this limit are tested sequentially.
+pub(crate) const MAX_DISCRIMINATION_NODES: usize = 1024;
 
 /// Parsed rule of the decision table.
 /// Input entries and output entries are parsed into evaluation clauses
 /// and stored in this structure.
+///
+/// Evaluators of identical entries in the same column are shared between rules.
 struct ParsedRule {
-  input_entries_evaluators: Vec<Evaluator>,
-  output_entries_evaluators: Vec<Evaluator>,
+  input_entries_evaluators: Vec<Arc<Evaluator>>,
+  output_entries_evaluators: Vec<Arc<Evaluator>>,
+  /// Optional description of the rule, reported when the rule fires.
+  description: Option<String>,
+  /// Names of annotation clauses with annotation entries, reported when the rule fires.
+  annotations: Vec<(String, String)>,
 }
 
 /// Parsed decision table.
 /// All expressions contained in different parts of the decision
 /// table are parsed into evaluation clauses and stored in this structure.
 struct ParsedDecisionTable {
+  decision_table_id: String,
   component_names: Vec<Name>,
   output_values_evaluators: Vec<Option<Evaluator>>,
   default_output_values_evaluators: Vec<Option<Evaluator>>,
   rules: Vec<ParsedRule>,
+  discrimination_tree: Option<DiscriminationTree>,
+}
+
+/// Node of the discrimination tree.
+enum DiscriminationNode {
+  /// Candidate rules, tested sequentially in rule order.
+  Rules(Vec<usize>),
+  /// Rules selected by the value of the input expression of the column.
+  Branch {
+    /// Index of the input column.
+    column: usize,
+    /// Nodes selected by string constants in the column, rules without
+    /// string constants in this column are contained in every node.
+    branches: HashMap<FeelString, DiscriminationNode>,
+    /// Node containing only rules without string constants in this column,
+    /// selected when the value matches no string constant.
+    other: Box<DiscriminationNode>,
+  },
+}
+
+impl DiscriminationNode {
+  /// Compiles rules with specified indexes into a node, discriminating
+  /// rules by the most selective of specified columns, then by the remaining columns.
+  ///
+  /// The most selective column is the one having string constants in input entries of the most rules,
+  /// ties are resolved by the number of distinct constants, then by the order of columns.
+  /// Rules are not discriminated when less than a half of them contain string constants in any column.
+  ///
+  /// Rules without string constants are repeated in every branch, so the number of nodes
+  /// may grow with the product of the numbers of constants in columns; each compiled node
+  /// takes its child nodes from the `budget` and when it is exhausted, rules are tested sequentially.
+  fn compile(input_entries: &[Vec<AstNode>], rules: Vec<usize>, columns: &[usize], budget: &mut usize) -> Self {
+    let most_selective = columns
+      .iter()
+      .map(|column| {
+        let constants = rules
+          .iter()
+          .filter_map(|rule| string_constants(&input_entries[*rule][*column]))
+          .collect::<Vec<Vec<&String>>>();
+        let distinct = constants.iter().flatten().collect::<HashSet<&&String>>().len();
+        (*column, constants.len(), distinct)
+      })
+      .max_by_key(|(column, count, distinct)| (*count, *distinct, Reverse(*column)));
+    let Some((column, count, distinct)) = most_selective else {
+      return Self::Rules(rules);
+    };
+    // a branch for every distinct constant and the other branch
+    if rules.len() < 2 || count * 2 <= rules.len() || distinct + 1 > *budget {
+      return Self::Rules(rules);
+    }
+    *budget -= distinct + 1;
+    let mut indexed_rules: HashMap<FeelString, Vec<usize>> = HashMap::new();
+    let mut other_rules = vec![];
+    for rule in rules {
+      if let Some(constants) = string_constants(&input_entries[rule][column]) {
+        for constant in constants {
+          let indexes = indexed_rules.entry(constant.as_str().into()).or_default();
+          if indexes.last() != Some(&rule) {
+            indexes.push(rule);
+          }
+        }
+      } else {
+        other_rules.push(rule);
+      }
+    }
+    let remaining_columns = columns.iter().copied().filter(|other_column| *other_column != column).collect::<Vec<usize>>();
+    let branches = indexed_rules
+      .into_iter()
+      .map(|(constant, mut indexes)| {
+        indexes.extend_from_slice(&other_rules);
+        indexes.sort_unstable();
+        (constant, Self::compile(input_entries, indexes, &remaining_columns, budget))
+      })
+      .collect();
+    Self::Branch {
+      column,
+      branches,
+      other: Box::new(Self::compile(input_entries, other_rules, &remaining_columns, budget)),
+    }
+  }
+
+  /// Returns the number of nodes in this node and its descendants.
+  #[cfg(test)]
+  fn node_count(&self) -> usize {
+    match self {
+      Self::Rules(_) => 1,
+      Self::Branch { branches, other, .. } => 1 + branches.values().map(Self::node_count).sum::<usize>() + other.node_count(),
+    }
+  }
+
+  /// Collects indexes of columns discriminated in this node and its descendants.
+  fn collect_columns(&self, columns: &mut HashSet<usize>) {
+    if let Self::Branch { column, branches, other } = self {
+      columns.insert(*column);
+      branches.values().for_each(|node| node.collect_columns(columns));
+      other.collect_columns(columns);
+    }
+  }
+}
+
+/// Decision table compiled into a discrimination tree, built for UNIQUE and FIRST hit policies.
+///
+/// Instead of testing all rules sequentially, the tree selects rules by values of input expressions
+/// compared with string constants in input entries, starting from the most selective column.
+/// Only rules in the reached leaf are tested; rules skipped this way could never match,
+/// so the result is the same as when testing all rules.
+struct DiscriminationTree {
+  /// Evaluators of input expressions of discriminated columns, indexed by columns.
+  input_expression_evaluators: Vec<Option<Evaluator>>,
+  /// The root node of the tree.
+  root: DiscriminationNode,
+}
+
+impl DiscriminationTree {
+  /// Compiles the decision table into the discrimination tree.
+  /// Returns `None` when the table is too small or no column discriminates most of the rules.
+  fn build(bx: &BuildContext, input_expressions: &[AstNode], input_entries: &[Vec<AstNode>]) -> Result<Option<Self>> {
+    if input_entries.len() < MIN_COMPILED_RULES {
+      return Ok(None);
+    }
+    let columns = (0..input_expressions.len()).collect::<Vec<usize>>();
+    let mut budget = MAX_DISCRIMINATION_NODES - 1;
+    let root = DiscriminationNode::compile(input_entries, (0..input_entries.len()).collect(), &columns, &mut budget);
+    if let DiscriminationNode::Rules(_) = root {
+      return Ok(None);
+    }
+    let mut discriminated_columns = HashSet::new();
+    root.collect_columns(&mut discriminated_columns);
+    let mut input_expression_evaluators = vec![];
+    for (column, input_expression) in input_expressions.iter().enumerate() {
+      if discriminated_columns.contains(&column) {
+        input_expression_evaluators.push(Some(dmntk_feel_evaluator::prepare(bx, input_expression)?));
+      } else {
+        input_expression_evaluators.push(None);
+      }
+    }
+    Ok(Some(Self {
+      input_expression_evaluators,
+      root,
+    }))
+  }
+
+  /// Returns indexes of rules that may match in specified scope, in rule order.
+  fn candidates(&self, scope: &FeelScope) -> &[usize] {
+    let mut node = &self.root;
+    loop {
+      match node {
+        DiscriminationNode::Rules(rules) => return rules,
+        DiscriminationNode::Branch { column, branches, other } => {
+          let value = self.input_expression_evaluators[*column].as_ref().map(|evaluator| evaluator(scope));
+          node = match value {
+            Some(Value::String(value)) => branches.get(&value).unwrap_or(other),
+            _ => other,
+          };
+        }
+      }
+    }
+  }
+}
+
+/// Returns string constants when the input entry consists only of string constants.
+fn string_constants(input_entry: &AstNode) -> Option<Vec<&String>> {
+  let AstNode::ExpressionList(items) = input_entry else {
+    return None;
+  };
+  if items.is_empty() {
+    return None;
+  }
+  items.iter().map(|item| if let AstNode::String(text) = item { Some(text) } else { None }).collect()
 }
 
 /// Evaluated rule of a decision table.
 struct EvaluatedRule {
+  /// Index of the rule in the decision table.
+  index: usize,
   matches: bool,
   output_entry_values: Vec<Value>,
 }
@@ -36,25 +225,47 @@ struct EvaluatedRule {
 /// Evaluated decision table.
 /// All evaluation clauses from [ParsedDecisionTable] are executed
 /// in specified context and results are stored as [Values](Value) in this structure.
-struct EvaluatedDecisionTable {
+struct EvaluatedDecisionTable<'a> {
+  parsed_decision_table: &'a ParsedDecisionTable,
+  /// Listener notified about fired rules, registered for the evaluation in progress.
+  listener: Option<Rc<dyn EvaluationListener>>,
   component_names: Vec<Name>,
-  output_values: Vec<Value>,
+  /// Output values of each output clause, empty when output values are not declared.
+  output_values: Vec<Vec<Value>>,
   default_output_values: Vec<Value>,
   evaluated_rules: Vec<EvaluatedRule>,
 }
 
-impl EvaluatedDecisionTable {
+impl EvaluatedDecisionTable<'_> {
+  /// Notifies the listener about rules contributing to the result of the decision table.
+  fn fire(&self, evaluated_rules: &[&EvaluatedRule]) {
+    if let Some(listener) = &self.listener {
+      for evaluated_rule in evaluated_rules {
+        let parsed_rule = &self.parsed_decision_table.rules[evaluated_rule.index];
+        listener.rule_fired(&FiredRule::new(
+          &self.parsed_decision_table.decision_table_id,
+          evaluated_rule.index + 1,
+          parsed_rule.description.clone(),
+          parsed_rule.annotations.clone(),
+        ));
+      }
+    }
+  }
   /// Returns all matching rules in rule order.
   fn get_matching_rules(&self) -> Vec<&EvaluatedRule> {
     self.evaluated_rules.iter().filter(|evaluated_rule| evaluated_rule.matches).collect()
   }
   /// Returns all matching rules in decreasing order of priority.
+  ///
+  /// The priority of the rule is given by the order of its output entry values in the lists
+  /// of output values, the first output clause is the most significant one.
+  /// Rules with the same priority remain in rule order.
   fn get_matching_rules_prioritized(&self) -> Vec<&EvaluatedRule> {
     let mut rules: Vec<&EvaluatedRule> = self.evaluated_rules.iter().filter(|v| v.matches).collect();
     let compare = |x: &&EvaluatedRule, y: &&EvaluatedRule| {
-      for (v1, v2) in x.output_entry_values.iter().zip(y.output_entry_values.iter()) {
-        let index1 = self.output_values.iter().position(|o| o == v1);
-        let index2 = self.output_values.iter().position(|o| o == v2);
+      for ((v1, v2), output_values) in x.output_entry_values.iter().zip(y.output_entry_values.iter()).zip(self.output_values.iter()) {
+        let index1 = output_values.iter().position(|o| dmntk_feel_evaluator::evaluate_equals(o, v1));
+        let index2 = output_values.iter().position(|o| dmntk_feel_evaluator::evaluate_equals(o, v2));
         match (index1, index2) {
           (Some(ix1), Some(ix2)) => {
             if ix1 < ix2 {
@@ -112,6 +323,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.len() > 1 {
       return value_null!("err_multiple_rules_match_in_unique_hit_policy");
     }
+    self.fire(&matching_rules);
     self.get_result(matching_rules[0])
   }
   fn evaluate_hit_policy_any(&self) -> Value {
@@ -120,12 +332,13 @@ impl EvaluatedDecisionTable {
       return self.evaluate_default_output_value();
     }
     let first_result = self.get_result(matching_rules[0]);
-    for evaluated_rule in matching_rules {
+    for evaluated_rule in &matching_rules {
       let result = self.get_result(evaluated_rule);
       if result != first_result {
         return value_null!("err_all_matching_rules_must_have_the_same_value");
       }
     }
+    self.fire(&matching_rules);
     first_result
   }
   fn evaluate_hit_policy_priority(&self) -> Value {
@@ -133,6 +346,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules[..1]);
     self.get_result(matching_rules[0])
   }
   fn evaluate_hit_policy_first(&self) -> Value {
@@ -140,6 +354,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules[..1]);
     self.get_result(matching_rules[0])
   }
   fn evaluate_hit_policy_rule_order(&self) -> Value {
@@ -147,6 +362,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules);
     self.get_results(&matching_rules)
   }
   fn evaluate_hit_policy_output_order(&self) -> Value {
@@ -154,6 +370,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules);
     self.get_results(&matching_rules)
   }
   fn evaluate_hit_policy_collect_list(&self) -> Value {
@@ -161,6 +378,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules);
     self.get_results(&matching_rules)
   }
   fn evaluate_hit_policy_collect_count(&self) -> Value {
@@ -168,6 +386,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules);
     Value::Number(matching_rules.len().into())
   }
   fn evaluate_hit_policy_collect_sum(&self) -> Value {
@@ -178,6 +397,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules);
     let output_values = matching_rules
       .iter()
       .map(|evaluated_rule| evaluated_rule.output_entry_values[0].clone())
@@ -192,6 +412,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules);
     let output_values = matching_rules
       .iter()
       .map(|evaluated_rule| evaluated_rule.output_entry_values[0].clone())
@@ -206,6 +427,7 @@ impl EvaluatedDecisionTable {
     if matching_rules.is_empty() {
       return self.evaluate_default_output_value();
     }
+    self.fire(&matching_rules);
     let output_values = matching_rules
       .iter()
       .map(|evaluated_rule| evaluated_rule.output_entry_values[0].clone())
@@ -214,7 +436,11 @@ impl EvaluatedDecisionTable {
   }
 }
 
-fn parse_decision_table(scope: &FeelScope, decision_table: &DecisionTable) -> Result<ParsedDecisionTable> {
+/// Parses the decision table, UNIQUE and FIRST tables are compiled into a discrimination tree when `compiled` is `true`.
+fn parse_decision_table(scope: &FeelScope, decision_table: &DecisionTable, compiled: bool) -> Result<ParsedDecisionTable> {
+  let hit_policy = decision_table.hit_policy();
+  // all evaluators share the same build context, so identical string literals share their content
+  let bx = BuildContext::default();
   // parse input expressions and input values
   let mut input_expressions_and_values = vec![];
   for input_clause in decision_table.input_clauses() {
@@ -227,6 +453,18 @@ fn parse_decision_table(scope: &FeelScope, decision_table: &DecisionTable) -> Re
     };
     input_expressions_and_values.push((input_expression, input_values))
   }
+  // the order of output values determines the order of rules in these hit policies,
+  // so output values must be declared for every output
+  if decision_table.output_clauses().any(|output_clause| output_clause.allowed_output_values.is_none()) {
+    match hit_policy {
+      HitPolicy::Priority => return Err(err_output_values_not_declared("PRIORITY")),
+      HitPolicy::OutputOrder => return Err(err_output_values_not_declared("OUTPUT ORDER")),
+      _ => {}
+    }
+  }
+  if decision_table.output_clauses().next().is_none() {
+    return Err(err_decision_table_without_outputs(decision_table.id()));
+  }
   // parse output values and output component names
   let mut component_names = vec![];
   let mut output_values_nodes = vec![];
@@ -248,43 +486,89 @@ fn parse_decision_table(scope: &FeelScope, decision_table: &DecisionTable) -> Re
       component_names.push(dmntk_feel_parser::parse_name(scope, name, false)?);
     }
   }
-  // parse all rules
+  // parse all rules, entries with identical text in the same column are parsed only once,
+  // large generated tables repeat the same entries in thousands of rules
+  let mut parsed_input_entries: HashMap<(usize, &str), (AstNode, Arc<Evaluator>)> = HashMap::new();
+  let mut parsed_output_entries: HashMap<(usize, &str), Arc<Evaluator>> = HashMap::new();
   let mut parsed_rules = vec![];
-  for rule in decision_table.rules() {
+  let mut input_entries_nodes = vec![];
+  for (rule_index, rule) in decision_table.rules().enumerate() {
+    // every rule must have an entry for each input and output clause
+    if rule.input_entries.len() != input_expressions_and_values.len() {
+      let (expected, actual) = (input_expressions_and_values.len(), rule.input_entries.len());
+      return Err(err_invalid_number_of_rule_entries(decision_table.id(), rule_index + 1, "input", expected, actual));
+    }
+    if rule.output_entries.len() != output_values_nodes.len() {
+      let (expected, actual) = (output_values_nodes.len(), rule.output_entries.len());
+      return Err(err_invalid_number_of_rule_entries(decision_table.id(), rule_index + 1, "output", expected, actual));
+    }
     // parse input clause
     let mut input_entries_evaluators = vec![];
+    let mut input_entry_nodes = vec![];
     for (i, (input_expression, input_values)) in input_expressions_and_values.iter().enumerate() {
-      let input_entry_node = dmntk_feel_parser::parse_unary_tests(scope, &rule.input_entries[i].text, false)?;
-      if let Some(input_values_node) = input_values {
+      let text = rule.input_entries[i].text.trim();
+      if let Some((input_entry_node, evaluator)) = parsed_input_entries.get(&(i, text)) {
+        input_entry_nodes.push(input_entry_node.clone());
+        input_entries_evaluators.push(Arc::clone(evaluator));
+        continue;
+      }
+      let input_entry_node = dmntk_feel_parser::parse_unary_tests(scope, text, false)?;
+      input_entry_nodes.push(input_entry_node.clone());
+      let node = if let Some(input_values_node) = input_values {
         let left = AstNode::In(Box::new(input_expression.clone()), Box::new(input_values_node.clone()));
-        let right = AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node));
-        let node = AstNode::And(Box::new(left), Box::new(right));
-        input_entries_evaluators.push(dmntk_feel_evaluator::prepare(&BuildContext::default(), &node)?);
+        let right = AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node.clone()));
+        AstNode::And(Box::new(left), Box::new(right))
       } else {
-        let node = AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node));
-        input_entries_evaluators.push(dmntk_feel_evaluator::prepare(&BuildContext::default(), &node)?);
-      }
+        AstNode::In(Box::new(input_expression.clone()), Box::new(input_entry_node.clone()))
+      };
+      let evaluator = Arc::new(dmntk_feel_evaluator::prepare(&bx, &node)?);
+      input_entries_evaluators.push(Arc::clone(&evaluator));
+      parsed_input_entries.insert((i, text), (input_entry_node, evaluator));
     }
     // parse output clause
     let mut output_entries_evaluators = vec![];
     for (i, output_values) in output_values_nodes.iter().enumerate() {
-      let output_entry_node = dmntk_feel_parser::parse_expression(scope, &rule.output_entries[i].text, false)?;
-      if let Some(output_value_node) = output_values {
+      let text = rule.output_entries[i].text.trim();
+      if let Some(evaluator) = parsed_output_entries.get(&(i, text)) {
+        output_entries_evaluators.push(Arc::clone(evaluator));
+        continue;
+      }
+      let output_entry_node = dmntk_feel_parser::parse_expression(scope, text, false)?;
+      let evaluator = if let Some(output_value_node) = output_values {
         let node = AstNode::Out(Box::new(output_entry_node), Box::new(output_value_node.clone()));
-        output_entries_evaluators.push(dmntk_feel_evaluator::prepare(&BuildContext::default(), &node)?);
+        Arc::new(dmntk_feel_evaluator::prepare(&bx, &node)?)
       } else {
-        output_entries_evaluators.push(dmntk_feel_evaluator::prepare(&BuildContext::default(), &output_entry_node)?);
-      }
+        Arc::new(dmntk_feel_evaluator::prepare(&bx, &output_entry_node)?)
+      };
+      output_entries_evaluators.push(Arc::clone(&evaluator));
+      parsed_output_entries.insert((i, text), evaluator);
     }
     parsed_rules.push(ParsedRule {
       input_entries_evaluators,
       output_entries_evaluators,
-    })
+      description: rule.description.clone(),
+      annotations: decision_table
+        .annotations()
+        .zip(rule.annotation_entries.iter())
+        .map(|(annotation, annotation_entry)| (annotation.name.clone(), annotation_entry.text.clone()))
+        .collect(),
+    });
+    input_entries_nodes.push(input_entry_nodes);
   }
+  // compile the discrimination tree, when only the first matching rule or the only one matching rule is needed
+  let discrimination_tree = if compiled && matches!(hit_policy, HitPolicy::First | HitPolicy::Unique) {
+    let input_expressions = input_expressions_and_values
+      .into_iter()
+      .map(|(input_expression, _)| input_expression)
+      .collect::<Vec<AstNode>>();
+    DiscriminationTree::build(&bx, &input_expressions, &input_entries_nodes)?
+  } else {
+    None
+  };
   let mut output_values_evaluators = vec![];
   for opt_node in output_values_nodes {
     if let Some(node) = opt_node {
-      output_values_evaluators.push(Some(dmntk_feel_evaluator::prepare(&BuildContext::default(), &node)?));
+      output_values_evaluators.push(Some(dmntk_feel_evaluator::prepare(&bx, &node)?));
     } else {
       output_values_evaluators.push(None);
     }
@@ -292,26 +576,28 @@ fn parse_decision_table(scope: &FeelScope, decision_table: &DecisionTable) -> Re
   let mut default_output_values_evaluators = vec![];
   for opt_node in default_output_values_nodes {
     if let Some(node) = opt_node {
-      default_output_values_evaluators.push(Some(dmntk_feel_evaluator::prepare(&BuildContext::default(), &node)?));
+      default_output_values_evaluators.push(Some(dmntk_feel_evaluator::prepare(&bx, &node)?));
     } else {
       default_output_values_evaluators.push(None);
     }
   }
   Ok(ParsedDecisionTable {
+    decision_table_id: decision_table.id().to_string(),
     component_names,
     output_values_evaluators,
     default_output_values_evaluators,
     rules: parsed_rules,
+    discrimination_tree,
   })
 }
 
-fn evaluate_parsed_decision_table(scope: &FeelScope, parsed_decision_table: &ParsedDecisionTable) -> EvaluatedDecisionTable {
-  // evaluate only non-empty output values
+fn evaluate_parsed_decision_table<'a>(scope: &FeelScope, parsed_decision_table: &'a ParsedDecisionTable) -> EvaluatedDecisionTable<'a> {
+  // evaluate output values of each output clause
   let mut output_values = vec![];
-  for evaluator in parsed_decision_table.output_values_evaluators.iter().flatten() {
-    let value = evaluator(scope);
-    if let Value::ExpressionList(values) = value {
-      output_values.append(&mut values.to_owned());
+  for opt_evaluator in &parsed_decision_table.output_values_evaluators {
+    match opt_evaluator.as_ref().map(|evaluator| evaluator(scope)) {
+      Some(Value::ExpressionList(values)) => output_values.push(values),
+      _ => output_values.push(vec![]),
     }
   }
   // evaluate only non-empty default output values
@@ -321,25 +607,24 @@ fn evaluate_parsed_decision_table(scope: &FeelScope, parsed_decision_table: &Par
       default_output_values.append(&mut values.to_owned());
     }
   }
-  // evaluate all rules
-  let mut evaluated_rules = vec![];
-  for parsed_rule in &parsed_decision_table.rules {
-    let mut input_entry_values = vec![];
-    let mut matches = true;
-    for evaluator in &parsed_rule.input_entries_evaluators {
-      let input_value: Value = evaluator(scope);
-      if !input_value.is_true() {
-        matches = false;
-      }
-      input_entry_values.push(input_value);
-    }
-    let mut output_entry_values = vec![];
-    for evaluator in &parsed_rule.output_entries_evaluators {
-      output_entry_values.push(evaluator(scope));
-    }
-    evaluated_rules.push(EvaluatedRule { matches, output_entry_values })
-  }
+  // evaluate all rules, or only rules selected by the discrimination tree
+  let evaluated_rules = if let Some(discrimination_tree) = &parsed_decision_table.discrimination_tree {
+    discrimination_tree
+      .candidates(scope)
+      .iter()
+      .map(|index| evaluate_parsed_rule(scope, *index, &parsed_decision_table.rules[*index]))
+      .collect()
+  } else {
+    parsed_decision_table
+      .rules
+      .iter()
+      .enumerate()
+      .map(|(index, parsed_rule)| evaluate_parsed_rule(scope, index, parsed_rule))
+      .collect()
+  };
   EvaluatedDecisionTable {
+    parsed_decision_table,
+    listener: current_listener(),
     component_names: parsed_decision_table.component_names.clone(),
     output_values,
     default_output_values,
@@ -347,12 +632,54 @@ fn evaluate_parsed_decision_table(scope: &FeelScope, parsed_decision_table: &Par
   }
 }
 
+fn evaluate_parsed_rule(scope: &FeelScope, index: usize, parsed_rule: &ParsedRule) -> EvaluatedRule {
+  let mut input_entry_values = vec![];
+  let mut matches = true;
+  for evaluator in &parsed_rule.input_entries_evaluators {
+    let input_value: Value = evaluator(scope);
+    if !input_value.is_true() {
+      matches = false;
+    }
+    input_entry_values.push(input_value);
+  }
+  let mut output_entry_values = vec![];
+  for evaluator in &parsed_rule.output_entries_evaluators {
+    output_entry_values.push(evaluator(scope));
+  }
+  EvaluatedRule {
+    index,
+    matches,
+    output_entry_values,
+  }
+}
+
 pub fn build_decision_table_evaluator(scope: &FeelScope, decision_table: &DecisionTable) -> Result<Evaluator> {
+  build_evaluator(scope, decision_table, true)
+}
+
+/// Builds the decision table evaluator testing all rules sequentially, used to verify compiled decision tables.
+#[cfg(test)]
+pub(crate) fn build_sequential_decision_table_evaluator(scope: &FeelScope, decision_table: &DecisionTable) -> Result<Evaluator> {
+  build_evaluator(scope, decision_table, false)
+}
+
+/// Returns the number of nodes in the discrimination tree compiled for the decision table, if built.
+#[cfg(test)]
+pub(crate) fn discrimination_tree_node_count(scope: &FeelScope, decision_table: &DecisionTable) -> Result<Option<usize>> {
+  let parsed_decision_table = parse_decision_table(scope, decision_table, true)?;
+  Ok(parsed_decision_table.discrimination_tree.map(|discrimination_tree| discrimination_tree.root.node_count()))
+}
+
+/// Builds the decision table evaluator, compiling the decision table when `compiled` is `true`.
+fn build_evaluator(scope: &FeelScope, decision_table: &DecisionTable, compiled: bool) -> Result<Evaluator> {
   let hit_policy = decision_table.hit_policy();
-  let parsed_decision_table = parse_decision_table(scope, decision_table)?;
+  let parsed_decision_table = parse_decision_table(scope, decision_table, compiled)?;
   Ok(Box::new(move |scope: &FeelScope| {
+    if let Some(listener) = current_listener() {
+      listener.decision_table_started(&parsed_decision_table.decision_table_id);
+    }
     let evaluated_decision_table = evaluate_parsed_decision_table(scope, &parsed_decision_table);
-    match hit_policy {
+    let result = match hit_policy {
       HitPolicy::Unique => evaluated_decision_table.evaluate_hit_policy_unique(),
       HitPolicy::Any => evaluated_decision_table.evaluate_hit_policy_any(),
       HitPolicy::Priority => evaluated_decision_table.evaluate_hit_policy_priority(),
@@ -366,17 +693,22 @@ pub fn build_decision_table_evaluator(scope: &FeelScope, decision_table: &Decisi
         BuiltinAggregator::Min => evaluated_decision_table.evaluate_hit_policy_collect_min(),
         BuiltinAggregator::Max => evaluated_decision_table.evaluate_hit_policy_collect_max(),
       },
+    };
+    if let Some(listener) = &evaluated_decision_table.listener {
+      listener.decision_table_finished(&parsed_decision_table.decision_table_id, &result);
     }
+    result
   }))
 }
 
 #[cfg(test)]
 mod tests {
-  use super::build_decision_table_evaluator;
+  use super::{build_decision_table_evaluator, parse_decision_table};
   use crate::tests::context;
   use dmntk_examples::decision_tables::H_000210;
   use dmntk_feel::values::Value;
   use dmntk_feel::{value_number, FeelNumber};
+  use std::sync::Arc;
 
   #[test]
   fn test() {
@@ -385,4 +717,16 @@ mod tests {
     let evaluator = build_decision_table_evaluator(&scope, &decision_table).unwrap();
     assert_eq!(value_number!(10, 2), evaluator(&scope));
   }
+
+  #[test]
+  fn test_shared_entries() {
+    // identical entries in the same column share evaluators
+    let decision_table = dmntk_recognizer::recognize_decision_table(H_000210, false).unwrap();
+    let scope = context(r#"{Customer:"Business", Order:-3.23 }"#).into();
+    let parsed_decision_table = parse_decision_table(&scope, &decision_table, true).unwrap();
+    let rules = &parsed_decision_table.rules;
+    assert!(Arc::ptr_eq(&rules[0].input_entries_evaluators[0], &rules[1].input_entries_evaluators[0]));
+    assert!(!Arc::ptr_eq(&rules[1].input_entries_evaluators[0], &rules[2].input_entries_evaluators[0]));
+    assert!(!Arc::ptr_eq(&rules[0].input_entries_evaluators[1], &rules[1].input_entries_evaluators[1]));
+  }
 }
@@ -1,34 +1,99 @@
-use dmntk_common::{DmntkError, ToErrorMessage};
-use dmntk_feel::FeelType;
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
+use dmntk_feel::{FeelType, QualifiedName};
 
 /// Errors related to model evaluation.
 #[derive(ToErrorMessage)]
-struct ModelEvaluatorError(String);
+struct ModelEvaluatorError(Message);
 
 pub fn err_business_knowledge_model_with_reference_not_found(namespace: &str, id: &str) -> DmntkError {
-  ModelEvaluatorError(format!("no business knowledge model with reference: '{namespace}#{id}'")).into()
+  ModelEvaluatorError(message!("no business knowledge model with reference: '{}#{}'", namespace, id)).into()
 }
 
 pub fn err_empty_literal_expression() -> DmntkError {
-  ModelEvaluatorError("empty literal expression".into()).into()
+  ModelEvaluatorError(message!("empty literal expression")).into()
 }
 
 pub fn err_empty_encapsulated_logic() -> DmntkError {
-  ModelEvaluatorError("empty encapsulated logic in business knowledge model".into()).into()
+  ModelEvaluatorError(message!("empty encapsulated logic in business knowledge model")).into()
 }
 
 pub fn err_invalid_item_definition_type(s: &str) -> DmntkError {
-  ModelEvaluatorError(format!("invalid item definition type for '{s}'")).into()
+  ModelEvaluatorError(message!("invalid item definition type for '{}'", s)).into()
 }
 
 pub fn err_unsupported_feel_type(feel_type: FeelType, s: &str) -> DmntkError {
-  ModelEvaluatorError(format!("unsupported FEEL type: {feel_type} in {s}")).into()
+  ModelEvaluatorError(message!("unsupported FEEL type: {} in {}", feel_type, s)).into()
 }
 
 pub fn err_empty_feel_type() -> DmntkError {
-  ModelEvaluatorError("empty FEEL type".into()).into()
+  ModelEvaluatorError(message!("empty FEEL type")).into()
 }
 
 pub fn err_empty_function_body() -> DmntkError {
-  ModelEvaluatorError("empty function definition body".into()).into()
+  ModelEvaluatorError(message!("empty function definition body")).into()
+}
+
+pub fn err_invalid_bundle(reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  ModelEvaluatorError(message!("invalid model bundle: {msg}", reason)).into()
+}
+
+pub fn err_unsupported_bundle_version(version: u16, expected: u16) -> DmntkError {
+  ModelEvaluatorError(message!("unsupported model bundle version: {}, expected version: {}", version, expected)).into()
+}
+
+pub fn err_output_values_not_declared(hit_policy: &str) -> DmntkError {
+  ModelEvaluatorError(message!("output values must be declared for every output of decision table with hit policy {}", hit_policy)).into()
+}
+
+pub fn err_decision_table_without_outputs(decision_table_id: &str) -> DmntkError {
+  ModelEvaluatorError(message!("decision table '{}' has no output clauses", decision_table_id)).into()
+}
+
+pub fn err_invalid_number_of_rule_entries(decision_table_id: &str, rule_number: usize, kind: &str, expected: usize, actual: usize) -> DmntkError {
+  ModelEvaluatorError(message!(
+    "rule {} of decision table '{}' has {} {} entries, expected {}",
+    rule_number,
+    decision_table_id,
+    actual,
+    kind,
+    expected
+  ))
+  .into()
+}
+
+pub fn err_invocable_not_found(namespace: &str, invocable_name: &str) -> DmntkError {
+  ModelEvaluatorError(message!("invocable '{}' not found in namespace '{}'", invocable_name, namespace)).into()
+}
+
+pub fn err_invocable_types_not_defined(namespace: &str, invocable_name: &str) -> DmntkError {
+  ModelEvaluatorError(message!("types of invocable '{}' in namespace '{}' are not defined", invocable_name, namespace)).into()
+}
+
+pub fn err_not_decision_service(namespace: &str, invocable_name: &str) -> DmntkError {
+  ModelEvaluatorError(message!("invocable '{}' in namespace '{}' is not a decision service", invocable_name, namespace)).into()
+}
+
+pub fn err_missing_inputs(invocable_name: &str, missing_inputs: &[QualifiedName]) -> DmntkError {
+  ModelEvaluatorError(message!(
+    "missing input data required by invocable '{}': {}",
+    invocable_name,
+    missing_inputs.iter().map(|name| name.to_string()).collect::<Vec<String>>().join(", ")
+  ))
+  .into()
+}
+
+pub fn err_invalid_default_value(input_data_name: &str, reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  ModelEvaluatorError(message!("invalid default value of input data '{}': {msg}", input_data_name, reason)).into()
+}
+
+pub fn err_invalid_constant(constant_name: &str, reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  ModelEvaluatorError(message!("invalid value of constant '{}': {msg}", constant_name, reason)).into()
+}
+
+#[cfg(feature = "generators")]
+pub fn err_input_data_generation_failed(input_data_name: &str) -> DmntkError {
+  ModelEvaluatorError(message!("generating allowed value of input data '{}' failed", input_data_name)).into()
 }
@@ -1,10 +1,15 @@
 //! # Builder for input data evaluators
 
+use crate::errors::err_invalid_default_value;
 use crate::item_definition::ItemDefinitionEvaluator;
+use crate::item_definition_type::ItemDefinitionTypeEvaluator;
 use crate::model_definitions::{DefDefinitions, DefKey};
 use crate::variable::{Variable, VariableEvaluatorFn};
+use dmntk_common::Result;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::json::{coerce_json_value, JsonCoercion};
 use dmntk_feel::values::Value;
-use dmntk_feel::Name;
+use dmntk_feel::{FeelScope, Name};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -14,26 +19,81 @@ pub type InputDataEvaluatorEntry = (Variable, VariableEvaluatorFn);
 #[derive(Default)]
 pub struct InputDataEvaluator {
   evaluators: Arc<HashMap<DefKey, InputDataEvaluatorEntry>>,
+  /// Default values of input data, used when input data are missing.
+  defaults: Arc<HashMap<DefKey, Value>>,
 }
 
 impl InputDataEvaluator {
   /// Builds a new input data evaluator.
-  pub fn new(definitions: &DefDefinitions) -> Self {
+  pub fn new(definitions: &DefDefinitions) -> Result<Self> {
     let mut evaluators = HashMap::new();
+    let mut defaults = HashMap::new();
     for input_data in definitions.input_data() {
       let input_data_namespace = input_data.namespace();
       let input_data_id = input_data.id();
       let variable: Variable = input_data.variable().into();
       let evaluator = variable.build_evaluator();
       let def_key = DefKey::new(input_data_namespace, input_data_id);
+      if let Some(text) = input_data.default_value() {
+        let scope = FeelScope::default();
+        let default_value = dmntk_feel_parser::parse_expression(&scope, text, false)
+          .and_then(|node| dmntk_feel_evaluator::evaluate(&scope, &node))
+          .map_err(|reason| err_invalid_default_value(input_data.name(), reason))?;
+        defaults.insert(def_key.clone(), default_value);
+      }
       evaluators.insert(def_key, (variable, evaluator));
     }
-    Self { evaluators: Arc::new(evaluators) }
+    Ok(Self {
+      evaluators: Arc::new(evaluators),
+      defaults: Arc::new(defaults),
+    })
   }
 
-  /// Evaluates input data.
+  /// Returns `true` when input data with specified key has a default value.
+  pub fn has_default(&self, def_key: &DefKey) -> bool {
+    self.defaults.contains_key(def_key)
+  }
+
+  /// Updates FEEL types of input data variables, called once while building the model.
+  pub fn update_feel_types(&mut self, item_definition_type_evaluator: &ItemDefinitionTypeEvaluator) {
+    if let Some(evaluators) = Arc::get_mut(&mut self.evaluators) {
+      for (variable, _) in evaluators.values_mut() {
+        variable.update_feel_type(item_definition_type_evaluator);
+      }
+    }
+  }
+
+  /// Coerces values of input data converted from JSON to the types of input data
+  /// declared in specified namespace, returns coerced input data and performed coercions.
+  pub fn coerce_json_input(&self, namespace: &str, input_data: &FeelContext) -> (FeelContext, Vec<JsonCoercion>) {
+    let mut coerced_input_data = input_data.clone();
+    let mut coercions = vec![];
+    for (def_key, (variable, _)) in self.evaluators.iter() {
+      if def_key.namespace() != namespace {
+        continue;
+      }
+      if let Some(value) = input_data.get_entry(variable.name()) {
+        let (coerced_value, mut value_coercions) = coerce_json_value(value, variable.feel_type(), &variable.name().to_string());
+        coerced_input_data.set_entry(variable.name(), coerced_value);
+        coercions.append(&mut value_coercions);
+      }
+    }
+    coercions.sort_by(|a, b| a.path().cmp(b.path()));
+    (coerced_input_data, coercions)
+  }
+
+  /// Evaluates input data, input data missing in the context take their default values.
   pub fn evaluate(&self, def_key: &DefKey, value: &Value, item_definition_evaluator: &ItemDefinitionEvaluator) -> Option<(Name, Value)> {
-    self.evaluators.get(def_key).map(|evaluator| evaluator.1(value, item_definition_evaluator))
+    self.evaluators.get(def_key).map(|(variable, evaluator)| {
+      if let (Some(default_value), Value::Context(ctx)) = (self.defaults.get(def_key), value) {
+        if !ctx.contains_entry(variable.name()) {
+          let mut default_ctx = FeelContext::default();
+          default_ctx.set_entry(variable.name(), default_value.clone());
+          return evaluator(&Value::Context(default_ctx), item_definition_evaluator);
+        }
+      }
+      evaluator(value, item_definition_evaluator)
+    })
   }
 
   /// Returns the variable for input data definition.
@@ -59,7 +119,7 @@ mod tests {
     let definitions = dmntk_model::parse(xml).unwrap();
     let mut def_definitions = DefDefinitions::default();
     def_definitions.add_model(&definitions);
-    (InputDataEvaluator::new(&def_definitions), ItemDefinitionEvaluator::new(&def_definitions).unwrap())
+    (InputDataEvaluator::new(&def_definitions).unwrap(), ItemDefinitionEvaluator::new(&def_definitions).unwrap())
   }
 
   #[test]
@@ -68,7 +128,7 @@ mod tests {
     let context_str = r#"{Full Name: "John"}"#;
     let context = dmntk_feel_evaluator::evaluate_context(&Default::default(), context_str).unwrap();
     assert_eq!(
-      Some((Name::new(&["Full", "Name"]), Value::String("John".to_string()))),
+      Some((Name::new(&["Full", "Name"]), Value::String("John".into()))),
       input_data_evaluator.evaluate(
         &DefKey::new(NAMESPACE, "_cba86e4d-e91c-46a2-9176-e9adf88e15db"),
         &Value::Context(context),
@@ -78,7 +138,7 @@ mod tests {
     let context_str = r#"{Full Name: "Phillip"}"#;
     let context = dmntk_feel_evaluator::evaluate_context(&Default::default(), context_str).unwrap();
     assert_eq!(
-      Some((Name::new(&["Full", "Name"]), Value::String("Phillip".to_string()))),
+      Some((Name::new(&["Full", "Name"]), Value::String("Phillip".into()))),
       input_data_evaluator.evaluate(
         &DefKey::new(NAMESPACE, "_cba86e4d-e91c-46a2-9176-e9adf88e15db"),
         &Value::Context(context),
@@ -189,7 +249,7 @@ mod tests {
     let context = dmntk_feel_evaluator::evaluate_context(&Default::default(), context_str).unwrap();
     let name = Name::new(&["Employment", "Status"]);
     assert_eq!(
-      Some((name, Value::String("EMPLOYED".to_string()))),
+      Some((name, Value::String("EMPLOYED".into()))),
       input_data_evaluator.evaluate(
         &DefKey::new(NAMESPACE, "_acfd4e1d-da0a-4842-aa35-ea50dd36fb01"),
         &Value::Context(context),
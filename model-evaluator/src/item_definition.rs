@@ -5,11 +5,11 @@ use crate::model_definitions::{DefDefinitions, DefItemDefinition, DefKey};
 use dmntk_common::Result;
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::{Value, Values};
-use dmntk_feel::{value_null, Evaluator, FeelScope, FeelType, Name};
+use dmntk_feel::{report_violation, value_null, Evaluator, FeelScope, FeelType, Name};
 use dmntk_feel_evaluator::BuildContext;
 use dmntk_feel_parser::AstNode;
-use dmntk_model::ItemDefinitionType;
-use std::collections::HashMap;
+use dmntk_model::{ExpressionInstance, ItemDefinitionType};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Type of closure that evaluates input data conformant with item definition.
@@ -25,8 +25,11 @@ impl ItemDefinitionEvaluator {
   /// Creates new item definition evaluator based on provided definitions.
   pub fn new(definitions: &DefDefinitions) -> Result<Self> {
     let mut evaluators = HashMap::new();
+    let constants = evaluate_constants(definitions);
+    let no_constants = FeelContext::default();
     for item_definition in definitions.item_definitions() {
-      let evaluator = build_item_definition_evaluator(item_definition)?;
+      let constants = constants.get(item_definition.namespace()).unwrap_or(&no_constants);
+      let evaluator = build_item_definition_evaluator(item_definition, constants)?;
       let namespace = item_definition.namespace();
       let type_ref = item_definition.name();
       let def_key = DefKey::new(namespace, type_ref);
@@ -41,29 +44,85 @@ impl ItemDefinitionEvaluator {
   }
 }
 
-pub fn build_item_definition_evaluator(item_definition: &DefItemDefinition) -> Result<ItemDefinitionEvaluatorFn> {
+/// Evaluates model-level constants, that may be referenced in allowed values of item definitions.
+///
+/// Constants are decisions without any requirements, defined with literal expressions.
+/// Constants are evaluated once, while building the evaluator. Constants defined in imported
+/// models are available under the name of the import, like `limits.Max Amount`.
+/// Returns constants available in each namespace.
+pub(crate) fn evaluate_constants(definitions: &DefDefinitions) -> HashMap<String, FeelContext> {
+  let mut constants: HashMap<String, FeelContext> = HashMap::new();
+  for decision in definitions.decisions() {
+    if !decision.information_requirements().is_empty() || !decision.knowledge_requirements().is_empty() {
+      continue;
+    }
+    let Some(ExpressionInstance::LiteralExpression(literal_expression)) = decision.decision_logic() else {
+      continue;
+    };
+    let Some(text) = literal_expression.text() else {
+      continue;
+    };
+    // decisions that can not be evaluated are reported when building decision evaluators
+    let scope = FeelScope::default();
+    if let Ok(value) = dmntk_feel_parser::parse_expression(&scope, text, false).and_then(|node| dmntk_feel_evaluator::evaluate(&scope, &node)) {
+      constants.entry(decision.namespace().to_string()).or_default().set_entry(decision.variable().name(), value);
+    }
+  }
+  let namespaces = definitions
+    .item_definitions()
+    .iter()
+    .map(|item_definition| item_definition.namespace().to_string())
+    .collect::<HashSet<String>>();
+  let mut available = HashMap::new();
+  for namespace in namespaces {
+    let mut ctx = constants.get(&namespace).cloned().unwrap_or_default();
+    for import in definitions.imports() {
+      if let Some(imported) = constants.get(import.namespace()) {
+        ctx.set_entry(import.name(), Value::Context(imported.clone()));
+      }
+    }
+    if !ctx.is_empty() {
+      available.insert(namespace, ctx);
+    }
+  }
+  available
+}
+
+pub fn build_item_definition_evaluator(item_definition: &DefItemDefinition, constants: &FeelContext) -> Result<ItemDefinitionEvaluatorFn> {
   // prepare optional allowed values evaluator
-  let av_evaluator = build_allowed_values_evaluator(item_definition)?;
+  let av_evaluator = build_allowed_values_evaluator(item_definition, constants)?;
   // build item definition evaluator
   match item_definition.item_definition_type()? {
     ItemDefinitionType::SimpleType(feel_type) => build_simple_type_evaluator(feel_type, av_evaluator),
     ItemDefinitionType::ReferencedType(namespace, type_ref) => build_referenced_type_evaluator(DefKey::new(&namespace, &type_ref)),
-    ItemDefinitionType::ComponentType => build_component_type_evaluator(item_definition),
+    ItemDefinitionType::ComponentType => build_component_type_evaluator(item_definition, constants),
     ItemDefinitionType::CollectionOfSimpleType(feel_type) => build_collection_of_simple_type_evaluator(feel_type, av_evaluator),
     ItemDefinitionType::CollectionOfReferencedType(namespace, type_ref) => build_collection_of_referenced_type_evaluator(DefKey::new(&namespace, &type_ref), av_evaluator),
-    ItemDefinitionType::CollectionOfComponentType => build_collection_of_component_type_evaluator(item_definition),
+    ItemDefinitionType::CollectionOfComponentType => build_collection_of_component_type_evaluator(item_definition, constants),
     ItemDefinitionType::FunctionType => build_function_type_evaluator(),
   }
 }
 
-fn build_allowed_values_evaluator(item_definition: &DefItemDefinition) -> Result<Option<Evaluator>> {
+/// Builds the evaluator of allowed values, that may reference model-level constants.
+fn build_allowed_values_evaluator(item_definition: &DefItemDefinition, constants: &FeelContext) -> Result<Option<Evaluator>> {
   let mut av_evaluator = None;
   if let Some(unary_tests) = item_definition.allowed_values() {
     if let Some(text) = unary_tests.text() {
-      let scope = FeelScope::default();
+      let scope = FeelScope::from(constants.clone());
       let unary_tests_node = dmntk_feel_parser::parse_unary_tests(&scope, text, false)?;
       let node = AstNode::In(Box::new(AstNode::Name("?".into())), Box::new(unary_tests_node));
-      av_evaluator = Some(dmntk_feel_evaluator::prepare(&BuildContext::default(), &node)?);
+      let evaluator = dmntk_feel_evaluator::prepare(&BuildContext::default(), &node)?;
+      if constants.is_empty() {
+        av_evaluator = Some(evaluator);
+      } else {
+        let constants = constants.clone();
+        av_evaluator = Some(Box::new(move |scope: &FeelScope| {
+          scope.push(constants.clone());
+          let value = evaluator(scope);
+          scope.pop();
+          value
+        }) as Evaluator);
+      }
     }
   }
   Ok(av_evaluator)
@@ -163,29 +222,54 @@ fn build_referenced_type_evaluator(def_key: DefKey) -> Result<ItemDefinitionEval
   }))
 }
 
-fn build_component_type_evaluator(item_definition: &DefItemDefinition) -> Result<ItemDefinitionEvaluatorFn> {
-  let mut component_evaluators: Vec<(Name, ItemDefinitionEvaluatorFn)> = vec![];
-  for component_item_definition in item_definition.item_components() {
-    component_evaluators.push((component_item_definition.feel_name().clone(), build_item_definition_evaluator(component_item_definition)?));
-  }
-  let av_evaluator = build_allowed_values_evaluator(item_definition)?;
+fn build_component_type_evaluator(item_definition: &DefItemDefinition, constants: &FeelContext) -> Result<ItemDefinitionEvaluatorFn> {
+  let component_evaluators = build_component_evaluators(item_definition, constants)?;
+  let av_evaluator = build_allowed_values_evaluator(item_definition, constants)?;
   Ok(Box::new(move |value: &Value, evaluators: &ItemDefinitionEvaluator| {
     if let Value::Context(ctx) = value {
-      let mut evaluated_ctx = FeelContext::default();
-      for (component_name, component_evaluator) in &component_evaluators {
-        if let Some(component_value) = ctx.get_entry(component_name) {
-          evaluated_ctx.set_entry(component_name, component_evaluator(component_value, evaluators));
-        } else {
-          return value_null!("item definition evaluator (Component): name not found: {} in context: {}", component_name, ctx);
-        }
+      match evaluate_components(ctx, &component_evaluators, evaluators) {
+        Ok(evaluated_ctx) => check_allowed_values(Value::Context(evaluated_ctx), av_evaluator.as_ref()),
+        Err(component_name) => value_null!("item definition evaluator (Component): name not found: {} in context: {}", component_name, ctx),
       }
-      check_allowed_values(Value::Context(evaluated_ctx), av_evaluator.as_ref())
     } else {
       value_null!("item definition evaluator (Component): expected context value, actual value is: {}", value)
     }
   }))
 }
 
+/// Evaluator of an item component, with the name of the component and flag indicating optional component.
+type ComponentEvaluator = (Name, bool, ItemDefinitionEvaluatorFn);
+
+/// Builds evaluators of item components.
+fn build_component_evaluators(item_definition: &DefItemDefinition, constants: &FeelContext) -> Result<Vec<ComponentEvaluator>> {
+  let mut component_evaluators = vec![];
+  for component_item_definition in item_definition.item_components() {
+    component_evaluators.push((
+      component_item_definition.feel_name().clone(),
+      component_item_definition.is_optional(),
+      build_item_definition_evaluator(component_item_definition, constants)?,
+    ));
+  }
+  Ok(component_evaluators)
+}
+
+/// Evaluates item components of the context, optional components missing in the context are `null`.
+/// Returns the name of the first missing component that is not optional as an error.
+fn evaluate_components(ctx: &FeelContext, component_evaluators: &[ComponentEvaluator], evaluators: &ItemDefinitionEvaluator) -> std::result::Result<FeelContext, Name> {
+  let mut evaluated_ctx = FeelContext::default();
+  for (component_name, is_optional, component_evaluator) in component_evaluators {
+    if let Some(component_value) = ctx.get_entry(component_name) {
+      evaluated_ctx.set_entry(component_name, component_evaluator(component_value, evaluators));
+    } else if *is_optional {
+      evaluated_ctx.set_entry(component_name, value_null!());
+    } else {
+      report_violation(|| format!("context has no value for item component '{component_name}'"));
+      return Err(component_name.clone());
+    }
+  }
+  Ok(evaluated_ctx)
+}
+
 fn build_collection_of_simple_type_evaluator(feel_type: FeelType, av_evaluator: Option<Evaluator>) -> Result<ItemDefinitionEvaluatorFn> {
   fn build_any_evaluator(av_evaluator: Option<Evaluator>) -> Result<ItemDefinitionEvaluatorFn> {
     Ok(Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
@@ -365,26 +449,18 @@ fn build_collection_of_referenced_type_evaluator(def_key: DefKey, av_evaluator:
   }))
 }
 
-fn build_collection_of_component_type_evaluator(item_definition: &DefItemDefinition) -> Result<ItemDefinitionEvaluatorFn> {
-  let mut component_evaluators: Vec<(Name, ItemDefinitionEvaluatorFn)> = vec![];
-  for component_item_definition in item_definition.item_components() {
-    component_evaluators.push((component_item_definition.feel_name().clone(), build_item_definition_evaluator(component_item_definition)?));
-  }
-  let av_evaluator = build_allowed_values_evaluator(item_definition)?;
+fn build_collection_of_component_type_evaluator(item_definition: &DefItemDefinition, constants: &FeelContext) -> Result<ItemDefinitionEvaluatorFn> {
+  let component_evaluators = build_component_evaluators(item_definition, constants)?;
+  let av_evaluator = build_allowed_values_evaluator(item_definition, constants)?;
   Ok(Box::new(move |value: &Value, evaluators: &ItemDefinitionEvaluator| {
     if let Value::List(values) = value {
       let mut evaluated_values = Values::default();
       for item_value in values {
         if let Value::Context(ctx) = item_value {
-          let mut evaluated_ctx = FeelContext::default();
-          for (component_name, component_evaluator) in &component_evaluators {
-            if let Some(component_value) = ctx.get_entry(component_name) {
-              evaluated_ctx.set_entry(component_name, component_evaluator(component_value, evaluators));
-            } else {
-              return value_null!("name '{}' not found in context '{}'", component_name, ctx);
-            }
+          match evaluate_components(ctx, &component_evaluators, evaluators) {
+            Ok(evaluated_ctx) => evaluated_values.push(Value::Context(evaluated_ctx)),
+            Err(component_name) => return value_null!("name '{}' not found in context '{}'", component_name, ctx),
           }
-          evaluated_values.push(Value::Context(evaluated_ctx))
         } else {
           return value_null!("expected context, actual type is '{}' in value '{}'", item_value.type_of(), item_value);
         }
@@ -428,10 +504,7 @@ mod tests {
     let context_str = r#"{ Customer Name : "Whistler" }"#;
     let context = dmntk_feel_evaluator::evaluate_context(&Default::default(), context_str).unwrap();
     let value = context.get_entry(&Name::new(&["Customer", "Name"])).unwrap();
-    assert_eq!(
-      Value::String("Whistler".to_string()),
-      evaluator.eval(&DefKey::new(NAMESPACE, "tCustomerName"), value).unwrap()
-    );
+    assert_eq!(Value::String("Whistler".into()), evaluator.eval(&DefKey::new(NAMESPACE, "tCustomerName"), value).unwrap());
   }
 
   #[test]
@@ -558,10 +631,7 @@ mod tests {
     let context_str = r#"{ Customer Name : "Bloomberg" }"#;
     let context = dmntk_feel_evaluator::evaluate_context(&Default::default(), context_str).unwrap();
     let value = context.get_entry(&Name::new(&["Customer", "Name"])).unwrap();
-    assert_eq!(
-      Value::String("Bloomberg".to_string()),
-      evaluator.eval(&DefKey::new(NAMESPACE, "tCustomerName"), value).unwrap()
-    );
+    assert_eq!(Value::String("Bloomberg".into()), evaluator.eval(&DefKey::new(NAMESPACE, "tCustomerName"), value).unwrap());
   }
 
   #[test]
@@ -594,10 +664,10 @@ mod tests {
     let context = dmntk_feel_evaluator::evaluate_context(&Default::default(), context_str).unwrap();
     let value = context.get_entry(&Name::new(&["Items"])).unwrap();
     let expected = Value::List(vec![
-      Value::String("Mercury".to_string()),
-      Value::String("Venus".to_string()),
-      Value::String("Earth".to_string()),
-      Value::String("Mars".to_string()),
+      Value::String("Mercury".into()),
+      Value::String("Venus".into()),
+      Value::String("Earth".into()),
+      Value::String("Mars".into()),
     ]);
     assert_eq!(expected, evaluator.eval(&DefKey::new(NAMESPACE, "tItems"), value).unwrap());
   }
@@ -704,11 +774,7 @@ mod tests {
     let context_str = r#"{ Items : ["Mercury", "Venus", "Earth"] }"#;
     let context = dmntk_feel_evaluator::evaluate_context(&Default::default(), context_str).unwrap();
     let value = context.get_entry(&Name::new(&["Items"])).unwrap();
-    let expected = Value::List(vec![
-      Value::String("Mercury".to_string()),
-      Value::String("Venus".to_string()),
-      Value::String("Earth".to_string()),
-    ]);
+    let expected = Value::List(vec![Value::String("Mercury".into()), Value::String("Venus".into()), Value::String("Earth".into())]);
     assert_eq!(expected, evaluator.eval(&DefKey::new(NAMESPACE, "tItems"), value).unwrap());
   }
 
@@ -720,16 +786,16 @@ mod tests {
     let value = context.get_entry(&Name::new(&["Items"])).unwrap();
     let mut ctx_1 = FeelContext::default();
     ctx_1.set_entry(&"number".into(), value_number!(1));
-    ctx_1.set_entry(&"name".into(), Value::String("One".to_string()));
-    ctx_1.set_entry(&"manager".into(), Value::String("John".to_string()));
+    ctx_1.set_entry(&"name".into(), Value::String("One".into()));
+    ctx_1.set_entry(&"manager".into(), Value::String("John".into()));
     let mut ctx_2 = FeelContext::default();
     ctx_2.set_entry(&"number".into(), value_number!(2));
-    ctx_2.set_entry(&"name".into(), Value::String("Two".to_string()));
-    ctx_2.set_entry(&"manager".into(), Value::String("Mike".to_string()));
+    ctx_2.set_entry(&"name".into(), Value::String("Two".into()));
+    ctx_2.set_entry(&"manager".into(), Value::String("Mike".into()));
     let mut ctx_3 = FeelContext::default();
     ctx_3.set_entry(&"number".into(), value_number!(3));
-    ctx_3.set_entry(&"name".into(), Value::String("Three".to_string()));
-    ctx_3.set_entry(&"manager".into(), Value::String("Bob".to_string()));
+    ctx_3.set_entry(&"name".into(), Value::String("Three".into()));
+    ctx_3.set_entry(&"manager".into(), Value::String("Bob".into()));
     let expected = Value::List(vec![Value::Context(ctx_1), Value::Context(ctx_2), Value::Context(ctx_3)]);
     assert_eq!(Some(expected), evaluator.eval(&DefKey::new(NAMESPACE, "tItems"), value));
   }
@@ -7,17 +7,20 @@ use dmntk_common::Result;
 use dmntk_feel::{FeelType, Name, FEEL_TYPE_NAME_ANY};
 use dmntk_model::ItemDefinitionType;
 use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 
 /// Type of function that evaluates the item definition type.
 type ItemDefinitionTypeEvaluatorFn = Box<dyn Fn(&ItemDefinitionTypeEvaluator) -> Option<FeelType> + Send + Sync>;
 
 /// Type alias for a collection of information item types.
-pub type InformationItemTypes = HashMap<DefKey, FeelType>;
+pub type InformationItemTypes = HashMap<DefKey, Arc<FeelType>>;
 
 /// Item definition type evaluators.
 #[derive(Default)]
 pub struct ItemDefinitionTypeEvaluator {
   evaluators: HashMap<DefKey, ItemDefinitionTypeEvaluatorFn>,
+  /// Types of item definitions, evaluated once and shared between all evaluators referencing them.
+  types: HashMap<DefKey, Arc<FeelType>>,
 }
 
 impl ItemDefinitionTypeEvaluator {
@@ -31,35 +34,50 @@ impl ItemDefinitionTypeEvaluator {
       let def_key = DefKey::new(namespace, type_ref);
       evaluators.insert(def_key, evaluator);
     }
-    Ok(Self { evaluators })
+    let mut item_definition_type_evaluator = Self {
+      evaluators,
+      types: HashMap::new(),
+    };
+    let mut types = HashMap::new();
+    for def_key in item_definition_type_evaluator.evaluators.keys() {
+      if let Some(feel_type) = item_definition_type_evaluator.eval(def_key) {
+        types.insert(def_key.clone(), Arc::new(feel_type));
+      }
+    }
+    item_definition_type_evaluator.types = types;
+    Ok(item_definition_type_evaluator)
   }
 
   /// Returns FEEL type for specified type reference.
   pub fn information_item_type(&self, namespace: &str, type_ref: &str) -> Option<FeelType> {
+    self.shared_information_item_type(namespace, type_ref).map(|feel_type| feel_type.as_ref().clone())
+  }
+
+  /// Returns shared FEEL type for specified type reference.
+  ///
+  /// Types of item definitions are evaluated only once, so all
+  /// information items referencing the same item definition share the same type.
+  pub fn shared_information_item_type(&self, namespace: &str, type_ref: &str) -> Option<Arc<FeelType>> {
     if type_ref == FEEL_TYPE_NAME_ANY {
-      return Some(FeelType::Any);
+      return Some(Arc::new(FeelType::Any));
     }
     if let Some(simple_type_ref) = type_ref_to_feel_type(type_ref) {
-      Some(simple_type_ref.clone())
+      Some(Arc::new(simple_type_ref.clone()))
     } else {
-      self.eval(&DefKey::new(namespace, type_ref))
+      self.types.get(&DefKey::new(namespace, type_ref)).cloned()
     }
   }
 
   /// Returns a map of all types for model defined information items.
   pub fn information_item_types(&self) -> InformationItemTypes {
-    let mut item_types = HashMap::new();
-    for def_key in self.evaluators.keys() {
-      if let Some(feel_type) = self.information_item_type(def_key.namespace(), def_key.id()) {
-        item_types.insert(def_key.clone(), feel_type);
-      }
-    }
-    item_types
+    self.types.clone()
   }
 
   /// Evaluates a type of the item definition with specified key.
   fn eval(&self, def_key: &DefKey) -> Option<FeelType> {
-    if let Some(evaluator) = self.evaluators.get(def_key) {
+    if let Some(feel_type) = self.types.get(def_key) {
+      Some(feel_type.as_ref().clone())
+    } else if let Some(evaluator) = self.evaluators.get(def_key) {
       evaluator(self)
     } else {
       None
@@ -333,4 +351,13 @@ mod tests {
     let list_type = FeelType::list(&component_type);
     assert_eq!(Some(list_type), evaluator.eval(&DefKey::new(NAMESPACE, "tItems")));
   }
+
+  #[test]
+  fn shared_information_item_type() {
+    let evaluator = build_evaluator(DMN_0601);
+    let type_a = evaluator.shared_information_item_type(NAMESPACE, "tItems").unwrap();
+    let type_b = evaluator.shared_information_item_type(NAMESPACE, "tItems").unwrap();
+    assert!(std::sync::Arc::ptr_eq(&type_a, &type_b));
+    assert_eq!(evaluator.information_item_type(NAMESPACE, "tItems"), Some(type_a.as_ref().clone()));
+  }
 }
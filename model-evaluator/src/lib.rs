@@ -2,7 +2,11 @@
 extern crate dmntk_macros;
 
 mod boxed_expressions;
+mod bundle;
 mod business_knowledge_model;
+mod constants;
+#[cfg(feature = "generators")]
+mod data_generator;
 mod decision;
 mod decision_service;
 mod decision_table;
@@ -12,17 +16,29 @@ mod input_data_context;
 mod item_definition;
 mod item_definition_context;
 mod item_definition_type;
+mod listener;
 mod model_builder;
 mod model_definitions;
 mod model_evaluator;
+mod profiler;
+mod requirements;
+mod self_test;
+mod simulation;
+mod statistics;
 mod type_ref;
 mod variable;
 
 #[cfg(test)]
 mod tests;
 
+pub use bundle::{build_bundle, load_bundle};
 pub use decision_table::build_decision_table_evaluator;
+pub use listener::{EvaluationListener, FiredRule};
 pub use model_evaluator::ModelEvaluator;
+pub use profiler::{EvaluationProfile, EvaluationProfiler, ProfileEntry};
+pub use self_test::{is_self_test, SelfTestResult, SELF_TEST_PREFIX};
+pub use simulation::{Simulation, SimulationRow};
+pub use statistics::BuildStatistics;
 
 #[cfg(test)]
 mod utilities {
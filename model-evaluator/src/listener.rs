@@ -0,0 +1,115 @@
+//! # Evaluation listeners
+//!
+//! Listeners are notified about rules of decision tables fired while evaluating
+//! invocables and about decisions resulting in `null`, so the reasons of the result
+//! may be traced or audited. Listeners are also notified when evaluating decisions,
+//! invocations of business knowledge models and decision tables start and finish,
+//! so the time spent on each of them may be measured.
+//! A listener is registered for a single evaluation on the current thread,
+//! so evaluating the same model without a listener has no additional cost.
+
+use dmntk_feel::values::Value;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Rule of a decision table that contributed to the result of the decision table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FiredRule {
+  /// Identifier of the decision table.
+  decision_table_id: String,
+  /// Number of the rule in the decision table, rules are numbered from 1.
+  rule_number: usize,
+  /// Optional description of the rule.
+  description: Option<String>,
+  /// Names of annotation clauses with annotation entries of the rule.
+  annotations: Vec<(String, String)>,
+}
+
+impl FiredRule {
+  /// Creates a fired rule.
+  pub fn new(decision_table_id: &str, rule_number: usize, description: Option<String>, annotations: Vec<(String, String)>) -> Self {
+    Self {
+      decision_table_id: decision_table_id.to_string(),
+      rule_number,
+      description,
+      annotations,
+    }
+  }
+
+  /// Returns the identifier of the decision table.
+  pub fn decision_table_id(&self) -> &str {
+    &self.decision_table_id
+  }
+
+  /// Returns the number of the rule in the decision table, rules are numbered from 1.
+  pub fn rule_number(&self) -> usize {
+    self.rule_number
+  }
+
+  /// Returns the optional description of the rule.
+  pub fn description(&self) -> Option<&str> {
+    self.description.as_deref()
+  }
+
+  /// Returns names of annotation clauses with annotation entries of the rule, in column order.
+  pub fn annotations(&self) -> &[(String, String)] {
+    &self.annotations
+  }
+
+  /// Returns the annotation entry of the rule in the annotation clause with specified name.
+  pub fn annotation(&self, name: &str) -> Option<&str> {
+    self.annotations.iter().find(|(annotation_name, _)| annotation_name == name).map(|(_, text)| text.as_str())
+  }
+}
+
+/// Listener notified about events occurring while evaluating a model.
+pub trait EvaluationListener {
+  /// Called when a rule of a decision table fires.
+  fn rule_fired(&self, fired_rule: &FiredRule);
+
+  /// Called when a decision results in `null` with a reason, causes are ordered
+  /// from the reason of the result to the reason of the original `null` value.
+  fn null_produced(&self, _output_name: &str, _causes: &[&str]) {}
+
+  /// Called when evaluating a decision starts, before its requirements are evaluated.
+  fn decision_started(&self, _decision_name: &str) {}
+
+  /// Called when evaluating a decision finishes, with the result of the decision.
+  fn decision_finished(&self, _decision_name: &str, _result: &Value) {}
+
+  /// Called when the function defined by a business knowledge model is invoked.
+  fn bkm_started(&self, _bkm_name: &str) {}
+
+  /// Called when the invocation of the function defined by a business knowledge model finishes, with its result.
+  fn bkm_finished(&self, _bkm_name: &str, _result: &Value) {}
+
+  /// Called when evaluating a decision table starts.
+  fn decision_table_started(&self, _decision_table_id: &str) {}
+
+  /// Called when evaluating a decision table finishes, with the result of the decision table.
+  fn decision_table_finished(&self, _decision_table_id: &str, _result: &Value) {}
+}
+
+thread_local! {
+  /// Listeners registered for evaluations in progress on the current thread.
+  static LISTENERS: RefCell<Vec<Rc<dyn EvaluationListener>>> = const { RefCell::new(vec![]) };
+}
+
+/// Registers the listener for the duration of specified evaluation on the current thread.
+pub(crate) fn with_listener<T>(listener: Rc<dyn EvaluationListener>, evaluate: impl FnOnce() -> T) -> T {
+  /// Unregisters the listener also when the evaluation panics.
+  struct Registration;
+  impl Drop for Registration {
+    fn drop(&mut self) {
+      LISTENERS.with(|listeners| listeners.borrow_mut().pop());
+    }
+  }
+  LISTENERS.with(|listeners| listeners.borrow_mut().push(listener));
+  let _registration = Registration;
+  evaluate()
+}
+
+/// Returns the listener registered for the evaluation in progress, if any.
+pub(crate) fn current_listener() -> Option<Rc<dyn EvaluationListener>> {
+  LISTENERS.with(|listeners| listeners.borrow().last().cloned())
+}
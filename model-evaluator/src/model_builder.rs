@@ -1,4 +1,7 @@
 use crate::business_knowledge_model::BusinessKnowledgeModelEvaluator;
+use crate::constants::ConstantsEvaluator;
+#[cfg(feature = "generators")]
+use crate::data_generator::DataGenerator;
 use crate::decision::DecisionEvaluator;
 use crate::decision_service::DecisionServiceEvaluator;
 use crate::input_data::InputDataEvaluator;
@@ -7,10 +10,14 @@ use crate::item_definition::ItemDefinitionEvaluator;
 use crate::item_definition_context::ItemDefinitionContextEvaluator;
 use crate::item_definition_type::{InformationItemTypes, ItemDefinitionTypeEvaluator};
 use crate::model_definitions::{DefDefinitions, DefKey, Invocables};
+use crate::requirements::Requirements;
+use crate::statistics::BuildStatistics;
 use dmntk_common::Result;
+use dmntk_feel::context::FeelContext;
 use dmntk_feel::Name;
 use dmntk_model::Definitions;
 use std::cell::RefCell;
+use std::time::Instant;
 
 pub struct EvaluatorBuilders {
   pub input_data_evaluator: InputDataEvaluator,
@@ -20,6 +27,10 @@ pub struct EvaluatorBuilders {
   pub decision_service_evaluator: DecisionServiceEvaluator,
   pub invocables: Invocables,
   pub information_item_types: InformationItemTypes,
+  pub requirements: Requirements,
+  #[cfg(feature = "generators")]
+  pub data_generator: DataGenerator,
+  pub statistics: BuildStatistics,
 }
 
 /// Model builder.
@@ -27,6 +38,10 @@ pub struct EvaluatorBuilders {
 pub struct ModelBuilder {
   /// Model definitions.
   model_definitions: DefDefinitions,
+  /// Constants visible in all models.
+  global_constants: FeelContext,
+  /// Named constants evaluator builder.
+  constants_evaluator: ConstantsEvaluator,
   /// Input data evaluator builder.
   input_data_evaluator: InputDataEvaluator,
   /// Input data context evaluator builder.
@@ -45,6 +60,13 @@ pub struct ModelBuilder {
   decision_service_evaluator: DecisionServiceEvaluator,
   /// Map of invocables indexed by invocable name.
   invocables: RefCell<Invocables>,
+  /// Requirements of decisions and decision services.
+  requirements: Requirements,
+  /// Generator of input data values.
+  #[cfg(feature = "generators")]
+  data_generator: DataGenerator,
+  /// Durations of build phases.
+  statistics: BuildStatistics,
 }
 
 impl ModelBuilder {
@@ -53,19 +75,49 @@ impl ModelBuilder {
     self.model_definitions.add_model(definitions);
   }
 
+  /// Adds constants visible in all models, replacing constants with the same names.
+  pub fn add_constants(&mut self, constants: &FeelContext) {
+    self.global_constants.zip(constants);
+  }
+
   /// Builds a model based on model definitions.
   pub fn build(&mut self) -> Result<()> {
-    self.input_data_evaluator = InputDataEvaluator::new(&self.model_definitions);
+    let started = Instant::now();
+    self.constants_evaluator = ConstantsEvaluator::new(&self.model_definitions, &self.global_constants)?;
+    self.statistics.add_phase("constants", started);
+    let started = Instant::now();
+    self.input_data_evaluator = InputDataEvaluator::new(&self.model_definitions)?;
     self.input_data_context_evaluator = InputDataContextEvaluator::new(&self.model_definitions);
+    self.statistics.add_phase("input data", started);
+    let started = Instant::now();
     self.item_definition_evaluator = ItemDefinitionEvaluator::new(&self.model_definitions)?;
     self.item_definition_context_evaluator = ItemDefinitionContextEvaluator::new(&self.model_definitions)?;
     self.item_definition_type_evaluator = ItemDefinitionTypeEvaluator::new(&self.model_definitions)?;
+    self.input_data_evaluator.update_feel_types(&self.item_definition_type_evaluator);
+    self.statistics.add_phase("item definitions", started);
+    let started = Instant::now();
     self.business_knowledge_model_evaluator = BusinessKnowledgeModelEvaluator::new(&self.model_definitions, self)?;
+    self.statistics.add_phase("business knowledge models", started);
+    let started = Instant::now();
     self.decision_evaluator = DecisionEvaluator::new(&self.model_definitions, self)?;
+    self.statistics.add_phase("decisions", started);
+    let started = Instant::now();
     self.decision_service_evaluator = DecisionServiceEvaluator::new(&self.model_definitions, self)?;
+    self.statistics.add_phase("decision services", started);
+    let started = Instant::now();
+    self.requirements = Requirements::new(&self.model_definitions);
+    #[cfg(feature = "generators")]
+    {
+      self.data_generator = DataGenerator::new(&self.model_definitions);
+    }
+    self.statistics.add_phase("requirements", started);
     Ok(())
   }
 
+  pub fn constants_evaluator(&self) -> &ConstantsEvaluator {
+    &self.constants_evaluator
+  }
+
   pub fn input_data_evaluator(&self) -> &InputDataEvaluator {
     &self.input_data_evaluator
   }
@@ -113,6 +165,10 @@ impl From<ModelBuilder> for EvaluatorBuilders {
       decision_service_evaluator: value.decision_service_evaluator,
       invocables: value.invocables.into_inner(),
       information_item_types: value.item_definition_type_evaluator.information_item_types(),
+      requirements: value.requirements,
+      #[cfg(feature = "generators")]
+      data_generator: value.data_generator,
+      statistics: value.statistics,
     }
   }
 }
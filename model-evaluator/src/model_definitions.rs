@@ -121,12 +121,11 @@ impl DefInformationItem {
   pub fn new(information_item: &InformationItem, imports: &[DefImport]) -> Self {
     let type_ref_name = Name::from(information_item.type_ref().clone());
     let qname = QualifiedName::from(type_ref_name);
-    if qname.len() == 2 {
+    if let (2, Some(import_name), Some(type_ref_name)) = (qname.len(), qname.first(), qname.get(1)) {
       // type reference is prefixed with the import name
-      let import_name = qname.first().unwrap(); // unwrap is safe
       let namespace = get_import_namespace(import_name, imports).unwrap_or(information_item.namespace().to_string());
       let name = information_item.feel_name().clone();
-      let type_ref = qname.get(1).unwrap().to_string(); // unwrap is safe
+      let type_ref = type_ref_name.to_string();
       Self { namespace, name, type_ref }
     } else {
       // type reference has no import prefix
@@ -160,6 +159,7 @@ pub struct DefInputData {
   id: String,
   name: String,
   variable: DefInformationItem,
+  default_value: Option<String>,
 }
 
 impl DefInputData {
@@ -170,6 +170,7 @@ impl DefInputData {
       id: input_data.id().to_string(),
       name: input_data.name().to_string(),
       variable: DefInformationItem::new(input_data.variable(), imports),
+      default_value: input_data.default_value().clone(),
     }
   }
 }
@@ -194,6 +195,43 @@ impl DefInputData {
   pub fn variable(&self) -> &DefInformationItem {
     &self.variable
   }
+
+  /// Returns a reference to optional FEEL expression defining the default value.
+  pub fn default_value(&self) -> &Option<String> {
+    &self.default_value
+  }
+}
+
+pub struct DefConstant {
+  namespace: String,
+  name: Name,
+  text: String,
+}
+
+impl DefConstant {
+  /// Creates [DefConstant] from [Constant] defined in specified namespace.
+  pub fn new(namespace: &str, constant: &Constant) -> Self {
+    Self {
+      namespace: namespace.to_string(),
+      name: constant.feel_name().clone(),
+      text: constant.text().to_string(),
+    }
+  }
+
+  /// Returns the namespace.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns a reference to the FEEL name.
+  pub fn name(&self) -> &Name {
+    &self.name
+  }
+
+  /// Returns a reference to FEEL expression defining the value.
+  pub fn text(&self) -> &str {
+    &self.text
+  }
 }
 
 pub struct DefItemDefinition {
@@ -206,6 +244,7 @@ pub struct DefItemDefinition {
   item_components: Vec<DefItemDefinition>,
   function_item: Option<FunctionItem>,
   is_collection: bool,
+  is_optional: bool,
 }
 
 impl DefItemDefinition {
@@ -220,6 +259,7 @@ impl DefItemDefinition {
       item_components: item_definition.item_components().iter().map(DefItemDefinition::new).collect(),
       function_item: item_definition.function_item().clone(),
       is_collection: item_definition.is_collection(),
+      is_optional: item_definition.is_optional(),
     }
   }
 }
@@ -271,27 +311,29 @@ impl DefItemDefinition {
     self.is_collection
   }
 
+  /// Returns `true` when this item component may be absent in values of the enclosing item definition.
+  pub fn is_optional(&self) -> bool {
+    self.is_optional
+  }
+
   /// Returns the item definition type.
   pub fn item_definition_type(&self) -> Result<ItemDefinitionType> {
     let simple_type_ref = if let Some(type_ref) = self.type_ref() { type_ref_to_feel_type(type_ref) } else { None };
     let condition = (
-      self.type_ref().is_some(),
-      simple_type_ref.is_some(),
+      self.type_ref(),
+      simple_type_ref,
       !self.item_components().is_empty(),
       self.is_collection(),
       self.function_item().is_some(),
     );
     match condition {
-      (_, true, false, false, false) => Ok(ItemDefinitionType::SimpleType(simple_type_ref.unwrap().clone())),
-      (true, false, false, false, false) => Ok(ItemDefinitionType::ReferencedType(self.namespace.clone(), self.type_ref().as_ref().unwrap().clone())),
-      (false, false, true, false, false) => Ok(ItemDefinitionType::ComponentType),
-      (_, true, false, true, false) => Ok(ItemDefinitionType::CollectionOfSimpleType(simple_type_ref.unwrap().clone())),
-      (false, false, true, true, false) => Ok(ItemDefinitionType::CollectionOfComponentType),
-      (true, false, false, true, false) => Ok(ItemDefinitionType::CollectionOfReferencedType(
-        self.namespace.clone(),
-        self.type_ref().as_ref().unwrap().clone(),
-      )),
-      (false, false, false, false, true) => Ok(ItemDefinitionType::FunctionType),
+      (_, Some(feel_type), false, false, false) => Ok(ItemDefinitionType::SimpleType(feel_type.clone())),
+      (Some(type_ref), None, false, false, false) => Ok(ItemDefinitionType::ReferencedType(self.namespace.clone(), type_ref.clone())),
+      (None, None, true, false, false) => Ok(ItemDefinitionType::ComponentType),
+      (_, Some(feel_type), false, true, false) => Ok(ItemDefinitionType::CollectionOfSimpleType(feel_type.clone())),
+      (None, None, true, true, false) => Ok(ItemDefinitionType::CollectionOfComponentType),
+      (Some(type_ref), None, false, true, false) => Ok(ItemDefinitionType::CollectionOfReferencedType(self.namespace.clone(), type_ref.clone())),
+      (None, None, false, false, true) => Ok(ItemDefinitionType::FunctionType),
       _ => Err(err_invalid_item_definition_type(self.name())),
     }
   }
@@ -590,6 +632,16 @@ impl DefImport {
       name: import.feel_name().clone(),
     }
   }
+
+  /// Returns the namespace of imported model.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the name of the import.
+  pub fn name(&self) -> &Name {
+    &self.name
+  }
 }
 
 /// All definitions needed to build complete model evaluator from DMN model.
@@ -607,6 +659,8 @@ pub struct DefDefinitions {
   decisions: HashMap<DefKey, DefDecision>,
   /// Decision services.
   decision_services: HashMap<DefKey, DefDecisionService>,
+  /// Named constants, in the order of definition.
+  constants: Vec<DefConstant>,
 }
 
 impl DefDefinitions {
@@ -616,6 +670,9 @@ impl DefDefinitions {
     self
       .item_definitions
       .append(&mut definitions.item_definitions().iter().map(DefItemDefinition::new).collect());
+    self
+      .constants
+      .extend(definitions.constants().iter().map(|constant| DefConstant::new(definitions.namespace(), constant)));
     for drg_element in definitions.drg_elements() {
       match drg_element {
         DrgElement::InputData(inner) => {
@@ -639,11 +696,21 @@ impl DefDefinitions {
     }
   }
 
+  /// Returns imports.
+  pub fn imports(&self) -> &Vec<DefImport> {
+    &self.imports
+  }
+
   /// Returns item definitions.
   pub fn item_definitions(&self) -> &Vec<DefItemDefinition> {
     &self.item_definitions
   }
 
+  /// Returns named constants.
+  pub fn constants(&self) -> &Vec<DefConstant> {
+    &self.constants
+  }
+
   /// Returns references to decisions.
   pub fn decisions(&self) -> Vec<&DefDecision> {
     self.decisions.values().collect()
@@ -1,20 +1,44 @@
 //! # Decision model evaluator
 
 use crate::business_knowledge_model::BusinessKnowledgeModelEvaluator;
+#[cfg(feature = "generators")]
+use crate::data_generator::{is_accepted, DataGenerator};
 use crate::decision::DecisionEvaluator;
 use crate::decision_service::DecisionServiceEvaluator;
+#[cfg(feature = "generators")]
+use crate::errors::err_input_data_generation_failed;
+use crate::errors::{err_invocable_not_found, err_invocable_types_not_defined, err_missing_inputs, err_not_decision_service};
 use crate::input_data::InputDataEvaluator;
 use crate::item_definition::ItemDefinitionEvaluator;
+use crate::listener::{with_listener, EvaluationListener};
 use crate::model_builder::{EvaluatorBuilders, ModelBuilder};
 use crate::model_definitions::{DefKey, InvocableType, Invocables};
+use crate::requirements::Requirements;
+use crate::self_test::{is_self_test, SelfTestResult};
+use crate::simulation::Simulation;
+use crate::statistics::BuildStatistics;
 use dmntk_common::Result;
 use dmntk_feel::context::FeelContext;
+#[cfg(feature = "generators")]
+use dmntk_feel::generators::FeelGenerator;
+use dmntk_feel::json::JsonCoercion;
 use dmntk_feel::values::Value;
-use dmntk_feel::{value_null, Name};
+use dmntk_feel::{evaluate_at_conformance_level, evaluate_in_mode, value_null, ConformanceLevel, EvaluationMode, FeelType, Name, QualifiedName, ScopeBuilder};
+use dmntk_feel_evaluator::{evaluate_with_environment, EnvironmentResolver};
 use dmntk_model::Definitions;
+use std::collections::BTreeMap;
+use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Instant;
+
+/// Maximum number of attempts to generate a value of input data that is allowed.
+#[cfg(feature = "generators")]
+const MAX_GENERATION_ATTEMPTS: usize = 100;
 
 /// Model evaluator.
+///
+/// Model evaluator is immutable after it is built, so a single instance
+/// may be shared between threads and evaluated concurrently without locking.
 pub struct ModelEvaluator {
   /// Input data evaluator.
   input_data_evaluator: InputDataEvaluator,
@@ -30,15 +54,31 @@ pub struct ModelEvaluator {
   invocables: Invocables,
   /// Map of global information item types defined in this model evaluator.
   global_context: FeelContext,
+  /// Requirements of decisions and decision services.
+  requirements: Requirements,
+  /// Generator of input data values.
+  #[cfg(feature = "generators")]
+  data_generator: DataGenerator,
+  /// Conformance level the models were validated at and are evaluated at.
+  conformance_level: ConformanceLevel,
+  /// Statistics collected while building this model evaluator.
+  build_statistics: BuildStatistics,
 }
 
+/// Compile-time check that [ModelEvaluator] may be shared between threads.
+const _: () = {
+  const fn assert_send_sync<T: Send + Sync>() {}
+  assert_send_sync::<ModelEvaluator>();
+  assert_send_sync::<Arc<ModelEvaluator>>();
+};
+
 impl From<ModelBuilder> for ModelEvaluator {
   /// Creates [ModelEvaluator] from provided [ModelBuilder].
   fn from(model_builder: ModelBuilder) -> Self {
     let builders: EvaluatorBuilders = model_builder.into();
     let mut global_context = FeelContext::default();
     for (def_key, feel_type) in builders.information_item_types {
-      global_context.set_entry(&Name::from(def_key.id()), Value::FeelType(feel_type))
+      global_context.set_entry(&Name::from(def_key.id()), Value::FeelType(feel_type.as_ref().clone()))
     }
     Self {
       input_data_evaluator: builders.input_data_evaluator,
@@ -48,6 +88,11 @@ impl From<ModelBuilder> for ModelEvaluator {
       decision_service_evaluator: builders.decision_service_evaluator,
       invocables: builders.invocables,
       global_context,
+      requirements: builders.requirements,
+      #[cfg(feature = "generators")]
+      data_generator: builders.data_generator,
+      conformance_level: ConformanceLevel::default(),
+      build_statistics: builders.statistics,
     }
   }
 }
@@ -55,14 +100,60 @@ impl From<ModelBuilder> for ModelEvaluator {
 impl ModelEvaluator {
   /// Creates an instance of [ModelEvaluator] from parsed [Definitions].
   pub fn new(definitions: &[Definitions]) -> Result<Arc<Self>> {
+    Self::new_with_constants(definitions, &FeelContext::default())
+  }
+
+  /// Creates an instance of [ModelEvaluator] from parsed [Definitions],
+  /// with named constants visible in all expressions of all models.
+  ///
+  /// Constants defined in models take precedence over specified constants.
+  pub fn new_with_constants(definitions: &[Definitions], constants: &FeelContext) -> Result<Arc<Self>> {
+    Self::build(definitions, constants, ConformanceLevel::default())
+  }
+
+  /// Creates an instance of [ModelEvaluator] from parsed [Definitions],
+  /// restricted to specified conformance level.
+  ///
+  /// Models using constructs not defined at specified conformance level are rejected,
+  /// all invocables are evaluated at specified conformance level.
+  pub fn new_at_conformance_level(definitions: &[Definitions], level: ConformanceLevel) -> Result<Arc<Self>> {
+    dmntk_model::validate_conformance_level(definitions, level)?;
+    Self::build(definitions, &FeelContext::default(), level)
+  }
+
+  /// Builds an instance of [ModelEvaluator] evaluating invocables at specified conformance level.
+  fn build(definitions: &[Definitions], constants: &FeelContext, level: ConformanceLevel) -> Result<Arc<Self>> {
+    let mut statistics = BuildStatistics::new(definitions);
+    let prepared_count = dmntk_feel_evaluator::prepared_count();
+    let started = Instant::now();
+    dmntk_model::validate_requirements(definitions)?;
+    statistics.add_phase("validation", started);
+    let started = Instant::now();
     let mut model_builder = ModelBuilder::default();
+    model_builder.add_constants(constants);
     definitions.iter().for_each(|definitions| model_builder.add_model(definitions));
+    statistics.add_phase("definitions", started);
     model_builder.build()?;
-    let model_evaluator: Arc<ModelEvaluator> = Arc::new(model_builder.into());
+    let mut model_evaluator: ModelEvaluator = model_builder.into();
+    model_evaluator.conformance_level = level;
+    statistics.append_phases(std::mem::take(&mut model_evaluator.build_statistics));
+    statistics.set_compiled_expressions(dmntk_feel_evaluator::prepared_count() - prepared_count);
+    model_evaluator.build_statistics = statistics;
+    let model_evaluator = Arc::new(model_evaluator);
     model_evaluator.decision_service_evaluator.build_function_definitions(&Arc::clone(&model_evaluator));
     Ok(model_evaluator)
   }
 
+  /// Returns statistics collected while building this model evaluator.
+  pub fn build_statistics(&self) -> &BuildStatistics {
+    &self.build_statistics
+  }
+
+  /// Returns the conformance level invocables are evaluated at.
+  pub fn conformance_level(&self) -> ConformanceLevel {
+    self.conformance_level
+  }
+
   /// Returns a reference to input data evaluator.
   pub fn input_data_evaluator(&self) -> &InputDataEvaluator {
     &self.input_data_evaluator
@@ -98,7 +189,7 @@ impl ModelEvaluator {
     let Some(invocable) = self.invocables.by_name(namespace, invocable_name) else {
       return value_null!("invocable '{}' not found in namespace '{}'", invocable_name, namespace);
     };
-    match invocable {
+    evaluate_at_conformance_level(self.conformance_level, || match invocable {
       InvocableType::Decision(def_key) => {
         // evaluate a decision
         self.evaluate_decision(def_key, input_data)
@@ -111,7 +202,176 @@ impl ModelEvaluator {
         // evaluate a decision service
         self.evaluate_decision_service(def_key, input_data)
       }
+    })
+  }
+
+  /// Evaluates an invocable, notifying specified listener about rules of decision tables fired during evaluation.
+  pub fn evaluate_invocable_with_listener(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext, listener: Rc<dyn EvaluationListener>) -> Value {
+    with_listener(listener, || self.evaluate_invocable(namespace, invocable_name, input_data))
+  }
+
+  /// Evaluates an invocable, resolving values of `env` function with specified resolver.
+  pub fn evaluate_invocable_with_environment(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext, resolver: Rc<dyn EnvironmentResolver>) -> Value {
+    evaluate_with_environment(resolver, || self.evaluate_invocable(namespace, invocable_name, input_data))
+  }
+
+  /// Evaluates an invocable, taking values of input data missing in input data from specified defaults.
+  ///
+  /// Defaults take precedence over default values of input data defined in the model.
+  pub fn evaluate_invocable_with_input_defaults(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext, defaults: &FeelContext) -> Value {
+    let mut input_data_with_defaults = defaults.clone();
+    input_data_with_defaults.zip(input_data);
+    self.evaluate_invocable(namespace, invocable_name, &input_data_with_defaults)
+  }
+
+  /// Evaluates an invocable with input data taken from layered scope.
+  ///
+  /// Request inputs shadow model variables, model variables shadow workspace globals.
+  pub fn evaluate_invocable_in_scope(&self, namespace: &str, invocable_name: &str, scope: &ScopeBuilder) -> Value {
+    self.evaluate_invocable(namespace, invocable_name, &scope.flatten())
+  }
+
+  /// Evaluates an invocable in specified mode.
+  ///
+  /// In strict mode, type coercion failures and missing context entries
+  /// fail the evaluation with diagnostics instead of producing `null` values.
+  pub fn evaluate_invocable_in_mode(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext, mode: EvaluationMode) -> Result<Value> {
+    evaluate_in_mode(mode, || self.evaluate_invocable(namespace, invocable_name, input_data))
+  }
+
+  /// Returns qualified names of input data required to evaluate an invocable, ordered by name.
+  ///
+  /// Input data are required transitively by all decisions the invocable depends on.
+  /// Business knowledge models take their inputs as parameters, so they require no input data.
+  pub fn required_inputs(&self, namespace: &str, invocable_name: &str) -> Result<Vec<QualifiedName>> {
+    match self.invocables.by_name(namespace, invocable_name) {
+      Some(InvocableType::Decision(def_key)) => Ok(self.requirements.decision_inputs(def_key)),
+      Some(InvocableType::BusinessKnowledgeModel(_, _)) => Ok(vec![]),
+      Some(InvocableType::DecisionService(def_key)) => Ok(self.requirements.decision_service_inputs(def_key)),
+      None => Err(err_invocable_not_found(namespace, invocable_name)),
+    }
+  }
+
+  /// Returns formal parameters and the output type of the decision service.
+  ///
+  /// Parameters are ordered like parameters of the decision service invoked as a function,
+  /// input data are followed by input decisions.
+  pub fn decision_service_signature(&self, namespace: &str, invocable_name: &str) -> Result<(Vec<(Name, FeelType)>, FeelType)> {
+    match self.invocables.by_name(namespace, invocable_name) {
+      Some(InvocableType::DecisionService(def_key)) => self
+        .decision_service_evaluator
+        .signature(def_key)
+        .ok_or_else(|| err_invocable_not_found(namespace, invocable_name)),
+      Some(_) => Err(err_not_decision_service(namespace, invocable_name)),
+      None => Err(err_invocable_not_found(namespace, invocable_name)),
+    }
+  }
+
+  /// Returns the type of input data of an invocable and the type of its result.
+  ///
+  /// Input data are described by a context type with entries named after input data required
+  /// by decisions or after input parameters of decision services, input data of imported models
+  /// are nested in contexts named after the imports. Business knowledge models evaluate
+  /// to functions invoked with parameters, so their types are not defined.
+  pub fn invocable_types(&self, namespace: &str, invocable_name: &str) -> Result<(FeelType, FeelType)> {
+    match self.invocables.by_name(namespace, invocable_name) {
+      Some(InvocableType::Decision(def_key)) => {
+        let mut input_types = BTreeMap::new();
+        for (qname, input_data_key) in self.requirements.decision_input_data(def_key) {
+          if let Some(variable) = self.input_data_evaluator.get_variable(&input_data_key) {
+            insert_nested_type(&mut input_types, &qname, variable.feel_type().as_ref().clone());
+          }
+        }
+        let output_type = self
+          .decision_evaluator
+          .get_variable(def_key)
+          .map(|variable| variable.feel_type().as_ref().clone())
+          .unwrap_or(FeelType::Any);
+        Ok((FeelType::Context(input_types), output_type))
+      }
+      Some(InvocableType::BusinessKnowledgeModel(_, _)) => Err(err_invocable_types_not_defined(namespace, invocable_name)),
+      Some(InvocableType::DecisionService(_)) => {
+        let (parameters, output_type) = self.decision_service_signature(namespace, invocable_name)?;
+        Ok((FeelType::Context(parameters.into_iter().collect()), output_type))
+      }
+      None => Err(err_invocable_not_found(namespace, invocable_name)),
+    }
+  }
+
+  /// Evaluates an invocable after checking that all input data required by the invocable are provided.
+  ///
+  /// Reports all missing input data in a single error, instead of evaluating them to `null`.
+  pub fn evaluate_invocable_with_required_inputs(&self, namespace: &str, invocable_name: &str, input_data: &FeelContext) -> Result<Value> {
+    let missing_inputs = Requirements::missing(self.required_inputs(namespace, invocable_name)?, input_data);
+    if !missing_inputs.is_empty() {
+      return Err(err_missing_inputs(invocable_name, &missing_inputs));
     }
+    Ok(self.evaluate_invocable(namespace, invocable_name, input_data))
+  }
+
+  /// Generates random input data required to evaluate an invocable.
+  ///
+  /// Values of input data conform to their types and allowed values defined in item definitions.
+  /// Generated input data depend only on the seed, so the same seed always gives the same input data.
+  #[cfg(feature = "generators")]
+  pub fn generate_input_data(&self, namespace: &str, invocable_name: &str, seed: u64) -> Result<FeelContext> {
+    let input_data = match self.invocables.by_name(namespace, invocable_name) {
+      Some(InvocableType::Decision(def_key)) => self.requirements.decision_input_data(def_key),
+      Some(InvocableType::BusinessKnowledgeModel(_, _)) => vec![],
+      Some(InvocableType::DecisionService(def_key)) => self.requirements.decision_service_input_data(def_key),
+      None => return Err(err_invocable_not_found(namespace, invocable_name)),
+    };
+    let mut generator = FeelGenerator::new(seed);
+    let mut generated = FeelContext::default();
+    for (qname, def_key) in input_data {
+      let Some(variable) = self.input_data_evaluator.get_variable(&def_key) else {
+        continue;
+      };
+      let value = (0..MAX_GENERATION_ATTEMPTS)
+        .map(|_| self.data_generator.generate(variable.namespace(), &variable.type_ref, &mut generator))
+        .find(|value| {
+          // generated value is checked the same way as provided input data
+          let mut ctx = FeelContext::default();
+          ctx.set_entry(variable.name(), value.clone());
+          matches!(self.input_data_evaluator.evaluate(&def_key, &Value::Context(ctx), &self.item_definition_evaluator), Some((_, checked)) if is_accepted(value, &checked))
+        })
+        .ok_or_else(|| err_input_data_generation_failed(&qname.to_string()))?;
+      generated.create_entry(&qname, value);
+    }
+    Ok(generated)
+  }
+
+  /// Simulates an invocable, evaluating it for each combination of candidate input values.
+  ///
+  /// Each entry of candidates holds a list of candidate values of the input with the same name.
+  pub fn simulate_invocable(&self, namespace: &str, invocable_name: &str, candidates: &FeelContext) -> Result<Simulation> {
+    if self.invocables.by_name(namespace, invocable_name).is_none() {
+      return Err(err_invocable_not_found(namespace, invocable_name));
+    }
+    Ok(Simulation::run(invocable_name, candidates, |input_data| {
+      self.evaluate_invocable(namespace, invocable_name, input_data)
+    }))
+  }
+
+  /// Coerces input data converted from JSON to the types of input data declared
+  /// in specified namespace, returns coerced input data and the report of performed coercions.
+  pub fn coerce_json_input(&self, namespace: &str, input_data: &FeelContext) -> (FeelContext, Vec<JsonCoercion>) {
+    self.input_data_evaluator.coerce_json_input(namespace, input_data)
+  }
+
+  /// Evaluates all self-tests embedded in models, ordered by namespace and name.
+  pub fn run_self_tests(&self) -> Vec<SelfTestResult> {
+    let input_data = FeelContext::default();
+    self
+      .invocables
+      .list()
+      .into_iter()
+      .filter(|(namespace, name)| is_self_test(name) && matches!(self.invocables.by_name(namespace, name), Some(InvocableType::Decision(_))))
+      .map(|(namespace, name)| {
+        let value = self.evaluate_invocable(&namespace, &name, &input_data);
+        SelfTestResult::new(namespace, name, value)
+      })
+      .collect()
   }
 
   /// Evaluates a decision.
@@ -168,3 +428,20 @@ impl ModelEvaluator {
     }
   }
 }
+
+/// Inserts the type of the entry with specified qualified name into types of context entries,
+/// entries with multiple names are nested in context types.
+fn insert_nested_type(entry_types: &mut BTreeMap<Name, FeelType>, names: &[Name], feel_type: FeelType) {
+  match names.split_first() {
+    Some((name, [])) => {
+      entry_types.insert(name.clone(), feel_type);
+    }
+    Some((name, rest)) => {
+      let nested = entry_types.entry(name.clone()).or_insert_with(|| FeelType::Context(BTreeMap::new()));
+      if let FeelType::Context(nested_types) = nested {
+        insert_nested_type(nested_types, rest, feel_type);
+      }
+    }
+    None => {}
+  }
+}
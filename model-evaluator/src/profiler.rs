@@ -0,0 +1,212 @@
+//! # Evaluation profiler
+//!
+//! Profiler is an evaluation listener counting evaluations of decisions, invocations
+//! of business knowledge models and evaluations of decision tables, and measuring
+//! the time spent on each of them. Nodes are identified by their stacks, so the same
+//! business knowledge model invoked from different decisions is reported separately.
+//!
+//! Collected profile is reported as a summary table, or in folded stacks format,
+//! one line per stack with the own time in microseconds, accepted by flame graph tools
+//! like `flamegraph.pl` or `inferno-flamegraph`.
+//!
+//! Profiling is opt-in, the profiler is registered as a listener for the profiled evaluations,
+//! a profiler is used on a single thread, profiles collected on multiple threads may be merged.
+
+use crate::listener::{EvaluationListener, FiredRule};
+use dmntk_feel::values::Value;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Statistics of a profiled node.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ProfileEntry {
+  /// Number of evaluations of the node.
+  count: u64,
+  /// Cumulative time of evaluations, including nested nodes.
+  total: Duration,
+  /// Cumulative time of evaluations, excluding nested nodes.
+  own: Duration,
+}
+
+impl ProfileEntry {
+  /// Returns the number of evaluations of the node.
+  pub fn count(&self) -> u64 {
+    self.count
+  }
+
+  /// Returns the cumulative time of evaluations, including nested nodes.
+  pub fn total(&self) -> Duration {
+    self.total
+  }
+
+  /// Returns the cumulative time of evaluations, excluding nested nodes.
+  pub fn own(&self) -> Duration {
+    self.own
+  }
+
+  /// Adds statistics of another entry to this entry.
+  fn add(&mut self, other: &ProfileEntry) {
+    self.count += other.count;
+    self.total += other.total;
+    self.own += other.own;
+  }
+}
+
+/// Profile of evaluations, collected by [EvaluationProfiler].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EvaluationProfile {
+  /// Statistics of nodes indexed by stacks, the last frame of the stack is the profiled node.
+  entries: BTreeMap<Vec<String>, ProfileEntry>,
+}
+
+impl EvaluationProfile {
+  /// Returns statistics of nodes indexed by stacks.
+  pub fn entries(&self) -> &BTreeMap<Vec<String>, ProfileEntry> {
+    &self.entries
+  }
+
+  /// Returns statistics of nodes summed over all stacks, sorted by descending total time.
+  pub fn nodes(&self) -> Vec<(String, ProfileEntry)> {
+    let mut nodes = BTreeMap::<String, ProfileEntry>::new();
+    for (stack, entry) in &self.entries {
+      if let Some(frame) = stack.last() {
+        let node = nodes.entry(frame.clone()).or_default();
+        node.count += entry.count;
+        node.own += entry.own;
+        // time of recursive invocations is counted only once, in the outermost frame
+        if !stack[..stack.len() - 1].contains(frame) {
+          node.total += entry.total;
+        }
+      }
+    }
+    let mut nodes = nodes.into_iter().collect::<Vec<(String, ProfileEntry)>>();
+    nodes.sort_by(|(name_a, entry_a), (name_b, entry_b)| entry_b.total.cmp(&entry_a.total).then(name_a.cmp(name_b)));
+    nodes
+  }
+
+  /// Adds statistics from another profile, e.g. collected on another thread.
+  pub fn merge(&mut self, other: &EvaluationProfile) {
+    for (stack, entry) in &other.entries {
+      self.entries.entry(stack.clone()).or_default().add(entry);
+    }
+  }
+
+  /// Returns the profile in folded stacks format, frames are separated with semicolons
+  /// and followed by the own time in microseconds.
+  pub fn folded(&self) -> String {
+    self
+      .entries
+      .iter()
+      .map(|(stack, entry)| format!("{} {}\n", stack.join(";"), entry.own.as_micros()))
+      .collect()
+  }
+}
+
+impl fmt::Display for EvaluationProfile {
+  /// Formats the summary table of profiled nodes.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "{:>10} {:>12} {:>12}  node", "count", "total [ms]", "own [ms]")?;
+    for (name, entry) in self.nodes() {
+      writeln!(
+        f,
+        "{:>10} {:>12.3} {:>12.3}  {}",
+        entry.count,
+        entry.total.as_secs_f64() * 1000.0,
+        entry.own.as_secs_f64() * 1000.0,
+        name
+      )?;
+    }
+    Ok(())
+  }
+}
+
+/// Node being evaluated.
+struct Frame {
+  /// Name of the frame.
+  name: String,
+  /// Time when the evaluation of the node started.
+  started: Instant,
+  /// Time spent on evaluating nested nodes.
+  nested: Duration,
+}
+
+/// Evaluation listener collecting the profile of evaluations.
+#[derive(Default)]
+pub struct EvaluationProfiler {
+  /// Nodes being evaluated.
+  stack: RefCell<Vec<Frame>>,
+  /// Collected profile.
+  profile: RefCell<EvaluationProfile>,
+}
+
+impl EvaluationProfiler {
+  /// Creates a new profiler with empty profile.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the profile collected so far.
+  pub fn profile(&self) -> EvaluationProfile {
+    self.profile.borrow().clone()
+  }
+
+  /// Starts profiling the node with specified name.
+  fn start(&self, name: String) {
+    self.stack.borrow_mut().push(Frame {
+      // semicolons separate frames in folded stacks
+      name: name.replace(';', ","),
+      started: Instant::now(),
+      nested: Duration::ZERO,
+    });
+  }
+
+  /// Finishes profiling the most recently started node.
+  fn finish(&self) {
+    let mut stack = self.stack.borrow_mut();
+    let Some(frame) = stack.pop() else {
+      return;
+    };
+    let elapsed = frame.started.elapsed();
+    if let Some(parent) = stack.last_mut() {
+      parent.nested += elapsed;
+    }
+    let mut path = stack.iter().map(|frame| frame.name.clone()).collect::<Vec<String>>();
+    path.push(frame.name);
+    let mut profile = self.profile.borrow_mut();
+    let entry = profile.entries.entry(path).or_default();
+    entry.count += 1;
+    entry.total += elapsed;
+    entry.own += elapsed.saturating_sub(frame.nested);
+  }
+}
+
+impl EvaluationListener for EvaluationProfiler {
+  /// Fired rules are not profiled.
+  fn rule_fired(&self, _fired_rule: &FiredRule) {}
+
+  fn decision_started(&self, decision_name: &str) {
+    self.start(format!("decision `{decision_name}`"));
+  }
+
+  fn decision_finished(&self, _decision_name: &str, _result: &Value) {
+    self.finish();
+  }
+
+  fn bkm_started(&self, bkm_name: &str) {
+    self.start(format!("bkm `{bkm_name}`"));
+  }
+
+  fn bkm_finished(&self, _bkm_name: &str, _result: &Value) {
+    self.finish();
+  }
+
+  fn decision_table_started(&self, decision_table_id: &str) {
+    self.start(format!("decision table `{decision_table_id}`"));
+  }
+
+  fn decision_table_finished(&self, _decision_table_id: &str, _result: &Value) {
+    self.finish();
+  }
+}
@@ -0,0 +1,143 @@
+//! # Required inputs of invocables
+//!
+//! Input data required by an invocable are computed transitively from information
+//! requirements of decisions, before the invocable is evaluated. Input data required
+//! by decisions from imported models are expected in contexts named after the imports,
+//! or directly in input data, like while evaluating decisions from imported models.
+
+use crate::model_definitions::{DefDefinitions, DefKey};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{Name, QualifiedName};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Requirement of a decision or decision service.
+#[derive(Clone)]
+enum Requirement {
+  /// Required input data, with the name of the input data variable.
+  Input(DefKey, Name),
+  /// Required decision, with optional name of the import the decision comes from.
+  Decision(DefKey, Option<Name>),
+}
+
+/// Requirements of decisions and decision services.
+#[derive(Default)]
+pub struct Requirements {
+  /// Requirements of decisions.
+  decisions: HashMap<DefKey, Vec<Requirement>>,
+  /// Requirements of decision services.
+  decision_services: HashMap<DefKey, Vec<Requirement>>,
+}
+
+impl Requirements {
+  /// Collects requirements of decisions and decision services from model definitions.
+  pub fn new(definitions: &DefDefinitions) -> Self {
+    // input data having default values are never missing
+    let input_name = |namespace: &str, id: &str| {
+      definitions
+        .input_data_by_key(namespace, id)
+        .filter(|input_data| input_data.default_value().is_none())
+        .map(|input_data| Requirement::Input(DefKey::new(namespace, id), input_data.variable().name().clone()))
+    };
+    let mut decisions = HashMap::new();
+    for decision in definitions.decisions() {
+      let mut requirements = vec![];
+      for information_requirement in decision.information_requirements() {
+        if let Some(href) = information_requirement.required_input() {
+          requirements.extend(input_name(href.namespace(), href.id()));
+        }
+        if let Some(href) = information_requirement.required_decision() {
+          requirements.push(Requirement::Decision(href.into(), href.import_name().cloned()));
+        }
+      }
+      decisions.insert(DefKey::new(decision.namespace(), decision.id()), requirements);
+    }
+    let mut decision_services = HashMap::new();
+    for decision_service in definitions.decision_services() {
+      let mut requirements = vec![];
+      for href in decision_service.input_data() {
+        requirements.extend(input_name(href.namespace(), href.id()));
+      }
+      // input decisions are evaluated by the decision service directly from its input data
+      for href in decision_service.input_decisions() {
+        requirements.push(Requirement::Decision(href.into(), None));
+      }
+      decision_services.insert(DefKey::new(decision_service.namespace(), decision_service.id()), requirements);
+    }
+    Self { decisions, decision_services }
+  }
+
+  /// Returns qualified names of input data required by the decision, ordered by name.
+  pub fn decision_inputs(&self, def_key: &DefKey) -> Vec<QualifiedName> {
+    self.inputs(self.decisions.get(def_key)).into_keys().collect()
+  }
+
+  /// Returns qualified names of input data required by the decision service, ordered by name.
+  pub fn decision_service_inputs(&self, def_key: &DefKey) -> Vec<QualifiedName> {
+    self.inputs(self.decision_services.get(def_key)).into_keys().collect()
+  }
+
+  /// Returns qualified names of input data required by the decision with keys of input data definitions, ordered by name.
+  pub fn decision_input_data(&self, def_key: &DefKey) -> Vec<(QualifiedName, DefKey)> {
+    self.inputs(self.decisions.get(def_key)).into_iter().collect()
+  }
+
+  /// Returns qualified names of input data required by the decision service with keys of input data definitions, ordered by name.
+  #[cfg(feature = "generators")]
+  pub fn decision_service_input_data(&self, def_key: &DefKey) -> Vec<(QualifiedName, DefKey)> {
+    self.inputs(self.decision_services.get(def_key)).into_iter().collect()
+  }
+
+  /// Returns qualified names of required inputs missing in input data.
+  pub fn missing(required_inputs: Vec<QualifiedName>, input_data: &FeelContext) -> Vec<QualifiedName> {
+    required_inputs.into_iter().filter(|name| !is_provided(input_data, name)).collect()
+  }
+
+  /// Collects input data required transitively by specified requirements.
+  fn inputs(&self, opt_requirements: Option<&Vec<Requirement>>) -> BTreeMap<QualifiedName, DefKey> {
+    let mut inputs = BTreeMap::new();
+    let mut visited = HashSet::new();
+    if let Some(requirements) = opt_requirements {
+      self.collect(requirements, &[], &mut visited, &mut inputs);
+    }
+    inputs
+  }
+
+  /// Collects input data required by requirements, prefixing their names with the names of imports.
+  fn collect(&self, requirements: &[Requirement], prefix: &[Name], visited: &mut HashSet<(DefKey, Vec<Name>)>, inputs: &mut BTreeMap<QualifiedName, DefKey>) {
+    for requirement in requirements {
+      match requirement {
+        Requirement::Input(def_key, name) => {
+          let mut names = prefix.to_vec();
+          names.push(name.clone());
+          inputs.insert(names.into(), def_key.clone());
+        }
+        Requirement::Decision(def_key, opt_import_name) => {
+          let mut decision_prefix = prefix.to_vec();
+          decision_prefix.extend(opt_import_name.iter().cloned());
+          if visited.insert((def_key.clone(), decision_prefix.clone())) {
+            if let Some(decision_requirements) = self.decisions.get(def_key) {
+              self.collect(decision_requirements, &decision_prefix, visited, inputs);
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+/// Returns `true` when the input data contain the value with specified qualified name,
+/// values of imported models are taken from the context named after the import, or from input data.
+fn is_provided(input_data: &FeelContext, names: &[Name]) -> bool {
+  match names {
+    [] => false,
+    [name] => input_data.contains_entry(name),
+    [import_name, tail @ ..] => {
+      let mut import_input_data = input_data.clone();
+      if let Some(Value::Context(ctx)) = input_data.get_entry(import_name) {
+        import_input_data.zip(ctx);
+      }
+      is_provided(&import_input_data, tail)
+    }
+  }
+}
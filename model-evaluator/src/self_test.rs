@@ -0,0 +1,54 @@
+//! # Self-tests embedded in DMN models
+//!
+//! Decisions whose names start with `test` followed by a space are self-tests of the model.
+//! Self-tests are evaluated without input data, so they usually invoke business knowledge
+//! models or decision services with literal arguments and compare the results with
+//! expected values. A self-test passes when its decision evaluates to `true`.
+
+use dmntk_feel::values::Value;
+
+/// Prefix of names of decisions being self-tests of the model.
+pub const SELF_TEST_PREFIX: &str = "test ";
+
+/// Returns `true` when the decision with specified name is a self-test of the model.
+pub fn is_self_test(decision_name: &str) -> bool {
+  decision_name.starts_with(SELF_TEST_PREFIX)
+}
+
+/// Result of evaluating a self-test embedded in DMN model.
+#[derive(Debug, Clone)]
+pub struct SelfTestResult {
+  /// Namespace of the model containing the self-test.
+  namespace: String,
+  /// Name of the decision being the self-test.
+  name: String,
+  /// Value the decision was evaluated to.
+  value: Value,
+}
+
+impl SelfTestResult {
+  /// Creates a result of the self-test.
+  pub(crate) fn new(namespace: String, name: String, value: Value) -> Self {
+    Self { namespace, name, value }
+  }
+
+  /// Returns the namespace of the model containing the self-test.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the name of the decision being the self-test.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the value the decision was evaluated to.
+  pub fn value(&self) -> &Value {
+    &self.value
+  }
+
+  /// Returns `true` when the self-test passed.
+  pub fn passed(&self) -> bool {
+    matches!(self.value, Value::Boolean(true))
+  }
+}
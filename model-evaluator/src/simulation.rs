@@ -0,0 +1,139 @@
+//! # Simulation of invocables
+//!
+//! Simulation evaluates an invocable for every combination of candidate input values,
+//! the combinations are the cartesian product of candidate values specified for each input.
+//! Results of the simulation are presented as a truth table in CSV format, one row
+//! for each combination, with a column for each input followed by the result column.
+
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::Name;
+
+/// Single row of the simulation, a combination of input values with the result.
+#[derive(Debug, Clone)]
+pub struct SimulationRow {
+  /// Input values, in the order of simulated inputs.
+  inputs: Vec<Value>,
+  /// Result of the invocable evaluated for the input values.
+  result: Value,
+}
+
+impl SimulationRow {
+  /// Returns input values of the row.
+  pub fn inputs(&self) -> &[Value] {
+    &self.inputs
+  }
+
+  /// Returns the result of the invocable.
+  pub fn result(&self) -> &Value {
+    &self.result
+  }
+}
+
+/// Result of the simulation of an invocable.
+#[derive(Debug, Clone)]
+pub struct Simulation {
+  /// Name of the simulated invocable.
+  invocable_name: String,
+  /// Names of simulated inputs.
+  input_names: Vec<Name>,
+  /// Rows of the simulation.
+  rows: Vec<SimulationRow>,
+}
+
+impl Simulation {
+  /// Runs the simulation, evaluating the invocable for each combination of candidate values.
+  ///
+  /// Each entry of candidates holds a list of candidate values of the input with the same name,
+  /// a value other than a list is the only candidate value of the input.
+  pub(crate) fn run(invocable_name: &str, candidates: &FeelContext, evaluate: impl Fn(&FeelContext) -> Value) -> Self {
+    let input_names = candidates.get_entries().into_iter().map(|(name, _)| name.clone()).collect::<Vec<Name>>();
+    let input_values = candidates
+      .get_entries()
+      .into_iter()
+      .map(|(_, value)| match value {
+        Value::List(items) => items.clone(),
+        other => vec![other.clone()],
+      })
+      .collect::<Vec<Vec<Value>>>();
+    let rows = cartesian_product(&input_values)
+      .into_iter()
+      .map(|inputs| {
+        let mut input_data = FeelContext::default();
+        for (name, value) in input_names.iter().zip(inputs.iter()) {
+          input_data.set_entry(name, value.clone());
+        }
+        let result = evaluate(&input_data);
+        SimulationRow { inputs, result }
+      })
+      .collect();
+    Self {
+      invocable_name: invocable_name.to_string(),
+      input_names,
+      rows,
+    }
+  }
+
+  /// Returns names of simulated inputs.
+  pub fn input_names(&self) -> &[Name] {
+    &self.input_names
+  }
+
+  /// Returns rows of the simulation.
+  pub fn rows(&self) -> &[SimulationRow] {
+    &self.rows
+  }
+
+  /// Returns the simulation as a truth table in CSV format.
+  pub fn to_csv(&self) -> String {
+    let mut csv = String::new();
+    let header = self.input_names.iter().map(|name| name.to_string()).chain([self.invocable_name.clone()]);
+    csv.push_str(&csv_line(header));
+    for row in &self.rows {
+      let cells = row.inputs.iter().chain([&row.result]).map(csv_cell_text);
+      csv.push_str(&csv_line(cells));
+    }
+    csv
+  }
+}
+
+/// Returns all combinations of values, taking one value from each list.
+fn cartesian_product(values: &[Vec<Value>]) -> Vec<Vec<Value>> {
+  values.iter().fold(vec![vec![]], |combinations, candidates| {
+    combinations
+      .iter()
+      .flat_map(|combination| {
+        candidates.iter().map(move |candidate| {
+          let mut extended = combination.clone();
+          extended.push(candidate.clone());
+          extended
+        })
+      })
+      .collect()
+  })
+}
+
+/// Returns the text of the value presented in CSV cell, strings are presented without quotes.
+fn csv_cell_text(value: &Value) -> String {
+  match value {
+    Value::String(text) => text.to_string(),
+    Value::Null(_) => "null".to_string(),
+    other => other.to_string(),
+  }
+}
+
+/// Returns a line of CSV file, cells containing separators, quotes or line breaks are quoted.
+fn csv_line(cells: impl Iterator<Item = String>) -> String {
+  let mut line = cells
+    .map(|cell| {
+      if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+      } else {
+        cell
+      }
+    })
+    .collect::<Vec<String>>()
+    .join(",");
+  line.push('\n');
+  line
+}
@@ -0,0 +1,197 @@
+//! # Build statistics
+//!
+//! Statistics collected while building a model evaluator: the number of elements
+//! in built models, the number of compiled expressions and the duration of each
+//! build phase, helpful when diagnosing slow deployments.
+
+use dmntk_model::{Definitions, DrgElement, ExpressionInstance};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Statistics of building a model evaluator.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BuildStatistics {
+  /// Number of models.
+  models: usize,
+  /// Number of decisions.
+  decisions: usize,
+  /// Number of business knowledge models.
+  business_knowledge_models: usize,
+  /// Number of decision services.
+  decision_services: usize,
+  /// Number of input data.
+  input_data: usize,
+  /// Number of top-level item definitions.
+  item_definitions: usize,
+  /// Number of decision tables, including nested ones.
+  decision_tables: usize,
+  /// Number of rules in all decision tables.
+  rules: usize,
+  /// Number of compiled expressions.
+  compiled_expressions: usize,
+  /// Names and durations of build phases, in the order of execution.
+  phases: Vec<(&'static str, Duration)>,
+}
+
+impl BuildStatistics {
+  /// Creates statistics with the number of elements in specified models.
+  pub(crate) fn new(definitions: &[Definitions]) -> Self {
+    let mut statistics = Self {
+      models: definitions.len(),
+      ..Default::default()
+    };
+    for definitions in definitions {
+      statistics.item_definitions += definitions.item_definitions().len();
+      for drg_element in definitions.drg_elements() {
+        match drg_element {
+          DrgElement::Decision(decision) => {
+            statistics.decisions += 1;
+            if let Some(expression_instance) = decision.decision_logic().as_ref() {
+              statistics.count_decision_tables(expression_instance);
+            }
+          }
+          DrgElement::BusinessKnowledgeModel(bkm) => {
+            statistics.business_knowledge_models += 1;
+            if let Some(body) = bkm.encapsulated_logic().as_ref().and_then(|function_definition| function_definition.body().as_ref()) {
+              statistics.count_decision_tables(body);
+            }
+          }
+          DrgElement::DecisionService(_) => statistics.decision_services += 1,
+          DrgElement::InputData(_) => statistics.input_data += 1,
+          DrgElement::KnowledgeSource(_) => {}
+        }
+      }
+    }
+    statistics
+  }
+
+  /// Returns the number of models.
+  pub fn models(&self) -> usize {
+    self.models
+  }
+
+  /// Returns the number of decisions.
+  pub fn decisions(&self) -> usize {
+    self.decisions
+  }
+
+  /// Returns the number of business knowledge models.
+  pub fn business_knowledge_models(&self) -> usize {
+    self.business_knowledge_models
+  }
+
+  /// Returns the number of decision services.
+  pub fn decision_services(&self) -> usize {
+    self.decision_services
+  }
+
+  /// Returns the number of input data.
+  pub fn input_data(&self) -> usize {
+    self.input_data
+  }
+
+  /// Returns the number of top-level item definitions.
+  pub fn item_definitions(&self) -> usize {
+    self.item_definitions
+  }
+
+  /// Returns the number of decision tables, including decision tables nested in boxed expressions.
+  pub fn decision_tables(&self) -> usize {
+    self.decision_tables
+  }
+
+  /// Returns the number of rules in all decision tables.
+  pub fn rules(&self) -> usize {
+    self.rules
+  }
+
+  /// Returns the number of compiled expressions.
+  pub fn compiled_expressions(&self) -> usize {
+    self.compiled_expressions
+  }
+
+  /// Returns names and durations of build phases, in the order of execution.
+  pub fn phases(&self) -> &[(&'static str, Duration)] {
+    &self.phases
+  }
+
+  /// Returns the total duration of all build phases.
+  pub fn duration(&self) -> Duration {
+    self.phases.iter().map(|(_, duration)| *duration).sum()
+  }
+
+  /// Records the build phase started at specified instant and finished now.
+  pub(crate) fn add_phase(&mut self, name: &'static str, started: Instant) {
+    self.phases.push((name, started.elapsed()));
+  }
+
+  /// Appends build phases recorded in other statistics.
+  pub(crate) fn append_phases(&mut self, other: BuildStatistics) {
+    self.phases.extend(other.phases);
+  }
+
+  /// Sets the number of compiled expressions.
+  pub(crate) fn set_compiled_expressions(&mut self, compiled_expressions: usize) {
+    self.compiled_expressions = compiled_expressions;
+  }
+
+  /// Counts decision tables and their rules in the expression instance and all expression instances nested in it.
+  fn count_decision_tables(&mut self, expression_instance: &ExpressionInstance) {
+    match expression_instance {
+      ExpressionInstance::Context(context) => {
+        for context_entry in context.context_entries() {
+          self.count_decision_tables(&context_entry.value);
+        }
+      }
+      ExpressionInstance::DecisionTable(decision_table) => {
+        self.decision_tables += 1;
+        self.rules += decision_table.rules().len();
+      }
+      ExpressionInstance::FunctionDefinition(function_definition) => {
+        if let Some(body) = function_definition.body() {
+          self.count_decision_tables(body);
+        }
+      }
+      ExpressionInstance::Invocation(invocation) => {
+        for binding in invocation.bindings() {
+          if let Some(binding_formula) = binding.binding_formula() {
+            self.count_decision_tables(binding_formula);
+          }
+        }
+      }
+      ExpressionInstance::List(list) => {
+        for element in list.elements() {
+          self.count_decision_tables(element);
+        }
+      }
+      ExpressionInstance::Relation(relation) => {
+        for row in relation.rows() {
+          for element in row.elements() {
+            self.count_decision_tables(element);
+          }
+        }
+      }
+      ExpressionInstance::LiteralExpression(_) => {}
+    }
+  }
+}
+
+impl fmt::Display for BuildStatistics {
+  /// Formats the statistics, one entry per line, durations of build phases in milliseconds.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "models: {}", self.models)?;
+    writeln!(f, "decisions: {}", self.decisions)?;
+    writeln!(f, "business knowledge models: {}", self.business_knowledge_models)?;
+    writeln!(f, "decision services: {}", self.decision_services)?;
+    writeln!(f, "input data: {}", self.input_data)?;
+    writeln!(f, "item definitions: {}", self.item_definitions)?;
+    writeln!(f, "decision tables: {}", self.decision_tables)?;
+    writeln!(f, "rules: {}", self.rules)?;
+    writeln!(f, "compiled expressions: {}", self.compiled_expressions)?;
+    writeln!(f, "build phases [ms]:")?;
+    for (name, duration) in &self.phases {
+      writeln!(f, "  {name}: {:.3}", duration.as_secs_f64() * 1000.0)?;
+    }
+    writeln!(f, "total [ms]: {:.3}", self.duration().as_secs_f64() * 1000.0)
+  }
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn _0001() {
+  let model_evaluator = build_model_evaluator(dmntk_examples::DMN_3_0020);
+  let statistics = model_evaluator.build_statistics();
+  assert_eq!(1, statistics.models());
+  assert_eq!(5, statistics.decisions());
+  assert_eq!(0, statistics.business_knowledge_models());
+  assert_eq!(0, statistics.decision_services());
+  assert_eq!(2, statistics.input_data());
+  assert_eq!(0, statistics.item_definitions());
+  assert_eq!(3, statistics.decision_tables());
+  assert_eq!(6, statistics.rules());
+  assert!(statistics.compiled_expressions() > 0);
+}
+
+#[test]
+fn _0002() {
+  let model_evaluator = build_model_evaluator(dmntk_examples::DMN_3_0020);
+  let statistics = model_evaluator.build_statistics();
+  let phases = statistics.phases().iter().map(|(name, _)| *name).collect::<Vec<&str>>();
+  assert_eq!(
+    vec![
+      "validation",
+      "definitions",
+      "constants",
+      "input data",
+      "item definitions",
+      "business knowledge models",
+      "decisions",
+      "decision services",
+      "requirements"
+    ],
+    phases
+  );
+  assert_eq!(
+    statistics.phases().iter().map(|(_, duration)| *duration).sum::<std::time::Duration>(),
+    statistics.duration()
+  );
+  let text = statistics.to_string();
+  assert!(text.starts_with("models: 1\ndecisions: 5\n"));
+  assert!(text.contains("\nbuild phases [ms]:\n  validation: "));
+}
+
+#[test]
+fn _0003() {
+  // expressions compiled while building other evaluators are not counted
+  let first = build_model_evaluator(dmntk_examples::DMN_3_0020);
+  let second = build_model_evaluator(dmntk_examples::DMN_3_0020);
+  assert_eq!(first.build_statistics().compiled_expressions(), second.build_statistics().compiled_expressions());
+}
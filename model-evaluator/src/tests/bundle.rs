@@ -0,0 +1,69 @@
+use super::*;
+use crate::{build_bundle, load_bundle};
+
+#[test]
+fn _0001() {
+  let bundle = build_bundle(&[dmntk_examples::DMN_2_0001]).unwrap();
+  assert_eq!(b"DMNB", &bundle[0..4]);
+  let (definitions, model_evaluator) = load_bundle(&bundle).unwrap();
+  assert_eq!(1, definitions.len());
+  let ctx = context(r#"{Full Name: "John Doe"}"#);
+  assert_decision(&model_evaluator, definitions[0].namespace(), "Greeting Message", &ctx, r#""Hello John Doe""#);
+}
+
+#[test]
+fn _0002() {
+  let bundle = build_bundle(&[dmntk_examples::DMN_2_0001, dmntk_examples::DMN_2_0002]).unwrap();
+  let (definitions, _) = load_bundle(&bundle).unwrap();
+  assert_eq!(2, definitions.len());
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    "<ModelParserError> parsing model from XML failed with reason: unknown token at 1:1",
+    build_bundle(&["not a model"]).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    "<ModelEvaluatorError> invalid model bundle: missing magic bytes",
+    load_bundle(b"XXXX\x01\x00\x00\x00\x00\x00").err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_eq!(
+    "<ModelEvaluatorError> unsupported model bundle version: 2, expected version: 1",
+    load_bundle(b"DMNB\x02\x00\x00\x00\x00\x00").err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  let bundle = build_bundle(&[dmntk_examples::DMN_2_0001]).unwrap();
+  assert_eq!(
+    "<ModelEvaluatorError> invalid model bundle: unexpected end of bundle",
+    load_bundle(&bundle[..bundle.len() - 1]).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_eq!(
+    "<ModelEvaluatorError> invalid model bundle: unexpected trailing bytes",
+    load_bundle(b"DMNB\x01\x00\x00\x00\x00\x00\x00").err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_eq!(4_294_967_295, crate::bundle::length_u32(u32::MAX as usize).unwrap());
+  assert_eq!(
+    "<ModelEvaluatorError> invalid model bundle: length 4294967296 exceeds the maximum length 4294967295",
+    crate::bundle::length_u32(u32::MAX as usize + 1).unwrap_err().to_string()
+  );
+}
@@ -1,39 +1,11 @@
 use super::*;
 
-from_examples!(DMN_2_0118);
-
 #[test]
 fn _0001() {
-  let ctx = context(r#"{Age: 17,RiskCategory: "High",isAffordable: true}"#);
-  assert_decision(
-    &MODEL_EVALUATOR,
-    &MODEL_NAMESPACE,
-    "Approval Status",
-    &ctx,
-    r#"{Approved/Declined: "Approved", Rate: "Standard"}"#,
-  );
-}
-
-#[test]
-fn _0002() {
-  let ctx = context(r#"{Age: 19,RiskCategory: "Low",isAffordable: true}"#);
-  assert_decision(
-    &MODEL_EVALUATOR,
-    &MODEL_NAMESPACE,
-    "Approval Status",
-    &ctx,
-    r#"{Approved/Declined: "Approved", Rate: "Basic"}"#,
-  );
-}
-
-#[test]
-fn _0003() {
-  let ctx = context(r#"{Age: 10,RiskCategory: "Low",isAffordable: true}"#);
-  assert_decision(
-    &MODEL_EVALUATOR,
-    &MODEL_NAMESPACE,
-    "Approval Status",
-    &ctx,
-    r#"{Approved/Declined: "Declined", Rate: "Standard"}"#,
+  // output values are not declared for the output 'Rate'
+  let definitions = dmntk_model::parse(dmntk_examples::DMN_2_0118).unwrap();
+  assert_eq!(
+    "<ModelEvaluatorError> output values must be declared for every output of decision table with hit policy PRIORITY",
+    ModelEvaluator::new(&[definitions]).err().unwrap().to_string()
   );
 }
@@ -280,7 +280,7 @@ fn _0027() {
     &MODEL_NAMESPACE,
     "feel-years-and-months-duration-function_ErrorCase_027_3374dd86c6",
     &ctx,
-    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null)"#,
+    r#"null([core::years and months duration] invalid argument type, expected date, date and time, actual type is Null; caused by: [core::date] invalid date string '')"#,
   );
 }
 
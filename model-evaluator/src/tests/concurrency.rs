@@ -0,0 +1,30 @@
+use super::*;
+use std::thread;
+
+/// Number of threads evaluating the same model evaluator concurrently.
+const THREAD_COUNT: usize = 8;
+
+/// Number of evaluations performed by each thread.
+const ITERATION_COUNT: usize = 200;
+
+from_examples!(DMN_3_0085);
+
+#[test]
+fn _0001() {
+  let handles = (0..THREAD_COUNT)
+    .map(|i| {
+      let model_evaluator = Arc::clone(&MODEL_EVALUATOR);
+      thread::spawn(move || {
+        for j in 0..ITERATION_COUNT {
+          let input = format!(r#"{{decision_002_input: "baz {i} {j}"}}"#);
+          let expected = format!(r#""foo baz {i} {j}""#);
+          assert_decision_service(&model_evaluator, &MODEL_NAMESPACE, "decisionService_002", &input, &expected);
+          assert_decision_service(&model_evaluator, &MODEL_NAMESPACE, "decision_001", "{}", r#""foo""#);
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+  for handle in handles {
+    handle.join().unwrap();
+  }
+}
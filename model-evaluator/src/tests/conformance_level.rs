@@ -0,0 +1,43 @@
+use super::*;
+use dmntk_examples::*;
+use dmntk_feel::ConformanceLevel;
+
+/// Builds a model evaluator restricted to specified conformance level.
+fn build_at_conformance_level(model_content: &str, level: ConformanceLevel) -> dmntk_common::Result<Arc<ModelEvaluator>> {
+  let definitions = dmntk_model::parse(model_content).unwrap();
+  ModelEvaluator::new_at_conformance_level(&[definitions], level)
+}
+
+#[test]
+fn _0001() {
+  // models built without specified conformance level are evaluated at conformance level 3
+  assert_eq!(ConformanceLevel::Level3, build_model_evaluator(DMN_2_0001).conformance_level());
+}
+
+#[test]
+fn _0002() {
+  // models written in S-FEEL are evaluated at conformance level 2
+  let model_evaluator = build_at_conformance_level(DMN_2_0001, ConformanceLevel::Level2).unwrap();
+  assert_eq!(ConformanceLevel::Level2, model_evaluator.conformance_level());
+  let ctx = context(r#"{Full Name: "John Doe"}"#);
+  assert_decision(&model_evaluator, "https://dmntk.io/2_0001", "Greeting Message", &ctx, r#""Hello John Doe""#);
+}
+
+#[test]
+fn _0003() {
+  // models using constructs of conformance level 3 are rejected at conformance level 2
+  assert_eq!(
+    "<ConformanceLevelError> relation in decision `Bankrates` is not allowed at conformance level 2",
+    build_at_conformance_level(DMN_3_0014, ConformanceLevel::Level2).err().unwrap().to_string()
+  );
+  assert!(build_at_conformance_level(DMN_3_0014, ConformanceLevel::Level3).is_ok());
+}
+
+#[test]
+fn _0004() {
+  // decision logic is not executable at conformance level 1
+  assert_eq!(
+    "<ConformanceLevelError> decision logic in decision `Greeting Message` is not allowed at conformance level 1",
+    build_at_conformance_level(DMN_2_0001, ConformanceLevel::Level1).err().unwrap().to_string()
+  );
+}
@@ -0,0 +1,234 @@
+use super::*;
+use crate::decision_table::{build_decision_table_evaluator, build_sequential_decision_table_evaluator, discrimination_tree_node_count, MAX_DISCRIMINATION_NODES};
+use dmntk_model::{DecisionRule, DecisionTable, DecisionTableOrientation, HitPolicy, InputClause, InputEntry, OutputClause, OutputEntry};
+
+/// Values of the `Region` input, including values not used in input entries.
+const REGIONS: [&str; 6] = [r#""North""#, r#""South""#, r#""East""#, r#""West""#, r#""Unknown""#, "null"];
+
+/// Values of the `Segment` input, including a value of invalid type.
+const SEGMENTS: [&str; 5] = [r#""Retail""#, r#""Corporate""#, r#""Public""#, r#""Unknown""#, "12"];
+
+/// Values of the `Channel` input.
+const CHANNELS: [&str; 3] = [r#""Web""#, r#""Branch""#, r#""Phone""#];
+
+/// Values of the `Amount` input.
+const AMOUNTS: [&str; 4] = ["10", "100", "120", "200"];
+
+/// Generator of pseudo-random numbers, the same seed always generates the same sequence.
+struct Generator(u64);
+
+impl Generator {
+  /// Returns the next pseudo-random number less than `bound`.
+  fn next(&mut self, bound: u64) -> usize {
+    self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ((self.0 >> 33) % bound) as usize
+  }
+
+  /// Returns an input entry for the column having specified values.
+  fn string_entry(&mut self, values: &[&str]) -> String {
+    let value = |generator: &mut Generator| values[generator.next(values.len() as u64)];
+    match self.next(10) {
+      0..=4 => value(self).to_string(),
+      5 => format!("{}, {}", value(self), value(self)),
+      6 => format!("not({})", value(self)),
+      _ => "-".to_string(),
+    }
+  }
+
+  /// Returns an input entry for the `Amount` column.
+  fn amount_entry(&mut self) -> String {
+    ["-", "-", "> 100", "<= 100", "[50..150]"][self.next(5)].to_string()
+  }
+}
+
+/// Creates a decision table with inputs `Region`, `Segment`, `Channel` and `Amount`.
+fn decision_table(hit_policy: HitPolicy, allowed_regions: Option<&str>, rules: Vec<[String; 4]>) -> DecisionTable {
+  let input_clause = |input_expression: &str, allowed_input_values: Option<&str>| InputClause {
+    input_expression: input_expression.to_string(),
+    input_expression_type_ref: None,
+    allowed_input_values: allowed_input_values.map(|text| text.to_string()),
+  };
+  let output_clause = OutputClause {
+    type_ref: None,
+    name: None,
+    allowed_output_values: None,
+    default_output_entry: None,
+  };
+  let rules = rules
+    .into_iter()
+    .enumerate()
+    .map(|(index, input_entries)| DecisionRule {
+      input_entries: input_entries.into_iter().map(|text| InputEntry { text }).collect(),
+      output_entries: vec![OutputEntry {
+        text: format!(r#""R{}""#, index + 1),
+      }],
+      annotation_entries: vec![],
+      description: None,
+    })
+    .collect();
+  DecisionTable::new(
+    None,
+    vec![
+      input_clause("Region", allowed_regions),
+      input_clause("Segment", None),
+      input_clause("Channel", None),
+      input_clause("Amount", None),
+    ],
+    vec![output_clause],
+    vec![],
+    rules,
+    hit_policy,
+    None,
+    DecisionTableOrientation::RuleAsRow,
+    None,
+  )
+}
+
+/// Generates a decision table with pseudo-random input entries.
+fn generate_decision_table(seed: u64, hit_policy: HitPolicy, allowed_regions: Option<&str>) -> DecisionTable {
+  let mut generator = Generator(seed);
+  let rules = (0..60)
+    .map(|_| {
+      [
+        generator.string_entry(&REGIONS[..4]),
+        generator.string_entry(&SEGMENTS[..3]),
+        generator.string_entry(&CHANNELS),
+        generator.amount_entry(),
+      ]
+    })
+    .collect();
+  decision_table(hit_policy, allowed_regions, rules)
+}
+
+/// Evaluates the decision table with all combinations of input values, using the compiled
+/// and the sequential evaluator, and checks if both evaluators return identical results.
+/// Returns the number of combinations of input values for which any rule matched.
+fn assert_identical_results(decision_table: &DecisionTable) -> usize {
+  let scope: FeelScope = context(r#"{Region: null, Segment: null, Channel: null, Amount: null}"#).into();
+  let compiled_evaluator = build_decision_table_evaluator(&scope, decision_table).unwrap();
+  let sequential_evaluator = build_sequential_decision_table_evaluator(&scope, decision_table).unwrap();
+  let mut matched = 0;
+  for region in REGIONS {
+    for segment in SEGMENTS {
+      for channel in CHANNELS {
+        for amount in AMOUNTS {
+          let input = format!(r#"{{Region: {region}, Segment: {segment}, Channel: {channel}, Amount: {amount}}}"#);
+          let scope: FeelScope = context(&input).into();
+          let expected = sequential_evaluator(&scope);
+          assert_eq!(expected, compiled_evaluator(&scope), "results differ for input: {input}");
+          if !expected.is_null() {
+            matched += 1;
+          }
+        }
+      }
+    }
+  }
+  matched
+}
+
+#[test]
+fn _0001() {
+  for seed in 1..=5 {
+    assert!(assert_identical_results(&generate_decision_table(seed, HitPolicy::First, None)) > 0);
+  }
+}
+
+#[test]
+fn _0002() {
+  for seed in 1..=5 {
+    assert_identical_results(&generate_decision_table(seed, HitPolicy::Unique, None));
+  }
+}
+
+#[test]
+fn _0003() {
+  // allowed input values are checked also in compiled decision tables
+  for seed in 6..=8 {
+    assert_identical_results(&generate_decision_table(seed, HitPolicy::First, Some(r#""North", "South""#)));
+    assert_identical_results(&generate_decision_table(seed, HitPolicy::Unique, Some(r#""North", "South""#)));
+  }
+}
+
+#[test]
+fn _0004() {
+  // every combination of region, segment and channel is matched by exactly one rule,
+  // the last rules do not check the region and match only high amounts of unknown segments
+  let mut rules = vec![];
+  for channel in CHANNELS {
+    for segment in &SEGMENTS[..3] {
+      for region in &REGIONS[..4] {
+        rules.push([region.to_string(), segment.to_string(), channel.to_string(), "-".to_string()]);
+      }
+    }
+  }
+  for channel in CHANNELS {
+    rules.push(["-".to_string(), r#""Unknown""#.to_string(), channel.to_string(), "> 100".to_string()]);
+  }
+  let decision_table = decision_table(HitPolicy::Unique, None, rules);
+  assert_eq!(4 * 3 * 3 * 4 + 6 * 3 * 2, assert_identical_results(&decision_table));
+}
+
+#[test]
+fn _0005() {
+  // wide table with wildcards in every column, repeated in every branch, has bounded discrimination tree
+  let columns = 10;
+  let input_clauses = (1..=columns)
+    .map(|column| InputClause {
+      input_expression: format!("C{column}"),
+      input_expression_type_ref: None,
+      allowed_input_values: None,
+    })
+    .collect();
+  let rules = (0..80)
+    .map(|rule| DecisionRule {
+      input_entries: (0..columns)
+        .map(|column| InputEntry {
+          text: if (rule + column) % 3 == 0 {
+            "-".to_string()
+          } else {
+            format!(r#""V{}""#, (rule * 7 + column) % 8)
+          },
+        })
+        .collect(),
+      output_entries: vec![OutputEntry {
+        text: format!(r#""R{}""#, rule + 1),
+      }],
+      annotation_entries: vec![],
+      description: None,
+    })
+    .collect();
+  let output_clause = OutputClause {
+    type_ref: None,
+    name: None,
+    allowed_output_values: None,
+    default_output_entry: None,
+  };
+  let decision_table = DecisionTable::new(
+    None,
+    input_clauses,
+    vec![output_clause],
+    vec![],
+    rules,
+    HitPolicy::First,
+    None,
+    DecisionTableOrientation::RuleAsRow,
+    None,
+  );
+  let names = (1..=columns).map(|column| format!("C{column}: null")).collect::<Vec<String>>().join(", ");
+  let scope: FeelScope = context(&format!("{{{names}}}")).into();
+  let node_count = discrimination_tree_node_count(&scope, &decision_table).unwrap().unwrap();
+  assert!(node_count > 1);
+  assert!(node_count <= MAX_DISCRIMINATION_NODES);
+  // rules tested sequentially when the limit is reached give the same results
+  let compiled_evaluator = build_decision_table_evaluator(&scope, &decision_table).unwrap();
+  let sequential_evaluator = build_sequential_decision_table_evaluator(&scope, &decision_table).unwrap();
+  for seed in 0..200 {
+    let mut generator = Generator(seed);
+    let values = (1..=columns)
+      .map(|column| format!(r#"C{column}: "V{}""#, generator.next(8)))
+      .collect::<Vec<String>>()
+      .join(", ");
+    let scope: FeelScope = context(&format!("{{{values}}}")).into();
+    assert_eq!(sequential_evaluator(&scope), compiled_evaluator(&scope), "results differ for input: {values}");
+  }
+}
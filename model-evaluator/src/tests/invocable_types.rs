@@ -0,0 +1,56 @@
+use super::*;
+use dmntk_examples::*;
+
+static MODEL_EVALUATOR_0082: Lazy<Arc<ModelEvaluator>> = Lazy::new(|| build_model_evaluator(DMN_3_0082));
+static MODEL_EVALUATOR_0085: Lazy<Arc<ModelEvaluator>> = Lazy::new(|| build_model_evaluator(DMN_3_0085));
+static MODEL_EVALUATOR_0089: Lazy<Arc<ModelEvaluator>> = Lazy::new(|| build_model_evaluators(&[DMN_3_0089_MODEL_C, DMN_3_0089_MODEL_B1, DMN_3_0089_MODEL_B2, DMN_3_0089_MODEL_A]));
+
+const NAMESPACE_0082: &str = "https://github.com/dmn-tck/tck";
+const NAMESPACE_0085: &str = "https://dmntk.io";
+const NAMESPACE_0089: &str = "http://www.trisotech.com/definitions/_10435dcd-8774-4575-a338-49dd554a0928";
+
+/// Returns the type of input data and the type of the result of the invocable, as text.
+fn invocable_types(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str) -> (String, String) {
+  let (input_type, output_type) = model_evaluator.invocable_types(namespace, invocable_name).unwrap();
+  (input_type.to_string(), output_type.to_string())
+}
+
+#[test]
+fn _0001() {
+  assert_eq!(
+    ("context<inputData_003: string>".to_string(), "string".to_string()),
+    invocable_types(&MODEL_EVALUATOR_0085, NAMESPACE_0085, "decision_003")
+  );
+}
+
+#[test]
+fn _0002() {
+  // input parameters of decision services are entries of the input data type
+  assert_eq!(
+    (
+      "context<decision_003_input_1: string, decision_003_input_2: string, inputData_003: string>".to_string(),
+      "Any".to_string()
+    ),
+    invocable_types(&MODEL_EVALUATOR_0085, NAMESPACE_0085, "decisionService_003")
+  );
+}
+
+#[test]
+fn _0003() {
+  // input data of imported models are nested in contexts named after imports
+  assert_eq!(
+    (
+      "context<Model B1: context<Model A: context<Person name: string>>, Model B2: context<Model A: context<Person name: string>>>".to_string(),
+      "Any".to_string()
+    ),
+    invocable_types(&MODEL_EVALUATOR_0089, NAMESPACE_0089, "Model C Decision based on Bs")
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    "<ModelEvaluatorError> types of invocable 'bkm_001' in namespace 'https://github.com/dmn-tck/tck' are not defined",
+    MODEL_EVALUATOR_0082.invocable_types(NAMESPACE_0082, "bkm_001").unwrap_err().to_string()
+  );
+}
@@ -0,0 +1,71 @@
+use super::*;
+use std::panic;
+
+/// Removes the first occurrence of the element starting with specified text from the model.
+fn remove_element(model: &str, start: &str, end: &str) -> String {
+  let begin = model.find(start).expect("element not found");
+  let finish = begin + model[begin..].find(end).expect("end of element not found") + end.len();
+  format!("{}{}", &model[..begin], &model[finish..])
+}
+
+/// Returns the error reported while building the model evaluator,
+/// fails when parsing or building the model evaluator panics.
+fn build_error(model: &str) -> Option<String> {
+  panic::catch_unwind(|| match dmntk_model::parse(model) {
+    Ok(definitions) => ModelEvaluator::new(&[definitions]).err().map(|reason| reason.to_string()),
+    Err(reason) => Some(reason.to_string()),
+  })
+  .expect("building model evaluator panicked")
+}
+
+/// Returns the model containing a decision table with explicit identifier.
+fn model() -> String {
+  dmntk_examples::DMN_2_0005.replace("<decisionTable hitPolicy", "<decisionTable id=\"_approval\" hitPolicy")
+}
+
+#[test]
+fn _0001() {
+  // malformed models are reported as errors, never as panics
+  let model = &model();
+  let corpus = [
+    String::new(),
+    "<definitions".to_string(),
+    model[..model.len() / 2].to_string(),
+    model.replace("<rule ", "<ruleX "),
+    model.replace("<text>&gt;=18</text>", "<text>&gt;=</text>"),
+    model.replace("hitPolicy=\"ANY\"", "hitPolicy=\"NONE\""),
+    remove_element(model, "<inputEntry id=\"_ce051810-0ddf-4423-8de4-2474df9ab864-2\">", "</inputEntry>"),
+    remove_element(model, "<outputEntry id=\"_ce051810-0ddf-4423-8de4-2474df9ab864-3\">", "</outputEntry>"),
+    remove_element(model, "<output id=", "</output>"),
+  ];
+  for model in &corpus {
+    let _ = build_error(model);
+  }
+}
+
+#[test]
+fn _0002() {
+  let model = remove_element(&model(), "<inputEntry id=\"_ce051810-0ddf-4423-8de4-2474df9ab864-2\">", "</inputEntry>");
+  assert_eq!(
+    Some("<ModelEvaluatorError> rule 1 of decision table '_approval' has 2 input entries, expected 3".to_string()),
+    build_error(&model)
+  );
+}
+
+#[test]
+fn _0003() {
+  let model = remove_element(&model(), "<outputEntry id=\"_ce051810-0ddf-4423-8de4-2474df9ab864-3\">", "</outputEntry>");
+  assert_eq!(
+    Some("<ModelEvaluatorError> rule 1 of decision table '_approval' has 0 output entries, expected 1".to_string()),
+    build_error(&model)
+  );
+}
+
+#[test]
+fn _0004() {
+  let model = remove_element(&model(), "<output id=", "</output>");
+  assert_eq!(
+    Some("<ModelEvaluatorError> decision table '_approval' has no output clauses".to_string()),
+    build_error(&model)
+  );
+}
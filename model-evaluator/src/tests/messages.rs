@@ -0,0 +1,181 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use dmntk_common::{DmntkError, Language};
+use dmntk_feel::{FeelType, Name, QualifiedName};
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_business_knowledge_model_with_reference_not_found("https://dmntk.io", "_bkm"),
+    "<ModelEvaluatorError> brak modelu wiedzy biznesowej o odwołaniu: 'https://dmntk.io#_bkm'",
+    "<ModelEvaluatorError> kein Geschäftswissensmodell mit Verweis: 'https://dmntk.io#_bkm'",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_empty_literal_expression(),
+    "<ModelEvaluatorError> puste wyrażenie literałowe",
+    "<ModelEvaluatorError> leerer Literalausdruck",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_empty_encapsulated_logic(),
+    "<ModelEvaluatorError> pusta logika w modelu wiedzy biznesowej",
+    "<ModelEvaluatorError> leere gekapselte Logik im Geschäftswissensmodell",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_invalid_item_definition_type("tAmount"),
+    "<ModelEvaluatorError> niepoprawny typ definicji typu dla 'tAmount'",
+    "<ModelEvaluatorError> ungültiger Typ der Typdefinition für 'tAmount'",
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_translated(
+    err_unsupported_feel_type(FeelType::Any, "tAmount"),
+    "<ModelEvaluatorError> nieobsługiwany typ FEEL: Any w tAmount",
+    "<ModelEvaluatorError> nicht unterstützter FEEL-Typ: Any in tAmount",
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_translated(err_empty_feel_type(), "<ModelEvaluatorError> pusty typ FEEL", "<ModelEvaluatorError> leerer FEEL-Typ");
+}
+
+#[test]
+fn _0007() {
+  assert_translated(
+    err_empty_function_body(),
+    "<ModelEvaluatorError> pusta treść definicji funkcji",
+    "<ModelEvaluatorError> leerer Rumpf der Funktionsdefinition",
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_translated(
+    err_invalid_bundle("unexpected end of bundle"),
+    "<ModelEvaluatorError> niepoprawny pakiet modeli: unexpected end of bundle",
+    "<ModelEvaluatorError> ungültiges Modellpaket: unexpected end of bundle",
+  );
+}
+
+#[test]
+fn _0009() {
+  assert_translated(
+    err_unsupported_bundle_version(2, 1),
+    "<ModelEvaluatorError> nieobsługiwana wersja pakietu modeli: 2, oczekiwana wersja: 1",
+    "<ModelEvaluatorError> nicht unterstützte Version des Modellpakets: 2, erwartete Version: 1",
+  );
+}
+
+#[test]
+fn _0010() {
+  assert_translated(
+    err_output_values_not_declared("PRIORITY"),
+    "<ModelEvaluatorError> wartości wyjściowe muszą być zadeklarowane dla każdej kolumny wyjściowej tabeli decyzyjnej z polityką trafień PRIORITY",
+    "<ModelEvaluatorError> Ausgabewerte müssen für jede Ausgabe der Entscheidungstabelle mit Trefferrichtlinie PRIORITY deklariert werden",
+  );
+}
+
+#[test]
+fn _0011() {
+  assert_translated(
+    err_decision_table_without_outputs("_dt"),
+    "<ModelEvaluatorError> tabela decyzyjna '_dt' nie ma kolumn wyjściowych",
+    "<ModelEvaluatorError> Entscheidungstabelle '_dt' hat keine Ausgabespalten",
+  );
+}
+
+#[test]
+fn _0012() {
+  assert_translated(
+    err_invalid_number_of_rule_entries("_dt", 2, "input", 3, 1),
+    "<ModelEvaluatorError> reguła 2 tabeli decyzyjnej '_dt' ma 1 wpisów rodzaju input, oczekiwano 3",
+    "<ModelEvaluatorError> Regel 2 der Entscheidungstabelle '_dt' hat 1 Einträge vom Typ input, erwartet 3",
+  );
+}
+
+#[test]
+fn _0013() {
+  assert_translated(
+    err_invocable_not_found("https://dmntk.io", "Fee"),
+    "<ModelEvaluatorError> nie znaleziono elementu wywoływalnego 'Fee' w przestrzeni nazw 'https://dmntk.io'",
+    "<ModelEvaluatorError> aufrufbares Element 'Fee' im Namensraum 'https://dmntk.io' nicht gefunden",
+  );
+}
+
+#[test]
+fn _0014() {
+  assert_translated(
+    err_invocable_types_not_defined("https://dmntk.io", "Fee"),
+    "<ModelEvaluatorError> typy elementu wywoływalnego 'Fee' w przestrzeni nazw 'https://dmntk.io' nie są zdefiniowane",
+    "<ModelEvaluatorError> Typen des aufrufbaren Elements 'Fee' im Namensraum 'https://dmntk.io' sind nicht definiert",
+  );
+}
+
+#[test]
+fn _0015() {
+  assert_translated(
+    err_not_decision_service("https://dmntk.io", "Fee"),
+    "<ModelEvaluatorError> element wywoływalny 'Fee' w przestrzeni nazw 'https://dmntk.io' nie jest usługą decyzyjną",
+    "<ModelEvaluatorError> aufrufbares Element 'Fee' im Namensraum 'https://dmntk.io' ist kein Entscheidungsdienst",
+  );
+}
+
+#[test]
+fn _0016() {
+  assert_translated(
+    err_missing_inputs("Fee", &[QualifiedName::new(&[&Name::from("Amount")])]),
+    "<ModelEvaluatorError> brak danych wejściowych wymaganych przez element wywoływalny 'Fee': Amount",
+    "<ModelEvaluatorError> fehlende Eingabedaten, die vom aufrufbaren Element 'Fee' benötigt werden: Amount",
+  );
+}
+
+#[test]
+fn _0017() {
+  assert_translated(
+    err_invalid_default_value("Amount", err_empty_literal_expression()),
+    "<ModelEvaluatorError> niepoprawna wartość domyślna danych wejściowych 'Amount': <ModelEvaluatorError> puste wyrażenie literałowe",
+    "<ModelEvaluatorError> ungültiger Standardwert der Eingabedaten 'Amount': <ModelEvaluatorError> leerer Literalausdruck",
+  );
+}
+
+#[test]
+fn _0018() {
+  assert_translated(
+    err_invalid_constant("Rate", "not a number"),
+    "<ModelEvaluatorError> niepoprawna wartość stałej 'Rate': not a number",
+    "<ModelEvaluatorError> ungültiger Wert der Konstante 'Rate': not a number",
+  );
+}
+
+#[cfg(feature = "generators")]
+#[test]
+fn _0019() {
+  assert_translated(
+    err_input_data_generation_failed("Amount"),
+    "<ModelEvaluatorError> generowanie dozwolonej wartości danych wejściowych 'Amount' nie powiodło się",
+    "<ModelEvaluatorError> Erzeugen eines zulässigen Werts der Eingabedaten 'Amount' fehlgeschlagen",
+  );
+}
@@ -5,7 +5,19 @@ use dmntk_feel::FeelScope;
 use once_cell::sync::Lazy;
 use std::sync::Arc;
 
+mod build_statistics;
+mod bundle;
 mod compatibility;
+mod concurrency;
+mod conformance_level;
+mod discrimination_tree;
+mod invocable_types;
+mod malformed;
+mod messages;
+mod profiler;
+mod required_inputs;
+mod rule_index;
+mod service_signature;
 mod various;
 
 macro_rules! from_examples {
@@ -40,7 +52,11 @@ macro_rules! static_context {
 }
 
 use dmntk_feel_evaluator::BuildContext;
-use {from_examples, model_evaluator, model_evaluator_from_examples, model_namespace_from_examples, static_context};
+use from_examples;
+use model_evaluator;
+use model_evaluator_from_examples;
+use model_namespace_from_examples;
+use static_context;
 
 /// Utility function that creates a `FEEL` context from specified input expression.
 pub fn context(input: &str) -> FeelContext {
@@ -0,0 +1,135 @@
+use super::*;
+use crate::{EvaluationListener, EvaluationProfile, EvaluationProfiler};
+use std::rc::Rc;
+
+const MODEL: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/profiler" name="profiler" id="_profiler" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <inputData name="Amount" id="_amount">
+        <variable typeRef="number" name="Amount"/>
+    </inputData>
+    <businessKnowledgeModel name="Rate" id="_rate">
+        <variable name="Rate"/>
+        <encapsulatedLogic>
+            <formalParameter name="value" typeRef="number"/>
+            <literalExpression>
+                <text>value * 0.1</text>
+            </literalExpression>
+        </encapsulatedLogic>
+    </businessKnowledgeModel>
+    <decision name="Category" id="_category">
+        <variable typeRef="string" name="Category"/>
+        <informationRequirement>
+            <requiredInput href="#_amount"/>
+        </informationRequirement>
+        <decisionTable id="_category_table" hitPolicy="UNIQUE">
+            <input id="_category_input">
+                <inputExpression typeRef="number">
+                    <text>Amount</text>
+                </inputExpression>
+            </input>
+            <output id="_category_output"/>
+            <rule>
+                <inputEntry>
+                    <text>&lt; 100</text>
+                </inputEntry>
+                <outputEntry>
+                    <text>"small"</text>
+                </outputEntry>
+            </rule>
+            <rule>
+                <inputEntry>
+                    <text>&gt;= 100</text>
+                </inputEntry>
+                <outputEntry>
+                    <text>"large"</text>
+                </outputEntry>
+            </rule>
+        </decisionTable>
+    </decision>
+    <decision name="Discount" id="_discount">
+        <variable typeRef="number" name="Discount"/>
+        <informationRequirement>
+            <requiredDecision href="#_category"/>
+        </informationRequirement>
+        <informationRequirement>
+            <requiredInput href="#_amount"/>
+        </informationRequirement>
+        <knowledgeRequirement>
+            <requiredKnowledge href="#_rate"/>
+        </knowledgeRequirement>
+        <literalExpression>
+            <text>if Category = "large" then Rate(Amount) + Rate(10) else 0</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"##;
+
+const NAMESPACE: &str = "https://dmntk.io/profiler";
+
+model_evaluator!(MODEL);
+
+/// Evaluates the discount for specified amounts, returns the collected profile.
+fn profile(amounts: &[u32]) -> EvaluationProfile {
+  let profiler = Rc::new(EvaluationProfiler::new());
+  for amount in amounts {
+    let input_data = context(&format!("{{Amount: {amount}}}"));
+    MODEL_EVALUATOR.evaluate_invocable_with_listener(NAMESPACE, "Discount", &input_data, Rc::clone(&profiler) as Rc<dyn EvaluationListener>);
+  }
+  profiler.profile()
+}
+
+/// Returns stacks with numbers of evaluations.
+fn counts(profile: &EvaluationProfile) -> Vec<String> {
+  profile.entries().iter().map(|(stack, entry)| format!("{} {}", stack.join(";"), entry.count())).collect()
+}
+
+#[test]
+fn _0001() {
+  let profile = profile(&[50, 150, 250]);
+  assert_eq!(
+    vec![
+      "decision `Discount` 3",
+      "decision `Discount`;bkm `Rate` 4",
+      "decision `Discount`;decision `Category` 3",
+      "decision `Discount`;decision `Category`;decision table `_category_table` 3",
+    ],
+    counts(&profile)
+  );
+}
+
+#[test]
+fn _0002() {
+  let profile = profile(&[150]);
+  for entry in profile.entries().values() {
+    assert!(entry.own() <= entry.total());
+  }
+  let discount = &profile.entries()[&vec!["decision `Discount`".to_string()]];
+  let nested = profile.entries().iter().filter(|(stack, _)| stack.len() == 2).map(|(_, entry)| entry.total()).sum();
+  assert_eq!(discount.total(), discount.own() + nested);
+  let nodes = profile
+    .nodes()
+    .into_iter()
+    .map(|(name, entry)| format!("{name} {}", entry.count()))
+    .collect::<Vec<String>>();
+  assert_eq!("decision `Discount` 1", nodes[0]);
+  assert_eq!(4, nodes.len());
+}
+
+#[test]
+fn _0003() {
+  let mut profile = profile(&[150]);
+  profile.merge(&self::profile(&[50]));
+  assert_eq!(
+    vec![
+      "decision `Discount` 2",
+      "decision `Discount`;bkm `Rate` 2",
+      "decision `Discount`;decision `Category` 2",
+      "decision `Discount`;decision `Category`;decision table `_category_table` 2",
+    ],
+    counts(&profile)
+  );
+  let folded = profile.folded();
+  assert_eq!(4, folded.lines().count());
+  assert!(folded.lines().all(|line| line.rsplit_once(' ').is_some_and(|(_, micros)| micros.parse::<u128>().is_ok())));
+  assert!(profile.to_string().starts_with("     count   total [ms]     own [ms]  node\n"));
+}
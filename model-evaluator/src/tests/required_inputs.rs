@@ -0,0 +1,92 @@
+use super::*;
+use dmntk_examples::*;
+use dmntk_feel::QualifiedName;
+
+static MODEL_EVALUATOR_0085: Lazy<Arc<ModelEvaluator>> = Lazy::new(|| build_model_evaluator(DMN_3_0085));
+static MODEL_EVALUATOR_0089: Lazy<Arc<ModelEvaluator>> = Lazy::new(|| build_model_evaluators(&[DMN_3_0089_MODEL_C, DMN_3_0089_MODEL_B1, DMN_3_0089_MODEL_B2, DMN_3_0089_MODEL_A]));
+
+const NAMESPACE_0085: &str = "https://dmntk.io";
+const NAMESPACE_0089: &str = "http://www.trisotech.com/definitions/_10435dcd-8774-4575-a338-49dd554a0928";
+
+/// Returns names of input data required by the invocable, as text.
+fn required_inputs(model_evaluator: &ModelEvaluator, namespace: &str, invocable_name: &str) -> Vec<String> {
+  model_evaluator
+    .required_inputs(namespace, invocable_name)
+    .unwrap()
+    .iter()
+    .map(QualifiedName::to_string)
+    .collect()
+}
+
+#[test]
+fn _0001() {
+  // decisions without requirements require no input data
+  assert!(required_inputs(&MODEL_EVALUATOR_0085, NAMESPACE_0085, "decision_001").is_empty());
+}
+
+#[test]
+fn _0002() {
+  // decision services require their input data and input data of input decisions
+  assert_eq!(vec!["inputData_003"], required_inputs(&MODEL_EVALUATOR_0085, NAMESPACE_0085, "decisionService_003"));
+  assert_eq!(vec!["inputData_003"], required_inputs(&MODEL_EVALUATOR_0085, NAMESPACE_0085, "decision_003"));
+}
+
+#[test]
+fn _0003() {
+  // input data of imported models are required in contexts named after the imports
+  assert_eq!(
+    vec!["Model B1.Model A.Person name", "Model B2.Model A.Person name"],
+    required_inputs(&MODEL_EVALUATOR_0089, NAMESPACE_0089, "Model C Decision based on Bs")
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    "<ModelEvaluatorError> invocable 'unknown' not found in namespace 'https://dmntk.io'",
+    MODEL_EVALUATOR_0085.required_inputs(NAMESPACE_0085, "unknown").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0005() {
+  let result = MODEL_EVALUATOR_0085.evaluate_invocable_with_required_inputs(NAMESPACE_0085, "decision_003", &context("{}"));
+  assert_eq!(
+    "<ModelEvaluatorError> missing input data required by invocable 'decision_003': inputData_003",
+    result.unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  let result = MODEL_EVALUATOR_0085.evaluate_invocable_with_required_inputs(NAMESPACE_0085, "decision_003", &context(r#"{inputData_003: "in3"}"#));
+  assert_eq!(r#""A d3_1 d3_2 in3""#, result.unwrap().to_string());
+}
+
+#[test]
+fn _0007() {
+  // null values are provided values
+  let result = MODEL_EVALUATOR_0085.evaluate_invocable_with_required_inputs(NAMESPACE_0085, "decision_003", &context(r#"{inputData_003: null}"#));
+  assert!(result.is_ok());
+}
+
+#[test]
+fn _0008() {
+  let result =
+    MODEL_EVALUATOR_0089.evaluate_invocable_with_required_inputs(NAMESPACE_0089, "Model C Decision based on Bs", &context(r#"{Model B1: {Model A: {Person name: "Bob"}}}"#));
+  assert_eq!(
+    "<ModelEvaluatorError> missing input data required by invocable 'Model C Decision based on Bs': Model B2.Model A.Person name",
+    result.unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0009() {
+  // input data of imported models may be provided directly
+  let ctx = context(r#"{Person name: "Bob", Model B2: {Model A: {Person name: "John"}}}"#);
+  let result = MODEL_EVALUATOR_0089.evaluate_invocable_with_required_inputs(NAMESPACE_0089, "Model C Decision based on Bs", &ctx);
+  assert_eq!(
+    r#""B1: Evaluating Say Hello to: Hello, Bob; B2: Evaluating Say Hello to: Hello, John""#,
+    result.unwrap().to_string()
+  );
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+/// Number of rules in generated decision tables.
+const RULE_COUNT: usize = 100;
+
+/// Generates a model with decision table having rules indexed by `Code` input.
+/// Every tenth rule (starting from rule 3) does not check the code,
+/// it only checks whether the amount is greater than 100.
+fn generate_model(hit_policy: &str) -> String {
+  let mut model = String::new();
+  model.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+  model.push_str(r#"<definitions xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="https://dmntk.io/rule-index" name="rule-index" id="_rule_index">"#);
+  model.push_str(r#"<decision name="Result" id="_result"><variable name="Result" typeRef="string"/>"#);
+  model.push_str(r##"<informationRequirement id="_ir_code"><requiredInput href="#_code"/></informationRequirement>"##);
+  model.push_str(r##"<informationRequirement id="_ir_amount"><requiredInput href="#_amount"/></informationRequirement>"##);
+  model.push_str(&format!(r#"<decisionTable hitPolicy="{hit_policy}" id="_table">"#));
+  model.push_str(r#"<input id="_input_code"><inputExpression typeRef="string"><text>Code</text></inputExpression></input>"#);
+  model.push_str(r#"<input id="_input_amount"><inputExpression typeRef="number"><text>Amount</text></inputExpression></input>"#);
+  model.push_str(r#"<output id="_output" typeRef="string"/>"#);
+  for i in 0..RULE_COUNT {
+    let (code, amount) = if i % 10 == 3 {
+      ("-".to_string(), "&gt; 100".to_string())
+    } else {
+      (format!(r#""C{i}""#), "-".to_string())
+    };
+    model.push_str(&format!(
+      r#"<rule id="_rule_{i}"><inputEntry id="_ie_code_{i}"><text>{code}</text></inputEntry><inputEntry id="_ie_amount_{i}"><text>{amount}</text></inputEntry><outputEntry id="_oe_{i}"><text>"R{i}"</text></outputEntry></rule>"#
+    ));
+  }
+  model.push_str(r#"<rule id="_rule_multi"><inputEntry id="_ie_code_multi"><text>"X", "Y"</text></inputEntry><inputEntry id="_ie_amount_multi"><text>-</text></inputEntry><outputEntry id="_oe_multi"><text>"XY"</text></outputEntry></rule>"#);
+  model.push_str("</decisionTable></decision>");
+  model.push_str(r#"<inputData name="Code" id="_code"><variable name="Code" typeRef="string"/></inputData>"#);
+  model.push_str(r#"<inputData name="Amount" id="_amount"><variable name="Amount" typeRef="number"/></inputData>"#);
+  model.push_str("</definitions>");
+  model
+}
+
+/// Evaluates the decision table with specified hit policy and compares the result.
+fn eq(model_evaluator: &ModelEvaluator, input: &str, expected: &str) {
+  assert_decision(model_evaluator, "https://dmntk.io/rule-index", "Result", &context(input), expected);
+}
+
+#[test]
+fn _0001() {
+  let model_evaluator = build_model_evaluator(&generate_model("FIRST"));
+  eq(&model_evaluator, r#"{Code: "C5", Amount: 10}"#, r#""R5""#);
+  eq(&model_evaluator, r#"{Code: "C99", Amount: 10}"#, r#""R99""#);
+  eq(&model_evaluator, r#"{Code: "X", Amount: 10}"#, r#""XY""#);
+  eq(&model_evaluator, r#"{Code: "Y", Amount: 10}"#, r#""XY""#);
+}
+
+#[test]
+fn _0002() {
+  // rules not checking the code are still evaluated in rule order
+  let model_evaluator = build_model_evaluator(&generate_model("FIRST"));
+  eq(&model_evaluator, r#"{Code: "C2", Amount: 500}"#, r#""R2""#);
+  eq(&model_evaluator, r#"{Code: "C50", Amount: 500}"#, r#""R3""#);
+  eq(&model_evaluator, r#"{Code: "unknown", Amount: 500}"#, r#""R3""#);
+  // code of invalid type is coerced to null
+  eq(&model_evaluator, r#"{Code: 12, Amount: 500}"#, r#""R3""#);
+}
+
+#[test]
+fn _0003() {
+  let model_evaluator = build_model_evaluator(&generate_model("FIRST"));
+  eq(&model_evaluator, r#"{Code: "unknown", Amount: 10}"#, r#"null(no rules matched, no output value defined)"#);
+  eq(&model_evaluator, r#"{Code: "C3", Amount: 10}"#, r#"null(no rules matched, no output value defined)"#);
+}
+
+#[test]
+fn _0004() {
+  let model_evaluator = build_model_evaluator(&generate_model("UNIQUE"));
+  eq(&model_evaluator, r#"{Code: "C5", Amount: 10}"#, r#""R5""#);
+  eq(
+    &model_evaluator,
+    r#"{Code: "unknown", Amount: 500}"#,
+    r#"null(err_multiple_rules_match_in_unique_hit_policy)"#,
+  );
+  eq(&model_evaluator, r#"{Code: "C5", Amount: 500}"#, r#"null(err_multiple_rules_match_in_unique_hit_policy)"#);
+}
@@ -0,0 +1,43 @@
+use super::*;
+use dmntk_examples::*;
+
+static MODEL_EVALUATOR_0085: Lazy<Arc<ModelEvaluator>> = Lazy::new(|| build_model_evaluator(DMN_3_0085));
+
+const NAMESPACE_0085: &str = "https://dmntk.io";
+
+/// Returns the signature of the decision service as text.
+fn signature(invocable_name: &str) -> String {
+  let (parameters, output_type) = MODEL_EVALUATOR_0085.decision_service_signature(NAMESPACE_0085, invocable_name).unwrap();
+  let parameters = parameters.iter().map(|(name, feel_type)| format!("{name}: {feel_type}")).collect::<Vec<String>>();
+  format!("({}) -> {output_type}", parameters.join(", "))
+}
+
+#[test]
+fn _0001() {
+  assert_eq!("() -> string", signature("decisionService_001"));
+}
+
+#[test]
+fn _0002() {
+  // input data are followed by input decisions
+  assert_eq!(
+    "(inputData_003: string, decision_003_input_1: string, decision_003_input_2: string) -> Any",
+    signature("decisionService_003")
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    "<ModelEvaluatorError> invocable 'decision_001' in namespace 'https://dmntk.io' is not a decision service",
+    MODEL_EVALUATOR_0085.decision_service_signature(NAMESPACE_0085, "decision_001").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(
+    "<ModelEvaluatorError> invocable 'unknown' not found in namespace 'https://dmntk.io'",
+    MODEL_EVALUATOR_0085.decision_service_signature(NAMESPACE_0085, "unknown").unwrap_err().to_string()
+  );
+}
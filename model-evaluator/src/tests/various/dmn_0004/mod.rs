@@ -0,0 +1,75 @@
+use super::super::*;
+
+const DMN_0004: &str = include_str!("_0004.dmn");
+
+model_evaluator!(DMN_0004);
+
+const NAMESPACE: &str = "https://dmntk.io/input-value";
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{Code: "xabcx", Amount: 100, a: 10, b: 20}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Category", &ctx, r#""contains abc""#);
+}
+
+#[test]
+fn _0002() {
+  let ctx = context(r#"{Code: "xyz", Amount: 31, a: 10, b: 20}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Category", &ctx, r#""over limit""#);
+}
+
+#[test]
+fn _0003() {
+  let ctx = context(r#"{Code: "xyz", Amount: 30, a: 10, b: 20}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Category", &ctx, r#""short x""#);
+}
+
+#[test]
+fn _0004() {
+  let ctx = context(r#"{Code: "xyz", Amount: -1, a: 10, b: 20}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Category", &ctx, r#""other""#);
+}
+
+#[test]
+fn _0005() {
+  let ctx = context(r#"{Code: "wxyz", Amount: 1, a: 10, b: 20}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Category", &ctx, r#""other""#);
+}
+
+#[test]
+fn _0006() {
+  // every combination of candidate values is evaluated, single values are the only candidates
+  let candidates = context(r#"{Code: ["xabcx", "xy"], Amount: [-1, 31], a: 10, b: 20}"#);
+  let simulation = MODEL_EVALUATOR.simulate_invocable(NAMESPACE, "Category", &candidates).unwrap();
+  assert_eq!(4, simulation.rows().len());
+  assert_eq!(
+    "Amount,Code,a,b,Category\n-1,xabcx,10,20,contains abc\n-1,xy,10,20,other\n31,xabcx,10,20,contains abc\n31,xy,10,20,over limit\n",
+    simulation.to_csv()
+  );
+}
+
+#[test]
+fn _0007() {
+  // cells containing separators or quotes are quoted in CSV
+  let candidates = context(r#"{Code: ["a,b", "say \"hi\""], Amount: 1, a: 10, b: 20}"#);
+  let simulation = MODEL_EVALUATOR.simulate_invocable(NAMESPACE, "Category", &candidates).unwrap();
+  assert_eq!("Amount,Code,a,b,Category\n1,\"a,b\",10,20,other\n1,\"say \"\"hi\"\"\",10,20,other\n", simulation.to_csv());
+}
+
+#[test]
+fn _0008() {
+  // empty list of candidate values produces no combinations
+  let candidates = context(r#"{Code: [], Amount: [1, 2], a: 10, b: 20}"#);
+  let simulation = MODEL_EVALUATOR.simulate_invocable(NAMESPACE, "Category", &candidates).unwrap();
+  assert_eq!("Amount,Code,a,b,Category\n", simulation.to_csv());
+}
+
+#[test]
+fn _0009() {
+  // simulating unknown invocable fails
+  let candidates = context(r#"{Code: ["xy"]}"#);
+  assert_eq!(
+    "<ModelEvaluatorError> invocable 'Unknown' not found in namespace 'https://dmntk.io/input-value'",
+    MODEL_EVALUATOR.simulate_invocable(NAMESPACE, "Unknown", &candidates).unwrap_err().to_string()
+  );
+}
@@ -0,0 +1,56 @@
+use super::super::*;
+
+const DMN_0005: &str = include_str!("_0005.dmn");
+const DMN_0005_LIMITS: &str = include_str!("_0005_limits.dmn");
+
+static MODEL_EVALUATOR: Lazy<Arc<ModelEvaluator>> = Lazy::new(|| build_model_evaluators(&[DMN_0005_LIMITS, DMN_0005]));
+
+const NAMESPACE: &str = "https://dmntk.io/constants";
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{Amount: 1000}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Amount", &ctx, r#"1000"#);
+}
+
+#[test]
+fn _0002() {
+  let ctx = context(r#"{Amount: 1001}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Amount", &ctx, r#"null(value '1001' is not allowed)"#);
+}
+
+#[test]
+fn _0003() {
+  let ctx = context(r#"{Code: "X"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Code", &ctx, r#""X""#);
+}
+
+#[test]
+fn _0004() {
+  let ctx = context(r#"{Code: "Y"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Code", &ctx, r#"null(value '"Y"' is not allowed)"#);
+}
+
+#[test]
+fn _0005() {
+  let ctx = context(r#"{Value: 15}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Value", &ctx, r#"15"#);
+}
+
+#[test]
+fn _0006() {
+  let ctx = context(r#"{Value: 21}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Value", &ctx, r#"null(value '21' is not allowed)"#);
+}
+
+#[test]
+fn _0007() {
+  let ctx = context(r#"{Discount: 30}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Discount", &ctx, r#"30"#);
+}
+
+#[test]
+fn _0008() {
+  let ctx = context(r#"{Discount: 31}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Checked Discount", &ctx, r#"null(value '31' is not allowed)"#);
+}
@@ -0,0 +1,76 @@
+use super::super::*;
+
+const DMN_0006: &str = include_str!("_0006.dmn");
+const DMN_0006_UNDECLARED: &str = include_str!("_0006_undeclared.dmn");
+const DMN_0006_PARTIAL: &str = include_str!("_0006_partial.dmn");
+const DMN_0006_DATES: &str = include_str!("_0006_dates.dmn");
+
+model_evaluator!(DMN_0006);
+
+const NAMESPACE: &str = "https://dmntk.io/priorities";
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{Score: 10, Risk: "low"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Approval", &ctx, r#"{Level: "HIGH", Status: "APPROVED"}"#);
+}
+
+#[test]
+fn _0002() {
+  let ctx = context(r#"{Score: 10, Risk: "high"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Approval", &ctx, r#"{Level: "HIGH", Status: "REFERRED"}"#);
+}
+
+#[test]
+fn _0003() {
+  let ctx = context(r#"{Score: 120, Risk: "high"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Approval", &ctx, r#"{Level: "LOW", Status: "DECLINED"}"#);
+}
+
+#[test]
+fn _0004() {
+  let ctx = context(r#"{Score: 10, Risk: "high"}"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    NAMESPACE,
+    "Approvals",
+    &ctx,
+    r#"[{Level: "HIGH", Status: "REFERRED"}, {Level: "LOW", Status: "REFERRED"}, {Level: "HIGH", Status: "APPROVED"}, {Level: "LOW", Status: "APPROVED"}]"#,
+  );
+}
+
+#[test]
+fn _0005() {
+  let ctx = context(r#"{Score: 60, Risk: "low"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Approvals", &ctx, r#"[{Level: "HIGH", Status: "APPROVED"}]"#);
+}
+
+#[test]
+fn _0006() {
+  let definitions = dmntk_model::parse(DMN_0006_UNDECLARED).unwrap();
+  assert_eq!(
+    "<ModelEvaluatorError> output values must be declared for every output of decision table with hit policy PRIORITY",
+    ModelEvaluator::new(&[definitions]).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  let definitions = dmntk_model::parse(DMN_0006_PARTIAL).unwrap();
+  assert_eq!(
+    "<ModelEvaluatorError> output values must be declared for every output of decision table with hit policy OUTPUT ORDER",
+    ModelEvaluator::new(&[definitions]).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0008() {
+  // output entries are matched with output values using FEEL equality,
+  // date and time at midnight UTC is equal to the date
+  let definitions = dmntk_model::parse(DMN_0006_DATES).unwrap();
+  let model_evaluator = ModelEvaluator::new(&[definitions]).unwrap();
+  let ctx = context(r#"{Score: 10}"#);
+  assert_decision(&model_evaluator, "https://dmntk.io/deadlines", "Deadline", &ctx, r#"2021-01-01T00:00:00Z"#);
+  let ctx = context(r#"{Score: 60}"#);
+  assert_decision(&model_evaluator, "https://dmntk.io/deadlines", "Deadline", &ctx, r#"2021-02-01"#);
+}
@@ -0,0 +1,71 @@
+use super::super::*;
+use crate::{EvaluationListener, FiredRule};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DMN_0007: &str = include_str!("_0007.dmn");
+
+model_evaluator!(DMN_0007);
+
+const NAMESPACE: &str = "https://dmntk.io/annotations";
+
+/// Listener collecting all fired rules.
+#[derive(Default)]
+struct Collector(RefCell<Vec<FiredRule>>);
+
+impl EvaluationListener for Collector {
+  fn rule_fired(&self, fired_rule: &FiredRule) {
+    self.0.borrow_mut().push(fired_rule.clone());
+  }
+}
+
+/// Evaluates the decision with collecting listener, returns the result and fired rules.
+fn evaluate(input: &str) -> (String, Vec<FiredRule>) {
+  let collector = Rc::new(Collector::default());
+  let result = MODEL_EVALUATOR.evaluate_invocable_with_listener(NAMESPACE, "Discount", &context(input), collector.clone());
+  let fired_rules = collector.0.borrow().clone();
+  (result.to_string(), fired_rules)
+}
+
+#[test]
+fn _0001() {
+  let (result, fired_rules) = evaluate(r#"{Customer: "Business", Order: 1200}"#);
+  assert_eq!("[0.10, 0.05]", result);
+  assert_eq!(2, fired_rules.len());
+  assert_eq!("_discount_table", fired_rules[0].decision_table_id());
+  assert_eq!(1, fired_rules[0].rule_number());
+  assert_eq!(Some("Discount for business customers"), fired_rules[0].description());
+  assert_eq!(Some("Business customer"), fired_rules[0].annotation("Reason"));
+  assert_eq!(Some("Sales policy 2024"), fired_rules[0].annotation("Source"));
+  assert_eq!(2, fired_rules[1].rule_number());
+  assert_eq!(None, fired_rules[1].description());
+  assert_eq!(
+    &[("Reason".to_string(), "Large order".to_string()), ("Source".to_string(), "".to_string())],
+    fired_rules[1].annotations()
+  );
+}
+
+#[test]
+fn _0002() {
+  let (result, fired_rules) = evaluate(r#"{Customer: "Private", Order: 100}"#);
+  assert_eq!("[0]", result);
+  assert_eq!(1, fired_rules.len());
+  assert_eq!(3, fired_rules[0].rule_number());
+  assert_eq!(Some("No discount"), fired_rules[0].annotation("Reason"));
+  assert_eq!(None, fired_rules[0].annotation("Category"));
+}
+
+#[test]
+fn _0003() {
+  let (_, fired_rules) = evaluate(r#"{Customer: "Government", Order: 100}"#);
+  assert!(fired_rules.is_empty());
+}
+
+#[test]
+fn _0004() {
+  // evaluation without listener is not affected by previous evaluations with listener
+  let (_, fired_rules) = evaluate(r#"{Customer: "Private", Order: 100}"#);
+  assert_eq!(1, fired_rules.len());
+  let ctx = context(r#"{Customer: "Business", Order: 100}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Discount", &ctx, "[0.10]");
+}
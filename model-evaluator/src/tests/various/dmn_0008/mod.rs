@@ -0,0 +1,37 @@
+use super::super::*;
+use crate::is_self_test;
+
+const DMN_0008: &str = include_str!("_0008.dmn");
+
+model_evaluator!(DMN_0008);
+
+const NAMESPACE: &str = "https://dmntk.io/self-tests";
+
+#[test]
+fn _0001() {
+  let results = MODEL_EVALUATOR.run_self_tests();
+  let names = results.iter().map(|result| result.name()).collect::<Vec<&str>>();
+  assert_eq!(vec!["test business discount", "test discount without customer", "test private discount"], names);
+  assert!(results.iter().all(|result| result.namespace() == NAMESPACE));
+}
+
+#[test]
+fn _0002() {
+  let results = MODEL_EVALUATOR.run_self_tests();
+  let outcomes = results.iter().map(|result| (result.passed(), result.value().to_string())).collect::<Vec<(bool, String)>>();
+  assert_eq!(vec![(true, "true".to_string()), (false, "0".to_string()), (false, "false".to_string())], outcomes);
+}
+
+#[test]
+fn _0003() {
+  assert!(is_self_test("test business discount"));
+  assert!(!is_self_test("Discount"));
+  assert!(!is_self_test("tested discount"));
+  assert!(!is_self_test("test"));
+}
+
+#[test]
+fn _0004() {
+  let ctx = context(r#"{Customer: "Business"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Discount", &ctx, "0.1");
+}
@@ -0,0 +1,111 @@
+use super::super::*;
+use crate::{EvaluationListener, FiredRule};
+use dmntk_feel::EvaluationMode;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DMN_0009: &str = include_str!("_0009.dmn");
+
+model_evaluator!(DMN_0009);
+
+const NAMESPACE: &str = "https://dmntk.io/null-causes";
+
+/// Listener collecting cause chains of decisions resulting in null.
+#[derive(Default)]
+struct Collector(RefCell<Vec<(String, Vec<String>)>>);
+
+impl EvaluationListener for Collector {
+  fn rule_fired(&self, _: &FiredRule) {}
+
+  fn null_produced(&self, output_name: &str, causes: &[&str]) {
+    self.0.borrow_mut().push((output_name.to_string(), causes.iter().map(|cause| cause.to_string()).collect()));
+  }
+}
+
+/// Evaluates the decision with collecting listener, returns the result and collected cause chains.
+fn evaluate(invocable_name: &str, input: &str) -> (String, Vec<(String, Vec<String>)>) {
+  let collector = Rc::new(Collector::default());
+  let result = MODEL_EVALUATOR.evaluate_invocable_with_listener(NAMESPACE, invocable_name, &context(input), collector.clone());
+  let nulls = collector.0.borrow().clone();
+  (result.to_string(), nulls)
+}
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{Amount: 1200}"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    NAMESPACE,
+    "Is large",
+    &ctx,
+    r#"null(eval_greater_then; caused by: [core::floor] invalid argument type, expected number, actual type is Null; caused by: [subtraction] incompatible types: 1200 - "fee")"#,
+  );
+}
+
+#[test]
+fn _0002() {
+  let (result, nulls) = evaluate("Rounded amount", r#"{Amount: 1200}"#);
+  assert_eq!(
+    r#"null([core::floor] invalid argument type, expected number, actual type is Null; caused by: [subtraction] incompatible types: 1200 - "fee")"#,
+    result
+  );
+  assert_eq!(2, nulls.len());
+  assert_eq!("Net amount", nulls[0].0);
+  assert_eq!(vec![r#"[subtraction] incompatible types: 1200 - "fee""#], nulls[0].1);
+  assert_eq!("Rounded amount", nulls[1].0);
+  assert_eq!(
+    vec![
+      "[core::floor] invalid argument type, expected number, actual type is Null",
+      r#"[subtraction] incompatible types: 1200 - "fee""#
+    ],
+    nulls[1].1
+  );
+}
+
+#[test]
+fn _0003() {
+  // decisions not resulting in null are not reported
+  let (_, nulls) = evaluate("Rounded amount", r#"{Amount: 0}"#);
+  assert!(nulls.is_empty());
+}
+
+#[test]
+fn _0004() {
+  let result = MODEL_EVALUATOR.evaluate_invocable_in_mode(NAMESPACE, "Net amount", &context(r#"{Amount: 1200}"#), EvaluationMode::Strict);
+  assert_eq!(r#"null([subtraction] incompatible types: 1200 - "fee")"#, result.unwrap().to_string());
+}
+
+#[test]
+fn _0005() {
+  let result = MODEL_EVALUATOR.evaluate_invocable_in_mode(NAMESPACE, "Net amount", &context(r#"{Amount: "1200"}"#), EvaluationMode::Strict);
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: value of type string can not be coerced to type number",
+    result.unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  let result = MODEL_EVALUATOR.evaluate_invocable_in_mode(NAMESPACE, "Net amount", &context(r#"{}"#), EvaluationMode::Strict);
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for key 'Amount'",
+    result.unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  let result = MODEL_EVALUATOR.evaluate_invocable_in_mode(NAMESPACE, "Net amount", &context(r#"{}"#), EvaluationMode::Lenient);
+  // missing input is null, so the else branch is taken
+  assert_eq!("0", result.unwrap().to_string());
+}
+
+#[test]
+fn _0008() {
+  // misspelled input is suggested in diagnostics
+  let result = MODEL_EVALUATOR.evaluate_invocable_in_mode(NAMESPACE, "Net amount", &context(r#"{Amont: 1200}"#), EvaluationMode::Strict);
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for key 'Amount', did you mean 'Amont'?",
+    result.unwrap_err().to_string()
+  );
+}
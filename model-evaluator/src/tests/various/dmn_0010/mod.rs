@@ -0,0 +1,57 @@
+use super::super::*;
+use dmntk_feel::json::context_from_json;
+
+const DMN_0010: &str = include_str!("_0010.dmn");
+
+model_evaluator!(DMN_0010);
+
+const NAMESPACE: &str = "https://dmntk.io/json-input";
+
+/// Evaluates the decision with input data converted from JSON, returns the result and the report of coercions.
+fn evaluate(json: &str) -> (String, Vec<String>) {
+  let (input_data, coercions) = MODEL_EVALUATOR.coerce_json_input(NAMESPACE, &context_from_json(json).unwrap());
+  let result = MODEL_EVALUATOR.evaluate_invocable(NAMESPACE, "Eligible", &input_data);
+  (result.to_string(), coercions.iter().map(|coercion| coercion.to_string()).collect())
+}
+
+#[test]
+fn _0001() {
+  let (result, coercions) = evaluate(r#"{"Applicant": {"Born": "1990-05-12", "Income": "1500.50"}, "Visit date": "2023-04-05"}"#);
+  assert_eq!("true", result);
+  assert_eq!(
+    vec![
+      "Applicant.Born: string coerced to date",
+      "Applicant.Income: string coerced to number",
+      "Visit date: string coerced to date"
+    ],
+    coercions
+  );
+}
+
+#[test]
+fn _0002() {
+  let (result, coercions) = evaluate(r#"{"Applicant": {"Born": "2010-05-12", "Income": 1500}, "Visit date": "2023-04-05"}"#);
+  assert_eq!("false", result);
+  assert_eq!(vec!["Applicant.Born: string coerced to date", "Visit date: string coerced to date"], coercions);
+}
+
+#[test]
+fn _0003() {
+  // values that can not be coerced are left to the evaluation
+  let (result, coercions) = evaluate(r#"{"Applicant": {"Born": "long ago", "Income": 1500}, "Visit date": "2023-04-05"}"#);
+  assert_eq!(
+    r#"null(eval_greater_or_equal; caused by: [core::years and months duration] invalid argument type, expected date, date and time, actual type is Null; caused by: expected type 'date', actual type is 'string' in value '"long ago"')"#,
+    result
+  );
+  assert_eq!(vec!["Visit date: string coerced to date"], coercions);
+}
+
+#[test]
+fn _0004() {
+  // without coercion, temporal values provided as JSON strings do not conform to declared types
+  let input_data = context_from_json(r#"{"Applicant": {"Born": "1990-05-12", "Income": 1500}, "Visit date": "2023-04-05"}"#).unwrap();
+  assert_eq!(
+    r#"null(eval_greater_or_equal; caused by: [core::years and months duration] invalid argument type, expected date, date and time, actual type is Null; caused by: expected type 'date', actual type is 'string' in value '"1990-05-12"')"#,
+    MODEL_EVALUATOR.evaluate_invocable(NAMESPACE, "Eligible", &input_data).to_string()
+  );
+}
@@ -0,0 +1,53 @@
+use super::super::*;
+
+const DMN_0011: &str = include_str!("_0011.dmn");
+
+model_evaluator!(DMN_0011);
+
+const NAMESPACE: &str = "https://dmntk.io/input-defaults";
+
+#[test]
+fn _0001() {
+  // missing input data take default values defined in the model
+  let ctx = context(r#"{Amount: 1000}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Interest", &ctx, r#""50 since 2024-01-01""#);
+}
+
+#[test]
+fn _0002() {
+  // provided input data take precedence over default values
+  let ctx = context(r#"{Amount: 1000, Rate: 0.1, Start date: date("2025-02-03")}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Interest", &ctx, r#""100 since 2025-02-03""#);
+}
+
+#[test]
+fn _0003() {
+  // null values are provided values
+  let ctx = context(r#"{Amount: 1000, Rate: null}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Interest", &ctx, r#"null([multiplication] incompatible types: 1000 * null)"#);
+}
+
+#[test]
+fn _0004() {
+  // defaults specified in the request take precedence over default values defined in the model
+  let defaults = context(r#"{Amount: 200, Rate: 0.5}"#);
+  let result = MODEL_EVALUATOR.evaluate_invocable_with_input_defaults(NAMESPACE, "Interest", &context(r#"{}"#), &defaults);
+  assert_eq!(r#""100 since 2024-01-01""#, result.to_string());
+  let result = MODEL_EVALUATOR.evaluate_invocable_with_input_defaults(NAMESPACE, "Interest", &context(r#"{Amount: 10}"#), &defaults);
+  assert_eq!(r#""5 since 2024-01-01""#, result.to_string());
+}
+
+#[test]
+fn _0005() {
+  // input data having default values are not required
+  let required_inputs = MODEL_EVALUATOR.required_inputs(NAMESPACE, "Interest").unwrap();
+  assert_eq!(vec!["Amount".to_string()], required_inputs.iter().map(|name| name.to_string()).collect::<Vec<String>>());
+}
+
+#[test]
+fn _0006() {
+  let model = DMN_0011.replace("<defaultValue>0.05</defaultValue>", "<defaultValue>0.05 +</defaultValue>");
+  let definitions = dmntk_model::parse(&model).unwrap();
+  let reason = ModelEvaluator::new(&[definitions]).err().unwrap().to_string();
+  assert!(reason.starts_with("<ModelEvaluatorError> invalid default value of input data 'Rate': "), "{reason}");
+}
@@ -0,0 +1,54 @@
+use super::super::*;
+use dmntk_feel::{evaluate_in_mode, EvaluationMode};
+
+const DMN_0012: &str = include_str!("_0012.dmn");
+
+model_evaluator!(DMN_0012);
+
+const NAMESPACE: &str = "https://dmntk.io/optional-components";
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{Person: {First name: "John", Middle name: "Ronald", Last name: "Tolkien"}}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Full name", &ctx, r#""John Ronald Tolkien""#);
+}
+
+#[test]
+fn _0002() {
+  // optional components may be absent
+  let ctx = context(r#"{Person: {First name: "John", Last name: "Tolkien"}}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Full name", &ctx, r#""John Tolkien""#);
+}
+
+#[test]
+fn _0003() {
+  // components that are not optional must be present
+  let ctx = context(r#"{Person: {First name: "John", Middle name: "Ronald"}}"#);
+  assert_decision(
+    &MODEL_EVALUATOR,
+    NAMESPACE,
+    "Full name",
+    &ctx,
+    r#"null(item definition evaluator (Component): name not found: Last name in context: {First name: "John", Middle name: "Ronald"})"#,
+  );
+}
+
+#[test]
+fn _0004() {
+  let ctx = context(r#"{People: [{Name: "Margaret", Nickname: "Peggy"}, {Name: "Robert"}]}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Nicknames", &ctx, r#"["Peggy", "Robert"]"#);
+}
+
+#[test]
+fn _0005() {
+  // missing components that are not optional are reported in strict mode
+  let ctx = context(r#"{Person: {First name: "John"}}"#);
+  let result = evaluate_in_mode(EvaluationMode::Strict, || MODEL_EVALUATOR.evaluate_invocable(NAMESPACE, "Full name", &ctx));
+  assert_eq!(
+    "<EvaluationModeError> strict evaluation failed: context has no value for item component 'Last name'",
+    result.unwrap_err().to_string()
+  );
+  let ctx = context(r#"{Person: {First name: "John", Last name: "Tolkien"}}"#);
+  let result = evaluate_in_mode(EvaluationMode::Strict, || MODEL_EVALUATOR.evaluate_invocable(NAMESPACE, "Full name", &ctx));
+  assert_eq!(r#""John Tolkien""#, result.unwrap().to_string());
+}
@@ -0,0 +1,75 @@
+use super::super::*;
+use dmntk_feel::Name;
+
+const DMN_0013: &str = include_str!("_0013.dmn");
+
+model_evaluator!(DMN_0013);
+
+const NAMESPACE: &str = "https://dmntk.io/generated-input-data";
+
+/// Returns the value of the entry with specified name.
+fn entry<'a>(ctx: &'a FeelContext, name: &str) -> &'a Value {
+  ctx.get_entry(&Name::from(name)).unwrap_or_else(|| panic!("no entry '{name}' in {ctx}"))
+}
+
+#[test]
+fn _0001() {
+  // only input data required by the invocable are generated
+  let input_data = MODEL_EVALUATOR.generate_input_data(NAMESPACE, "Offer", 1).unwrap();
+  let names = input_data.iter().map(|(name, _)| name.to_string()).collect::<Vec<String>>();
+  assert_eq!(vec!["Amount", "Customer", "Rate"], names);
+}
+
+#[test]
+fn _0002() {
+  // generated values conform to types and allowed values
+  for seed in 1..100 {
+    let input_data = MODEL_EVALUATOR.generate_input_data(NAMESPACE, "Offer", seed).unwrap();
+    let Value::Context(customer) = entry(&input_data, "Customer") else {
+      panic!("expected customer context in {input_data}");
+    };
+    assert!(matches!(entry(customer, "Name"), Value::String(_)));
+    assert!(matches!(entry(customer, "Age"), Value::Number(age) if *age >= 18 && *age <= 65 && age.is_integer()));
+    assert!(matches!(entry(customer, "Segment"), Value::String(segment) if ["A", "B", "C"].contains(&segment.as_str())));
+    assert!(matches!(entry(customer, "Scores"), Value::List(scores) if scores.iter().all(|score| matches!(score, Value::Number(_)))));
+    assert!(matches!(entry(&input_data, "Amount"), Value::Number(amount) if *amount > 0));
+    assert!(matches!(entry(&input_data, "Rate"), Value::Number(rate) if *rate > 0 && *rate < 1));
+    let result = MODEL_EVALUATOR.evaluate_invocable(NAMESPACE, "Offer", &input_data);
+    assert!(matches!(result, Value::String(_)), "unexpected result {result} for input data {input_data}");
+  }
+}
+
+#[test]
+fn _0003() {
+  // optional components are present only in some generated values
+  let nicknames = (1..50)
+    .map(|seed| MODEL_EVALUATOR.generate_input_data(NAMESPACE, "Offer", seed).unwrap())
+    .filter(|input_data| matches!(entry(input_data, "Customer"), Value::Context(customer) if customer.contains_entry(&Name::from("Nickname"))))
+    .count();
+  assert!(nicknames > 0 && nicknames < 49);
+}
+
+#[test]
+fn _0004() {
+  // the same seed gives the same input data
+  let first = MODEL_EVALUATOR.generate_input_data(NAMESPACE, "Offer", 42).unwrap();
+  let second = MODEL_EVALUATOR.generate_input_data(NAMESPACE, "Offer", 42).unwrap();
+  assert_eq!(first.to_string(), second.to_string());
+}
+
+#[test]
+fn _0005() {
+  // allowed values that can not be met by generated values
+  assert_eq!(
+    "<ModelEvaluatorError> generating allowed value of input data 'Code' failed",
+    MODEL_EVALUATOR.generate_input_data(NAMESPACE, "Code check", 1).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_eq!(
+    "<ModelEvaluatorError> invocable 'Unknown' not found in namespace 'https://dmntk.io/generated-input-data'",
+    MODEL_EVALUATOR.generate_input_data(NAMESPACE, "Unknown", 1).unwrap_err().to_string()
+  );
+}
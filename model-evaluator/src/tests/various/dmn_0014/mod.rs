@@ -0,0 +1,49 @@
+use super::super::*;
+use crate::{EvaluationListener, FiredRule};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DMN_0014: &str = include_str!("_0014.dmn");
+
+model_evaluator!(DMN_0014);
+
+const NAMESPACE: &str = "https://dmntk.io/dependencies";
+
+/// Listener collecting all fired rules.
+#[derive(Default)]
+struct Collector(RefCell<Vec<FiredRule>>);
+
+impl EvaluationListener for Collector {
+  fn rule_fired(&self, fired_rule: &FiredRule) {
+    self.0.borrow_mut().push(fired_rule.clone());
+  }
+}
+
+/// Evaluates the decision with collecting listener, returns the result and the number of fired rules.
+fn evaluate(invocable_name: &str, input: &str) -> (String, usize) {
+  let collector = Rc::new(Collector::default());
+  let result = MODEL_EVALUATOR.evaluate_invocable_with_listener(NAMESPACE, invocable_name, &context(input), collector.clone());
+  let fired_rules = collector.0.borrow().len();
+  (result.to_string(), fired_rules)
+}
+
+#[test]
+fn _0001() {
+  // required decision not referenced by the literal expression is not evaluated
+  let (result, fired_rules) = evaluate("Greeting", r#"{Applicant Age: 20, Applicant Name: "John"}"#);
+  assert_eq!(r#""Hello John""#, result);
+  assert_eq!(0, fired_rules);
+}
+
+#[test]
+fn _0002() {
+  let (result, fired_rules) = evaluate("Summary", r#"{Applicant Age: 20, Applicant Name: "John"}"#);
+  assert_eq!(r#""John: HIGH""#, result);
+  assert_eq!(1, fired_rules);
+}
+
+#[test]
+fn _0003() {
+  let ctx = context(r#"{Applicant Age: 40, Applicant Name: "Jane"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Summary", &ctx, r#""Jane: LOW""#);
+}
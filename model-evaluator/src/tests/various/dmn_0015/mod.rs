@@ -0,0 +1,29 @@
+use super::super::*;
+use dmntk_feel::{ScopeBuilder, ScopeLayer};
+
+const DMN_0015: &str = include_str!("_0015.dmn");
+
+model_evaluator!(DMN_0015);
+
+const NAMESPACE: &str = "https://dmntk.io/binding-scope";
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{x: 5, factor: 3}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Swapped", &ctx, r#"18"#);
+}
+
+#[test]
+fn _0002() {
+  let ctx = context(r#"{x: 5, factor: 3}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Nested", &ctx, r#"30"#);
+}
+
+#[test]
+fn _0003() {
+  let scope = ScopeBuilder::new()
+    .globals(&context(r#"{x: 1, factor: 1}"#))
+    .model(&context(r#"{factor: 3}"#))
+    .layer(ScopeLayer::Inputs, &context(r#"{x: 5}"#));
+  assert_eq!("18", MODEL_EVALUATOR.evaluate_invocable_in_scope(NAMESPACE, "Swapped", &scope).to_string());
+}
@@ -0,0 +1,43 @@
+use super::super::*;
+
+const DMN_0016: &str = include_str!("_0016.dmn");
+
+model_evaluator!(DMN_0016);
+
+const NAMESPACE: &str = "https://dmntk.io/constants";
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{Price: 100}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Gross price", &ctx, r#"123"#);
+}
+
+#[test]
+fn _0002() {
+  let ctx = context(r#"{Price: 100}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Final price", &ctx, r#"110.7"#);
+}
+
+#[test]
+fn _0003() {
+  let ctx = context(r#"{Price: 100}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Label", &ctx, r#"null(expected string as a second argument in addition)"#);
+}
+
+#[test]
+fn _0004() {
+  let definitions = dmntk_model::parse(DMN_0016).unwrap();
+  let constants = context(r#"{Currency: "EUR", VAT rate: 0.5}"#);
+  let model_evaluator = ModelEvaluator::new_with_constants(&[definitions], &constants).unwrap();
+  let ctx = context(r#"{Price: 100}"#);
+  assert_decision(&model_evaluator, NAMESPACE, "Gross price", &ctx, r#"123"#);
+  assert_decision(&model_evaluator, NAMESPACE, "Label", &ctx, r#""110.7 EUR""#);
+}
+
+#[test]
+fn _0005() {
+  let model = DMN_0016.replace("1 - Standard discount", "1 -");
+  let definitions = dmntk_model::parse(&model).unwrap();
+  let reason = ModelEvaluator::new(&[definitions]).err().unwrap().to_string();
+  assert!(reason.starts_with("<ModelEvaluatorError> invalid value of constant 'Net factor': "), "{reason}");
+}
@@ -0,0 +1,24 @@
+use super::super::*;
+use dmntk_feel_evaluator::EnvironmentResolver;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+const DMN_0017: &str = include_str!("_0017.dmn");
+
+model_evaluator!(DMN_0017);
+
+const NAMESPACE: &str = "https://dmntk.io/environment";
+
+#[test]
+fn _0001() {
+  let ctx = context(r#"{Service: "rates"}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Endpoint", &ctx, r#"null(expected string as a second argument in addition)"#);
+}
+
+#[test]
+fn _0002() {
+  let ctx = context(r#"{Service: "rates"}"#);
+  let resolver: Rc<dyn EnvironmentResolver> = Rc::new(HashMap::from([("REGION".to_string(), "eu-west".to_string())]));
+  let actual = MODEL_EVALUATOR.evaluate_invocable_with_environment(NAMESPACE, "Endpoint", &ctx, resolver).to_string();
+  assert_eq!(r#""https://rates.eu-west.example.com""#, actual);
+}
@@ -0,0 +1,73 @@
+use super::super::*;
+use crate::{EvaluationListener, FiredRule};
+use dmntk_feel::values::Value;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DMN_0018: &str = include_str!("_0018.dmn");
+
+model_evaluator!(DMN_0018);
+
+const NAMESPACE: &str = "https://dmntk.io/spans";
+
+/// Listener collecting started and finished evaluations of decisions and decision tables.
+#[derive(Default)]
+struct Collector(RefCell<Vec<String>>);
+
+impl EvaluationListener for Collector {
+  fn rule_fired(&self, _: &FiredRule) {}
+
+  fn decision_started(&self, decision_name: &str) {
+    self.0.borrow_mut().push(format!("decision started: {decision_name}"));
+  }
+
+  fn decision_finished(&self, decision_name: &str, result: &Value) {
+    self.0.borrow_mut().push(format!("decision finished: {decision_name} = {result}"));
+  }
+
+  fn decision_table_started(&self, decision_table_id: &str) {
+    self.0.borrow_mut().push(format!("decision table started: {decision_table_id}"));
+  }
+
+  fn decision_table_finished(&self, decision_table_id: &str, result: &Value) {
+    self.0.borrow_mut().push(format!("decision table finished: {decision_table_id} = {result}"));
+  }
+}
+
+/// Evaluates the decision with collecting listener, returns the result and collected events.
+fn evaluate(invocable_name: &str, input: &str) -> (String, Vec<String>) {
+  let collector = Rc::new(Collector::default());
+  let result = MODEL_EVALUATOR.evaluate_invocable_with_listener(NAMESPACE, invocable_name, &context(input), collector.clone());
+  let events = collector.0.borrow().clone();
+  (result.to_string(), events)
+}
+
+#[test]
+fn _0001() {
+  let (result, events) = evaluate("Discount", r#"{Order: 1200}"#);
+  assert_eq!("0.05", result);
+  assert_eq!(
+    vec![
+      "decision started: Discount",
+      "decision started: Category",
+      r#"decision finished: Category = "Large""#,
+      "decision table started: _discount_table",
+      "decision table finished: _discount_table = 0.05",
+      "decision finished: Discount = 0.05",
+    ],
+    events
+  );
+}
+
+#[test]
+fn _0002() {
+  let (result, events) = evaluate("Category", r#"{Order: 10}"#);
+  assert_eq!(r#""Small""#, result);
+  assert_eq!(vec!["decision started: Category", r#"decision finished: Category = "Small""#], events);
+}
+
+#[test]
+fn _0003() {
+  let ctx = context(r#"{Order: 10}"#);
+  assert_decision(&MODEL_EVALUATOR, NAMESPACE, "Discount", &ctx, r#"0"#);
+}
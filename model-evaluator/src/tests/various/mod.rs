@@ -3,3 +3,19 @@
 mod dmn_0001;
 mod dmn_0002;
 mod dmn_0003;
+mod dmn_0004;
+mod dmn_0005;
+mod dmn_0006;
+mod dmn_0007;
+mod dmn_0008;
+mod dmn_0009;
+mod dmn_0010;
+mod dmn_0011;
+mod dmn_0012;
+#[cfg(feature = "generators")]
+mod dmn_0013;
+mod dmn_0014;
+mod dmn_0015;
+mod dmn_0016;
+mod dmn_0017;
+mod dmn_0018;
@@ -1,8 +1,10 @@
 use crate::item_definition::ItemDefinitionEvaluator;
 use crate::item_definition_type::ItemDefinitionTypeEvaluator;
 use crate::model_definitions::{DefInformationItem, DefKey};
+use dmntk_common::did_you_mean;
 use dmntk_feel::values::Value;
-use dmntk_feel::{value_null, FeelType, Name};
+use dmntk_feel::{report_violation, value_null, FeelType, Name};
+use std::sync::Arc;
 
 /// Type of closure that evaluates values from variable definition.
 pub type VariableEvaluatorFn = Box<dyn Fn(&Value, &ItemDefinitionEvaluator) -> (Name, Value) + Send + Sync>;
@@ -16,8 +18,8 @@ pub struct Variable {
   name: Name,
   /// Variable's type reference.
   pub type_ref: String,
-  /// Variables FEEL type (evaluated).
-  feel_type: FeelType,
+  /// Variables FEEL type (evaluated), shared with all variables having the same type.
+  feel_type: Arc<FeelType>,
 }
 
 impl From<&DefInformationItem> for Variable {
@@ -27,7 +29,7 @@ impl From<&DefInformationItem> for Variable {
       namespace: value.namespace().to_string(),
       name: value.name().clone(),
       type_ref: value.type_ref().clone(),
-      feel_type: FeelType::Any,
+      feel_type: Arc::new(FeelType::Any),
     }
   }
 }
@@ -53,12 +55,12 @@ impl Variable {
   /// Updates the FEEL type of the variable.
   pub fn update_feel_type(&mut self, item_definition_type_evaluator: &ItemDefinitionTypeEvaluator) {
     self.feel_type = item_definition_type_evaluator
-      .information_item_type(&self.namespace, &self.type_ref)
-      .unwrap_or(FeelType::Any);
+      .shared_information_item_type(&self.namespace, &self.type_ref)
+      .unwrap_or_else(|| Arc::new(FeelType::Any));
   }
 
-  /// Returns variable's FEEL type.
-  pub fn feel_type(&self) -> &FeelType {
+  /// Returns variable's shared FEEL type.
+  pub fn feel_type(&self) -> &Arc<FeelType> {
     &self.feel_type
   }
 
@@ -74,7 +76,7 @@ impl Variable {
             return (variable_name.clone(), v.clone());
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "Null" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -86,7 +88,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "string" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -98,7 +100,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "number" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -110,7 +112,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "boolean" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -122,7 +124,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "date" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -134,7 +136,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "time" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -146,7 +148,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "dateTime" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -158,7 +160,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "dayTimeDuration" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -170,7 +172,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       "yearMonthDuration" => Box::new(move |value: &Value, _: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -182,7 +184,7 @@ impl Variable {
             };
           }
         }
-        (variable_name.clone(), value_null!())
+        missing_variable(&variable_name, value)
       }),
       _ => Box::new(move |value: &Value, item_definition_evaluator: &ItemDefinitionEvaluator| {
         if let Value::Context(ctx) = value {
@@ -192,6 +194,7 @@ impl Variable {
               .unwrap_or_else(|| value_null!("input data evaluator: item definition evaluator '{}' not found", variable_type_ref));
             (variable_name.clone(), evaluated_value)
           } else {
+            report_violation(|| format!("context has no value for key '{variable_name}'{}", name_suggestion(&variable_name, value)));
             (variable_name.clone(), value_null!("no name {} in context {}", variable_name, ctx))
           }
         } else {
@@ -201,3 +204,19 @@ impl Variable {
     }
   }
 }
+
+/// Returns `null` value of the variable missing in the input context, reporting a violation of strict evaluation.
+fn missing_variable(variable_name: &Name, value: &Value) -> (Name, Value) {
+  report_violation(|| format!("context has no value for key '{variable_name}'{}", name_suggestion(variable_name, value)));
+  (variable_name.clone(), value_null!())
+}
+
+/// Returns the suggestion of names in the input context similar to the name of the missing variable,
+/// or an empty string when there are no similar names.
+fn name_suggestion(variable_name: &Name, value: &Value) -> String {
+  let Value::Context(ctx) = value else {
+    return String::new();
+  };
+  let names = ctx.iter().map(|(name, _)| name.to_string()).collect::<Vec<String>>();
+  did_you_mean(&variable_name.to_string(), names.iter().map(String::as_str))
+}
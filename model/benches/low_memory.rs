@@ -0,0 +1,148 @@
+//! # Low-memory parsing benchmarks
+//!
+//! Compares the time, the peak resident set size and the peak number of allocated heap bytes
+//! while parsing a model with decision table containing 100k rules, in regular and low-memory mode.
+//!
+//! The peak resident set size is measured in a child process running the same benchmark,
+//! once only generating the model (baseline) and once parsing it. The child process reports
+//! its `VmHWM` (Linux only), which unlike `ru_maxrss` does not include the peak resident
+//! set size of the parent process inherited when spawning the child process.
+//!
+//! ```text
+//! cargo +nightly bench -p dmntk-model --bench low_memory -- --nocapture
+//! ```
+
+#![feature(test)]
+
+extern crate test;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::env;
+use std::io::Write;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test::Bencher;
+
+/// Allocator tracking the number of currently allocated heap bytes and its peak value.
+struct PeakAllocator;
+
+/// Number of currently allocated bytes.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+/// Peak number of allocated bytes.
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAllocator = PeakAllocator;
+
+/// Name of the environment variable selecting the step executed in the child process.
+const RSS_PROBE: &str = "DMNTK_RSS_PROBE";
+
+/// Number of rules in generated decision table.
+const RULE_COUNT: usize = 100_000;
+
+/// Generates a model with single decision table having many rules.
+fn generate_model() -> String {
+  let mut model = String::new();
+  model.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+  model.push_str(r#"<definitions xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="https://dmntk.io/large" name="large" id="_large">"#);
+  model.push_str(r#"<decision name="Large" id="_large_decision"><variable name="Large" typeRef="string"/><decisionTable hitPolicy="FIRST">"#);
+  model.push_str(r#"<input><inputExpression typeRef="string"><text>Code</text></inputExpression></input>"#);
+  model.push_str(r#"<input><inputExpression typeRef="number"><text>Amount</text></inputExpression></input><output/>"#);
+  for i in 0..RULE_COUNT {
+    model.push_str(&format!(
+      r#"<rule id="_rule_{i}"><inputEntry><text>"C{i}"</text></inputEntry><inputEntry><text>&lt; {i}</text></inputEntry><outputEntry><text>"R{i}"</text></outputEntry></rule>"#
+    ));
+  }
+  model.push_str("</decisionTable></decision></definitions>");
+  model
+}
+
+/// Executes the step selected in the child process, returns `true` when running in the child process.
+///
+/// The peak resident set size is written directly to standard output, bypassing the output captured by the harness.
+fn probe(xml: &str, parse: fn(&str) -> dmntk_common::Result<dmntk_model::Definitions>) -> bool {
+  let Ok(step) = env::var(RSS_PROBE) else {
+    return false;
+  };
+  if step == "parse" {
+    test::black_box(parse(xml).unwrap());
+  }
+  let status = std::fs::read_to_string("/proc/self/status").unwrap();
+  let peak = status.lines().find_map(|line| line.strip_prefix("VmHWM:")).unwrap().trim();
+  std::io::stdout().write_all(format!("\n{RSS_PROBE} {peak}\n").as_bytes()).unwrap();
+  true
+}
+
+/// Runs the specified benchmark in a child process executing the specified step
+/// and returns the peak resident set size of the child process in kilobytes.
+fn peak_rss(bench: &str, step: &str) -> u64 {
+  let output = Command::new(env::current_exe().unwrap())
+    .args([bench, "--exact", "--bench"])
+    .env(RSS_PROBE, step)
+    .output()
+    .unwrap();
+  assert!(output.status.success());
+  let stdout = String::from_utf8(output.stdout).unwrap();
+  let line = stdout.lines().find_map(|line| line.strip_prefix(RSS_PROBE)).unwrap();
+  line.trim().trim_end_matches("kB").trim().parse().unwrap()
+}
+
+/// Reports the peak resident set size of the process parsing the model in specified benchmark.
+fn report_rss(label: &str, bench: &str) {
+  let baseline = peak_rss(bench, "generate");
+  let peak = peak_rss(bench, "parse");
+  eprintln!(
+    "{label}: peak RSS {peak} kB, {} kB above the peak RSS {baseline} kB of generating the document",
+    peak - baseline
+  );
+}
+
+/// Parses the model with specified function and reports the peak number of heap bytes allocated while parsing.
+fn report_peak(label: &str, xml: &str, parse: fn(&str) -> dmntk_common::Result<dmntk_model::Definitions>) {
+  PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+  let before = CURRENT.load(Ordering::Relaxed);
+  let definitions = parse(xml).unwrap();
+  let peak = PEAK.load(Ordering::Relaxed) - before;
+  let retained = CURRENT.load(Ordering::Relaxed) - before;
+  drop(definitions);
+  eprintln!(
+    "{label}: document {} bytes, peak heap {peak} bytes allocated while parsing, model {retained} bytes",
+    xml.len()
+  );
+}
+
+#[bench]
+fn bench_parse(b: &mut Bencher) {
+  let xml = generate_model();
+  if probe(&xml, dmntk_model::parse) {
+    return;
+  }
+  report_rss("regular", "bench_parse");
+  report_peak("regular", &xml, dmntk_model::parse);
+  b.iter(|| dmntk_model::parse(&xml).unwrap());
+}
+
+#[bench]
+fn bench_parse_low_memory(b: &mut Bencher) {
+  let xml = generate_model();
+  if probe(&xml, dmntk_model::parse_low_memory) {
+    return;
+  }
+  report_rss("low-memory", "bench_parse_low_memory");
+  report_peak("low-memory", &xml, dmntk_model::parse_low_memory);
+  b.iter(|| dmntk_model::parse_low_memory(&xml).unwrap());
+}
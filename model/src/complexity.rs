@@ -0,0 +1,452 @@
+//! # Complexity estimation
+//!
+//! Estimates the worst-case complexity of evaluating decisions, as a function of the size
+//! of lists provided as input data, and reports constructs that may be expensive to evaluate:
+//!
+//! - iterations over unbounded lists nested in other iterations,
+//! - cartesian products of unbounded lists iterated in a single `for` or quantified expression,
+//! - recursive business knowledge models, invoked directly or through other business knowledge models.
+//!
+//! A list is unbounded when it is not a list literal, and a range is unbounded when its bounds
+//! are not number literals. Iterations are `for`, `some` and `every` expressions, filters
+//! and built-in functions processing lists, like `count` or `sort`, sorting is counted as linear.
+//! Decision tables are evaluated in constant time, only expressions in their cells are analysed.
+//! The complexity of a decision includes the complexity of required decisions
+//! and invoked business knowledge models and decision services.
+
+use crate::linter::parsing_scope;
+use crate::model::{DecisionTable, Definitions, DrgElement, ExpressionInstance, NamedElement};
+use crate::DmnElement;
+use dmntk_feel::FeelScope;
+use dmntk_feel_parser::AstNode;
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Names of built-in functions iterating over elements of lists passed as arguments.
+const LIST_FUNCTIONS: [&str; 26] = [
+  "all",
+  "any",
+  "append",
+  "concatenate",
+  "context merge",
+  "count",
+  "distinct values",
+  "flatten",
+  "index of",
+  "insert before",
+  "list contains",
+  "list replace",
+  "max",
+  "mean",
+  "median",
+  "min",
+  "mode",
+  "product",
+  "remove",
+  "reverse",
+  "sort",
+  "stddev",
+  "string join",
+  "sublist",
+  "sum",
+  "union",
+];
+
+/// Estimated worst-case complexity class, ordered from the cheapest to the most expensive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ComplexityClass {
+  /// Evaluation does not depend on the size of input lists.
+  Constant,
+  /// Evaluation time grows linearly with the size of input lists.
+  Linear,
+  /// Evaluation time grows with the specified power of the size of input lists.
+  Polynomial(u32),
+  /// Evaluation time can not be estimated, e.g. because of recursion.
+  Unbounded,
+}
+
+impl ComplexityClass {
+  /// Returns the complexity class for specified degree of the polynomial.
+  fn from_degree(degree: u32) -> Self {
+    match degree {
+      0 => Self::Constant,
+      1 => Self::Linear,
+      _ => Self::Polynomial(degree),
+    }
+  }
+
+  /// Returns the complexity class of the evaluation repeated in specified number of nested iterations.
+  fn nested(self, depth: u32) -> Self {
+    match self {
+      Self::Constant => Self::from_degree(depth),
+      Self::Linear => Self::from_degree(depth + 1),
+      Self::Polynomial(degree) => Self::from_degree(degree + depth),
+      Self::Unbounded => Self::Unbounded,
+    }
+  }
+}
+
+impl fmt::Display for ComplexityClass {
+  /// Formats the complexity class in big O notation.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Constant => write!(f, "O(1)"),
+      Self::Linear => write!(f, "O(n)"),
+      Self::Polynomial(degree) => write!(f, "O(n^{degree})"),
+      Self::Unbounded => write!(f, "unbounded"),
+    }
+  }
+}
+
+/// Estimated complexity of a decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionComplexity {
+  /// Identifier of the decision.
+  decision_id: String,
+  /// Name of the decision.
+  decision_name: String,
+  /// Estimated complexity class.
+  complexity: ComplexityClass,
+  /// Descriptions of potentially expensive constructs.
+  findings: Vec<String>,
+}
+
+impl DecisionComplexity {
+  /// Returns the identifier of the decision.
+  pub fn decision_id(&self) -> &str {
+    &self.decision_id
+  }
+
+  /// Returns the name of the decision.
+  pub fn decision_name(&self) -> &str {
+    &self.decision_name
+  }
+
+  /// Returns the estimated complexity class.
+  pub fn complexity(&self) -> ComplexityClass {
+    self.complexity
+  }
+
+  /// Returns descriptions of potentially expensive constructs, in the order of appearance.
+  pub fn findings(&self) -> &[String] {
+    &self.findings
+  }
+}
+
+impl fmt::Display for DecisionComplexity {
+  /// Formats the decision with its estimated complexity class.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "decision `{}`: {}", self.decision_name, self.complexity)
+  }
+}
+
+/// Estimates the complexity of all decisions, returns estimations in the order of decisions in the model.
+pub fn estimate_complexity(definitions: &Definitions) -> Vec<DecisionComplexity> {
+  let mut estimator = Estimator::new(definitions);
+  let mut estimations = vec![];
+  for drg_element in definitions.drg_elements() {
+    if let DrgElement::Decision(decision) = drg_element {
+      let complexity = estimator.element_complexity(decision.id());
+      estimations.push(DecisionComplexity {
+        decision_id: decision.id().to_string(),
+        decision_name: decision.name().to_string(),
+        complexity,
+        findings: estimator.findings.remove(decision.id()).unwrap_or_default(),
+      });
+    }
+  }
+  estimations
+}
+
+/// Estimator of complexity of DRG elements.
+struct Estimator<'a> {
+  /// Analysed definitions.
+  definitions: &'a Definitions,
+  /// Scope used for parsing expressions.
+  scope: FeelScope,
+  /// Identifiers of business knowledge models and decision services indexed by names.
+  invocables: HashMap<String, String>,
+  /// Estimated complexity of elements indexed by identifiers.
+  complexities: HashMap<String, ComplexityClass>,
+  /// Findings of elements indexed by identifiers.
+  findings: HashMap<String, Vec<String>>,
+  /// Identifiers of elements being analysed, used to detect recursion.
+  stack: Vec<String>,
+  /// Identifiers of elements invoking themselves, directly or indirectly.
+  recursive: HashSet<String>,
+}
+
+impl<'a> Estimator<'a> {
+  /// Creates an estimator for specified definitions.
+  fn new(definitions: &'a Definitions) -> Self {
+    let invocables = definitions
+      .drg_elements()
+      .filter(|drg_element| matches!(drg_element, DrgElement::BusinessKnowledgeModel(_) | DrgElement::DecisionService(_)))
+      .map(|drg_element| (drg_element.name().to_string(), drg_element.id().to_string()))
+      .collect();
+    Self {
+      definitions,
+      scope: parsing_scope(definitions),
+      invocables,
+      complexities: HashMap::new(),
+      findings: HashMap::new(),
+      stack: vec![],
+      recursive: HashSet::new(),
+    }
+  }
+
+  /// Returns the estimated complexity of the DRG element with specified identifier,
+  /// elements invoked while being analysed are unbounded, because they are recursive.
+  fn element_complexity(&mut self, id: &str) -> ComplexityClass {
+    if let Some(complexity) = self.complexities.get(id) {
+      return *complexity;
+    }
+    if let Some(position) = self.stack.iter().position(|stacked| stacked == id) {
+      self.recursive.extend(self.stack[position..].iter().cloned());
+      return ComplexityClass::Unbounded;
+    }
+    let Some(drg_element) = self.definitions.drg_elements().find(|drg_element| drg_element.id() == id) else {
+      return ComplexityClass::Constant;
+    };
+    self.stack.push(id.to_string());
+    let mut findings = vec![];
+    let mut complexity = match drg_element {
+      DrgElement::Decision(decision) => {
+        let mut complexity = ComplexityClass::Constant;
+        for information_requirement in decision.information_requirements() {
+          if let Some(href) = information_requirement.required_decision() {
+            complexity = max(complexity, self.dependency_complexity(href.id(), &mut findings));
+          }
+        }
+        for knowledge_requirement in decision.knowledge_requirements() {
+          complexity = max(complexity, self.dependency_complexity(knowledge_requirement.required_knowledge().id(), &mut findings));
+        }
+        if let Some(expression_instance) = decision.decision_logic().as_ref() {
+          complexity = max(complexity, self.expression_instance_complexity(expression_instance, &mut findings));
+        }
+        complexity
+      }
+      DrgElement::BusinessKnowledgeModel(bkm) => {
+        let mut complexity = ComplexityClass::Constant;
+        for knowledge_requirement in bkm.knowledge_requirements() {
+          complexity = max(complexity, self.dependency_complexity(knowledge_requirement.required_knowledge().id(), &mut findings));
+        }
+        if let Some(body) = bkm.encapsulated_logic().as_ref().and_then(|function_definition| function_definition.body().as_ref()) {
+          complexity = max(complexity, self.expression_instance_complexity(body, &mut findings));
+        }
+        complexity
+      }
+      DrgElement::DecisionService(decision_service) => {
+        let mut complexity = ComplexityClass::Constant;
+        for href in decision_service.output_decisions().iter().chain(decision_service.encapsulated_decisions()) {
+          complexity = max(complexity, self.dependency_complexity(href.id(), &mut findings));
+        }
+        complexity
+      }
+      _ => ComplexityClass::Constant,
+    };
+    self.stack.pop();
+    if self.recursive.contains(id) {
+      complexity = ComplexityClass::Unbounded;
+    }
+    let mut unique = HashSet::new();
+    findings.retain(|finding| unique.insert(finding.clone()));
+    self.complexities.insert(id.to_string(), complexity);
+    self.findings.insert(id.to_string(), findings);
+    complexity
+  }
+
+  /// Returns the estimated complexity of the required or invoked element,
+  /// reports the element when it is recursive or more expensive than linear.
+  fn dependency_complexity(&mut self, id: &str, findings: &mut Vec<String>) -> ComplexityClass {
+    let complexity = self.element_complexity(id);
+    if complexity > ComplexityClass::Linear {
+      if let Some(drg_element) = self.definitions.drg_elements().find(|drg_element| drg_element.id() == id) {
+        if self.recursive.contains(id) {
+          findings.push(format!("invokes recursive {} `{}`", drg_element.kind(), drg_element.name()));
+        } else {
+          findings.push(format!("depends on {} `{}` with estimated complexity {complexity}", drg_element.kind(), drg_element.name()));
+        }
+      }
+    }
+    complexity
+  }
+
+  /// Returns the estimated complexity of the expression instance and all expression instances nested in it.
+  fn expression_instance_complexity(&mut self, expression_instance: &ExpressionInstance, findings: &mut Vec<String>) -> ComplexityClass {
+    let mut complexity = ComplexityClass::Constant;
+    match expression_instance {
+      ExpressionInstance::Context(context) => {
+        for context_entry in context.context_entries() {
+          complexity = max(complexity, self.expression_instance_complexity(&context_entry.value, findings));
+        }
+      }
+      ExpressionInstance::DecisionTable(decision_table) => complexity = self.decision_table_complexity(decision_table, findings),
+      ExpressionInstance::FunctionDefinition(function_definition) => {
+        if let Some(body) = function_definition.body() {
+          complexity = self.expression_instance_complexity(body, findings);
+        }
+      }
+      ExpressionInstance::Invocation(invocation) => {
+        let called_function = match invocation.called_function() {
+          ExpressionInstance::LiteralExpression(literal_expression) => literal_expression.text().as_ref().and_then(|text| self.invocables.get(text.trim()).cloned()),
+          _ => None,
+        };
+        complexity = match called_function {
+          Some(id) => self.dependency_complexity(&id, findings),
+          None => self.expression_instance_complexity(invocation.called_function(), findings),
+        };
+        for binding in &invocation.bindings {
+          if let Some(binding_formula) = &binding.binding_formula {
+            complexity = max(complexity, self.expression_instance_complexity(binding_formula, findings));
+          }
+        }
+      }
+      ExpressionInstance::List(list) => {
+        for element in &list.elements {
+          complexity = max(complexity, self.expression_instance_complexity(element, findings));
+        }
+      }
+      ExpressionInstance::LiteralExpression(literal_expression) => {
+        if let Some(text) = literal_expression.text() {
+          complexity = self.text_complexity(text, findings);
+        }
+      }
+      ExpressionInstance::Relation(relation) => {
+        for row in relation.rows() {
+          for element in &row.elements {
+            complexity = max(complexity, self.expression_instance_complexity(element, findings));
+          }
+        }
+      }
+    }
+    complexity
+  }
+
+  /// Returns the estimated complexity of input expressions and output entries of the decision table,
+  /// the number of evaluated rules does not depend on input values.
+  fn decision_table_complexity(&mut self, decision_table: &DecisionTable, findings: &mut Vec<String>) -> ComplexityClass {
+    let texts = decision_table
+      .input_clauses()
+      .map(|input_clause| input_clause.input_expression.clone())
+      .chain(decision_table.output_clauses().filter_map(|output_clause| output_clause.default_output_entry.clone()))
+      .chain(
+        decision_table
+          .rules()
+          .flat_map(|rule| rule.output_entries.iter().map(|output_entry| output_entry.text.clone())),
+      )
+      .collect::<Vec<String>>();
+    texts
+      .iter()
+      .fold(ComplexityClass::Constant, |complexity, text| max(complexity, self.text_complexity(text, findings)))
+  }
+
+  /// Returns the estimated complexity of the expression, expressions that could not be parsed are skipped.
+  fn text_complexity(&mut self, text: &str, findings: &mut Vec<String>) -> ComplexityClass {
+    match dmntk_feel_parser::parse_expression(&self.scope, text, false) {
+      Ok(node) => self.node_complexity(&node, findings),
+      Err(_) => ComplexityClass::Constant,
+    }
+  }
+
+  /// Returns the estimated complexity of the node and its descendants.
+  fn node_complexity(&mut self, node: &AstNode, findings: &mut Vec<String>) -> ComplexityClass {
+    match node {
+      AstNode::For(contexts, body) => self.iteration_complexity("for", contexts, body, findings),
+      AstNode::Some(contexts, satisfies) => self.iteration_complexity("some", contexts, satisfies, findings),
+      AstNode::Every(contexts, satisfies) => self.iteration_complexity("every", contexts, satisfies, findings),
+      AstNode::Filter(list, condition) => {
+        let list_complexity = self.node_complexity(list, findings);
+        let condition_complexity = self.node_complexity(condition, findings);
+        if is_bounded(list) {
+          return max(list_complexity, condition_complexity);
+        }
+        if condition_complexity > ComplexityClass::Constant {
+          findings.push(format!(
+            "nested iteration over unbounded lists in filter, estimated complexity {}",
+            condition_complexity.nested(1)
+          ));
+        }
+        max(list_complexity, condition_complexity.nested(1))
+      }
+      AstNode::FunctionInvocation(function, parameters) => {
+        let parameters_complexity = self.node_complexity(parameters, findings);
+        let AstNode::Name(name) = function.as_ref() else {
+          return max(self.node_complexity(function, findings), parameters_complexity);
+        };
+        let name = name.to_string();
+        if let Some(id) = self.invocables.get(&name).cloned() {
+          let complexity = self.dependency_complexity(&id, findings);
+          return max(complexity, parameters_complexity);
+        }
+        if LIST_FUNCTIONS.contains(&name.as_str()) && arguments(parameters).iter().any(|argument| !is_bounded(argument)) {
+          return max(parameters_complexity, ComplexityClass::Linear);
+        }
+        parameters_complexity
+      }
+      _ => node
+        .children()
+        .into_iter()
+        .fold(ComplexityClass::Constant, |complexity, child| max(complexity, self.node_complexity(child, findings))),
+    }
+  }
+
+  /// Returns the estimated complexity of the iteration, repeating the evaluation
+  /// of the body for every combination of elements of iterated lists.
+  fn iteration_complexity(&mut self, kind: &str, contexts: &AstNode, body: &AstNode, findings: &mut Vec<String>) -> ComplexityClass {
+    let mut lists_complexity = ComplexityClass::Constant;
+    let mut unbounded = 0;
+    for context in contexts.children() {
+      match context {
+        AstNode::IterationContextSingle(_, list) | AstNode::QuantifiedContext(_, list) => {
+          lists_complexity = max(lists_complexity, self.node_complexity(list, findings));
+          if !is_bounded(list) {
+            unbounded += 1;
+          }
+        }
+        AstNode::IterationContextRange(_, start, end) => {
+          lists_complexity = max(lists_complexity, self.node_complexity(start, findings));
+          lists_complexity = max(lists_complexity, self.node_complexity(end, findings));
+          if !is_number_literal(start) || !is_number_literal(end) {
+            unbounded += 1;
+          }
+        }
+        other => lists_complexity = max(lists_complexity, self.node_complexity(other, findings)),
+      }
+    }
+    if unbounded > 1 {
+      findings.push(format!("cartesian product of {unbounded} unbounded lists in `{kind}` expression"));
+    }
+    let body_complexity = self.node_complexity(body, findings);
+    if unbounded > 0 && body_complexity > ComplexityClass::Constant {
+      findings.push(format!(
+        "nested iteration over unbounded lists in `{kind}` expression, estimated complexity {}",
+        body_complexity.nested(unbounded)
+      ));
+    }
+    max(lists_complexity, body_complexity.nested(unbounded))
+  }
+}
+
+/// Returns arguments of the function invocation.
+fn arguments(parameters: &AstNode) -> Vec<&AstNode> {
+  match parameters {
+    AstNode::NamedParameters(named_parameters) => named_parameters.iter().flat_map(|named_parameter| named_parameter.children().into_iter().skip(1)).collect(),
+    other => other.children(),
+  }
+}
+
+/// Returns `true` when the number of elements of the list does not depend on input values.
+fn is_bounded(node: &AstNode) -> bool {
+  matches!(node, AstNode::List(_) | AstNode::Null)
+}
+
+/// Returns `true` when the node is a number literal.
+fn is_number_literal(node: &AstNode) -> bool {
+  match node {
+    AstNode::Numeric(_, _) => true,
+    AstNode::Neg(node) => is_number_literal(node),
+    _ => false,
+  }
+}
@@ -0,0 +1,220 @@
+//! # Conformance level validation
+//!
+//! Validates that decision logic uses only constructs defined at the selected conformance level:
+//!
+//! - At conformance level 1 decision logic is not executable, so decisions
+//!   and business knowledge models must not define any decision logic.
+//! - At conformance level 2 decision logic is limited to decision tables and literal expressions
+//!   written in S-FEEL: input expressions, output entries and literal expressions must be simple
+//!   expressions (literals, names, arithmetic, comparisons, conjunctions, disjunctions, negations
+//!   and invocations of business knowledge models) and input entries must be simple unary tests
+//!   (comparisons with endpoints, intervals, negations and `-`).
+//!   Boxed contexts, function definitions, invocations, lists and relations,
+//!   as well as FEEL constructs like `if`, `for`, quantified expressions, filters, contexts,
+//!   lists and invocations of built-in functions other than `not` and date and time literals
+//!   are not allowed.
+//! - At conformance level 3 all constructs are allowed.
+//!
+//! Expressions that can not be parsed are not reported, they are reported by the evaluator.
+
+use crate::errors::*;
+use crate::linter::parsing_scope;
+use crate::model::{DecisionTable, Definitions, DrgElement, ExpressionInstance, NamedElement};
+use dmntk_common::Result;
+use dmntk_feel::bif::is_built_in_function_name;
+use dmntk_feel::{ConformanceLevel, FeelScope};
+use dmntk_feel_parser::AstNode;
+
+/// Validates that decision logic of all specified decision models uses only constructs
+/// defined at specified conformance level, returns an error describing the first violation.
+pub fn validate_conformance_level(definitions: &[Definitions], level: ConformanceLevel) -> Result<()> {
+  if level == ConformanceLevel::Level3 {
+    return Ok(());
+  }
+  for definitions in definitions {
+    let scope = parsing_scope(definitions);
+    for drg_element in definitions.drg_elements() {
+      let expression_instance = match drg_element {
+        DrgElement::Decision(decision) => decision.decision_logic().as_ref(),
+        DrgElement::BusinessKnowledgeModel(bkm) => bkm.encapsulated_logic().as_ref().and_then(|function_definition| function_definition.body().as_ref()),
+        _ => None,
+      };
+      if let Some(expression_instance) = expression_instance {
+        let location = format!("{} `{}`", drg_element.kind(), drg_element.name());
+        if level == ConformanceLevel::Level1 {
+          return Err(err_not_allowed_at_conformance_level("decision logic", &location, level));
+        }
+        validate_expression_instance(&scope, &location, expression_instance, level)?;
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Validates that the expression instance is a decision table or literal expression written in S-FEEL.
+fn validate_expression_instance(scope: &FeelScope, location: &str, expression_instance: &ExpressionInstance, level: ConformanceLevel) -> Result<()> {
+  let construct = match expression_instance {
+    ExpressionInstance::Context(_) => Some("boxed context".to_string()),
+    ExpressionInstance::DecisionTable(decision_table) => decision_table_violation(scope, decision_table),
+    ExpressionInstance::FunctionDefinition(_) => Some("boxed function definition".to_string()),
+    ExpressionInstance::Invocation(_) => Some("boxed invocation".to_string()),
+    ExpressionInstance::List(_) => Some("boxed list".to_string()),
+    ExpressionInstance::LiteralExpression(literal_expression) => literal_expression
+      .text()
+      .as_ref()
+      .and_then(|text| expression_violation(scope, text))
+      .map(|construct| format!("{construct} in literal expression")),
+    ExpressionInstance::Relation(_) => Some("relation".to_string()),
+  };
+  match construct {
+    Some(construct) => Err(err_not_allowed_at_conformance_level(&construct, location, level)),
+    None => Ok(()),
+  }
+}
+
+/// Returns the description of the first construct of the decision table not defined in S-FEEL.
+fn decision_table_violation(scope: &FeelScope, decision_table: &DecisionTable) -> Option<String> {
+  for input_clause in decision_table.input_clauses() {
+    if let Some(construct) = expression_violation(scope, &input_clause.input_expression) {
+      return Some(format!("{construct} in input expression `{}`", input_clause.input_expression.trim()));
+    }
+  }
+  for (rule_index, rule) in decision_table.rules().enumerate() {
+    for input_entry in &rule.input_entries {
+      if let Some(construct) = dmntk_feel_parser::parse_unary_tests(scope, &input_entry.text, false)
+        .ok()
+        .and_then(|node| unary_tests_violation(&node))
+      {
+        return Some(format!("{construct} in input entry `{}` of rule {}", input_entry.text.trim(), rule_index + 1));
+      }
+    }
+    for output_entry in &rule.output_entries {
+      if let Some(construct) = expression_violation(scope, &output_entry.text) {
+        return Some(format!("{construct} in output entry `{}` of rule {}", output_entry.text.trim(), rule_index + 1));
+      }
+    }
+  }
+  None
+}
+
+/// Parses the text of the expression and returns the first construct not defined in S-FEEL.
+fn expression_violation(scope: &FeelScope, text: &str) -> Option<&'static str> {
+  dmntk_feel_parser::parse_expression(scope, text, false)
+    .ok()
+    .and_then(|node| simple_expression_violation(&node))
+}
+
+/// Returns the first construct of the simple expression not defined in S-FEEL.
+fn simple_expression_violation(node: &AstNode) -> Option<&'static str> {
+  match node {
+    AstNode::Add(lhs, rhs)
+    | AstNode::Sub(lhs, rhs)
+    | AstNode::Mul(lhs, rhs)
+    | AstNode::Div(lhs, rhs)
+    | AstNode::Exp(lhs, rhs)
+    | AstNode::Eq(lhs, rhs)
+    | AstNode::Nq(lhs, rhs)
+    | AstNode::Lt(lhs, rhs)
+    | AstNode::Le(lhs, rhs)
+    | AstNode::Gt(lhs, rhs)
+    | AstNode::Ge(lhs, rhs)
+    | AstNode::And(lhs, rhs)
+    | AstNode::Or(lhs, rhs) => simple_expression_violation(lhs).or_else(|| simple_expression_violation(rhs)),
+    AstNode::Neg(node) => simple_expression_violation(node),
+    AstNode::FunctionInvocation(function, parameters) if !is_date_time_literal(function, parameters) => match function.as_ref() {
+      AstNode::Name(name) if name.to_string() == "not" || !is_built_in_function_name(&name.to_string()) => parameters_violation(parameters),
+      _ => Some(construct_name(node)),
+    },
+    other => simple_value_violation(other),
+  }
+}
+
+/// Returns the first construct of the parameters of the invocation not defined in S-FEEL.
+fn parameters_violation(parameters: &AstNode) -> Option<&'static str> {
+  match parameters {
+    AstNode::PositionalParameters(items) => items.iter().find_map(simple_expression_violation),
+    AstNode::NamedParameters(items) => items.iter().find_map(|item| match item {
+      AstNode::NamedParameter(_, value) => simple_expression_violation(value),
+      other => Some(construct_name(other)),
+    }),
+    other => Some(construct_name(other)),
+  }
+}
+
+/// Returns the first construct of the unary tests not defined in S-FEEL.
+fn unary_tests_violation(node: &AstNode) -> Option<&'static str> {
+  match node {
+    AstNode::Irrelevant => None,
+    AstNode::ExpressionList(items) | AstNode::NegatedList(items) => items.iter().find_map(positive_unary_test_violation),
+    other => positive_unary_test_violation(other),
+  }
+}
+
+/// Returns the first construct of the positive unary test not defined in S-FEEL.
+fn positive_unary_test_violation(node: &AstNode) -> Option<&'static str> {
+  match node {
+    AstNode::UnaryLt(endpoint) | AstNode::UnaryLe(endpoint) | AstNode::UnaryGt(endpoint) | AstNode::UnaryGe(endpoint) => simple_value_violation(endpoint),
+    AstNode::Range(start, end) => match (start.as_ref(), end.as_ref()) {
+      (AstNode::IntervalStart(start, _), AstNode::IntervalEnd(end, _)) => simple_value_violation(start).or_else(|| simple_value_violation(end)),
+      _ => Some(construct_name(node)),
+    },
+    other => simple_value_violation(other),
+  }
+}
+
+/// Returns the construct when the node is not a simple value, i.e. not a literal or qualified name.
+fn simple_value_violation(node: &AstNode) -> Option<&'static str> {
+  let is_simple_value = match node {
+    AstNode::Numeric(_, _) | AstNode::String(_) | AstNode::Boolean(_) | AstNode::Null | AstNode::At(_) | AstNode::Name(_) | AstNode::QualifiedName(_) => true,
+    AstNode::Neg(node) => matches!(node.as_ref(), AstNode::Numeric(_, _)),
+    AstNode::Path(_, _) => is_qualified_name(node),
+    AstNode::FunctionInvocation(function, parameters) => is_date_time_literal(function, parameters),
+    _ => false,
+  };
+  if is_simple_value {
+    None
+  } else {
+    Some(construct_name(node))
+  }
+}
+
+/// Returns `true` when the path is built only from names.
+fn is_qualified_name(node: &AstNode) -> bool {
+  match node {
+    AstNode::Name(_) => true,
+    AstNode::Path(lhs, rhs) => is_qualified_name(lhs) && is_qualified_name(rhs),
+    _ => false,
+  }
+}
+
+/// Returns `true` when the invocation is a date and time literal, like `date("2024-01-01")`.
+fn is_date_time_literal(function: &AstNode, parameters: &AstNode) -> bool {
+  match (function, parameters) {
+    (AstNode::Name(name), AstNode::PositionalParameters(items)) => {
+      matches!(name.to_string().as_str(), "date" | "time" | "date and time" | "duration") && matches!(items.as_slice(), [AstNode::String(_)])
+    }
+    _ => false,
+  }
+}
+
+/// Returns the name of the construct represented by the node.
+fn construct_name(node: &AstNode) -> &'static str {
+  match node {
+    AstNode::Between(_, _, _) => "`between` expression",
+    AstNode::Context(_) => "context",
+    AstNode::Every(_, _) | AstNode::Some(_, _) => "quantified expression",
+    AstNode::Filter(_, _) => "filter",
+    AstNode::For(_, _) => "`for` expression",
+    AstNode::FunctionDefinition(_, _) => "function definition",
+    AstNode::FunctionInvocation(_, _) => "function invocation",
+    AstNode::If(_, _, _) => "`if` expression",
+    AstNode::In(_, _) => "`in` expression",
+    AstNode::InstanceOf(_, _) => "`instance of` expression",
+    AstNode::List(_) => "list",
+    AstNode::NegatedList(_) => "nested negation",
+    AstNode::Path(_, _) => "path expression",
+    AstNode::Range(_, _) => "range",
+    AstNode::UnaryLt(_) | AstNode::UnaryLe(_) | AstNode::UnaryGt(_) | AstNode::UnaryGe(_) => "unary comparison",
+    _ => "expression",
+  }
+}
@@ -0,0 +1,361 @@
+//! # Consolidation of decision table rules
+//!
+//! Two rules can be merged when they have equal output entries and equal input entries
+//! in all columns except at most one, where the input entries can be combined into
+//! a single unary test:
+//!
+//! - adjacent ranges, like `[1..5]` and `(5..10]`, are combined into `[1..10]`,
+//! - complementary conditions, like `< 18` and `>= 18` or `"a"` and `not("a")`, are combined into `-`,
+//! - irrelevant input entry `-` absorbs any other input entry,
+//! - other input entries are combined into a list of unary tests, like `"a", "b"`.
+//!
+//! Rules are merged repeatedly, until no more rules can be merged, so the result
+//! is a minimized rule set. Merges preserve the result of the decision table:
+//! rules of decision tables with multiple hits are merged only when their input entries
+//! do not overlap, and rules of decision tables with ordered hits are merged only
+//! when they are adjacent. Merged conditions are compared with non-null input values,
+//! e.g. combined complementary conditions also match the `null` input value.
+
+use crate::model::{DecisionRule, DecisionTable, HitPolicy, InputEntry};
+use dmntk_feel::FeelScope;
+use dmntk_feel_parser::AstNode;
+
+/// Rule proposed as a replacement of merged rules of the decision table.
+#[derive(Debug, Clone)]
+pub struct RuleMerge {
+  /// Numbers of merged rules, numbered from 1 like in the decision table.
+  merged_rules: Vec<usize>,
+  /// Rule replacing merged rules.
+  rule: DecisionRule,
+}
+
+impl RuleMerge {
+  /// Returns numbers of merged rules, numbered from 1 like in the decision table.
+  pub fn merged_rules(&self) -> &[usize] {
+    &self.merged_rules
+  }
+
+  /// Returns the rule replacing merged rules.
+  pub fn rule(&self) -> &DecisionRule {
+    &self.rule
+  }
+}
+
+/// Minimized rule set proposed for the decision table.
+#[derive(Debug, Clone)]
+pub struct RuleConsolidation {
+  /// Proposed rules of the decision table, in the order of original rules.
+  rules: Vec<DecisionRule>,
+  /// Merges of rules performed while minimizing the rule set.
+  merges: Vec<RuleMerge>,
+}
+
+impl RuleConsolidation {
+  /// Returns proposed rules of the decision table, in the order of original rules.
+  pub fn rules(&self) -> &[DecisionRule] {
+    &self.rules
+  }
+
+  /// Returns merges of rules, each merge replaces multiple original rules with a single rule.
+  pub fn merges(&self) -> &[RuleMerge] {
+    &self.merges
+  }
+
+  /// Returns `true` when no rules can be merged.
+  pub fn is_minimal(&self) -> bool {
+    self.merges.is_empty()
+  }
+}
+
+/// Rule being consolidated, with numbers of original rules it replaces.
+struct CandidateRule {
+  /// Numbers of original rules replaced by this rule.
+  origins: Vec<usize>,
+  /// Rule with texts of entries.
+  rule: DecisionRule,
+  /// Parsed input entries, `None` when the input entry could not be parsed.
+  input_entries: Vec<Option<AstNode>>,
+  /// Parsed output entries, `None` when the output entry could not be parsed.
+  output_entries: Vec<Option<AstNode>>,
+}
+
+/// Input entry combined from input entries of two rules.
+struct CombinedEntry {
+  /// Text of the combined input entry.
+  text: String,
+  /// Flag indicating if combined input entries do not overlap.
+  disjoint: bool,
+}
+
+/// Proposes a minimized rule set of the decision table, merging rules
+/// with equal output entries and combinable input entries.
+pub fn consolidate_rules(decision_table: &DecisionTable) -> RuleConsolidation {
+  let scope = FeelScope::default();
+  let hit_policy = decision_table.hit_policy();
+  // decision tables with multiple hits return an output for each matched rule
+  let multiple_hits = matches!(hit_policy, HitPolicy::Collect(_) | HitPolicy::RuleOrder | HitPolicy::OutputOrder);
+  // decision tables with ordered hits depend on the order of matched rules
+  let ordered_hits = matches!(hit_policy, HitPolicy::First | HitPolicy::RuleOrder);
+  let mut candidates = decision_table
+    .rules()
+    .enumerate()
+    .map(|(index, rule)| CandidateRule {
+      origins: vec![index + 1],
+      rule: rule.clone(),
+      input_entries: rule
+        .input_entries
+        .iter()
+        .map(|entry| dmntk_feel_parser::parse_unary_tests(&scope, &entry.text, false).ok())
+        .collect(),
+      output_entries: rule
+        .output_entries
+        .iter()
+        .map(|entry| dmntk_feel_parser::parse_expression(&scope, &entry.text, false).ok())
+        .collect(),
+    })
+    .collect::<Vec<CandidateRule>>();
+  'merging: loop {
+    for first in 0..candidates.len() {
+      for second in first + 1..candidates.len() {
+        if ordered_hits && second > first + 1 {
+          break;
+        }
+        if let Some((column, entry)) = merge(&candidates[first], &candidates[second], multiple_hits) {
+          let merged = candidates.remove(second);
+          let candidate = &mut candidates[first];
+          candidate.origins.extend(merged.origins);
+          candidate.origins.sort_unstable();
+          if let Some(column) = column {
+            candidate.rule.input_entries[column] = InputEntry { text: entry };
+            candidate.input_entries[column] = dmntk_feel_parser::parse_unary_tests(&scope, &candidate.rule.input_entries[column].text, false).ok();
+          }
+          continue 'merging;
+        }
+      }
+    }
+    break;
+  }
+  let merges = candidates
+    .iter()
+    .filter(|candidate| candidate.origins.len() > 1)
+    .map(|candidate| RuleMerge {
+      merged_rules: candidate.origins.clone(),
+      rule: candidate.rule.clone(),
+    })
+    .collect();
+  RuleConsolidation {
+    rules: candidates.into_iter().map(|candidate| candidate.rule).collect(),
+    merges,
+  }
+}
+
+/// Checks if two rules can be merged, returns the column with combined input entry
+/// and its text, the column is `None` when all input entries of both rules are equal.
+fn merge(first: &CandidateRule, second: &CandidateRule, multiple_hits: bool) -> Option<(Option<usize>, String)> {
+  if first.rule.output_entries.len() != second.rule.output_entries.len() || first.rule.input_entries.len() != second.rule.input_entries.len() {
+    return None;
+  }
+  let outputs_equal = (0..first.rule.output_entries.len()).all(|index| {
+    equal_entries(
+      &first.output_entries[index],
+      &second.output_entries[index],
+      &first.rule.output_entries[index].text,
+      &second.rule.output_entries[index].text,
+    )
+  });
+  if !outputs_equal {
+    return None;
+  }
+  let different_columns = (0..first.rule.input_entries.len())
+    .filter(|index| {
+      !equal_entries(
+        &first.input_entries[*index],
+        &second.input_entries[*index],
+        &first.rule.input_entries[*index].text,
+        &second.rule.input_entries[*index].text,
+      )
+    })
+    .collect::<Vec<usize>>();
+  match different_columns.as_slice() {
+    // duplicated rules overlap
+    [] if !multiple_hits => Some((None, String::new())),
+    [column] => {
+      let (Some(first_node), Some(second_node)) = (&first.input_entries[*column], &second.input_entries[*column]) else {
+        return None;
+      };
+      let combined = combine(
+        first_node,
+        second_node,
+        first.rule.input_entries[*column].text.trim(),
+        second.rule.input_entries[*column].text.trim(),
+      )?;
+      if multiple_hits && !combined.disjoint {
+        return None;
+      }
+      Some((Some(*column), combined.text))
+    }
+    _ => None,
+  }
+}
+
+/// Checks if two entries are equal, parsed entries are compared regardless of formatting.
+fn equal_entries(first: &Option<AstNode>, second: &Option<AstNode>, first_text: &str, second_text: &str) -> bool {
+  match (first, second) {
+    (Some(first_node), Some(second_node)) => first_node.normalized() == second_node.normalized(),
+    _ => first_text.trim() == second_text.trim(),
+  }
+}
+
+/// Combines two input entries into a single input entry.
+fn combine(first: &AstNode, second: &AstNode, first_text: &str, second_text: &str) -> Option<CombinedEntry> {
+  match (first, second) {
+    (AstNode::Irrelevant, _) | (_, AstNode::Irrelevant) => Some(CombinedEntry {
+      text: "-".to_string(),
+      disjoint: false,
+    }),
+    (AstNode::ExpressionList(items), AstNode::NegatedList(negated_items)) | (AstNode::NegatedList(negated_items), AstNode::ExpressionList(items))
+      if normalized_items(items) == normalized_items(negated_items) =>
+    {
+      Some(CombinedEntry {
+        text: "-".to_string(),
+        disjoint: true,
+      })
+    }
+    (AstNode::ExpressionList(first_items), AstNode::ExpressionList(second_items)) => {
+      if let ([first_item], [second_item]) = (first_items.as_slice(), second_items.as_slice()) {
+        if let (Some(first_interval), Some(second_interval)) = (Interval::new(first_item), Interval::new(second_item)) {
+          if let Some(interval) = first_interval.join(&second_interval).or_else(|| second_interval.join(&first_interval)) {
+            return interval.text().map(|text| CombinedEntry { text, disjoint: true });
+          }
+        }
+      }
+      // literals are disjoint when no literal is repeated
+      let all_literals = first_items.iter().chain(second_items).all(is_literal);
+      let first_normalized = normalized_items(first_items);
+      let disjoint = all_literals && normalized_items(second_items).iter().all(|item| !first_normalized.contains(item));
+      Some(CombinedEntry {
+        text: format!("{first_text}, {second_text}"),
+        disjoint,
+      })
+    }
+    _ => None,
+  }
+}
+
+/// Returns normalized copies of nodes.
+fn normalized_items(items: &[AstNode]) -> Vec<AstNode> {
+  items.iter().map(AstNode::normalized).collect()
+}
+
+/// Returns `true` when the node is a literal value.
+fn is_literal(node: &AstNode) -> bool {
+  matches!(node, AstNode::Numeric(_, _) | AstNode::String(_) | AstNode::Boolean(_) | AstNode::At(_))
+}
+
+/// Bound of an interval, with the flag indicating if the bound is closed.
+type Bound<'a> = Option<(&'a AstNode, bool)>;
+
+/// Interval of values matched by a unary test.
+struct Interval<'a> {
+  /// Lower bound, `None` when the interval is unbounded from below.
+  start: Bound<'a>,
+  /// Upper bound, `None` when the interval is unbounded from above.
+  end: Bound<'a>,
+}
+
+impl<'a> Interval<'a> {
+  /// Creates an interval from unary test, returns `None` when the unary test is not an interval.
+  fn new(node: &'a AstNode) -> Option<Self> {
+    match node {
+      AstNode::UnaryLt(end) => Some(Self {
+        start: None,
+        end: Some((end, false)),
+      }),
+      AstNode::UnaryLe(end) => Some(Self {
+        start: None,
+        end: Some((end, true)),
+      }),
+      AstNode::UnaryGt(start) => Some(Self {
+        start: Some((start, false)),
+        end: None,
+      }),
+      AstNode::UnaryGe(start) => Some(Self {
+        start: Some((start, true)),
+        end: None,
+      }),
+      AstNode::Range(start, end) => match (start.as_ref(), end.as_ref()) {
+        (AstNode::IntervalStart(start, start_closed), AstNode::IntervalEnd(end, end_closed)) => Some(Self {
+          start: Some((start, *start_closed)),
+          end: Some((end, *end_closed)),
+        }),
+        _ => None,
+      },
+      literal if is_literal(literal) => Some(Self {
+        start: Some((literal, true)),
+        end: Some((literal, true)),
+      }),
+      _ => None,
+    }
+  }
+
+  /// Joins this interval with the interval directly following it,
+  /// returns `None` when intervals are not adjacent.
+  fn join(&self, other: &Interval<'a>) -> Option<Interval<'a>> {
+    let ((end, end_closed), (start, start_closed)) = (self.end?, other.start?);
+    // adjacent intervals share the bound, closed in exactly one of them
+    if end_closed != start_closed && end.normalized() == start.normalized() {
+      Some(Interval {
+        start: self.start,
+        end: other.end,
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Returns the text of the unary test matching this interval.
+  fn text(&self) -> Option<String> {
+    Some(match (self.start, self.end) {
+      (None, None) => "-".to_string(),
+      (None, Some((end, closed))) => format!("{} {}", if closed { "<=" } else { "<" }, bound_text(end)?),
+      (Some((start, closed)), None) => format!("{} {}", if closed { ">=" } else { ">" }, bound_text(start)?),
+      (Some((start, start_closed)), Some((end, end_closed))) => {
+        if start_closed && end_closed && start.normalized() == end.normalized() {
+          bound_text(start)?
+        } else {
+          format!(
+            "{}{}..{}{}",
+            if start_closed { "[" } else { "(" },
+            bound_text(start)?,
+            bound_text(end)?,
+            if end_closed { "]" } else { ")" }
+          )
+        }
+      }
+    })
+  }
+}
+
+/// Returns the text of the bound, `None` when the bound is not a literal value or a name.
+fn bound_text(node: &AstNode) -> Option<String> {
+  match node {
+    AstNode::Numeric(before, after) if after.is_empty() => Some(before.clone()),
+    AstNode::Numeric(before, after) => Some(format!("{before}.{after}")),
+    AstNode::Neg(value) => bound_text(value).map(|text| format!("-{text}")),
+    AstNode::String(text) => Some(format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))),
+    AstNode::At(text) => Some(format!("@\"{text}\"")),
+    AstNode::Boolean(value) => Some(value.to_string()),
+    AstNode::Name(name) => Some(name.to_string()),
+    AstNode::QualifiedName(items) => items.iter().map(bound_text).collect::<Option<Vec<String>>>().map(|names| names.join(".")),
+    AstNode::QualifiedNameSegment(name) => Some(name.to_string()),
+    AstNode::FunctionInvocation(function, parameters) => match parameters.as_ref() {
+      AstNode::PositionalParameters(arguments) => Some(format!(
+        "{}({})",
+        bound_text(function)?,
+        arguments.iter().map(bound_text).collect::<Option<Vec<String>>>()?.join(", ")
+      )),
+      _ => None,
+    },
+    _ => None,
+  }
+}
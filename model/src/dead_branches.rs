@@ -0,0 +1,220 @@
+//! # Dead branches
+//!
+//! Detects parts of expressions that are never evaluated and unary tests that never match:
+//!
+//! - branches of `if` expressions with conditions being always `true` or always `false`,
+//! - filters with conditions being always `false`,
+//! - empty ranges of numbers,
+//! - unary tests that never match allowed values or declared type of the tested value.
+//!
+//! Conditions are evaluated statically only when built from literals,
+//! all other conditions are assumed to be satisfiable.
+
+use dmntk_feel_parser::AstNode;
+
+/// Returns descriptions of dead branches found in the expression, in the order of appearance.
+pub fn expression_dead_branches(node: &AstNode) -> Vec<String> {
+  let mut dead_branches = vec![];
+  collect_dead_branches(node, &mut dead_branches);
+  dead_branches
+}
+
+/// Returns the reason why unary tests never match, when tested values are constrained
+/// by allowed values and by the name of the declared type.
+pub fn unmatchable_tests(unary_tests: &AstNode, opt_allowed_values: Option<&AstNode>, opt_type_name: Option<&str>) -> Option<String> {
+  let domain = Domain::from_unary_tests(unary_tests)?;
+  if domain.is_empty() {
+    return Some("range is empty".to_string());
+  }
+  if let Some(type_name) = opt_type_name {
+    if !domain.is_of_type(type_name) {
+      return Some(format!("tested values are of type `{type_name}`"));
+    }
+  }
+  if let Some(allowed_domain) = opt_allowed_values.and_then(Domain::from_unary_tests) {
+    if !domain.intersects(&allowed_domain) {
+      return Some("tested values are constrained by allowed values".to_string());
+    }
+  }
+  None
+}
+
+/// Collects dead branches in the node and its descendants.
+fn collect_dead_branches(node: &AstNode, dead_branches: &mut Vec<String>) {
+  match node {
+    AstNode::If(condition, _, _) => match static_truth(condition) {
+      Some(true) => dead_branches.push("condition of `if` expression is always true, `else` branch is never evaluated".to_string()),
+      Some(false) => dead_branches.push("condition of `if` expression is always false, `then` branch is never evaluated".to_string()),
+      None => {}
+    },
+    AstNode::Filter(_, condition) if static_truth(condition) == Some(false) => {
+      dead_branches.push("condition of filter is always false, filtered list is always empty".to_string());
+    }
+    AstNode::Range(_, _) if Domain::from_unary_tests(node).is_some_and(|domain| domain.is_empty()) => {
+      dead_branches.push("range is empty, no value is ever in this range".to_string());
+    }
+    _ => {}
+  }
+  for child in node.children() {
+    collect_dead_branches(child, dead_branches);
+  }
+}
+
+/// Returns the value of the condition built only from literals, otherwise returns `None`.
+fn static_truth(node: &AstNode) -> Option<bool> {
+  match node {
+    AstNode::Boolean(value) => Some(*value),
+    AstNode::And(lhs, rhs) => match (static_truth(lhs), static_truth(rhs)) {
+      (Some(false), _) | (_, Some(false)) => Some(false),
+      (Some(true), Some(true)) => Some(true),
+      _ => None,
+    },
+    AstNode::Or(lhs, rhs) => match (static_truth(lhs), static_truth(rhs)) {
+      (Some(true), _) | (_, Some(true)) => Some(true),
+      (Some(false), Some(false)) => Some(false),
+      _ => None,
+    },
+    AstNode::FunctionInvocation(function, parameters) => match (function.as_ref(), parameters.as_ref()) {
+      (AstNode::Name(name), AstNode::PositionalParameters(items)) if name.to_string() == "not" && items.len() == 1 => static_truth(&items[0]).map(|value| !value),
+      _ => None,
+    },
+    AstNode::Eq(lhs, rhs) => compare(lhs, rhs).map(|ordering| ordering.is_eq()),
+    AstNode::Nq(lhs, rhs) => compare(lhs, rhs).map(|ordering| ordering.is_ne()),
+    AstNode::Lt(lhs, rhs) => compare(lhs, rhs).map(|ordering| ordering.is_lt()),
+    AstNode::Le(lhs, rhs) => compare(lhs, rhs).map(|ordering| ordering.is_le()),
+    AstNode::Gt(lhs, rhs) => compare(lhs, rhs).map(|ordering| ordering.is_gt()),
+    AstNode::Ge(lhs, rhs) => compare(lhs, rhs).map(|ordering| ordering.is_ge()),
+    _ => None,
+  }
+}
+
+/// Compares two numeric literals or two string literals.
+fn compare(lhs: &AstNode, rhs: &AstNode) -> Option<std::cmp::Ordering> {
+  match (lhs, rhs) {
+    (AstNode::String(lhs), AstNode::String(rhs)) => Some(lhs.cmp(rhs)),
+    _ => number(lhs)?.partial_cmp(&number(rhs)?),
+  }
+}
+
+/// Returns the value of the numeric literal, optionally negated.
+fn number(node: &AstNode) -> Option<f64> {
+  match node {
+    AstNode::Numeric(integer, fraction) => format!("{integer}.{fraction}0").parse().ok(),
+    AstNode::Neg(node) => number(node).map(|value| -value),
+    _ => None,
+  }
+}
+
+/// Interval of numbers, unbounded ends are infinite.
+#[derive(Clone, Copy)]
+struct Interval {
+  start: f64,
+  start_closed: bool,
+  end: f64,
+  end_closed: bool,
+}
+
+impl Interval {
+  /// Returns `true` when no number belongs to this interval.
+  fn is_empty(&self) -> bool {
+    self.start > self.end || (self.start == self.end && !(self.start_closed && self.end_closed))
+  }
+
+  /// Returns the common part of two intervals.
+  fn intersection(&self, other: &Interval) -> Interval {
+    let (start, start_closed) = if self.start > other.start {
+      (self.start, self.start_closed)
+    } else if self.start < other.start {
+      (other.start, other.start_closed)
+    } else {
+      (self.start, self.start_closed && other.start_closed)
+    };
+    let (end, end_closed) = if self.end < other.end {
+      (self.end, self.end_closed)
+    } else if self.end > other.end {
+      (other.end, other.end_closed)
+    } else {
+      (self.end, self.end_closed && other.end_closed)
+    };
+    Interval {
+      start,
+      start_closed,
+      end,
+      end_closed,
+    }
+  }
+}
+
+/// Values matched by unary tests built only from literals.
+enum Domain {
+  /// Numbers belonging to any of the intervals.
+  Numbers(Vec<Interval>),
+  /// Strings equal to any of the listed strings.
+  Strings(Vec<String>),
+}
+
+impl Domain {
+  /// Returns values matched by unary tests, or `None` when the unary tests are not built only from literals.
+  fn from_unary_tests(node: &AstNode) -> Option<Domain> {
+    let interval = |start: f64, start_closed: bool, end: f64, end_closed: bool| {
+      Some(Domain::Numbers(vec![Interval {
+        start,
+        start_closed,
+        end,
+        end_closed,
+      }]))
+    };
+    match node {
+      AstNode::ExpressionList(items) => {
+        let mut domains = items.iter().map(Domain::from_unary_tests).collect::<Option<Vec<Domain>>>()?.into_iter();
+        let first = domains.next()?;
+        domains.try_fold(first, |acc, domain| match (acc, domain) {
+          (Domain::Numbers(mut lhs), Domain::Numbers(rhs)) => {
+            lhs.extend(rhs);
+            Some(Domain::Numbers(lhs))
+          }
+          (Domain::Strings(mut lhs), Domain::Strings(rhs)) => {
+            lhs.extend(rhs);
+            Some(Domain::Strings(lhs))
+          }
+          _ => None,
+        })
+      }
+      AstNode::String(value) => Some(Domain::Strings(vec![value.clone()])),
+      AstNode::UnaryLt(node) => interval(f64::NEG_INFINITY, false, number(node)?, false),
+      AstNode::UnaryLe(node) => interval(f64::NEG_INFINITY, false, number(node)?, true),
+      AstNode::UnaryGt(node) => interval(number(node)?, false, f64::INFINITY, false),
+      AstNode::UnaryGe(node) => interval(number(node)?, true, f64::INFINITY, false),
+      AstNode::Range(start, end) => match (start.as_ref(), end.as_ref()) {
+        (AstNode::IntervalStart(start, start_closed), AstNode::IntervalEnd(end, end_closed)) => interval(number(start)?, *start_closed, number(end)?, *end_closed),
+        _ => None,
+      },
+      other => {
+        let value = number(other)?;
+        interval(value, true, value, true)
+      }
+    }
+  }
+
+  /// Returns `true` when no value is matched.
+  fn is_empty(&self) -> bool {
+    match self {
+      Domain::Numbers(intervals) => intervals.iter().all(Interval::is_empty),
+      Domain::Strings(values) => values.is_empty(),
+    }
+  }
+
+  /// Returns `true` when matched values may be of the type with specified name.
+  fn is_of_type(&self, type_name: &str) -> bool {
+    !matches!((self, type_name), (Domain::Numbers(_), "string") | (Domain::Strings(_), "number"))
+  }
+
+  /// Returns `true` when any value is matched by both domains.
+  fn intersects(&self, other: &Domain) -> bool {
+    match (self, other) {
+      (Domain::Numbers(lhs), Domain::Numbers(rhs)) => lhs.iter().any(|lhs| rhs.iter().any(|rhs| !lhs.intersection(rhs).is_empty())),
+      (Domain::Strings(lhs), Domain::Strings(rhs)) => lhs.iter().any(|value| rhs.contains(value)),
+      _ => false,
+    }
+  }
+}
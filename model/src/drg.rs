@@ -0,0 +1,172 @@
+//! # Decision requirements graph
+//!
+//! Decision requirements graph (DRG) built from DRG elements of one or more [Definitions],
+//! represented as a [DiGraph], ready to be analysed using algorithms provided by [petgraph].
+//!
+//! Edges are directed from the required element to the requiring element,
+//! just like the arrows in decision requirements diagrams.
+//! References to elements that do not exist in provided definitions are skipped.
+
+use crate::model::*;
+use dmntk_common::HRef;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Kind of the element represented by a node in decision requirements graph.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DrgNodeKind {
+  Decision,
+  InputData,
+  BusinessKnowledgeModel,
+  DecisionService,
+  KnowledgeSource,
+}
+
+impl fmt::Display for DrgNodeKind {
+  /// Formats the human-readable name of the kind, the same as returned by [DrgElement::kind].
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      DrgNodeKind::Decision => write!(f, "decision"),
+      DrgNodeKind::InputData => write!(f, "input data"),
+      DrgNodeKind::BusinessKnowledgeModel => write!(f, "business knowledge model"),
+      DrgNodeKind::DecisionService => write!(f, "decision service"),
+      DrgNodeKind::KnowledgeSource => write!(f, "knowledge source"),
+    }
+  }
+}
+
+/// Node of the decision requirements graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrgNode {
+  /// Kind of the element.
+  kind: DrgNodeKind,
+  /// Namespace of the definitions containing the element.
+  namespace: String,
+  /// Identifier of the element.
+  id: String,
+  /// Name of the element.
+  name: String,
+}
+
+impl DrgNode {
+  /// Returns the kind of the element.
+  pub fn kind(&self) -> DrgNodeKind {
+    self.kind
+  }
+  /// Returns the namespace of the definitions containing the element.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+  /// Returns the identifier of the element.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+  /// Returns the name of the element.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+}
+
+/// Edge of the decision requirements graph.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DrgEdge {
+  /// Information requirement, the target element requires the value of the source decision or input data.
+  InformationRequirement,
+  /// Knowledge requirement, the target element invokes the source business knowledge model or decision service.
+  KnowledgeRequirement,
+  /// Authority requirement, the source element is an authority for the target element.
+  AuthorityRequirement,
+  /// The source decision is an output decision of the target decision service.
+  OutputDecision,
+  /// The source decision is encapsulated in the target decision service.
+  EncapsulatedDecision,
+  /// The source decision or input data is an input of the target decision service.
+  ServiceInput,
+}
+
+/// Builds the decision requirements graph from DRG elements of all specified definitions.
+pub fn decision_requirements_graph(definitions: &[Definitions]) -> DiGraph<DrgNode, DrgEdge> {
+  let mut graph = DiGraph::new();
+  let mut node_indexes: HashMap<(String, String), NodeIndex> = HashMap::new();
+  for definitions in definitions {
+    for drg_element in definitions.drg_elements() {
+      let kind = match drg_element {
+        DrgElement::Decision(_) => DrgNodeKind::Decision,
+        DrgElement::InputData(_) => DrgNodeKind::InputData,
+        DrgElement::BusinessKnowledgeModel(_) => DrgNodeKind::BusinessKnowledgeModel,
+        DrgElement::DecisionService(_) => DrgNodeKind::DecisionService,
+        DrgElement::KnowledgeSource(_) => DrgNodeKind::KnowledgeSource,
+      };
+      let node = DrgNode {
+        kind,
+        namespace: definitions.namespace().to_string(),
+        id: drg_element.id().to_string(),
+        name: drg_element.name().to_string(),
+      };
+      let key = (node.namespace.clone(), node.id.clone());
+      node_indexes.insert(key, graph.add_node(node));
+    }
+  }
+  for definitions in definitions {
+    let namespace = definitions.namespace();
+    for drg_element in definitions.drg_elements() {
+      let Some(target) = node_indexes.get(&(namespace.to_string(), drg_element.id().to_string())).copied() else {
+        continue;
+      };
+      let mut add_edges = |hrefs: Vec<&HRef>, edge: DrgEdge| {
+        for href in hrefs {
+          let source_namespace = href.namespace().map(|namespace| namespace.as_str()).unwrap_or(namespace);
+          if let Some(source) = node_indexes.get(&(source_namespace.to_string(), href.id().to_string())) {
+            graph.add_edge(*source, target, edge);
+          }
+        }
+      };
+      match drg_element {
+        DrgElement::Decision(decision) => {
+          add_edges(information_requirements(decision.information_requirements()), DrgEdge::InformationRequirement);
+          add_edges(knowledge_requirements(decision.knowledge_requirements()), DrgEdge::KnowledgeRequirement);
+          add_edges(authority_requirements(decision.authority_requirements()), DrgEdge::AuthorityRequirement);
+        }
+        DrgElement::BusinessKnowledgeModel(bkm) => {
+          add_edges(knowledge_requirements(bkm.knowledge_requirements()), DrgEdge::KnowledgeRequirement);
+          add_edges(authority_requirements(bkm.authority_requirements()), DrgEdge::AuthorityRequirement);
+        }
+        DrgElement::DecisionService(decision_service) => {
+          add_edges(decision_service.output_decisions().iter().collect(), DrgEdge::OutputDecision);
+          add_edges(decision_service.encapsulated_decisions().iter().collect(), DrgEdge::EncapsulatedDecision);
+          add_edges(decision_service.input_decisions().iter().collect(), DrgEdge::ServiceInput);
+          add_edges(decision_service.input_data().iter().collect(), DrgEdge::ServiceInput);
+        }
+        DrgElement::KnowledgeSource(knowledge_source) => {
+          add_edges(authority_requirements(knowledge_source.authority_requirements()), DrgEdge::AuthorityRequirement);
+        }
+        DrgElement::InputData(_) => {}
+      }
+    }
+  }
+  graph
+}
+
+/// Returns references to elements required by information requirements.
+fn information_requirements(information_requirements: &[InformationRequirement]) -> Vec<&HRef> {
+  information_requirements
+    .iter()
+    .flat_map(|requirement| [requirement.required_decision(), requirement.required_input()])
+    .flatten()
+    .collect()
+}
+
+/// Returns references to elements required by knowledge requirements.
+fn knowledge_requirements(knowledge_requirements: &[KnowledgeRequirement]) -> Vec<&HRef> {
+  knowledge_requirements.iter().map(|requirement| requirement.required_knowledge()).collect()
+}
+
+/// Returns references to elements required by authority requirements.
+fn authority_requirements(authority_requirements: &[AuthorityRequirement]) -> Vec<&HRef> {
+  authority_requirements
+    .iter()
+    .flat_map(|requirement| [requirement.required_authority(), requirement.required_decision(), requirement.required_input()])
+    .flatten()
+    .collect()
+}
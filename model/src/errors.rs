@@ -1,32 +1,39 @@
-use dmntk_common::{DmntkError, ToErrorMessage};
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
+use dmntk_feel::ConformanceLevel;
 
 /// Errors related to the DMN model.
 #[derive(ToErrorMessage)]
-struct ModelError(String);
+struct ModelError(Message);
 
 pub fn err_invalid_decision_table_orientation(orientation: &str) -> DmntkError {
-  ModelError(format!("invalid decision table orientation: {orientation}")).into()
+  ModelError(message!("invalid decision table orientation: {}", orientation)).into()
 }
 
 pub fn err_invalid_decision_table_hit_policy(hit_policy: &str) -> DmntkError {
-  ModelError(format!("invalid decision table hit policy: {hit_policy}")).into()
+  ModelError(message!("invalid decision table hit policy: {}", hit_policy)).into()
+}
+
+/// Raised when the element with specified identifier or name was not found in any definitions.
+pub fn err_element_not_found(id: &str) -> DmntkError {
+  ModelError(message!("element with identifier or name '{}' not found", id)).into()
 }
 
 /// Errors related with parsing the decision model.
 #[derive(ToErrorMessage)]
-struct ModelParserError(String);
+struct ModelParserError(Message);
 
 /// Raised when parsed text is not a valid function kind, accepted values are:
 /// `FEEL`, `Java` or `PMML`.
 pub fn err_invalid_function_kind(s: &str) -> DmntkError {
-  ModelParserError(format!("'{s}' is not a valid function kind, accepted values are: 'FEEL', 'Java', 'PMML'")).into()
+  ModelParserError(message!("'{}' is not a valid function kind, accepted values are: 'FEEL', 'Java', 'PMML'", s)).into()
 }
 
 /// Raised when parsed text is not a valid hit policy, accepted values are:
 /// `UNIQUE`, `FIRST`, `PRIORITY`, `ANY`, `COLLECT`, `RULE ORDER` or `OUTPUT ORDER`.
 pub fn err_invalid_hit_policy(s: &str) -> DmntkError {
-  ModelParserError(format!(
-    "'{s}' is not a valid hit policy, allowed values are: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'"
+  ModelParserError(message!(
+    "'{}' is not a valid hit policy, allowed values are: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'",
+    s
   ))
   .into()
 }
@@ -34,63 +41,92 @@ pub fn err_invalid_hit_policy(s: &str) -> DmntkError {
 /// Raised when parsed text is not a valid aggregation for hit policy, accepted values are:
 /// `COUNT`, `SUM`, `MIN`, or `MAX`.
 pub fn err_invalid_aggregation(s: &str) -> DmntkError {
-  ModelParserError(format!("'{s}' is not a valid aggregation, allowed values are: 'COUNT', 'SUM', 'MIN', 'MAX'")).into()
+  ModelParserError(message!("'{}' is not a valid aggregation, allowed values are: 'COUNT', 'SUM', 'MIN', 'MAX'", s)).into()
 }
 
 /// Invalid value for a color.
-pub fn err_invalid_color_value(s: &str) -> DmntkError {
-  ModelParserError(format!("conversion to valid color value failed with reason: {s}")).into()
+pub fn err_invalid_color_value(s: impl Into<Message>) -> DmntkError {
+  let s: Message = s.into();
+  ModelParserError(message!("conversion to valid color value failed with reason: {msg}", s)).into()
 }
 
 /// Invalid value for a double.
-pub fn err_invalid_double_value(reason: &str) -> DmntkError {
-  ModelParserError(format!("conversion to valid double value failed with reason: {reason}")).into()
+pub fn err_invalid_double_value(reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  ModelParserError(message!("conversion to valid double value failed with reason: {msg}", reason)).into()
 }
 
 /// Raised when required child node is missing.
 pub fn err_required_child_node_is_missing(s1: &str, s2: &str) -> DmntkError {
-  ModelParserError(format!("required child node '{s2}' in parent node '{s1}' is missing")).into()
+  ModelParserError(message!("required child node '{}' in parent node '{}' is missing", s2, s1)).into()
 }
 
 /// Raised when required `inputExpression` node is missing.
 pub fn err_required_input_expression_is_missing() -> DmntkError {
-  ModelParserError("required input expression in decision table's input clause is missing".to_string()).into()
+  ModelParserError(message!("required input expression in decision table's input clause is missing")).into()
 }
 
 /// Raised when required expression instance is missing.
 pub fn err_required_expression_instance_is_missing() -> DmntkError {
-  ModelParserError("required expression instance is missing".to_string()).into()
+  ModelParserError(message!("required expression instance is missing")).into()
 }
 
 /// Raised when the number of elements in a row differs from the number of columns in relation.
 pub fn err_number_of_elements_in_row_differs_from_number_of_columns() -> DmntkError {
-  ModelParserError("number of elements in a row differs from the number of columns defined in a relation".to_string()).into()
+  ModelParserError(message!("number of elements in a row differs from the number of columns defined in a relation")).into()
 }
 
-pub fn err_xml_parsing_model_failed(s: &str) -> DmntkError {
-  ModelParserError(format!("parsing model from XML failed with reason: {s}")).into()
+pub fn err_xml_parsing_model_failed(s: impl Into<Message>) -> DmntkError {
+  let s: Message = s.into();
+  ModelParserError(message!("parsing model from XML failed with reason: {msg}", s)).into()
 }
 
 pub fn err_xml_unexpected_node(s1: &str, s2: &str) -> DmntkError {
-  ModelParserError(format!("unexpected XML node, expected: {s1}, actual: {s2}")).into()
+  ModelParserError(message!("unexpected XML node, expected: {}, actual: {}", s1, s2)).into()
 }
 
 pub fn err_xml_expected_mandatory_attribute(s1: &str, s2: &str) -> DmntkError {
-  ModelParserError(format!("expected value for mandatory attribute '{s2}' in node {s1}")).into()
+  ModelParserError(message!("expected value for mandatory attribute '{}' in node {}", s2, s1)).into()
 }
 
 pub fn err_xml_expected_mandatory_child_node(s1: &str, s2: &str) -> DmntkError {
-  ModelParserError(format!("expected mandatory child node '{s2}' in parent node {s1}")).into()
+  ModelParserError(message!("expected mandatory child node '{}' in parent node {}", s2, s1)).into()
 }
 
 pub fn err_xml_expected_mandatory_text_content(s: &str) -> DmntkError {
-  ModelParserError(format!("expected mandatory text content in node '{s}'")).into()
+  ModelParserError(message!("expected mandatory text content in node '{}'", s)).into()
 }
 
 /// Errors related with validating the decision model.
 #[derive(ToErrorMessage)]
-struct ModelValidatorError(String);
+struct ModelValidatorError(Message);
 
 pub fn err_item_definitions_cycle() -> DmntkError {
-  ModelValidatorError("cyclic dependency between item definitions".to_string()).into()
+  ModelValidatorError(message!("cyclic dependency between item definitions")).into()
+}
+
+pub fn err_requirements_cycle(path: &str) -> DmntkError {
+  ModelValidatorError(message!("cyclic dependency between decision requirements: {}", path)).into()
+}
+
+pub fn err_variable_name_collision(name: &str, location: &str, other_location: &str) -> DmntkError {
+  ModelValidatorError(message!("variable name `{}` of {} collides with variable name of {}", name, location, other_location)).into()
+}
+
+/// Errors reported while validating XML document against DMN schema.
+#[derive(ToErrorMessage)]
+struct ModelSchemaError(Message);
+
+pub fn err_schema_violation(line: u32, column: u32, path: &str, reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  ModelSchemaError(message!("line {}, column {}, element {}: {msg}", line, column, path, reason)).into()
+}
+
+/// Errors related to conformance levels of decision models.
+#[derive(ToErrorMessage)]
+struct ConformanceLevelError(Message);
+
+/// Raised when the decision logic uses a construct not defined at the selected conformance level.
+pub fn err_not_allowed_at_conformance_level(construct: &str, location: &str, level: ConformanceLevel) -> DmntkError {
+  ConformanceLevelError(message!("{} in {} is not allowed at conformance level {}", construct, location, level)).into()
 }
@@ -0,0 +1,122 @@
+//! # Impact analysis
+//!
+//! Finds decisions and decision services affected by changing an item definition,
+//! input data, business knowledge model or any other DRG element.
+
+use crate::drg::{decision_requirements_graph, DrgEdge, DrgNode, DrgNodeKind};
+use crate::errors::err_element_not_found;
+use crate::model::*;
+use dmntk_common::Result;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use std::collections::{HashSet, VecDeque};
+
+/// Returns decisions and decision services affected by changing the element with specified identifier.
+///
+/// The changed element is a DRG element identified by its identifier, or an item definition
+/// identified by its identifier or name. Changing an item definition affects item definitions
+/// built from it and DRG elements typed with any of them (including parameters of business
+/// knowledge models) in the same definitions. Changes propagate along information requirements,
+/// knowledge requirements and decision service relationships, authority requirements are ignored.
+///
+/// Affected elements are ordered by namespace and name, the changed element itself is not reported.
+pub fn impact_analysis(definitions: &[Definitions], id: &str) -> Result<Vec<DrgNode>> {
+  let graph = decision_requirements_graph(definitions);
+  let mut changed = HashSet::new();
+  let mut affected = HashSet::new();
+  for node_index in graph.node_indices() {
+    if graph[node_index].id() == id {
+      changed.insert(node_index);
+    }
+  }
+  let mut found = !changed.is_empty();
+  for definitions in definitions {
+    let type_names = affected_type_names(definitions, id);
+    if type_names.is_empty() {
+      continue;
+    }
+    found = true;
+    for drg_element in definitions.drg_elements() {
+      if uses_types(drg_element, &type_names) {
+        if let Some(node_index) = graph
+          .node_indices()
+          .find(|index| graph[*index].namespace() == definitions.namespace() && graph[*index].id() == drg_element.id())
+        {
+          affected.insert(node_index);
+        }
+      }
+    }
+  }
+  if !found {
+    return Err(err_element_not_found(id));
+  }
+  let mut queue = changed.iter().chain(affected.iter()).copied().collect::<VecDeque<NodeIndex>>();
+  while let Some(node_index) = queue.pop_front() {
+    for edge in graph.edges_directed(node_index, Direction::Outgoing) {
+      if *edge.weight() != DrgEdge::AuthorityRequirement && !changed.contains(&edge.target()) && affected.insert(edge.target()) {
+        queue.push_back(edge.target());
+      }
+    }
+  }
+  let mut nodes = affected
+    .into_iter()
+    .map(|node_index| &graph[node_index])
+    .filter(|node| matches!(node.kind(), DrgNodeKind::Decision | DrgNodeKind::DecisionService))
+    .cloned()
+    .collect::<Vec<DrgNode>>();
+  nodes.sort_by(|a, b| (a.namespace(), a.name(), a.id()).cmp(&(b.namespace(), b.name(), b.id())));
+  Ok(nodes)
+}
+
+/// Returns names of top-level item definitions affected by changing the item definition
+/// with specified identifier or name, including the changed item definition itself.
+/// Returns an empty set when no such item definition exists in specified definitions.
+fn affected_type_names(definitions: &Definitions, id: &str) -> HashSet<String> {
+  let mut type_names = definitions
+    .item_definitions()
+    .iter()
+    .filter(|item_definition| item_definition.opt_id().is_some_and(|opt_id| opt_id == id) || item_definition.name() == id)
+    .map(|item_definition| item_definition.name().to_string())
+    .collect::<HashSet<String>>();
+  if type_names.is_empty() {
+    return type_names;
+  }
+  loop {
+    let dependent = definitions
+      .item_definitions()
+      .iter()
+      .filter(|item_definition| !type_names.contains(item_definition.name()) && references_types(item_definition, &type_names))
+      .map(|item_definition| item_definition.name().to_string())
+      .collect::<Vec<String>>();
+    if dependent.is_empty() {
+      return type_names;
+    }
+    type_names.extend(dependent);
+  }
+}
+
+/// Returns `true` when specified item definition or any of its components is typed with one of specified types.
+fn references_types(item_definition: &ItemDefinition, type_names: &HashSet<String>) -> bool {
+  item_definition.type_ref().as_ref().is_some_and(|type_ref| type_names.contains(type_ref))
+    || item_definition.item_components().iter().any(|item_component| references_types(item_component, type_names))
+}
+
+/// Returns `true` when the variable of specified DRG element, or any parameter
+/// of the business knowledge model, is typed with one of specified types.
+fn uses_types(drg_element: &DrgElement, type_names: &HashSet<String>) -> bool {
+  let uses = |information_item: &InformationItem| type_names.contains(information_item.type_ref());
+  match drg_element {
+    DrgElement::Decision(decision) => uses(decision.variable()),
+    DrgElement::InputData(input_data) => uses(input_data.variable()),
+    DrgElement::BusinessKnowledgeModel(bkm) => {
+      uses(bkm.variable())
+        || bkm
+          .encapsulated_logic()
+          .as_ref()
+          .is_some_and(|function_definition| function_definition.formal_parameters().iter().any(uses))
+    }
+    DrgElement::DecisionService(decision_service) => uses(decision_service.variable()),
+    DrgElement::KnowledgeSource(_) => false,
+  }
+}
@@ -1,14 +1,42 @@
 #[macro_use]
 extern crate dmntk_macros;
 
+mod complexity;
+mod conformance;
+mod consolidation;
+mod dead_branches;
+mod drg;
 mod errors;
+mod impact;
+mod linter;
+mod low_memory;
 mod model;
 mod parser;
+mod sarif;
+mod schema;
+mod scope_variables;
+mod search;
+mod source_map;
+mod syntax;
 mod validator;
 mod xml_utils;
 
 #[cfg(test)]
 mod tests;
 
+pub use complexity::{estimate_complexity, ComplexityClass, DecisionComplexity};
+pub use conformance::validate_conformance_level;
+pub use consolidation::{consolidate_rules, RuleConsolidation, RuleMerge};
+pub use drg::{decision_requirements_graph, DrgEdge, DrgNode, DrgNodeKind};
+pub use impact::impact_analysis;
+pub use linter::{lint, LintWarning, LINT_DEAD_BRANCH, LINT_INVALID_NAME, LINT_MERGEABLE_RULES, LINT_SHADOWED_BUILT_IN_FUNCTION};
 pub use model::*;
-pub use parser::parse;
+pub use parser::{parse, parse_low_memory};
+pub use petgraph;
+pub use sarif::{SarifReport, RULE_INVALID_MODEL, RULE_SCHEMA_VIOLATION};
+pub use schema::{schema_violations, validate_schema, DmnVersion};
+pub use scope_variables::{scope_variables, ScopeVariable, ScopeVariableKind};
+pub use search::{search, SearchField, SearchResult};
+pub use source_map::{SourceMap, SourcePosition, SourceSpan};
+pub use syntax::{SyntaxChecker, SyntaxDiagnostic};
+pub use validator::validate_requirements;
@@ -0,0 +1,326 @@
+//! # Decision model linter
+//!
+//! Lints point to parts of decision models that are valid and evaluate correctly,
+//! but could be simplified. Reported lints:
+//!
+//! - Rules of decision tables that can be merged, see [consolidate_rules].
+//! - Branches of literal expressions that are never evaluated and input entries
+//!   of decision tables that never match, see [expression_dead_branches].
+//! - Names of DRG elements and item definitions that are not valid `FEEL` names,
+//!   such elements can not be referenced in `FEEL` expressions.
+//! - Names of input data shadowing built-in functions, such functions
+//!   can not be invoked in expressions requiring the input data.
+//!
+
+use crate::consolidation::consolidate_rules;
+use crate::dead_branches::{expression_dead_branches, unmatchable_tests};
+use crate::model::{DecisionRule, DecisionTable, Definitions, DrgElement, ExpressionInstance, ItemDefinition, LiteralExpression, NamedElement};
+use crate::source_map::SourceSpan;
+use crate::DmnElement;
+use dmntk_feel::bif::Bif;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelScope, Name};
+use dmntk_feel_parser::AstNode;
+use std::fmt;
+
+/// Code of the lint reporting rules of decision tables that can be merged.
+pub const LINT_MERGEABLE_RULES: &str = "mergeable-rules";
+
+/// Code of the lint reporting branches that are never evaluated and input entries that never match.
+pub const LINT_DEAD_BRANCH: &str = "dead-branch";
+
+/// Code of the lint reporting names that are not valid `FEEL` names.
+pub const LINT_INVALID_NAME: &str = "invalid-name";
+
+/// Code of the lint reporting names of input data shadowing built-in functions.
+pub const LINT_SHADOWED_BUILT_IN_FUNCTION: &str = "shadowed-built-in-function";
+
+/// Warning reported by the linter.
+#[derive(Debug, Clone)]
+pub struct LintWarning {
+  /// Code of the lint.
+  code: &'static str,
+  /// Identifier of the element the warning refers to.
+  element_id: String,
+  /// Message describing the warning.
+  message: String,
+  /// Span of the element in the original document.
+  span: Option<SourceSpan>,
+}
+
+impl LintWarning {
+  /// Returns the code of the lint.
+  pub fn code(&self) -> &str {
+    self.code
+  }
+
+  /// Returns the identifier of the element the warning refers to.
+  pub fn element_id(&self) -> &str {
+    &self.element_id
+  }
+
+  /// Returns the message describing the warning.
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// Returns the span of the element in the original document.
+  pub fn span(&self) -> Option<&SourceSpan> {
+    self.span.as_ref()
+  }
+}
+
+impl fmt::Display for LintWarning {
+  /// Formats the warning with the position of the element, when known.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.span {
+      Some(span) => write!(f, "{} [{}]: {}", span.start(), self.code, self.message),
+      None => write!(f, "[{}]: {}", self.code, self.message),
+    }
+  }
+}
+
+/// Lints the decision model, returns warnings in the order of elements in the model.
+pub fn lint(definitions: &Definitions) -> Vec<LintWarning> {
+  let mut warnings = vec![];
+  let scope = parsing_scope(definitions);
+  for drg_element in definitions.drg_elements() {
+    lint_name(definitions, drg_element.kind(), drg_element, &mut warnings);
+    if let DrgElement::InputData(input_data) = drg_element {
+      if input_data.name().trim().parse::<Bif>().is_ok() {
+        warnings.push(LintWarning {
+          code: LINT_SHADOWED_BUILT_IN_FUNCTION,
+          element_id: input_data.id().to_string(),
+          message: format!("name of input data `{}` shadows built-in function", input_data.name()),
+          span: definitions.source_span(input_data.id()).cloned(),
+        });
+      }
+    }
+    let expression_instance = match drg_element {
+      DrgElement::Decision(decision) => decision.decision_logic().as_ref(),
+      DrgElement::BusinessKnowledgeModel(bkm) => bkm.encapsulated_logic().as_ref().and_then(|function_definition| function_definition.body().as_ref()),
+      _ => None,
+    };
+    if let Some(expression_instance) = expression_instance {
+      let location = format!("{} `{}`", drg_element.kind(), drg_element.name());
+      lint_expression_instance(definitions, &scope, &location, expression_instance, &mut warnings);
+    }
+  }
+  for item_definition in definitions.item_definitions() {
+    lint_item_definition_names(definitions, item_definition, &mut warnings);
+  }
+  warnings
+}
+
+/// Reports the name of the element that is not a valid `FEEL` name.
+fn lint_name(definitions: &Definitions, kind: &str, element: &dyn NamedElement, warnings: &mut Vec<LintWarning>) {
+  if !dmntk_feel_parser::is_name(element.name()) {
+    warnings.push(LintWarning {
+      code: LINT_INVALID_NAME,
+      element_id: element.id().to_string(),
+      message: format!("name of {kind} `{}` is not a valid FEEL name", element.name()),
+      span: definitions.source_span(element.id()).cloned(),
+    });
+  }
+}
+
+/// Reports names of the item definition and all its components that are not valid `FEEL` names.
+fn lint_item_definition_names(definitions: &Definitions, item_definition: &ItemDefinition, warnings: &mut Vec<LintWarning>) {
+  lint_name(definitions, "item definition", item_definition, warnings);
+  for item_component in item_definition.item_components() {
+    lint_item_definition_names(definitions, item_component, warnings);
+  }
+}
+
+/// Prepares the scope for parsing expressions, containing names of variables
+/// defined in the model, so names built from multiple words are properly recognized.
+pub(crate) fn parsing_scope(definitions: &Definitions) -> FeelScope {
+  let mut ctx = FeelContext::default();
+  for drg_element in definitions.drg_elements() {
+    ctx.set_entry(&Name::from(drg_element.name()), Value::Null(None));
+    let expression_instance = match drg_element {
+      DrgElement::Decision(decision) => decision.decision_logic().as_ref(),
+      DrgElement::BusinessKnowledgeModel(bkm) => bkm.encapsulated_logic().as_ref().and_then(|function_definition| {
+        for parameter in function_definition.formal_parameters() {
+          ctx.set_entry(&Name::from(parameter.name()), Value::Null(None));
+        }
+        function_definition.body().as_ref()
+      }),
+      _ => None,
+    };
+    if let Some(expression_instance) = expression_instance {
+      collect_names(expression_instance, &mut ctx);
+    }
+  }
+  ctx.into()
+}
+
+/// Collects names of context entries and parameters defined in the expression instance.
+fn collect_names(expression_instance: &ExpressionInstance, ctx: &mut FeelContext) {
+  match expression_instance {
+    ExpressionInstance::Context(context) => {
+      for context_entry in context.context_entries() {
+        if let Some(variable) = &context_entry.variable {
+          ctx.set_entry(&Name::from(variable.name()), Value::Null(None));
+        }
+        collect_names(&context_entry.value, ctx);
+      }
+    }
+    ExpressionInstance::FunctionDefinition(function_definition) => {
+      for parameter in function_definition.formal_parameters() {
+        ctx.set_entry(&Name::from(parameter.name()), Value::Null(None));
+      }
+      if let Some(body) = function_definition.body() {
+        collect_names(body, ctx);
+      }
+    }
+    ExpressionInstance::Invocation(invocation) => {
+      for binding in &invocation.bindings {
+        ctx.set_entry(&Name::from(binding.parameter.name()), Value::Null(None));
+        if let Some(binding_formula) = &binding.binding_formula {
+          collect_names(binding_formula, ctx);
+        }
+      }
+    }
+    ExpressionInstance::List(list) => {
+      for element in &list.elements {
+        collect_names(element, ctx);
+      }
+    }
+    ExpressionInstance::DecisionTable(_) | ExpressionInstance::LiteralExpression(_) | ExpressionInstance::Relation(_) => {}
+  }
+}
+
+/// Lints the expression instance and all expression instances nested in it.
+fn lint_expression_instance(definitions: &Definitions, scope: &FeelScope, location: &str, expression_instance: &ExpressionInstance, warnings: &mut Vec<LintWarning>) {
+  match expression_instance {
+    ExpressionInstance::Context(context) => {
+      for context_entry in context.context_entries() {
+        lint_expression_instance(definitions, scope, location, &context_entry.value, warnings);
+      }
+    }
+    ExpressionInstance::DecisionTable(decision_table) => {
+      lint_decision_table(definitions, location, decision_table, warnings);
+      lint_unmatchable_entries(definitions, scope, location, decision_table, warnings);
+    }
+    ExpressionInstance::FunctionDefinition(function_definition) => {
+      if let Some(body) = function_definition.body() {
+        lint_expression_instance(definitions, scope, location, body, warnings);
+      }
+    }
+    ExpressionInstance::Invocation(invocation) => {
+      for binding in &invocation.bindings {
+        if let Some(binding_formula) = &binding.binding_formula {
+          lint_expression_instance(definitions, scope, location, binding_formula, warnings);
+        }
+      }
+    }
+    ExpressionInstance::List(list) => {
+      for element in &list.elements {
+        lint_expression_instance(definitions, scope, location, element, warnings);
+      }
+    }
+    ExpressionInstance::LiteralExpression(literal_expression) => lint_literal_expression(definitions, scope, location, literal_expression, warnings),
+    ExpressionInstance::Relation(_) => {}
+  }
+}
+
+/// Reports branches of the literal expression that are never evaluated.
+fn lint_literal_expression(definitions: &Definitions, scope: &FeelScope, location: &str, literal_expression: &LiteralExpression, warnings: &mut Vec<LintWarning>) {
+  // expressions that could not be parsed are reported by the evaluator
+  let Some(node) = literal_expression
+    .text()
+    .as_ref()
+    .and_then(|text| dmntk_feel_parser::parse_expression(scope, text, false).ok())
+  else {
+    return;
+  };
+  for dead_branch in expression_dead_branches(&node) {
+    warnings.push(LintWarning {
+      code: LINT_DEAD_BRANCH,
+      element_id: literal_expression.id().to_string(),
+      message: format!("{dead_branch}, in literal expression in {location}"),
+      span: definitions.source_span(literal_expression.id()).cloned(),
+    });
+  }
+}
+
+/// Reports input entries of the decision table that never match allowed values
+/// or declared types of input values, so rules containing them never match.
+fn lint_unmatchable_entries(definitions: &Definitions, scope: &FeelScope, location: &str, decision_table: &DecisionTable, warnings: &mut Vec<LintWarning>) {
+  let parse = |text: &str| dmntk_feel_parser::parse_unary_tests(scope, text, false).ok();
+  let constraints = decision_table
+    .input_clauses()
+    .map(|input_clause| {
+      let (opt_type_name, opt_type_allowed_values) = declared_type(definitions, input_clause.input_expression.trim(), input_clause.input_expression_type_ref.as_deref());
+      let opt_allowed_values = input_clause.allowed_input_values.as_deref().or(opt_type_allowed_values.as_deref()).and_then(parse);
+      (opt_type_name, opt_allowed_values)
+    })
+    .collect::<Vec<(Option<String>, Option<AstNode>)>>();
+  for (rule_index, rule) in decision_table.rules().enumerate() {
+    for (input_entry, (opt_type_name, opt_allowed_values)) in rule.input_entries.iter().zip(&constraints) {
+      let Some(unary_tests) = parse(&input_entry.text) else {
+        continue;
+      };
+      if let Some(reason) = unmatchable_tests(&unary_tests, opt_allowed_values.as_ref(), opt_type_name.as_deref()) {
+        warnings.push(LintWarning {
+          code: LINT_DEAD_BRANCH,
+          element_id: decision_table.id().to_string(),
+          message: format!(
+            "input entry `{}` of rule {} of decision table in {location} never matches, {reason}",
+            input_entry.text.trim(),
+            rule_index + 1
+          ),
+          span: definitions.source_span(decision_table.id()).cloned(),
+        });
+      }
+    }
+  }
+}
+
+/// Returns the name of the base type and the text of allowed values of the input expression.
+/// When not specified in the input clause, the type of the input data or decision named
+/// like the input expression is taken, the type may be defined by item definition.
+fn declared_type(definitions: &Definitions, input_expression: &str, opt_type_ref: Option<&str>) -> (Option<String>, Option<String>) {
+  let type_ref = opt_type_ref.map(|type_ref| type_ref.to_string()).or_else(|| {
+    definitions.drg_elements().find_map(|drg_element| match drg_element {
+      DrgElement::InputData(input_data) if input_data.name() == input_expression => Some(input_data.variable.type_ref.clone()),
+      DrgElement::Decision(decision) if decision.name() == input_expression => Some(decision.variable.type_ref.clone()),
+      _ => None,
+    })
+  });
+  let Some(type_ref) = type_ref else {
+    return (None, None);
+  };
+  match definitions.item_definitions().iter().find(|item_definition| item_definition.name() == type_ref) {
+    Some(item_definition) => (
+      item_definition.type_ref.clone(),
+      item_definition.allowed_values().as_ref().and_then(|allowed_values| allowed_values.text().clone()),
+    ),
+    None => (Some(type_ref), None),
+  }
+}
+
+/// Reports rules of the decision table that can be merged.
+fn lint_decision_table(definitions: &Definitions, location: &str, decision_table: &DecisionTable, warnings: &mut Vec<LintWarning>) {
+  for rule_merge in consolidate_rules(decision_table).merges() {
+    let rule_numbers = rule_merge.merged_rules().iter().map(|number| number.to_string()).collect::<Vec<String>>().join(", ");
+    warnings.push(LintWarning {
+      code: LINT_MERGEABLE_RULES,
+      element_id: decision_table.id().to_string(),
+      message: format!(
+        "rules {rule_numbers} of decision table in {location} can be merged into rule: {}",
+        rule_text(rule_merge.rule())
+      ),
+      span: definitions.source_span(decision_table.id()).cloned(),
+    });
+  }
+}
+
+/// Returns the text of the rule, input entries are followed by output entries.
+fn rule_text(rule: &DecisionRule) -> String {
+  let input_entries = rule.input_entries.iter().map(|entry| entry.text.trim()).collect::<Vec<&str>>().join(" | ");
+  let output_entries = rule.output_entries.iter().map(|entry| entry.text.trim()).collect::<Vec<&str>>().join(" | ");
+  format!("{input_entries} => {output_entries}")
+}
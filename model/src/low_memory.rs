@@ -0,0 +1,258 @@
+//! # Low-memory parsing of large DMN models
+//!
+//! Building the whole document tree of a DMN model containing decision tables
+//! with tens of thousands of rules requires many times more memory than the model itself.
+//! In low-memory mode, the document is scanned once without building the tree,
+//! and rules of decision tables are detached from the document in chunks.
+//! The remaining skeleton of the document is parsed as usual, and while parsing
+//! the decision table, its rules are parsed chunk by chunk, so at most one
+//! small document tree of rules is kept in memory at the same time.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Maximum number of rules parsed in a single chunk.
+const RULES_PER_CHUNK: usize = 1024;
+
+/// Chunk of consecutive rules detached from the document.
+pub struct RuleChunk {
+  /// Range of the chunk in the original document.
+  pub range: Range<usize>,
+  /// Namespace declarations in scope of the rules, ready to be placed in a start tag.
+  pub namespaces: String,
+}
+
+/// Rules detached from the original document.
+pub struct DetachedRules<'a> {
+  /// Original document.
+  pub xml: &'a str,
+  /// Chunks of rules, indexed by the position of the decision table in the skeleton document.
+  pub chunks: HashMap<usize, Vec<RuleChunk>>,
+  /// Positions in the skeleton document where chunks were detached,
+  /// with the total length of all chunks detached up to this position.
+  pub detached: Vec<(usize, usize)>,
+}
+
+impl DetachedRules<'_> {
+  /// Returns the position in the original document corresponding to specified position in the skeleton document.
+  pub fn original_position(&self, position: usize) -> usize {
+    match self.detached.partition_point(|(detached_position, _)| *detached_position <= position) {
+      0 => position,
+      index => position + self.detached[index - 1].1,
+    }
+  }
+
+  /// Returns the range in the original document corresponding to specified range in the skeleton document.
+  pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+    // the end is mapped from the last character, so it is not moved behind the chunk detached just after the range
+    let end = if range.is_empty() { range.end } else { self.original_position(range.end - 1) + 1 };
+    self.original_position(range.start)..end
+  }
+}
+
+/// Element opened while scanning the document.
+struct OpenElement<'a> {
+  /// Local name of the element.
+  name: &'a str,
+  /// Namespace declarations of the element, as pairs of the attribute name and the raw value.
+  namespaces: Vec<(&'a str, &'a str)>,
+  /// Position of the element in the skeleton document, set for decision tables.
+  skeleton_position: Option<usize>,
+  /// Position of the rule in the original document, set for rules of decision tables.
+  rule_start: Option<usize>,
+}
+
+/// Scanner detaching rules of decision tables from the document.
+struct Scanner<'a> {
+  /// Scanned document.
+  xml: &'a str,
+  /// Skeleton document, containing everything except detached rules.
+  skeleton: String,
+  /// Position in the original document up to which the content was copied to skeleton.
+  copied: usize,
+  /// Stack of open elements.
+  stack: Vec<OpenElement<'a>>,
+  /// Currently collected chunk of rules: position of the decision table in skeleton, the chunk and the number of rules.
+  chunk: Option<(usize, RuleChunk, usize)>,
+  /// Detached chunks of rules.
+  chunks: HashMap<usize, Vec<RuleChunk>>,
+  /// Positions in the skeleton document where chunks were detached, with the total detached length.
+  detached: Vec<(usize, usize)>,
+}
+
+/// Detaches rules of decision tables from the document.
+///
+/// Returns the skeleton document and detached rules, or `None` when the document
+/// is not well-formed, so it should be parsed as usual to report the actual error.
+pub fn detach_rules(xml: &str) -> Option<(String, DetachedRules<'_>)> {
+  let mut scanner = Scanner {
+    xml,
+    skeleton: String::new(),
+    copied: 0,
+    stack: vec![],
+    chunk: None,
+    chunks: HashMap::new(),
+    detached: vec![],
+  };
+  scanner.scan()?;
+  let mut skeleton = scanner.skeleton;
+  skeleton.push_str(&xml[scanner.copied..]);
+  Some((
+    skeleton,
+    DetachedRules {
+      xml,
+      chunks: scanner.chunks,
+      detached: scanner.detached,
+    },
+  ))
+}
+
+impl<'a> Scanner<'a> {
+  /// Scans the whole document.
+  fn scan(&mut self) -> Option<()> {
+    let xml = self.xml;
+    let mut position = 0;
+    while let Some(offset) = xml[position..].find('<') {
+      let start = position + offset;
+      let rest = &xml[start..];
+      position = if rest.starts_with("<!--") {
+        start + rest.find("-->")? + 3
+      } else if rest.starts_with("<![CDATA[") {
+        start + rest.find("]]>")? + 3
+      } else if rest.starts_with("<?") {
+        start + rest.find("?>")? + 2
+      } else if rest.starts_with("<!") {
+        start + rest.find('>')? + 1
+      } else if rest.starts_with("</") {
+        let end = start + rest.find('>')? + 1;
+        self.end_tag(end)?;
+        end
+      } else {
+        self.start_tag(start)?
+      };
+    }
+    self.flush();
+    self.stack.is_empty().then_some(())
+  }
+
+  /// Processes the start tag beginning at specified position, returns the position after the tag.
+  fn start_tag(&mut self, start: usize) -> Option<usize> {
+    let xml = self.xml;
+    let bytes = xml.as_bytes();
+    let mut position = start + 1;
+    while position < bytes.len() && !matches!(bytes[position], b' ' | b'\t' | b'\r' | b'\n' | b'/' | b'>') {
+      position += 1;
+    }
+    let qualified_name = &xml[start + 1..position];
+    let name = qualified_name.rsplit(':').next()?;
+    let mut namespaces = vec![];
+    let self_closing = loop {
+      while position < bytes.len() && bytes[position].is_ascii_whitespace() {
+        position += 1;
+      }
+      match bytes.get(position)? {
+        b'>' => break false,
+        b'/' => {
+          position += 1;
+          if bytes.get(position)? != &b'>' {
+            return None;
+          }
+          break true;
+        }
+        _ => {
+          let attribute_start = position;
+          position += xml[position..].find('=')?;
+          let attribute_name = xml[attribute_start..position].trim_end();
+          position += 1;
+          while position < bytes.len() && bytes[position].is_ascii_whitespace() {
+            position += 1;
+          }
+          let quote = *bytes.get(position)?;
+          if quote != b'"' && quote != b'\'' {
+            return None;
+          }
+          let value_start = position + 1;
+          position = value_start + xml[value_start..].find(quote as char)?;
+          if attribute_name == "xmlns" || attribute_name.starts_with("xmlns:") {
+            namespaces.push((attribute_name, &xml[value_start..position]));
+          }
+          position += 1;
+        }
+      }
+    };
+    let end = position + 1;
+    let in_decision_table = self.stack.last().is_some_and(|parent| parent.name == "decisionTable");
+    let is_rule = in_decision_table && name == "rule";
+    if in_decision_table && !is_rule {
+      // other children of the decision table remain in the skeleton document
+      self.flush();
+    }
+    let element = OpenElement {
+      name,
+      namespaces,
+      skeleton_position: (name == "decisionTable").then(|| self.skeleton.len() + start - self.copied),
+      rule_start: is_rule.then_some(start),
+    };
+    if self_closing {
+      if is_rule {
+        self.rule(start..end);
+      }
+    } else {
+      self.stack.push(element);
+    }
+    Some(end)
+  }
+
+  /// Processes the end tag ending at specified position.
+  fn end_tag(&mut self, end: usize) -> Option<()> {
+    let element = self.stack.pop()?;
+    if let Some(rule_start) = element.rule_start {
+      self.rule(rule_start..end);
+    } else if element.name == "decisionTable" {
+      self.flush();
+    }
+    Some(())
+  }
+
+  /// Adds the rule to currently collected chunk.
+  fn rule(&mut self, range: Range<usize>) {
+    let Some(decision_table) = self.stack.last().and_then(|element| element.skeleton_position) else {
+      return;
+    };
+    if let Some((position, chunk, count)) = &mut self.chunk {
+      if *position == decision_table && *count < RULES_PER_CHUNK {
+        chunk.range.end = range.end;
+        *count += 1;
+        return;
+      }
+    }
+    self.flush();
+    let chunk = RuleChunk {
+      range,
+      namespaces: self.namespaces(),
+    };
+    self.chunk = Some((decision_table, chunk, 1));
+  }
+
+  /// Detaches currently collected chunk of rules from the skeleton document.
+  fn flush(&mut self) {
+    if let Some((decision_table, chunk, _)) = self.chunk.take() {
+      self.skeleton.push_str(&self.xml[self.copied..chunk.range.start]);
+      self.copied = chunk.range.end;
+      let total = self.detached.last().map(|(_, total)| *total).unwrap_or_default() + chunk.range.len();
+      self.detached.push((self.skeleton.len(), total));
+      self.chunks.entry(decision_table).or_default().push(chunk);
+    }
+  }
+
+  /// Returns namespace declarations in scope of the current element,
+  /// inner declarations override outer declarations with the same prefix.
+  fn namespaces(&self) -> String {
+    let mut declarations: Vec<(&str, &str)> = vec![];
+    for (attribute_name, value) in self.stack.iter().flat_map(|element| element.namespaces.iter()) {
+      declarations.retain(|(name, _)| name != attribute_name);
+      declarations.push((attribute_name, value));
+    }
+    declarations.iter().map(|(name, value)| format!(r#" {name}="{value}""#)).collect()
+  }
+}
@@ -4,6 +4,7 @@
 //! depicted in one or more Decision Requirements Diagrams (DRD).
 
 use crate::errors::*;
+use crate::source_map::{SourceMap, SourceSpan};
 use dmntk_common::{gen_id, DmntkError, HRef, Result, Uri};
 use dmntk_feel::{FeelType, Name};
 use std::fmt;
@@ -202,6 +203,63 @@ pub enum DrgElement {
   KnowledgeSource(KnowledgeSource),
 }
 
+impl DrgElement {
+  /// Returns the human-readable name of the kind of this [DrgElement].
+  pub fn kind(&self) -> &'static str {
+    match self {
+      DrgElement::Decision(_) => "decision",
+      DrgElement::InputData(_) => "input data",
+      DrgElement::BusinessKnowledgeModel(_) => "business knowledge model",
+      DrgElement::DecisionService(_) => "decision service",
+      DrgElement::KnowledgeSource(_) => "knowledge source",
+    }
+  }
+
+  /// Returns a reference to the wrapped element as a [NamedElement].
+  fn named_element(&self) -> &dyn NamedElement {
+    match self {
+      DrgElement::Decision(inner) => inner,
+      DrgElement::InputData(inner) => inner,
+      DrgElement::BusinessKnowledgeModel(inner) => inner,
+      DrgElement::DecisionService(inner) => inner,
+      DrgElement::KnowledgeSource(inner) => inner,
+    }
+  }
+}
+
+impl DmnElement for DrgElement {
+  fn namespace(&self) -> &str {
+    self.named_element().namespace()
+  }
+  fn id(&self) -> &String {
+    self.named_element().id()
+  }
+  fn opt_id(&self) -> Option<&String> {
+    self.named_element().opt_id()
+  }
+  fn description(&self) -> &Option<String> {
+    self.named_element().description()
+  }
+  fn label(&self) -> &Option<String> {
+    self.named_element().label()
+  }
+  fn extension_elements(&self) -> &Vec<ExtensionElement> {
+    self.named_element().extension_elements()
+  }
+  fn extension_attributes(&self) -> &Vec<ExtensionAttribute> {
+    self.named_element().extension_attributes()
+  }
+}
+
+impl NamedElement for DrgElement {
+  fn name(&self) -> &str {
+    self.named_element().name()
+  }
+  fn feel_name(&self) -> &Name {
+    self.named_element().feel_name()
+  }
+}
+
 /// Enumeration of specific requirements.
 pub enum Requirement {
   Information(InformationRequirement),
@@ -209,6 +267,34 @@ pub enum Requirement {
   Authority(AuthorityRequirement),
 }
 
+/// Named constant defined in `constant` extension element of [Definitions].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constant {
+  /// Name of this [Constant].
+  pub(crate) name: String,
+  /// `FEEL` name of this [Constant].
+  pub(crate) feel_name: Name,
+  /// `FEEL` expression defining the value of this [Constant].
+  pub(crate) text: String,
+}
+
+impl Constant {
+  /// Returns the name of this [Constant].
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the `FEEL` name of this [Constant].
+  pub fn feel_name(&self) -> &Name {
+    &self.feel_name
+  }
+
+  /// Returns the `FEEL` expression defining the value of this [Constant].
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+}
+
 /// [Definitions] element is the outermost containing object
 /// for all elements of a DMN decision model.
 /// It defines the scope of visibility and the namespace
@@ -259,6 +345,11 @@ pub struct Definitions {
   pub(crate) imports: Vec<Import>,
   /// Optional diagram interchange information contained within this [Definitions].
   pub(crate) dmndi: Option<Dmndi>,
+  /// Locations of elements contained in this [Definitions] in the original XML document.
+  pub(crate) source_map: SourceMap,
+  /// Named constants visible in all expressions of this [Definitions],
+  /// specified in `constant` extension elements.
+  pub(crate) constants: Vec<Constant>,
 }
 
 impl Definitions {
@@ -302,6 +393,21 @@ impl Definitions {
     &self.dmndi
   }
 
+  /// Returns reference to the [SourceMap] of elements contained in this [Definitions].
+  pub fn source_map(&self) -> &SourceMap {
+    &self.source_map
+  }
+
+  /// Returns reference to named constants defined in this [Definitions].
+  pub fn constants(&self) -> &Vec<Constant> {
+    &self.constants
+  }
+
+  /// Returns the span of the element with specified identifier in the original XML document.
+  pub fn source_span(&self, id: &str) -> Option<&SourceSpan> {
+    self.source_map.span(id)
+  }
+
   /// Returns reference to [DrgElements](DrgElement) container.
   pub fn drg_elements(&self) -> Iter<DrgElement> {
     self.drg_elements.iter()
@@ -565,6 +671,16 @@ pub struct InputData {
   pub(crate) feel_name: Name,
   /// The instance of [InformationItem] that stores the result of this [InputData].
   pub(crate) variable: InformationItem,
+  /// Optional `FEEL` expression defining the value of this [InputData] missing in input data,
+  /// specified in `defaultValue` extension element.
+  pub(crate) default_value: Option<String>,
+}
+
+impl InputData {
+  /// Returns a reference to optional `FEEL` expression defining the default value of this [InputData].
+  pub fn default_value(&self) -> &Option<String> {
+    &self.default_value
+  }
 }
 
 impl RequiredVariable for InputData {
@@ -975,11 +1091,29 @@ pub struct KnowledgeSource {
   pub(crate) name: String,
   /// `FEEL` name of this [KnowledgeSource].
   pub(crate) feel_name: Name,
-  /// Collection of the instances of [AuthorityRequirement] that compose this [Decision].
+  /// Optional URI where this [KnowledgeSource] is located.
+  pub(crate) location_uri: Option<String>,
+  /// Optional type of this [KnowledgeSource].
+  pub(crate) source_type: Option<String>,
+  /// Optional reference to the owner of this [KnowledgeSource], usually an [OrganizationUnit].
+  pub(crate) owner: Option<HRef>,
+  /// Collection of the instances of [AuthorityRequirement] that compose this [KnowledgeSource].
   pub(crate) authority_requirements: Vec<AuthorityRequirement>,
 }
 
 impl KnowledgeSource {
+  /// Returns a reference to optional URI where this [KnowledgeSource] is located.
+  pub fn location_uri(&self) -> &Option<String> {
+    &self.location_uri
+  }
+  /// Returns a reference to optional type of this [KnowledgeSource].
+  pub fn source_type(&self) -> &Option<String> {
+    &self.source_type
+  }
+  /// Returns a reference to optional owner of this [KnowledgeSource].
+  pub fn owner(&self) -> &Option<HRef> {
+    &self.owner
+  }
   /// Returns a reference to collection of [AuthorityRequirement].
   pub fn authority_requirements(&self) -> &Vec<AuthorityRequirement> {
     &self.authority_requirements
@@ -1149,6 +1283,10 @@ pub struct ItemDefinition {
   pub(crate) is_collection: bool,
   /// Describes an optional [FunctionItem] that compose this [ItemDefinition].
   pub(crate) function_item: Option<FunctionItem>,
+  /// Setting this flag to true indicates that this [ItemDefinition], when used
+  /// as an item component, may be absent in values of the enclosing [ItemDefinition].
+  /// Specified in `optional` extension element, the default value is [false].
+  pub(crate) is_optional: bool,
 }
 
 impl ItemDefinition {
@@ -1173,6 +1311,10 @@ impl ItemDefinition {
   pub fn is_collection(&self) -> bool {
     self.is_collection
   }
+  /// Returns flag indicating if this item component may be absent in values of the enclosing item definition.
+  pub fn is_optional(&self) -> bool {
+    self.is_optional
+  }
   /// Returns a reference to optional `FEEL` type.
   pub fn feel_type(&self) -> &Option<FeelType> {
     &self.feel_type
@@ -1651,6 +1793,8 @@ impl fmt::Display for BuiltinAggregator {
 pub struct InputClause {
   /// The subject of this input clause, text representation of unary tests.
   pub input_expression: String,
+  /// Optional type reference of the input expression of this input clause.
+  pub input_expression_type_ref: Option<String>,
   /// Optional unary tests that constrain the result of input expression of this input clause.
   pub allowed_input_values: Option<String>,
 }
@@ -1681,6 +1825,8 @@ pub struct DecisionRule {
   pub output_entries: Vec<OutputEntry>,
   /// Ordered list of rule annotations that compose this decision rule.
   pub annotation_entries: Vec<AnnotationEntry>,
+  /// Optional description of this decision rule.
+  pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1,16 +1,22 @@
 //! # XML parser for DMN model
 
 use crate::errors::*;
+use crate::low_memory::{detach_rules, DetachedRules, RuleChunk};
 use crate::model::*;
+use crate::source_map::SourceMap;
 use crate::validator::validate;
 use crate::xml_utils::*;
 use dmntk_common::{gen_id, to_uri, HRef, Result, Uri};
 use dmntk_feel::{Name, FEEL_TYPE_NAME_ANY};
 use roxmltree::Node;
+use std::cell::RefCell;
+use std::ops::Range;
 
 // XML node names
 const NODE_ALLOWED_ANSWERS: &str = "allowedAnswers";
 const NODE_ALLOWED_VALUES: &str = "allowedValues";
+const NODE_ANNOTATION: &str = "annotation";
+const NODE_ANNOTATION_ENTRY: &str = "annotationEntry";
 const NODE_AUTHORITY_REQUIREMENT: &str = "authorityRequirement";
 const NODE_BINDING: &str = "binding";
 const NODE_BUSINESS_KNOWLEDGE_MODEL: &str = "businessKnowledgeModel";
@@ -18,6 +24,8 @@ const NODE_COLUMN: &str = "column";
 const NODE_CONTEXT: &str = "context";
 const NODE_CONTEXT_ENTRY: &str = "contextEntry";
 const NODE_DEFAULT_OUTPUT_ENTRY: &str = "defaultOutputEntry";
+const NODE_CONSTANT: &str = "constant";
+const NODE_DEFAULT_VALUE: &str = "defaultValue";
 const NODE_DEFINITIONS: &str = "definitions";
 const NODE_DECISION: &str = "decision";
 const NODE_DECISION_MADE: &str = "decisionMade";
@@ -43,6 +51,7 @@ const NODE_DMNDI_DECISION_SERVICE_DIVIDER_LINE: &str = "DMNDecisionServiceDivide
 const NODE_DESCRIPTION: &str = "description";
 const NODE_ENCAPSULATED_DECISION: &str = "encapsulatedDecision";
 const NODE_ENCAPSULATED_LOGIC: &str = "encapsulatedLogic";
+const NODE_EXTENSION_ELEMENTS: &str = "extensionElements";
 const NODE_FUNCTION_DEFINITION: &str = "functionDefinition";
 const NODE_FORMAL_PARAMETER: &str = "formalParameter";
 const NODE_FUNCTION_ITEM: &str = "functionItem";
@@ -62,10 +71,12 @@ const NODE_KNOWLEDGE_REQUIREMENT: &str = "knowledgeRequirement";
 const NODE_KNOWLEDGE_SOURCE: &str = "knowledgeSource";
 const NODE_LIST: &str = "list";
 const NODE_LITERAL_EXPRESSION: &str = "literalExpression";
+const NODE_OPTIONAL: &str = "optional";
 const NODE_OUTPUT: &str = "output";
 const NODE_OUTPUT_DECISION: &str = "outputDecision";
 const NODE_OUTPUT_ENTRY: &str = "outputEntry";
 const NODE_OUTPUT_VALUES: &str = "outputValues";
+const NODE_OWNER: &str = "owner";
 const NODE_PARAMETER: &str = "parameter";
 const NODE_PARAMETERS: &str = "parameters";
 const NODE_PERFORMANCE_INDICATOR: &str = "performanceIndicator";
@@ -79,6 +90,7 @@ const NODE_REQUIRED_INPUT: &str = "requiredInput";
 const NODE_ROW: &str = "row";
 const NODE_RULE: &str = "rule";
 const NODE_TEXT: &str = "text";
+const NODE_TYPE: &str = "type";
 const NODE_TYPE_REF: &str = "typeRef";
 const NODE_VARIABLE: &str = "variable";
 
@@ -124,6 +136,23 @@ const ATTR_Y: &str = "y";
 
 /// Parses the XML document containing DMN model.
 pub fn parse(xml: &str) -> Result<Definitions> {
+  parse_document(xml, None)
+}
+
+/// Parses the XML document containing DMN model in low-memory mode.
+///
+/// Produces the same [Definitions] as [parse], but rules of decision tables
+/// are parsed in chunks, without building the tree of the whole document.
+/// Recommended for very large models, like generated decision tables with many thousands of rules.
+pub fn parse_low_memory(xml: &str) -> Result<Definitions> {
+  match detach_rules(xml) {
+    Some((skeleton, detached_rules)) => parse_document(&skeleton, Some(detached_rules)),
+    None => parse(xml),
+  }
+}
+
+/// Parses the XML document containing DMN model, with optionally detached rules of decision tables.
+fn parse_document(xml: &str, detached_rules: Option<DetachedRules>) -> Result<Definitions> {
   // parse document
   match roxmltree::Document::parse(xml) {
     Ok(document) => {
@@ -131,23 +160,53 @@ pub fn parse(xml: &str) -> Result<Definitions> {
       if definitions_node.tag_name().name() != NODE_DEFINITIONS {
         return Err(err_xml_unexpected_node(NODE_DEFINITIONS, definitions_node.tag_name().name()));
       }
-      let mut model_parser = ModelParser::new();
+      let mut model_parser = ModelParser::new(detached_rules);
       validate(model_parser.parse_definitions(&definitions_node)?)
     }
-    Err(reason) => Err(err_xml_parsing_model_failed(&reason.to_string())),
+    Err(reason) => Err(err_xml_parsing_model_failed(reason.to_string())),
   }
 }
 
 /// XML parser for DMN model.
-pub struct ModelParser {
+pub struct ModelParser<'a> {
   /// Namespace for parsed definitions.
   namespace: String,
+  /// Rules of decision tables detached from parsed document in low-memory mode.
+  detached_rules: Option<DetachedRules<'a>>,
+  /// Identifiers and ranges of parsed elements in the original document.
+  ranges: RefCell<Vec<(DmnId, Range<usize>)>>,
 }
 
-impl ModelParser {
+impl<'a> ModelParser<'a> {
   /// Creates new model parser.
-  fn new() -> Self {
-    Self { namespace: "".to_string() }
+  fn new(detached_rules: Option<DetachedRules<'a>>) -> Self {
+    Self {
+      namespace: "".to_string(),
+      detached_rules,
+      ranges: RefCell::new(vec![]),
+    }
+  }
+
+  /// Returns optional identifier provided in model or generates a new one,
+  /// and records the range of the identified element in the original document.
+  fn optional_id(&self, node: &Node) -> DmnId {
+    let id = optional_attribute(node, ATTR_ID).map(DmnId::Provided).unwrap_or(DmnId::Generated(gen_id()));
+    let range = match &self.detached_rules {
+      Some(detached_rules) => detached_rules.original_range(node.range()),
+      None => node.range(),
+    };
+    self.ranges.borrow_mut().push((id.clone(), range));
+    id
+  }
+
+  /// Builds the source map of all elements parsed so far.
+  fn source_map(&self, node: &Node) -> SourceMap {
+    let xml = self
+      .detached_rules
+      .as_ref()
+      .map(|detached_rules| detached_rules.xml)
+      .unwrap_or(node.document().input_text());
+    SourceMap::new(xml, self.ranges.take())
   }
 
   /// Parses model [Definitions].
@@ -156,7 +215,7 @@ impl ModelParser {
     let definitions = Definitions {
       name: required_name(node)?,
       feel_name: required_feel_name(node)?,
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -171,10 +230,27 @@ impl ModelParser {
       business_context_elements: self.parse_business_context_elements(node)?,
       imports: self.parse_imports(node)?,
       dmndi: self.parse_dmndi(node)?,
+      source_map: self.source_map(node),
+      constants: self.parse_constants(node)?,
     };
     Ok(definitions)
   }
 
+  /// Parses named constants specified in extension elements.
+  fn parse_constants(&self, node: &Node) -> Result<Vec<Constant>> {
+    let mut constants = vec![];
+    if let Some(extension_node) = optional_child(node, NODE_EXTENSION_ELEMENTS) {
+      for ref child_node in extension_node.children().filter(|n| n.tag_name().name() == NODE_CONSTANT) {
+        constants.push(Constant {
+          name: required_name(child_node)?,
+          feel_name: required_feel_name(child_node)?,
+          text: required_content(child_node)?,
+        });
+      }
+    }
+    Ok(constants)
+  }
+
   /// Parses a collection of [ItemDefinition].
   fn parse_item_definitions(&mut self, node: &Node, child_name: &str) -> Result<Vec<ItemDefinition>> {
     let mut items = vec![];
@@ -190,7 +266,7 @@ impl ModelParser {
       namespace: self.namespace.clone(),
       name: required_name(node)?,
       feel_name: required_feel_name(node)?,
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -202,6 +278,7 @@ impl ModelParser {
       item_components: self.parse_item_definitions(node, NODE_ITEM_COMPONENT)?,
       is_collection: self.parse_boolean_attribute(node, ATTR_IS_COLLECTION, false),
       function_item: self.parse_function_item(node)?,
+      is_optional: self.parse_optional_marker(node),
     })
   }
 
@@ -246,7 +323,7 @@ impl ModelParser {
     for ref child_node in node.children().filter(|n| n.tag_name().name() == NODE_INPUT_DATA) {
       let input_data = InputData {
         namespace: self.namespace.clone(),
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
@@ -254,6 +331,7 @@ impl ModelParser {
         name: required_name(child_node)?,
         feel_name: required_feel_name(child_node)?,
         variable: self.parse_information_item_child(child_node, NODE_VARIABLE)?,
+        default_value: optional_child(child_node, NODE_EXTENSION_ELEMENTS).and_then(|extension_node| optional_child_optional_content(&extension_node, NODE_DEFAULT_VALUE)),
       };
       input_data_items.push(DrgElement::InputData(input_data));
     }
@@ -268,7 +346,7 @@ impl ModelParser {
         namespace: self.namespace.clone(),
         name: required_name(child_node)?,
         feel_name: required_feel_name(child_node)?,
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
@@ -294,7 +372,7 @@ impl ModelParser {
         namespace: self.namespace.clone(),
         name: required_name(child_node)?,
         feel_name: required_feel_name(child_node)?,
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
@@ -317,7 +395,7 @@ impl ModelParser {
         namespace: self.namespace.clone(),
         name: required_name(child_node)?,
         feel_name: required_feel_name(child_node)?,
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
@@ -339,13 +417,16 @@ impl ModelParser {
     for ref child_node in node.children().filter(|n| n.tag_name().name() == NODE_KNOWLEDGE_SOURCE) {
       let knowledge_source = KnowledgeSource {
         namespace: self.namespace.clone(),
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
         extension_attributes: self.parse_extension_attributes(child_node),
         name: required_name(child_node)?,
         feel_name: required_feel_name(child_node)?,
+        location_uri: optional_attribute(child_node, ATTR_LOCATION_URI),
+        source_type: optional_child_optional_content(child_node, NODE_TYPE),
+        owner: optional_child_required_href(child_node, NODE_OWNER)?,
         authority_requirements: self.parse_authority_requirements(child_node, NODE_AUTHORITY_REQUIREMENT)?,
       };
       drg_elements.push(DrgElement::KnowledgeSource(knowledge_source));
@@ -382,7 +463,7 @@ impl ModelParser {
   fn parse_function_definition(&self, node: &Node) -> Result<FunctionDefinition> {
     Ok(FunctionDefinition {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -413,7 +494,7 @@ impl ModelParser {
     for ref child_node in node.children().filter(|n| n.tag_name().name() == NODE_PERFORMANCE_INDICATOR) {
       let performance_indicator = PerformanceIndicator {
         namespace: self.namespace.clone(),
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
@@ -428,7 +509,7 @@ impl ModelParser {
     for ref child_node in node.children().filter(|n| n.tag_name().name() == NODE_ORGANISATION_UNIT) {
       let organisation_unit = OrganizationUnit {
         namespace: self.namespace.clone(),
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
@@ -449,7 +530,7 @@ impl ModelParser {
     let mut imports = vec![];
     for ref child_node in node.children().filter(|n| n.tag_name().name() == NODE_IMPORT) {
       let import = Import {
-        id: optional_id(child_node),
+        id: self.optional_id(child_node),
         description: optional_child_optional_content(child_node, NODE_DESCRIPTION),
         label: optional_attribute(child_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(child_node),
@@ -495,7 +576,7 @@ impl ModelParser {
   fn parse_information_item(&self, node: &Node) -> Result<InformationItem> {
     Ok(InformationItem {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -520,7 +601,7 @@ impl ModelParser {
   fn parse_information_requirement(&mut self, node: &Node) -> Result<InformationRequirement> {
     let req = InformationRequirement {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -544,7 +625,7 @@ impl ModelParser {
   fn parse_knowledge_requirement(&mut self, node: &Node) -> Result<KnowledgeRequirement> {
     let req = KnowledgeRequirement {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -567,7 +648,7 @@ impl ModelParser {
   fn parse_authority_requirement(&mut self, node: &Node) -> Result<AuthorityRequirement> {
     let req = AuthorityRequirement {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -652,7 +733,7 @@ impl ModelParser {
   fn parse_decision_table(&self, node: &Node) -> Result<DecisionTable> {
     Ok(DecisionTable {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -661,7 +742,7 @@ impl ModelParser {
       information_item_name: None,
       input_clauses: self.parse_decision_table_inputs(node)?,
       output_clauses: self.parse_decision_table_outputs(node)?,
-      annotations: vec![], // TODO implement parsing annotations
+      annotations: self.parse_decision_table_annotations(node)?,
       rules: self.parse_decision_table_rules(node)?,
       hit_policy: self.parse_hit_policy_attribute(node)?,
       aggregation: None,
@@ -679,8 +760,8 @@ impl ModelParser {
   }
 
   fn parse_decision_table_input(&self, node: &Node) -> Result<InputClause> {
-    let input_expression = if let Ok(ref child_node) = required_child(node, NODE_INPUT_EXPRESSION) {
-      required_child_required_content(child_node, NODE_TEXT)?
+    let (input_expression, input_expression_type_ref) = if let Ok(ref child_node) = required_child(node, NODE_INPUT_EXPRESSION) {
+      (required_child_required_content(child_node, NODE_TEXT)?, optional_attribute(child_node, ATTR_TYPE_REF))
     } else {
       return Err(err_required_input_expression_is_missing());
     };
@@ -691,6 +772,7 @@ impl ModelParser {
     };
     Ok(InputClause {
       input_expression,
+      input_expression_type_ref,
       allowed_input_values: input_values,
     })
   }
@@ -722,7 +804,22 @@ impl ModelParser {
     })
   }
 
+  fn parse_decision_table_annotations(&self, node: &Node) -> Result<Vec<RuleAnnotationClause>> {
+    let mut annotations = vec![];
+    for ref child_node in node.children().filter(|n| n.tag_name().name() == NODE_ANNOTATION) {
+      annotations.push(RuleAnnotationClause {
+        name: required_attribute(child_node, ATTR_NAME)?,
+      });
+    }
+    Ok(annotations)
+  }
+
   fn parse_decision_table_rules(&self, node: &Node) -> Result<Vec<DecisionRule>> {
+    if let Some(detached_rules) = &self.detached_rules {
+      if let Some(chunks) = detached_rules.chunks.get(&node.range().start) {
+        return self.parse_detached_decision_table_rules(detached_rules.xml, chunks);
+      }
+    }
     let mut rules = vec![];
     for ref child_node in node.children().filter(|n| n.tag_name().name() == NODE_RULE) {
       rules.push(self.parse_decision_table_rule(child_node)?);
@@ -730,11 +827,25 @@ impl ModelParser {
     Ok(rules)
   }
 
+  /// Parses rules detached from the document, chunk by chunk.
+  fn parse_detached_decision_table_rules(&self, xml: &str, chunks: &[RuleChunk]) -> Result<Vec<DecisionRule>> {
+    let mut rules = vec![];
+    for chunk in chunks {
+      let chunk_xml = format!("<{NODE_RULE}s{}>{}</{NODE_RULE}s>", chunk.namespaces, &xml[chunk.range.clone()]);
+      let document = roxmltree::Document::parse(&chunk_xml).map_err(|reason| err_xml_parsing_model_failed(reason.to_string()))?;
+      for ref child_node in document.root_element().children().filter(|n| n.tag_name().name() == NODE_RULE) {
+        rules.push(self.parse_decision_table_rule(child_node)?);
+      }
+    }
+    Ok(rules)
+  }
+
   fn parse_decision_table_rule(&self, node: &Node) -> Result<DecisionRule> {
     Ok(DecisionRule {
       input_entries: self.parse_decision_table_input_entries(node)?,
       output_entries: self.parse_decision_table_output_entries(node)?,
-      annotation_entries: vec![],
+      annotation_entries: self.parse_decision_table_annotation_entries(node),
+      description: optional_child_optional_content(node, NODE_DESCRIPTION),
     })
   }
 
@@ -766,6 +877,16 @@ impl ModelParser {
     })
   }
 
+  fn parse_decision_table_annotation_entries(&self, node: &Node) -> Vec<AnnotationEntry> {
+    node
+      .children()
+      .filter(|n| n.tag_name().name() == NODE_ANNOTATION_ENTRY)
+      .map(|child_node| AnnotationEntry {
+        text: optional_child_optional_content(&child_node, NODE_TEXT).unwrap_or_default(),
+      })
+      .collect()
+  }
+
   fn parse_optional_context(&self, node: &Node) -> Result<Option<Context>> {
     if let Some(ref child_node) = node.children().find(|n| n.tag_name().name() == NODE_CONTEXT) {
       return Ok(Some(self.parse_context(child_node)?));
@@ -776,7 +897,7 @@ impl ModelParser {
   fn parse_context(&self, node: &Node) -> Result<Context> {
     Ok(Context {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -807,7 +928,7 @@ impl ModelParser {
   fn parse_invocation(&self, node: &Node) -> Result<Invocation> {
     Ok(Invocation {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -852,7 +973,7 @@ impl ModelParser {
     }
     Ok(List {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -876,7 +997,7 @@ impl ModelParser {
   fn parse_literal_expression(&self, node: &Node) -> LiteralExpression {
     LiteralExpression {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -914,7 +1035,7 @@ impl ModelParser {
       }
       rows.push(List {
         namespace: self.namespace.clone(),
-        id: optional_id(row_node),
+        id: self.optional_id(row_node),
         description: optional_child_optional_content(row_node, NODE_DESCRIPTION),
         label: optional_attribute(row_node, ATTR_LABEL),
         extension_elements: self.parse_extension_elements(row_node),
@@ -925,7 +1046,7 @@ impl ModelParser {
     }
     Ok(Relation {
       namespace: self.namespace.clone(),
-      id: optional_id(node),
+      id: self.optional_id(node),
       description: optional_child_optional_content(node, NODE_DESCRIPTION),
       label: optional_attribute(node, ATTR_LABEL),
       extension_elements: self.parse_extension_elements(node),
@@ -942,6 +1063,14 @@ impl ModelParser {
     vec![]
   }
 
+  /// Parses the `optional` marker specified in extension elements,
+  /// the marker without content or with content `true` marks the element as optional.
+  fn parse_optional_marker(&self, node: &Node) -> bool {
+    optional_child(node, NODE_EXTENSION_ELEMENTS)
+      .and_then(|extension_node| optional_child(&extension_node, NODE_OPTIONAL).map(|optional_node| optional_content(&optional_node)))
+      .is_some_and(|content| content.is_none_or(|text| text.trim() == "true"))
+  }
+
   /// Parses extension attributes.
   fn parse_extension_attributes(&self, _node: &Node) -> Vec<ExtensionAttribute> {
     // Currently ignored. Ready for future development when needed.
@@ -1218,11 +1347,6 @@ fn required_name(node: &Node) -> Result<String> {
   required_attribute(node, ATTR_NAME)
 }
 
-/// Returns optional identifier provided in model or generates a new one.
-fn optional_id(node: &Node) -> DmnId {
-  optional_attribute(node, ATTR_ID).map(DmnId::Provided).unwrap_or(DmnId::Generated(gen_id()))
-}
-
 /// Returns FEEL name for specified node.
 fn required_feel_name(node: &Node) -> Result<Name> {
   let input = required_name(node)?;
@@ -0,0 +1,205 @@
+//! # SARIF reports
+//!
+//! Collects results of validating and linting decision models into a report
+//! in Static Analysis Results Interchange Format (SARIF) version 2.1.0,
+//! accepted by code review tools and problem panes of IDEs.
+//!
+//! Validation errors are reported with level `error`, lint warnings with level `warning`.
+//! Results point to the model file and, when known, to the region of the reported element.
+
+use crate::linter::{LintWarning, LINT_DEAD_BRANCH, LINT_INVALID_NAME, LINT_MERGEABLE_RULES, LINT_SHADOWED_BUILT_IN_FUNCTION};
+use crate::source_map::SourceSpan;
+use dmntk_common::{DmntkError, Jsonify};
+use serde_json::json;
+
+/// Identifier of the rule reporting violations of the DMN schema.
+pub const RULE_SCHEMA_VIOLATION: &str = "schema-violation";
+
+/// Identifier of the rule reporting models that fail parsing or validation.
+pub const RULE_INVALID_MODEL: &str = "invalid-model";
+
+/// Identifiers and descriptions of all rules reported in SARIF reports.
+const RULES: [(&str, &str); 6] = [
+  (RULE_SCHEMA_VIOLATION, "Model violates the DMN schema."),
+  (RULE_INVALID_MODEL, "Model can not be parsed or is not valid."),
+  (LINT_MERGEABLE_RULES, "Rules of decision table can be merged."),
+  (LINT_DEAD_BRANCH, "Branch of expression is never evaluated or input entry never matches."),
+  (LINT_INVALID_NAME, "Name of element is not a valid FEEL name."),
+  (LINT_SHADOWED_BUILT_IN_FUNCTION, "Name of input data shadows built-in function."),
+];
+
+/// Region of the model file, lines and columns are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Region {
+  /// Line of the first character of the region.
+  start_line: usize,
+  /// Column of the first character of the region.
+  start_column: usize,
+  /// Line and column just after the last character of the region, when known.
+  end: Option<(usize, usize)>,
+}
+
+/// Single result in SARIF report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SarifResult {
+  /// Identifier of the reported rule.
+  rule_id: String,
+  /// Level of the result, `error` or `warning`.
+  level: &'static str,
+  /// Message describing the result.
+  message: String,
+  /// URI of the model file.
+  uri: String,
+  /// Region of the reported element, when known.
+  region: Option<Region>,
+  /// Identifier of the reported element, when known.
+  element_id: Option<String>,
+}
+
+/// Report of validation errors and lint warnings in SARIF format.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SarifReport {
+  /// Collected results.
+  results: Vec<SarifResult>,
+}
+
+impl SarifReport {
+  /// Creates an empty report.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the number of results in the report.
+  pub fn len(&self) -> usize {
+    self.results.len()
+  }
+
+  /// Returns `true` when the report contains no results.
+  pub fn is_empty(&self) -> bool {
+    self.results.is_empty()
+  }
+
+  /// Returns `true` when the report contains any errors.
+  pub fn has_errors(&self) -> bool {
+    self.results.iter().any(|result| result.level == "error")
+  }
+
+  /// Adds the violation of the DMN schema, reported by [schema_violations](crate::schema_violations) for the model file.
+  pub fn add_schema_violation(&mut self, uri: &str, error: &DmntkError) {
+    let region = schema_violation_position(error).map(|(line, column)| Region {
+      start_line: line,
+      start_column: column,
+      end: None,
+    });
+    self.add_error(RULE_SCHEMA_VIOLATION, uri, error_message(error), region);
+  }
+
+  /// Adds the error reported while parsing or validating the model file.
+  pub fn add_validation_error(&mut self, uri: &str, error: &DmntkError) {
+    self.add_error(RULE_INVALID_MODEL, uri, error_message(error), None);
+  }
+
+  /// Adds warnings reported by the linter for the model file.
+  pub fn add_lint_warnings(&mut self, uri: &str, warnings: &[LintWarning]) {
+    for warning in warnings {
+      self.results.push(SarifResult {
+        rule_id: warning.code().to_string(),
+        level: "warning",
+        message: warning.message().to_string(),
+        uri: uri.to_string(),
+        region: warning.span().map(span_region),
+        element_id: Some(warning.element_id().to_string()),
+      });
+    }
+  }
+
+  /// Adds the error with specified rule identifier.
+  fn add_error(&mut self, rule_id: &str, uri: &str, message: String, region: Option<Region>) {
+    self.results.push(SarifResult {
+      rule_id: rule_id.to_string(),
+      level: "error",
+      message,
+      uri: uri.to_string(),
+      region,
+      element_id: None,
+    });
+  }
+}
+
+impl Jsonify for SarifReport {
+  /// Converts the report into SARIF document with a single run.
+  fn jsonify(&self) -> String {
+    let rules = RULES
+      .iter()
+      .map(|(id, description)| json!({ "id": id, "shortDescription": { "text": description } }))
+      .collect::<Vec<serde_json::Value>>();
+    let results = self
+      .results
+      .iter()
+      .map(|result| {
+        let mut physical_location = json!({ "artifactLocation": { "uri": result.uri } });
+        if let Some(region) = &result.region {
+          let mut json_region = json!({ "startLine": region.start_line, "startColumn": region.start_column });
+          if let Some((end_line, end_column)) = region.end {
+            json_region["endLine"] = json!(end_line);
+            json_region["endColumn"] = json!(end_column);
+          }
+          physical_location["region"] = json_region;
+        }
+        let mut location = json!({ "physicalLocation": physical_location });
+        if let Some(element_id) = &result.element_id {
+          location["logicalLocations"] = json!([{ "name": element_id, "kind": "element" }]);
+        }
+        json!({
+          "ruleId": result.rule_id,
+          "level": result.level,
+          "message": { "text": result.message },
+          "locations": [location],
+        })
+      })
+      .collect::<Vec<serde_json::Value>>();
+    json!({
+      "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": "dmntk",
+            "informationUri": "https://dmntk.io",
+            "version": env!("CARGO_PKG_VERSION"),
+            "rules": rules,
+          }
+        },
+        "results": results,
+      }],
+    })
+    .to_string()
+  }
+}
+
+/// Returns the message of the error without the name of the error source.
+fn error_message(error: &DmntkError) -> String {
+  let message = error.to_string();
+  match message.strip_prefix('<').and_then(|text| text.split_once("> ")) {
+    Some((_, text)) => text.to_string(),
+    None => message,
+  }
+}
+
+/// Returns the line and column of the schema violation, starting the message like `line 17, column 9, ...`.
+/// The position is read from the message not translated into the selected language.
+fn schema_violation_position(error: &DmntkError) -> Option<(usize, usize)> {
+  let (_, message) = error.untranslated().split_once("> ")?;
+  let (line, rest) = message.strip_prefix("line ")?.split_once(", column ")?;
+  let column = rest.split(',').next()?;
+  Some((line.parse().ok()?, column.parse().ok()?))
+}
+
+/// Converts the span of the element into region of the model file.
+fn span_region(span: &SourceSpan) -> Region {
+  Region {
+    start_line: span.start().line(),
+    start_column: span.start().column(),
+    end: Some((span.end().line(), span.end().column())),
+  }
+}
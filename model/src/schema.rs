@@ -0,0 +1,371 @@
+//! # Schema validation of DMN models
+//!
+//! Validates the XML document against a subset of the structure defined in official XML schemas
+//! of DMN 1.3, DMN 1.4 and DMN 1.5, before the document is parsed into [Definitions](crate::Definitions).
+//! The document is **not** validated by an XSD validator, the rules below are hand-written
+//! from the official schemas, so documents accepted here may still be invalid against the XSDs.
+//!
+//! The version of the schema is selected based on the namespace of the `definitions` element.
+//! Checked are: allowed child elements (including elements introduced in later versions),
+//! required attributes, values of enumerated and boolean attributes and text-only elements.
+//! Not checked are: the order and the number of occurrences of child elements, attributes not defined
+//! in the schema, types of attribute values other than enumerations and booleans, uniqueness of identifiers, elements and attributes
+//! from other namespaces (like DMNDI) and the content of extension elements.
+//!
+//! All violations found in the document are reported, each with the line and column
+//! in the XML document and the path of the element that violates the schema.
+//! Content of elements that are not allowed is not validated.
+
+use crate::errors::*;
+use dmntk_common::{message, DmntkError, Message, Result};
+use roxmltree::{Document, Node};
+
+/// Namespace of DMN 1.3 model.
+const NS_DMN_13: &str = "https://www.omg.org/spec/DMN/20191111/MODEL/";
+/// Namespace of DMN 1.4 model.
+const NS_DMN_14: &str = "https://www.omg.org/spec/DMN/20211108/MODEL/";
+/// Namespace of DMN 1.5 model.
+const NS_DMN_15: &str = "https://www.omg.org/spec/DMN/20230324/MODEL/";
+
+/// Elements representing expressions in DMN 1.3.
+const EXPRESSIONS_13: &[&str] = &["literalExpression", "context", "decisionTable", "functionDefinition", "invocation", "list", "relation"];
+/// Elements representing expressions introduced in DMN 1.4.
+const EXPRESSIONS_14: &[&str] = &["conditional", "filter", "for", "every", "some"];
+
+/// Version of the DMN schema.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DmnVersion {
+  V1_3,
+  V1_4,
+  V1_5,
+}
+
+/// Content model of the element.
+enum Content {
+  /// Element contains only text.
+  Text,
+  /// Element contains specified child elements, `true` when it may contain an expression.
+  Elements(&'static [&'static str], bool),
+  /// The content of the element is not validated.
+  Any,
+}
+
+/// Schema of the element.
+struct ElementSchema {
+  /// Content model of the element.
+  content: Content,
+  /// Names of required attributes.
+  required: &'static [&'static str],
+  /// Minimal version of the schema defining the element.
+  since: DmnVersion,
+}
+
+impl ElementSchema {
+  /// Creates the schema of the element defined in DMN 1.3.
+  fn new(content: Content, required: &'static [&'static str]) -> Self {
+    Self {
+      content,
+      required,
+      since: DmnVersion::V1_3,
+    }
+  }
+
+  /// Creates the schema of the element containing specified child elements.
+  fn elements(children: &'static [&'static str], required: &'static [&'static str]) -> Self {
+    Self::new(Content::Elements(children, false), required)
+  }
+
+  /// Creates the schema of the element containing specified child elements and an expression.
+  fn expression(children: &'static [&'static str], required: &'static [&'static str]) -> Self {
+    Self::new(Content::Elements(children, true), required)
+  }
+
+  /// Creates the schema of the element containing only text.
+  fn text() -> Self {
+    Self::new(Content::Text, &[])
+  }
+
+  /// Sets the minimal version of the schema defining the element.
+  fn since(mut self, version: DmnVersion) -> Self {
+    self.since = version;
+    self
+  }
+}
+
+/// Validates the XML document containing DMN model against the schema.
+///
+/// Returns the version of the schema the document was validated against,
+/// or the first violation of the schema. Use [schema_violations] to get all violations.
+pub fn validate_schema(xml: &str) -> Result<DmnVersion> {
+  let (opt_version, mut violations) = check_schema(xml);
+  match opt_version {
+    Some(version) if violations.is_empty() => Ok(version),
+    _ => Err(violations.remove(0)),
+  }
+}
+
+/// Validates the XML document containing DMN model against the schema
+/// and returns all violations of the schema, ordered by their position in the document.
+///
+/// Returns no violations when the document is valid against the schema.
+pub fn schema_violations(xml: &str) -> Vec<DmntkError> {
+  check_schema(xml).1
+}
+
+/// Validates the XML document, returns the version of the schema and all violations.
+/// The version is not returned when the schema can not be selected for the document.
+fn check_schema(xml: &str) -> (Option<DmnVersion>, Vec<DmntkError>) {
+  let document = match Document::parse(xml) {
+    Ok(document) => document,
+    Err(reason) => return (None, vec![err_xml_parsing_model_failed(reason.to_string())]),
+  };
+  let root = document.root_element();
+  let Some(namespace) = root.tag_name().namespace() else {
+    return (None, vec![violation(&document, root, message!("missing DMN namespace"))]);
+  };
+  let version = match namespace {
+    NS_DMN_13 => DmnVersion::V1_3,
+    NS_DMN_14 => DmnVersion::V1_4,
+    NS_DMN_15 => DmnVersion::V1_5,
+    other => return (None, vec![violation(&document, root, message!("unsupported DMN namespace `{}`", other))]),
+  };
+  if root.tag_name().name() != "definitions" {
+    return (
+      None,
+      vec![violation(&document, root, message!("expected element `definitions`, actual `{}`", root.tag_name().name()))],
+    );
+  }
+  let mut violations = vec![];
+  SchemaValidator {
+    document: &document,
+    namespace,
+    version,
+  }
+  .validate(root, "", &mut violations);
+  (Some(version), violations)
+}
+
+/// Validator of the XML document against the schema of specified version.
+struct SchemaValidator<'a, 'input> {
+  /// Validated document.
+  document: &'a Document<'input>,
+  /// Namespace of DMN model elements.
+  namespace: &'a str,
+  /// Version of the schema.
+  version: DmnVersion,
+}
+
+impl SchemaValidator<'_, '_> {
+  /// Validates the element and all its child elements, collects found violations.
+  fn validate(&self, node: Node, parent: &str, violations: &mut Vec<DmntkError>) {
+    let name = node.tag_name().name();
+    let Some(schema) = element_schema(parent, name).filter(|schema| schema.since <= self.version) else {
+      violations.push(violation(self.document, node, message!("unexpected element `{}` in `{}`", name, parent)));
+      return;
+    };
+    for attribute in schema.required {
+      if node.attribute(*attribute).is_none() {
+        violations.push(violation(self.document, node, message!("missing required attribute `{}`", attribute)));
+      }
+    }
+    for attribute in node.attributes().filter(|attribute| attribute.namespace().is_none()) {
+      if let Some(allowed) = attribute_values(name, attribute.name()) {
+        if !allowed.contains(&attribute.value()) {
+          violations.push(violation(
+            self.document,
+            node,
+            message!(
+              "invalid value `{}` of attribute `{}`, expected one of: {}",
+              attribute.value(),
+              attribute.name(),
+              allowed.join(", ")
+            ),
+          ));
+        }
+      }
+    }
+    match schema.content {
+      Content::Any => {}
+      Content::Text => {
+        for child in node.children().filter(|child| child.is_element()) {
+          violations.push(violation(self.document, child, message!("element `{}` may contain only text", name)));
+        }
+      }
+      Content::Elements(children, has_expression) => {
+        for child in node.children().filter(|child| child.is_element()) {
+          if child.tag_name().namespace() != Some(self.namespace) {
+            // elements from other namespaces are validated by their own schemas
+            continue;
+          }
+          let child_name = child.tag_name().name();
+          let is_common = matches!(child_name, "description" | "extensionElements");
+          let is_expression = has_expression && self.is_expression(child_name);
+          if !is_common && !is_expression && !children.contains(&child_name) {
+            violations.push(violation(self.document, child, message!("unexpected element `{}` in `{}`", child_name, name)));
+            continue;
+          }
+          self.validate(child, name, violations);
+        }
+      }
+    }
+  }
+
+  /// Returns `true` when the element with specified name is an expression in validated version.
+  fn is_expression(&self, name: &str) -> bool {
+    EXPRESSIONS_13.contains(&name) || (self.version >= DmnVersion::V1_4 && EXPRESSIONS_14.contains(&name))
+  }
+}
+
+/// Returns the schema of the element with specified name, contained in specified parent element.
+fn element_schema(parent: &str, name: &str) -> Option<ElementSchema> {
+  use DmnVersion::*;
+  Some(match (parent, name) {
+    (_, "description" | "text" | "typeRef" | "question" | "allowedAnswers" | "importedElement") => ElementSchema::text(),
+    ("knowledgeSource", "type") => ElementSchema::text(),
+    (_, "extensionElements") => ElementSchema::new(Content::Any, &[]),
+    ("", "definitions") => ElementSchema::elements(
+      &[
+        "import",
+        "itemDefinition",
+        "decision",
+        "businessKnowledgeModel",
+        "decisionService",
+        "inputData",
+        "knowledgeSource",
+        "association",
+        "group",
+        "textAnnotation",
+        "elementCollection",
+        "performanceIndicator",
+        "organizationUnit",
+      ],
+      &["name", "namespace"],
+    ),
+    ("definitions", "import") => ElementSchema::elements(&[], &["namespace", "importType", "name"]),
+    (_, "itemDefinition" | "itemComponent") => ElementSchema::elements(&["typeRef", "allowedValues", "typeConstraint", "itemComponent", "functionItem"], &["name"]),
+    (_, "typeConstraint") => ElementSchema::elements(&["text"], &[]).since(V1_5),
+    (_, "allowedValues" | "inputValues" | "outputValues" | "inputEntry") => ElementSchema::elements(&["text"], &[]),
+    ("functionItem", "parameters") => ElementSchema::elements(&[], &["name"]),
+    (_, "functionItem") => ElementSchema::elements(&["parameters"], &[]),
+    (_, "variable" | "formalParameter" | "column" | "parameter") => ElementSchema::elements(&[], &["name"]),
+    ("definitions", "decision") => ElementSchema::expression(
+      &[
+        "question",
+        "allowedAnswers",
+        "variable",
+        "informationRequirement",
+        "knowledgeRequirement",
+        "authorityRequirement",
+        "supportedObjective",
+        "impactedPerformanceIndicator",
+        "decisionMaker",
+        "decisionOwner",
+        "usingProcess",
+        "usingTask",
+      ],
+      &["name"],
+    ),
+    ("definitions", "businessKnowledgeModel") => ElementSchema::elements(&["variable", "encapsulatedLogic", "knowledgeRequirement", "authorityRequirement"], &["name"]),
+    ("definitions", "decisionService") => ElementSchema::elements(&["variable", "outputDecision", "encapsulatedDecision", "inputDecision", "inputData"], &["name"]),
+    ("definitions", "inputData") => ElementSchema::elements(&["variable"], &["name"]),
+    ("definitions", "knowledgeSource") => ElementSchema::elements(&["authorityRequirement", "type", "owner"], &["name"]),
+    ("definitions", "performanceIndicator") => ElementSchema::elements(&["impactingDecision"], &["name"]),
+    ("definitions", "organizationUnit") => ElementSchema::elements(&["decisionMade", "decisionOwned"], &["name"]),
+    ("definitions", "elementCollection") => ElementSchema::elements(&["drgElement"], &["name"]),
+    ("definitions", "textAnnotation") => ElementSchema::elements(&["text"], &[]),
+    ("definitions", "association") => ElementSchema::elements(&["sourceRef", "targetRef"], &[]),
+    ("definitions", "group") => ElementSchema::elements(&[], &[]),
+    ("decisionService", "inputData")
+    | (
+      _,
+      "requiredDecision"
+      | "requiredInput"
+      | "requiredKnowledge"
+      | "requiredAuthority"
+      | "outputDecision"
+      | "encapsulatedDecision"
+      | "inputDecision"
+      | "supportedObjective"
+      | "impactedPerformanceIndicator"
+      | "decisionMaker"
+      | "decisionOwner"
+      | "usingProcess"
+      | "usingTask"
+      | "owner"
+      | "impactingDecision"
+      | "decisionMade"
+      | "decisionOwned"
+      | "drgElement"
+      | "sourceRef"
+      | "targetRef",
+    ) => ElementSchema::elements(&[], &["href"]),
+    (_, "informationRequirement") => ElementSchema::elements(&["requiredDecision", "requiredInput"], &[]),
+    (_, "knowledgeRequirement") => ElementSchema::elements(&["requiredKnowledge"], &[]),
+    (_, "authorityRequirement") => ElementSchema::elements(&["requiredDecision", "requiredInput", "requiredAuthority"], &[]),
+    (_, "encapsulatedLogic" | "functionDefinition") => ElementSchema::expression(&["formalParameter"], &[]),
+    (_, "literalExpression" | "inputExpression" | "outputEntry" | "defaultOutputEntry") => ElementSchema::elements(&["text", "importedValues"], &[]),
+    (_, "importedValues") => ElementSchema::elements(&["importedElement"], &["namespace", "importType"]),
+    (_, "context") => ElementSchema::elements(&["contextEntry"], &[]),
+    ("context", "contextEntry") => ElementSchema::expression(&["variable"], &[]),
+    (_, "decisionTable") => ElementSchema::elements(&["input", "output", "annotation", "rule"], &[]),
+    ("decisionTable", "input") => ElementSchema::elements(&["inputExpression", "inputValues"], &[]),
+    ("decisionTable", "output") => ElementSchema::elements(&["outputValues", "defaultOutputEntry"], &[]),
+    ("decisionTable", "annotation") => ElementSchema::elements(&[], &["name"]),
+    ("decisionTable", "rule") => ElementSchema::elements(&["inputEntry", "outputEntry", "annotationEntry"], &[]),
+    ("rule", "annotationEntry") => ElementSchema::elements(&["text"], &[]),
+    (_, "invocation") => ElementSchema::expression(&["binding"], &[]),
+    ("invocation", "binding") => ElementSchema::expression(&["parameter"], &[]),
+    (_, "list") => ElementSchema::expression(&[], &[]),
+    (_, "relation") => ElementSchema::elements(&["column", "row"], &[]),
+    ("relation", "row") => ElementSchema::expression(&[], &[]),
+    (_, "conditional") => ElementSchema::elements(&["if", "then", "else"], &[]).since(V1_4),
+    ("conditional", "if" | "then" | "else") => ElementSchema::expression(&[], &[]).since(V1_4),
+    (_, "filter") => ElementSchema::elements(&["in", "match"], &[]).since(V1_4),
+    ("filter" | "for" | "every" | "some", "in") | ("filter", "match") | ("for", "return") | ("every" | "some", "satisfies") => ElementSchema::expression(&[], &[]).since(V1_4),
+    (_, "for") => ElementSchema::elements(&["in", "return"], &["iteratorVariable"]).since(V1_4),
+    (_, "every" | "some") => ElementSchema::elements(&["in", "satisfies"], &["iteratorVariable"]).since(V1_4),
+    _ => return None,
+  })
+}
+
+/// Returns allowed values of enumerated and boolean attributes.
+fn attribute_values(element: &str, attribute: &str) -> Option<&'static [&'static str]> {
+  match (element, attribute) {
+    (_, "isCollection" | "isCollapsed") => Some(&["true", "false", "1", "0"]),
+    ("decisionTable", "hitPolicy") => Some(&["UNIQUE", "FIRST", "PRIORITY", "ANY", "COLLECT", "RULE ORDER", "OUTPUT ORDER"]),
+    ("decisionTable", "aggregation") => Some(&["SUM", "COUNT", "MIN", "MAX"]),
+    ("decisionTable", "preferredOrientation") => Some(&["Rule-as-Row", "Rule-as-Column", "CrossTable"]),
+    ("encapsulatedLogic" | "functionDefinition", "kind") => Some(&["FEEL", "Java", "PMML"]),
+    ("association", "associationDirection") => Some(&["None", "One", "Both"]),
+    _ => None,
+  }
+}
+
+/// Returns the error describing the violation of the schema by specified node,
+/// with the position of the node in the document and the path of the node.
+fn violation(document: &Document, node: Node, reason: Message) -> DmntkError {
+  let position = document.text_pos_at(node.range().start);
+  err_schema_violation(position.row, position.col, &node_path(node), reason)
+}
+
+/// Returns the path of the node, every step contains the name of the element
+/// and the position of the element among its siblings with the same name.
+fn node_path(node: Node) -> String {
+  let mut steps = node
+    .ancestors()
+    .filter(|ancestor| ancestor.is_element())
+    .map(|element| {
+      let name = element.tag_name().name();
+      let same_name = |sibling: &Node| sibling.is_element() && sibling.tag_name().name() == name;
+      let position = element.prev_siblings().filter(same_name).count();
+      let count = position + element.next_siblings().skip(1).filter(same_name).count();
+      if count > 1 {
+        format!("{name}[{position}]")
+      } else {
+        name.to_string()
+      }
+    })
+    .collect::<Vec<String>>();
+  steps.reverse();
+  format!("/{}", steps.join("/"))
+}
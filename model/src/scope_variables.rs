@@ -0,0 +1,255 @@
+//! # Scope variables
+//!
+//! Lists variables that are in scope of an expression in the decision model,
+//! together with their types, e.g. to propose names while editing the expression.
+//!
+//! Variables in scope of expressions of a decision are its required decisions and input data,
+//! and required business knowledge models and decision services, that may be invoked.
+//! Expressions of business knowledge models see their parameters and required invocables.
+//! Nested boxed expressions add variables defined by enclosing expressions:
+//! parameters of function definitions and context entries preceding the expression.
+//! Variables defined closer to the expression shadow variables with the same names.
+
+use crate::errors::err_element_not_found;
+use crate::model::*;
+use dmntk_common::Result;
+use std::fmt;
+
+/// Kind of the variable in scope.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScopeVariableKind {
+  /// Value of the required input data.
+  InputData,
+  /// Value of the required decision.
+  Decision,
+  /// Required business knowledge model, invoked like a function.
+  BusinessKnowledgeModel,
+  /// Required decision service, invoked like a function.
+  DecisionService,
+  /// Parameter of the business knowledge model or of the function definition.
+  Parameter,
+  /// Entry of the enclosing context.
+  ContextEntry,
+}
+
+impl fmt::Display for ScopeVariableKind {
+  /// Formats the human-readable name of the kind.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ScopeVariableKind::InputData => write!(f, "input data"),
+      ScopeVariableKind::Decision => write!(f, "decision"),
+      ScopeVariableKind::BusinessKnowledgeModel => write!(f, "business knowledge model"),
+      ScopeVariableKind::DecisionService => write!(f, "decision service"),
+      ScopeVariableKind::Parameter => write!(f, "parameter"),
+      ScopeVariableKind::ContextEntry => write!(f, "context entry"),
+    }
+  }
+}
+
+/// Variable in scope of an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeVariable {
+  /// Name of the variable.
+  name: String,
+  /// Type of the variable, invocables are typed as functions.
+  type_ref: String,
+  /// Kind of the variable.
+  kind: ScopeVariableKind,
+}
+
+impl ScopeVariable {
+  /// Returns the name of the variable.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the type of the variable, invocables are typed as functions.
+  pub fn type_ref(&self) -> &str {
+    &self.type_ref
+  }
+
+  /// Returns the kind of the variable.
+  pub fn kind(&self) -> ScopeVariableKind {
+    self.kind
+  }
+}
+
+impl fmt::Display for ScopeVariable {
+  /// Formats the variable with its type and kind.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}: {} ({})", self.name, self.type_ref, self.kind)
+  }
+}
+
+/// Returns variables in scope of the expression with specified identifier, in the order of definition.
+///
+/// The identifier may point to a decision or business knowledge model, then variables in scope
+/// of its top-level expression are returned, or to any boxed expression nested in it,
+/// or to the variable of a context entry, then variables in scope of the entry value are returned.
+pub fn scope_variables(definitions: &Definitions, id: &str) -> Result<Vec<ScopeVariable>> {
+  for drg_element in definitions.drg_elements() {
+    let (mut variables, expression_instance) = match drg_element {
+      DrgElement::Decision(decision) => (decision_variables(definitions, decision), decision.decision_logic().as_ref()),
+      DrgElement::BusinessKnowledgeModel(bkm) => {
+        let mut variables = invocable_variables(definitions, bkm.knowledge_requirements());
+        let body = bkm.encapsulated_logic().as_ref().and_then(|function_definition| {
+          for parameter in function_definition.formal_parameters() {
+            add_variable(&mut variables, parameter.name(), parameter.type_ref(), ScopeVariableKind::Parameter);
+          }
+          function_definition.body().as_ref()
+        });
+        (variables, body)
+      }
+      _ => continue,
+    };
+    if drg_element.id() == id {
+      return Ok(variables);
+    }
+    if let Some(expression_instance) = expression_instance {
+      if find_expression(expression_instance, id, &mut variables) {
+        return Ok(variables);
+      }
+    }
+  }
+  Err(err_element_not_found(id))
+}
+
+/// Returns variables required by the decision.
+fn decision_variables(definitions: &Definitions, decision: &Decision) -> Vec<ScopeVariable> {
+  let mut variables = vec![];
+  for information_requirement in decision.information_requirements() {
+    let href = information_requirement.required_decision().as_ref().or(information_requirement.required_input().as_ref());
+    match href.and_then(|href| find_drg_element(definitions, href.id())) {
+      Some(DrgElement::Decision(required_decision)) => add_variable(
+        &mut variables,
+        required_decision.name(),
+        required_decision.variable().type_ref(),
+        ScopeVariableKind::Decision,
+      ),
+      Some(DrgElement::InputData(input_data)) => add_variable(&mut variables, input_data.name(), input_data.variable().type_ref(), ScopeVariableKind::InputData),
+      _ => {}
+    }
+  }
+  variables.extend(invocable_variables(definitions, decision.knowledge_requirements()));
+  variables
+}
+
+/// Returns business knowledge models and decision services required in knowledge requirements.
+fn invocable_variables(definitions: &Definitions, knowledge_requirements: &[KnowledgeRequirement]) -> Vec<ScopeVariable> {
+  let mut variables = vec![];
+  for knowledge_requirement in knowledge_requirements {
+    match find_drg_element(definitions, knowledge_requirement.required_knowledge().id()) {
+      Some(DrgElement::BusinessKnowledgeModel(bkm)) => {
+        let parameter_types = bkm
+          .encapsulated_logic()
+          .as_ref()
+          .map(|function_definition| function_definition.formal_parameters().iter().map(|parameter| parameter.type_ref().to_string()).collect())
+          .unwrap_or_default();
+        let type_ref = function_type(parameter_types, bkm.variable().type_ref());
+        add_variable(&mut variables, bkm.name(), &type_ref, ScopeVariableKind::BusinessKnowledgeModel);
+      }
+      Some(DrgElement::DecisionService(decision_service)) => {
+        let parameter_types = decision_service
+          .input_decisions()
+          .iter()
+          .chain(decision_service.input_data())
+          .filter_map(|href| match find_drg_element(definitions, href.id()) {
+            Some(DrgElement::Decision(decision)) => Some(decision.variable().type_ref().to_string()),
+            Some(DrgElement::InputData(input_data)) => Some(input_data.variable().type_ref().to_string()),
+            _ => None,
+          })
+          .collect();
+        let type_ref = function_type(parameter_types, decision_service.variable().type_ref());
+        add_variable(&mut variables, decision_service.name(), &type_ref, ScopeVariableKind::DecisionService);
+      }
+      _ => {}
+    }
+  }
+  variables
+}
+
+/// Searches the expression with specified identifier in the expression instance and expressions nested in it,
+/// adds variables defined on the path to the found expression. Returns `true` when the expression was found.
+fn find_expression(expression_instance: &ExpressionInstance, id: &str, variables: &mut Vec<ScopeVariable>) -> bool {
+  if expression_id(expression_instance) == id {
+    return true;
+  }
+  match expression_instance {
+    ExpressionInstance::Context(context) => {
+      let mut context_variables = variables.clone();
+      for context_entry in context.context_entries() {
+        let found = context_entry.variable.as_ref().is_some_and(|variable| variable.id() == id);
+        let mut entry_variables = context_variables.clone();
+        if found || find_expression(&context_entry.value, id, &mut entry_variables) {
+          *variables = if found { context_variables } else { entry_variables };
+          return true;
+        }
+        if let Some(variable) = &context_entry.variable {
+          add_variable(&mut context_variables, variable.name(), variable.type_ref(), ScopeVariableKind::ContextEntry);
+        }
+      }
+      false
+    }
+    ExpressionInstance::FunctionDefinition(function_definition) => {
+      let Some(body) = function_definition.body() else {
+        return false;
+      };
+      let mut body_variables = variables.clone();
+      for parameter in function_definition.formal_parameters() {
+        add_variable(&mut body_variables, parameter.name(), parameter.type_ref(), ScopeVariableKind::Parameter);
+      }
+      if find_expression(body, id, &mut body_variables) {
+        *variables = body_variables;
+        return true;
+      }
+      false
+    }
+    ExpressionInstance::Invocation(invocation) => {
+      find_expression(invocation.called_function(), id, variables)
+        || invocation
+          .bindings()
+          .iter()
+          .filter_map(|binding| binding.binding_formula().as_ref())
+          .any(|binding_formula| find_expression(binding_formula, id, variables))
+    }
+    ExpressionInstance::List(list) => list.elements().iter().any(|element| find_expression(element, id, variables)),
+    ExpressionInstance::Relation(relation) => relation
+      .rows()
+      .iter()
+      .any(|row| row.elements().iter().any(|element| find_expression(element, id, variables))),
+    ExpressionInstance::DecisionTable(_) | ExpressionInstance::LiteralExpression(_) => false,
+  }
+}
+
+/// Returns the identifier of the expression instance.
+fn expression_id(expression_instance: &ExpressionInstance) -> &str {
+  match expression_instance {
+    ExpressionInstance::Context(context) => context.id(),
+    ExpressionInstance::DecisionTable(decision_table) => decision_table.id(),
+    ExpressionInstance::FunctionDefinition(function_definition) => function_definition.id(),
+    ExpressionInstance::Invocation(invocation) => invocation.id(),
+    ExpressionInstance::List(list) => list.id(),
+    ExpressionInstance::LiteralExpression(literal_expression) => literal_expression.id(),
+    ExpressionInstance::Relation(relation) => relation.id(),
+  }
+}
+
+/// Returns the DRG element with specified identifier.
+fn find_drg_element<'a>(definitions: &'a Definitions, id: &str) -> Option<&'a DrgElement> {
+  definitions.drg_elements().find(|drg_element| drg_element.id() == id)
+}
+
+/// Returns the text of the function type with specified types of parameters and result.
+fn function_type(parameter_types: Vec<String>, result_type: &str) -> String {
+  format!("function<{}>->{result_type}", parameter_types.join(", "))
+}
+
+/// Adds the variable, replacing the variable with the same name defined earlier.
+fn add_variable(variables: &mut Vec<ScopeVariable>, name: &str, type_ref: &str, kind: ScopeVariableKind) {
+  variables.retain(|variable| variable.name != name);
+  variables.push(ScopeVariable {
+    name: name.to_string(),
+    type_ref: type_ref.to_string(),
+    kind,
+  });
+}
@@ -0,0 +1,120 @@
+//! # Search for DRG elements
+
+use crate::model::*;
+use dmntk_common::similarity;
+
+/// Minimal similarity of the query and searched text, required to report a fuzzy match.
+const MIN_SIMILARITY: f64 = 0.6;
+
+/// Field of the [DrgElement] that matched the search query.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SearchField {
+  /// The query matched the name of the element.
+  Name,
+  /// The query matched the identifier of the element.
+  Id,
+  /// The query matched the label of the element.
+  Label,
+}
+
+/// Single result of searching for DRG elements.
+#[derive(Debug, Clone)]
+pub struct SearchResult<'a> {
+  /// Definitions containing the matching element.
+  definitions: &'a Definitions,
+  /// Matching element.
+  drg_element: &'a DrgElement,
+  /// Field of the element that matched the query.
+  field: SearchField,
+  /// Score of the match in range `0.0..=1.0`, the higher, the better.
+  score: f64,
+}
+
+impl<'a> SearchResult<'a> {
+  /// Returns a reference to definitions containing the matching element.
+  pub fn definitions(&self) -> &'a Definitions {
+    self.definitions
+  }
+  /// Returns a reference to matching element.
+  pub fn drg_element(&self) -> &'a DrgElement {
+    self.drg_element
+  }
+  /// Returns the field of the element that matched the query.
+  pub fn field(&self) -> SearchField {
+    self.field
+  }
+  /// Returns the score of the match.
+  pub fn score(&self) -> f64 {
+    self.score
+  }
+}
+
+/// Searches DRG elements in all definitions, whose name, identifier or label match the query.
+///
+/// Matching is case-insensitive and fuzzy. Exact matches are ranked first,
+/// followed by prefix matches, substring matches, subsequence matches
+/// and finally matches with similar spelling. Results are ordered by descending score,
+/// results having the same score are ordered by element name.
+pub fn search<'a>(definitions: &'a [Definitions], query: &str) -> Vec<SearchResult<'a>> {
+  let query = query.trim().to_lowercase();
+  if query.is_empty() {
+    return vec![];
+  }
+  let mut results = vec![];
+  for definitions in definitions {
+    for drg_element in definitions.drg_elements() {
+      let candidates = [
+        (SearchField::Name, Some(drg_element.name())),
+        (SearchField::Id, drg_element.opt_id().map(|id| id.as_str())),
+        (SearchField::Label, drg_element.label().as_deref()),
+      ];
+      let best = candidates
+        .into_iter()
+        .filter_map(|(field, opt_text)| opt_text.map(|text| (field, score(&query, &text.to_lowercase()))))
+        .filter(|(_, score)| *score > 0.0)
+        .fold(None, |best: Option<(SearchField, f64)>, candidate| match best {
+          Some(best) if best.1 >= candidate.1 => Some(best),
+          _ => Some(candidate),
+        });
+      if let Some((field, score)) = best {
+        results.push(SearchResult {
+          definitions,
+          drg_element,
+          field,
+          score,
+        });
+      }
+    }
+  }
+  results.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.drg_element.name().cmp(b.drg_element.name())));
+  results
+}
+
+/// Returns the score of matching the query against the text, both already lowercased.
+/// Zero means that the text does not match the query.
+fn score(query: &str, text: &str) -> f64 {
+  if text == query {
+    return 1.0;
+  }
+  let ratio = query.chars().count() as f64 / text.chars().count().max(1) as f64;
+  if text.starts_with(query) {
+    return 0.8 + 0.1 * ratio;
+  }
+  if text.contains(query) {
+    return 0.6 + 0.1 * ratio;
+  }
+  if is_subsequence(query, text) {
+    return 0.4 + 0.1 * ratio;
+  }
+  let similarity = similarity(query, text);
+  if similarity >= MIN_SIMILARITY {
+    return 0.4 * similarity;
+  }
+  0.0
+}
+
+/// Returns `true` when all characters of the query appear in the text in the same order.
+fn is_subsequence(query: &str, text: &str) -> bool {
+  let mut text_chars = text.chars();
+  query.chars().all(|query_ch| text_chars.any(|text_ch| text_ch == query_ch))
+}
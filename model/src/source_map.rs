@@ -0,0 +1,143 @@
+//! # Source map of DMN models
+//!
+//! Maps identifiers of model elements to their locations in the original XML document,
+//! so errors reported by validators and evaluators may point to the exact place
+//! in the DMN file, and tools may jump from the element to its definition and back.
+
+use crate::model::DmnId;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+/// Position in the original document, lines and columns are numbered from 1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SourcePosition {
+  /// Line number.
+  line: usize,
+  /// Column number, counted in characters.
+  column: usize,
+}
+
+impl SourcePosition {
+  /// Returns the line number.
+  pub fn line(&self) -> usize {
+    self.line
+  }
+
+  /// Returns the column number.
+  pub fn column(&self) -> usize {
+    self.column
+  }
+}
+
+impl fmt::Display for SourcePosition {
+  /// Formats the position the same way as positions reported in schema violations.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "line {}, column {}", self.line, self.column)
+  }
+}
+
+/// Span of the element in the original document, from the start of the start tag
+/// to the end of the end tag (or to the end of the empty element tag).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSpan {
+  /// Byte range of the element.
+  range: Range<usize>,
+  /// Position of the first character of the element.
+  start: SourcePosition,
+  /// Position just after the last character of the element.
+  end: SourcePosition,
+}
+
+impl SourceSpan {
+  /// Returns the byte range of the element.
+  pub fn range(&self) -> Range<usize> {
+    self.range.clone()
+  }
+
+  /// Returns the position of the first character of the element.
+  pub fn start(&self) -> SourcePosition {
+    self.start
+  }
+
+  /// Returns the position just after the last character of the element.
+  pub fn end(&self) -> SourcePosition {
+    self.end
+  }
+}
+
+/// Spans of all model elements having identifiers (provided or generated).
+#[derive(Default, Clone)]
+pub struct SourceMap {
+  /// Identifiers and spans of elements, ordered by position in the document.
+  spans: Vec<(DmnId, SourceSpan)>,
+  /// Indexes of spans by element identifiers.
+  index: HashMap<String, usize>,
+}
+
+impl fmt::Debug for SourceMap {
+  /// Spans are formatted in document order, so the output does not depend on the order of hashing.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_list().entries(self.spans.iter()).finish()
+  }
+}
+
+impl SourceMap {
+  /// Builds the source map from byte ranges of elements in specified original document.
+  pub(crate) fn new(xml: &str, mut ranges: Vec<(DmnId, Range<usize>)>) -> Self {
+    ranges.sort_by_key(|(_, range)| range.start);
+    let line_starts = std::iter::once(0).chain(xml.match_indices('\n').map(|(index, _)| index + 1)).collect::<Vec<usize>>();
+    let position = |offset: usize| {
+      let line = line_starts.partition_point(|line_start| *line_start <= offset);
+      let line_start = line_starts[line - 1];
+      SourcePosition {
+        line,
+        column: xml.get(line_start..offset).map(|text| text.chars().count()).unwrap_or(offset - line_start) + 1,
+      }
+    };
+    let mut index = HashMap::new();
+    let mut spans = vec![];
+    for (id, range) in ranges {
+      let span = SourceSpan {
+        start: position(range.start),
+        end: position(range.end),
+        range,
+      };
+      index.insert(id_text(&id).to_string(), spans.len());
+      spans.push((id, span));
+    }
+    Self { spans, index }
+  }
+
+  /// Returns the span of the element with specified identifier.
+  pub fn span(&self, id: &str) -> Option<&SourceSpan> {
+    self.index.get(id).map(|index| &self.spans[*index].1)
+  }
+
+  /// Returns the identifier of the innermost element containing specified byte offset.
+  pub fn element_at(&self, offset: usize) -> Option<&str> {
+    self
+      .spans
+      .iter()
+      .filter(|(_, span)| span.range.contains(&offset))
+      .min_by_key(|(_, span)| span.range.len())
+      .map(|(id, _)| id_text(id))
+  }
+
+  /// Returns the number of elements in the source map.
+  pub fn len(&self) -> usize {
+    self.spans.len()
+  }
+
+  /// Returns `true` when the source map contains no elements.
+  pub fn is_empty(&self) -> bool {
+    self.spans.is_empty()
+  }
+}
+
+/// Returns the text of the identifier, provided or generated.
+fn id_text(id: &DmnId) -> &str {
+  match id {
+    DmnId::Provided(id) | DmnId::Generated(id) => id,
+  }
+}
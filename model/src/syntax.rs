@@ -0,0 +1,271 @@
+//! # Incremental syntax checking
+//!
+//! Syntax checker reports syntax errors in all `FEEL` expressions of the decision model:
+//! literal expressions, input expressions, allowed input values and entries of decision tables.
+//! Results of parsing are cached by the text of the expression, so after an edit
+//! only changed expressions are parsed again. The cache is discarded when the names
+//! defined in the model change, because names built from multiple words influence parsing.
+//!
+//! When only the text of a single literal expression was edited, the expression
+//! may be re-validated alone with [SyntaxChecker::check_literal_expression],
+//! without walking the whole model.
+
+use crate::linter::parsing_scope;
+use crate::model::{DecisionTable, Definitions, DrgElement, ExpressionInstance, LiteralExpression, NamedElement};
+use crate::source_map::SourceSpan;
+use crate::DmnElement;
+use dmntk_feel::FeelScope;
+use dmntk_feel_parser::SyntaxError;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Kind of the parsed text.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum TextKind {
+  /// Text is an expression.
+  Expression,
+  /// Text contains unary tests.
+  UnaryTests,
+}
+
+/// Syntax error found in the expression of the decision model.
+#[derive(Debug, Clone)]
+pub struct SyntaxDiagnostic {
+  /// Identifier of the element containing the expression.
+  element_id: String,
+  /// Description of the location of the expression in the model.
+  location: String,
+  /// Syntax error reported by the parser.
+  error: SyntaxError,
+  /// Span of the element in the original document.
+  span: Option<SourceSpan>,
+}
+
+impl SyntaxDiagnostic {
+  /// Returns the identifier of the element containing the expression.
+  pub fn element_id(&self) -> &str {
+    &self.element_id
+  }
+
+  /// Returns the description of the location of the expression in the model.
+  pub fn location(&self) -> &str {
+    &self.location
+  }
+
+  /// Returns the syntax error reported by the parser.
+  pub fn error(&self) -> &SyntaxError {
+    &self.error
+  }
+
+  /// Returns the span of the element in the original document.
+  pub fn span(&self) -> Option<&SourceSpan> {
+    self.span.as_ref()
+  }
+}
+
+impl fmt::Display for SyntaxDiagnostic {
+  /// Formats the diagnostic with the position of the element, when known.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.span {
+      Some(span) => write!(f, "{} {}: {}", span.start(), self.location, self.error),
+      None => write!(f, "{}: {}", self.location, self.error),
+    }
+  }
+}
+
+/// Syntax checker re-parsing only expressions changed since the previous check.
+#[derive(Default)]
+pub struct SyntaxChecker {
+  /// Scope used for parsing, prepared during the last full check.
+  scope: Option<FeelScope>,
+  /// Textual form of the scope, used to detect changes of names defined in the model.
+  scope_key: String,
+  /// Syntax errors of already parsed texts.
+  cache: HashMap<(TextKind, String), Vec<SyntaxError>>,
+  /// Syntax errors of texts parsed before the current full check.
+  previous: HashMap<(TextKind, String), Vec<SyntaxError>>,
+  /// Locations of literal expressions, indexed by their identifiers.
+  locations: HashMap<String, String>,
+  /// Number of texts parsed so far.
+  parse_count: usize,
+}
+
+impl SyntaxChecker {
+  /// Creates a new syntax checker with empty cache.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the number of texts parsed so far, texts found in cache are not counted.
+  pub fn parse_count(&self) -> usize {
+    self.parse_count
+  }
+
+  /// Checks the syntax of all expressions in the decision model,
+  /// returns diagnostics in the order of elements in the model.
+  pub fn check(&mut self, definitions: &Definitions) -> Vec<SyntaxDiagnostic> {
+    let scope = parsing_scope(definitions);
+    let scope_key = scope.to_string();
+    // texts not present in the model any more are removed from cache, so it does not grow while editing
+    self.previous = std::mem::take(&mut self.cache);
+    if self.scope.is_none() || scope_key != self.scope_key {
+      self.previous.clear();
+      self.scope_key = scope_key;
+    }
+    self.scope = Some(scope);
+    self.locations.clear();
+    let mut diagnostics = vec![];
+    for drg_element in definitions.drg_elements() {
+      let expression_instance = match drg_element {
+        DrgElement::Decision(decision) => decision.decision_logic().as_ref(),
+        DrgElement::BusinessKnowledgeModel(bkm) => bkm.encapsulated_logic().as_ref().and_then(|function_definition| function_definition.body().as_ref()),
+        _ => None,
+      };
+      if let Some(expression_instance) = expression_instance {
+        let location = format!("{} `{}`", drg_element.kind(), drg_element.name());
+        self.check_expression_instance(definitions, &location, expression_instance, &mut diagnostics);
+      }
+    }
+    self.previous.clear();
+    diagnostics
+  }
+
+  /// Checks the syntax of the edited text of the literal expression with specified identifier.
+  /// Only this text is parsed, names defined in the model are taken from the last full check,
+  /// so after edits changing names (like renaming a decision) the whole model should be checked.
+  pub fn check_literal_expression(&mut self, definitions: &Definitions, id: &str, text: &str) -> Vec<SyntaxDiagnostic> {
+    if self.scope.is_none() {
+      let scope = parsing_scope(definitions);
+      self.scope_key = scope.to_string();
+      self.scope = Some(scope);
+    }
+    let location = match self.locations.get(id) {
+      Some(location) => location.clone(),
+      None => format!("literal expression `{id}`"),
+    };
+    let span = definitions.source_span(id).cloned();
+    self
+      .syntax_errors(TextKind::Expression, text)
+      .into_iter()
+      .map(|error| SyntaxDiagnostic {
+        element_id: id.to_string(),
+        location: location.clone(),
+        error,
+        span: span.clone(),
+      })
+      .collect()
+  }
+
+  /// Checks the expression instance and all expression instances nested in it.
+  fn check_expression_instance(&mut self, definitions: &Definitions, location: &str, expression_instance: &ExpressionInstance, diagnostics: &mut Vec<SyntaxDiagnostic>) {
+    match expression_instance {
+      ExpressionInstance::Context(context) => {
+        for context_entry in context.context_entries() {
+          self.check_expression_instance(definitions, location, &context_entry.value, diagnostics);
+        }
+      }
+      ExpressionInstance::DecisionTable(decision_table) => self.check_decision_table(definitions, location, decision_table, diagnostics),
+      ExpressionInstance::FunctionDefinition(function_definition) => {
+        if let Some(body) = function_definition.body() {
+          self.check_expression_instance(definitions, location, body, diagnostics);
+        }
+      }
+      ExpressionInstance::Invocation(invocation) => {
+        for binding in &invocation.bindings {
+          if let Some(binding_formula) = &binding.binding_formula {
+            self.check_expression_instance(definitions, location, binding_formula, diagnostics);
+          }
+        }
+      }
+      ExpressionInstance::List(list) => {
+        for element in &list.elements {
+          self.check_expression_instance(definitions, location, element, diagnostics);
+        }
+      }
+      ExpressionInstance::LiteralExpression(literal_expression) => self.check_literal(definitions, location, literal_expression, diagnostics),
+      ExpressionInstance::Relation(relation) => {
+        for row in relation.rows() {
+          for element in &row.elements {
+            self.check_expression_instance(definitions, location, element, diagnostics);
+          }
+        }
+      }
+    }
+  }
+
+  /// Checks the syntax of the literal expression and remembers its location.
+  fn check_literal(&mut self, definitions: &Definitions, location: &str, literal_expression: &LiteralExpression, diagnostics: &mut Vec<SyntaxDiagnostic>) {
+    let id = literal_expression.id().to_string();
+    let location = format!("literal expression in {location}");
+    self.locations.insert(id.clone(), location.clone());
+    if let Some(text) = literal_expression.text() {
+      let span = definitions.source_span(&id).cloned();
+      for error in self.syntax_errors(TextKind::Expression, text) {
+        diagnostics.push(SyntaxDiagnostic {
+          element_id: id.clone(),
+          location: location.clone(),
+          error,
+          span: span.clone(),
+        });
+      }
+    }
+  }
+
+  /// Checks the syntax of input expressions, allowed input values and entries of the decision table.
+  fn check_decision_table(&mut self, definitions: &Definitions, location: &str, decision_table: &DecisionTable, diagnostics: &mut Vec<SyntaxDiagnostic>) {
+    let mut texts = vec![];
+    for (index, input_clause) in decision_table.input_clauses().enumerate() {
+      texts.push((TextKind::Expression, format!("input expression {}", index + 1), input_clause.input_expression.as_str()));
+      if let Some(allowed_input_values) = &input_clause.allowed_input_values {
+        texts.push((TextKind::UnaryTests, format!("allowed values of input {}", index + 1), allowed_input_values.as_str()));
+      }
+    }
+    for (rule_index, rule) in decision_table.rules().enumerate() {
+      for (index, input_entry) in rule.input_entries.iter().enumerate() {
+        texts.push((
+          TextKind::UnaryTests,
+          format!("input entry {} of rule {}", index + 1, rule_index + 1),
+          input_entry.text.as_str(),
+        ));
+      }
+      for (index, output_entry) in rule.output_entries.iter().enumerate() {
+        texts.push((
+          TextKind::Expression,
+          format!("output entry {} of rule {}", index + 1, rule_index + 1),
+          output_entry.text.as_str(),
+        ));
+      }
+    }
+    let span = definitions.source_span(decision_table.id()).cloned();
+    for (kind, part, text) in texts {
+      for error in self.syntax_errors(kind, text) {
+        diagnostics.push(SyntaxDiagnostic {
+          element_id: decision_table.id().to_string(),
+          location: format!("{part} of decision table in {location}"),
+          error,
+          span: span.clone(),
+        });
+      }
+    }
+  }
+
+  /// Returns syntax errors of the text, the text is parsed only when not found in cache.
+  fn syntax_errors(&mut self, kind: TextKind, text: &str) -> Vec<SyntaxError> {
+    let key = (kind, text.to_string());
+    if let Some(errors) = self.cache.get(&key) {
+      return errors.clone();
+    }
+    if let Some(errors) = self.previous.remove(&key) {
+      self.cache.insert(key, errors.clone());
+      return errors;
+    }
+    let scope = self.scope.get_or_insert_with(FeelScope::default);
+    let (_, errors) = match kind {
+      TextKind::Expression => dmntk_feel_parser::parse_expression_with_recovery(scope, text),
+      TextKind::UnaryTests => dmntk_feel_parser::parse_unary_tests_with_recovery(scope, text),
+    };
+    self.parse_count += 1;
+    self.cache.insert(key, errors.clone());
+    errors
+  }
+}
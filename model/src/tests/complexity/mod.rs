@@ -0,0 +1,62 @@
+//! # Tests for complexity estimation
+
+use crate::tests::parser::input_files::{T_DMN_0017, T_DMN_0021};
+use crate::{estimate_complexity, parse, ComplexityClass};
+
+#[test]
+fn _0001() {
+  let definitions = parse(T_DMN_0021).unwrap();
+  let estimations = estimate_complexity(&definitions);
+  let complexities = estimations.iter().map(|estimation| estimation.to_string()).collect::<Vec<String>>();
+  assert_eq!(
+    vec![
+      "decision `Squares`: O(1)",
+      "decision `Total`: O(n)",
+      "decision `Pairs`: O(n^2)",
+      "decision `Joined`: O(n^2)",
+      "decision `Report`: O(n^2)",
+      "decision `Permutations`: unbounded",
+    ],
+    complexities
+  );
+  assert_eq!("_pairs", estimations[2].decision_id());
+  assert_eq!("Pairs", estimations[2].decision_name());
+  assert_eq!(ComplexityClass::Polynomial(2), estimations[2].complexity());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(T_DMN_0021).unwrap();
+  let estimations = estimate_complexity(&definitions);
+  let findings = estimations.iter().map(|estimation| estimation.findings().join("; ")).collect::<Vec<String>>();
+  assert_eq!(
+    vec![
+      "",
+      "",
+      "cartesian product of 2 unbounded lists in `for` expression",
+      "nested iteration over unbounded lists in filter, estimated complexity O(n^2)",
+      "depends on decision `Pairs` with estimated complexity O(n^2)",
+      "invokes recursive business knowledge model `Factorial`",
+    ],
+    findings
+  );
+}
+
+#[test]
+fn _0003() {
+  // models without lists are evaluated in constant time
+  let definitions = parse(T_DMN_0017).unwrap();
+  assert!(estimate_complexity(&definitions)
+    .iter()
+    .all(|estimation| estimation.complexity() == ComplexityClass::Constant));
+}
+
+#[test]
+fn _0004() {
+  assert_eq!("O(1)", ComplexityClass::Constant.to_string());
+  assert_eq!("O(n)", ComplexityClass::Linear.to_string());
+  assert_eq!("O(n^3)", ComplexityClass::Polynomial(3).to_string());
+  assert_eq!("unbounded", ComplexityClass::Unbounded.to_string());
+  assert!(ComplexityClass::Linear < ComplexityClass::Polynomial(2));
+  assert!(ComplexityClass::Polynomial(3) < ComplexityClass::Unbounded);
+}
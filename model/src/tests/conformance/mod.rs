@@ -0,0 +1,77 @@
+//! # Tests for conformance level validation
+
+use crate::tests::parser::input_files::{T_DMN_0017, T_DMN_0019, T_DMN_0020};
+use crate::{parse, validate_conformance_level};
+use dmntk_examples::*;
+use dmntk_feel::ConformanceLevel;
+
+#[test]
+fn _0001() {
+  let definitions = parse(T_DMN_0019).unwrap();
+  assert!(validate_conformance_level(std::slice::from_ref(&definitions), ConformanceLevel::Level2).is_ok());
+  assert!(validate_conformance_level(&[definitions], ConformanceLevel::Level3).is_ok());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(T_DMN_0017).unwrap();
+  assert!(validate_conformance_level(&[definitions], ConformanceLevel::Level2).is_ok());
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(T_DMN_0020).unwrap();
+  assert_eq!(
+    "<ConformanceLevelError> `if` expression in literal expression in decision `Discount` is not allowed at conformance level 2",
+    validate_conformance_level(std::slice::from_ref(&definitions), ConformanceLevel::Level2).err().unwrap().to_string()
+  );
+  assert!(validate_conformance_level(&[definitions], ConformanceLevel::Level3).is_ok());
+}
+
+#[test]
+fn _0004() {
+  let definitions = parse(T_DMN_0019).unwrap();
+  assert_eq!(
+    "<ConformanceLevelError> decision logic in decision `Age group` is not allowed at conformance level 1",
+    validate_conformance_level(&[definitions], ConformanceLevel::Level1).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0005() {
+  let definitions = parse(DMN_3_0014).unwrap();
+  assert_eq!(
+    "<ConformanceLevelError> relation in decision `Bankrates` is not allowed at conformance level 2",
+    validate_conformance_level(&[definitions], ConformanceLevel::Level2).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  let definitions = parse(&T_DMN_0019.replacen(r#""young""#, r#"string length("young")"#, 1)).unwrap();
+  assert_eq!(
+    r#"<ConformanceLevelError> function invocation in output entry `string length("young")` of rule 2 in decision `Ordered group` is not allowed at conformance level 2"#,
+    validate_conformance_level(&[definitions], ConformanceLevel::Level2).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  let definitions = parse(&T_DMN_0019.replace("[30..40]", "abs(-30)")).unwrap();
+  assert_eq!(
+    "<ConformanceLevelError> function invocation in input entry `abs(-30)` of rule 3 in decision `Ordered group` is not allowed at conformance level 2",
+    validate_conformance_level(&[definitions], ConformanceLevel::Level2).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0008() {
+  // all models of compatibility tests at level 2 are valid at conformance level 2
+  for model in [
+    DMN_2_0001, DMN_2_0002, DMN_2_0003, DMN_2_0004, DMN_2_0005, DMN_2_0006, DMN_2_0007, DMN_2_0008, DMN_2_0009, DMN_2_0010, DMN_2_0100, DMN_2_0101, DMN_2_0102, DMN_2_0105,
+    DMN_2_0106, DMN_2_0107, DMN_2_0108, DMN_2_0109, DMN_2_0110, DMN_2_0111, DMN_2_0112, DMN_2_0113, DMN_2_0114, DMN_2_0115, DMN_2_0116, DMN_2_0117, DMN_2_0118, DMN_2_0119,
+  ] {
+    let definitions = parse(model).unwrap();
+    assert!(validate_conformance_level(&[definitions], ConformanceLevel::Level2).is_ok());
+  }
+}
@@ -0,0 +1,74 @@
+//! # Tests for consolidation of decision table rules
+
+use crate::model::*;
+use crate::tests::parser::input_files::T_DMN_0019;
+use crate::{consolidate_rules, parse, RuleConsolidation};
+
+/// Returns the consolidation of rules of the decision table being the logic of specified decision.
+fn consolidation(decision_name: &str) -> RuleConsolidation {
+  let definitions = parse(T_DMN_0019).unwrap();
+  let decision = definitions.decisions().into_iter().find(|decision| decision.name() == decision_name).unwrap();
+  let Some(ExpressionInstance::DecisionTable(decision_table)) = decision.decision_logic() else {
+    panic!("expected decision table");
+  };
+  consolidate_rules(decision_table)
+}
+
+/// Returns proposed rules as text, input entries are followed by output entries.
+fn rules(consolidation: &RuleConsolidation) -> Vec<String> {
+  consolidation
+    .rules()
+    .iter()
+    .map(|rule| {
+      rule
+        .input_entries
+        .iter()
+        .map(|entry| entry.text.as_str())
+        .chain(rule.output_entries.iter().map(|entry| entry.text.as_str()))
+        .collect::<Vec<&str>>()
+        .join(" | ")
+    })
+    .collect()
+}
+
+/// Returns numbers of rules merged by each merge.
+fn merged_rules(consolidation: &RuleConsolidation) -> Vec<Vec<usize>> {
+  consolidation.merges().iter().map(|merge| merge.merged_rules().to_vec()).collect()
+}
+
+#[test]
+fn _0001() {
+  // adjacent ranges are joined, different literals are combined into a list
+  let consolidation = consolidation("Age group");
+  assert_eq!(vec![r#"< 18 | "yes", "no" | "junior""#, r#">= 18 | "yes" | "adult""#], rules(&consolidation));
+  assert_eq!(vec![vec![1, 4], vec![2, 3]], merged_rules(&consolidation));
+  assert!(!consolidation.is_minimal());
+}
+
+#[test]
+fn _0002() {
+  // rules of decision tables with FIRST hit policy are merged only when adjacent
+  let consolidation = consolidation("Ordered group");
+  assert_eq!(4, consolidation.rules().len());
+  assert!(consolidation.is_minimal());
+}
+
+#[test]
+fn _0003() {
+  // rules of decision tables with COLLECT hit policy are merged only when not overlapping,
+  // complementary conditions are combined into irrelevant input entry
+  let consolidation = consolidation("Collected groups");
+  assert_eq!(
+    vec![r#"[0..18) | "yes" | "child""#, r#"[0..10] | "yes" | "child""#, r#"- | - | "guest""#],
+    rules(&consolidation)
+  );
+  assert_eq!(vec![vec![1, 2], vec![4, 5]], merged_rules(&consolidation));
+}
+
+#[test]
+fn _0004() {
+  // rules with different outputs are never merged
+  let consolidation = consolidation("Minimal");
+  assert_eq!(vec![r#"< 18 | "junior""#, r#">= 18 | "adult""#], rules(&consolidation));
+  assert!(consolidation.is_minimal());
+}
@@ -0,0 +1,91 @@
+//! # Tests for decision requirements graph
+
+use crate::tests::parser::input_files::T_DMN_0017;
+use crate::{decision_requirements_graph, parse, DrgEdge, DrgNode, DrgNodeKind};
+use dmntk_examples::{DMN_3_0085, DMN_3_0086, DMN_3_0086_IMPORT};
+use petgraph::algo::toposort;
+use petgraph::graph::DiGraph;
+use petgraph::Direction;
+
+/// Returns edges of the graph as tuples `(source name, target name, edge)`, sorted.
+fn edges(graph: &DiGraph<DrgNode, DrgEdge>) -> Vec<(String, String, DrgEdge)> {
+  let mut edges = graph
+    .edge_indices()
+    .map(|edge| {
+      let (source, target) = graph.edge_endpoints(edge).unwrap();
+      (graph[source].name().to_string(), graph[target].name().to_string(), graph[edge])
+    })
+    .collect::<Vec<_>>();
+  edges.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+  edges
+}
+
+#[test]
+fn _0001() {
+  let definitions = [parse(T_DMN_0017).unwrap()];
+  let graph = decision_requirements_graph(&definitions);
+  assert_eq!(4, graph.node_count());
+  assert_eq!(
+    vec![
+      ("Amount".to_string(), "Approval".to_string(), DrgEdge::InformationRequirement),
+      ("Amount".to_string(), "Credit policy".to_string(), DrgEdge::AuthorityRequirement),
+      ("Credit policy".to_string(), "Approval".to_string(), DrgEdge::AuthorityRequirement),
+      ("Regulation".to_string(), "Credit policy".to_string(), DrgEdge::AuthorityRequirement),
+    ],
+    edges(&graph)
+  );
+}
+
+#[test]
+fn _0002() {
+  let definitions = [parse(T_DMN_0017).unwrap()];
+  let graph = decision_requirements_graph(&definitions);
+  let node = graph.node_indices().find(|node| graph[*node].id() == "_ks_policy").unwrap();
+  assert_eq!(DrgNodeKind::KnowledgeSource, graph[node].kind());
+  assert_eq!("Credit policy", graph[node].name());
+  assert_eq!("https://dmntk.io", graph[node].namespace());
+  let dependents = graph
+    .neighbors_directed(node, Direction::Outgoing)
+    .map(|node| graph[node].name().to_string())
+    .collect::<Vec<String>>();
+  assert_eq!(vec!["Approval"], dependents);
+  // the graph can be analysed with petgraph algorithms
+  let sorted = toposort(&graph, None)
+    .unwrap()
+    .into_iter()
+    .map(|node| graph[node].name().to_string())
+    .collect::<Vec<String>>();
+  assert_eq!("Approval", sorted.last().unwrap());
+}
+
+#[test]
+fn _0003() {
+  let definitions = [parse(DMN_3_0085).unwrap()];
+  let graph = decision_requirements_graph(&definitions);
+  let edges = edges(&graph);
+  assert!(edges.iter().any(|(_, target, edge)| target == "decisionService_001" && *edge == DrgEdge::OutputDecision));
+  assert!(edges.iter().any(|(_, target, edge)| target == "decisionService_002" && *edge == DrgEdge::ServiceInput));
+}
+
+#[test]
+fn _0004() {
+  let definitions = [parse(DMN_3_0086).unwrap(), parse(DMN_3_0086_IMPORT).unwrap()];
+  let graph = decision_requirements_graph(&definitions);
+  let edge = graph
+    .edge_indices()
+    .find(|edge| graph[*edge] == DrgEdge::KnowledgeRequirement)
+    .and_then(|edge| graph.edge_endpoints(edge))
+    .unwrap();
+  assert_eq!("Say Hello", graph[edge.0].name());
+  assert_eq!("https://dmntk.io/3_0086/imported", graph[edge.0].namespace());
+  assert_eq!("https://dmntk.io/3_0086", graph[edge.1].namespace());
+}
+
+#[test]
+fn _0005() {
+  // references to elements in not provided definitions are skipped
+  let definitions = [parse(DMN_3_0086).unwrap()];
+  let graph = decision_requirements_graph(&definitions);
+  assert_eq!(2, graph.node_count());
+  assert_eq!(1, graph.edge_count());
+}
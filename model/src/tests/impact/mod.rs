@@ -0,0 +1,64 @@
+//! # Tests for impact analysis
+
+use crate::tests::parser::input_files::T_DMN_0017;
+use crate::{impact_analysis, parse, DrgNodeKind};
+use dmntk_examples::{DMN_3_0014, DMN_3_0085, DMN_3_0086, DMN_3_0086_IMPORT};
+
+/// Returns names of affected elements, in reported order.
+fn affected(xml: &[&str], id: &str) -> Vec<String> {
+  let definitions = xml.iter().map(|xml| parse(xml).unwrap()).collect::<Vec<_>>();
+  impact_analysis(&definitions, id).unwrap().iter().map(|node| node.name().to_string()).collect()
+}
+
+#[test]
+fn _0001() {
+  // item definition used directly by a decision and a parameter of a business knowledge model
+  assert_eq!(vec!["Bankrates", "RankedProducts"], affected(&[DMN_3_0014], "tLoanProduct"));
+}
+
+#[test]
+fn _0002() {
+  // item definition used only by a component of another item definition
+  assert_eq!(vec!["RankedProducts"], affected(&[DMN_3_0014], "tMetrics"));
+}
+
+#[test]
+fn _0003() {
+  // input data
+  assert_eq!(vec!["RankedProducts"], affected(&[DMN_3_0014], "_67c50a4c-3002-4d0a-9acf-4c76cb0364fa"));
+}
+
+#[test]
+fn _0004() {
+  // authority requirements do not propagate changes
+  assert_eq!(vec!["Approval"], affected(&[T_DMN_0017], "_i_amount"));
+  assert!(affected(&[T_DMN_0017], "_ks_regulation").is_empty());
+}
+
+#[test]
+fn _0005() {
+  // business knowledge model in imported definitions
+  assert_eq!(
+    vec!["A Decision Ctx with DT"],
+    affected(&[DMN_3_0086, DMN_3_0086_IMPORT], "_32543811-b499-4608-b784-6c6f294b1c58")
+  );
+}
+
+#[test]
+fn _0006() {
+  // decision services having the changed element as an input or output
+  let definitions = [parse(DMN_3_0085).unwrap()];
+  let nodes = impact_analysis(&definitions, "_decision_002_input").unwrap();
+  assert_eq!(2, nodes.len());
+  assert_eq!(("decisionService_002", DrgNodeKind::DecisionService), (nodes[0].name(), nodes[0].kind()));
+  assert_eq!(("decision_002", DrgNodeKind::Decision), (nodes[1].name(), nodes[1].kind()));
+}
+
+#[test]
+fn _0007() {
+  let definitions = [parse(DMN_3_0014).unwrap()];
+  assert_eq!(
+    "<ModelError> element with identifier or name 'tUnknown' not found",
+    impact_analysis(&definitions, "tUnknown").unwrap_err().to_string()
+  );
+}
@@ -0,0 +1,56 @@
+//! # Tests for decision model linter
+
+use crate::tests::parser::input_files::{T_DMN_0017, T_DMN_0019, T_DMN_0020};
+use crate::{lint, parse, LINT_DEAD_BRANCH, LINT_MERGEABLE_RULES};
+
+#[test]
+fn _0001() {
+  let definitions = parse(T_DMN_0019).unwrap();
+  let warnings = lint(&definitions);
+  assert_eq!(4, warnings.len());
+  assert!(warnings.iter().all(|warning| warning.code() == LINT_MERGEABLE_RULES));
+  assert_eq!("_age_group_table", warnings[0].element_id());
+  assert_eq!(
+    r#"rules 1, 4 of decision table in decision `Age group` can be merged into rule: < 18 | "yes", "no" => "junior""#,
+    warnings[0].message()
+  );
+  assert_eq!(
+    r#"line 21, column 9 [mergeable-rules]: rules 2, 3 of decision table in decision `Age group` can be merged into rule: >= 18 | "yes" => "adult""#,
+    warnings[1].to_string()
+  );
+  assert_eq!("_collected_groups_table", warnings[2].element_id());
+}
+
+#[test]
+fn _0002() {
+  // models without decision tables produce no warnings
+  let definitions = parse(T_DMN_0017).unwrap();
+  assert!(lint(&definitions).is_empty());
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(T_DMN_0020).unwrap();
+  let warnings = lint(&definitions);
+  assert!(warnings.iter().all(|warning| warning.code() == LINT_DEAD_BRANCH));
+  let messages = warnings.iter().map(|warning| warning.message()).collect::<Vec<&str>>();
+  assert_eq!(
+    vec![
+      "condition of `if` expression is always false, `then` branch is never evaluated, in literal expression in decision `Discount`",
+      "condition of `if` expression is always true, `else` branch is never evaluated, in literal expression in decision `Discount`",
+      "range is empty, no value is ever in this range, in literal expression in decision `Seniors`",
+      "condition of filter is always false, filtered list is always empty, in literal expression in decision `Seniors`",
+      "input entry `> 150` of rule 2 of decision table in decision `Risk` never matches, tested values are constrained by allowed values",
+      r#"input entry `"Government"` of rule 3 of decision table in decision `Risk` never matches, tested values are constrained by allowed values"#,
+      "input entry `[30..20]` of rule 4 of decision table in decision `Risk` never matches, range is empty",
+      "input entry `10` of rule 4 of decision table in decision `Risk` never matches, tested values are of type `string`",
+    ],
+    messages
+  );
+  assert_eq!("_discount_expression", warnings[0].element_id());
+  assert_eq!("_risk_table", warnings[4].element_id());
+  assert_eq!(
+    "line 29, column 9 [dead-branch]: condition of `if` expression is always false, `then` branch is never evaluated, in literal expression in decision `Discount`",
+    warnings[0].to_string()
+  );
+}
@@ -0,0 +1,280 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use crate::schema_violations;
+use dmntk_common::{DmntkError, Language};
+use dmntk_feel::ConformanceLevel;
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+/// Returns the model with specified root element and content.
+fn model_with(root: &str, content: &str) -> String {
+  format!(r#"<{root} xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="https://dmntk.io" name="A">{content}</{root}>"#)
+}
+
+/// Returns the first violation of the schema.
+fn schema_violation(xml: &str) -> DmntkError {
+  schema_violations(xml).remove(0)
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_invalid_decision_table_orientation("Diagonal"),
+    "<ModelError> niepoprawna orientacja tabeli decyzyjnej: Diagonal",
+    "<ModelError> ungültige Ausrichtung der Entscheidungstabelle: Diagonal",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_invalid_decision_table_hit_policy("LAST"),
+    "<ModelError> niepoprawna polityka trafień tabeli decyzyjnej: LAST",
+    "<ModelError> ungültige Trefferrichtlinie der Entscheidungstabelle: LAST",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_element_not_found("_a"),
+    "<ModelError> nie znaleziono elementu o identyfikatorze lub nazwie '_a'",
+    "<ModelError> Element mit Kennung oder Name '_a' nicht gefunden",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_invalid_function_kind("Python"),
+    "<ModelParserError> 'Python' nie jest poprawnym rodzajem funkcji, dozwolone wartości to: 'FEEL', 'Java', 'PMML'",
+    "<ModelParserError> 'Python' ist keine gültige Funktionsart, zulässige Werte sind: 'FEEL', 'Java', 'PMML'",
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_translated(
+    err_invalid_hit_policy("LAST"),
+    "<ModelParserError> 'LAST' nie jest poprawną polityką trafień, dozwolone wartości to: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'",
+    "<ModelParserError> 'LAST' ist keine gültige Trefferrichtlinie, zulässige Werte sind: 'UNIQUE', 'FIRST', 'PRIORITY', 'ANY', 'COLLECT', 'RULE ORDER', 'OUTPUT ORDER'",
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_translated(
+    err_invalid_aggregation("AVG"),
+    "<ModelParserError> 'AVG' nie jest poprawną agregacją, dozwolone wartości to: 'COUNT', 'SUM', 'MIN', 'MAX'",
+    "<ModelParserError> 'AVG' ist keine gültige Aggregation, zulässige Werte sind: 'COUNT', 'SUM', 'MIN', 'MAX'",
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_translated(
+    err_invalid_color_value("invalid digit found in string"),
+    "<ModelParserError> konwersja na poprawną wartość koloru nie powiodła się, przyczyna: invalid digit found in string",
+    "<ModelParserError> Konvertierung in einen gültigen Farbwert fehlgeschlagen, Ursache: invalid digit found in string",
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_translated(
+    err_invalid_double_value("invalid float literal"),
+    "<ModelParserError> konwersja na poprawną wartość typu double nie powiodła się, przyczyna: invalid float literal",
+    "<ModelParserError> Konvertierung in einen gültigen Double-Wert fehlgeschlagen, Ursache: invalid float literal",
+  );
+}
+
+#[test]
+fn _0009() {
+  assert_translated(
+    err_required_child_node_is_missing("inputEntry", "text"),
+    "<ModelParserError> brak wymaganego węzła podrzędnego 'text' w węźle nadrzędnym 'inputEntry'",
+    "<ModelParserError> erforderlicher Kindknoten 'text' im Elternknoten 'inputEntry' fehlt",
+  );
+}
+
+#[test]
+fn _0010() {
+  assert_translated(
+    err_required_input_expression_is_missing(),
+    "<ModelParserError> brak wymaganego wyrażenia wejściowego w kolumnie wejściowej tabeli decyzyjnej",
+    "<ModelParserError> erforderlicher Eingabeausdruck in der Eingabespalte der Entscheidungstabelle fehlt",
+  );
+}
+
+#[test]
+fn _0011() {
+  assert_translated(
+    err_required_expression_instance_is_missing(),
+    "<ModelParserError> brak wymaganego wyrażenia",
+    "<ModelParserError> erforderlicher Ausdruck fehlt",
+  );
+}
+
+#[test]
+fn _0012() {
+  assert_translated(
+    err_number_of_elements_in_row_differs_from_number_of_columns(),
+    "<ModelParserError> liczba elementów w wierszu różni się od liczby kolumn zdefiniowanych w relacji",
+    "<ModelParserError> Anzahl der Elemente in einer Zeile weicht von der Anzahl der in der Relation definierten Spalten ab",
+  );
+}
+
+#[test]
+fn _0013() {
+  assert_translated(
+    err_xml_parsing_model_failed(err_required_expression_instance_is_missing()),
+    "<ModelParserError> parsowanie modelu z XML nie powiodło się, przyczyna: <ModelParserError> brak wymaganego wyrażenia",
+    "<ModelParserError> Einlesen des Modells aus XML fehlgeschlagen, Ursache: <ModelParserError> erforderlicher Ausdruck fehlt",
+  );
+}
+
+#[test]
+fn _0014() {
+  assert_translated(
+    err_xml_unexpected_node("decision", "input"),
+    "<ModelParserError> nieoczekiwany węzeł XML, oczekiwano: decision, napotkano: input",
+    "<ModelParserError> unerwarteter XML-Knoten, erwartet: decision, tatsächlich: input",
+  );
+}
+
+#[test]
+fn _0015() {
+  assert_translated(
+    err_xml_expected_mandatory_attribute("decision", "name"),
+    "<ModelParserError> oczekiwano wartości obowiązkowego atrybutu 'name' w węźle decision",
+    "<ModelParserError> Wert für Pflichtattribut 'name' im Knoten decision erwartet",
+  );
+}
+
+#[test]
+fn _0016() {
+  assert_translated(
+    err_xml_expected_mandatory_child_node("decision", "variable"),
+    "<ModelParserError> oczekiwano obowiązkowego węzła podrzędnego 'variable' w węźle nadrzędnym decision",
+    "<ModelParserError> obligatorischer Kindknoten 'variable' im Elternknoten decision erwartet",
+  );
+}
+
+#[test]
+fn _0017() {
+  assert_translated(
+    err_xml_expected_mandatory_text_content("text"),
+    "<ModelParserError> oczekiwano obowiązkowej treści tekstowej w węźle 'text'",
+    "<ModelParserError> obligatorischer Textinhalt im Knoten 'text' erwartet",
+  );
+}
+
+#[test]
+fn _0018() {
+  assert_translated(
+    err_item_definitions_cycle(),
+    "<ModelValidatorError> cykliczna zależność między definicjami typów",
+    "<ModelValidatorError> zyklische Abhängigkeit zwischen Typdefinitionen",
+  );
+}
+
+#[test]
+fn _0019() {
+  assert_translated(
+    err_requirements_cycle("A -> B -> A"),
+    "<ModelValidatorError> cykliczna zależność między wymaganiami decyzji: A -> B -> A",
+    "<ModelValidatorError> zyklische Abhängigkeit zwischen Entscheidungsanforderungen: A -> B -> A",
+  );
+}
+
+#[test]
+fn _0020() {
+  assert_translated(
+    err_variable_name_collision("A", "decision `_a`", "input data `_b`"),
+    "<ModelValidatorError> nazwa zmiennej `A` elementu decision `_a` koliduje z nazwą zmiennej elementu input data `_b`",
+    "<ModelValidatorError> Variablenname `A` von decision `_a` kollidiert mit dem Variablennamen von input data `_b`",
+  );
+}
+
+#[test]
+fn _0021() {
+  assert_translated(
+    err_schema_violation(3, 5, "/definitions/decision", "unknown reason"),
+    "<ModelSchemaError> wiersz 3, kolumna 5, element /definitions/decision: unknown reason",
+    "<ModelSchemaError> Zeile 3, Spalte 5, Element /definitions/decision: unknown reason",
+  );
+}
+
+#[test]
+fn _0022() {
+  assert_translated(
+    schema_violation("<definitions/>"),
+    "<ModelSchemaError> wiersz 1, kolumna 1, element /definitions: brak przestrzeni nazw DMN",
+    "<ModelSchemaError> Zeile 1, Spalte 1, Element /definitions: DMN-Namensraum fehlt",
+  );
+}
+
+#[test]
+fn _0023() {
+  assert_translated(
+    schema_violation(r#"<definitions xmlns="https://dmntk.io"/>"#),
+    "<ModelSchemaError> wiersz 1, kolumna 1, element /definitions: nieobsługiwana przestrzeń nazw DMN `https://dmntk.io`",
+    "<ModelSchemaError> Zeile 1, Spalte 1, Element /definitions: nicht unterstützter DMN-Namensraum `https://dmntk.io`",
+  );
+}
+
+#[test]
+fn _0024() {
+  assert_translated(
+    schema_violation(&model_with("decisions", "")),
+    "<ModelSchemaError> wiersz 1, kolumna 1, element /decisions: oczekiwano elementu `definitions`, napotkano `decisions`",
+    "<ModelSchemaError> Zeile 1, Spalte 1, Element /decisions: Element `definitions` erwartet, tatsächlich `decisions`",
+  );
+}
+
+#[test]
+fn _0025() {
+  assert_translated(
+    schema_violation(&model_with("definitions", "<rule/>")),
+    "<ModelSchemaError> wiersz 1, kolumna 105, element /definitions/rule: nieoczekiwany element `rule` w `definitions`",
+    "<ModelSchemaError> Zeile 1, Spalte 105, Element /definitions/rule: unerwartetes Element `rule` in `definitions`",
+  );
+}
+
+#[test]
+fn _0026() {
+  assert_translated(
+    schema_violation(&model_with("definitions", "<inputData/>")),
+    "<ModelSchemaError> wiersz 1, kolumna 105, element /definitions/inputData: brak wymaganego atrybutu `name`",
+    "<ModelSchemaError> Zeile 1, Spalte 105, Element /definitions/inputData: erforderliches Attribut `name` fehlt",
+  );
+}
+
+#[test]
+fn _0027() {
+  assert_translated(schema_violation(&model_with("definitions", r#"<decision name="A"><decisionTable aggregation="AVG"/></decision>"#)), "<ModelSchemaError> wiersz 1, kolumna 124, element /definitions/decision/decisionTable: niepoprawna wartość `AVG` atrybutu `aggregation`, oczekiwano jednej z: SUM, COUNT, MIN, MAX", "<ModelSchemaError> Zeile 1, Spalte 124, Element /definitions/decision/decisionTable: ungültiger Wert `AVG` des Attributs `aggregation`, erwartet wird einer von: SUM, COUNT, MIN, MAX");
+}
+
+#[test]
+fn _0028() {
+  assert_translated(
+    schema_violation(&model_with("definitions", "<description><b/></description>")),
+    "<ModelSchemaError> wiersz 1, kolumna 118, element /definitions/description/b: element `description` może zawierać tylko tekst",
+    "<ModelSchemaError> Zeile 1, Spalte 118, Element /definitions/description/b: Element `description` darf nur Text enthalten",
+  );
+}
+
+#[test]
+fn _0029() {
+  assert_translated(
+    err_not_allowed_at_conformance_level("decision table", "decision `A`", ConformanceLevel::Level1),
+    "<ConformanceLevelError> decision table w decision `A` nie jest dozwolone na poziomie zgodności 1",
+    "<ConformanceLevelError> decision table in decision `A` ist auf der Konformitätsstufe 1 nicht zulässig",
+  );
+}
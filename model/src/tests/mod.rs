@@ -1,5 +1,18 @@
 //! # Tests for DMN model components
 
+mod complexity;
+mod conformance;
+mod consolidation;
+mod drg;
+mod impact;
+mod linter;
+mod messages;
 mod model;
-mod parser;
+pub mod parser;
+mod sarif;
+mod schema;
+mod scope_variables;
+mod search;
+mod source_map;
+mod syntax;
 mod validator;
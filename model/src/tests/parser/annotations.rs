@@ -0,0 +1,38 @@
+use crate::model::*;
+use crate::parse;
+use crate::tests::parser::input_files::*;
+
+fn decision_table(definitions: &Definitions) -> &DecisionTable {
+  let decision = definitions.get_decision("_discount").unwrap();
+  let Some(ExpressionInstance::DecisionTable(decision_table)) = decision.decision_logic() else {
+    panic!("expected decision table");
+  };
+  decision_table
+}
+
+#[test]
+fn _0001() {
+  let definitions = parse(T_DMN_0018).unwrap();
+  let decision_table = decision_table(&definitions);
+  let names = decision_table.annotations().map(|annotation| annotation.name.as_str()).collect::<Vec<&str>>();
+  assert_eq!(vec!["Reason", "Source"], names);
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(T_DMN_0018).unwrap();
+  let rules = decision_table(&definitions).rules().collect::<Vec<&DecisionRule>>();
+  assert_eq!(3, rules.len());
+  let texts = |rule: &DecisionRule| rule.annotation_entries.iter().map(|entry| entry.text.clone()).collect::<Vec<String>>();
+  assert_eq!(vec!["Business customer", "Sales policy 2024"], texts(rules[0]));
+  assert_eq!(vec!["Large order", ""], texts(rules[1]));
+  assert_eq!(vec!["No discount", "Sales policy 2024"], texts(rules[2]));
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(T_DMN_0018).unwrap();
+  let rules = decision_table(&definitions).rules().collect::<Vec<&DecisionRule>>();
+  assert_eq!(Some("Discount for business customers"), rules[0].description.as_deref());
+  assert_eq!(None, rules[1].description.as_deref());
+}
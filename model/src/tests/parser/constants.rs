@@ -0,0 +1,32 @@
+use crate::parse;
+
+const MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<definitions namespace="https://dmntk.io/constants" name="constants" id="_constants" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <extensionElements>
+        <constant name="VAT rate">0.23</constant>
+        <constant name="Currency">"EUR"</constant>
+    </extensionElements>
+    <inputData name="Amount" id="_amount">
+        <variable typeRef="number" name="Amount"/>
+    </inputData>
+</definitions>
+"#;
+
+#[test]
+fn _0001() {
+  let definitions = parse(MODEL).unwrap();
+  let constants = definitions.constants();
+  assert_eq!(2, constants.len());
+  assert_eq!("VAT rate", constants[0].name());
+  assert_eq!("VAT rate", constants[0].feel_name().to_string());
+  assert_eq!("0.23", constants[0].text());
+  assert_eq!("Currency", constants[1].name());
+  assert_eq!(r#""EUR""#, constants[1].text());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(&MODEL.replace(r#"<constant name="Currency">"EUR"</constant>"#, "")).unwrap();
+  assert_eq!(1, definitions.constants().len());
+  assert!(parse(&MODEL.replace(r#" name="Currency""#, "")).is_err());
+}
@@ -0,0 +1,24 @@
+use crate::parse;
+
+const MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<definitions namespace="https://dmntk.io/input-defaults" name="input-defaults" id="_input_defaults" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <inputData name="Amount" id="_amount">
+        <variable typeRef="number" name="Amount"/>
+    </inputData>
+    <inputData name="Rate" id="_rate">
+        <extensionElements>
+            <defaultValue>0.05</defaultValue>
+        </extensionElements>
+        <variable typeRef="number" name="Rate"/>
+    </inputData>
+</definitions>
+"#;
+
+#[test]
+fn _0001() {
+  let definitions = parse(MODEL).unwrap();
+  let input_data = definitions.input_data();
+  assert_eq!(2, input_data.len());
+  assert!(input_data[0].default_value().is_none());
+  assert_eq!(Some("0.05".to_string()), *input_data[1].default_value());
+}
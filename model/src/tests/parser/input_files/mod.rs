@@ -16,3 +16,9 @@ pub const T_DMN_0013: &str = include_str!("t_0013.dmn");
 pub const T_DMN_0014: &str = include_str!("t_0014.dmn");
 pub const T_DMN_0015: &str = include_str!("t_0015.dmn");
 pub const T_DMN_0016: &str = include_str!("t_0016.dmn");
+pub const T_DMN_0017: &str = include_str!("t_0017.dmn");
+pub const T_DMN_0018: &str = include_str!("t_0018.dmn");
+pub const T_DMN_0019: &str = include_str!("t_0019.dmn");
+pub const T_DMN_0020: &str = include_str!("t_0020.dmn");
+pub const T_DMN_0021: &str = include_str!("t_0021.dmn");
+pub const T_DMN_0022: &str = include_str!("t_0022.dmn");
@@ -0,0 +1,39 @@
+use crate::model::*;
+use crate::parse;
+use crate::tests::parser::input_files::*;
+
+#[test]
+fn _0001() {
+  let definitions = parse(T_DMN_0017).unwrap();
+  assert_eq!(2, definitions.knowledge_sources().len());
+  let knowledge_source = definitions.get_knowledge_source("_ks_policy").unwrap();
+  assert_eq!("Credit policy", knowledge_source.name());
+  assert_eq!("https://dmntk.io/policies/credit", knowledge_source.location_uri().as_ref().unwrap());
+  assert_eq!("policy", knowledge_source.source_type().as_ref().unwrap());
+  assert_eq!("_ou_risk", knowledge_source.owner().as_ref().unwrap().id());
+  let authority_requirements = knowledge_source.authority_requirements();
+  assert_eq!(2, authority_requirements.len());
+  assert_eq!("_ks_regulation", authority_requirements[0].required_authority().as_ref().unwrap().id());
+  assert!(authority_requirements[0].required_decision().is_none());
+  assert!(authority_requirements[0].required_input().is_none());
+  assert_eq!("_i_amount", authority_requirements[1].required_input().as_ref().unwrap().id());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(T_DMN_0017).unwrap();
+  let knowledge_source = definitions.get_knowledge_source("_ks_regulation").unwrap();
+  assert!(knowledge_source.location_uri().is_none());
+  assert!(knowledge_source.source_type().is_none());
+  assert!(knowledge_source.owner().is_none());
+  assert!(knowledge_source.authority_requirements().is_empty());
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(T_DMN_0017).unwrap();
+  let decision = definitions.get_decision("_d_approval").unwrap();
+  assert_eq!(1, decision.authority_requirements().len());
+  assert_eq!("_ks_policy", decision.authority_requirements()[0].required_authority().as_ref().unwrap().id());
+  assert!(matches!(definitions.get_requirement("_ar_policy_amount"), Some(Requirement::Authority(_))));
+}
@@ -0,0 +1,79 @@
+//! # Tests for parsing DMN models in low-memory mode
+
+use crate::{parse, parse_low_memory, Definitions, DrgElement, ExpressionInstance};
+use dmntk_examples::{DMN_2_0001, DMN_3_0004, DMN_3_0008, DMN_3_0014, DMN_3_0017, DMN_3_0020, DMN_3_0035, DMN_FULL};
+
+/// Returns the debug representation of definitions, without generated identifiers.
+fn normalized(definitions: &Definitions) -> String {
+  let text = format!("{definitions:?}");
+  let mut result = String::with_capacity(text.len());
+  let mut parts = text.split("Generated(\"");
+  result.push_str(parts.next().unwrap_or_default());
+  for part in parts {
+    result.push_str("Generated(");
+    result.push_str(part.split_once('"').map(|(_, rest)| rest).unwrap_or_default());
+  }
+  result
+}
+
+/// Returns the number of rules in every decision table defined directly in decisions.
+fn rule_counts(definitions: &Definitions) -> Vec<usize> {
+  definitions
+    .drg_elements()
+    .filter_map(|drg_element| match drg_element {
+      DrgElement::Decision(decision) => match decision.decision_logic() {
+        Some(ExpressionInstance::DecisionTable(decision_table)) => Some(decision_table.rules().count()),
+        _ => None,
+      },
+      _ => None,
+    })
+    .collect()
+}
+
+/// Generates the model with two decision tables having specified number of rules,
+/// model elements are prefixed, rules are separated with comments.
+fn generate_model(rule_count: usize) -> String {
+  let mut model = String::new();
+  model.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+  model.push_str(r#"<dmn:definitions xmlns:dmn="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="https://dmntk.io/low-memory" name="low memory" id="_low_memory">"#);
+  model.push_str(r#"<dmn:inputData name="Code" id="_code"><dmn:variable name="Code" typeRef="string"/></dmn:inputData>"#);
+  for table in 1..=2 {
+    model.push_str(&format!(
+      r#"<dmn:decision name="Table {table}" id="_table_{table}"><dmn:variable name="Table {table}" typeRef="string"/>"#
+    ));
+    model.push_str(r##"<dmn:informationRequirement><dmn:requiredInput href="#_code"/></dmn:informationRequirement>"##);
+    model.push_str(
+      r#"<dmn:decisionTable hitPolicy="FIRST"><dmn:input><dmn:inputExpression typeRef="string"><dmn:text>Code</dmn:text></dmn:inputExpression></dmn:input><dmn:output/>"#,
+    );
+    for i in 0..rule_count {
+      model.push_str(&format!(
+        r#"<!-- rule {i} --><dmn:rule><dmn:inputEntry><dmn:text>"C{i}"</dmn:text></dmn:inputEntry><dmn:outputEntry><dmn:text>"T{table}R{i}"</dmn:text></dmn:outputEntry></dmn:rule>"#
+      ));
+    }
+    model.push_str("</dmn:decisionTable></dmn:decision>");
+  }
+  model.push_str("</dmn:definitions>");
+  model
+}
+
+#[test]
+fn _0001() {
+  for xml in [DMN_FULL, DMN_2_0001, DMN_3_0004, DMN_3_0008, DMN_3_0014, DMN_3_0017, DMN_3_0020, DMN_3_0035] {
+    assert_eq!(normalized(&parse(xml).unwrap()), normalized(&parse_low_memory(xml).unwrap()));
+  }
+}
+
+#[test]
+fn _0002() {
+  let xml = generate_model(2500);
+  let definitions = parse_low_memory(&xml).unwrap();
+  assert_eq!(vec![2500, 2500], rule_counts(&definitions));
+  assert_eq!(normalized(&parse(&xml).unwrap()), normalized(&definitions));
+}
+
+#[test]
+fn _0003() {
+  // malformed documents report the same error as in regular mode
+  let xml = r#"<definitions namespace="https://dmntk.io" name="malformed"><decision name="A">"#;
+  assert_eq!(parse(xml).unwrap_err().to_string(), parse_low_memory(xml).unwrap_err().to_string());
+}
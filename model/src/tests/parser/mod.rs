@@ -1,5 +1,11 @@
 //! # Tests for DMN model parser
 
+mod annotations;
+mod constants;
+mod default_values;
 mod full_model;
-mod input_files;
+pub mod input_files;
 mod invalid_models;
+mod knowledge_sources;
+mod low_memory;
+mod optional_components;
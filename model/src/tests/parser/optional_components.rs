@@ -0,0 +1,38 @@
+use crate::parse;
+
+const MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<definitions namespace="https://dmntk.io/optional-components" name="optional-components" id="_optional_components" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <itemDefinition name="tPerson">
+        <itemComponent name="First name">
+            <typeRef>string</typeRef>
+        </itemComponent>
+        <itemComponent name="Middle name">
+            <extensionElements>
+                <optional/>
+            </extensionElements>
+            <typeRef>string</typeRef>
+        </itemComponent>
+        <itemComponent name="Nickname">
+            <extensionElements>
+                <optional>true</optional>
+            </extensionElements>
+            <typeRef>string</typeRef>
+        </itemComponent>
+        <itemComponent name="Last name">
+            <extensionElements>
+                <optional>false</optional>
+            </extensionElements>
+            <typeRef>string</typeRef>
+        </itemComponent>
+    </itemDefinition>
+</definitions>
+"#;
+
+#[test]
+fn _0001() {
+  let definitions = parse(MODEL).unwrap();
+  let item_definition = &definitions.item_definitions()[0];
+  assert!(!item_definition.is_optional());
+  let optional = item_definition.item_components().iter().map(|component| component.is_optional()).collect::<Vec<bool>>();
+  assert_eq!(vec![false, true, true, false], optional);
+}
@@ -0,0 +1,66 @@
+//! # Tests for SARIF reports
+
+use crate::tests::parser::input_files::{T_DMN_0002, T_DMN_0019};
+use crate::{lint, parse, validate_schema, SarifReport, LINT_MERGEABLE_RULES, RULE_INVALID_MODEL, RULE_SCHEMA_VIOLATION};
+use dmntk_common::Jsonify;
+use serde_json::Value;
+
+/// Returns the JSON document of the report.
+fn document(report: &SarifReport) -> Value {
+  serde_json::from_str(&report.jsonify()).unwrap()
+}
+
+#[test]
+fn _0001() {
+  let report = SarifReport::new();
+  assert!(report.is_empty());
+  let document = document(&report);
+  assert_eq!("2.1.0", document["version"]);
+  assert_eq!("dmntk", document["runs"][0]["tool"]["driver"]["name"]);
+  assert_eq!(6, document["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap().len());
+  assert!(document["runs"][0]["results"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(T_DMN_0019).unwrap();
+  let mut report = SarifReport::new();
+  report.add_lint_warnings("models/t_0019.dmn", &lint(&definitions));
+  assert_eq!(4, report.len());
+  assert!(!report.has_errors());
+  let result = &document(&report)["runs"][0]["results"][1];
+  assert_eq!(LINT_MERGEABLE_RULES, result["ruleId"]);
+  assert_eq!("warning", result["level"]);
+  assert_eq!(
+    r#"rules 2, 3 of decision table in decision `Age group` can be merged into rule: >= 18 | "yes" => "adult""#,
+    result["message"]["text"]
+  );
+  let location = &result["locations"][0];
+  assert_eq!("models/t_0019.dmn", location["physicalLocation"]["artifactLocation"]["uri"]);
+  assert_eq!(21, location["physicalLocation"]["region"]["startLine"]);
+  assert_eq!(9, location["physicalLocation"]["region"]["startColumn"]);
+  assert!(location["physicalLocation"]["region"]["endLine"].as_u64().unwrap() > 21);
+  assert_eq!("_age_group_table", location["logicalLocations"][0]["name"]);
+}
+
+#[test]
+fn _0003() {
+  let mut report = SarifReport::new();
+  report.add_schema_violation("t_0002.dmn", &validate_schema(T_DMN_0002).unwrap_err());
+  report.add_validation_error("invalid.dmn", &parse("<definitions/>").unwrap_err());
+  assert!(report.has_errors());
+  let document = document(&report);
+  let result = &document["runs"][0]["results"][0];
+  assert_eq!(RULE_SCHEMA_VIOLATION, result["ruleId"]);
+  assert_eq!("error", result["level"]);
+  assert_eq!(
+    "line 17, column 9, element /definitions/decision/decisionTable: invalid value `LAST` of attribute `hitPolicy`, expected one of: UNIQUE, FIRST, PRIORITY, ANY, COLLECT, RULE ORDER, OUTPUT ORDER",
+    result["message"]["text"]
+  );
+  assert_eq!(17, result["locations"][0]["physicalLocation"]["region"]["startLine"]);
+  assert_eq!(9, result["locations"][0]["physicalLocation"]["region"]["startColumn"]);
+  let result = &document["runs"][0]["results"][1];
+  assert_eq!(RULE_INVALID_MODEL, result["ruleId"]);
+  assert_eq!("invalid.dmn", result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]);
+  assert!(result["locations"][0]["physicalLocation"]["region"].is_null());
+}
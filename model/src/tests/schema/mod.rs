@@ -0,0 +1,141 @@
+//! # Tests for validating DMN models against schema
+
+use crate::tests::parser::input_files::{T_DMN_0002, T_DMN_0008};
+use crate::{schema_violations, validate_schema, DmnVersion};
+use dmntk_examples::{DMN_3_0014, DMN_3_0085, DMN_3_0086, DMN_3_0086_IMPORT};
+
+/// Returns the model with specified namespace of the schema and specified content.
+fn model(namespace: &str, content: &str) -> String {
+  format!(
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<definitions xmlns="{namespace}" namespace="https://dmntk.io/schema" name="schema">
+{content}
+</definitions>"#
+  )
+}
+
+/// Conditional expression introduced in DMN 1.4.
+const CONDITIONAL: &str = r#"  <decision name="Result">
+    <variable name="Result"/>
+    <conditional>
+      <if><literalExpression><text>true</text></literalExpression></if>
+      <then><literalExpression><text>1</text></literalExpression></then>
+      <else><literalExpression><text>2</text></literalExpression></else>
+    </conditional>
+  </decision>"#;
+
+#[test]
+fn _0001() {
+  for xml in [DMN_3_0014, DMN_3_0085, DMN_3_0086, DMN_3_0086_IMPORT] {
+    assert_eq!(DmnVersion::V1_3, validate_schema(xml).unwrap());
+  }
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    "<ModelSchemaError> line 17, column 9, element /definitions/decision/decisionTable: invalid value `LAST` of attribute `hitPolicy`, expected one of: UNIQUE, FIRST, PRIORITY, ANY, COLLECT, RULE ORDER, OUTPUT ORDER",
+    validate_schema(T_DMN_0002).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    "<ModelSchemaError> line 2, column 1, element /definition: expected element `definitions`, actual `definition`",
+    validate_schema(T_DMN_0008).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  // conditional expression is not allowed in DMN 1.3
+  assert_eq!(
+    "<ModelSchemaError> line 5, column 5, element /definitions/decision/conditional: unexpected element `conditional` in `decision`",
+    validate_schema(&model("https://www.omg.org/spec/DMN/20191111/MODEL/", CONDITIONAL))
+      .unwrap_err()
+      .to_string()
+  );
+  assert_eq!(
+    DmnVersion::V1_4,
+    validate_schema(&model("https://www.omg.org/spec/DMN/20211108/MODEL/", CONDITIONAL)).unwrap()
+  );
+  assert_eq!(
+    DmnVersion::V1_5,
+    validate_schema(&model("https://www.omg.org/spec/DMN/20230324/MODEL/", CONDITIONAL)).unwrap()
+  );
+}
+
+#[test]
+fn _0005() {
+  let content = r#"  <inputData name="A"><variable name="A"/></inputData>
+  <inputData name="B"><variable typeRef="number"/></inputData>"#;
+  assert_eq!(
+    "<ModelSchemaError> line 4, column 23, element /definitions/inputData[2]/variable: missing required attribute `name`",
+    validate_schema(&model("https://www.omg.org/spec/DMN/20191111/MODEL/", content)).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0006() {
+  let content = r#"  <decision name="A">
+    <variable name="A"/>
+    <literalExpression><text><b>1</b></text></literalExpression>
+  </decision>"#;
+  assert_eq!(
+    "<ModelSchemaError> line 5, column 30, element /definitions/decision/literalExpression/text/b: element `text` may contain only text",
+    validate_schema(&model("https://www.omg.org/spec/DMN/20191111/MODEL/", content)).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_eq!(
+    "<ModelSchemaError> line 2, column 1, element /definitions: unsupported DMN namespace `https://www.omg.org/spec/DMN/20151101/dmn.xsd`",
+    validate_schema(&model("https://www.omg.org/spec/DMN/20151101/dmn.xsd", "")).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0008() {
+  // elements from other namespaces and extension elements are not validated
+  let content = r#"  <extensionElements><anything/></extensionElements>
+  <inputData name="A"><variable name="A"/></inputData>
+  <dmndi:DMNDI xmlns:dmndi="https://www.omg.org/spec/DMN/20191111/DMNDI/"><dmndi:DMNDiagram/></dmndi:DMNDI>"#;
+  assert!(validate_schema(&model("https://www.omg.org/spec/DMN/20191111/MODEL/", content)).is_ok());
+}
+
+#[test]
+fn _0009() {
+  // all violations are reported, content of not allowed elements is not validated
+  let content = r#"  <inputData><variable typeRef="number"/></inputData>
+  <decision name="A" hitPolicy="LAST">
+    <variable name="A"/>
+    <decisionTable hitPolicy="LAST" aggregation="AVG"><rule><unknown/></rule></decisionTable>
+  </decision>
+  <unknown><inputData/></unknown>"#;
+  assert_eq!(
+    vec![
+      "<ModelSchemaError> line 3, column 3, element /definitions/inputData: missing required attribute `name`",
+      "<ModelSchemaError> line 3, column 14, element /definitions/inputData/variable: missing required attribute `name`",
+      "<ModelSchemaError> line 6, column 5, element /definitions/decision/decisionTable: invalid value `LAST` of attribute `hitPolicy`, expected one of: UNIQUE, FIRST, PRIORITY, ANY, COLLECT, RULE ORDER, OUTPUT ORDER",
+      "<ModelSchemaError> line 6, column 5, element /definitions/decision/decisionTable: invalid value `AVG` of attribute `aggregation`, expected one of: SUM, COUNT, MIN, MAX",
+      "<ModelSchemaError> line 6, column 61, element /definitions/decision/decisionTable/rule/unknown: unexpected element `unknown` in `rule`",
+      "<ModelSchemaError> line 8, column 3, element /definitions/unknown: unexpected element `unknown` in `definitions`",
+    ],
+    schema_violations(&model("https://www.omg.org/spec/DMN/20191111/MODEL/", content))
+      .iter()
+      .map(ToString::to_string)
+      .collect::<Vec<String>>()
+  );
+}
+
+#[test]
+fn _0010() {
+  // valid documents have no violations, documents with not supported schema have a single violation
+  for xml in [DMN_3_0014, DMN_3_0085, DMN_3_0086, DMN_3_0086_IMPORT] {
+    assert!(schema_violations(xml).is_empty());
+  }
+  assert_eq!(1, schema_violations(T_DMN_0008).len());
+  assert_eq!(1, schema_violations("<definitions").len());
+}
@@ -0,0 +1,85 @@
+//! # Tests for listing variables in scope
+
+use crate::tests::parser::input_files::T_DMN_0022;
+use crate::{parse, scope_variables, ScopeVariableKind};
+
+/// Returns formatted variables in scope of the expression with specified identifier.
+fn variables(id: &str) -> Vec<String> {
+  let definitions = parse(T_DMN_0022).unwrap();
+  scope_variables(&definitions, id).unwrap().iter().map(|variable| variable.to_string()).collect()
+}
+
+#[test]
+fn _0001() {
+  let expected = vec![
+    "Score: number (decision)",
+    "Applicant: tApplicant (input data)",
+    "Rate: number (input data)",
+    "Installment: function<number, number>->number (business knowledge model)",
+    "Scoring: function<tApplicant>->number (decision service)",
+  ];
+  assert_eq!(expected, variables("_offer"));
+  assert_eq!(expected, variables("_offer_context"));
+  assert_eq!(expected, variables("_amount_variable"));
+  assert_eq!(expected, variables("_amount_expression"));
+}
+
+#[test]
+fn _0002() {
+  // context entries see preceding entries, the result entry sees all of them
+  assert_eq!(
+    vec![
+      "Score: number (decision)",
+      "Applicant: tApplicant (input data)",
+      "Rate: number (input data)",
+      "Installment: function<number, number>->number (business knowledge model)",
+      "Scoring: function<tApplicant>->number (decision service)",
+      "Amount: number (context entry)",
+    ],
+    variables("_monthly_expression")
+  );
+  assert_eq!(
+    vec![
+      "Score: number (decision)",
+      "Applicant: tApplicant (input data)",
+      "Rate: number (input data)",
+      "Installment: function<number, number>->number (business knowledge model)",
+      "Scoring: function<tApplicant>->number (decision service)",
+      "Amount: number (context entry)",
+      "Monthly: number (context entry)",
+      "Adjust: Any (context entry)",
+    ],
+    variables("_offer_result")
+  );
+}
+
+#[test]
+fn _0003() {
+  // parameters of the function definition shadow variables with the same names
+  let definitions = parse(T_DMN_0022).unwrap();
+  let variables = scope_variables(&definitions, "_adjust_expression").unwrap();
+  let rate = variables.iter().find(|variable| variable.name() == "Rate").unwrap();
+  assert_eq!("string", rate.type_ref());
+  assert_eq!(ScopeVariableKind::Parameter, rate.kind());
+  assert_eq!(1, variables.iter().filter(|variable| variable.name() == "Rate").count());
+  assert_eq!(
+    vec!["Score", "Applicant", "Installment", "Scoring", "Amount", "Monthly", "Rate", "Factor"],
+    variables.iter().map(|variable| variable.name()).collect::<Vec<&str>>()
+  );
+}
+
+#[test]
+fn _0004() {
+  // business knowledge models see only their parameters, decisions see only their requirements
+  assert_eq!(vec!["Amount: number (parameter)", "Rate: number (parameter)"], variables("_installment_expression"));
+  assert_eq!(vec!["Applicant: tApplicant (input data)"], variables("_score_expression"));
+}
+
+#[test]
+fn _0005() {
+  let definitions = parse(T_DMN_0022).unwrap();
+  assert_eq!(
+    "<ModelError> element with identifier or name '_unknown' not found",
+    scope_variables(&definitions, "_unknown").unwrap_err().to_string()
+  );
+}
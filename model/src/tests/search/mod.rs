@@ -0,0 +1,63 @@
+//! # Tests for searching DRG elements
+
+use crate::model::*;
+use crate::tests::parser::input_files::T_DMN_0017;
+use crate::{parse, search, SearchField};
+use dmntk_examples::DMN_3_0087;
+
+#[test]
+fn _0001() {
+  let definitions = [parse(T_DMN_0017).unwrap()];
+  let results = search(&definitions, "Amount");
+  assert_eq!(1, results.len());
+  assert_eq!("Amount", results[0].drg_element().name());
+  assert_eq!("input data", results[0].drg_element().kind());
+  assert_eq!(SearchField::Name, results[0].field());
+  assert_eq!(1.0, results[0].score());
+  assert_eq!("https://dmntk.io", results[0].definitions().namespace());
+}
+
+#[test]
+fn _0002() {
+  let definitions = [parse(T_DMN_0017).unwrap()];
+  let results = search(&definitions, "_ks_policy");
+  assert_eq!("Credit policy", results[0].drg_element().name());
+  assert_eq!(SearchField::Id, results[0].field());
+}
+
+#[test]
+fn _0003() {
+  let definitions = [parse(DMN_3_0087).unwrap()];
+  let names = search(&definitions, "risk")
+    .iter()
+    .map(|result| result.drg_element().name().to_string())
+    .collect::<Vec<String>>();
+  assert_eq!("Risk manager", names[0]);
+  assert!(names.contains(&"Application risk score".to_string()));
+  assert!(names.contains(&"Post-bureau risk category".to_string()));
+}
+
+#[test]
+fn _0004() {
+  let definitions = [parse(DMN_3_0087).unwrap()];
+  // misspelled name is still found
+  let results = search(&definitions, "Adjudicaton");
+  assert_eq!("Adjudication", results[0].drg_element().name());
+  assert!(results[0].score() < 0.5);
+}
+
+#[test]
+fn _0005() {
+  let definitions = [parse(T_DMN_0017).unwrap(), parse(DMN_3_0087).unwrap()];
+  assert!(search(&definitions, "  ").is_empty());
+  assert!(search(&definitions, "qqqqqqqq").is_empty());
+  let results = search(&definitions, "credit");
+  assert_eq!(
+    2,
+    results
+      .iter()
+      .map(|result| result.definitions().namespace())
+      .collect::<std::collections::HashSet<&str>>()
+      .len()
+  );
+}
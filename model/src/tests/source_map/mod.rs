@@ -0,0 +1,95 @@
+//! # Tests for source map of DMN models
+
+use crate::{parse, parse_low_memory, Definitions, DmnElement};
+use dmntk_examples::DMN_3_0014;
+
+const MODEL: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" namespace="https://dmntk.io/source-map" name="source map" id="_source_map">
+  <itemDefinition name="tÄxÖs" id="_taxes">
+    <itemComponent name="rate" id="_rate"><typeRef>number</typeRef></itemComponent>
+  </itemDefinition>
+  <!-- ÄÖÜ --><inputData name="Income" id="_income"><variable name="Income" typeRef="number"/></inputData>
+  <decision name="Tax" id="_tax">
+    <variable name="Tax"/>
+    <informationRequirement><requiredInput href="#_income"/></informationRequirement>
+    <decisionTable id="_table">
+      <input id="_input"><inputExpression typeRef="number"><text>Income</text></inputExpression></input>
+      <output id="_output"/>
+      <rule id="_rule_1"><inputEntry><text>&lt;1000</text></inputEntry><outputEntry><text>0</text></outputEntry></rule>
+      <rule id="_rule_2"><inputEntry><text>&gt;=1000</text></inputEntry><outputEntry><text>Income*0.2</text></outputEntry></rule>
+      <annotation name="Comment"/>
+    </decisionTable>
+  </decision>
+</definitions>
+"##;
+
+/// Returns the text of the element with specified identifier in the original document.
+fn text<'a>(xml: &'a str, definitions: &Definitions, id: &str) -> &'a str {
+  &xml[definitions.source_span(id).unwrap().range()]
+}
+
+#[test]
+fn _0001() {
+  let definitions = parse(MODEL).unwrap();
+  let span = definitions.source_span("_tax").unwrap();
+  assert_eq!(7, span.start().line());
+  assert_eq!(3, span.start().column());
+  assert_eq!(17, span.end().line());
+  assert_eq!(14, span.end().column());
+  assert!(text(MODEL, &definitions, "_tax").starts_with(r#"<decision name="Tax" id="_tax">"#));
+  assert!(text(MODEL, &definitions, "_tax").ends_with("</decision>"));
+  assert_eq!("line 7, column 3", span.start().to_string());
+}
+
+#[test]
+fn _0002() {
+  // columns are counted in characters, not in bytes
+  let definitions = parse(MODEL).unwrap();
+  let span = definitions.source_span("_income").unwrap();
+  assert_eq!(6, span.start().line());
+  assert_eq!(15, span.start().column());
+  assert_eq!(
+    r#"<itemComponent name="rate" id="_rate"><typeRef>number</typeRef></itemComponent>"#,
+    text(MODEL, &definitions, "_rate")
+  );
+}
+
+#[test]
+fn _0003() {
+  // the innermost element containing the offset is found
+  let definitions = parse(MODEL).unwrap();
+  let source_map = definitions.source_map();
+  let offset = MODEL.find("<text>Income</text>").unwrap();
+  // input clauses are not identified in the model, so the decision table is the innermost element
+  assert_eq!(Some("_table"), source_map.element_at(offset));
+  let offset = MODEL.find("<variable name=\"Tax\"/>").unwrap();
+  assert_eq!(Some(definitions.decisions()[0].variable().id().as_str()), source_map.element_at(offset));
+  assert_eq!(None, source_map.element_at(0));
+}
+
+#[test]
+fn _0004() {
+  // elements without identifiers are mapped using generated identifiers
+  let definitions = parse(DMN_3_0014).unwrap();
+  for decision in definitions.decisions() {
+    assert!(decision.opt_id().is_some());
+    let variable = decision.variable();
+    assert!(variable.opt_id().is_none());
+    let span = definitions.source_span(variable.id()).unwrap();
+    assert!(DMN_3_0014[span.range()].starts_with("<variable"));
+    assert!(definitions.source_span(decision.id()).unwrap().range().contains(&span.range().start));
+  }
+  assert_eq!(None, definitions.source_span("_unknown"));
+}
+
+#[test]
+fn _0005() {
+  // low-memory mode maps elements to the same ranges of the original document
+  let regular = parse(MODEL).unwrap();
+  let low_memory = parse_low_memory(MODEL).unwrap();
+  for id in ["_source_map", "_taxes", "_rate", "_income", "_tax", "_table"] {
+    assert_eq!(regular.source_span(id), low_memory.source_span(id));
+  }
+  assert_eq!(regular.source_map().len(), low_memory.source_map().len());
+  assert!(text(MODEL, &low_memory, "_table").ends_with("</decisionTable>"));
+}
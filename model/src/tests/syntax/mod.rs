@@ -0,0 +1,77 @@
+//! # Tests for incremental syntax checking
+
+use crate::tests::parser::input_files::T_DMN_0020;
+use crate::{parse, SyntaxChecker};
+
+#[test]
+fn _0001() {
+  // valid model produces no diagnostics
+  let definitions = parse(T_DMN_0020).unwrap();
+  let mut checker = SyntaxChecker::new();
+  assert!(checker.check(&definitions).is_empty());
+}
+
+#[test]
+fn _0002() {
+  let definitions = parse(&T_DMN_0020.replace("0.05 else 0", "0.05 esle 0")).unwrap();
+  let diagnostics = SyntaxChecker::new().check(&definitions);
+  assert_eq!(1, diagnostics.len());
+  assert_eq!("_discount_expression", diagnostics[0].element_id());
+  assert_eq!("literal expression in decision `Discount`", diagnostics[0].location());
+  assert_eq!(Some("`else`"), diagnostics[0].error().suggestion());
+  assert_eq!(
+    "line 29, column 9 literal expression in decision `Discount`: 64..68: unexpected `esle`, did you mean `else`?",
+    diagnostics[0].to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  let definitions = parse(&T_DMN_0020.replace(r#"<text>"Government"</text>"#, r#"<text>"Government</text>"#)).unwrap();
+  let diagnostics = SyntaxChecker::new().check(&definitions);
+  assert_eq!(1, diagnostics.len());
+  assert_eq!("_risk_table", diagnostics[0].element_id());
+  assert_eq!("input entry 2 of rule 3 of decision table in decision `Risk`", diagnostics[0].location());
+}
+
+#[test]
+fn _0004() {
+  // unchanged expressions are not parsed again
+  let definitions = parse(T_DMN_0020).unwrap();
+  let mut checker = SyntaxChecker::new();
+  checker.check(&definitions);
+  let parse_count = checker.parse_count();
+  assert!(parse_count > 0);
+  assert!(checker.check(&definitions).is_empty());
+  assert_eq!(parse_count, checker.parse_count());
+}
+
+#[test]
+fn _0005() {
+  // only the edited literal expression is parsed
+  let definitions = parse(T_DMN_0020).unwrap();
+  let mut checker = SyntaxChecker::new();
+  checker.check(&definitions);
+  let parse_count = checker.parse_count();
+  let diagnostics = checker.check_literal_expression(&definitions, "_seniors_expression", "Applicant Age in [100..65] or count([1, 2, 3][false]) >");
+  assert_eq!(1, diagnostics.len());
+  assert_eq!("literal expression in decision `Seniors`", diagnostics[0].location());
+  assert_eq!(parse_count + 1, checker.parse_count());
+  assert!(checker.check_literal_expression(&definitions, "_seniors_expression", "Applicant Age > 65").is_empty());
+  assert_eq!(parse_count + 2, checker.parse_count());
+  // names defined in the model are recognized when checking the edited expression alone
+  assert!(checker.check_literal_expression(&definitions, "_seniors_expression", "Applicant Age > 65").is_empty());
+  assert_eq!(parse_count + 2, checker.parse_count());
+}
+
+#[test]
+fn _0006() {
+  // changed names of the model invalidate cached results
+  let definitions = parse(T_DMN_0020).unwrap();
+  let mut checker = SyntaxChecker::new();
+  checker.check(&definitions);
+  let parse_count = checker.parse_count();
+  let definitions = parse(&T_DMN_0020.replace(r#"name="Customer Type""#, r#"name="Client Type""#)).unwrap();
+  checker.check(&definitions);
+  assert_eq!(2 * parse_count, checker.parse_count());
+}
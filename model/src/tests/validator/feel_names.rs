@@ -0,0 +1,46 @@
+//! # Test cases for names of model elements
+
+use super::input_files::*;
+use crate::{lint, parse, LINT_INVALID_NAME, LINT_SHADOWED_BUILT_IN_FUNCTION};
+
+#[test]
+fn _0001() {
+  // invalid names do not prevent parsing the model, they are reported by the linter
+  let warnings = lint(&parse(DMN_1006).unwrap());
+  assert_eq!(1, warnings.len());
+  assert_eq!(LINT_INVALID_NAME, warnings[0].code());
+  assert_eq!("_amount_eur", warnings[0].element_id());
+  assert_eq!(
+    "line 11, column 5 [invalid-name]: name of decision `Amount (EUR)` is not a valid FEEL name",
+    warnings[0].to_string()
+  );
+}
+
+#[test]
+fn _0002() {
+  let warnings = lint(&parse(DMN_1007).unwrap());
+  assert_eq!(1, warnings.len());
+  assert_eq!(
+    "line 15, column 9 [invalid-name]: name of item definition `2nd name` is not a valid FEEL name",
+    warnings[0].to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(
+    "<ModelValidatorError> variable name `Total  Amount` of decision `Total  Amount` (id: _total_amount_2, namespace: https://dmntk.io/feel-names, line 19, column 5) collides with variable name of decision `Total Amount` (id: _total_amount_1, namespace: https://dmntk.io/feel-names, line 12, column 5)",
+    parse(DMN_1008).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  let warnings = lint(&parse(DMN_1009).unwrap());
+  assert_eq!(1, warnings.len());
+  assert_eq!(LINT_SHADOWED_BUILT_IN_FUNCTION, warnings[0].code());
+  assert_eq!(
+    "line 11, column 5 [shadowed-built-in-function]: name of input data `sum` shadows built-in function",
+    warnings[0].to_string()
+  );
+}
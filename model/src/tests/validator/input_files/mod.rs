@@ -1,4 +1,12 @@
-//! # Test decision models for checking item definition cycles
+//! # Test decision models for validator
 
 pub const DMN_0001: &str = include_str!("0001.dmn");
 pub const DMN_1001: &str = include_str!("1001.dmn");
+pub const DMN_1002: &str = include_str!("1002.dmn");
+pub const DMN_1003: &str = include_str!("1003.dmn");
+pub const DMN_1004: &str = include_str!("1004.dmn");
+pub const DMN_1005: &str = include_str!("1005.dmn");
+pub const DMN_1006: &str = include_str!("1006.dmn");
+pub const DMN_1007: &str = include_str!("1007.dmn");
+pub const DMN_1008: &str = include_str!("1008.dmn");
+pub const DMN_1009: &str = include_str!("1009.dmn");
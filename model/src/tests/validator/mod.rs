@@ -1,2 +1,4 @@
+mod feel_names;
 pub mod input_files;
 mod item_definition_cycles;
+mod requirement_cycles;
@@ -0,0 +1,37 @@
+//! # Test cases for cyclic dependencies between decision requirements
+
+use super::input_files::*;
+use crate::{parse, validate_requirements};
+use dmntk_examples::{DMN_3_0085, DMN_3_0086, DMN_3_0086_IMPORT};
+
+#[test]
+fn _0001() {
+  assert_eq!(
+    "<ModelValidatorError> cyclic dependency between decision requirements: `Decision A` (https://dmntk.io/requirement-cycles) -> `Decision B` (https://dmntk.io/requirement-cycles) -> `Decision C` (https://dmntk.io/requirement-cycles) -> `Decision A` (https://dmntk.io/requirement-cycles)",
+    parse(DMN_1002).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(
+    "<ModelValidatorError> cyclic dependency between decision requirements: `Decision A` (https://dmntk.io/requirement-cycles) -> `Decision A` (https://dmntk.io/requirement-cycles)",
+    parse(DMN_1003).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  // each model is valid on its own, the cycle spans both models
+  let definitions = [parse(DMN_1004).unwrap(), parse(DMN_1005).unwrap()];
+  assert_eq!(
+    "<ModelValidatorError> cyclic dependency between decision requirements: `Greeting` (https://dmntk.io/requirement-cycles/main) -> `Greeting Service` (https://dmntk.io/requirement-cycles/main) -> `Say Hello` (https://dmntk.io/requirement-cycles/imported) -> `Greeting` (https://dmntk.io/requirement-cycles/main)",
+    validate_requirements(&definitions).err().unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  assert!(validate_requirements(&[parse(DMN_3_0085).unwrap()]).is_ok());
+  assert!(validate_requirements(&[parse(DMN_3_0086).unwrap(), parse(DMN_3_0086_IMPORT).unwrap()]).is_ok());
+}
@@ -3,18 +3,30 @@
 //! Validations at the single decision model level:
 //!
 //! - Cycles in item definitions.
+//! - Cycles in decision requirements.
+//! - Colliding variable names of decisions.
+//!
+//! Names that are not valid FEEL names and names of input data shadowing built-in functions
+//! do not prevent evaluating the model, they are reported as warnings by the [linter](crate::lint).
+//!
+//! Validations across decision models importing each other:
+//!
+//! - Cycles in decision requirements.
 //!
 //! TO-DO:
 //!
 //! - Go through the spec and add all required cycle checks on single model level.
 //!
 
-use crate::errors::err_item_definitions_cycle;
-use crate::{Definitions, ItemDefinition, NamedElement};
+use crate::drg::{decision_requirements_graph, DrgEdge, DrgNode};
+use crate::errors::*;
+use crate::{Definitions, DrgElement, ItemDefinition, NamedElement};
 use dmntk_common::Result;
-use petgraph::algo::is_cyclic_directed;
+use dmntk_feel::Name;
+use petgraph::algo::{is_cyclic_directed, tarjan_scc};
 use petgraph::graph::{DiGraph, NodeIndex};
-use std::collections::HashMap;
+use petgraph::visit::{EdgeFiltered, IntoNeighbors};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Validates the decision model.
 pub fn validate(definitions: Definitions) -> Result<Definitions> {
@@ -22,6 +34,71 @@ pub fn validate(definitions: Definitions) -> Result<Definitions> {
   model_validator.validate(definitions)
 }
 
+/// Validates decision requirements between elements of all specified decision models.
+///
+/// Information requirements, knowledge requirements, output decisions and encapsulated
+/// decisions of decision services must not form a cycle, also when the cycle spans
+/// imported models. The reported error contains the path of the first found cycle,
+/// with the names and namespaces of all elements in this cycle.
+pub fn validate_requirements(definitions: &[Definitions]) -> Result<()> {
+  let graph = decision_requirements_graph(definitions);
+  let requirements = EdgeFiltered::from_fn(&graph, |edge| {
+    matches!(
+      edge.weight(),
+      DrgEdge::InformationRequirement | DrgEdge::KnowledgeRequirement | DrgEdge::OutputDecision | DrgEdge::EncapsulatedDecision
+    )
+  });
+  for component in tarjan_scc(&requirements) {
+    // the cycle path starts at the element defined first
+    let start = component.iter().copied().min().unwrap_or_default();
+    let is_cyclic = component.len() > 1 || (&requirements).neighbors(start).any(|node_index| node_index == start);
+    if is_cyclic {
+      let nodes = component.iter().copied().collect::<HashSet<NodeIndex>>();
+      let path = cycle_path(&requirements, &nodes, start)
+        .iter()
+        .map(|node_index| display_node(&graph[*node_index]))
+        .collect::<Vec<String>>()
+        .join(" -> ");
+      return Err(err_requirements_cycle(&path));
+    }
+  }
+  Ok(())
+}
+
+/// Returns the shortest path from the start node back to the start node,
+/// visiting only nodes from the strongly connected component.
+fn cycle_path<G>(graph: G, nodes: &HashSet<NodeIndex>, start: NodeIndex) -> Vec<NodeIndex>
+where
+  G: IntoNeighbors<NodeId = NodeIndex>,
+{
+  let mut predecessors: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+  let mut queue = VecDeque::from([start]);
+  while let Some(node_index) = queue.pop_front() {
+    for neighbor in graph.neighbors(node_index) {
+      if neighbor == start {
+        let mut path = vec![start, node_index];
+        let mut current = node_index;
+        while let Some(predecessor) = predecessors.get(&current) {
+          path.push(*predecessor);
+          current = *predecessor;
+        }
+        path.reverse();
+        return path;
+      }
+      if nodes.contains(&neighbor) && !predecessors.contains_key(&neighbor) {
+        predecessors.insert(neighbor, node_index);
+        queue.push_back(neighbor);
+      }
+    }
+  }
+  vec![start]
+}
+
+/// Returns the name and namespace of the node, as displayed in cycle path.
+fn display_node(node: &DrgNode) -> String {
+  format!("`{}` ({})", node.name(), node.namespace())
+}
+
 /// Decision model validator.
 pub struct ModelValidator {
   /// Directed graph for modelling item definition type references.
@@ -41,6 +118,8 @@ impl ModelValidator {
   /// Validated the decision model.
   fn validate(&mut self, definitions: Definitions) -> Result<Definitions> {
     self.check_recursive_item_definitions(&definitions)?;
+    check_variable_names(&definitions)?;
+    validate_requirements(std::slice::from_ref(&definitions))?;
     Ok(definitions)
   }
 
@@ -79,3 +158,33 @@ impl ModelValidator {
     }
   }
 }
+
+/// Checks if variables of decisions defined in the same model have unique names,
+/// otherwise results of some decisions could not be referenced in expressions.
+fn check_variable_names(definitions: &Definitions) -> Result<()> {
+  let mut variable_names: HashMap<&Name, &DrgElement> = HashMap::new();
+  for drg_element in definitions.drg_elements() {
+    if let DrgElement::Decision(decision) = drg_element {
+      let variable = decision.variable();
+      if let Some(other) = variable_names.insert(variable.feel_name(), drg_element) {
+        return Err(err_variable_name_collision(
+          variable.name(),
+          &location(definitions, drg_element.kind(), drg_element),
+          &location(definitions, other.kind(), other),
+        ));
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Returns the location of the element reported in errors:
+/// the kind, the name, optional identifier, the namespace
+/// and the position of the element in the original document.
+fn location(definitions: &Definitions, kind: &str, element: &dyn NamedElement) -> String {
+  let position = definitions.source_span(element.id()).map(|span| format!(", {}", span.start())).unwrap_or_default();
+  match element.opt_id() {
+    Some(id) => format!("{kind} `{}` (id: {id}, namespace: {}{position})", element.name(), element.namespace()),
+    None => format!("{kind} `{}` (namespace: {}{position})", element.name(), element.namespace()),
+  }
+}
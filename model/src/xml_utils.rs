@@ -16,12 +16,12 @@ pub fn required_attribute(node: &Node, attr_name: &str) -> Result<String> {
 
 /// Returns the value of the mandatory color attribute.
 pub fn required_color_part(node: &Node, attr_name: &str) -> Result<u8> {
-  u8::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_color_value(&e.to_string()))
+  u8::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_color_value(e.to_string()))
 }
 
 /// Returns the value of the mandatory double value.
 pub fn required_double(node: &Node, attr_name: &str) -> Result<f64> {
-  f64::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_double_value(&e.to_string()))
+  f64::from_str(&required_attribute(node, attr_name)?).map_err(|e| err_invalid_double_value(e.to_string()))
 }
 
 /// Returns the value of the optional attribute.
@@ -0,0 +1,118 @@
+//! # Node.js bindings for **DMNTK**
+//!
+//! Exposes loading of decision models and evaluation of invocables to Node.js.
+//! Input data are passed as JavaScript objects and converted to FEEL contexts
+//! like input data in JSON format, results are returned as JavaScript values.
+//! Parsing and evaluation errors are thrown as JavaScript errors.
+//!
+//! ```javascript
+//! const { ModelEvaluator, evaluateFeel } = require('dmntk');
+//!
+//! const evaluator = ModelEvaluator.fromFiles(['compliance.dmn']);
+//! const result = evaluator.evaluate('Loan approval', { Applicant: { Age: 42 } });
+//! const total = evaluateFeel('a + b', { a: 1, b: 2 });
+//! ```
+
+#[macro_use]
+extern crate napi_derive;
+
+use dmntk_common::Jsonify;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::FeelScope;
+use dmntk_model::Definitions;
+use napi::{Error, Result};
+use std::fs;
+use std::sync::Arc;
+
+/// Evaluator of decision models.
+#[napi]
+pub struct ModelEvaluator {
+  /// Evaluator of loaded decision models.
+  model_evaluator: Arc<dmntk_evaluator::ModelEvaluator>,
+  /// Namespace of the first loaded model, the default namespace of evaluated invocables.
+  namespace: String,
+}
+
+#[napi]
+impl ModelEvaluator {
+  /// Creates an evaluator of decision models given as XML documents.
+  #[napi(constructor)]
+  pub fn new(models: Vec<String>) -> Result<Self> {
+    let definitions = models
+      .iter()
+      .map(|model| dmntk_model::parse(model).map_err(to_js_err))
+      .collect::<Result<Vec<Definitions>>>()?;
+    Self::build(definitions)
+  }
+
+  /// Creates an evaluator of decision models loaded from files.
+  #[napi(factory)]
+  pub fn from_files(file_names: Vec<String>) -> Result<Self> {
+    let definitions = file_names
+      .iter()
+      .map(|file_name| {
+        let model = fs::read_to_string(file_name).map_err(|reason| Error::from_reason(format!("loading model file '{file_name}' failed with reason: {reason}")))?;
+        dmntk_model::parse(&model).map_err(to_js_err)
+      })
+      .collect::<Result<Vec<Definitions>>>()?;
+    Self::build(definitions)
+  }
+
+  /// Returns the namespace of the first loaded model.
+  #[napi(getter)]
+  pub fn namespace(&self) -> String {
+    self.namespace.clone()
+  }
+
+  /// Evaluates the invocable (decision, business knowledge model or decision service)
+  /// with input data given as an object, returns the result of the evaluation.
+  #[napi]
+  pub fn evaluate(&self, invocable_name: String, input_data: Option<serde_json::Value>, namespace: Option<String>) -> Result<serde_json::Value> {
+    let namespace = namespace.as_deref().unwrap_or(&self.namespace);
+    let input_data = context_from_object(input_data)?;
+    let (coerced_input_data, _) = self.model_evaluator.coerce_json_input(namespace, &input_data);
+    let result = self.model_evaluator.evaluate_invocable(namespace, &invocable_name, &coerced_input_data);
+    value_to_js(&result)
+  }
+}
+
+impl ModelEvaluator {
+  /// Builds the evaluator of parsed decision models.
+  fn build(definitions: Vec<Definitions>) -> Result<Self> {
+    let namespace = definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default();
+    let model_evaluator = dmntk_evaluator::ModelEvaluator::new(&definitions).map_err(to_js_err)?;
+    Ok(Self { model_evaluator, namespace })
+  }
+}
+
+/// Evaluates the FEEL expression in the context given as an object.
+#[napi]
+pub fn evaluate_feel(expression: String, context: Option<serde_json::Value>) -> Result<serde_json::Value> {
+  let scope: FeelScope = context_from_object(context)?.into();
+  let node = dmntk_feel_parser::parse_expression(&scope, &expression, false).map_err(to_js_err)?;
+  let result = dmntk_evaluator::evaluate(&scope, &node).map_err(to_js_err)?;
+  value_to_js(&result)
+}
+
+/// Converts the object into FEEL context, the same way as input data in JSON format.
+fn context_from_object(object: Option<serde_json::Value>) -> Result<FeelContext> {
+  match object {
+    Some(object) => dmntk_feel::json::context_from_json(&object.to_string()).map_err(to_js_err),
+    None => Ok(FeelContext::default()),
+  }
+}
+
+/// Converts the value into JavaScript value, through its JSON representation.
+/// Values being `null` with a reason are thrown as errors.
+fn value_to_js(value: &Value) -> Result<serde_json::Value> {
+  if let Value::Null(Some(reason)) = value {
+    return Err(Error::from_reason(reason.to_string()));
+  }
+  serde_json::from_str(&value.jsonify()).map_err(|reason| Error::from_reason(reason.to_string()))
+}
+
+/// Converts the error reported by **DMNTK** into JavaScript error.
+fn to_js_err(reason: dmntk_common::DmntkError) -> Error {
+  Error::from_reason(reason.to_string())
+}
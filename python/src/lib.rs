@@ -0,0 +1,137 @@
+//! # Python bindings for **DMNTK**
+//!
+//! Exposes evaluation of decision models and FEEL expressions to Python.
+//! Input data are passed as dictionaries and converted to FEEL contexts
+//! like input data in JSON format, results are returned as Python values.
+//! Parsing and evaluation errors are raised as `dmntk.DmntkError` exceptions.
+//!
+//! ```python
+//! import dmntk
+//!
+//! evaluator = dmntk.ModelEvaluator.from_files(["compliance.dmn"])
+//! result = evaluator.evaluate("Loan approval", {"Applicant": {"Age": 42}})
+//! total = dmntk.evaluate_feel("a + b", {"a": 1, "b": 2})
+//! ```
+
+use dmntk_common::Jsonify;
+use dmntk_evaluator::ModelEvaluator;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::FeelScope;
+use dmntk_model::Definitions;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::fs;
+use std::sync::Arc;
+
+create_exception!(
+  dmntk,
+  DmntkError,
+  PyException,
+  "Error raised when parsing or evaluating decision models or FEEL expressions fails."
+);
+
+/// Evaluator of decision models.
+#[pyclass(name = "ModelEvaluator", unsendable)]
+struct PyModelEvaluator {
+  /// Evaluator of loaded decision models.
+  model_evaluator: Arc<ModelEvaluator>,
+  /// Namespace of the first loaded model, the default namespace of evaluated invocables.
+  namespace: String,
+}
+
+#[pymethods]
+impl PyModelEvaluator {
+  /// Creates an evaluator of decision models given as XML documents.
+  #[new]
+  fn new(models: Vec<String>) -> PyResult<Self> {
+    let definitions = models
+      .iter()
+      .map(|model| dmntk_model::parse(model).map_err(to_py_err))
+      .collect::<PyResult<Vec<Definitions>>>()?;
+    Self::build(definitions)
+  }
+
+  /// Creates an evaluator of decision models loaded from files.
+  #[staticmethod]
+  fn from_files(file_names: Vec<String>) -> PyResult<Self> {
+    let definitions = file_names
+      .iter()
+      .map(|file_name| {
+        let model = fs::read_to_string(file_name).map_err(|reason| DmntkError::new_err(format!("loading model file '{file_name}' failed with reason: {reason}")))?;
+        dmntk_model::parse(&model).map_err(to_py_err)
+      })
+      .collect::<PyResult<Vec<Definitions>>>()?;
+    Self::build(definitions)
+  }
+
+  /// Returns the namespace of the first loaded model.
+  #[getter]
+  fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Evaluates the invocable (decision, business knowledge model or decision service)
+  /// with input data given as a dictionary, returns the result of the evaluation.
+  #[pyo3(signature = (invocable_name, input_data = None, namespace = None))]
+  fn evaluate(&self, py: Python<'_>, invocable_name: &str, input_data: Option<&Bound<'_, PyDict>>, namespace: Option<&str>) -> PyResult<PyObject> {
+    let namespace = namespace.unwrap_or(&self.namespace);
+    let input_data = context_from_dict(py, input_data)?;
+    let (coerced_input_data, _) = self.model_evaluator.coerce_json_input(namespace, &input_data);
+    let result = self.model_evaluator.evaluate_invocable(namespace, invocable_name, &coerced_input_data);
+    value_to_py(py, &result)
+  }
+}
+
+impl PyModelEvaluator {
+  /// Builds the evaluator of parsed decision models.
+  fn build(definitions: Vec<Definitions>) -> PyResult<Self> {
+    let namespace = definitions.first().map(|definitions| definitions.namespace().to_string()).unwrap_or_default();
+    let model_evaluator = ModelEvaluator::new(&definitions).map_err(to_py_err)?;
+    Ok(Self { model_evaluator, namespace })
+  }
+}
+
+/// Evaluates the FEEL expression in the context given as a dictionary.
+#[pyfunction]
+#[pyo3(signature = (expression, context = None))]
+fn evaluate_feel(py: Python<'_>, expression: &str, context: Option<&Bound<'_, PyDict>>) -> PyResult<PyObject> {
+  let scope: FeelScope = context_from_dict(py, context)?.into();
+  let node = dmntk_feel_parser::parse_expression(&scope, expression, false).map_err(to_py_err)?;
+  let result = dmntk_evaluator::evaluate(&scope, &node).map_err(to_py_err)?;
+  value_to_py(py, &result)
+}
+
+/// Converts the dictionary into FEEL context, the same way as input data in JSON format.
+fn context_from_dict(py: Python<'_>, dict: Option<&Bound<'_, PyDict>>) -> PyResult<FeelContext> {
+  let Some(dict) = dict else {
+    return Ok(FeelContext::default());
+  };
+  let json = py.import_bound("json")?.call_method1("dumps", (dict,))?.extract::<String>()?;
+  dmntk_feel::json::context_from_json(&json).map_err(to_py_err)
+}
+
+/// Converts the value into Python value, through its JSON representation.
+/// Values being `null` with a reason are raised as errors.
+fn value_to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+  if let Value::Null(Some(reason)) = value {
+    return Err(DmntkError::new_err(reason.to_string()));
+  }
+  Ok(py.import_bound("json")?.call_method1("loads", (value.jsonify(),))?.unbind())
+}
+
+/// Converts the error reported by **DMNTK** into Python exception.
+fn to_py_err(reason: dmntk_common::DmntkError) -> PyErr {
+  DmntkError::new_err(reason.to_string())
+}
+
+/// Python module `dmntk`.
+#[pymodule]
+fn dmntk(m: &Bound<'_, PyModule>) -> PyResult<()> {
+  m.add_class::<PyModelEvaluator>()?;
+  m.add_function(wrap_pyfunction!(evaluate_feel, m)?)?;
+  m.add("DmntkError", m.py().get_type_bound::<DmntkError>())?;
+  Ok(())
+}
@@ -152,6 +152,7 @@ pub fn recognize_decision_table(text: &str, trace: bool) -> Result<DecisionTable
   for i in 0..size.input_clauses_count {
     input_clauses.push(InputClause {
       input_expression: recognizer.input_expressions[i].clone(),
+      input_expression_type_ref: None,
       allowed_input_values: if size.input_values_count > 0 {
         recognizer.allowed_input_values[i].clone()
       } else {
@@ -212,6 +213,7 @@ pub fn recognize_decision_table(text: &str, trace: bool) -> Result<DecisionTable
       input_entries,
       output_entries,
       annotation_entries,
+      description: None,
     });
   }
 
@@ -0,0 +1,204 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use crate::point::Point;
+use crate::rect::Rect;
+use dmntk_common::{DmntkError, Language};
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_canvas_expected_characters_not_found(vec!['┌', '┐']),
+    "<RecognizerError> nie znaleziono oczekiwanych znaków: ['┌', '┐']",
+    "<RecognizerError> erwartete Zeichen nicht gefunden: ['┌', '┐']",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_canvas_character_is_not_allowed('x', vec!['─']),
+    "<RecognizerError> znak 'x' nie jest dozwolony w ['─']",
+    "<RecognizerError> Zeichen 'x' ist in ['─'] nicht zulässig",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_canvas_rectangle_not_closed(Point::new(1, 2), Point::new(3, 4)),
+    "<RecognizerError> prostokąt nie jest zamknięty, punkt początkowy: (1,2), punkt końcowy: (3,4)",
+    "<RecognizerError> Rechteck ist nicht geschlossen, Startpunkt: (1,2), Endpunkt: (3,4)",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_canvas_region_not_found(Rect::new(1, 2, 3, 4)),
+    "<RecognizerError> nie znaleziono obszaru, prostokąt: (1,2;3,4)",
+    "<RecognizerError> Bereich nicht gefunden, Rechteck: (1,2;3,4)",
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_translated(err_plane_is_empty(), "<RecognizerError> płaszczyzna jest pusta", "<RecognizerError> Ebene ist leer");
+}
+
+#[test]
+fn _0006() {
+  assert_translated(
+    err_plane_cell_is_not_region("row 1"),
+    "<RecognizerError> komórka nie należy do obszaru płaszczyzny: row 1",
+    "<RecognizerError> keine Bereichszelle in der Ebene: row 1",
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_translated(
+    err_plane_row_is_out_of_range(),
+    "<RecognizerError> wiersz płaszczyzny jest poza zakresem",
+    "<RecognizerError> Zeile der Ebene liegt außerhalb des Bereichs",
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_translated(
+    err_plane_no_main_double_crossing(),
+    "<RecognizerError> płaszczyzna nie ma głównego podwójnego skrzyżowania",
+    "<RecognizerError> Ebene hat keine doppelte Hauptkreuzung",
+  );
+}
+
+#[test]
+fn _0009() {
+  assert_translated(
+    err_plane_column_is_out_of_range(),
+    "<RecognizerError> kolumna płaszczyzny jest poza zakresem",
+    "<RecognizerError> Spalte der Ebene liegt außerhalb des Bereichs",
+  );
+}
+
+#[test]
+fn _0010() {
+  assert_translated(
+    err_plane_invalid_rule_number(3),
+    "<RecognizerError> niepoprawny numer reguły w płaszczyźnie: 3",
+    "<RecognizerError> ungültige Regelnummer in der Ebene: 3",
+  );
+}
+
+#[test]
+fn _0011() {
+  assert_translated(
+    err_expected_no_rule_numbers_present(),
+    "<RecognizerError> oczekiwano braku numerów reguł",
+    "<RecognizerError> keine Regelnummern erwartet",
+  );
+}
+
+#[test]
+fn _0012() {
+  assert_translated(
+    err_invalid_input_expressions(),
+    "<RecognizerError> niepoprawne wyrażenia wejściowe",
+    "<RecognizerError> ungültige Eingabeausdrücke",
+  );
+}
+
+#[test]
+fn _0013() {
+  assert_translated(
+    err_invalid_output_expressions(),
+    "<RecognizerError> niepoprawne wyrażenia wyjściowe",
+    "<RecognizerError> ungültige Ausgabeausdrücke",
+  );
+}
+
+#[test]
+fn _0014() {
+  assert_translated(err_no_output_clause(), "<RecognizerError> brak kolumny wyjściowej", "<RecognizerError> keine Ausgabespalte");
+}
+
+#[test]
+fn _0015() {
+  assert_translated(
+    err_expected_right_after_rule_numbers_placement(),
+    "<RecognizerError> oczekiwano numerów reguł umieszczonych bezpośrednio po prawej",
+    "<RecognizerError> Regelnummern direkt rechts erwartet",
+  );
+}
+
+#[test]
+fn _0016() {
+  assert_translated(
+    err_expected_left_below_rule_numbers_placement(),
+    "<RecognizerError> oczekiwano numerów reguł umieszczonych na dole po lewej",
+    "<RecognizerError> Regelnummern links unten erwartet",
+  );
+}
+
+#[test]
+fn _0017() {
+  assert_translated(
+    err_expected_bottom_left_hit_policy_placement(),
+    "<RecognizerError> oczekiwano polityki trafień umieszczonej na dole po lewej",
+    "<RecognizerError> Trefferrichtlinie unten links erwartet",
+  );
+}
+
+#[test]
+fn _0018() {
+  assert_translated(
+    err_expected_top_left_hit_policy_placement(),
+    "<RecognizerError> oczekiwano polityki trafień umieszczonej na górze po lewej",
+    "<RecognizerError> Trefferrichtlinie oben links erwartet",
+  );
+}
+
+#[test]
+fn _0019() {
+  assert_translated(
+    err_recognizing_cross_tab_not_supported_yet(),
+    "<RecognizerError> rozpoznawanie krzyżowych tabel decyzyjnych nie jest jeszcze zaimplementowane",
+    "<RecognizerError> Erkennen von Kreuztabellen-Entscheidungstabellen ist noch nicht implementiert",
+  );
+}
+
+#[test]
+fn _0020() {
+  assert_translated(
+    err_too_many_rows_in_input_clause(),
+    "<RecognizerError> zbyt wiele wierszy w kolumnie wejściowej",
+    "<RecognizerError> zu viele Zeilen in der Eingabespalte",
+  );
+}
+
+#[test]
+fn _0021() {
+  assert_translated(
+    err_too_many_rows_in_output_clause(),
+    "<RecognizerError> zbyt wiele wierszy w kolumnie wyjściowej",
+    "<RecognizerError> zu viele Zeilen in der Ausgabespalte",
+  );
+}
+
+#[test]
+fn _0022() {
+  assert_translated(
+    err_invalid_size("3x4"),
+    "<RecognizerError> niepoprawny rozmiar: 3x4",
+    "<RecognizerError> ungültige Größe: 3x4",
+  );
+}
@@ -1,5 +1,6 @@
 mod builder;
 mod canvas;
+mod messages;
 mod plane;
 mod point;
 mod recognizer;
@@ -1,9 +1,17 @@
 //! # Shared application data
 
-use dmntk_workspace::Workspaces;
+use crate::feel::FeelLimits;
+use dmntk_workspace::{Tenants, Workspaces};
 use std::sync::Arc;
 
 /// Workspaces with decision model evaluators.
 pub struct ApplicationData {
   pub workspaces: Arc<Workspaces>,
+  /// Isolated workspaces of tenants, when the directory of tenants was specified.
+  pub tenants: Option<Arc<Tenants>>,
+  /// Limits of ad-hoc evaluation of FEEL expressions.
+  pub feel_limits: FeelLimits,
+  /// Flag indicating if evaluations are traced with OpenTelemetry spans.
+  #[cfg(feature = "otel")]
+  pub tracing: bool,
 }
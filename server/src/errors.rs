@@ -0,0 +1,69 @@
+//! # Error definitions for server
+
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
+
+/// Server errors.
+#[derive(ToErrorMessage)]
+struct ServerError(Message);
+
+pub fn err_expression_too_long(length: usize, max_length: usize) -> DmntkError {
+  ServerError(message!("expression too long, length {} exceeds the maximum length {}", length, max_length)).into()
+}
+
+pub fn err_invalid_multipart(reason: &str) -> DmntkError {
+  ServerError(message!("invalid multipart request: {}", reason)).into()
+}
+
+pub fn err_model_part_not_found() -> DmntkError {
+  ServerError(message!("multipart request contains no part named 'model' and no uploaded file")).into()
+}
+
+pub fn err_model_not_utf8() -> DmntkError {
+  ServerError(message!("model is not encoded in UTF-8")).into()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use dmntk_common::Language;
+
+  #[test]
+  fn test_expression_too_long() {
+    let error = err_expression_too_long(120, 100);
+    assert_eq!(
+      "<ServerError> wyrażenie jest za długie, długość 120 przekracza maksymalną długość 100",
+      error.translated(Language::Polish)
+    );
+    assert_eq!(
+      "<ServerError> Ausdruck zu lang, Länge 120 überschreitet die maximale Länge 100",
+      error.translated(Language::German)
+    );
+  }
+
+  #[test]
+  fn test_invalid_multipart() {
+    let error = err_invalid_multipart("missing boundary");
+    assert_eq!("<ServerError> niepoprawne żądanie wieloczęściowe: missing boundary", error.translated(Language::Polish));
+    assert_eq!("<ServerError> ungültige mehrteilige Anfrage: missing boundary", error.translated(Language::German));
+  }
+
+  #[test]
+  fn test_model_part_not_found() {
+    let error = err_model_part_not_found();
+    assert_eq!(
+      "<ServerError> żądanie wieloczęściowe nie zawiera części o nazwie 'model' ani przesłanego pliku",
+      error.translated(Language::Polish)
+    );
+    assert_eq!(
+      "<ServerError> mehrteilige Anfrage enthält weder einen Teil namens 'model' noch eine hochgeladene Datei",
+      error.translated(Language::German)
+    );
+  }
+
+  #[test]
+  fn test_model_not_utf8() {
+    let error = err_model_not_utf8();
+    assert_eq!("<ServerError> model nie jest zakodowany w UTF-8", error.translated(Language::Polish));
+    assert_eq!("<ServerError> Modell ist nicht in UTF-8 kodiert", error.translated(Language::German));
+  }
+}
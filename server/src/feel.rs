@@ -0,0 +1,128 @@
+//! # Ad-hoc evaluation of FEEL expressions
+//!
+//! Expressions sent to `/feel` endpoint are not part of any deployed model,
+//! so their evaluation is sandboxed with limits specific to this endpoint,
+//! independent of limits applied when evaluating models.
+
+use crate::data::ApplicationData;
+use crate::errors::err_expression_too_long;
+use crate::server::CONTENT_TYPE;
+use actix_web::web::Json;
+use actix_web::{post, web, HttpResponse};
+use dmntk_common::{Jsonify, Result};
+use dmntk_evaluator::Budget;
+use dmntk_feel::values::Value;
+use dmntk_feel::FeelScope;
+use serde::Deserialize;
+use std::env;
+use std::str::FromStr;
+use std::time::Duration;
+
+const DMNTK_FEEL_MAX_LENGTH_VARIABLE: &str = "DMNTK_FEEL_MAX_LENGTH";
+const DMNTK_FEEL_MAX_TIME_VARIABLE: &str = "DMNTK_FEEL_MAX_TIME";
+const DMNTK_FEEL_MAX_STEPS_VARIABLE: &str = "DMNTK_FEEL_MAX_STEPS";
+const DMNTK_FEEL_MAX_ITEMS_VARIABLE: &str = "DMNTK_FEEL_MAX_ITEMS";
+
+/// Limits of resources consumed by a single request to `/feel` endpoint.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FeelLimits {
+  /// Maximum length of the expression and the context, in characters.
+  max_length: usize,
+  /// Maximum duration of the evaluation, in milliseconds.
+  max_time: u64,
+  /// Maximum number of evaluation steps (iterations and function invocations).
+  max_steps: usize,
+  /// Maximum number of created list elements and context entries, limiting the consumed memory.
+  max_items: usize,
+}
+
+impl Default for FeelLimits {
+  /// Creates limits safe for shared environments.
+  fn default() -> Self {
+    Self {
+      max_length: 4_096,
+      max_time: 1_000,
+      max_steps: 100_000,
+      max_items: 100_000,
+    }
+  }
+}
+
+impl FeelLimits {
+  /// Creates limits with default values overridden by environment variables:
+  /// - `DMNTK_FEEL_MAX_LENGTH` for the maximum length of the expression and the context,
+  /// - `DMNTK_FEEL_MAX_TIME` for the maximum duration of the evaluation in milliseconds,
+  /// - `DMNTK_FEEL_MAX_STEPS` for the maximum number of evaluation steps,
+  /// - `DMNTK_FEEL_MAX_ITEMS` for the maximum number of created list elements and context entries.
+  pub fn from_env() -> Self {
+    let default = Self::default();
+    Self {
+      max_length: env_limit(DMNTK_FEEL_MAX_LENGTH_VARIABLE, default.max_length),
+      max_time: env_limit(DMNTK_FEEL_MAX_TIME_VARIABLE, default.max_time),
+      max_steps: env_limit(DMNTK_FEEL_MAX_STEPS_VARIABLE, default.max_steps),
+      max_items: env_limit(DMNTK_FEEL_MAX_ITEMS_VARIABLE, default.max_items),
+    }
+  }
+
+  /// Returns the evaluation budget defined by these limits.
+  fn budget(&self) -> Budget {
+    Budget::default()
+      .with_max_duration(Duration::from_millis(self.max_time))
+      .with_max_steps(self.max_steps)
+      .with_max_list_elements(self.max_items)
+      .with_max_context_entries(self.max_items)
+  }
+
+  /// Checks the length of the text sent for evaluation.
+  fn check_length(&self, text: &str) -> Result<()> {
+    let length = text.chars().count();
+    if length > self.max_length {
+      return Err(err_expression_too_long(length, self.max_length));
+    }
+    Ok(())
+  }
+}
+
+/// Returns the limit read from specified environment variable, or the default value.
+fn env_limit<T: FromStr + Copy>(variable: &str, default: T) -> T {
+  match env::var(variable) {
+    Ok(text) => T::from_str(&text).unwrap_or_else(|_| {
+      eprintln!("invalid limit specified in environment variable {}: {}", variable, text);
+      default
+    }),
+    Err(_) => default,
+  }
+}
+
+/// Request for evaluating FEEL expression.
+#[derive(Deserialize)]
+pub struct FeelRequestDto {
+  /// Evaluated expression.
+  #[serde(rename = "expression")]
+  expression: String,
+  /// Optional context, in which the expression is evaluated.
+  #[serde(rename = "context")]
+  context: Option<String>,
+}
+
+/// Handler for evaluating FEEL expression within limits of `/feel` endpoint.
+#[post("/feel")]
+async fn evaluate_feel(request: Json<FeelRequestDto>, data: web::Data<ApplicationData>) -> HttpResponse {
+  match evaluate(&request, &data.feel_limits) {
+    Ok(value) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{}}}"#, value.jsonify())),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Evaluates the expression in optional context, within specified limits.
+fn evaluate(request: &FeelRequestDto, limits: &FeelLimits) -> Result<Value> {
+  limits.check_length(&request.expression)?;
+  let context = request.context.as_deref().unwrap_or("{}");
+  limits.check_length(context)?;
+  dmntk_evaluator::evaluate_with_budget(limits.budget(), || {
+    let ctx = dmntk_evaluator::evaluate_context(&FeelScope::default(), context)?;
+    let scope: FeelScope = ctx.into();
+    let node = dmntk_feel_parser::parse_expression(&scope, &request.expression, false)?;
+    dmntk_evaluator::evaluate(&scope, &node)
+  })?
+}
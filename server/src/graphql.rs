@@ -0,0 +1,23 @@
+//! # GraphQL endpoint
+//!
+//! Decisions and decision services are evaluated with GraphQL queries sent with HTTP POST
+//! to `/graphql`, the schema of the API is available with HTTP GET at `/graphql/schema`.
+
+use crate::data::ApplicationData;
+use crate::server::CONTENT_TYPE;
+use actix_web::{get, post, web, HttpResponse};
+
+/// Content type of the GraphQL schema.
+const SDL_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
+
+/// Handler for executing GraphQL requests.
+#[post("/graphql")]
+pub async fn evaluate_graphql(request_body: String, data: web::Data<ApplicationData>) -> HttpResponse {
+  HttpResponse::Ok().content_type(CONTENT_TYPE).body(data.workspaces.evaluate_graphql(&request_body))
+}
+
+/// Handler for retrieving the schema of the GraphQL API in schema definition language.
+#[get("/graphql/schema")]
+pub async fn graphql_schema(data: web::Data<ApplicationData>) -> HttpResponse {
+  HttpResponse::Ok().content_type(SDL_CONTENT_TYPE).body(data.workspaces.graphql_schema())
+}
@@ -1,11 +1,17 @@
-#[cfg(feature = "tck")]
 #[macro_use]
 extern crate dmntk_macros;
 
 mod data;
+mod errors;
+mod feel;
+#[cfg(feature = "graphql")]
+mod graphql;
+mod multipart;
+#[cfg(feature = "otel")]
+mod otel;
 mod server;
 
 #[cfg(feature = "tck")]
 mod tck;
 
-pub use server::start_server;
+pub use server::{get_conformance_level, get_language, start_server};
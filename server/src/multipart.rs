@@ -0,0 +1,202 @@
+//! # Parsing `multipart/form-data` request bodies
+//!
+//! Request bodies are parsed as described in RFC 7578: parts are separated by lines
+//! with the boundary taken from the `Content-Type` header, each part starts with headers,
+//! `Content-Disposition` gives the name of the form field and the name of the uploaded file.
+//! The whole body is parsed at once, so the size of uploaded files is limited
+//! by the payload limit of the server.
+
+use crate::errors::*;
+use dmntk_common::Result;
+
+/// Media type of request bodies with uploaded files.
+pub(crate) const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+
+/// Line break separating headers and parts.
+const CRLF: &[u8] = b"\r\n";
+
+/// Part of the `multipart/form-data` request body.
+pub(crate) struct Part {
+  /// Name of the form field.
+  name: Option<String>,
+  /// Name of the uploaded file.
+  filename: Option<String>,
+  /// Content of the part.
+  content: Vec<u8>,
+}
+
+impl Part {
+  /// Returns the name of the form field.
+  pub(crate) fn name(&self) -> Option<&str> {
+    self.name.as_deref()
+  }
+
+  /// Returns the name of the uploaded file.
+  pub(crate) fn filename(&self) -> Option<&str> {
+    self.filename.as_deref()
+  }
+
+  /// Returns the content of the part.
+  pub(crate) fn content(&self) -> &[u8] {
+    &self.content
+  }
+}
+
+/// Returns the boundary when the content type is `multipart/form-data`.
+pub(crate) fn boundary(content_type: &str) -> Option<String> {
+  let mut parameters = content_type.split(';');
+  if !parameters.next()?.trim().eq_ignore_ascii_case(MULTIPART_FORM_DATA) {
+    return None;
+  }
+  parameters
+    .filter_map(|parameter| parameter.split_once('='))
+    .find(|(name, _)| name.trim().eq_ignore_ascii_case("boundary"))
+    .map(|(_, value)| unquote(value.trim()).to_string())
+    .filter(|boundary| !boundary.is_empty())
+}
+
+/// Parses the request body into parts separated by the boundary.
+pub(crate) fn parse(body: &[u8], boundary: &str) -> Result<Vec<Part>> {
+  let delimiter = [b"--", boundary.as_bytes()].concat();
+  let closing = [CRLF, &delimiter].concat();
+  // the first delimiter is at the beginning of the body or follows the preamble
+  let mut position = if body.starts_with(&delimiter) {
+    delimiter.len()
+  } else {
+    find(body, &closing, 0).ok_or_else(|| err_invalid_multipart("missing boundary"))? + closing.len()
+  };
+  let mut parts = vec![];
+  loop {
+    if body[position..].starts_with(b"--") {
+      return Ok(parts);
+    }
+    // transport padding may follow the delimiter
+    while body.get(position).is_some_and(|byte| *byte == b' ' || *byte == b'\t') {
+      position += 1;
+    }
+    if !body[position..].starts_with(CRLF) {
+      return Err(err_invalid_multipart("expected line break after boundary"));
+    }
+    position += CRLF.len();
+    let end = find(body, &closing, position).ok_or_else(|| err_invalid_multipart("missing closing boundary"))?;
+    parts.push(parse_part(&body[position..end])?);
+    position = end + closing.len();
+  }
+}
+
+/// Parses headers and the content of a single part.
+fn parse_part(bytes: &[u8]) -> Result<Part> {
+  let (headers, content) = if bytes.starts_with(CRLF) {
+    (&bytes[..0], &bytes[CRLF.len()..])
+  } else {
+    let separator = find(bytes, b"\r\n\r\n", 0).ok_or_else(|| err_invalid_multipart("missing end of part headers"))?;
+    (&bytes[..separator], &bytes[separator + 4..])
+  };
+  let headers = std::str::from_utf8(headers).map_err(|_| err_invalid_multipart("part headers are not valid UTF-8"))?;
+  let mut part = Part {
+    name: None,
+    filename: None,
+    content: content.to_vec(),
+  };
+  for header in headers.split("\r\n").filter(|header| !header.is_empty()) {
+    let Some((header_name, header_value)) = header.split_once(':') else {
+      return Err(err_invalid_multipart(&format!("invalid part header '{header}'")));
+    };
+    if header_name.trim().eq_ignore_ascii_case("content-disposition") {
+      for parameter in header_value.split(';').skip(1) {
+        if let Some((name, value)) = parameter.split_once('=') {
+          match name.trim().to_ascii_lowercase().as_str() {
+            "name" => part.name = Some(unquote(value.trim()).to_string()),
+            "filename" => part.filename = Some(unquote(value.trim()).to_string()),
+            _ => {}
+          }
+        }
+      }
+    }
+  }
+  Ok(part)
+}
+
+/// Returns the position of the first occurrence of the pattern, starting from specified position.
+fn find(bytes: &[u8], pattern: &[u8], start: usize) -> Option<usize> {
+  bytes
+    .get(start..)?
+    .windows(pattern.len())
+    .position(|window| window == pattern)
+    .map(|position| start + position)
+}
+
+/// Removes enclosing double quotes.
+fn unquote(value: &str) -> &str {
+  value.strip_prefix('"').and_then(|value| value.strip_suffix('"')).unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_boundary() {
+    assert_eq!(Some("abc".to_string()), boundary("multipart/form-data; boundary=abc"));
+    assert_eq!(Some("a b".to_string()), boundary(r#"Multipart/Form-Data;charset=utf-8; Boundary="a b""#));
+    assert_eq!(None, boundary("multipart/form-data"));
+    assert_eq!(None, boundary("multipart/mixed; boundary=abc"));
+    assert_eq!(None, boundary("application/xml"));
+  }
+
+  #[test]
+  fn test_parse() {
+    let body = concat!(
+      "preamble\r\n",
+      "--xyz\r\n",
+      "Content-Disposition: form-data; name=\"model\"; filename=\"model.dmn\"\r\n",
+      "Content-Type: application/xml\r\n",
+      "\r\n",
+      "<definitions/>\r\n",
+      "--xyz  \r\n",
+      "content-disposition: form-data; name=comment\r\n",
+      "\r\n",
+      "first line\r\nsecond line\r\n",
+      "--xyz\r\n",
+      "\r\n",
+      "no headers\r\n",
+      "--xyz--\r\n",
+      "epilogue"
+    );
+    let parts = parse(body.as_bytes(), "xyz").unwrap();
+    assert_eq!(3, parts.len());
+    assert_eq!(Some("model"), parts[0].name());
+    assert_eq!(Some("model.dmn"), parts[0].filename());
+    assert_eq!(b"<definitions/>", parts[0].content());
+    assert_eq!(Some("comment"), parts[1].name());
+    assert_eq!(None, parts[1].filename());
+    assert_eq!(b"first line\r\nsecond line", parts[1].content());
+    assert_eq!(None, parts[2].name());
+    assert_eq!(b"no headers", parts[2].content());
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert_eq!(
+      "<ServerError> invalid multipart request: missing boundary",
+      parse(b"<definitions/>", "xyz").err().unwrap().to_string()
+    );
+    assert_eq!(
+      "<ServerError> invalid multipart request: missing closing boundary",
+      parse(b"--xyz\r\n\r\n<definitions/>", "xyz").err().unwrap().to_string()
+    );
+    assert_eq!(
+      "<ServerError> invalid multipart request: expected line break after boundary",
+      parse(b"--xyzabc\r\n\r\n\r\n--xyz--", "xyz").err().unwrap().to_string()
+    );
+    assert_eq!(
+      "<ServerError> invalid multipart request: missing end of part headers",
+      parse(b"--xyz\r\nContent-Type: text/plain\r\n--xyz--", "xyz").err().unwrap().to_string()
+    );
+    assert_eq!(
+      "<ServerError> invalid multipart request: invalid part header 'no colon'",
+      parse(b"--xyz\r\nno colon\r\n\r\nx\r\n--xyz--", "xyz").err().unwrap().to_string()
+    );
+    assert!(parse(b"--xyz--", "xyz").unwrap().is_empty());
+  }
+}
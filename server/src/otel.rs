@@ -0,0 +1,158 @@
+//! # OpenTelemetry tracing
+//!
+//! When the OTLP endpoint is configured, each evaluation of an invocable is traced
+//! with a span, containing nested spans of evaluated decisions and decision tables.
+//! Trace context received in `traceparent` header of the request is used as the parent,
+//! so evaluations appear in distributed traces of the calling services.
+//! Spans are exported in background over OTLP/HTTP.
+
+use actix_web::http::header::HeaderMap;
+use dmntk_common::Result;
+use dmntk_evaluator::{EvaluationListener, FiredRule};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_workspace::Workspaces;
+use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::{Span, Status, TraceContextExt, Tracer};
+use opentelemetry::{global, Context, KeyValue};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use std::cell::RefCell;
+use std::env;
+use std::rc::Rc;
+
+const DMNTK_OTLP_ENDPOINT_VARIABLE: &str = "DMNTK_OTLP_ENDPOINT";
+const DMNTK_OTLP_SERVICE_NAME_VARIABLE: &str = "DMNTK_OTLP_SERVICE_NAME";
+const DMNTK_DEFAULT_SERVICE_NAME: &str = "dmntk";
+const TRACER_NAME: &str = "dmntk";
+
+/// Installs the global tracer provider exporting spans to the OTLP endpoint.
+///
+/// The endpoint is given in `DMNTK_OTLP_ENDPOINT` environment variable, e.g. `http://localhost:4318/v1/traces`,
+/// the name of the service reported in traces may be given in `DMNTK_OTLP_SERVICE_NAME` environment variable.
+/// Returns `None` when no endpoint is configured, evaluations are then not traced.
+pub fn init_tracing() -> Option<SdkTracerProvider> {
+  let endpoint = env::var(DMNTK_OTLP_ENDPOINT_VARIABLE).ok()?;
+  let service_name = env::var(DMNTK_OTLP_SERVICE_NAME_VARIABLE).unwrap_or_else(|_| DMNTK_DEFAULT_SERVICE_NAME.to_string());
+  let exporter = match SpanExporter::builder().with_http().with_endpoint(&endpoint).build() {
+    Ok(exporter) => exporter,
+    Err(reason) => {
+      eprintln!("invalid OTLP endpoint specified in environment variable {DMNTK_OTLP_ENDPOINT_VARIABLE}: {endpoint} ({reason})");
+      return None;
+    }
+  };
+  let provider = SdkTracerProvider::builder()
+    .with_batch_exporter(exporter)
+    .with_resource(Resource::builder().with_service_name(service_name).build())
+    .build();
+  global::set_text_map_propagator(TraceContextPropagator::new());
+  global::set_tracer_provider(provider.clone());
+  Some(provider)
+}
+
+/// Evaluates invocable identified by invocable path within a span,
+/// the trace context of the span's parent is extracted from request headers.
+pub fn evaluate_traced(workspaces: &Workspaces, invocable_path: &str, input_data: &FeelContext, headers: &HeaderMap) -> Result<Value> {
+  let parent = global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)));
+  let tracer = global::tracer(TRACER_NAME);
+  let span = tracer.start_with_context(format!("evaluate {invocable_path}"), &parent);
+  let context = parent.with_span(span);
+  let listener = Rc::new(SpanListener::new(context.clone()));
+  let result = workspaces.evaluate_with_listener(invocable_path, input_data, listener);
+  let span = context.span();
+  span.set_attribute(KeyValue::new("dmntk.invocable", invocable_path.to_string()));
+  match &result {
+    Ok(Value::Null(Some(reason))) => span.set_status(Status::error(reason.to_string())),
+    Err(reason) => span.set_status(Status::error(reason.to_string())),
+    _ => {}
+  }
+  span.end();
+  result
+}
+
+/// Extractor of trace context from request headers.
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+  fn get(&self, key: &str) -> Option<&str> {
+    self.0.get(key).and_then(|value| value.to_str().ok())
+  }
+
+  fn keys(&self) -> Vec<&str> {
+    self.0.keys().map(|name| name.as_str()).collect()
+  }
+}
+
+/// Listener creating nested spans for evaluated decisions and decision tables.
+struct SpanListener {
+  /// Contexts of spans started and not finished yet, the first one is the context of the evaluation.
+  contexts: RefCell<Vec<Context>>,
+}
+
+impl SpanListener {
+  /// Creates a listener nesting spans in specified context.
+  fn new(context: Context) -> Self {
+    Self {
+      contexts: RefCell::new(vec![context]),
+    }
+  }
+
+  /// Starts a span nested in the most recently started span.
+  fn start(&self, name: String, attribute: KeyValue) {
+    let mut contexts = self.contexts.borrow_mut();
+    let parent = contexts.last().cloned().unwrap_or_default();
+    let mut span = global::tracer(TRACER_NAME).start_with_context(name, &parent);
+    span.set_attribute(attribute);
+    contexts.push(parent.with_span(span));
+  }
+
+  /// Finishes the most recently started span, recording the reason of `null` result as an error.
+  fn finish(&self, result: &Value) {
+    let mut contexts = self.contexts.borrow_mut();
+    // the context of the evaluation is finished by the caller
+    if contexts.len() > 1 {
+      if let Some(context) = contexts.pop() {
+        let span = context.span();
+        if let Value::Null(Some(reason)) = result {
+          span.set_status(Status::error(reason.to_string()));
+        }
+        span.end();
+      }
+    }
+  }
+}
+
+impl EvaluationListener for SpanListener {
+  fn rule_fired(&self, fired_rule: &FiredRule) {
+    if let Some(context) = self.contexts.borrow().last() {
+      context.span().add_event(
+        "rule fired",
+        vec![
+          KeyValue::new("dmntk.decision_table", fired_rule.decision_table_id().to_string()),
+          KeyValue::new("dmntk.rule", fired_rule.rule_number() as i64),
+        ],
+      );
+    }
+  }
+
+  fn decision_started(&self, decision_name: &str) {
+    self.start(format!("decision {decision_name}"), KeyValue::new("dmntk.decision", decision_name.to_string()));
+  }
+
+  fn decision_finished(&self, _decision_name: &str, result: &Value) {
+    self.finish(result);
+  }
+
+  fn decision_table_started(&self, decision_table_id: &str) {
+    self.start(
+      format!("decision table {decision_table_id}"),
+      KeyValue::new("dmntk.decision_table", decision_table_id.to_string()),
+    );
+  }
+
+  fn decision_table_finished(&self, _decision_table_id: &str, result: &Value) {
+    self.finish(result);
+  }
+}
@@ -1,8 +1,19 @@
 use crate::data::ApplicationData;
-use actix_web::{post, web, App, HttpResponse, HttpServer};
-use dmntk_common::{ColorPalette, Jsonify};
-use dmntk_feel::FeelScope;
-use dmntk_workspace::Workspaces;
+use crate::errors::{err_model_not_utf8, err_model_part_not_found};
+use crate::feel::FeelLimits;
+use crate::multipart;
+use actix_web::http::header;
+use actix_web::{delete, get, post, put, web, App, HttpRequest, HttpResponse, HttpServer};
+use dmntk_common::{ColorPalette, Jsonify, Language};
+use dmntk_feel::cbor::value_to_cbor;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{ConformanceLevel, FeelScope};
+use dmntk_workspace::{
+  AuditSink, CanaryStatus, DeploymentReport, DeploymentStatus, ExternalShadow, FileAuditSink, InvocableStats, Projection, ServiceDescriptor, ShadowSummary, StdoutAuditSink,
+  Tenants, Webhooks, Workspaces,
+};
+use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::net::IpAddr;
 use std::path::{Path, PathBuf};
@@ -15,51 +26,689 @@ const DMNTK_DEFAULT_HOST: &str = "0.0.0.0";
 const DMNTK_HOST_VARIABLE: &str = "DMNTK_HOST";
 const DMNTK_PORT_VARIABLE: &str = "DMNTK_PORT";
 const DMNTK_DIR_VARIABLE: &str = "DMNTK_DIR";
-const CONTENT_TYPE: &str = "application/json";
+const DMNTK_TENANTS_DIR_VARIABLE: &str = "DMNTK_TENANTS_DIR";
+const DMNTK_WEBHOOKS_VARIABLE: &str = "DMNTK_WEBHOOKS";
+const DMNTK_CONFORMANCE_LEVEL_VARIABLE: &str = "DMNTK_CONFORMANCE_LEVEL";
+const DMNTK_AUDIT_VARIABLE: &str = "DMNTK_AUDIT";
+const DMNTK_SHADOW_URL_VARIABLE: &str = "DMNTK_SHADOW_URL";
+const DMNTK_LANGUAGE_VARIABLE: &str = "DMNTK_LANGUAGE";
+const DMNTK_DEPLOYMENTS_VARIABLE: &str = "DMNTK_DEPLOYMENTS";
+pub(crate) const CONTENT_TYPE: &str = "application/json";
+const XML_CONTENT_TYPE: &str = "application/xml";
+const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+/// Parameters of evaluation endpoints.
+#[derive(Deserialize)]
+struct SelectParams {
+  /// Comma separated FEEL paths or JSON pointers of parts of the result to be returned.
+  select: Option<String>,
+}
+
+/// Returns only parts of the evaluation result selected in parameters,
+/// the whole result is returned when no parts are selected.
+fn select(params: &SelectParams, result: dmntk_common::Result<Value>) -> dmntk_common::Result<Value> {
+  match &params.select {
+    Some(paths) => result.and_then(|value| Projection::parse(paths)?.apply(&value)),
+    None => result,
+  }
+}
+
+/// Returns `true` when CBOR is preferred over JSON in the `Accept` header.
+///
+/// CBOR is preferred when its quality is greater than the quality of JSON,
+/// or equal but CBOR is named explicitly and JSON is not; zero quality means not acceptable.
+fn prefers_cbor(accept: &str) -> bool {
+  let (cbor_specificity, cbor_quality) = media_type_quality(accept, CBOR_CONTENT_TYPE);
+  let (json_specificity, json_quality) = media_type_quality(accept, CONTENT_TYPE);
+  cbor_quality > 0.0 && (cbor_quality > json_quality || (cbor_quality == json_quality && cbor_specificity == 3 && json_specificity < 3))
+}
+
+/// Returns the specificity and the quality of the most specific media range in the `Accept` header
+/// matching the media type; specificity is 3 for the media type, 2 for `type/*` and 1 for `*/*`,
+/// the quality defaults to 1 when no `q` parameter is given, `(0, 0.0)` is returned when no media range matches.
+fn media_type_quality(accept: &str, media_type: &str) -> (u8, f32) {
+  let main_type = media_type.split('/').next().unwrap_or_default();
+  let mut matched = (0, 0.0);
+  for media_range in accept.split(',') {
+    let mut parts = media_range.split(';');
+    let range = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+    let specificity = match range.split_once('/') {
+      _ if range == media_type => 3,
+      Some((range_type, "*")) if range_type == main_type => 2,
+      Some(("*", "*")) => 1,
+      _ => continue,
+    };
+    if specificity > matched.0 {
+      let quality = parts
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+        .map_or(Some(1.0), |(_, value)| value.trim().parse::<f32>().ok())
+        .unwrap_or(0.0)
+        .clamp(0.0, 1.0);
+      matched = (specificity, quality);
+    }
+  }
+  matched
+}
+
+/// Converts the evaluation result into response, encoded in CBOR
+/// when preferred in `Accept` header, otherwise encoded in JSON.
+fn evaluation_response(request: &HttpRequest, result: dmntk_common::Result<Value>) -> HttpResponse {
+  let accepts_cbor = request.headers().get(header::ACCEPT).and_then(|accept| accept.to_str().ok()).is_some_and(prefers_cbor);
+  if accepts_cbor {
+    let mut body = FeelContext::default();
+    match result {
+      Ok(value) => body.set_entry(&"data".into(), value),
+      Err(reason) => {
+        let mut error = FeelContext::default();
+        error.set_entry(&"detail".into(), Value::String(reason.to_string().into()));
+        body.set_entry(&"errors".into(), Value::List(vec![Value::Context(error)]));
+      }
+    }
+    return HttpResponse::Ok().content_type(CBOR_CONTENT_TYPE).body(value_to_cbor(&Value::Context(body)));
+  }
+  match result {
+    Ok(value) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{}}}"#, value.jsonify())),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
 
 /// Handler for evaluating invocable identified
 /// by unique name in namespace represented by RDNN.
 #[post("/evaluate/{path:.*}")]
-async fn evaluate(path: web::Path<String>, request_body: String, data: web::Data<ApplicationData>) -> HttpResponse {
+async fn evaluate(path: web::Path<String>, params: web::Query<SelectParams>, request_body: String, data: web::Data<ApplicationData>, request: HttpRequest) -> HttpResponse {
   let workspace: &Workspaces = data.workspaces.borrow();
-  match dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body).and_then(|input_data| workspace.evaluate(&path, &input_data)) {
-    Ok(value) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{}}}"#, value.jsonify())),
+  let result = dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body).and_then(|input_data| {
+    #[cfg(feature = "otel")]
+    if data.tracing {
+      return crate::otel::evaluate_traced(workspace, &path, &input_data, request.headers());
+    }
+    workspace.evaluate(&path, &input_data)
+  });
+  evaluation_response(&request, select(&params, result))
+}
+
+/// Handler for evaluating invocable identified by unique name
+/// in namespace represented by RDNN, in workspaces of the tenant.
+#[post("/tenants/{tenant}/evaluate/{path:.*}")]
+async fn evaluate_tenant(
+  params: web::Path<(String, String)>,
+  select_params: web::Query<SelectParams>,
+  request_body: String,
+  data: web::Data<ApplicationData>,
+  request: HttpRequest,
+) -> HttpResponse {
+  let (tenant, path) = params.into_inner();
+  let Some(tenants) = &data.tenants else {
+    return not_found().await;
+  };
+  let result = dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body).and_then(|input_data| tenants.evaluate(&tenant, &path, &input_data));
+  evaluation_response(&request, select(&select_params, result))
+}
+
+/// Handler for retrieving metrics collected for the tenant.
+#[get("/tenants/{tenant}/metrics")]
+async fn tenant_metrics(tenant: web::Path<String>, data: web::Data<ApplicationData>) -> HttpResponse {
+  let Some(tenants) = &data.tenants else {
+    return not_found().await;
+  };
+  match tenants.metrics(&tenant) {
+    Ok(metrics) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(
+      r#"{{"data":{{"invocables":{},"evaluations":{},"failures":{}}}}}"#,
+      metrics.invocables(),
+      metrics.evaluations(),
+      metrics.failures()
+    )),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Failure of loading a model file or deploying a workspace.
+#[derive(Serialize)]
+struct DeploymentFailureDto<'a> {
+  /// Model file or workspace that failed.
+  source: &'a str,
+  /// Reason of the failure.
+  reason: &'a str,
+}
+
+/// Summary of loading and deploying decision models.
+#[derive(Serialize)]
+struct DeploymentStatusDto<'a> {
+  /// Total number of model files found.
+  found: usize,
+  /// Number of successfully loaded models.
+  loaded: usize,
+  /// Number of models that failed to load.
+  #[serde(rename = "failedLoads")]
+  failed_loads: usize,
+  /// Number of workspaces that failed to deploy.
+  #[serde(rename = "failedDeployments")]
+  failed_deployments: usize,
+  /// Number of deployed invocables.
+  invocables: usize,
+  /// Time spent on loading and deploying models, in milliseconds.
+  duration: u128,
+  /// Failures with reasons.
+  failures: Vec<DeploymentFailureDto<'a>>,
+}
+
+impl<'a> From<&'a DeploymentStatus> for DeploymentStatusDto<'a> {
+  fn from(status: &'a DeploymentStatus) -> Self {
+    Self {
+      found: status.file_count(),
+      loaded: status.loaded_count(),
+      failed_loads: status.failed_loads_count(),
+      failed_deployments: status.failed_deployments_count(),
+      invocables: status.invocable_count(),
+      duration: status.duration().as_millis(),
+      failures: status
+        .failures()
+        .iter()
+        .map(|failure| DeploymentFailureDto {
+          source: failure.source(),
+          reason: failure.reason(),
+        })
+        .collect(),
+    }
+  }
+}
+
+/// Handler for retrieving the summary of deployed models, including failures with reasons.
+#[get("/status/deployments")]
+async fn deployment_status(data: web::Data<ApplicationData>) -> HttpResponse {
+  let status = DeploymentStatusDto::from(data.workspaces.status());
+  match serde_json::to_string(&status) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Report of deploying a decision model at runtime.
+#[derive(Serialize)]
+struct DeploymentReportDto<'a> {
+  /// Unique identifier of the deployment.
+  id: &'a str,
+  /// Identifier of the replaced deployment.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  replaced: Option<&'a str>,
+  /// Namespace of the deployed model.
+  namespace: &'a str,
+  /// Name of the deployed model.
+  name: &'a str,
+  /// Diagnostics reported while compiling the model.
+  diagnostics: &'a [String],
+  /// Paths of invocables exposed by the deployed model.
+  invocables: &'a [String],
+}
+
+impl<'a> From<&'a DeploymentReport> for DeploymentReportDto<'a> {
+  fn from(report: &'a DeploymentReport) -> Self {
+    Self {
+      id: report.id(),
+      replaced: report.replaced(),
+      namespace: report.namespace(),
+      name: report.name(),
+      diagnostics: report.diagnostics(),
+      invocables: report.invocables(),
+    }
+  }
+}
+
+/// Returns the DMN XML of the deployed model, given in the request body or uploaded as `multipart/form-data`.
+///
+/// From `multipart/form-data` the part named `model` is taken, or the first uploaded file
+/// when there is no part named `model`.
+fn deployed_model(request: &HttpRequest, request_body: &[u8]) -> dmntk_common::Result<String> {
+  let opt_boundary = request
+    .headers()
+    .get(header::CONTENT_TYPE)
+    .and_then(|content_type| content_type.to_str().ok())
+    .and_then(multipart::boundary);
+  let content = match opt_boundary {
+    Some(boundary) => {
+      let parts = multipart::parse(request_body, &boundary)?;
+      let part = parts
+        .iter()
+        .find(|part| part.name() == Some("model"))
+        .or_else(|| parts.iter().find(|part| part.filename().is_some()))
+        .ok_or_else(err_model_part_not_found)?;
+      part.content().to_vec()
+    }
+    None => request_body.to_vec(),
+  };
+  String::from_utf8(content).map_err(|_| err_model_not_utf8())
+}
+
+/// Handler for deploying a decision model given as DMN XML in the request body
+/// or uploaded as `multipart/form-data`.
+#[post("/deployments")]
+async fn deploy(request: HttpRequest, request_body: web::Bytes, data: web::Data<ApplicationData>) -> HttpResponse {
+  let report = match deployed_model(&request, &request_body).and_then(|xml| data.workspaces.deploy(&xml)) {
+    Ok(report) => report,
+    Err(reason) => return HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  };
+  match serde_json::to_string(&DeploymentReportDto::from(&report)) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Handler for retrieving the original DMN XML source of the deployed model.
+#[get("/deployments/{id}/source")]
+async fn deployment_source(id: web::Path<String>, data: web::Data<ApplicationData>) -> HttpResponse {
+  match data.workspaces.deployment_source(&id) {
+    Ok(xml) => HttpResponse::Ok().content_type(XML_CONTENT_TYPE).body(xml),
+    Err(reason) => HttpResponse::NotFound()
+      .content_type(CONTENT_TYPE)
+      .body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Parameters of undeploying a model.
+#[derive(Deserialize)]
+struct UndeployParams {
+  /// Flag indicating if models importing the undeployed model are undeployed too.
+  #[serde(default)]
+  cascade: bool,
+}
+
+/// Handler for undeploying the model deployed at runtime.
+#[delete("/deployments/{id}")]
+async fn undeploy(id: web::Path<String>, params: web::Query<UndeployParams>, data: web::Data<ApplicationData>) -> HttpResponse {
+  match data.workspaces.undeploy(&id, params.cascade) {
+    Ok(removed) => match serde_json::to_string(&removed) {
+      Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{{"removed":{json}}}}}"#)),
+      Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+    },
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Status of a canary deployment.
+#[derive(Serialize)]
+struct CanaryStatusDto<'a> {
+  /// Unique identifier of the canary deployment.
+  id: &'a str,
+  /// Identifier of the stable deployment.
+  stable: &'a str,
+  /// Namespace of the model.
+  namespace: &'a str,
+  /// Name of the model.
+  name: &'a str,
+  /// Percentage of evaluations routed to the canary.
+  percentage: u8,
+  /// Diagnostics reported while compiling the canary model.
+  diagnostics: &'a [String],
+  /// Paths of invocables exposed by the canary model.
+  invocables: &'a [String],
+  /// Number of evaluations of invocables exposed by the canary.
+  evaluations: u64,
+  /// Number of evaluations routed to the canary.
+  routed: u64,
+  /// Number of evaluations routed to the canary with the result different from the stable version.
+  divergences: u64,
+  /// Number of evaluations routed to the canary compared with the result of the stable version.
+  compared: u64,
+  /// Number of evaluations routed to the canary not compared, because the queue of shadow evaluations was full.
+  dropped: u64,
+}
+
+impl<'a> From<&'a CanaryStatus> for CanaryStatusDto<'a> {
+  fn from(status: &'a CanaryStatus) -> Self {
+    Self {
+      id: status.id(),
+      stable: status.stable(),
+      namespace: status.namespace(),
+      name: status.name(),
+      percentage: status.percentage(),
+      diagnostics: status.diagnostics(),
+      invocables: status.invocables(),
+      evaluations: status.evaluations(),
+      routed: status.routed(),
+      divergences: status.divergences(),
+      compared: status.compared(),
+      dropped: status.dropped(),
+    }
+  }
+}
+
+/// Parameters of routing evaluations to a canary.
+#[derive(Deserialize)]
+struct CanaryParams {
+  /// Percentage of evaluations routed to the canary.
+  percentage: u8,
+}
+
+/// Converts the status of the canary into response.
+fn canary_response(result: dmntk_common::Result<CanaryStatus>) -> HttpResponse {
+  let status = match result {
+    Ok(status) => status,
+    Err(reason) => return HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  };
+  match serde_json::to_string(&CanaryStatusDto::from(&status)) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Handler for deploying a decision model given as DMN XML in the request body
+/// as a canary of the deployed model with the same namespace.
+#[post("/canaries")]
+async fn deploy_canary(request_body: String, params: web::Query<CanaryParams>, data: web::Data<ApplicationData>) -> HttpResponse {
+  canary_response(data.workspaces.deploy_canary(&request_body, params.percentage))
+}
+
+/// Handler for retrieving statuses of all canaries.
+#[get("/canaries")]
+async fn canaries(data: web::Data<ApplicationData>) -> HttpResponse {
+  let canaries = data.workspaces.canaries();
+  match serde_json::to_string(&canaries.iter().map(CanaryStatusDto::from).collect::<Vec<_>>()) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Handler for changing the percentage of evaluations routed to the canary.
+#[put("/canaries/{id}")]
+async fn route_canary(id: web::Path<String>, params: web::Query<CanaryParams>, data: web::Data<ApplicationData>) -> HttpResponse {
+  canary_response(data.workspaces.set_canary_percentage(&id, params.percentage))
+}
+
+/// Handler for promoting the canary to the stable version.
+#[post("/canaries/{id}/promote")]
+async fn promote_canary(id: web::Path<String>, data: web::Data<ApplicationData>) -> HttpResponse {
+  let report = match data.workspaces.promote_canary(&id) {
+    Ok(report) => report,
+    Err(reason) => return HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  };
+  match serde_json::to_string(&DeploymentReportDto::from(&report)) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Handler for removing the canary.
+#[delete("/canaries/{id}")]
+async fn remove_canary(id: web::Path<String>, data: web::Data<ApplicationData>) -> HttpResponse {
+  match data.workspaces.remove_canary(&id) {
+    Ok(()) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{{"removed":"{id}"}}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Evaluation with the result different from the result returned by the external engine.
+#[derive(Serialize)]
+struct ShadowMismatchDto<'a> {
+  /// Path of the evaluated invocable.
+  #[serde(rename = "invocablePath")]
+  invocable_path: &'a str,
+  /// Input data forwarded to the external engine.
+  #[serde(rename = "inputData")]
+  input_data: serde_json::Value,
+  /// Result of the evaluation.
+  result: serde_json::Value,
+  /// Result returned by the external engine.
+  external: serde_json::Value,
+}
+
+/// Summary of evaluations in the shadow by the external engine.
+#[derive(Serialize)]
+struct ShadowSummaryDto<'a> {
+  /// Base URL of the external endpoint.
+  url: &'a str,
+  /// Number of evaluations with the same result returned by the external engine.
+  matched: u64,
+  /// Number of evaluations with different result returned by the external engine.
+  mismatched: u64,
+  /// Number of evaluations that could not be forwarded.
+  failed: u64,
+  /// Number of evaluations not forwarded because the queue was full.
+  dropped: u64,
+  /// Most recent mismatches.
+  mismatches: Vec<ShadowMismatchDto<'a>>,
+}
+
+impl<'a> From<&'a ShadowSummary> for ShadowSummaryDto<'a> {
+  fn from(summary: &'a ShadowSummary) -> Self {
+    let json = |s: &str| serde_json::from_str(s).unwrap_or_else(|_| serde_json::Value::String(s.to_string()));
+    Self {
+      url: summary.url(),
+      matched: summary.matched(),
+      mismatched: summary.mismatched(),
+      failed: summary.failed(),
+      dropped: summary.dropped(),
+      mismatches: summary
+        .mismatches()
+        .iter()
+        .map(|mismatch| ShadowMismatchDto {
+          invocable_path: mismatch.invocable_path(),
+          input_data: json(mismatch.input_data()),
+          result: json(mismatch.result()),
+          external: json(mismatch.external()),
+        })
+        .collect(),
+    }
+  }
+}
+
+/// Handler for retrieving the summary of evaluations in the shadow by the external engine.
+#[get("/shadow")]
+async fn shadow_summary(data: web::Data<ApplicationData>) -> HttpResponse {
+  let Some(summary) = data.workspaces.shadow_summary() else {
+    return not_found().await;
+  };
+  match serde_json::to_string(&ShadowSummaryDto::from(&summary)) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Evaluation statistics of a single invocable.
+#[derive(Serialize)]
+struct InvocableStatsDto<'a> {
+  /// Path of the invocable.
+  #[serde(rename = "invocablePath")]
+  invocable_path: &'a str,
+  /// Number of evaluations.
+  evaluations: u64,
+  /// Number of failed evaluations.
+  failures: u64,
+  /// Mean latency of all evaluations, in microseconds.
+  #[serde(rename = "meanLatency")]
+  mean_latency: u128,
+  /// Median latency of recent evaluations, in microseconds.
+  #[serde(rename = "p50Latency")]
+  p50_latency: u128,
+  /// 95th percentile of latency of recent evaluations, in microseconds.
+  #[serde(rename = "p95Latency")]
+  p95_latency: u128,
+  /// 99th percentile of latency of recent evaluations, in microseconds.
+  #[serde(rename = "p99Latency")]
+  p99_latency: u128,
+}
+
+impl<'a> From<&'a InvocableStats> for InvocableStatsDto<'a> {
+  fn from(stats: &'a InvocableStats) -> Self {
+    Self {
+      invocable_path: stats.invocable_path(),
+      evaluations: stats.evaluations(),
+      failures: stats.failures(),
+      mean_latency: stats.mean().as_micros(),
+      p50_latency: stats.p50().as_micros(),
+      p95_latency: stats.p95().as_micros(),
+      p99_latency: stats.p99().as_micros(),
+    }
+  }
+}
+
+/// Handler for retrieving evaluation statistics of invocables.
+#[get("/stats")]
+async fn invocable_stats(data: web::Data<ApplicationData>) -> HttpResponse {
+  let stats = data.workspaces.stats();
+  match serde_json::to_string(&stats.iter().map(InvocableStatsDto::from).collect::<Vec<_>>()) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
+    Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Decision service exposed with dedicated route.
+#[derive(Serialize)]
+struct ServiceDto<'a> {
+  /// Path of the decision service.
+  path: &'a str,
+  /// Name of the decision service.
+  name: &'a str,
+  /// Names of input parameters.
+  parameters: Vec<String>,
+}
+
+impl<'a> From<&'a ServiceDescriptor> for ServiceDto<'a> {
+  fn from(service: &'a ServiceDescriptor) -> Self {
+    Self {
+      path: service.path(),
+      name: service.name(),
+      parameters: service.parameters().iter().map(|(name, _)| name.to_string()).collect(),
+    }
+  }
+}
+
+/// Handler for retrieving decision services exposed with dedicated routes.
+#[get("/services")]
+async fn services(data: web::Data<ApplicationData>) -> HttpResponse {
+  let services = data.workspaces.services();
+  match serde_json::to_string(&services.iter().map(ServiceDto::from).collect::<Vec<_>>()) {
+    Ok(json) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"data":{json}}}"#)),
     Err(reason) => HttpResponse::Ok().content_type(CONTENT_TYPE).body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
   }
 }
 
+/// Handler for retrieving JSON Schemas of the request and the response of the decision service.
+#[get("/services/{path:.*}/schema")]
+async fn service_schema(path: web::Path<String>, data: web::Data<ApplicationData>) -> HttpResponse {
+  match data.workspaces.service(&path) {
+    Ok(service) => HttpResponse::Ok()
+      .content_type(CONTENT_TYPE)
+      .body(format!(r#"{{"data":{{"request":{},"response":{}}}}}"#, service.request_schema(), service.response_schema())),
+    Err(reason) => HttpResponse::NotFound()
+      .content_type(CONTENT_TYPE)
+      .body(format!(r#"{{"errors":[{{"detail":"{reason}"}}]}}"#)),
+  }
+}
+
+/// Handler for evaluating the decision service, the request body contains
+/// values of input parameters of the decision service.
+#[post("/services/{path:.*}")]
+async fn evaluate_service(path: web::Path<String>, params: web::Query<SelectParams>, request_body: String, data: web::Data<ApplicationData>, request: HttpRequest) -> HttpResponse {
+  let result = dmntk_evaluator::evaluate_context(&FeelScope::default(), &request_body).and_then(|input_data| data.workspaces.evaluate_service(&path, &input_data));
+  evaluation_response(&request, select(&params, result))
+}
+
 /// Handler for 404 errors.
 async fn not_found() -> HttpResponse {
   HttpResponse::NotFound().content_type(CONTENT_TYPE).body(r#"{"errors":[{"detail":"endpoint not found"}]}"#)
 }
 
+/// Configures endpoints changing deployed models at runtime, registered only when enabled
+/// with `DMNTK_DEPLOYMENTS` environment variable, these endpoints are not authenticated.
+fn deployments_config(cfg: &mut web::ServiceConfig) {
+  cfg.service(deploy);
+  cfg.service(deployment_source);
+  cfg.service(undeploy);
+  cfg.service(deploy_canary);
+  cfg.service(canaries);
+  cfg.service(route_canary);
+  cfg.service(promote_canary);
+  cfg.service(remove_canary);
+}
+
 #[cfg(feature = "tck")]
 fn config(cfg: &mut web::ServiceConfig) {
   cfg.service(crate::tck::post_tck_evaluate);
+  cfg.service(evaluate_tenant);
+  cfg.service(tenant_metrics);
+  cfg.service(deployment_status);
+  cfg.service(invocable_stats);
+  cfg.service(shadow_summary);
+  cfg.service(services);
+  cfg.service(service_schema);
+  cfg.service(evaluate_service);
+  #[cfg(feature = "graphql")]
+  cfg.service(crate::graphql::evaluate_graphql);
+  #[cfg(feature = "graphql")]
+  cfg.service(crate::graphql::graphql_schema);
+  cfg.service(crate::feel::evaluate_feel);
 }
 
 #[cfg(not(feature = "tck"))]
 fn config(cfg: &mut web::ServiceConfig) {
   cfg.service(evaluate);
+  cfg.service(evaluate_tenant);
+  cfg.service(tenant_metrics);
+  cfg.service(deployment_status);
+  cfg.service(invocable_stats);
+  cfg.service(shadow_summary);
+  cfg.service(services);
+  cfg.service(service_schema);
+  cfg.service(evaluate_service);
+  #[cfg(feature = "graphql")]
+  cfg.service(crate::graphql::evaluate_graphql);
+  #[cfg(feature = "graphql")]
+  cfg.service(crate::graphql::graphql_schema);
+  cfg.service(crate::feel::evaluate_feel);
 }
 
 /// Starts the server.
-pub async fn start_server(opt_host: Option<String>, opt_port: Option<String>, opt_dir: Option<String>, colors: ColorPalette, verbose: bool) -> io::Result<()> {
+pub async fn start_server(
+  opt_host: Option<String>,
+  opt_port: Option<String>,
+  opt_dir: Option<String>,
+  opt_tenants_dir: Option<String>,
+  colors: ColorPalette,
+  verbose: bool,
+) -> io::Result<()> {
+  #[cfg(feature = "otel")]
+  let tracer_provider = crate::otel::init_tracing();
+  let conformance_level = get_conformance_level();
+  let mut workspaces = Workspaces::new_at_conformance_level(&get_root_dir(opt_dir), colors.clone(), verbose, conformance_level).with_webhooks(get_webhooks());
+  let mut opt_tenants = get_tenants_dir(opt_tenants_dir).map(|tenants_dir| Tenants::new(tenants_dir, colors.clone(), verbose).with_conformance_level(conformance_level));
+  if let Some(audit_sink) = get_audit_sink() {
+    workspaces = workspaces.with_audit_sink(Arc::clone(&audit_sink));
+    opt_tenants = opt_tenants.map(|tenants| tenants.with_audit_sink(audit_sink));
+  }
+  if let Some(external_shadow) = get_external_shadow() {
+    workspaces = workspaces.with_external_shadow(external_shadow);
+  }
   let application_data = web::Data::new(ApplicationData {
-    workspaces: Arc::new(Workspaces::new(&get_root_dir(opt_dir), colors.clone(), verbose)),
+    workspaces: Arc::new(workspaces),
+    tenants: opt_tenants.map(Arc::new),
+    feel_limits: FeelLimits::from_env(),
+    #[cfg(feature = "otel")]
+    tracing: tracer_provider.is_some(),
   });
+  let deployments_enabled = !cfg!(feature = "tck") && get_deployments_enabled();
   let address = get_server_address(opt_host, opt_port);
   println!("{1}dmntk{0} {2}{address}{0}", colors.reset(), colors.blue(), colors.yellow());
+  if deployments_enabled {
+    println!("{1}Deployments at runtime enabled.{0}", colors.reset(), colors.yellow());
+  }
   HttpServer::new(move || {
-    App::new()
+    let app = App::new()
       .app_data(application_data.clone())
       .app_data(web::PayloadConfig::new(4 * 1024 * 1024))
-      .configure(config)
-      .default_service(web::route().to(not_found))
+      .configure(config);
+    let app = if deployments_enabled { app.configure(deployments_config) } else { app };
+    app.default_service(web::route().to(not_found))
   })
   .bind(address)?
   .run()
-  .await
+  .await?;
+  #[cfg(feature = "otel")]
+  if let Some(tracer_provider) = tracer_provider {
+    // export spans remaining in the batch
+    let _ = tracer_provider.shutdown();
+  }
+  Ok(())
 }
 
 /// Returns the host address and the port number, the server will start to listen on.
@@ -142,3 +791,157 @@ fn get_root_dir(opt_dir: Option<String>) -> PathBuf {
   }
   current_dir_path
 }
+
+/// Returns the directory containing directories of tenants, if specified.
+///
+/// The directory may be given as a parameter or in `DMNTK_TENANTS_DIR` environment variable,
+/// the parameter has higher priority. Workspaces of tenants are not served when no directory is specified.
+fn get_tenants_dir(opt_tenants_dir: Option<String>) -> Option<PathBuf> {
+  let mut tenants_dir = None;
+  if let Ok(s) = env::var(DMNTK_TENANTS_DIR_VARIABLE) {
+    let dir_path = Path::new(&s);
+    if dir_path.is_dir() {
+      tenants_dir = Some(dir_path.into());
+    } else {
+      eprintln!("invalid directory specified in environment variable {}: {}", DMNTK_TENANTS_DIR_VARIABLE, s);
+    }
+  }
+  if let Some(s) = opt_tenants_dir {
+    let dir_path = Path::new(&s);
+    if dir_path.is_dir() {
+      tenants_dir = Some(dir_path.into());
+    } else {
+      eprintln!("invalid directory specified as command option: {}", s);
+    }
+  }
+  tenants_dir
+}
+
+/// Returns webhooks notified on deployment lifecycle events.
+///
+/// Webhook URLs are given in `DMNTK_WEBHOOKS` environment variable, separated with commas.
+/// No webhooks are notified when the variable is not set.
+fn get_webhooks() -> Webhooks {
+  let urls = env::var(DMNTK_WEBHOOKS_VARIABLE)
+    .map(|urls| urls.split(',').map(str::trim).filter(|url| !url.is_empty()).map(str::to_string).collect())
+    .unwrap_or_default();
+  Webhooks::new(urls)
+}
+
+/// Returns the conformance level decision models are deployed at.
+///
+/// The conformance level is given in `DMNTK_CONFORMANCE_LEVEL` environment variable,
+/// as `1`, `2` or `3` (optionally prefixed with `CL`). When the variable is not set
+/// or its value is not valid, models are deployed at conformance level 3.
+pub fn get_conformance_level() -> ConformanceLevel {
+  let mut conformance_level = ConformanceLevel::default();
+  if let Ok(s) = env::var(DMNTK_CONFORMANCE_LEVEL_VARIABLE) {
+    match s.parse() {
+      Ok(level) => conformance_level = level,
+      Err(reason) => eprintln!(
+        "invalid conformance level specified in environment variable {}: {}",
+        DMNTK_CONFORMANCE_LEVEL_VARIABLE, reason
+      ),
+    }
+  }
+  conformance_level
+}
+
+/// Returns the language of error messages.
+///
+/// The language is given in `DMNTK_LANGUAGE` environment variable, as `en`, `pl` or `de`
+/// (optionally followed by a region and encoding, e.g. `pl_PL.UTF-8`). When the variable
+/// is not set or its value is not valid, error messages are in English.
+pub fn get_language() -> Language {
+  let mut language = Language::default();
+  if let Ok(s) = env::var(DMNTK_LANGUAGE_VARIABLE) {
+    match s.parse() {
+      Ok(selected_language) => language = selected_language,
+      Err(reason) => eprintln!("invalid language specified in environment variable {}: {}", DMNTK_LANGUAGE_VARIABLE, reason),
+    }
+  }
+  language
+}
+
+/// Returns `true` when endpoints changing deployed models at runtime are enabled.
+///
+/// Endpoints are enabled when `DMNTK_DEPLOYMENTS` environment variable is set to `true`, `yes`, `on` or `1`.
+/// These endpoints are not authenticated, so they are disabled by default.
+fn get_deployments_enabled() -> bool {
+  env::var(DMNTK_DEPLOYMENTS_VARIABLE).is_ok_and(|value| is_enabled(&value))
+}
+
+/// Returns `true` when the value of the environment variable enables a feature.
+fn is_enabled(value: &str) -> bool {
+  matches!(value.trim().to_ascii_lowercase().as_str(), "true" | "yes" | "on" | "1")
+}
+
+/// Returns the sink receiving audit records of evaluations.
+///
+/// The sink is given in `DMNTK_AUDIT` environment variable, `stdout` writes audit records
+/// to standard output, any other value is the name of the file audit records are appended to.
+/// Evaluations are not audited when the variable is not set or the file can not be opened.
+fn get_audit_sink() -> Option<Arc<dyn AuditSink>> {
+  let s = env::var(DMNTK_AUDIT_VARIABLE).ok()?;
+  match s.trim() {
+    "" => None,
+    "stdout" => Some(Arc::new(StdoutAuditSink)),
+    file_name => match FileAuditSink::new(Path::new(file_name)) {
+      Ok(audit_sink) => Some(Arc::new(audit_sink)),
+      Err(reason) => {
+        eprintln!("invalid audit sink specified in environment variable {}: {}", DMNTK_AUDIT_VARIABLE, reason);
+        None
+      }
+    },
+  }
+}
+
+/// Returns the external engine evaluating invocables in the shadow.
+///
+/// The base URL of the external DMN endpoint is given in `DMNTK_SHADOW_URL` environment variable,
+/// input data of each evaluation is forwarded to this URL followed by the invocable path.
+/// No evaluations are forwarded when the variable is not set.
+fn get_external_shadow() -> Option<ExternalShadow> {
+  let url = env::var(DMNTK_SHADOW_URL_VARIABLE).ok()?;
+  let url = url.trim();
+  if url.is_empty() {
+    None
+  } else {
+    Some(ExternalShadow::new(url))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_is_enabled() {
+    assert!(is_enabled("true"));
+    assert!(is_enabled(" YES "));
+    assert!(is_enabled("on"));
+    assert!(is_enabled("1"));
+    assert!(!is_enabled(""));
+    assert!(!is_enabled("false"));
+    assert!(!is_enabled("0"));
+    assert!(!is_enabled("enabled"));
+  }
+
+  #[test]
+  fn test_prefers_cbor() {
+    assert!(prefers_cbor("application/cbor"));
+    assert!(prefers_cbor("application/json;q=0.5, application/cbor"));
+    assert!(prefers_cbor("application/cbor, */*;q=0.8"));
+    assert!(prefers_cbor("application/cbor, application/*"));
+    assert!(prefers_cbor("APPLICATION/CBOR ; Q=0.9"));
+    assert!(!prefers_cbor("application/json"));
+    assert!(!prefers_cbor("*/*"));
+    assert!(!prefers_cbor("application/*"));
+    assert!(!prefers_cbor("application/cbor;q=0"));
+    assert!(!prefers_cbor("application/cbor;q=0.5, application/json"));
+    assert!(!prefers_cbor("application/cbor, application/json"));
+    assert!(!prefers_cbor("application/cbor;q=invalid"));
+    assert!(!prefers_cbor("application/cbor-seq"));
+    assert!(!prefers_cbor("text/html, application/xhtml+xml;q=0.9, */*;q=0.8"));
+  }
+}
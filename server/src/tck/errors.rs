@@ -1,11 +1,11 @@
 //! # Error definitions for TCK handler
 
-use dmntk_common::{DmntkError, ToErrorMessage};
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
 
 /// Server errors for TCK handler.
 #[derive(ToErrorMessage)]
-struct TckServerError(String);
+struct TckServerError(Message);
 
 pub fn err_missing_attribute(name: &str) -> DmntkError {
-  TckServerError(format!("missing attribute: {name}")).into()
+  TckServerError(message!("missing attribute: {}", name)).into()
 }
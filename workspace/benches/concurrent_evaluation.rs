@@ -0,0 +1,88 @@
+//! # Concurrent evaluation benchmarks
+//!
+//! Measures the time of evaluating a deployed model from many threads at once,
+//! with and without models being deployed at the same time.
+
+#![feature(test)]
+
+extern crate test;
+
+use dmntk_common::{gen_id, ColorMode};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+use dmntk_workspace::Workspaces;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{fs, thread};
+use test::Bencher;
+
+/// Number of threads evaluating the model.
+const THREADS: usize = 16;
+
+/// Number of evaluations performed by each thread in a single iteration.
+const EVALUATIONS: usize = 100;
+
+const MODEL: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/rates" name="rates" id="_rates" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <inputData name="Amount" id="_amount">
+        <variable typeRef="number" name="Amount"/>
+    </inputData>
+    <decision name="Fee" id="_fee">
+        <variable typeRef="number" name="Fee"/>
+        <informationRequirement>
+            <requiredInput href="#_amount"/>
+        </informationRequirement>
+        <literalExpression>
+            <text>Amount * 0.1</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"##;
+
+/// Creates workspaces with deployed model.
+fn workspaces() -> Workspaces {
+  let dir = std::env::temp_dir().join(gen_id());
+  fs::create_dir_all(&dir).unwrap();
+  let workspaces = Workspaces::new(&dir, ColorMode::Off.into(), false);
+  fs::remove_dir_all(&dir).unwrap();
+  workspaces.deploy(MODEL).unwrap();
+  workspaces
+}
+
+/// Evaluates the deployed model from many threads.
+fn evaluate_concurrently(workspaces: &Workspaces, input_data: &FeelContext) {
+  thread::scope(|scope| {
+    for _ in 0..THREADS {
+      scope.spawn(|| {
+        for _ in 0..EVALUATIONS {
+          test::black_box(workspaces.evaluate("io/dmntk/rates/Fee", input_data).unwrap());
+        }
+      });
+    }
+  });
+}
+
+#[bench]
+fn bench_concurrent_evaluation(b: &mut Bencher) {
+  let workspaces = workspaces();
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value_number!(200));
+  b.iter(|| evaluate_concurrently(&workspaces, &input_data));
+}
+
+#[bench]
+fn bench_concurrent_evaluation_while_deploying(b: &mut Bencher) {
+  let workspaces = workspaces();
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value_number!(200));
+  let finished = AtomicBool::new(false);
+  thread::scope(|scope| {
+    scope.spawn(|| {
+      while !finished.load(Ordering::Relaxed) {
+        workspaces.deploy(MODEL).unwrap();
+      }
+    });
+    b.iter(|| evaluate_concurrently(&workspaces, &input_data));
+    finished.store(true, Ordering::Relaxed);
+  });
+}
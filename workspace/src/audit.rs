@@ -0,0 +1,329 @@
+//! # Audit of evaluations
+//!
+//! When an audit sink is configured, an audit record is passed to the sink after each evaluation
+//! of an invocable. The record contains input data, the result of the evaluation, rules of decision
+//! tables fired during the evaluation, the duration of the evaluation and the version of the model.
+//! Models deployed at runtime are versioned by the identifier of the deployment, models loaded
+//! from the workspace directory are versioned by the digest of their source.
+//!
+//! Input data and results are stored as typed values (see [ValueDto]),
+//! so audited evaluations may be replayed with exactly the same input data.
+
+use crate::errors::*;
+use dmntk_common::{gen_id, DmntkError, Jsonify, Result};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::dto::ValueDto;
+use dmntk_feel::values::Value;
+use dmntk_model_evaluator::{EvaluationListener, FiredRule};
+use serde_json::json;
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Audit record of a single evaluation.
+#[derive(Debug, Clone)]
+pub struct AuditRecord {
+  /// Unique identifier of the record.
+  id: String,
+  /// Date and time when the evaluation started, in RFC 3339 format.
+  timestamp: String,
+  /// Path of the evaluated invocable.
+  invocable_path: String,
+  /// Namespace of the model defining the invocable.
+  namespace: String,
+  /// Name of the invocable.
+  invocable_name: String,
+  /// Version of the model defining the invocable.
+  model_version: String,
+  /// Input data of the evaluation.
+  input_data: FeelContext,
+  /// Result of the evaluation.
+  result: Value,
+  /// Rules of decision tables fired during the evaluation.
+  fired_rules: Vec<FiredRule>,
+  /// Duration of the evaluation.
+  duration: Duration,
+}
+
+impl AuditRecord {
+  /// Returns the unique identifier of the record.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Returns date and time when the evaluation started, in RFC 3339 format.
+  pub fn timestamp(&self) -> &str {
+    &self.timestamp
+  }
+
+  /// Returns the path of the evaluated invocable.
+  pub fn invocable_path(&self) -> &str {
+    &self.invocable_path
+  }
+
+  /// Returns the namespace of the model defining the invocable.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the name of the invocable.
+  pub fn invocable_name(&self) -> &str {
+    &self.invocable_name
+  }
+
+  /// Returns the version of the model defining the invocable.
+  pub fn model_version(&self) -> &str {
+    &self.model_version
+  }
+
+  /// Returns input data of the evaluation.
+  pub fn input_data(&self) -> &FeelContext {
+    &self.input_data
+  }
+
+  /// Returns the result of the evaluation.
+  pub fn result(&self) -> &Value {
+    &self.result
+  }
+
+  /// Returns rules of decision tables fired during the evaluation, in the order of firing.
+  pub fn fired_rules(&self) -> &[FiredRule] {
+    &self.fired_rules
+  }
+
+  /// Returns the duration of the evaluation.
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+}
+
+impl Jsonify for AuditRecord {
+  /// Converts the audit record into a single line JSON document.
+  fn jsonify(&self) -> String {
+    let typed = |value: &Value| ValueDto::try_from(value).ok().and_then(|dto| serde_json::to_value(dto).ok());
+    let fired_rules = self
+      .fired_rules
+      .iter()
+      .map(|fired_rule| {
+        json!({
+          "decisionTableId": fired_rule.decision_table_id(),
+          "ruleNumber": fired_rule.rule_number(),
+          "description": fired_rule.description(),
+        })
+      })
+      .collect::<Vec<serde_json::Value>>();
+    json!({
+      "id": self.id,
+      "timestamp": self.timestamp,
+      "invocable": self.invocable_path,
+      "namespace": self.namespace,
+      "name": self.invocable_name,
+      "modelVersion": self.model_version,
+      "inputs": typed(&Value::Context(self.input_data.clone())),
+      "outputs": typed(&self.result),
+      "firedRules": fired_rules,
+      "durationMicros": self.duration.as_micros() as u64,
+    })
+    .to_string()
+  }
+}
+
+impl FromStr for AuditRecord {
+  type Err = DmntkError;
+  /// Parses the audit record from the JSON document created by [Jsonify::jsonify].
+  fn from_str(s: &str) -> Result<Self> {
+    let document = serde_json::from_str::<serde_json::Value>(s).map_err(|reason| err_invalid_audit_record(reason.to_string()))?;
+    let text = |name: &str| {
+      document[name]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| err_invalid_audit_record(format!("missing '{name}'")))
+    };
+    let typed = |name: &str| {
+      let dto = serde_json::from_value::<ValueDto>(document[name].clone()).map_err(|reason| err_invalid_audit_record(reason.to_string()))?;
+      Value::try_from(&dto)
+    };
+    let Value::Context(input_data) = typed("inputs")? else {
+      return Err(err_invalid_audit_record("inputs are not a context"));
+    };
+    let fired_rules = document["firedRules"]
+      .as_array()
+      .map(|fired_rules| {
+        fired_rules
+          .iter()
+          .map(|fired_rule| {
+            FiredRule::new(
+              fired_rule["decisionTableId"].as_str().unwrap_or_default(),
+              fired_rule["ruleNumber"].as_u64().unwrap_or_default() as usize,
+              fired_rule["description"].as_str().map(str::to_string),
+              vec![],
+            )
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+    Ok(Self {
+      id: text("id")?,
+      timestamp: text("timestamp")?,
+      invocable_path: text("invocable")?,
+      namespace: text("namespace")?,
+      invocable_name: text("name")?,
+      model_version: text("modelVersion")?,
+      input_data,
+      result: typed("outputs")?,
+      fired_rules,
+      duration: Duration::from_micros(document["durationMicros"].as_u64().unwrap_or_default()),
+    })
+  }
+}
+
+/// Receiver of audit records.
+///
+/// Sinks are shared between threads evaluating invocables concurrently,
+/// so implementations must synchronize access to the underlying storage.
+pub trait AuditSink: Send + Sync {
+  /// Stores the audit record of a finished evaluation.
+  fn store(&self, record: &AuditRecord) -> Result<()>;
+}
+
+/// Audit sink writing records as JSON lines to standard output.
+#[derive(Debug, Default)]
+pub struct StdoutAuditSink;
+
+impl AuditSink for StdoutAuditSink {
+  /// Writes the record as a single JSON line to standard output.
+  fn store(&self, record: &AuditRecord) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{}", record.jsonify()).map_err(|reason| err_storing_audit_record(reason.to_string()))
+  }
+}
+
+/// Audit sink appending records as JSON lines to a file.
+pub struct FileAuditSink {
+  /// Writer of the file with audit records.
+  writer: Mutex<BufWriter<File>>,
+}
+
+impl FileAuditSink {
+  /// Creates an audit sink appending records to specified file, the file is created when it does not exist.
+  pub fn new(path: &Path) -> Result<Self> {
+    let file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(path)
+      .map_err(|reason| err_opening_audit_file(&path.display().to_string(), reason.to_string()))?;
+    Ok(Self {
+      writer: Mutex::new(BufWriter::new(file)),
+    })
+  }
+}
+
+impl AuditSink for FileAuditSink {
+  /// Appends the record as a single JSON line, the line is flushed immediately,
+  /// so records of all finished evaluations are stored also when the server stops.
+  fn store(&self, record: &AuditRecord) -> Result<()> {
+    let mut writer = self.writer.lock().unwrap();
+    writeln!(writer, "{}", record.jsonify())
+      .and_then(|_| writer.flush())
+      .map_err(|reason| err_storing_audit_record(reason.to_string()))
+  }
+}
+
+/// Builder of the audit record, collects rules fired during the evaluation
+/// and forwards all events to the listener registered by the caller.
+pub(crate) struct AuditListener {
+  /// Rules fired during the evaluation.
+  fired_rules: RefCell<Vec<FiredRule>>,
+  /// Listener registered by the caller.
+  opt_listener: Option<Rc<dyn EvaluationListener>>,
+}
+
+impl AuditListener {
+  /// Creates a listener forwarding events to specified listener.
+  pub(crate) fn new(opt_listener: Option<Rc<dyn EvaluationListener>>) -> Self {
+    Self {
+      fired_rules: RefCell::new(vec![]),
+      opt_listener,
+    }
+  }
+
+  /// Builds the audit record of the finished evaluation.
+  #[allow(clippy::too_many_arguments)]
+  pub(crate) fn record(
+    &self,
+    timestamp: String,
+    invocable_path: &str,
+    namespace: &str,
+    invocable_name: &str,
+    model_version: &str,
+    input_data: &FeelContext,
+    result: &Value,
+    duration: Duration,
+  ) -> AuditRecord {
+    AuditRecord {
+      id: gen_id(),
+      timestamp,
+      invocable_path: invocable_path.to_string(),
+      namespace: namespace.to_string(),
+      invocable_name: invocable_name.to_string(),
+      model_version: model_version.to_string(),
+      input_data: input_data.clone(),
+      result: result.clone(),
+      fired_rules: self.fired_rules.take(),
+      duration,
+    }
+  }
+}
+
+impl EvaluationListener for AuditListener {
+  fn rule_fired(&self, fired_rule: &FiredRule) {
+    self.fired_rules.borrow_mut().push(fired_rule.clone());
+    if let Some(listener) = &self.opt_listener {
+      listener.rule_fired(fired_rule);
+    }
+  }
+
+  fn null_produced(&self, output_name: &str, causes: &[&str]) {
+    if let Some(listener) = &self.opt_listener {
+      listener.null_produced(output_name, causes);
+    }
+  }
+
+  fn decision_started(&self, decision_name: &str) {
+    if let Some(listener) = &self.opt_listener {
+      listener.decision_started(decision_name);
+    }
+  }
+
+  fn decision_finished(&self, decision_name: &str, result: &Value) {
+    if let Some(listener) = &self.opt_listener {
+      listener.decision_finished(decision_name, result);
+    }
+  }
+
+  fn decision_table_started(&self, decision_table_id: &str) {
+    if let Some(listener) = &self.opt_listener {
+      listener.decision_table_started(decision_table_id);
+    }
+  }
+
+  fn decision_table_finished(&self, decision_table_id: &str, result: &Value) {
+    if let Some(listener) = &self.opt_listener {
+      listener.decision_table_finished(decision_table_id, result);
+    }
+  }
+}
+
+/// Returns the version of the model loaded from the workspace directory,
+/// computed as the FNV-1a digest of the model source.
+pub(crate) fn model_version(source: &str) -> String {
+  let digest = source
+    .bytes()
+    .fold(0xcbf29ce484222325_u64, |digest, byte| (digest ^ byte as u64).wrapping_mul(0x100000001b3));
+  format!("{digest:016x}")
+}
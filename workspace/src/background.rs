@@ -0,0 +1,68 @@
+//! # Background work
+//!
+//! Work following evaluations that must not delay responses, like evaluating
+//! in the shadow and comparing results, is queued and done by a fixed number
+//! of background threads. The queue is bounded, when it is full, submitted tasks
+//! are dropped and counted, so under heavy load the background work never grows
+//! beyond the configured number of threads and queued tasks.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Task done in background.
+type Task = Box<dyn FnOnce() + Send>;
+
+/// Fixed number of background threads taking tasks from a bounded queue.
+///
+/// Threads finish when the worker is dropped, after completing already queued tasks.
+pub(crate) struct BackgroundWorker {
+  /// Sending end of the queue of tasks.
+  sender: SyncSender<Task>,
+  /// Number of tasks dropped because the queue was full.
+  dropped: AtomicU64,
+}
+
+impl BackgroundWorker {
+  /// Creates a worker with specified number of threads and specified capacity of the queue,
+  /// threads are named after the worker name followed by the thread number.
+  pub(crate) fn new(name: &str, threads: usize, capacity: usize) -> Self {
+    let (sender, receiver) = sync_channel::<Task>(capacity);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for number in 1..=threads.max(1) {
+      let receiver = Arc::clone(&receiver);
+      if let Err(reason) = thread::Builder::new().name(format!("{name}-{number}")).spawn(move || run(&receiver)) {
+        eprintln!("starting background thread '{name}-{number}' failed with reason: {reason}");
+      }
+    }
+    Self {
+      sender,
+      dropped: AtomicU64::new(0),
+    }
+  }
+
+  /// Queues the task, returns `false` when the task was dropped because the queue is full.
+  pub(crate) fn submit(&self, task: impl FnOnce() + Send + 'static) -> bool {
+    let submitted = self.sender.try_send(Box::new(task)).is_ok();
+    if !submitted {
+      self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+    submitted
+  }
+
+  /// Returns the number of tasks dropped because the queue was full.
+  pub(crate) fn dropped(&self) -> u64 {
+    self.dropped.load(Ordering::Relaxed)
+  }
+}
+
+/// Does queued tasks until the sending end of the queue is dropped.
+fn run(receiver: &Mutex<Receiver<Task>>) {
+  loop {
+    let Ok(task) = receiver.lock().unwrap().recv() else {
+      break;
+    };
+    task();
+  }
+}
@@ -1,12 +1,18 @@
 //! # Workspace builder
 
+use crate::audit::model_version;
+use crate::status::{DeploymentFailure, DeploymentStatus};
 use dmntk_common::{to_rdnn, ColorPalette};
+use dmntk_feel::ConformanceLevel;
 use dmntk_model::Definitions;
 use dmntk_model_evaluator::ModelEvaluator;
 use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::io::{IsTerminal, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
+use std::{fs, io, thread};
 use urlencoding::encode;
 use walkdir::WalkDir;
 
@@ -16,14 +22,10 @@ pub struct WorkspaceBuilder {
   colors: ColorPalette,
   /// Flag indicating the level of verbosity.
   verbose: bool,
-  /// Total number of model files found.
-  file_count: usize,
-  /// The number of sucessfully loaded models.
-  loaded_count: usize,
-  /// The number of models that failed to load.
-  failed_loads_count: usize,
-  /// The number of workspaces that failed to load.
-  failed_deployments_count: usize,
+  /// Conformance level the decision models are deployed at.
+  conformance_level: ConformanceLevel,
+  /// Summary of loading and deploying models.
+  pub(crate) status: DeploymentStatus,
   /// Map: workspace name -> model_definitions
   workspace_definitions: HashMap<String, Vec<Definitions>>,
   /// Map: workspace name -> namespaces in workspace
@@ -34,51 +36,73 @@ pub struct WorkspaceBuilder {
   pub(crate) invocables: HashMap<String, (String, String, String)>,
   /// Map: workspace name -> model evaluator
   pub(crate) evaluators: HashMap<String, Arc<ModelEvaluator>>,
+  /// Map: (workspace name, namespace) -> model version
+  pub(crate) model_versions: HashMap<(String, String), String>,
 }
 
 impl WorkspaceBuilder {
   /// Creates a new workspace builder.
-  pub fn new(colors: ColorPalette, verbose: bool) -> Self {
+  pub fn new(colors: ColorPalette, verbose: bool, conformance_level: ConformanceLevel) -> Self {
     Self {
       colors,
       verbose,
-      file_count: 0,
-      loaded_count: 0,
-      failed_loads_count: 0,
-      failed_deployments_count: 0,
+      conformance_level,
+      status: Default::default(),
       workspace_definitions: Default::default(),
       workspace_namespaces: Default::default(),
       workspace_models: Default::default(),
       invocables: Default::default(),
       evaluators: Default::default(),
+      model_versions: Default::default(),
     }
   }
 
   /// Loads decision models from files and builds the workspaces.
+  ///
+  /// Model files are parsed and workspaces are deployed in parallel,
+  /// using all available processors.
   pub fn load_decision_models(&mut self, dir: &Path) {
-    // load models
+    let started = Instant::now();
+    // find model files
+    let mut files = vec![];
     for entry_result in WalkDir::new(dir).into_iter() {
       match entry_result {
         Ok(entry) => {
           let path = entry.path();
           if path.is_file() && path.extension().map_or(false, |ext| ext == "dmn") {
-            self.file_count += 1;
-            let workspace_name = self.workspace_name(dir, path);
-            self.load_file(&workspace_name, path);
+            files.push((self.workspace_name(dir, path), path.to_path_buf()));
           }
         }
-        Err(reason) => self.err_file_operation(reason.to_string()),
+        Err(reason) => self.err_file_operation(reason.path().map(|path| path.display().to_string()).unwrap_or_default(), reason.to_string()),
       }
     }
+    self.status.file_count = files.len();
+    // parse models
+    let progress = Progress::new("Loading models", files.len(), self.colors.clone());
+    let parsed = parallel_map(&files, |(_, file)| Self::parse_file(file), |done| progress.display(done));
+    progress.clear();
+    for ((workspace_name, file), parsed_result) in files.iter().zip(parsed) {
+      self.load_definitions(workspace_name, file, parsed_result);
+    }
     // build evaluators
-    for (workspace_name, loaded_definitions) in &self.workspace_definitions {
-      match ModelEvaluator::new(loaded_definitions) {
+    let mut workspace_definitions = self.workspace_definitions.iter().collect::<Vec<(&String, &Vec<Definitions>)>>();
+    workspace_definitions.sort_by_key(|(workspace_name, _)| *workspace_name);
+    let progress = Progress::new("Deploying workspaces", workspace_definitions.len(), self.colors.clone());
+    let deployed = parallel_map(
+      &workspace_definitions,
+      |(_, loaded_definitions)| ModelEvaluator::new_at_conformance_level(loaded_definitions, self.conformance_level),
+      |done| progress.display(done),
+    );
+    progress.clear();
+    let workspace_names = workspace_definitions.into_iter().map(|(workspace_name, _)| workspace_name.clone()).collect::<Vec<String>>();
+    for (workspace_name, deployed_result) in workspace_names.into_iter().zip(deployed) {
+      match deployed_result {
         Ok(evaluator) => {
-          self.evaluators.insert(workspace_name.to_string(), evaluator);
+          self.evaluators.insert(workspace_name, evaluator);
         }
         Err(reason) => {
-          self.err_deployment_failure(workspace_name, reason.to_string());
-          self.failed_deployments_count += 1;
+          self.err_deployment_failure(&workspace_name, reason.to_string());
+          self.status.failed_deployments_count += 1;
         }
       }
     }
@@ -94,74 +118,79 @@ impl WorkspaceBuilder {
         self.invocables.insert(invocable_path.clone(), (workspace_name.clone(), namespace, invocable_name));
       }
     }
+    self.status.invocable_count = self.evaluators.values().map(|evaluator| evaluator.invocables().len()).sum();
+    self.status.duration = started.elapsed();
     // display summary
     self.display_summary();
   }
 
   /// Checks if namespaces are duplicated in workspace.
-  fn check_namespace_duplicates(&self, file: &Path, workspace_name: &str, namespace: &str) -> bool {
+  fn check_namespace_duplicates(&mut self, file: &Path, workspace_name: &str, namespace: &str) -> bool {
     if let Some(namespaces) = self.workspace_namespaces.get(workspace_name) {
       if namespaces.contains(namespace) {
-        let file_name = self.workspace_models.get(workspace_name).unwrap().get(namespace).unwrap();
-        self.err_duplicated_namespace(file, namespace, file_name);
+        let file_name = self.workspace_models.get(workspace_name).unwrap().get(namespace).unwrap().clone();
+        self.err_duplicated_namespace(file, namespace, &file_name);
         return false;
       }
     }
     true
   }
 
-  /// Loads decision model from file.
-  fn load_file(&mut self, workspace_name: &str, file: &Path) {
-    match fs::read_to_string(file) {
-      Ok(xml) => match dmntk_model::parse(&xml) {
-        Ok(definitions) => {
-          let namespace = definitions.namespace().to_string();
-          if to_rdnn(&namespace).is_some() {
-            if self.check_namespace_duplicates(file, workspace_name, &namespace) {
-              self
-                .workspace_definitions
-                .entry(workspace_name.to_string())
-                .and_modify(|loaded_definitions| {
-                  loaded_definitions.push(definitions.clone());
-                })
-                .or_insert(vec![definitions]);
-              self
-                .workspace_namespaces
-                .entry(workspace_name.to_string())
-                .and_modify(|loaded_namespaces| {
-                  loaded_namespaces.insert(namespace.clone());
-                })
-                .or_insert({
-                  let mut set = HashSet::new();
-                  set.insert(namespace.clone());
-                  set
-                });
-              self
-                .workspace_models
-                .entry(workspace_name.to_string())
-                .and_modify(|loaded_models| {
-                  loaded_models.insert(namespace.clone(), file.to_string_lossy().to_string());
-                })
-                .or_insert({
-                  let mut map = HashMap::new();
-                  map.insert(namespace.clone(), file.to_string_lossy().to_string());
-                  map
-                });
-              self.loaded_count += 1;
-            }
-          } else {
-            self.err_invalid_namespace(file, &namespace);
-            self.failed_loads_count += 1;
+  /// Parses decision model from file, returns parsed model with the version of the model.
+  /// Returns the reason of failure when the file could not be read or parsed.
+  fn parse_file(file: &Path) -> std::result::Result<(Definitions, String), String> {
+    let xml = fs::read_to_string(file).map_err(|reason| reason.to_string())?;
+    let definitions = dmntk_model::parse(&xml).map_err(|reason| reason.to_string())?;
+    Ok((definitions, model_version(&xml)))
+  }
+
+  /// Adds parsed decision model to workspace.
+  fn load_definitions(&mut self, workspace_name: &str, file: &Path, parsed_result: std::result::Result<(Definitions, String), String>) {
+    match parsed_result {
+      Ok((definitions, model_version)) => {
+        let namespace = definitions.namespace().to_string();
+        if to_rdnn(&namespace).is_some() {
+          if self.check_namespace_duplicates(file, workspace_name, &namespace) {
+            self
+              .workspace_definitions
+              .entry(workspace_name.to_string())
+              .and_modify(|loaded_definitions| {
+                loaded_definitions.push(definitions.clone());
+              })
+              .or_insert(vec![definitions]);
+            self
+              .workspace_namespaces
+              .entry(workspace_name.to_string())
+              .and_modify(|loaded_namespaces| {
+                loaded_namespaces.insert(namespace.clone());
+              })
+              .or_insert({
+                let mut set = HashSet::new();
+                set.insert(namespace.clone());
+                set
+              });
+            self
+              .workspace_models
+              .entry(workspace_name.to_string())
+              .and_modify(|loaded_models| {
+                loaded_models.insert(namespace.clone(), file.to_string_lossy().to_string());
+              })
+              .or_insert({
+                let mut map = HashMap::new();
+                map.insert(namespace.clone(), file.to_string_lossy().to_string());
+                map
+              });
+            self.model_versions.insert((workspace_name.to_string(), namespace), model_version);
+            self.status.loaded_count += 1;
           }
+        } else {
+          self.err_invalid_namespace(file, &namespace);
+          self.status.failed_loads_count += 1;
         }
-        Err(reason) => {
-          self.err_file_load(file, reason.to_string());
-          self.failed_loads_count += 1;
-        }
-      },
+      }
       Err(reason) => {
-        self.err_file_load(file, reason.to_string());
-        self.failed_loads_count += 1;
+        self.err_file_load(file, reason);
+        self.status.failed_loads_count += 1;
       }
     }
   }
@@ -171,29 +200,29 @@ impl WorkspaceBuilder {
     println!(
       "{1}Found {2} {3}.{0}",
       self.colors.reset(),
-      if self.file_count > 0 { self.colors.green() } else { self.colors.red() },
-      self.file_count,
-      Self::plural("model", self.file_count)
+      if self.status.file_count > 0 { self.colors.green() } else { self.colors.red() },
+      self.status.file_count,
+      Self::plural("model", self.status.file_count)
     );
-    if self.loaded_count > 0 {
+    if self.status.loaded_count > 0 {
       println!(
         "{1}Loaded {2} {3}.{0}",
         self.colors.reset(),
         self.colors.green(),
-        self.loaded_count,
-        Self::plural("model", self.loaded_count)
+        self.status.loaded_count,
+        Self::plural("model", self.status.loaded_count)
       );
     }
-    if self.failed_loads_count > 0 {
+    if self.status.failed_loads_count > 0 {
       println!(
         "{1}Failed to load {2} {3}.{0}",
         self.colors.reset(),
         self.colors.red(),
-        self.failed_loads_count,
-        Self::plural("model", self.failed_loads_count)
+        self.status.failed_loads_count,
+        Self::plural("model", self.status.failed_loads_count)
       );
     }
-    let deployed_invocables_count = self.evaluators.values().map(|evaluator| evaluator.invocables().len()).sum();
+    let deployed_invocables_count = self.status.invocable_count;
     println!(
       "{1}Deployed {2} {3}.{0}",
       self.colors.reset(),
@@ -201,17 +230,34 @@ impl WorkspaceBuilder {
       deployed_invocables_count,
       Self::plural("invocable", deployed_invocables_count)
     );
-    if self.failed_deployments_count > 0 {
+    if self.status.failed_deployments_count > 0 {
       println!(
         "{1}Failed to deploy {2} {3}.{0}",
         self.colors.reset(),
         self.colors.red(),
-        self.failed_deployments_count,
-        Self::plural("workspace", self.failed_deployments_count)
+        self.status.failed_deployments_count,
+        Self::plural("workspace", self.status.failed_deployments_count)
       );
     }
+    println!("{1}Finished in {2:.3} s.{0}", self.colors.reset(), self.colors.green(), self.status.duration.as_secs_f64());
     if self.verbose {
       self.display_deployed_invocables();
+      self.display_build_statistics();
+    }
+  }
+
+  /// Displays statistics of building model evaluators of deployed workspaces.
+  fn display_build_statistics(&self) {
+    let mut workspace_names = self.evaluators.keys().cloned().collect::<Vec<String>>();
+    workspace_names.sort();
+    for workspace_name in workspace_names {
+      if let Some(evaluator) = self.evaluators.get(&workspace_name) {
+        println!("{1}Build statistics of workspace `{2}`:{0}", self.colors.reset(), self.colors.yellow(), workspace_name);
+        for line in evaluator.build_statistics().to_string().lines() {
+          println!("  {line}");
+        }
+        println!();
+      }
     }
   }
 
@@ -275,8 +321,14 @@ impl WorkspaceBuilder {
     }
   }
 
+  /// Records the failure reported for specified model file or workspace.
+  fn record_failure(&mut self, source: &str, reason: &str) {
+    self.status.failures.push(DeploymentFailure::new(source, reason));
+  }
+
   /// Prints file loading error details.
-  fn err_file_load(&self, file: &Path, reason: String) {
+  fn err_file_load(&mut self, file: &Path, reason: String) {
+    self.record_failure(&file.display().to_string(), &reason);
     eprintln!(
       "[{1}error{0}][{2}{3}{0}] {1}{4}{0}",
       self.colors.reset(),
@@ -288,7 +340,8 @@ impl WorkspaceBuilder {
   }
 
   /// Prints duplicated namespace error details.
-  fn err_duplicated_namespace(&self, file: &Path, namespace: &str, file_name: &str) {
+  fn err_duplicated_namespace(&mut self, file: &Path, namespace: &str, file_name: &str) {
+    self.record_failure(&file.display().to_string(), &format!("duplicated namespace {namespace} in file {file_name}"));
     eprintln!(
       "[{1}error{0}][{2}{3}{0}] {1}duplicated namespace {4} in file {5}{0}",
       self.colors.reset(),
@@ -301,7 +354,8 @@ impl WorkspaceBuilder {
   }
 
   /// Prints invalid namespace error details.
-  fn err_invalid_namespace(&self, file: &Path, namespace: &str) {
+  fn err_invalid_namespace(&mut self, file: &Path, namespace: &str) {
+    self.record_failure(&file.display().to_string(), &format!("invalid namespace {namespace}"));
     eprintln!(
       "[{1}error{0}][{2}{3}{0}] {1}invalid namespace {4}{0}",
       self.colors.reset(),
@@ -313,7 +367,11 @@ impl WorkspaceBuilder {
   }
 
   /// Prints deployment error details.
-  fn err_deployment_failure(&self, workspace_name: &str, reason: String) {
+  fn err_deployment_failure(&mut self, workspace_name: &str, reason: String) {
+    self.record_failure(
+      if workspace_name.is_empty() { "." } else { workspace_name },
+      &format!("deployment failed with reason: {reason}"),
+    );
     eprintln!(
       "[{1}error{0}][{2}{3}{0}] {1}deployment failed with reason: {4}{0}",
       self.colors.reset(),
@@ -325,7 +383,96 @@ impl WorkspaceBuilder {
   }
 
   /// Prints file operation error details.
-  fn err_file_operation(&self, reason: String) {
+  fn err_file_operation(&mut self, source: String, reason: String) {
+    self.record_failure(&source, &reason);
     eprintln!("[{1}error{0}] {1}{2}{0}", self.colors.reset(), self.colors.red(), reason);
   }
 }
+
+/// Progress of processing items, displayed only when the standard output is a terminal.
+struct Progress {
+  /// Description of the processing.
+  label: &'static str,
+  /// Total number of processed items.
+  total: usize,
+  /// Color palette based on color mode.
+  colors: ColorPalette,
+  /// Flag indicating if the progress is displayed.
+  enabled: bool,
+  /// Last displayed percentage.
+  percentage: AtomicUsize,
+}
+
+impl Progress {
+  /// Creates a progress of processing specified number of items.
+  fn new(label: &'static str, total: usize, colors: ColorPalette) -> Self {
+    Self {
+      label,
+      total,
+      colors,
+      enabled: total > 0 && io::stdout().is_terminal(),
+      percentage: AtomicUsize::new(0),
+    }
+  }
+
+  /// Displays the progress after processing specified number of items,
+  /// the line is updated only when the percentage changes.
+  fn display(&self, done: usize) {
+    if !self.enabled {
+      return;
+    }
+    let percentage = done * 100 / self.total;
+    if done == 1 || self.percentage.fetch_max(percentage, Ordering::Relaxed) < percentage {
+      let mut stdout = io::stdout().lock();
+      let _ = write!(
+        stdout,
+        "\r{1}{2}: {3}% ({4}/{5}){0}",
+        self.colors.reset(),
+        self.colors.yellow(),
+        self.label,
+        percentage,
+        done,
+        self.total
+      );
+      let _ = stdout.flush();
+    }
+  }
+
+  /// Clears the line with displayed progress.
+  fn clear(&self) {
+    if self.enabled {
+      print!("\r\x1b[2K");
+      let _ = io::stdout().flush();
+    }
+  }
+}
+
+/// Applies the function to all items in parallel, using all available processors.
+/// The callback is called with the number of processed items after processing each item.
+/// Results are returned in the order of items.
+fn parallel_map<T: Sync, R: Send>(items: &[T], function: impl Fn(&T) -> R + Sync, processed: impl Fn(usize) + Sync) -> Vec<R> {
+  let thread_count = thread::available_parallelism().map_or(1, |count| count.get()).clamp(1, items.len().max(1));
+  let next_index = AtomicUsize::new(0);
+  let processed_count = AtomicUsize::new(0);
+  let mut results = thread::scope(|scope| {
+    let workers = (0..thread_count)
+      .map(|_| {
+        scope.spawn(|| {
+          let mut results = vec![];
+          loop {
+            let index = next_index.fetch_add(1, Ordering::Relaxed);
+            let Some(item) = items.get(index) else {
+              break;
+            };
+            results.push((index, function(item)));
+            processed(processed_count.fetch_add(1, Ordering::Relaxed) + 1);
+          }
+          results
+        })
+      })
+      .collect::<Vec<_>>();
+    workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect::<Vec<(usize, R)>>()
+  });
+  results.sort_by_key(|(index, _)| *index);
+  results.into_iter().map(|(_, result)| result).collect()
+}
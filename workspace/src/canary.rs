@@ -0,0 +1,202 @@
+//! # Canary deployments
+//!
+//! A new version of a deployed model may be deployed as a canary alongside the stable version,
+//! the model with the same namespace deployed at runtime. A configurable percentage of evaluations
+//! of invocables exposed by both versions is routed to the canary, the remaining evaluations
+//! are served by the stable version.
+//!
+//! Evaluations routed to the canary are also evaluated in the shadow by the stable version,
+//! in background, off the request path, results that differ are counted and logged, so the canary
+//! can be promoted to the stable version or removed, based on observed divergences.
+//! Shadow evaluations are queued in a bounded queue and done by a fixed number of threads,
+//! when the queue is full, shadow evaluations are dropped and counted. Evaluations are routed deterministically,
+//! exactly the configured percentage of every hundred evaluations is routed to the canary.
+//!
+//! Replacing or undeploying the stable version removes its canary.
+
+use crate::deployments::Deployment;
+use dmntk_feel::values::Value;
+use dmntk_model::NamedElement;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Number of threads evaluating canary invocables in the shadow by the stable version.
+pub(crate) const CANARY_SHADOW_THREADS: usize = 4;
+
+/// Maximum number of shadow evaluations of canary invocables waiting in the queue.
+pub(crate) const CANARY_SHADOW_QUEUE_CAPACITY: usize = 1000;
+
+/// Status of the canary deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanaryStatus {
+  /// Unique identifier of the canary deployment.
+  id: String,
+  /// Identifier of the stable deployment.
+  stable: String,
+  /// Namespace of the model.
+  namespace: String,
+  /// Name of the model.
+  name: String,
+  /// Percentage of evaluations routed to the canary.
+  percentage: u8,
+  /// Diagnostics reported while compiling the canary model.
+  diagnostics: Vec<String>,
+  /// Paths of invocables exposed by the canary model, ordered alphabetically.
+  invocables: Vec<String>,
+  /// Number of evaluations of invocables exposed by the canary.
+  evaluations: u64,
+  /// Number of evaluations routed to the canary.
+  routed: u64,
+  /// Number of evaluations routed to the canary with the result different from the result of the stable version.
+  divergences: u64,
+  /// Number of evaluations routed to the canary compared with the result of the stable version.
+  compared: u64,
+  /// Number of evaluations routed to the canary not compared, because the queue of shadow evaluations was full.
+  dropped: u64,
+}
+
+impl CanaryStatus {
+  /// Returns the unique identifier of the canary deployment.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Returns the identifier of the stable deployment.
+  pub fn stable(&self) -> &str {
+    &self.stable
+  }
+
+  /// Returns the namespace of the model.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the name of the model.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the percentage of evaluations routed to the canary.
+  pub fn percentage(&self) -> u8 {
+    self.percentage
+  }
+
+  /// Returns diagnostics reported while compiling the canary model.
+  pub fn diagnostics(&self) -> &[String] {
+    &self.diagnostics
+  }
+
+  /// Returns paths of invocables exposed by the canary model.
+  pub fn invocables(&self) -> &[String] {
+    &self.invocables
+  }
+
+  /// Returns the number of evaluations of invocables exposed by the canary.
+  pub fn evaluations(&self) -> u64 {
+    self.evaluations
+  }
+
+  /// Returns the number of evaluations routed to the canary.
+  pub fn routed(&self) -> u64 {
+    self.routed
+  }
+
+  /// Returns the number of evaluations routed to the canary with the result
+  /// different from the result of the stable version.
+  pub fn divergences(&self) -> u64 {
+    self.divergences
+  }
+
+  /// Returns the number of evaluations routed to the canary compared with the result of the stable version.
+  pub fn compared(&self) -> u64 {
+    self.compared
+  }
+
+  /// Returns the number of evaluations routed to the canary not compared with the result
+  /// of the stable version, because the queue of shadow evaluations was full.
+  pub fn dropped(&self) -> u64 {
+    self.dropped
+  }
+}
+
+/// Counters shared by all snapshots of the canary.
+#[derive(Default)]
+pub(crate) struct CanaryCounters {
+  /// Number of evaluations of invocables exposed by the canary.
+  evaluations: AtomicU64,
+  /// Number of evaluations routed to the canary.
+  routed: AtomicU64,
+  /// Number of divergent evaluations.
+  divergences: AtomicU64,
+  /// Number of compared evaluations.
+  compared: AtomicU64,
+  /// Number of evaluations not compared, because the queue of shadow evaluations was full.
+  dropped: AtomicU64,
+}
+
+/// Model deployed as a canary of the stable version.
+pub(crate) struct Canary {
+  /// Deployed canary model.
+  pub(crate) deployment: Arc<Deployment>,
+  /// Identifier of the stable deployment.
+  pub(crate) stable: String,
+  /// Percentage of evaluations routed to the canary.
+  pub(crate) percentage: u8,
+  /// Diagnostics reported while compiling the canary model.
+  pub(crate) diagnostics: Vec<String>,
+  /// Counters of evaluations.
+  pub(crate) counters: Arc<CanaryCounters>,
+}
+
+impl Canary {
+  /// Returns `true` when the evaluation of specified invocable is routed to the canary.
+  pub(crate) fn route(&self, invocable_path: &str) -> bool {
+    if !self.deployment.invocables.iter().any(|path| path == invocable_path) {
+      return false;
+    }
+    let percentage = self.percentage as u64;
+    let evaluation = self.counters.evaluations.fetch_add(1, Ordering::Relaxed);
+    let routed = (evaluation + 1) * percentage / 100 > evaluation * percentage / 100;
+    if routed {
+      self.counters.routed.fetch_add(1, Ordering::Relaxed);
+    }
+    routed
+  }
+
+  /// Compares the result of the canary with the result of the stable version evaluated in the shadow,
+  /// divergent results are counted and logged. Results being `null` are equal.
+  pub(crate) fn compare(&self, invocable_path: &str, stable: &Value, canary: &Value) {
+    self.counters.compared.fetch_add(1, Ordering::Relaxed);
+    let divergent = !matches!((stable, canary), (Value::Null(_), Value::Null(_))) && stable != canary;
+    if divergent {
+      self.counters.divergences.fetch_add(1, Ordering::Relaxed);
+      eprintln!(
+        "canary divergence in '{invocable_path}': stable deployment '{}' returned {stable}, canary deployment '{}' returned {canary}",
+        self.stable, self.deployment.id
+      );
+    }
+  }
+
+  /// Counts the evaluation not compared, because the queue of shadow evaluations was full.
+  pub(crate) fn drop_comparison(&self) {
+    self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Returns the current status of the canary.
+  pub(crate) fn status(&self) -> CanaryStatus {
+    CanaryStatus {
+      id: self.deployment.id.clone(),
+      stable: self.stable.clone(),
+      namespace: self.deployment.definitions.namespace().to_string(),
+      name: self.deployment.definitions.name().to_string(),
+      percentage: self.percentage,
+      diagnostics: self.diagnostics.clone(),
+      invocables: self.deployment.invocables.clone(),
+      evaluations: self.counters.evaluations.load(Ordering::Relaxed),
+      routed: self.counters.routed.load(Ordering::Relaxed),
+      divergences: self.counters.divergences.load(Ordering::Relaxed),
+      compared: self.counters.compared.load(Ordering::Relaxed),
+      dropped: self.counters.dropped.load(Ordering::Relaxed),
+    }
+  }
+}
@@ -0,0 +1,371 @@
+//! # Models deployed at runtime
+//!
+//! Models deployed at runtime are served alongside models loaded from the workspace directory,
+//! invocables are available under paths built from the namespace and the invocable name.
+//! Deploying a model with the namespace of already deployed model replaces the previous deployment.
+//! Models imported by the deployed model are taken from other deployments.
+//! Undeploying a model imported by other deployed models is refused, unless cascading
+//! removal is requested, then all models importing it directly or transitively are undeployed too.
+//! Deployed models may be accompanied by canaries, see [canary](crate::canary).
+
+use crate::canary::{Canary, CanaryStatus};
+use crate::errors::*;
+use dmntk_common::{gen_id, to_rdnn, Result};
+use dmntk_feel::ConformanceLevel;
+use dmntk_model::{Definitions, NamedElement};
+use dmntk_model_evaluator::ModelEvaluator;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Model deployed at runtime.
+pub(crate) struct Deployment {
+  /// Unique identifier of the deployment.
+  pub(crate) id: String,
+  /// Paths of invocables exposed by the deployed model, ordered alphabetically.
+  pub(crate) invocables: Vec<String>,
+  /// Namespace of the model in RDNN form.
+  pub(crate) rdnn: String,
+  /// Parsed model.
+  pub(crate) definitions: Definitions,
+  /// Original DMN XML source of the model.
+  pub(crate) source: String,
+  /// Evaluator of the model, built together with imported models.
+  pub(crate) evaluator: Arc<ModelEvaluator>,
+}
+
+/// Model compiled for deployment.
+pub(crate) struct CompiledModel {
+  /// Parsed model.
+  definitions: Definitions,
+  /// Original DMN XML source of the model.
+  source: String,
+  /// Evaluator of the model, built together with imported models.
+  evaluator: Arc<ModelEvaluator>,
+  /// Namespace of the model in RDNN form.
+  rdnn: String,
+  /// Diagnostics reported while compiling the model.
+  diagnostics: Vec<String>,
+  /// Namespaces of models imported directly or transitively, with identifiers
+  /// of deployments the model was compiled with, `None` when the imported model was not deployed.
+  imported: Vec<(String, Option<String>)>,
+}
+
+impl CompiledModel {
+  /// Converts the compiled model into a deployment with a new unique identifier,
+  /// returns the deployment and diagnostics reported while compiling the model.
+  fn into_deployment(self) -> (Arc<Deployment>, Vec<String>) {
+    let namespace = self.definitions.namespace();
+    let mut invocables = self
+      .evaluator
+      .invocables()
+      .list()
+      .into_iter()
+      .filter(|(invocable_namespace, _)| invocable_namespace == namespace)
+      .map(|(_, invocable_name)| format!("{}/{invocable_name}", self.rdnn))
+      .collect::<Vec<String>>();
+    invocables.sort();
+    let deployment = Deployment {
+      id: gen_id(),
+      invocables,
+      rdnn: self.rdnn,
+      definitions: self.definitions,
+      source: self.source,
+      evaluator: self.evaluator,
+    };
+    (Arc::new(deployment), self.diagnostics)
+  }
+}
+
+/// Report of deploying a model at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentReport {
+  /// Unique identifier of the deployment.
+  id: String,
+  /// Identifier of the deployment replaced by this deployment.
+  replaced: Option<String>,
+  /// Namespace of the deployed model.
+  namespace: String,
+  /// Name of the deployed model.
+  name: String,
+  /// Diagnostics reported while compiling the model.
+  diagnostics: Vec<String>,
+  /// Paths of invocables exposed by the deployed model, ordered alphabetically.
+  invocables: Vec<String>,
+}
+
+impl DeploymentReport {
+  /// Returns the unique identifier of the deployment.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Returns the identifier of the deployment replaced by this deployment.
+  pub fn replaced(&self) -> Option<&str> {
+    self.replaced.as_deref()
+  }
+
+  /// Returns the namespace of the deployed model.
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  /// Returns the name of the deployed model.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns diagnostics reported while compiling the model.
+  pub fn diagnostics(&self) -> &[String] {
+    &self.diagnostics
+  }
+
+  /// Returns paths of invocables exposed by the deployed model.
+  pub fn invocables(&self) -> &[String] {
+    &self.invocables
+  }
+}
+
+/// Container for models deployed at runtime.
+///
+/// Deployments are never modified while evaluated, changes are applied to a copy
+/// which replaces the whole container, see [Workspaces](crate::Workspaces).
+#[derive(Default, Clone)]
+pub(crate) struct Deployments {
+  /// Map: deployment id -> deployment
+  pub(crate) deployments: HashMap<String, Arc<Deployment>>,
+  /// Map: namespace -> deployment id
+  pub(crate) namespaces: HashMap<String, String>,
+  /// Map: invocable path -> (deployment id, namespace, invocable name)
+  pub(crate) invocables: HashMap<String, (String, String, String)>,
+  /// Map: namespace -> canary of the deployed model
+  pub(crate) canaries: HashMap<String, Arc<Canary>>,
+}
+
+impl Deployments {
+  /// Validates and compiles the model given as DMN XML, together with deployed models it imports,
+  /// models with reserved namespaces can not be deployed.
+  pub(crate) fn compile(&self, xml: &str, conformance_level: ConformanceLevel, is_reserved: impl Fn(&str) -> bool) -> Result<CompiledModel> {
+    let definitions = dmntk_model::parse(xml)?;
+    let namespace = definitions.namespace().to_string();
+    let Some(rdnn) = to_rdnn(&namespace) else {
+      return Err(err_invalid_namespace(&namespace));
+    };
+    if is_reserved(&namespace) {
+      return Err(err_namespace_reserved(&namespace));
+    }
+    let mut models = vec![definitions.clone()];
+    self.collect_imported(&definitions, &mut models);
+    let evaluator = ModelEvaluator::new_at_conformance_level(&models, conformance_level)?;
+    let diagnostics = dmntk_model::lint(&definitions).iter().map(|warning| warning.to_string()).collect();
+    let imported = models
+      .iter()
+      .flat_map(|model| model.imports())
+      .map(|import| (import.namespace().to_string(), self.namespaces.get(import.namespace()).cloned()))
+      .collect();
+    Ok(CompiledModel {
+      definitions,
+      source: xml.to_string(),
+      evaluator,
+      rdnn,
+      diagnostics,
+      imported,
+    })
+  }
+
+  /// Returns `true` when deployments of models imported by the compiled model changed
+  /// since the model was compiled, then the model must be compiled again before deploying.
+  pub(crate) fn is_stale(&self, compiled: &CompiledModel) -> bool {
+    compiled.imported.iter().any(|(namespace, id)| self.namespaces.get(namespace) != id.as_ref())
+  }
+
+  /// Deploys compiled model, replacing the deployment of the model with the same namespace.
+  pub(crate) fn commit(&mut self, compiled: CompiledModel) -> DeploymentReport {
+    let (deployment, diagnostics) = compiled.into_deployment();
+    self.install(deployment, diagnostics)
+  }
+
+  /// Deploys compiled model as a canary of the deployed model with the same namespace,
+  /// replacing the previous canary of that model.
+  pub(crate) fn stage_canary(&mut self, compiled: CompiledModel, percentage: u8) -> Result<CanaryStatus> {
+    if percentage > 100 {
+      return Err(err_invalid_canary_percentage(percentage));
+    }
+    let namespace = compiled.definitions.namespace().to_string();
+    let Some(stable) = self.namespaces.get(&namespace).cloned() else {
+      return Err(err_canary_without_stable(&namespace));
+    };
+    let (deployment, diagnostics) = compiled.into_deployment();
+    let canary = Arc::new(Canary {
+      deployment,
+      stable,
+      percentage,
+      diagnostics,
+      counters: Default::default(),
+    });
+    let status = canary.status();
+    self.canaries.insert(namespace, canary);
+    Ok(status)
+  }
+
+  /// Changes the percentage of evaluations routed to the canary with specified identifier.
+  pub(crate) fn set_canary_percentage(&mut self, id: &str, percentage: u8) -> Result<CanaryStatus> {
+    if percentage > 100 {
+      return Err(err_invalid_canary_percentage(percentage));
+    }
+    let canary = self.canary_by_id(id)?;
+    let canary = Arc::new(Canary {
+      deployment: Arc::clone(&canary.deployment),
+      stable: canary.stable.clone(),
+      percentage,
+      diagnostics: canary.diagnostics.clone(),
+      counters: Arc::clone(&canary.counters),
+    });
+    let status = canary.status();
+    self.canaries.insert(canary.deployment.definitions.namespace().to_string(), canary);
+    Ok(status)
+  }
+
+  /// Promotes the canary with specified identifier to the stable version, replacing the stable deployment.
+  pub(crate) fn promote_canary(&mut self, id: &str) -> Result<DeploymentReport> {
+    let canary = self.remove_canary(id)?;
+    Ok(self.install(Arc::clone(&canary.deployment), canary.diagnostics.clone()))
+  }
+
+  /// Removes the canary with specified identifier.
+  pub(crate) fn remove_canary(&mut self, id: &str) -> Result<Arc<Canary>> {
+    let namespace = self.canary_by_id(id)?.deployment.definitions.namespace().to_string();
+    self.canaries.remove(&namespace).ok_or_else(|| err_canary_not_found(id))
+  }
+
+  /// Returns the canary of the deployed model with specified namespace.
+  pub(crate) fn canary(&self, namespace: &str) -> Option<&Arc<Canary>> {
+    self.canaries.get(namespace)
+  }
+
+  /// Returns statuses of all canaries, ordered by namespaces.
+  pub(crate) fn canaries(&self) -> Vec<CanaryStatus> {
+    let mut canaries = self.canaries.values().map(|canary| canary.status()).collect::<Vec<CanaryStatus>>();
+    canaries.sort_by(|a, b| a.namespace().cmp(b.namespace()));
+    canaries
+  }
+
+  /// Returns the canary with specified identifier.
+  fn canary_by_id(&self, id: &str) -> Result<&Arc<Canary>> {
+    self.canaries.values().find(|canary| canary.deployment.id == id).ok_or_else(|| err_canary_not_found(id))
+  }
+
+  /// Installs the deployment, replacing the deployment of the model with the same namespace.
+  fn install(&mut self, deployment: Arc<Deployment>, diagnostics: Vec<String>) -> DeploymentReport {
+    let namespace = deployment.definitions.namespace().to_string();
+    let name = deployment.definitions.name().to_string();
+    let replaced = self.namespaces.get(&namespace).cloned();
+    if let Some(previous_id) = &replaced {
+      self.remove(previous_id);
+    }
+    let id = deployment.id.clone();
+    for (invocable_namespace, invocable_name) in deployment.evaluator.invocables().list() {
+      if invocable_namespace == namespace {
+        let invocable_path = format!("{}/{invocable_name}", deployment.rdnn);
+        self.invocables.insert(invocable_path, (id.clone(), invocable_namespace, invocable_name));
+      }
+    }
+    let invocables = deployment.invocables.clone();
+    self.namespaces.insert(namespace.clone(), id.clone());
+    self.deployments.insert(id.clone(), deployment);
+    DeploymentReport {
+      id,
+      replaced,
+      namespace,
+      name,
+      diagnostics,
+      invocables,
+    }
+  }
+
+  /// Returns the evaluator, namespace, name and deployment identifier of deployed invocable identified by invocable path,
+  /// returns `None` when no deployed model exposes such invocable.
+  pub(crate) fn invocable(&self, invocable_path: &str) -> Option<(&Arc<ModelEvaluator>, &str, &str, &str)> {
+    let (id, namespace, invocable_name) = self.invocables.get(invocable_path)?;
+    let deployment = self.deployments.get(id)?;
+    Some((&deployment.evaluator, namespace, invocable_name, id))
+  }
+
+  /// Returns the original DMN XML source of the deployed model.
+  pub(crate) fn source(&self, id: &str) -> Result<String> {
+    self
+      .deployments
+      .get(id)
+      .map(|deployment| deployment.source.clone())
+      .ok_or_else(|| err_deployment_not_found(id))
+  }
+
+  /// Undeploys the model with specified identifier, returns removed deployments.
+  /// When the model is imported by other deployed models, undeploying is refused,
+  /// unless `cascade` is `true`, then importing models are undeployed too.
+  pub(crate) fn undeploy(&mut self, id: &str, cascade: bool) -> Result<Vec<Arc<Deployment>>> {
+    let Some(deployment) = self.deployments.get(id) else {
+      return Err(err_deployment_not_found(id));
+    };
+    let importers = self.importers(deployment.definitions.namespace());
+    if !importers.is_empty() && !cascade {
+      let mut namespaces = importers
+        .iter()
+        .filter_map(|importer_id| self.deployments.get(importer_id))
+        .map(|importer| importer.definitions.namespace().to_string())
+        .collect::<Vec<String>>();
+      namespaces.sort();
+      return Err(err_deployment_imported(id, &namespaces.join(", ")));
+    }
+    let mut removed = vec![id.to_string()];
+    let mut index = 0;
+    while index < removed.len() {
+      if let Some(deployment) = self.deployments.get(&removed[index]) {
+        for importer_id in self.importers(deployment.definitions.namespace()) {
+          if !removed.contains(&importer_id) {
+            removed.push(importer_id);
+          }
+        }
+      }
+      index += 1;
+    }
+    Ok(removed.iter().filter_map(|removed_id| self.remove(removed_id)).collect())
+  }
+
+  /// Returns identifiers of deployments directly importing the model with specified namespace.
+  fn importers(&self, namespace: &str) -> Vec<String> {
+    let mut importers = self
+      .deployments
+      .iter()
+      .filter(|(_, deployment)| deployment.definitions.imports().iter().any(|import| import.namespace() == namespace))
+      .map(|(id, _)| id.clone())
+      .collect::<Vec<String>>();
+    importers.sort();
+    importers
+  }
+
+  /// Removes the deployment with specified identifier together with its invocables and canary.
+  fn remove(&mut self, id: &str) -> Option<Arc<Deployment>> {
+    let deployment = self.deployments.remove(id)?;
+    self.namespaces.remove(deployment.definitions.namespace());
+    self.canaries.remove(deployment.definitions.namespace());
+    self.invocables.retain(|_, (deployment_id, _, _)| deployment_id != id);
+    Some(deployment)
+  }
+
+  /// Collects deployed models imported directly or transitively by specified model.
+  fn collect_imported(&self, definitions: &Definitions, models: &mut Vec<Definitions>) {
+    for import in definitions.imports() {
+      let imported = self
+        .namespaces
+        .get(import.namespace())
+        .and_then(|id| self.deployments.get(id))
+        .map(|deployment| &deployment.definitions);
+      if let Some(imported) = imported {
+        if models.iter().all(|model| model.namespace() != imported.namespace()) {
+          models.push(imported.clone());
+          self.collect_imported(imported, models);
+        }
+      }
+    }
+  }
+}
@@ -1,11 +1,111 @@
 //! Errors reported by workspace.
 
-use dmntk_common::{DmntkError, ToErrorMessage};
+use dmntk_common::{message, DmntkError, Message, ToErrorMessage};
 
 /// Errors reported by workspace.
 #[derive(ToErrorMessage)]
-struct WorkspaceError(String);
+struct WorkspaceError(Message);
 
 pub fn err_invocable_not_found(invocable_path: &str) -> DmntkError {
-  WorkspaceError(format!("invocable not found: '{invocable_path}'")).into()
+  WorkspaceError(message!("invocable not found: '{}'", invocable_path)).into()
+}
+
+pub fn err_tenant_not_found(tenant_name: &str) -> DmntkError {
+  WorkspaceError(message!("tenant not found: '{}'", tenant_name)).into()
+}
+
+pub fn err_invalid_tenant_configuration(tenant_name: &str, reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  WorkspaceError(message!("invalid configuration of tenant '{}': {msg}", tenant_name, reason)).into()
+}
+
+pub fn err_invalid_namespace(namespace: &str) -> DmntkError {
+  WorkspaceError(message!("invalid namespace: '{}'", namespace)).into()
+}
+
+pub fn err_namespace_reserved(namespace: &str) -> DmntkError {
+  WorkspaceError(message!("namespace is already served by models loaded from workspace directory: '{}'", namespace)).into()
+}
+
+pub fn err_deployment_not_found(id: &str) -> DmntkError {
+  WorkspaceError(message!("deployment not found: '{}'", id)).into()
+}
+
+pub fn err_deployment_imported(id: &str, importers: &str) -> DmntkError {
+  WorkspaceError(message!("deployment '{}' is imported by deployed models: {}", id, importers)).into()
+}
+
+pub fn err_opening_audit_file(path: &str, reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  WorkspaceError(message!("opening audit file '{}' failed with reason: {msg}", path, reason)).into()
+}
+
+pub fn err_storing_audit_record(reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  WorkspaceError(message!("storing audit record failed with reason: {msg}", reason)).into()
+}
+
+pub fn err_invalid_audit_record(reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  WorkspaceError(message!("invalid audit record: {msg}", reason)).into()
+}
+
+pub fn err_canary_not_found(id: &str) -> DmntkError {
+  WorkspaceError(message!("canary not found: '{}'", id)).into()
+}
+
+pub fn err_canary_without_stable(namespace: &str) -> DmntkError {
+  WorkspaceError(message!("canary requires a deployed model with namespace: '{}'", namespace)).into()
+}
+
+pub fn err_invalid_canary_percentage(percentage: u8) -> DmntkError {
+  WorkspaceError(message!("invalid canary percentage: {}, expected value from 0 to 100", percentage)).into()
+}
+
+pub fn err_unknown_service_parameters(service_path: &str, names: &str) -> DmntkError {
+  WorkspaceError(message!("decision service '{}' has no input parameters named: {}", service_path, names)).into()
+}
+
+#[cfg(feature = "graphql")]
+pub fn err_graphql_syntax(message: &str, line: usize, column: usize) -> DmntkError {
+  WorkspaceError(message!("invalid GraphQL query: {} at line {}, column {}", message, line, column)).into()
+}
+
+#[cfg(feature = "graphql")]
+pub fn err_graphql_operation_not_found(operation_name: &str) -> DmntkError {
+  WorkspaceError(message!("GraphQL operation not found: '{}'", operation_name)).into()
+}
+
+#[cfg(feature = "graphql")]
+pub fn err_graphql_operation_name_required() -> DmntkError {
+  WorkspaceError(message!("GraphQL query contains multiple operations, operation name is required")).into()
+}
+
+#[cfg(feature = "graphql")]
+pub fn err_graphql_invalid_request(reason: impl Into<Message>) -> DmntkError {
+  let reason: Message = reason.into();
+  WorkspaceError(message!("invalid GraphQL request: {msg}", reason)).into()
+}
+
+#[cfg(feature = "graphql")]
+pub fn err_graphql_unknown_field(field_name: &str, type_name: &str) -> DmntkError {
+  WorkspaceError(message!("field '{}' is not defined in type '{}'", field_name, type_name)).into()
+}
+
+#[cfg(feature = "graphql")]
+pub fn err_graphql_unknown_argument(argument_name: &str, field_name: &str) -> DmntkError {
+  WorkspaceError(message!("argument '{}' is not defined in field '{}'", argument_name, field_name)).into()
+}
+
+#[cfg(feature = "graphql")]
+pub fn err_graphql_selection_on_scalar(type_name: &str) -> DmntkError {
+  WorkspaceError(message!("fields can not be selected from scalar value of '{}'", type_name)).into()
+}
+
+pub fn err_invalid_selected_path(path: &str) -> DmntkError {
+  WorkspaceError(message!("invalid selected path: '{}'", path)).into()
+}
+
+pub fn err_selected_path_not_found(path: &str) -> DmntkError {
+  WorkspaceError(message!("selected path not found in result: '{}'", path)).into()
 }
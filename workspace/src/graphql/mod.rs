@@ -0,0 +1,309 @@
+//! # GraphQL API for evaluating invocables
+//!
+//! Decisions and decision services are exposed as fields of the `Query` type. Arguments of fields
+//! are input data of decisions or input parameters of decision services, their types are derived
+//! from types of input data, including item definitions, so contexts become input objects.
+//! Results of invocables are returned as values of fields, only selected entries of contexts
+//! are returned, so a single query may evaluate multiple invocables and select only needed outputs.
+//!
+//! Names of fields are FEEL names with characters not allowed in GraphQL names replaced with `_`.
+//! Fields are named after invocables, when multiple invocables have the same name,
+//! their fields are named after invocable paths.
+//!
+//! Numbers are represented as `Float`, temporal values as `String`, values of types without
+//! GraphQL representation (like ranges, functions or values of any type) as custom `JSON` scalar.
+//! Evaluations resulting in `null` with a reason are reported in `errors` of the response.
+//!
+//! The API is available with the `graphql` feature, enabled by the `graphql` feature of the server.
+
+mod parser;
+
+use crate::errors::*;
+use dmntk_common::{Jsonify, Result};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::json::{coerce_json_value, value_from_json};
+use dmntk_feel::values::Value;
+use dmntk_feel::{FeelType, Name};
+use parser::{parse_query, Field, GqlValue};
+use serde_json::{json, Map};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// Invocable exposed as a field of the `Query` type.
+pub(crate) struct QueryField {
+  /// Name of the field.
+  name: String,
+  /// Path of the invocable.
+  path: String,
+  /// Types of input data, indexed by FEEL names.
+  input_types: BTreeMap<Name, FeelType>,
+  /// Type of the result.
+  output_type: FeelType,
+}
+
+/// Builds fields of the `Query` type from invocables given as tuples
+/// (invocable path, invocable name, type of input data, type of the result).
+pub(crate) fn query_fields(invocables: Vec<(String, String, FeelType, FeelType)>) -> Vec<QueryField> {
+  let mut name_counts = HashMap::new();
+  for (_, invocable_name, _, _) in &invocables {
+    *name_counts.entry(graphql_name(invocable_name)).or_insert(0) += 1;
+  }
+  let mut fields = invocables
+    .into_iter()
+    .map(|(path, invocable_name, input_type, output_type)| {
+      let name = graphql_name(&invocable_name);
+      QueryField {
+        name: if name_counts[&name] > 1 { graphql_name(&path) } else { name },
+        path,
+        input_types: match input_type {
+          FeelType::Context(entry_types) => entry_types,
+          _ => BTreeMap::new(),
+        },
+        output_type,
+      }
+    })
+    .collect::<Vec<QueryField>>();
+  fields.sort_by(|a, b| a.name.cmp(&b.name));
+  fields
+}
+
+/// Converts FEEL name into GraphQL name, characters not allowed in GraphQL names are replaced with `_`.
+pub(crate) fn graphql_name(name: &str) -> String {
+  let mut graphql_name = name.chars().map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' }).collect::<String>();
+  if !graphql_name.starts_with(|ch: char| ch == '_' || ch.is_ascii_alphabetic()) {
+    graphql_name.insert(0, '_');
+  }
+  graphql_name
+}
+
+/// Generates the schema of the GraphQL API in schema definition language.
+pub(crate) fn schema(fields: &[QueryField]) -> String {
+  let mut types = Vec::<String>::new();
+  let mut uses_json = false;
+  let mut query_type = String::from("type Query {\n");
+  for field in fields {
+    let arguments = field
+      .input_types
+      .iter()
+      .map(|(name, feel_type)| {
+        let type_name = format!("{}_{}Input", field.name, graphql_name(&name.to_string()));
+        format!(
+          "{}: {}",
+          graphql_name(&name.to_string()),
+          type_reference(feel_type, &type_name, true, &mut types, &mut uses_json)
+        )
+      })
+      .collect::<Vec<String>>();
+    let output_type = type_reference(&field.output_type, &format!("{}Result", field.name), false, &mut types, &mut uses_json);
+    if arguments.is_empty() {
+      let _ = writeln!(query_type, "  {}: {output_type}", field.name);
+    } else {
+      let _ = writeln!(query_type, "  {}({}): {output_type}", field.name, arguments.join(", "));
+    }
+  }
+  query_type.push('}');
+  let mut schema = String::new();
+  if uses_json {
+    schema.push_str("scalar JSON\n\n");
+  }
+  schema.push_str(&query_type);
+  for type_definition in types {
+    schema.push_str("\n\n");
+    schema.push_str(&type_definition);
+  }
+  schema.push('\n');
+  schema
+}
+
+/// Returns the reference to the GraphQL type representing specified FEEL type,
+/// object types and input object types are generated with specified type name.
+fn type_reference(feel_type: &FeelType, type_name: &str, input: bool, types: &mut Vec<String>, uses_json: &mut bool) -> String {
+  match feel_type {
+    FeelType::Boolean => "Boolean".to_string(),
+    FeelType::Number => "Float".to_string(),
+    FeelType::String | FeelType::Date | FeelType::DateTime | FeelType::Time | FeelType::DaysAndTimeDuration | FeelType::YearsAndMonthsDuration => "String".to_string(),
+    FeelType::List(items_type) => format!("[{}]", type_reference(items_type, type_name, input, types, uses_json)),
+    FeelType::Context(entry_types) if !entry_types.is_empty() => {
+      let mut type_definition = format!("{} {type_name} {{\n", if input { "input" } else { "type" });
+      for (name, entry_type) in entry_types {
+        let entry_name = graphql_name(&name.to_string());
+        let entry_type_name = match type_name.strip_suffix("Input") {
+          Some(prefix) => format!("{prefix}_{entry_name}Input"),
+          None => format!("{type_name}_{entry_name}"),
+        };
+        let _ = writeln!(type_definition, "  {entry_name}: {}", type_reference(entry_type, &entry_type_name, input, types, uses_json));
+      }
+      type_definition.push('}');
+      types.push(type_definition);
+      type_name.to_string()
+    }
+    _ => {
+      *uses_json = true;
+      "JSON".to_string()
+    }
+  }
+}
+
+/// Executes the GraphQL request given as JSON object with `query`, `variables` and `operationName` members,
+/// invocables are evaluated with specified function. Returns the response as JSON object.
+pub(crate) fn execute(fields: &[QueryField], request_body: &str, evaluate: impl Fn(&str, &FeelContext) -> Result<Value>) -> String {
+  let request = match serde_json::from_str::<serde_json::Value>(request_body) {
+    Ok(request) => request,
+    Err(reason) => return error_response(&err_graphql_invalid_request(reason.to_string()).to_string()),
+  };
+  let Some(query) = request.get("query").and_then(serde_json::Value::as_str) else {
+    return error_response(&err_graphql_invalid_request("missing query").to_string());
+  };
+  let operation_name = request.get("operationName").and_then(serde_json::Value::as_str);
+  let operation = match parse_query(query, operation_name) {
+    Ok(operation) => operation,
+    Err(reason) => return error_response(&reason.to_string()),
+  };
+  let mut variables = request.get("variables").and_then(serde_json::Value::as_object).cloned().unwrap_or_default();
+  for (name, default_value) in operation.variables {
+    if let (false, Some(default_value)) = (variables.contains_key(&name), default_value) {
+      let value = json_value(&default_value, &Map::new());
+      variables.insert(name, value);
+    }
+  }
+  let mut data = Map::new();
+  let mut errors = vec![];
+  for selection in &operation.selections {
+    let key = selection.response_key().to_string();
+    match execute_field(fields, selection, &variables, &evaluate) {
+      Ok(value) => {
+        data.insert(key, value);
+      }
+      Err(message) => {
+        data.insert(key.clone(), serde_json::Value::Null);
+        errors.push(json!({ "message": message, "path": [key] }));
+      }
+    }
+  }
+  if errors.is_empty() {
+    json!({ "data": data }).to_string()
+  } else {
+    json!({ "data": data, "errors": errors }).to_string()
+  }
+}
+
+/// Returns the response containing only a single error.
+fn error_response(message: &str) -> String {
+  json!({ "errors": [{ "message": message }] }).to_string()
+}
+
+/// Evaluates the invocable selected as a field of the `Query` type, returns the value of the field
+/// or the message describing the error.
+fn execute_field(
+  fields: &[QueryField],
+  selection: &Field,
+  variables: &Map<String, serde_json::Value>,
+  evaluate: &impl Fn(&str, &FeelContext) -> Result<Value>,
+) -> std::result::Result<serde_json::Value, String> {
+  if selection.name == "__typename" {
+    return Ok(json!("Query"));
+  }
+  let Some(field) = fields.iter().find(|field| field.name == selection.name) else {
+    return Err(err_graphql_unknown_field(&selection.name, "Query").to_string());
+  };
+  let mut input_data = FeelContext::default();
+  for (argument_name, argument_value) in &selection.arguments {
+    let Some((name, feel_type)) = field.input_types.iter().find(|(name, _)| graphql_name(&name.to_string()) == *argument_name) else {
+      return Err(err_graphql_unknown_argument(argument_name, &field.name).to_string());
+    };
+    let json = feel_names(json_value(argument_value, variables), feel_type);
+    let value = value_from_json(&json.to_string()).map_err(|reason| reason.to_string())?;
+    let (value, _) = coerce_json_value(&value, feel_type, &name.to_string());
+    input_data.set_entry(name, value);
+  }
+  match evaluate(&field.path, &input_data).map_err(|reason| reason.to_string())? {
+    Value::Null(Some(reason)) => Err(reason),
+    value => {
+      let result = serde_json::from_str(&value.jsonify()).unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+      select(&result, &field.output_type, &selection.selections, &format!("{}Result", field.name))
+    }
+  }
+}
+
+/// Returns the value with only selected entries of objects.
+fn select(value: &serde_json::Value, feel_type: &FeelType, selections: &[Field], type_name: &str) -> std::result::Result<serde_json::Value, String> {
+  if selections.is_empty() {
+    return Ok(value.clone());
+  }
+  match value {
+    serde_json::Value::Null => Ok(serde_json::Value::Null),
+    serde_json::Value::Array(items) => {
+      let items_type = match feel_type {
+        FeelType::List(items_type) => items_type.as_ref(),
+        other => other,
+      };
+      items
+        .iter()
+        .map(|item| select(item, items_type, selections, type_name))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map(serde_json::Value::Array)
+    }
+    serde_json::Value::Object(entries) => {
+      let mut selected = Map::new();
+      for selection in selections {
+        if selection.name == "__typename" {
+          selected.insert(selection.response_key().to_string(), json!(type_name));
+          continue;
+        }
+        let entry_types = match feel_type {
+          FeelType::Context(entry_types) => Some(entry_types),
+          _ => None,
+        };
+        let entry_type = entry_types
+          .and_then(|entry_types| entry_types.iter().find(|(name, _)| graphql_name(&name.to_string()) == selection.name))
+          .map(|(_, entry_type)| entry_type);
+        let entry = entries.iter().find(|(name, _)| graphql_name(name) == selection.name);
+        let (entry_value, entry_type) = match (entry, entry_type) {
+          (Some((_, entry_value)), Some(entry_type)) => (entry_value, entry_type),
+          (Some((_, entry_value)), None) if entry_types.is_none() => (entry_value, &FeelType::Any),
+          (None, Some(entry_type)) => (&serde_json::Value::Null, entry_type),
+          _ => return Err(err_graphql_unknown_field(&selection.name, type_name).to_string()),
+        };
+        let entry_type_name = format!("{type_name}_{}", selection.name);
+        selected.insert(
+          selection.response_key().to_string(),
+          select(entry_value, entry_type, &selection.selections, &entry_type_name)?,
+        );
+      }
+      Ok(serde_json::Value::Object(selected))
+    }
+    _ => Err(err_graphql_selection_on_scalar(type_name).to_string()),
+  }
+}
+
+/// Converts the GraphQL value into JSON value, variables not defined are `null`.
+fn json_value(value: &GqlValue, variables: &Map<String, serde_json::Value>) -> serde_json::Value {
+  match value {
+    GqlValue::Variable(name) => variables.get(name).cloned().unwrap_or(serde_json::Value::Null),
+    GqlValue::Number(number) => serde_json::from_str(number).unwrap_or(serde_json::Value::Null),
+    GqlValue::String(text) | GqlValue::Enum(text) => serde_json::Value::String(text.clone()),
+    GqlValue::Boolean(flag) => serde_json::Value::Bool(*flag),
+    GqlValue::Null => serde_json::Value::Null,
+    GqlValue::List(items) => serde_json::Value::Array(items.iter().map(|item| json_value(item, variables)).collect()),
+    GqlValue::Object(fields) => serde_json::Value::Object(fields.iter().map(|(name, value)| (name.clone(), json_value(value, variables))).collect()),
+  }
+}
+
+/// Renames members of objects in the JSON value from GraphQL names to FEEL names of context entries.
+fn feel_names(value: serde_json::Value, feel_type: &FeelType) -> serde_json::Value {
+  match (value, feel_type) {
+    (serde_json::Value::Array(items), FeelType::List(items_type)) => serde_json::Value::Array(items.into_iter().map(|item| feel_names(item, items_type)).collect()),
+    (serde_json::Value::Object(members), FeelType::Context(entry_types)) => serde_json::Value::Object(
+      members
+        .into_iter()
+        .map(
+          |(name, member)| match entry_types.iter().find(|(entry_name, _)| graphql_name(&entry_name.to_string()) == name) {
+            Some((entry_name, entry_type)) => (entry_name.to_string(), feel_names(member, entry_type)),
+            None => (name, member),
+          },
+        )
+        .collect(),
+    ),
+    (value, _) => value,
+  }
+}
@@ -0,0 +1,455 @@
+//! # Parser of GraphQL queries
+//!
+//! Parses the subset of GraphQL query language used for evaluating invocables:
+//! query operations with variables, fields with aliases, arguments and selection sets.
+//! Fragments, directives, mutations and subscriptions are reported as not supported.
+
+use crate::errors::*;
+use dmntk_common::Result;
+
+/// Value of an argument or a default value of a variable.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum GqlValue {
+  /// Reference to a variable.
+  Variable(String),
+  /// Integer or floating-point number, in textual form.
+  Number(String),
+  /// String value.
+  String(String),
+  /// Boolean value.
+  Boolean(bool),
+  /// Null value.
+  Null,
+  /// Enumeration value, evaluated as a string.
+  Enum(String),
+  /// List of values.
+  List(Vec<GqlValue>),
+  /// Object with named fields.
+  Object(Vec<(String, GqlValue)>),
+}
+
+/// Field selected in a query.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Field {
+  /// Alias of the field in the response.
+  pub(crate) alias: Option<String>,
+  /// Name of the field.
+  pub(crate) name: String,
+  /// Arguments of the field.
+  pub(crate) arguments: Vec<(String, GqlValue)>,
+  /// Fields selected from the value of this field.
+  pub(crate) selections: Vec<Field>,
+}
+
+impl Field {
+  /// Returns the key of the field in the response, the alias when specified, otherwise the name.
+  pub(crate) fn response_key(&self) -> &str {
+    self.alias.as_deref().unwrap_or(&self.name)
+  }
+}
+
+/// Query operation.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Operation {
+  /// Name of the operation.
+  pub(crate) name: Option<String>,
+  /// Names of variables with default values.
+  pub(crate) variables: Vec<(String, Option<GqlValue>)>,
+  /// Fields selected in the operation.
+  pub(crate) selections: Vec<Field>,
+}
+
+/// Tokens of GraphQL query language.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  /// Punctuator, like `{` or `:`.
+  Punctuator(char),
+  /// Spread operator `...`.
+  Spread,
+  /// Name.
+  Name(String),
+  /// Integer or floating-point number.
+  Number(String),
+  /// String value.
+  String(String),
+  /// End of input.
+  End,
+}
+
+impl Token {
+  /// Returns the text of the token used in error messages.
+  fn text(&self) -> String {
+    match self {
+      Token::Punctuator(ch) => format!("`{ch}`"),
+      Token::Spread => "`...`".to_string(),
+      Token::Name(name) => format!("`{name}`"),
+      Token::Number(number) => format!("`{number}`"),
+      Token::String(_) => "string".to_string(),
+      Token::End => "end of query".to_string(),
+    }
+  }
+}
+
+/// Splits the query into tokens with line and column numbers.
+fn tokenize(query: &str) -> Result<Vec<(Token, usize, usize)>> {
+  let chars = query.chars().collect::<Vec<char>>();
+  let mut tokens = vec![];
+  let (mut index, mut line, mut line_start) = (0, 1, 0);
+  while index < chars.len() {
+    let ch = chars[index];
+    let column = index - line_start + 1;
+    match ch {
+      '\n' => {
+        index += 1;
+        line += 1;
+        line_start = index;
+      }
+      ' ' | '\t' | '\r' | ',' | '\u{feff}' => index += 1,
+      '#' => {
+        while index < chars.len() && chars[index] != '\n' {
+          index += 1;
+        }
+      }
+      '{' | '}' | '(' | ')' | '[' | ']' | ':' | '$' | '=' | '!' | '@' | '|' | '&' => {
+        tokens.push((Token::Punctuator(ch), line, column));
+        index += 1;
+      }
+      '.' if chars[index..].starts_with(&['.', '.', '.']) => {
+        tokens.push((Token::Spread, line, column));
+        index += 3;
+      }
+      '"' if chars[index..].starts_with(&['"', '"', '"']) => {
+        index += 3;
+        let mut value = String::new();
+        loop {
+          if index >= chars.len() {
+            return Err(err_graphql_syntax("unterminated block string", line, column));
+          }
+          if chars[index..].starts_with(&['"', '"', '"']) {
+            index += 3;
+            break;
+          }
+          if chars[index] == '\n' {
+            line += 1;
+            line_start = index + 1;
+          }
+          value.push(chars[index]);
+          index += 1;
+        }
+        tokens.push((Token::String(value.trim().to_string()), line, column));
+      }
+      '"' => {
+        index += 1;
+        let mut value = String::new();
+        loop {
+          match chars.get(index) {
+            None | Some('\n') => return Err(err_graphql_syntax("unterminated string", line, column)),
+            Some('"') => {
+              index += 1;
+              break;
+            }
+            Some('\\') => {
+              let escaped = match chars.get(index + 1) {
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some('/') => '/',
+                Some('b') => '\u{8}',
+                Some('f') => '\u{c}',
+                Some('n') => '\n',
+                Some('r') => '\r',
+                Some('t') => '\t',
+                Some('u') => {
+                  let code = chars.get(index + 2..index + 6).map(|digits| digits.iter().collect::<String>()).unwrap_or_default();
+                  let Some(escaped) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) else {
+                    return Err(err_graphql_syntax("invalid unicode escape sequence", line, column));
+                  };
+                  index += 4;
+                  escaped
+                }
+                _ => return Err(err_graphql_syntax("invalid escape sequence", line, column)),
+              };
+              value.push(escaped);
+              index += 2;
+            }
+            Some(ch) => {
+              value.push(*ch);
+              index += 1;
+            }
+          }
+        }
+        tokens.push((Token::String(value), line, column));
+      }
+      '-' | '0'..='9' => {
+        let start = index;
+        index += 1;
+        while index < chars.len()
+          && (chars[index].is_ascii_digit() || matches!(chars[index], '.' | 'e' | 'E') || (matches!(chars[index], '+' | '-') && matches!(chars[index - 1], 'e' | 'E')))
+        {
+          index += 1;
+        }
+        let number = chars[start..index].iter().collect::<String>();
+        if number.parse::<f64>().is_err() {
+          return Err(err_graphql_syntax(&format!("invalid number `{number}`"), line, column));
+        }
+        tokens.push((Token::Number(number), line, column));
+      }
+      '_' | 'a'..='z' | 'A'..='Z' => {
+        let start = index;
+        while index < chars.len() && (chars[index] == '_' || chars[index].is_ascii_alphanumeric()) {
+          index += 1;
+        }
+        tokens.push((Token::Name(chars[start..index].iter().collect()), line, column));
+      }
+      other => return Err(err_graphql_syntax(&format!("unexpected character `{other}`"), line, column)),
+    }
+  }
+  let column = index - line_start + 1;
+  tokens.push((Token::End, line, column));
+  Ok(tokens)
+}
+
+/// Parser of GraphQL queries.
+struct Parser {
+  /// Tokens with line and column numbers.
+  tokens: Vec<(Token, usize, usize)>,
+  /// Index of the current token.
+  position: usize,
+}
+
+impl Parser {
+  /// Returns the current token.
+  fn peek(&self) -> &Token {
+    &self.tokens[self.position].0
+  }
+
+  /// Returns the current token and advances to the next one.
+  fn next(&mut self) -> Token {
+    let token = self.tokens[self.position].0.clone();
+    if self.position + 1 < self.tokens.len() {
+      self.position += 1;
+    }
+    token
+  }
+
+  /// Returns the error reported at the current token.
+  fn error(&self, message: &str) -> dmntk_common::DmntkError {
+    let (_, line, column) = &self.tokens[self.position];
+    err_graphql_syntax(message, *line, *column)
+  }
+
+  /// Returns the error reporting the current token as unexpected.
+  fn unexpected(&self) -> dmntk_common::DmntkError {
+    self.error(&format!("unexpected {}", self.peek().text()))
+  }
+
+  /// Consumes the expected punctuator.
+  fn expect(&mut self, punctuator: char) -> Result<()> {
+    if *self.peek() == Token::Punctuator(punctuator) {
+      self.next();
+      Ok(())
+    } else {
+      Err(self.error(&format!("expected `{punctuator}`, found {}", self.peek().text())))
+    }
+  }
+
+  /// Consumes the punctuator when it is the current token.
+  fn accept(&mut self, punctuator: char) -> bool {
+    if *self.peek() == Token::Punctuator(punctuator) {
+      self.next();
+      true
+    } else {
+      false
+    }
+  }
+
+  /// Consumes a name.
+  fn name(&mut self) -> Result<String> {
+    match self.peek().clone() {
+      Token::Name(name) => {
+        self.next();
+        Ok(name)
+      }
+      other => Err(self.error(&format!("expected name, found {}", other.text()))),
+    }
+  }
+
+  /// Parses the document containing operations.
+  fn document(&mut self) -> Result<Vec<Operation>> {
+    let mut operations = vec![];
+    while *self.peek() != Token::End {
+      operations.push(self.operation()?);
+    }
+    if operations.is_empty() {
+      return Err(self.error("no operations in query"));
+    }
+    Ok(operations)
+  }
+
+  /// Parses a single operation.
+  fn operation(&mut self) -> Result<Operation> {
+    let mut operation = Operation {
+      name: None,
+      variables: vec![],
+      selections: vec![],
+    };
+    match self.peek().clone() {
+      Token::Punctuator('{') => {}
+      Token::Name(keyword) if keyword == "query" => {
+        self.next();
+        if let Token::Name(_) = self.peek() {
+          operation.name = Some(self.name()?);
+        }
+        if self.accept('(') {
+          while !self.accept(')') {
+            operation.variables.push(self.variable_definition()?);
+          }
+        }
+        self.directives()?;
+      }
+      Token::Name(keyword) if keyword == "mutation" || keyword == "subscription" => return Err(self.error(&format!("{keyword} operations are not supported"))),
+      Token::Name(keyword) if keyword == "fragment" => return Err(self.error("fragments are not supported")),
+      _ => return Err(self.unexpected()),
+    }
+    operation.selections = self.selection_set()?;
+    Ok(operation)
+  }
+
+  /// Parses the definition of a variable, type of the variable is checked only syntactically.
+  fn variable_definition(&mut self) -> Result<(String, Option<GqlValue>)> {
+    self.expect('$')?;
+    let name = self.name()?;
+    self.expect(':')?;
+    self.type_reference()?;
+    let default_value = if self.accept('=') { Some(self.value(true)?) } else { None };
+    self.directives()?;
+    Ok((name, default_value))
+  }
+
+  /// Parses the reference to a type.
+  fn type_reference(&mut self) -> Result<()> {
+    if self.accept('[') {
+      self.type_reference()?;
+      self.expect(']')?;
+    } else {
+      self.name()?;
+    }
+    self.accept('!');
+    Ok(())
+  }
+
+  /// Reports directives as not supported.
+  fn directives(&mut self) -> Result<()> {
+    if *self.peek() == Token::Punctuator('@') {
+      Err(self.error("directives are not supported"))
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Parses the selection set.
+  fn selection_set(&mut self) -> Result<Vec<Field>> {
+    self.expect('{')?;
+    let mut selections = vec![];
+    while !self.accept('}') {
+      if *self.peek() == Token::Spread {
+        return Err(self.error("fragments are not supported"));
+      }
+      selections.push(self.field()?);
+    }
+    if selections.is_empty() {
+      return Err(self.error("selection set must not be empty"));
+    }
+    Ok(selections)
+  }
+
+  /// Parses the field with optional alias, arguments and selection set.
+  fn field(&mut self) -> Result<Field> {
+    let mut name = self.name()?;
+    let mut alias = None;
+    if self.accept(':') {
+      alias = Some(name);
+      name = self.name()?;
+    }
+    let mut arguments = vec![];
+    if self.accept('(') {
+      while !self.accept(')') {
+        let argument_name = self.name()?;
+        self.expect(':')?;
+        arguments.push((argument_name, self.value(false)?));
+      }
+    }
+    self.directives()?;
+    let selections = if *self.peek() == Token::Punctuator('{') { self.selection_set()? } else { vec![] };
+    Ok(Field {
+      alias,
+      name,
+      arguments,
+      selections,
+    })
+  }
+
+  /// Parses a value, constant values can not contain variables.
+  fn value(&mut self, constant: bool) -> Result<GqlValue> {
+    match self.peek().clone() {
+      Token::Punctuator('$') if !constant => {
+        self.next();
+        Ok(GqlValue::Variable(self.name()?))
+      }
+      Token::Number(number) => {
+        self.next();
+        Ok(GqlValue::Number(number))
+      }
+      Token::String(value) => {
+        self.next();
+        Ok(GqlValue::String(value))
+      }
+      Token::Name(name) => {
+        self.next();
+        Ok(match name.as_str() {
+          "true" => GqlValue::Boolean(true),
+          "false" => GqlValue::Boolean(false),
+          "null" => GqlValue::Null,
+          _ => GqlValue::Enum(name),
+        })
+      }
+      Token::Punctuator('[') => {
+        self.next();
+        let mut items = vec![];
+        while !self.accept(']') {
+          items.push(self.value(constant)?);
+        }
+        Ok(GqlValue::List(items))
+      }
+      Token::Punctuator('{') => {
+        self.next();
+        let mut fields = vec![];
+        while !self.accept('}') {
+          let name = self.name()?;
+          self.expect(':')?;
+          fields.push((name, self.value(constant)?));
+        }
+        Ok(GqlValue::Object(fields))
+      }
+      _ => Err(self.unexpected()),
+    }
+  }
+}
+
+/// Parses the query and returns the operation to be executed.
+///
+/// When the query contains multiple operations, the name of the operation must be specified.
+pub(crate) fn parse_query(query: &str, operation_name: Option<&str>) -> Result<Operation> {
+  let mut parser = Parser {
+    tokens: tokenize(query)?,
+    position: 0,
+  };
+  let mut operations = parser.document()?;
+  match operation_name {
+    Some(operation_name) => operations
+      .into_iter()
+      .find(|operation| operation.name.as_deref() == Some(operation_name))
+      .ok_or_else(|| err_graphql_operation_not_found(operation_name)),
+    None if operations.len() == 1 => Ok(operations.remove(0)),
+    None => Err(err_graphql_operation_name_required()),
+  }
+}
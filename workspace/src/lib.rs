@@ -3,8 +3,34 @@
 #[macro_use]
 extern crate dmntk_macros;
 
+mod audit;
+mod background;
 mod builder;
+mod canary;
+mod deployments;
 mod errors;
+#[cfg(feature = "graphql")]
+mod graphql;
+mod projection;
+mod services;
+mod shadow;
+mod stats;
+mod status;
+mod tenants;
+mod webhooks;
 mod workspaces;
 
+#[cfg(test)]
+mod tests;
+
+pub use audit::{AuditRecord, AuditSink, FileAuditSink, StdoutAuditSink};
+pub use canary::CanaryStatus;
+pub use deployments::DeploymentReport;
+pub use projection::Projection;
+pub use services::ServiceDescriptor;
+pub use shadow::{ExternalShadow, ShadowMismatch, ShadowSummary};
+pub use stats::InvocableStats;
+pub use status::{DeploymentFailure, DeploymentStatus};
+pub use tenants::{TenantMetrics, Tenants};
+pub use webhooks::{DeploymentEvent, ModelMetadata, Webhooks};
 pub use workspaces::Workspaces;
@@ -0,0 +1,113 @@
+//! # Projection of evaluation results
+//!
+//! Projection selects only chosen parts of the result of an evaluation, so large output contexts
+//! need not be returned as a whole. Parts are selected with a list of paths separated with commas.
+//! Each path is either a FEEL path with names of context entries separated with dots (`Offer.Limit`),
+//! or a JSON pointer with names separated with slashes (`/Offer/Limit`), where `~1` stands for `/`
+//! and `~0` stands for `~`.
+//!
+//! Selected entries of contexts retain their nesting, so the projected result has the same shape
+//! as the original result. A name applied to a list selects entries from each element of the list,
+//! a number applied to a list selects the element with this index, counted from zero.
+//! Results being `null` are returned unchanged.
+
+use crate::errors::*;
+use dmntk_common::Result;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::Name;
+use std::collections::BTreeMap;
+
+/// Tree of selected paths.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Selection {
+  /// Flag indicating if the whole value is selected.
+  whole: bool,
+  /// Selections of entries or elements, indexed by names or indexes.
+  children: BTreeMap<String, Selection>,
+}
+
+/// Projection of evaluation results to selected paths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Projection {
+  /// Tree of selected paths.
+  selection: Selection,
+}
+
+impl Projection {
+  /// Parses the list of paths separated with commas.
+  pub fn parse(paths: &str) -> Result<Self> {
+    let mut selection = Selection::default();
+    for path in paths.split(',').map(str::trim) {
+      let segments = match path.strip_prefix('/') {
+        Some(pointer) => pointer.split('/').map(|segment| segment.replace("~1", "/").replace("~0", "~")).collect::<Vec<String>>(),
+        None => path.split('.').map(|segment| segment.trim().to_string()).collect::<Vec<String>>(),
+      };
+      if segments.iter().any(String::is_empty) {
+        return Err(err_invalid_selected_path(path));
+      }
+      let mut node = &mut selection;
+      for segment in segments {
+        node = node.children.entry(segment).or_default();
+      }
+      node.whole = true;
+    }
+    Ok(Self { selection })
+  }
+
+  /// Returns the value with only selected parts.
+  pub fn apply(&self, value: &Value) -> Result<Value> {
+    select(value, &self.selection, "")
+  }
+}
+
+/// Returns the value with only parts selected in the selection tree,
+/// path of the value is used in error messages.
+fn select(value: &Value, selection: &Selection, path: &str) -> Result<Value> {
+  if selection.whole {
+    return Ok(value.clone());
+  }
+  match value {
+    Value::Null(_) => Ok(value.clone()),
+    Value::Context(ctx) => {
+      let mut selected = FeelContext::default();
+      for (segment, child) in &selection.children {
+        let name = Name::from(segment.as_str());
+        let child_path = child_path(path, segment);
+        let Some(entry_value) = ctx.get_entry(&name) else {
+          return Err(err_selected_path_not_found(&child_path));
+        };
+        selected.set_entry(&name, select(entry_value, child, &child_path)?);
+      }
+      Ok(Value::Context(selected))
+    }
+    Value::List(items) if selection.children.keys().all(|segment| segment.parse::<usize>().is_ok()) => {
+      let mut indexed = selection
+        .children
+        .iter()
+        .map(|(segment, child)| (segment.parse::<usize>().unwrap_or_default(), segment, child))
+        .collect::<Vec<_>>();
+      indexed.sort_by_key(|(index, _, _)| *index);
+      let mut selected = vec![];
+      for (index, segment, child) in indexed {
+        let child_path = child_path(path, segment);
+        let Some(item) = items.get(index) else {
+          return Err(err_selected_path_not_found(&child_path));
+        };
+        selected.push(select(item, child, &child_path)?);
+      }
+      Ok(Value::List(selected))
+    }
+    Value::List(items) => Ok(Value::List(items.iter().map(|item| select(item, selection, path)).collect::<Result<Vec<Value>>>()?)),
+    _ => Err(err_selected_path_not_found(&child_path(path, selection.children.keys().next().map_or("", String::as_str)))),
+  }
+}
+
+/// Returns the path of the child value.
+fn child_path(path: &str, segment: &str) -> String {
+  if path.is_empty() {
+    segment.to_string()
+  } else {
+    format!("{path}.{segment}")
+  }
+}
@@ -0,0 +1,133 @@
+//! # Decision service facade
+//!
+//! Decision services are exposed under paths built like invocable paths, the request body
+//! is a JSON object with members named after input parameters of the decision service,
+//! so consumers get an API shaped like the decision service, independent of the structure
+//! of the model behind it. Members not being input parameters of the decision service are rejected.
+//!
+//! Requests and responses are described with JSON Schema generated from types of input parameters
+//! and the type of the output variable of the decision service. Values without JSON representation,
+//! like ranges and functions, are not constrained.
+
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::{FeelType, Name};
+use serde_json::{json, Map, Value};
+
+/// Version of JSON Schema used in generated schemas.
+const JSON_SCHEMA_VERSION: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Decision service exposed with dedicated route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceDescriptor {
+  /// Path of the decision service, the same as the invocable path.
+  path: String,
+  /// Name of the decision service.
+  name: String,
+  /// Input parameters with types, input data are followed by input decisions.
+  parameters: Vec<(Name, FeelType)>,
+  /// Type of the output variable.
+  output_type: FeelType,
+}
+
+impl ServiceDescriptor {
+  /// Creates a descriptor of the decision service.
+  pub(crate) fn new(path: &str, name: &str, parameters: Vec<(Name, FeelType)>, output_type: FeelType) -> Self {
+    Self {
+      path: path.to_string(),
+      name: name.to_string(),
+      parameters,
+      output_type,
+    }
+  }
+
+  /// Returns the path of the decision service.
+  pub fn path(&self) -> &str {
+    &self.path
+  }
+
+  /// Returns the name of the decision service.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns input parameters with types.
+  pub fn parameters(&self) -> &[(Name, FeelType)] {
+    &self.parameters
+  }
+
+  /// Returns the type of the output variable.
+  pub fn output_type(&self) -> &FeelType {
+    &self.output_type
+  }
+
+  /// Returns JSON Schema of the request body.
+  pub fn request_schema(&self) -> String {
+    let properties = self
+      .parameters
+      .iter()
+      .map(|(name, feel_type)| (name.to_string(), json_schema(feel_type)))
+      .collect::<Map<String, Value>>();
+    json!({
+      "$schema": JSON_SCHEMA_VERSION,
+      "title": self.name,
+      "type": "object",
+      "properties": properties,
+      "additionalProperties": false
+    })
+    .to_string()
+  }
+
+  /// Returns JSON Schema of the response body, containing the result or errors.
+  pub fn response_schema(&self) -> String {
+    json!({
+      "$schema": JSON_SCHEMA_VERSION,
+      "title": self.name,
+      "type": "object",
+      "properties": {
+        "data": json_schema(&self.output_type),
+        "errors": {
+          "type": "array",
+          "items": {
+            "type": "object",
+            "properties": {
+              "detail": { "type": "string" }
+            }
+          }
+        }
+      }
+    })
+    .to_string()
+  }
+
+  /// Returns names of entries in input data that are not input parameters of the decision service.
+  pub(crate) fn unknown_parameters(&self, input_data: &FeelContext) -> Vec<String> {
+    input_data
+      .iter()
+      .filter(|(name, _)| !self.parameters.iter().any(|(parameter_name, _)| parameter_name == *name))
+      .map(|(name, _)| name.to_string())
+      .collect()
+  }
+}
+
+/// Returns JSON Schema of values of specified type.
+pub(crate) fn json_schema(feel_type: &FeelType) -> Value {
+  match feel_type {
+    FeelType::Boolean => json!({ "type": "boolean" }),
+    FeelType::Context(entries) => {
+      let properties = entries
+        .iter()
+        .map(|(name, feel_type)| (name.to_string(), json_schema(feel_type)))
+        .collect::<Map<String, Value>>();
+      json!({ "type": "object", "properties": properties })
+    }
+    FeelType::Date => json!({ "type": "string", "format": "date" }),
+    FeelType::DateTime => json!({ "type": "string", "format": "date-time" }),
+    FeelType::DaysAndTimeDuration | FeelType::YearsAndMonthsDuration => json!({ "type": "string", "format": "duration" }),
+    FeelType::List(items_type) => json!({ "type": "array", "items": json_schema(items_type) }),
+    FeelType::Null => json!({ "type": "null" }),
+    FeelType::Number => json!({ "type": "number" }),
+    FeelType::String => json!({ "type": "string" }),
+    FeelType::Time => json!({ "type": "string", "format": "time" }),
+    FeelType::Any | FeelType::Function(_, _) | FeelType::Range(_) => json!({}),
+  }
+}
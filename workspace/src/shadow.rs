@@ -0,0 +1,258 @@
+//! # Shadow evaluation by an external engine
+//!
+//! When an external DMN endpoint is configured, input data of each evaluation is forwarded
+//! in background to the external endpoint, and the result returned by the external engine
+//! is compared with the result of the evaluation, which eases validating the migration
+//! of decision models from other engines. Input data is sent as a JSON object with HTTP POST
+//! to the URL built from the base URL of the endpoint and the invocable path. The result
+//! is read from the `data` member of the response, or from the whole response when it has no such member.
+//!
+//! Numbers are compared by value and results being `null` are equal, independently of the reason
+//! of the `null` value. Mismatches are reported on standard error and the most recent
+//! mismatches are retained, failed forwardings are counted and reported on standard error.
+//!
+//! Evaluations are forwarded by a fixed number of background threads sharing a single HTTP client,
+//! see [BackgroundWorker]. When the queue of evaluations waiting for forwarding is full,
+//! evaluations are not forwarded, only counted as dropped.
+
+use crate::background::BackgroundWorker;
+use dmntk_common::Jsonify;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use reqwest::blocking::Client;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+/// Timeout of a single forwarded evaluation.
+const SHADOW_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default number of background threads forwarding evaluations.
+const SHADOW_THREADS: usize = 4;
+
+/// Default number of evaluations waiting for forwarding.
+const SHADOW_QUEUE_CAPACITY: usize = 1000;
+
+/// Number of most recent mismatches retained.
+const MISMATCH_WINDOW: usize = 100;
+
+/// Evaluation with the result different from the result returned by the external engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowMismatch {
+  /// Path of the evaluated invocable.
+  invocable_path: String,
+  /// Input data forwarded to the external engine, as JSON.
+  input_data: String,
+  /// Result of the evaluation, as JSON.
+  result: String,
+  /// Result returned by the external engine, as JSON.
+  external: String,
+}
+
+impl ShadowMismatch {
+  /// Returns the path of the evaluated invocable.
+  pub fn invocable_path(&self) -> &str {
+    &self.invocable_path
+  }
+
+  /// Returns input data forwarded to the external engine, as JSON.
+  pub fn input_data(&self) -> &str {
+    &self.input_data
+  }
+
+  /// Returns the result of the evaluation, as JSON.
+  pub fn result(&self) -> &str {
+    &self.result
+  }
+
+  /// Returns the result returned by the external engine, as JSON.
+  pub fn external(&self) -> &str {
+    &self.external
+  }
+}
+
+/// Summary of shadow evaluations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowSummary {
+  /// Base URL of the external endpoint.
+  url: String,
+  /// Number of evaluations with the same result returned by the external engine.
+  matched: u64,
+  /// Number of evaluations with different result returned by the external engine.
+  mismatched: u64,
+  /// Number of evaluations that could not be forwarded or returned invalid response.
+  failed: u64,
+  /// Number of evaluations not forwarded because the queue was full.
+  dropped: u64,
+  /// Most recent mismatches, the oldest first.
+  mismatches: Vec<ShadowMismatch>,
+}
+
+impl ShadowSummary {
+  /// Returns the base URL of the external endpoint.
+  pub fn url(&self) -> &str {
+    &self.url
+  }
+
+  /// Returns the number of evaluations with the same result returned by the external engine.
+  pub fn matched(&self) -> u64 {
+    self.matched
+  }
+
+  /// Returns the number of evaluations with different result returned by the external engine.
+  pub fn mismatched(&self) -> u64 {
+    self.mismatched
+  }
+
+  /// Returns the number of evaluations that could not be forwarded or returned invalid response.
+  pub fn failed(&self) -> u64 {
+    self.failed
+  }
+
+  /// Returns the number of evaluations not forwarded because the queue was full.
+  pub fn dropped(&self) -> u64 {
+    self.dropped
+  }
+
+  /// Returns the most recent mismatches, the oldest first.
+  pub fn mismatches(&self) -> &[ShadowMismatch] {
+    &self.mismatches
+  }
+}
+
+/// Outcomes of shadow evaluations, shared with background threads.
+#[derive(Default)]
+struct Outcomes {
+  /// Number of matching evaluations.
+  matched: AtomicU64,
+  /// Number of mismatching evaluations.
+  mismatched: AtomicU64,
+  /// Number of failed forwardings.
+  failed: AtomicU64,
+  /// Most recent mismatches.
+  mismatches: Mutex<VecDeque<ShadowMismatch>>,
+}
+
+/// External DMN endpoint evaluating the same invocables in the shadow.
+#[derive(Clone)]
+pub struct ExternalShadow {
+  /// Base URL of the external endpoint, without trailing slash.
+  url: String,
+  /// Outcomes of shadow evaluations.
+  outcomes: Arc<Outcomes>,
+  /// HTTP client shared by all background threads, built by the first forwarding thread.
+  client: Arc<OnceLock<reqwest::Result<Client>>>,
+  /// Background threads forwarding evaluations.
+  worker: Arc<BackgroundWorker>,
+}
+
+impl ExternalShadow {
+  /// Creates a shadow forwarding evaluations to the endpoint with specified base URL.
+  pub fn new(url: &str) -> Self {
+    Self::with_limits(url, SHADOW_THREADS, SHADOW_QUEUE_CAPACITY)
+  }
+
+  /// Creates a shadow forwarding evaluations to the endpoint with specified base URL,
+  /// by specified number of background threads, with specified number of evaluations
+  /// waiting for forwarding.
+  pub fn with_limits(url: &str, threads: usize, capacity: usize) -> Self {
+    Self {
+      url: url.trim_end_matches('/').to_string(),
+      outcomes: Default::default(),
+      client: Default::default(),
+      worker: Arc::new(BackgroundWorker::new("dmntk-shadow", threads, capacity)),
+    }
+  }
+
+  /// Returns the summary of shadow evaluations.
+  pub fn summary(&self) -> ShadowSummary {
+    ShadowSummary {
+      url: self.url.clone(),
+      matched: self.outcomes.matched.load(Ordering::Relaxed),
+      mismatched: self.outcomes.mismatched.load(Ordering::Relaxed),
+      failed: self.outcomes.failed.load(Ordering::Relaxed),
+      dropped: self.worker.dropped(),
+      mismatches: self.outcomes.mismatches.lock().unwrap().iter().cloned().collect(),
+    }
+  }
+
+  /// Queues forwarding input data of the evaluation to the external endpoint
+  /// and comparing the returned result with the result of the evaluation.
+  /// Returns `false` when the evaluation was dropped because the queue is full.
+  pub fn forward(&self, invocable_path: &str, input_data: &FeelContext, result: &Value) -> bool {
+    let url = format!("{}/{invocable_path}", self.url);
+    let outcomes = Arc::clone(&self.outcomes);
+    let client = Arc::clone(&self.client);
+    let invocable_path = invocable_path.to_string();
+    let input_data = input_data.jsonify();
+    let result = to_json(result);
+    self.worker.submit(move || {
+      let response = match client.get_or_init(|| Client::builder().timeout(SHADOW_TIMEOUT).build()) {
+        Ok(client) => client
+          .post(&url)
+          .header("Content-Type", "application/json")
+          .body(input_data.clone())
+          .send()
+          .and_then(|response| response.error_for_status())
+          .and_then(|response| response.json::<serde_json::Value>()),
+        Err(reason) => {
+          outcomes.failed.fetch_add(1, Ordering::Relaxed);
+          eprintln!("shadow evaluation of '{invocable_path}' by {url} failed with reason: {reason}");
+          return;
+        }
+      };
+      let external = match response {
+        Ok(mut document) => match document.get_mut("data") {
+          Some(data) => data.take(),
+          None => document,
+        },
+        Err(reason) => {
+          outcomes.failed.fetch_add(1, Ordering::Relaxed);
+          eprintln!("shadow evaluation of '{invocable_path}' by {url} failed with reason: {reason}");
+          return;
+        }
+      };
+      if same_results(&result, &external) {
+        outcomes.matched.fetch_add(1, Ordering::Relaxed);
+        return;
+      }
+      outcomes.mismatched.fetch_add(1, Ordering::Relaxed);
+      let mismatch = ShadowMismatch {
+        invocable_path,
+        input_data,
+        result: result.to_string(),
+        external: external.to_string(),
+      };
+      eprintln!(
+        "shadow mismatch in '{}' for input {}: result {}, external result {}",
+        mismatch.invocable_path, mismatch.input_data, mismatch.result, mismatch.external
+      );
+      let mut mismatches = outcomes.mismatches.lock().unwrap();
+      if mismatches.len() == MISMATCH_WINDOW {
+        mismatches.pop_front();
+      }
+      mismatches.push_back(mismatch);
+    })
+  }
+}
+
+/// Converts the result of the evaluation into JSON, `null` values are converted without the reason.
+fn to_json(value: &Value) -> serde_json::Value {
+  if value.is_null() {
+    return serde_json::Value::Null;
+  }
+  serde_json::from_str(&value.jsonify()).unwrap_or_else(|_| serde_json::Value::String(value.to_string()))
+}
+
+/// Returns `true` when both results are equal, numbers are compared by value.
+pub(crate) fn same_results(lhs: &serde_json::Value, rhs: &serde_json::Value) -> bool {
+  match (lhs, rhs) {
+    (serde_json::Value::Number(lhs), serde_json::Value::Number(rhs)) => lhs.as_f64() == rhs.as_f64(),
+    (serde_json::Value::Array(lhs), serde_json::Value::Array(rhs)) => lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(lhs, rhs)| same_results(lhs, rhs)),
+    (serde_json::Value::Object(lhs), serde_json::Value::Object(rhs)) => {
+      lhs.len() == rhs.len() && lhs.iter().all(|(name, lhs)| rhs.get(name).is_some_and(|rhs| same_results(lhs, rhs)))
+    }
+    _ => lhs == rhs,
+  }
+}
@@ -0,0 +1,200 @@
+//! # Evaluation statistics of invocables
+//!
+//! Statistics are collected for each evaluated invocable: the number of evaluations,
+//! the number of evaluations returning `null` with a reason (failed evaluations),
+//! the mean latency of all evaluations and latency percentiles computed from
+//! the most recent evaluations. Recording an evaluation takes no locks, latencies
+//! are stored in a ring buffer of atomic counters. Statistics of invocables
+//! of undeployed models are removed.
+
+use arc_swap::ArcSwap;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Number of most recent latencies used to compute percentiles.
+const LATENCY_WINDOW: usize = 1024;
+
+/// Snapshot of statistics collected for a single invocable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvocableStats {
+  /// Path of the invocable.
+  invocable_path: String,
+  /// Number of evaluations.
+  evaluations: u64,
+  /// Number of failed evaluations.
+  failures: u64,
+  /// Mean latency of all evaluations.
+  mean: Duration,
+  /// Median latency of recent evaluations.
+  p50: Duration,
+  /// 95th percentile of latency of recent evaluations.
+  p95: Duration,
+  /// 99th percentile of latency of recent evaluations.
+  p99: Duration,
+}
+
+impl InvocableStats {
+  /// Returns the path of the invocable.
+  pub fn invocable_path(&self) -> &str {
+    &self.invocable_path
+  }
+
+  /// Returns the number of evaluations.
+  pub fn evaluations(&self) -> u64 {
+    self.evaluations
+  }
+
+  /// Returns the number of failed evaluations.
+  pub fn failures(&self) -> u64 {
+    self.failures
+  }
+
+  /// Returns the mean latency of all evaluations.
+  pub fn mean(&self) -> Duration {
+    self.mean
+  }
+
+  /// Returns the median latency of recent evaluations.
+  pub fn p50(&self) -> Duration {
+    self.p50
+  }
+
+  /// Returns the 95th percentile of latency of recent evaluations.
+  pub fn p95(&self) -> Duration {
+    self.p95
+  }
+
+  /// Returns the 99th percentile of latency of recent evaluations.
+  pub fn p99(&self) -> Duration {
+    self.p99
+  }
+}
+
+/// Statistics recorded for a single invocable.
+struct Recorder {
+  /// Number of evaluations.
+  evaluations: AtomicU64,
+  /// Number of failed evaluations.
+  failures: AtomicU64,
+  /// Total latency of all evaluations in nanoseconds.
+  total_nanos: AtomicU64,
+  /// Ring buffer with latencies of the most recent evaluations in nanoseconds,
+  /// the slot of each evaluation is the number of preceding evaluations modulo the window size.
+  latencies: Box<[AtomicU64]>,
+}
+
+impl Default for Recorder {
+  /// Creates a recorder with no evaluations.
+  fn default() -> Self {
+    Self {
+      evaluations: AtomicU64::new(0),
+      failures: AtomicU64::new(0),
+      total_nanos: AtomicU64::new(0),
+      latencies: (0..LATENCY_WINDOW).map(|_| AtomicU64::new(0)).collect(),
+    }
+  }
+}
+
+impl Recorder {
+  /// Records a single evaluation.
+  fn record(&self, latency: Duration, failed: bool) {
+    let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+    let index = self.evaluations.fetch_add(1, Ordering::Relaxed);
+    self.total_nanos.fetch_add(nanos, Ordering::Relaxed);
+    if failed {
+      self.failures.fetch_add(1, Ordering::Relaxed);
+    }
+    self.latencies[(index % LATENCY_WINDOW as u64) as usize].store(nanos, Ordering::Relaxed);
+  }
+
+  /// Returns the snapshot of recorded statistics.
+  ///
+  /// Latencies recorded concurrently with taking the snapshot may be not yet visible,
+  /// so percentiles are approximate while the invocable is being evaluated.
+  fn stats(&self, invocable_path: &str) -> InvocableStats {
+    let evaluations = self.evaluations.load(Ordering::Relaxed);
+    let recorded = evaluations.min(LATENCY_WINDOW as u64) as usize;
+    let mut latencies = self.latencies[..recorded].iter().map(|latency| latency.load(Ordering::Relaxed)).collect::<Vec<u64>>();
+    latencies.sort_unstable();
+    let mean = self.total_nanos.load(Ordering::Relaxed).checked_div(evaluations).unwrap_or_default();
+    InvocableStats {
+      invocable_path: invocable_path.to_string(),
+      evaluations,
+      failures: self.failures.load(Ordering::Relaxed),
+      mean: Duration::from_nanos(mean),
+      p50: percentile(&latencies, 50),
+      p95: percentile(&latencies, 95),
+      p99: percentile(&latencies, 99),
+    }
+  }
+}
+
+/// Returns the percentile of sorted latencies, using the nearest-rank method.
+fn percentile(sorted_latencies: &[u64], percent: usize) -> Duration {
+  if sorted_latencies.is_empty() {
+    return Duration::ZERO;
+  }
+  let rank = (percent * sorted_latencies.len()).div_ceil(100).max(1);
+  Duration::from_nanos(sorted_latencies[rank - 1])
+}
+
+/// Collector of evaluation statistics of invocables.
+///
+/// Recorders of already evaluated invocables are found without locking,
+/// the map of recorders is replaced only when an invocable is evaluated for the first time.
+#[derive(Default)]
+pub(crate) struct StatsCollector {
+  /// Map: invocable path -> recorded statistics
+  recorders: ArcSwap<HashMap<String, Arc<Recorder>>>,
+}
+
+impl StatsCollector {
+  /// Records a single evaluation of specified invocable.
+  pub(crate) fn record(&self, invocable_path: &str, latency: Duration, failed: bool) {
+    if let Some(recorder) = self.recorders.load().get(invocable_path) {
+      recorder.record(latency, failed);
+      return;
+    }
+    self.recorders.rcu(|recorders| {
+      let mut recorders = HashMap::clone(recorders);
+      recorders.entry(invocable_path.to_string()).or_default();
+      recorders
+    });
+    if let Some(recorder) = self.recorders.load().get(invocable_path) {
+      recorder.record(latency, failed);
+    }
+  }
+
+  /// Returns statistics of all evaluated invocables, ordered by invocable paths.
+  pub(crate) fn stats(&self) -> Vec<InvocableStats> {
+    let mut stats = self
+      .recorders
+      .load()
+      .iter()
+      .map(|(invocable_path, recorder)| recorder.stats(invocable_path))
+      .collect::<Vec<InvocableStats>>();
+    stats.sort_by(|a, b| a.invocable_path.cmp(&b.invocable_path));
+    stats
+  }
+
+  /// Removes statistics of specified invocables.
+  pub(crate) fn remove(&self, invocable_paths: &[String]) {
+    if !invocable_paths.iter().any(|invocable_path| self.recorders.load().contains_key(invocable_path)) {
+      return;
+    }
+    self.recorders.rcu(|recorders| {
+      let mut recorders = HashMap::clone(recorders);
+      for invocable_path in invocable_paths {
+        recorders.remove(invocable_path);
+      }
+      recorders
+    });
+  }
+
+  /// Returns statistics of specified invocable, when it was already evaluated.
+  pub(crate) fn invocable_stats(&self, invocable_path: &str) -> Option<InvocableStats> {
+    self.recorders.load().get(invocable_path).map(|recorder| recorder.stats(invocable_path))
+  }
+}
@@ -0,0 +1,88 @@
+//! # Status of deployments
+
+use std::time::Duration;
+
+/// Failure of loading a model file or deploying a workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentFailure {
+  /// Model file or workspace that failed.
+  source: String,
+  /// Reason of the failure.
+  reason: String,
+}
+
+impl DeploymentFailure {
+  /// Creates a deployment failure.
+  pub(crate) fn new(source: &str, reason: &str) -> Self {
+    Self {
+      source: source.to_string(),
+      reason: reason.to_string(),
+    }
+  }
+
+  /// Returns the model file or workspace that failed.
+  pub fn source(&self) -> &str {
+    &self.source
+  }
+
+  /// Returns the reason of the failure.
+  pub fn reason(&self) -> &str {
+    &self.reason
+  }
+}
+
+/// Summary of loading and deploying decision models.
+#[derive(Debug, Clone, Default)]
+pub struct DeploymentStatus {
+  /// Total number of model files found.
+  pub(crate) file_count: usize,
+  /// The number of successfully loaded models.
+  pub(crate) loaded_count: usize,
+  /// The number of models that failed to load.
+  pub(crate) failed_loads_count: usize,
+  /// The number of workspaces that failed to deploy.
+  pub(crate) failed_deployments_count: usize,
+  /// The number of deployed invocables.
+  pub(crate) invocable_count: usize,
+  /// Time spent on loading and deploying models.
+  pub(crate) duration: Duration,
+  /// Failures of loading models and deploying workspaces, in order of occurrence.
+  pub(crate) failures: Vec<DeploymentFailure>,
+}
+
+impl DeploymentStatus {
+  /// Returns the total number of model files found.
+  pub fn file_count(&self) -> usize {
+    self.file_count
+  }
+
+  /// Returns the number of successfully loaded models.
+  pub fn loaded_count(&self) -> usize {
+    self.loaded_count
+  }
+
+  /// Returns the number of models that failed to load.
+  pub fn failed_loads_count(&self) -> usize {
+    self.failed_loads_count
+  }
+
+  /// Returns the number of workspaces that failed to deploy.
+  pub fn failed_deployments_count(&self) -> usize {
+    self.failed_deployments_count
+  }
+
+  /// Returns the number of deployed invocables.
+  pub fn invocable_count(&self) -> usize {
+    self.invocable_count
+  }
+
+  /// Returns the time spent on loading and deploying models.
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+
+  /// Returns failures of loading models and deploying workspaces.
+  pub fn failures(&self) -> &[DeploymentFailure] {
+    &self.failures
+  }
+}
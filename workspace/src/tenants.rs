@@ -0,0 +1,244 @@
+//! # Isolated workspaces of tenants
+//!
+//! Each subdirectory of the tenants directory contains decision models of a single tenant.
+//! Workspaces of a tenant are loaded from its directory on the first request to this tenant,
+//! so tenants not used since the server started consume no resources.
+//!
+//! The optional file named `tenant.json` in the directory of the tenant holds the configuration
+//! of the tenant, overriding the configuration shared by all tenants, for example:
+//!
+//! ```json
+//! {
+//!   "conformanceLevel": "2",
+//!   "webhooks": ["https://hooks.example.com/dmntk"],
+//!   "audit": "audit.log",
+//!   "shadowUrl": "https://engine.example.com/evaluate"
+//! }
+//! ```
+//!
+//! All properties are optional. The audit sink is `stdout` or the name of the file audit records
+//! are appended to, relative to the directory of the tenant. Tenants with invalid configuration
+//! are not loaded, evaluations of their invocables report the configuration error.
+//!
+//! Loading workspaces of one tenant does not block evaluations in other tenants,
+//! the map of tenants is locked only to find or register the tenant.
+
+use crate::audit::{AuditSink, FileAuditSink, StdoutAuditSink};
+use crate::errors::*;
+use crate::shadow::ExternalShadow;
+use crate::webhooks::Webhooks;
+use crate::workspaces::Workspaces;
+use dmntk_common::{message, ColorPalette, DmntkError, Message, Result};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::ConformanceLevel;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Name of the file holding the configuration of the tenant.
+const TENANT_CONFIGURATION_FILE: &str = "tenant.json";
+
+/// Snapshot of metrics collected for a single tenant.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TenantMetrics {
+  /// Number of deployed invocables.
+  invocables: usize,
+  /// Number of requested evaluations.
+  evaluations: usize,
+  /// Number of evaluations that failed, because the invocable was not found.
+  failures: usize,
+}
+
+impl TenantMetrics {
+  /// Returns the number of deployed invocables.
+  pub fn invocables(&self) -> usize {
+    self.invocables
+  }
+
+  /// Returns the number of requested evaluations.
+  pub fn evaluations(&self) -> usize {
+    self.evaluations
+  }
+
+  /// Returns the number of evaluations that failed.
+  pub fn failures(&self) -> usize {
+    self.failures
+  }
+}
+
+/// Single tenant, its workspaces are loaded on first use.
+#[derive(Default)]
+struct Tenant {
+  /// Workspaces loaded from the directory of the tenant, or the reason of failed loading.
+  workspaces: OnceLock<std::result::Result<Workspaces, Message>>,
+  /// Number of requested evaluations.
+  evaluations: AtomicUsize,
+  /// Number of evaluations that failed.
+  failures: AtomicUsize,
+}
+
+/// Container for isolated workspaces of tenants.
+pub struct Tenants {
+  /// Directory containing directories of all tenants.
+  root_dir: PathBuf,
+  /// Color palette used when loading workspaces.
+  colors: ColorPalette,
+  /// Flag indicating the level of verbosity when loading workspaces.
+  verbose: bool,
+  /// Conformance level the decision models of tenants are deployed at, unless configured by the tenant.
+  conformance_level: ConformanceLevel,
+  /// Sink receiving audit records of evaluations of tenants, unless configured by the tenant.
+  audit_sink: Option<Arc<dyn AuditSink>>,
+  /// Map: tenant name -> tenant
+  tenants: RwLock<HashMap<String, Arc<Tenant>>>,
+}
+
+impl Tenants {
+  /// Creates a new [Tenants] serving tenants from subdirectories of specified directory.
+  pub fn new(root_dir: PathBuf, colors: ColorPalette, verbose: bool) -> Self {
+    Self {
+      root_dir,
+      colors,
+      verbose,
+      conformance_level: ConformanceLevel::default(),
+      audit_sink: None,
+      tenants: RwLock::new(HashMap::new()),
+    }
+  }
+
+  /// Sets the conformance level the decision models of tenants are deployed at,
+  /// unless configured by the tenant.
+  pub fn with_conformance_level(mut self, conformance_level: ConformanceLevel) -> Self {
+    self.conformance_level = conformance_level;
+    self
+  }
+
+  /// Sets the sink receiving audit records of evaluations of tenants,
+  /// unless configured by the tenant.
+  pub fn with_audit_sink(mut self, audit_sink: Arc<dyn AuditSink>) -> Self {
+    self.audit_sink = Some(audit_sink);
+    self
+  }
+
+  /// Evaluates invocable identified by invocable path in workspaces of specified tenant.
+  pub fn evaluate(&self, tenant_name: &str, invocable_path: &str, input_data: &FeelContext) -> Result<Value> {
+    let tenant = self.tenant(tenant_name)?;
+    tenant.evaluations.fetch_add(1, Ordering::Relaxed);
+    let result = self.workspaces(tenant_name, &tenant).and_then(|workspaces| workspaces.evaluate(invocable_path, input_data));
+    if result.is_err() {
+      tenant.failures.fetch_add(1, Ordering::Relaxed);
+    }
+    result
+  }
+
+  /// Returns metrics collected for specified tenant.
+  pub fn metrics(&self, tenant_name: &str) -> Result<TenantMetrics> {
+    let tenant = self.tenant(tenant_name)?;
+    let workspaces = self.workspaces(tenant_name, &tenant)?;
+    Ok(TenantMetrics {
+      invocables: workspaces.invocables.len(),
+      evaluations: tenant.evaluations.load(Ordering::Relaxed),
+      failures: tenant.failures.load(Ordering::Relaxed),
+    })
+  }
+
+  /// Returns the tenant with specified name, registering it on first use.
+  fn tenant(&self, tenant_name: &str) -> Result<Arc<Tenant>> {
+    // tenant names are plain directory names, so requests can not reach directories of other tenants
+    let valid_name = !tenant_name.is_empty() && tenant_name.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if !valid_name || !self.root_dir.join(tenant_name).is_dir() {
+      return Err(err_tenant_not_found(tenant_name));
+    }
+    if let Some(tenant) = self.tenants.read().unwrap().get(tenant_name) {
+      return Ok(Arc::clone(tenant));
+    }
+    let mut tenants = self.tenants.write().unwrap();
+    Ok(Arc::clone(tenants.entry(tenant_name.to_string()).or_default()))
+  }
+
+  /// Returns workspaces of the tenant, loading them on first use.
+  ///
+  /// Workspaces are loaded without holding the lock of the map of tenants,
+  /// concurrent requests to the same tenant wait until loading completes.
+  fn workspaces<'a>(&self, tenant_name: &str, tenant: &'a Tenant) -> Result<&'a Workspaces> {
+    tenant
+      .workspaces
+      .get_or_init(|| self.load(&self.root_dir.join(tenant_name)))
+      .as_ref()
+      .map_err(|reason| err_invalid_tenant_configuration(tenant_name, reason.clone()))
+  }
+
+  /// Loads workspaces from the directory of the tenant, configured as specified in the configuration file of the tenant.
+  fn load(&self, dir: &Path) -> std::result::Result<Workspaces, Message> {
+    let configuration = TenantConfiguration::read(dir)?;
+    let conformance_level = configuration.conformance_level.unwrap_or(self.conformance_level);
+    let mut workspaces = Workspaces::new_at_conformance_level(dir, self.colors.clone(), self.verbose, conformance_level);
+    if let Some(audit_sink) = configuration.audit_sink.or_else(|| self.audit_sink.clone()) {
+      workspaces = workspaces.with_audit_sink(audit_sink);
+    }
+    if let Some(webhooks) = configuration.webhooks {
+      workspaces = workspaces.with_webhooks(webhooks);
+    }
+    if let Some(external_shadow) = configuration.external_shadow {
+      workspaces = workspaces.with_external_shadow(external_shadow);
+    }
+    Ok(workspaces)
+  }
+}
+
+/// Configuration of a single tenant, read from the configuration file in the directory of the tenant.
+#[derive(Default)]
+struct TenantConfiguration {
+  /// Conformance level the decision models of the tenant are deployed at.
+  conformance_level: Option<ConformanceLevel>,
+  /// Sink receiving audit records of evaluations of the tenant.
+  audit_sink: Option<Arc<dyn AuditSink>>,
+  /// Webhooks notified on deployment lifecycle events of the tenant.
+  webhooks: Option<Webhooks>,
+  /// External engine evaluating invocables of the tenant in the shadow.
+  external_shadow: Option<ExternalShadow>,
+}
+
+impl TenantConfiguration {
+  /// Reads the configuration from the configuration file in specified directory,
+  /// returns the default configuration when there is no configuration file.
+  fn read(dir: &Path) -> std::result::Result<Self, Message> {
+    let file_name = dir.join(TENANT_CONFIGURATION_FILE);
+    if !file_name.is_file() {
+      return Ok(Self::default());
+    }
+    let content = fs::read_to_string(&file_name).map_err(|reason| reason.to_string())?;
+    let document = serde_json::from_str::<serde_json::Value>(&content).map_err(|reason| reason.to_string())?;
+    let Some(properties) = document.as_object() else {
+      return Err(message!("expected JSON object"));
+    };
+    let mut configuration = Self::default();
+    for (name, value) in properties {
+      match (name.as_str(), value) {
+        ("conformanceLevel", serde_json::Value::String(_) | serde_json::Value::Number(_)) => {
+          let level = value.as_str().map_or_else(|| value.to_string(), str::to_string);
+          configuration.conformance_level = Some(level.parse().map_err(|reason: DmntkError| Message::from(reason))?);
+        }
+        ("webhooks", serde_json::Value::Array(items)) => {
+          let urls = items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string).ok_or_else(|| format!("expected URL of webhook, found: {item}")))
+            .collect::<std::result::Result<Vec<String>, String>>()?;
+          configuration.webhooks = Some(Webhooks::new(urls));
+        }
+        ("audit", serde_json::Value::String(s)) => {
+          configuration.audit_sink = match s.trim() {
+            "stdout" => Some(Arc::new(StdoutAuditSink)),
+            file_name => Some(Arc::new(FileAuditSink::new(&dir.join(file_name)).map_err(|reason| reason.to_string())?)),
+          }
+        }
+        ("shadowUrl", serde_json::Value::String(url)) => configuration.external_shadow = Some(ExternalShadow::new(url.trim())),
+        (other, value) => return Err(format!("invalid property '{other}' with value: {value}").into()),
+      }
+    }
+    Ok(configuration)
+  }
+}
@@ -0,0 +1,186 @@
+use super::*;
+use crate::audit::model_version;
+use crate::{AuditRecord, AuditSink, FileAuditSink};
+use dmntk_common::{Jsonify, Result};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+use std::sync::{Arc, Mutex};
+
+const MODEL_C: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/discounts" name="discounts" id="_discounts" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <inputData name="Amount" id="_amount">
+        <variable typeRef="number" name="Amount"/>
+    </inputData>
+    <decision name="Discount" id="_discount">
+        <variable typeRef="number" name="Discount"/>
+        <informationRequirement>
+            <requiredInput href="#_amount"/>
+        </informationRequirement>
+        <decisionTable id="_discount_table" hitPolicy="UNIQUE">
+            <input>
+                <inputExpression typeRef="number">
+                    <text>Amount</text>
+                </inputExpression>
+            </input>
+            <output typeRef="number"/>
+            <rule>
+                <description>small orders</description>
+                <inputEntry>
+                    <text>&lt; 100</text>
+                </inputEntry>
+                <outputEntry>
+                    <text>0</text>
+                </outputEntry>
+            </rule>
+            <rule>
+                <inputEntry>
+                    <text>&gt;= 100</text>
+                </inputEntry>
+                <outputEntry>
+                    <text>0.05</text>
+                </outputEntry>
+            </rule>
+        </decisionTable>
+    </decision>
+</definitions>
+"##;
+
+/// Audit sink collecting records in memory.
+#[derive(Default)]
+struct CollectingAuditSink {
+  records: Mutex<Vec<AuditRecord>>,
+}
+
+impl AuditSink for CollectingAuditSink {
+  fn store(&self, record: &AuditRecord) -> Result<()> {
+    self.records.lock().unwrap().push(record.clone());
+    Ok(())
+  }
+}
+
+/// Returns input data with the amount.
+fn amount(value: Value) -> FeelContext {
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value);
+  input_data
+}
+
+#[test]
+fn _0001() {
+  // evaluations of models deployed at runtime are audited with the deployment identifier as model version
+  let audit_sink = Arc::new(CollectingAuditSink::default());
+  let workspaces = empty_workspaces().with_audit_sink(audit_sink.clone());
+  let report = workspaces.deploy(MODEL_C).unwrap();
+  assert_eq!("0.05", workspaces.evaluate("io/dmntk/discounts/Discount", &amount(value_number!(250))).unwrap().to_string());
+  let records = audit_sink.records.lock().unwrap();
+  assert_eq!(1, records.len());
+  let record = &records[0];
+  assert_eq!("io/dmntk/discounts/Discount", record.invocable_path());
+  assert_eq!("https://dmntk.io/discounts", record.namespace());
+  assert_eq!("Discount", record.invocable_name());
+  assert_eq!(report.id(), record.model_version());
+  assert_eq!("{Amount: 250}", record.input_data().to_string());
+  assert_eq!("0.05", record.result().to_string());
+  assert_eq!(1, record.fired_rules().len());
+  assert_eq!("_discount_table", record.fired_rules()[0].decision_table_id());
+  assert_eq!(2, record.fired_rules()[0].rule_number());
+  assert!(chrono::DateTime::parse_from_rfc3339(record.timestamp()).is_ok());
+  assert_eq!(36, record.id().len());
+}
+
+#[test]
+fn _0002() {
+  // audit records are converted into single line JSON documents with typed values
+  let audit_sink = Arc::new(CollectingAuditSink::default());
+  let workspaces = empty_workspaces().with_audit_sink(audit_sink.clone());
+  workspaces.deploy(MODEL_C).unwrap();
+  workspaces.evaluate("io/dmntk/discounts/Discount", &amount(value_number!(50))).unwrap();
+  let json = audit_sink.records.lock().unwrap()[0].jsonify();
+  assert!(!json.contains('\n'));
+  let document: serde_json::Value = serde_json::from_str(&json).unwrap();
+  assert_eq!("io/dmntk/discounts/Discount", document["invocable"]);
+  assert_eq!("Amount", document["inputs"]["components"][0]["name"]);
+  assert_eq!(
+    r#"{"isNil":false,"text":"50","type":"xsd:decimal"}"#,
+    document["inputs"]["components"][0]["value"]["simple"].to_string()
+  );
+  assert_eq!(r#"{"isNil":false,"text":"0","type":"xsd:decimal"}"#, document["outputs"]["simple"].to_string());
+  assert_eq!(
+    r#"[{"decisionTableId":"_discount_table","description":"small orders","ruleNumber":1}]"#,
+    document["firedRules"].to_string()
+  );
+  assert!(document["durationMicros"].is_u64());
+}
+
+#[test]
+fn _0003() {
+  // evaluations of models loaded from workspace directory are audited with the digest of the model as model version
+  let dir = std::env::temp_dir().join(gen_id());
+  fs::create_dir_all(&dir).unwrap();
+  fs::write(dir.join("discounts.dmn"), MODEL_C).unwrap();
+  let audit_sink = Arc::new(CollectingAuditSink::default());
+  let workspaces = Workspaces::new(&dir, ColorMode::Off.into(), false).with_audit_sink(audit_sink.clone());
+  fs::remove_dir_all(&dir).unwrap();
+  workspaces.evaluate("io/dmntk/discounts/Discount", &amount(value_number!(50))).unwrap();
+  let records = audit_sink.records.lock().unwrap();
+  assert_eq!(model_version(MODEL_C), records[0].model_version());
+  assert_eq!(16, records[0].model_version().len());
+}
+
+#[test]
+fn _0004() {
+  // evaluations of not existing invocables are not audited
+  let audit_sink = Arc::new(CollectingAuditSink::default());
+  let workspaces = empty_workspaces().with_audit_sink(audit_sink.clone());
+  assert!(workspaces.evaluate("io/dmntk/discounts/Discount", &amount(value_number!(50))).is_err());
+  assert!(audit_sink.records.lock().unwrap().is_empty());
+}
+
+#[test]
+fn _0005() {
+  // file audit sink appends records as JSON lines
+  let file = std::env::temp_dir().join(format!("{}.jsonl", gen_id()));
+  let workspaces = empty_workspaces().with_audit_sink(Arc::new(FileAuditSink::new(&file).unwrap()));
+  workspaces.deploy(MODEL_C).unwrap();
+  workspaces.evaluate("io/dmntk/discounts/Discount", &amount(value_number!(50))).unwrap();
+  workspaces.evaluate("io/dmntk/discounts/Discount", &amount(value_number!(150))).unwrap();
+  let content = fs::read_to_string(&file).unwrap();
+  fs::remove_file(&file).unwrap();
+  let lines = content.lines().collect::<Vec<&str>>();
+  assert_eq!(2, lines.len());
+  assert!(lines.iter().all(|line| serde_json::from_str::<serde_json::Value>(line).is_ok()));
+}
+
+#[test]
+fn _0006() {
+  // audit records are parsed from JSON documents
+  let audit_sink = Arc::new(CollectingAuditSink::default());
+  let workspaces = empty_workspaces().with_audit_sink(audit_sink.clone());
+  workspaces.deploy(MODEL_C).unwrap();
+  workspaces.evaluate("io/dmntk/discounts/Discount", &amount(value_number!(50))).unwrap();
+  let record = audit_sink.records.lock().unwrap()[0].clone();
+  let parsed = record.jsonify().parse::<AuditRecord>().unwrap();
+  assert_eq!(record.id(), parsed.id());
+  assert_eq!(record.timestamp(), parsed.timestamp());
+  assert_eq!(record.invocable_path(), parsed.invocable_path());
+  assert_eq!(record.namespace(), parsed.namespace());
+  assert_eq!(record.invocable_name(), parsed.invocable_name());
+  assert_eq!(record.model_version(), parsed.model_version());
+  assert_eq!(record.input_data(), parsed.input_data());
+  assert_eq!(record.result(), parsed.result());
+  assert_eq!(record.fired_rules(), parsed.fired_rules());
+  assert_eq!(record.duration().as_micros(), parsed.duration().as_micros());
+}
+
+#[test]
+fn _0007() {
+  assert_eq!(
+    "<WorkspaceError> invalid audit record: expected ident at line 1 column 2",
+    "not a record".parse::<AuditRecord>().err().unwrap().to_string()
+  );
+  assert_eq!(
+    "<WorkspaceError> invalid audit record: missing 'id'",
+    r#"{"inputs":{"components":[]},"outputs":{"simple":{"isNil":true}}}"#.parse::<AuditRecord>().err().unwrap().to_string()
+  );
+}
@@ -0,0 +1,144 @@
+use super::*;
+use crate::CanaryStatus;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+use std::thread;
+use std::time::Duration;
+
+const PATH: &str = "io/dmntk/rates/Fee";
+
+/// Returns input data with the amount.
+fn amount(value: Value) -> FeelContext {
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value);
+  input_data
+}
+
+/// Evaluates the fee a number of times and returns results.
+fn fees(workspaces: &Workspaces, count: usize) -> Vec<String> {
+  (0..count).map(|_| workspaces.evaluate(PATH, &amount(value_number!(200))).unwrap().to_string()).collect()
+}
+
+/// Waits until all evaluations routed to the canary are compared in background
+/// and returns the status of the first canary.
+fn compared(workspaces: &Workspaces) -> CanaryStatus {
+  for _ in 0..500 {
+    let status = workspaces.canaries().remove(0);
+    if status.compared() + status.dropped() == status.routed() {
+      return status;
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  workspaces.canaries().remove(0)
+}
+
+#[test]
+fn _0001() {
+  let workspaces = empty_workspaces();
+  let stable = workspaces.deploy(MODEL_A).unwrap();
+  let canary = workspaces.deploy_canary(&MODEL_A.replace("Amount * 0.1", "Amount * 0.2"), 100).unwrap();
+  assert_ne!(stable.id(), canary.id());
+  assert_eq!(stable.id(), canary.stable());
+  assert_eq!("https://dmntk.io/rates", canary.namespace());
+  assert_eq!(vec![PATH.to_string()], canary.invocables());
+  assert_eq!(vec!["40", "40", "40"], fees(&workspaces, 3));
+  let status = compared(&workspaces);
+  assert_eq!(3, status.evaluations());
+  assert_eq!(3, status.routed());
+  assert_eq!(3, status.compared());
+  assert_eq!(0, status.dropped());
+  assert_eq!(3, status.divergences());
+}
+
+#[test]
+fn _0002() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  workspaces.deploy_canary(&MODEL_A.replace("Amount * 0.1", "Amount * 0.2"), 25).unwrap();
+  let results = fees(&workspaces, 8);
+  assert_eq!(2, results.iter().filter(|result| *result == "40").count());
+  assert_eq!(6, results.iter().filter(|result| *result == "20").count());
+  let status = compared(&workspaces);
+  assert_eq!(25, status.percentage());
+  assert_eq!(8, status.evaluations());
+  assert_eq!(2, status.routed());
+  assert_eq!(2, status.divergences());
+}
+
+#[test]
+fn _0003() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  workspaces.deploy_canary(&MODEL_A.replace("Amount * 0.1", "0.1 * Amount"), 100).unwrap();
+  assert_eq!(vec!["20", "20"], fees(&workspaces, 2));
+  let status = compared(&workspaces);
+  assert_eq!(2, status.routed());
+  assert_eq!(2, status.compared());
+  assert_eq!(0, status.divergences());
+}
+
+#[test]
+fn _0004() {
+  let workspaces = empty_workspaces();
+  assert_eq!(
+    "<WorkspaceError> canary requires a deployed model with namespace: 'https://dmntk.io/rates'",
+    workspaces.deploy_canary(MODEL_A, 10).unwrap_err().to_string()
+  );
+  workspaces.deploy(MODEL_A).unwrap();
+  assert_eq!(
+    "<WorkspaceError> invalid canary percentage: 101, expected value from 0 to 100",
+    workspaces.deploy_canary(MODEL_A, 101).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<WorkspaceError> canary not found: 'unknown'",
+    workspaces.set_canary_percentage("unknown", 10).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<WorkspaceError> canary not found: 'unknown'",
+    workspaces.promote_canary("unknown").unwrap_err().to_string()
+  );
+  assert_eq!("<WorkspaceError> canary not found: 'unknown'", workspaces.remove_canary("unknown").unwrap_err().to_string());
+  assert!(workspaces.canaries().is_empty());
+}
+
+#[test]
+fn _0005() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  let canary = workspaces.deploy_canary(&MODEL_A.replace("Amount * 0.1", "Amount * 0.2"), 100).unwrap();
+  assert_eq!(vec!["40"], fees(&workspaces, 1));
+  let status = workspaces.set_canary_percentage(canary.id(), 0).unwrap();
+  assert_eq!(0, status.percentage());
+  assert_eq!(1, status.routed());
+  assert_eq!(vec!["20", "20"], fees(&workspaces, 2));
+  assert_eq!(3, workspaces.canaries()[0].evaluations());
+  workspaces.remove_canary(canary.id()).unwrap();
+  assert!(workspaces.canaries().is_empty());
+  assert_eq!(vec!["20"], fees(&workspaces, 1));
+}
+
+#[test]
+fn _0006() {
+  let workspaces = empty_workspaces();
+  let stable = workspaces.deploy(MODEL_A).unwrap();
+  let canary_xml = MODEL_A.replace("Amount * 0.1", "Amount * 0.2");
+  let canary = workspaces.deploy_canary(&canary_xml, 0).unwrap();
+  let report = workspaces.promote_canary(canary.id()).unwrap();
+  assert_eq!(canary.id(), report.id());
+  assert_eq!(Some(stable.id()), report.replaced());
+  assert_eq!(vec![PATH.to_string()], report.invocables());
+  assert!(workspaces.canaries().is_empty());
+  assert_eq!(canary_xml, workspaces.deployment_source(canary.id()).unwrap());
+  assert_eq!(vec!["40", "40"], fees(&workspaces, 2));
+}
+
+#[test]
+fn _0007() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  workspaces.deploy_canary(&MODEL_A.replace("Amount * 0.1", "Amount * 0.2"), 50).unwrap();
+  workspaces.deploy(&MODEL_A.replace("Amount * 0.1", "Amount * 0.3")).unwrap();
+  assert!(workspaces.canaries().is_empty());
+  assert_eq!(vec!["60", "60"], fees(&workspaces, 2));
+}
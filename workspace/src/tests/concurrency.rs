@@ -0,0 +1,204 @@
+//! # Concurrency tests
+//!
+//! Models with the same namespace are deployed, evaluated and undeployed concurrently.
+//! Versions of the model are deployed in increasing order by a single deployer, so every
+//! reader must observe non-decreasing versions (no stale or torn reads), and all workers
+//! must finish in time (no deadlocks).
+
+use super::*;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+/// Maximum time of running a single scenario.
+const SCENARIO_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Invocable path of the versioned model.
+const VERSION_PATH: &str = "io/dmntk/versions/Version";
+
+/// Returns the model with the decision returning specified version.
+fn versioned_model(version: usize) -> String {
+  format!(
+    r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/versions" name="versions" id="_versions" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <decision name="Version" id="_version">
+        <variable typeRef="number" name="Version"/>
+        <literalExpression>
+            <text>{version}</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"##
+  )
+}
+
+/// Outcome of evaluating the versioned model.
+enum Observation {
+  /// Evaluated version.
+  Version(usize),
+  /// Model was not deployed at the time of evaluation.
+  NotFound,
+}
+
+/// Evaluates the versioned model.
+fn observe(workspaces: &Workspaces) -> Result<Observation, String> {
+  match workspaces.evaluate(VERSION_PATH, &FeelContext::default()) {
+    Ok(Value::Number(version)) => version.to_string().parse().map(Observation::Version).map_err(|_| format!("invalid version: {version}")),
+    Ok(other) => Err(format!("unexpected result: {other}")),
+    Err(reason) if reason.to_string().contains("invocable not found") => Ok(Observation::NotFound),
+    Err(reason) => Err(reason.to_string()),
+  }
+}
+
+/// Scenario of concurrent deploys, evaluations and undeploys of the same namespace.
+struct StressScenario {
+  /// Number of deployed versions.
+  versions: usize,
+  /// Number of threads evaluating the model.
+  readers: usize,
+  /// Number of threads undeploying the model.
+  undeployers: usize,
+}
+
+/// Summary of running the scenario.
+#[derive(Default)]
+struct StressReport {
+  /// Number of evaluations returning a version.
+  evaluations: usize,
+  /// Number of evaluations while the model was not deployed.
+  not_found: usize,
+  /// Number of successful undeploys.
+  undeploys: usize,
+}
+
+impl StressScenario {
+  /// Runs the scenario, returns the summary or the first detected violation.
+  fn run(&self, workspaces: Workspaces) -> Result<StressReport, String> {
+    let workspaces = Arc::new(workspaces);
+    let finished = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = mpsc::channel::<Result<StressReport, String>>();
+    let mut workers = 0;
+    // deployer
+    {
+      let (workspaces, finished, sender) = (Arc::clone(&workspaces), Arc::clone(&finished), sender.clone());
+      let versions = self.versions;
+      thread::spawn(move || {
+        let result = (1..=versions)
+          .try_for_each(|version| workspaces.deploy(&versioned_model(version)).map(|_| ()).map_err(|reason| reason.to_string()))
+          .map(|_| StressReport::default());
+        finished.store(true, Ordering::SeqCst);
+        let _ = sender.send(result);
+      });
+      workers += 1;
+    }
+    // readers
+    for _ in 0..self.readers {
+      let (workspaces, finished, sender) = (Arc::clone(&workspaces), Arc::clone(&finished), sender.clone());
+      thread::spawn(move || {
+        let mut report = StressReport::default();
+        let mut last_version = 0;
+        let result = loop {
+          let done = finished.load(Ordering::SeqCst);
+          match observe(&workspaces) {
+            Ok(Observation::Version(version)) if version < last_version => break Err(format!("stale read: version {version} after {last_version}")),
+            Ok(Observation::Version(version)) => {
+              last_version = version;
+              report.evaluations += 1;
+            }
+            Ok(Observation::NotFound) => report.not_found += 1,
+            Err(reason) => break Err(reason),
+          }
+          if done {
+            break Ok(report);
+          }
+        };
+        let _ = sender.send(result);
+      });
+      workers += 1;
+    }
+    // undeployers
+    for _ in 0..self.undeployers {
+      let (workspaces, finished, sender) = (Arc::clone(&workspaces), Arc::clone(&finished), sender.clone());
+      thread::spawn(move || {
+        let mut report = StressReport::default();
+        while !finished.load(Ordering::SeqCst) {
+          let opt_id = workspaces.deployments.load().namespaces.get("https://dmntk.io/versions").cloned();
+          if let Some(id) = opt_id {
+            // the deployment may be already replaced or undeployed by other threads
+            if workspaces.undeploy(&id, false).is_ok() {
+              report.undeploys += 1;
+            }
+          }
+          thread::yield_now();
+        }
+        let _ = sender.send(Ok(report));
+      });
+      workers += 1;
+    }
+    let mut summary = StressReport::default();
+    for _ in 0..workers {
+      let report = receiver
+        .recv_timeout(SCENARIO_TIMEOUT)
+        .map_err(|_| "workers did not finish in time, deadlock suspected".to_string())??;
+      summary.evaluations += report.evaluations;
+      summary.not_found += report.not_found;
+      summary.undeploys += report.undeploys;
+    }
+    Ok(summary)
+  }
+}
+
+#[test]
+fn _0001() {
+  let scenario = StressScenario {
+    versions: 50,
+    readers: 4,
+    undeployers: 0,
+  };
+  let workspaces = empty_workspaces();
+  let report = scenario.run(workspaces).unwrap();
+  assert_eq!(0, report.undeploys);
+  assert!(report.evaluations > 0);
+}
+
+#[test]
+fn _0002() {
+  let scenario = StressScenario {
+    versions: 50,
+    readers: 4,
+    undeployers: 2,
+  };
+  let report = scenario.run(empty_workspaces()).unwrap();
+  assert!(report.evaluations + report.not_found > 0);
+}
+
+#[test]
+fn _0003() {
+  let workspaces = Arc::new(empty_workspaces());
+  workspaces.deploy(MODEL_A).unwrap();
+  let workers = (0..8)
+    .map(|index| {
+      let workspaces = Arc::clone(&workspaces);
+      thread::spawn(move || {
+        for _ in 0..20 {
+          if index % 2 == 0 {
+            workspaces.deploy(MODEL_B).unwrap();
+          } else {
+            workspaces.deploy(MODEL_A).unwrap();
+          }
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+  for worker in workers {
+    worker.join().unwrap();
+  }
+  let deployments = workspaces.deployments.load();
+  assert_eq!(2, deployments.deployments.len());
+  assert_eq!(2, deployments.namespaces.len());
+  assert_eq!(2, deployments.invocables.len());
+  assert!(deployments.invocables.values().all(|(id, _, _)| deployments.deployments.contains_key(id)));
+}
@@ -0,0 +1,169 @@
+use super::*;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+
+/// Returns input data with the amount.
+fn amount(value: Value) -> FeelContext {
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value);
+  input_data
+}
+
+#[test]
+fn _0001() {
+  let workspaces = empty_workspaces();
+  let report = workspaces.deploy(MODEL_A).unwrap();
+  assert_eq!(36, report.id().len());
+  assert_eq!("https://dmntk.io/rates", report.namespace());
+  assert!(report.diagnostics().is_empty());
+  assert_eq!(vec!["io/dmntk/rates/Fee".to_string()], report.invocables());
+  assert_eq!("20", workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap().to_string());
+}
+
+#[test]
+fn _0002() {
+  let workspaces = empty_workspaces();
+  let first = workspaces.deploy(MODEL_A).unwrap();
+  let second = workspaces.deploy(&MODEL_A.replace("Amount * 0.1", "Amount * 0.2")).unwrap();
+  assert_ne!(first.id(), second.id());
+  assert_eq!("40", workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap().to_string());
+}
+
+#[test]
+fn _0003() {
+  let workspaces = empty_workspaces();
+  let report = workspaces.deploy(&MODEL_A.replace("Amount * 0.1", "if true then Amount * 0.1 else 0")).unwrap();
+  assert_eq!(1, report.diagnostics().len());
+  assert!(report.diagnostics()[0].contains("[dead-branch]"), "{}", report.diagnostics()[0]);
+}
+
+#[test]
+fn _0004() {
+  let workspaces = empty_workspaces();
+  assert!(workspaces.deploy("<definitions").is_err());
+  assert_eq!(
+    "<WorkspaceError> invalid namespace: 'urn:dmntk:rates'",
+    workspaces.deploy(&MODEL_A.replace("https://dmntk.io/rates", "urn:dmntk:rates")).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<WorkspaceError> invocable not found: 'io/dmntk/rates/Fee'",
+    workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0005() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  let report = workspaces.deploy(MODEL_B).unwrap();
+  assert_eq!(vec!["io/dmntk/billing/Total".to_string()], report.invocables());
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("rates"), Value::Context(amount(value_number!(200))));
+  assert_eq!("21", workspaces.evaluate("io/dmntk/billing/Total", &input_data).unwrap().to_string());
+}
+
+#[test]
+fn _0006() {
+  let workspaces = empty_workspaces();
+  let report = workspaces.deploy(MODEL_A).unwrap();
+  assert_eq!(MODEL_A, workspaces.deployment_source(report.id()).unwrap());
+  assert_eq!(
+    "<WorkspaceError> deployment not found: 'unknown'",
+    workspaces.deployment_source("unknown").unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0007() {
+  let workspaces = empty_workspaces();
+  let first = workspaces.deploy(MODEL_A).unwrap();
+  let replaced = MODEL_A.replace("Amount * 0.1", "Amount * 0.2");
+  let second = workspaces.deploy(&replaced).unwrap();
+  assert!(workspaces.deployment_source(first.id()).is_err());
+  assert_eq!(replaced, workspaces.deployment_source(second.id()).unwrap());
+}
+
+#[test]
+fn _0008() {
+  let workspaces = empty_workspaces();
+  let report = workspaces.deploy(MODEL_A).unwrap();
+  assert_eq!(vec![report.id().to_string()], workspaces.undeploy(report.id(), false).unwrap());
+  assert!(workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).is_err());
+  assert!(workspaces.deployment_source(report.id()).is_err());
+  assert_eq!(
+    format!("<WorkspaceError> deployment not found: '{}'", report.id()),
+    workspaces.undeploy(report.id(), false).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0009() {
+  let workspaces = empty_workspaces();
+  let rates = workspaces.deploy(MODEL_A).unwrap();
+  let billing = workspaces.deploy(MODEL_B).unwrap();
+  assert_eq!(
+    format!("<WorkspaceError> deployment '{}' is imported by deployed models: https://dmntk.io/billing", rates.id()),
+    workspaces.undeploy(rates.id(), false).unwrap_err().to_string()
+  );
+  assert_eq!("20", workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap().to_string());
+  assert_eq!(vec![billing.id().to_string()], workspaces.undeploy(billing.id(), false).unwrap());
+  assert_eq!(vec![rates.id().to_string()], workspaces.undeploy(rates.id(), false).unwrap());
+}
+
+#[test]
+fn _0010() {
+  let workspaces = empty_workspaces();
+  let rates = workspaces.deploy(MODEL_A).unwrap();
+  let billing = workspaces.deploy(MODEL_B).unwrap();
+  assert_eq!(vec![rates.id().to_string(), billing.id().to_string()], workspaces.undeploy(rates.id(), true).unwrap());
+  assert!(workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).is_err());
+  assert!(workspaces.evaluate("io/dmntk/billing/Total", &FeelContext::default()).is_err());
+}
+
+#[test]
+fn _0011() {
+  /// Listener collecting names of evaluated decisions.
+  #[derive(Default)]
+  struct Collector(std::cell::RefCell<Vec<String>>);
+  impl dmntk_model_evaluator::EvaluationListener for Collector {
+    fn rule_fired(&self, _: &dmntk_model_evaluator::FiredRule) {}
+    fn decision_finished(&self, decision_name: &str, result: &Value) {
+      self.0.borrow_mut().push(format!("{decision_name} = {result}"));
+    }
+  }
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  let collector = std::rc::Rc::new(Collector::default());
+  let value = workspaces
+    .evaluate_with_listener("io/dmntk/rates/Fee", &amount(value_number!(200)), collector.clone())
+    .unwrap();
+  assert_eq!("20", value.to_string());
+  assert_eq!(vec!["Fee = 20".to_string()], *collector.0.borrow());
+  assert_eq!(1, workspaces.invocable_stats("io/dmntk/rates/Fee").unwrap().evaluations());
+}
+
+#[test]
+fn _0012() {
+  // the model compiled outside the lock is compiled again,
+  // when the imported model was undeployed before the compiled model is deployed
+  let workspaces = empty_workspaces();
+  let rates = workspaces.deploy(MODEL_A).unwrap();
+  let compiled = workspaces.deployments.load().compile(MODEL_B, workspaces.conformance_level, |_| false).unwrap();
+  assert!(!workspaces.deployments.load().is_stale(&compiled));
+  workspaces.undeploy(rates.id(), false).unwrap();
+  let deployments = workspaces.deployments.load();
+  assert!(deployments.is_stale(&compiled));
+  let recompiled = workspaces.recompile_if_stale(&deployments, MODEL_B, compiled).unwrap();
+  assert!(!deployments.is_stale(&recompiled));
+}
+
+#[test]
+fn _0013() {
+  // replacing the imported model also makes the compiled model stale
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  let compiled = workspaces.deployments.load().compile(MODEL_B, workspaces.conformance_level, |_| false).unwrap();
+  workspaces.deploy(&MODEL_A.replace("Amount * 0.1", "Amount * 0.2")).unwrap();
+  assert!(workspaces.deployments.load().is_stale(&compiled));
+}
@@ -0,0 +1,144 @@
+use super::*;
+
+const MODEL_LOAN: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/loan" name="loan" id="_loan" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <itemDefinition name="tApplicant">
+        <itemComponent name="Monthly Income">
+            <typeRef>number</typeRef>
+        </itemComponent>
+        <itemComponent name="Birth Date">
+            <typeRef>date</typeRef>
+        </itemComponent>
+    </itemDefinition>
+    <itemDefinition name="tOffer">
+        <itemComponent name="Limit">
+            <typeRef>number</typeRef>
+        </itemComponent>
+        <itemComponent name="Approved">
+            <typeRef>boolean</typeRef>
+        </itemComponent>
+    </itemDefinition>
+    <inputData name="Applicant" id="_applicant">
+        <variable typeRef="tApplicant" name="Applicant"/>
+    </inputData>
+    <decision name="Offer" id="_offer">
+        <variable typeRef="tOffer" name="Offer"/>
+        <informationRequirement>
+            <requiredInput href="#_applicant"/>
+        </informationRequirement>
+        <literalExpression>
+            <text>{Limit: Applicant.Monthly Income * 10, Approved: Applicant.Birth Date &lt; date("2000-01-01")}</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"##;
+
+/// Returns workspaces with deployed models.
+fn workspaces() -> Workspaces {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  workspaces.deploy(MODEL_LOAN).unwrap();
+  workspaces
+}
+
+/// Executes the GraphQL query.
+fn query(workspaces: &Workspaces, query: &str) -> String {
+  workspaces.evaluate_graphql(&serde_json::json!({ "query": query }).to_string())
+}
+
+#[test]
+fn _0001() {
+  assert_eq!(
+    r#"type Query {
+  Fee(Amount: Float): Float
+  Offer(Applicant: Offer_ApplicantInput): OfferResult
+}
+
+input Offer_ApplicantInput {
+  Birth_Date: String
+  Monthly_Income: Float
+}
+
+type OfferResult {
+  Approved: Boolean
+  Limit: Float
+}
+"#,
+    workspaces().graphql_schema()
+  );
+}
+
+#[test]
+fn _0002() {
+  // multiple invocables are evaluated in one query, only selected entries are returned
+  let workspaces = workspaces();
+  assert_eq!(
+    r#"{"data":{"Fee":20,"offer":{"limit":30000}}}"#,
+    query(
+      &workspaces,
+      r#"{ Fee(Amount: 200) offer: Offer(Applicant: {Monthly_Income: 3000, Birth_Date: "1990-05-12"}) { limit: Limit } }"#
+    )
+  );
+}
+
+#[test]
+fn _0003() {
+  // values of arguments are taken from variables
+  let workspaces = workspaces();
+  let request = r#"{
+    "query": "query Loan($income: Float, $birth: String = \"2005-01-01\") { Offer(Applicant: {Monthly_Income: $income, Birth_Date: $birth}) { Approved Limit __typename } }",
+    "variables": { "income": 1000 }
+  }"#;
+  assert_eq!(
+    r#"{"data":{"Offer":{"Approved":false,"Limit":10000,"__typename":"OfferResult"}}}"#,
+    workspaces.evaluate_graphql(request)
+  );
+}
+
+#[test]
+fn _0004() {
+  // errors are reported for fields, other fields are evaluated
+  let workspaces = workspaces();
+  assert_eq!(
+    r#"{"data":{"Fee":20,"Unknown":null},"errors":[{"message":"<WorkspaceError> field 'Unknown' is not defined in type 'Query'","path":["Unknown"]}]}"#,
+    query(&workspaces, "{ Fee(Amount: 200) Unknown }")
+  );
+  assert_eq!(
+    r#"{"data":{"Fee":null},"errors":[{"message":"<WorkspaceError> argument 'Amont' is not defined in field 'Fee'","path":["Fee"]}]}"#,
+    query(&workspaces, "{ Fee(Amont: 200) }")
+  );
+  assert_eq!(
+    r#"{"data":{"Offer":null},"errors":[{"message":"<WorkspaceError> field 'Rate' is not defined in type 'OfferResult'","path":["Offer"]}]}"#,
+    query(&workspaces, "{ Offer(Applicant: {Monthly_Income: 1}) { Rate } }")
+  );
+}
+
+#[test]
+fn _0005() {
+  let workspaces = workspaces();
+  assert_eq!(
+    r#"{"errors":[{"message":"<WorkspaceError> invalid GraphQL query: expected `:`, found `200` at line 1, column 14"}]}"#,
+    query(&workspaces, "{ Fee(Amount 200) }")
+  );
+  assert_eq!(
+    r#"{"errors":[{"message":"<WorkspaceError> invalid GraphQL query: mutation operations are not supported at line 1, column 1"}]}"#,
+    query(&workspaces, "mutation { Fee }")
+  );
+  assert_eq!(
+    r#"{"errors":[{"message":"<WorkspaceError> invalid GraphQL request: missing query"}]}"#,
+    workspaces.evaluate_graphql("{}")
+  );
+}
+
+#[test]
+fn _0006() {
+  // operation is selected by name
+  let workspaces = workspaces();
+  let request = r#"{"query": "query A { Fee(Amount: 10) } query B { Fee(Amount: 20) }", "operationName": "B"}"#;
+  assert_eq!(r#"{"data":{"Fee":2}}"#, workspaces.evaluate_graphql(request));
+  let request = r#"{"query": "query A { Fee(Amount: 10) } query B { Fee(Amount: 20) }"}"#;
+  assert_eq!(
+    r#"{"errors":[{"message":"<WorkspaceError> GraphQL query contains multiple operations, operation name is required"}]}"#,
+    workspaces.evaluate_graphql(request)
+  );
+}
@@ -0,0 +1,226 @@
+//! # Tests for translated error messages
+//!
+//! Every message of this crate in the message catalog is created by its error constructor and translated.
+
+use crate::errors::*;
+use dmntk_common::{message, DmntkError, Language};
+
+/// Asserts the error message translated into Polish and German.
+fn assert_translated(error: DmntkError, polish: &str, german: &str) {
+  assert_eq!(polish, error.translated(Language::Polish));
+  assert_eq!(german, error.translated(Language::German));
+}
+
+#[test]
+fn _0001() {
+  assert_translated(
+    err_invocable_not_found("io/dmntk/Fee"),
+    "<WorkspaceError> nie znaleziono elementu wywoływalnego: 'io/dmntk/Fee'",
+    "<WorkspaceError> aufrufbares Element nicht gefunden: 'io/dmntk/Fee'",
+  );
+}
+
+#[test]
+fn _0002() {
+  assert_translated(
+    err_tenant_not_found("alpha"),
+    "<WorkspaceError> nie znaleziono dzierżawcy: 'alpha'",
+    "<WorkspaceError> Mandant nicht gefunden: 'alpha'",
+  );
+}
+
+#[test]
+fn _0003() {
+  assert_translated(
+    err_invalid_tenant_configuration("alpha", message!("expected JSON object")),
+    "<WorkspaceError> niepoprawna konfiguracja dzierżawcy 'alpha': oczekiwano obiektu JSON",
+    "<WorkspaceError> ungültige Konfiguration des Mandanten 'alpha': JSON-Objekt erwartet",
+  );
+}
+
+#[test]
+fn _0004() {
+  assert_translated(
+    err_invalid_namespace("dmntk"),
+    "<WorkspaceError> niepoprawna przestrzeń nazw: 'dmntk'",
+    "<WorkspaceError> ungültiger Namensraum: 'dmntk'",
+  );
+}
+
+#[test]
+fn _0005() {
+  assert_translated(
+    err_namespace_reserved("https://dmntk.io"),
+    "<WorkspaceError> przestrzeń nazw jest już obsługiwana przez modele wczytane z katalogu przestrzeni roboczej: 'https://dmntk.io'",
+    "<WorkspaceError> Namensraum wird bereits von Modellen aus dem Arbeitsbereichsverzeichnis bedient: 'https://dmntk.io'",
+  );
+}
+
+#[test]
+fn _0006() {
+  assert_translated(
+    err_deployment_not_found("_1"),
+    "<WorkspaceError> nie znaleziono wdrożenia: '_1'",
+    "<WorkspaceError> Bereitstellung nicht gefunden: '_1'",
+  );
+}
+
+#[test]
+fn _0007() {
+  assert_translated(
+    err_deployment_imported("_1", "_2, _3"),
+    "<WorkspaceError> wdrożenie '_1' jest importowane przez wdrożone modele: _2, _3",
+    "<WorkspaceError> Bereitstellung '_1' wird von bereitgestellten Modellen importiert: _2, _3",
+  );
+}
+
+#[test]
+fn _0008() {
+  assert_translated(
+    err_opening_audit_file("audit.jsonl", "permission denied"),
+    "<WorkspaceError> otwarcie pliku audytu 'audit.jsonl' nie powiodło się, przyczyna: permission denied",
+    "<WorkspaceError> Öffnen der Audit-Datei 'audit.jsonl' fehlgeschlagen, Ursache: permission denied",
+  );
+}
+
+#[test]
+fn _0009() {
+  assert_translated(
+    err_storing_audit_record("disk full"),
+    "<WorkspaceError> zapisanie rekordu audytu nie powiodło się, przyczyna: disk full",
+    "<WorkspaceError> Speichern des Audit-Datensatzes fehlgeschlagen, Ursache: disk full",
+  );
+}
+
+#[test]
+fn _0010() {
+  assert_translated(
+    err_invalid_audit_record("missing timestamp"),
+    "<WorkspaceError> niepoprawny rekord audytu: missing timestamp",
+    "<WorkspaceError> ungültiger Audit-Datensatz: missing timestamp",
+  );
+}
+
+#[test]
+fn _0011() {
+  assert_translated(
+    err_canary_not_found("_1"),
+    "<WorkspaceError> nie znaleziono wdrożenia kanarkowego: '_1'",
+    "<WorkspaceError> Canary-Bereitstellung nicht gefunden: '_1'",
+  );
+}
+
+#[test]
+fn _0012() {
+  assert_translated(
+    err_canary_without_stable("https://dmntk.io"),
+    "<WorkspaceError> wdrożenie kanarkowe wymaga wdrożonego modelu z przestrzenią nazw: 'https://dmntk.io'",
+    "<WorkspaceError> Canary-Bereitstellung erfordert ein bereitgestelltes Modell mit Namensraum: 'https://dmntk.io'",
+  );
+}
+
+#[test]
+fn _0013() {
+  assert_translated(
+    err_invalid_canary_percentage(101),
+    "<WorkspaceError> niepoprawny procent wdrożenia kanarkowego: 101, oczekiwano wartości od 0 do 100",
+    "<WorkspaceError> ungültiger Canary-Prozentsatz: 101, erwartet wird ein Wert von 0 bis 100",
+  );
+}
+
+#[test]
+fn _0014() {
+  assert_translated(
+    err_unknown_service_parameters("io/dmntk/Service", "A, B"),
+    "<WorkspaceError> usługa decyzyjna 'io/dmntk/Service' nie ma parametrów wejściowych o nazwach: A, B",
+    "<WorkspaceError> Entscheidungsdienst 'io/dmntk/Service' hat keine Eingabeparameter mit den Namen: A, B",
+  );
+}
+
+#[test]
+#[cfg(feature = "graphql")]
+fn _0015() {
+  assert_translated(
+    err_graphql_syntax("unexpected `}`", 1, 7),
+    "<WorkspaceError> niepoprawne zapytanie GraphQL: unexpected `}` w wierszu 1, kolumnie 7",
+    "<WorkspaceError> ungültige GraphQL-Abfrage: unexpected `}` in Zeile 1, Spalte 7",
+  );
+}
+
+#[test]
+#[cfg(feature = "graphql")]
+fn _0016() {
+  assert_translated(
+    err_graphql_operation_not_found("Rates"),
+    "<WorkspaceError> nie znaleziono operacji GraphQL: 'Rates'",
+    "<WorkspaceError> GraphQL-Operation nicht gefunden: 'Rates'",
+  );
+}
+
+#[test]
+#[cfg(feature = "graphql")]
+fn _0017() {
+  assert_translated(
+    err_graphql_operation_name_required(),
+    "<WorkspaceError> zapytanie GraphQL zawiera wiele operacji, wymagana jest nazwa operacji",
+    "<WorkspaceError> GraphQL-Abfrage enthält mehrere Operationen, Operationsname ist erforderlich",
+  );
+}
+
+#[test]
+#[cfg(feature = "graphql")]
+fn _0018() {
+  assert_translated(
+    err_graphql_invalid_request("missing query"),
+    "<WorkspaceError> niepoprawne żądanie GraphQL: missing query",
+    "<WorkspaceError> ungültige GraphQL-Anfrage: missing query",
+  );
+}
+
+#[test]
+#[cfg(feature = "graphql")]
+fn _0019() {
+  assert_translated(
+    err_graphql_unknown_field("fee", "Query"),
+    "<WorkspaceError> pole 'fee' nie jest zdefiniowane w typie 'Query'",
+    "<WorkspaceError> Feld 'fee' ist im Typ 'Query' nicht definiert",
+  );
+}
+
+#[test]
+#[cfg(feature = "graphql")]
+fn _0020() {
+  assert_translated(
+    err_graphql_unknown_argument("amount", "fee"),
+    "<WorkspaceError> argument 'amount' nie jest zdefiniowany w polu 'fee'",
+    "<WorkspaceError> Argument 'amount' ist im Feld 'fee' nicht definiert",
+  );
+}
+
+#[test]
+#[cfg(feature = "graphql")]
+fn _0021() {
+  assert_translated(
+    err_graphql_selection_on_scalar("fee"),
+    "<WorkspaceError> nie można wybierać pól z wartości skalarnej 'fee'",
+    "<WorkspaceError> Felder können nicht aus dem Skalarwert von 'fee' ausgewählt werden",
+  );
+}
+
+#[test]
+fn _0022() {
+  assert_translated(
+    err_invalid_selected_path("a..b"),
+    "<WorkspaceError> niepoprawna wybrana ścieżka: 'a..b'",
+    "<WorkspaceError> ungültiger ausgewählter Pfad: 'a..b'",
+  );
+}
+
+#[test]
+fn _0023() {
+  assert_translated(
+    err_selected_path_not_found("a.b"),
+    "<WorkspaceError> nie znaleziono wybranej ścieżki w wyniku: 'a.b'",
+    "<WorkspaceError> ausgewählter Pfad im Ergebnis nicht gefunden: 'a.b'",
+  );
+}
@@ -0,0 +1,60 @@
+//! # Tests for workspaces
+
+use crate::Workspaces;
+use dmntk_common::{gen_id, ColorMode};
+use std::fs;
+
+mod audit;
+mod canary;
+mod concurrency;
+mod deployments;
+#[cfg(feature = "graphql")]
+mod graphql;
+mod messages;
+mod projection;
+mod services;
+mod shadow;
+mod stats;
+mod tenants;
+mod webhooks;
+
+const MODEL_A: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/rates" name="rates" id="_rates" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <inputData name="Amount" id="_amount">
+        <variable typeRef="number" name="Amount"/>
+    </inputData>
+    <decision name="Fee" id="_fee">
+        <variable typeRef="number" name="Fee"/>
+        <informationRequirement>
+            <requiredInput href="#_amount"/>
+        </informationRequirement>
+        <literalExpression>
+            <text>Amount * 0.1</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"##;
+
+const MODEL_B: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/billing" name="billing" id="_billing" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/" xmlns:rates="https://dmntk.io/rates">
+    <import namespace="https://dmntk.io/rates" name="rates" importType="https://www.omg.org/spec/DMN/20191111/MODEL/"/>
+    <decision name="Total" id="_total">
+        <variable typeRef="number" name="Total"/>
+        <informationRequirement>
+            <requiredDecision href="https://dmntk.io/rates#_fee"/>
+        </informationRequirement>
+        <literalExpression>
+            <text>rates.Fee + 1</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"##;
+
+/// Creates workspaces loaded from an empty directory.
+fn empty_workspaces() -> Workspaces {
+  let dir = std::env::temp_dir().join(gen_id());
+  fs::create_dir_all(&dir).unwrap();
+  let workspaces = Workspaces::new(&dir, ColorMode::Off.into(), false);
+  fs::remove_dir_all(&dir).unwrap();
+  workspaces
+}
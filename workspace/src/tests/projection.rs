@@ -0,0 +1,54 @@
+use crate::Projection;
+use dmntk_common::Jsonify;
+use dmntk_feel::json::value_from_json;
+
+const RESULT: &str = r#"{"Offer":{"Limit":5000,"Approved":true,"Reason":"score"},"Score":720,"Items":[{"Code":"A","Price":10},{"Code":"B","Price":20},{"Code":"C","Price":30}]}"#;
+
+/// Returns the JSON form of the result projected to selected paths.
+fn project(paths: &str) -> String {
+  let value = value_from_json(RESULT).unwrap();
+  Projection::parse(paths).unwrap().apply(&value).unwrap().jsonify()
+}
+
+/// Returns the error message reported when projecting the result to selected paths.
+fn project_err(paths: &str) -> String {
+  let value = value_from_json(RESULT).unwrap();
+  match Projection::parse(paths) {
+    Ok(projection) => projection.apply(&value).unwrap_err().to_string(),
+    Err(reason) => reason.to_string(),
+  }
+}
+
+#[test]
+fn _0001() {
+  assert_eq!(r#"{"Offer": {"Limit": 5000}, "Score": 720}"#, project("Offer.Limit,Score"));
+}
+
+#[test]
+fn _0002() {
+  assert_eq!(r#"{"Offer": {"Approved": true, "Limit": 5000}}"#, project("/Offer/Limit, /Offer/Approved"));
+}
+
+#[test]
+fn _0003() {
+  assert_eq!(r#"{"Items": [{"Price": 10}, {"Price": 20}, {"Price": 30}]}"#, project("Items.Price"));
+}
+
+#[test]
+fn _0004() {
+  assert_eq!(r#"{"Items": [{"Code": "A", "Price": 10}, {"Code": "C"}]}"#, project("/Items/2/Code,/Items/0"));
+}
+
+#[test]
+fn _0005() {
+  assert_eq!(r#"{"Offer": {"Approved": true, "Limit": 5000, "Reason": "score"}}"#, project("Offer,Offer.Limit"));
+}
+
+#[test]
+fn _0006() {
+  assert_eq!("<WorkspaceError> selected path not found in result: 'Offer.Rate'", project_err("Offer.Rate"));
+  assert_eq!("<WorkspaceError> selected path not found in result: 'Score.Value'", project_err("Score.Value"));
+  assert_eq!("<WorkspaceError> selected path not found in result: 'Items.3'", project_err("/Items/3"));
+  assert_eq!("<WorkspaceError> invalid selected path: 'Offer..Limit'", project_err("Offer..Limit"));
+  assert_eq!("<WorkspaceError> invalid selected path: ''", project_err("Score,"));
+}
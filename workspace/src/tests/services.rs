@@ -0,0 +1,104 @@
+use super::*;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, value_string, Name};
+
+const MODEL_SERVICE: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<definitions namespace="https://dmntk.io/pricing" name="pricing" id="_pricing" xmlns="https://www.omg.org/spec/DMN/20191111/MODEL/">
+    <itemDefinition name="tItems" isCollection="true">
+        <typeRef>number</typeRef>
+    </itemDefinition>
+    <decisionService name="Pricing" id="_pricing_service">
+        <variable typeRef="number" name="Pricing"/>
+        <outputDecision href="#_price"/>
+        <inputData href="#_items"/>
+        <inputData href="#_customer"/>
+    </decisionService>
+    <inputData name="Items" id="_items">
+        <variable typeRef="tItems" name="Items"/>
+    </inputData>
+    <inputData name="Customer" id="_customer">
+        <variable typeRef="string" name="Customer"/>
+    </inputData>
+    <decision name="Price" id="_price">
+        <variable typeRef="number" name="Price"/>
+        <informationRequirement>
+            <requiredInput href="#_items"/>
+        </informationRequirement>
+        <informationRequirement>
+            <requiredInput href="#_customer"/>
+        </informationRequirement>
+        <literalExpression>
+            <text>if Customer = "VIP" then sum(Items) * 0.9 else sum(Items)</text>
+        </literalExpression>
+    </decision>
+</definitions>
+"##;
+
+/// Returns input data of the pricing service.
+fn pricing_input(customer: &str) -> FeelContext {
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Items"), Value::List(vec![value_number!(100), value_number!(200)]));
+  input_data.set_entry(&Name::from("Customer"), value_string!(customer));
+  input_data
+}
+
+#[test]
+fn _0001() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_SERVICE).unwrap();
+  let service = workspaces.service("io/dmntk/pricing/Pricing").unwrap();
+  assert_eq!("io/dmntk/pricing/Pricing", service.path());
+  assert_eq!("Pricing", service.name());
+  assert_eq!(
+    vec!["Items", "Customer"],
+    service.parameters().iter().map(|(name, _)| name.to_string()).collect::<Vec<String>>()
+  );
+  assert_eq!(
+    r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","additionalProperties":false,"properties":{"Customer":{"type":"string"},"Items":{"items":{"type":"number"},"type":"array"}},"title":"Pricing","type":"object"}"#,
+    service.request_schema()
+  );
+  assert!(service.response_schema().contains(r#""data":{"type":"number"}"#));
+}
+
+#[test]
+fn _0002() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_SERVICE).unwrap();
+  assert_eq!("270", workspaces.evaluate_service("io/dmntk/pricing/Pricing", &pricing_input("VIP")).unwrap().to_string());
+  assert_eq!(
+    "300",
+    workspaces.evaluate_service("io/dmntk/pricing/Pricing", &pricing_input("Regular")).unwrap().to_string()
+  );
+}
+
+#[test]
+fn _0003() {
+  // entries not being input parameters of the decision service are rejected
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_SERVICE).unwrap();
+  let mut input_data = pricing_input("VIP");
+  input_data.set_entry(&Name::from("Discount"), value_number!(10));
+  assert_eq!(
+    "<WorkspaceError> decision service 'io/dmntk/pricing/Pricing' has no input parameters named: Discount",
+    workspaces.evaluate_service("io/dmntk/pricing/Pricing", &input_data).unwrap_err().to_string()
+  );
+}
+
+#[test]
+fn _0004() {
+  // decisions are not exposed as decision services
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_SERVICE).unwrap();
+  workspaces.deploy(MODEL_A).unwrap();
+  let paths = workspaces.services().iter().map(|service| service.path().to_string()).collect::<Vec<String>>();
+  assert_eq!(vec!["io/dmntk/pricing/Pricing"], paths);
+  assert_eq!(
+    "<ModelEvaluatorError> invocable 'Fee' in namespace 'https://dmntk.io/rates' is not a decision service",
+    workspaces.service("io/dmntk/rates/Fee").unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<WorkspaceError> invocable not found: 'io/dmntk/pricing/Unknown'",
+    workspaces.service("io/dmntk/pricing/Unknown").unwrap_err().to_string()
+  );
+}
@@ -0,0 +1,159 @@
+use super::*;
+use crate::shadow::same_results;
+use crate::{ExternalShadow, ShadowSummary};
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+use serde_json::json;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+/// Returns input data with the amount.
+fn amount(value: Value) -> FeelContext {
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value);
+  input_data
+}
+
+/// Accepts a single forwarded evaluation and responds with specified status and body,
+/// returns the request line and the body of the forwarded request.
+fn respond(listener: &TcpListener, status: &str, body: &str) -> (String, String) {
+  let (stream, _) = listener.accept().unwrap();
+  let mut reader = BufReader::new(stream);
+  let mut request_line = String::new();
+  reader.read_line(&mut request_line).unwrap();
+  let mut content_length = 0;
+  loop {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    if line.trim().is_empty() {
+      break;
+    }
+    if let Some((name, value)) = line.split_once(':') {
+      if name.eq_ignore_ascii_case("content-length") {
+        content_length = value.trim().parse().unwrap();
+      }
+    }
+  }
+  let mut request_body = vec![0; content_length];
+  reader.read_exact(&mut request_body).unwrap();
+  let response = format!(
+    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+    body.len()
+  );
+  reader.get_mut().write_all(response.as_bytes()).unwrap();
+  (request_line.trim().to_string(), String::from_utf8(request_body).unwrap())
+}
+
+/// Waits until the summary of shadow evaluations satisfies the condition.
+fn wait_for(shadow: &ExternalShadow, condition: impl Fn(&ShadowSummary) -> bool) -> ShadowSummary {
+  for _ in 0..500 {
+    let summary = shadow.summary();
+    if condition(&summary) {
+      return summary;
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  shadow.summary()
+}
+
+/// Returns the shadow forwarding evaluations to the listener.
+fn shadow(listener: &TcpListener) -> ExternalShadow {
+  ExternalShadow::new(&format!("http://{}/engine/", listener.local_addr().unwrap()))
+}
+
+#[test]
+fn _0001() {
+  assert!(same_results(&json!(20), &json!(20.0)));
+  assert!(same_results(&json!({"Fee": 20, "Rate": [0.1, "low"]}), &json!({"Rate": [0.10, "low"], "Fee": 20.0})));
+  assert!(same_results(&json!(null), &json!(null)));
+  assert!(!same_results(&json!(20), &json!("20")));
+  assert!(!same_results(&json!({"Fee": 20}), &json!({"Fee": 20, "Rate": 0.1})));
+  assert!(!same_results(&json!([1, 2]), &json!([2, 1])));
+}
+
+#[test]
+fn _0002() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let shadow = shadow(&listener);
+  assert!(shadow.forward("io/dmntk/rates/Fee", &amount(value_number!(200)), &value_number!(20)));
+  let (request_line, request_body) = respond(&listener, "200 OK", r#"{"data":20.0}"#);
+  assert_eq!("POST /engine/io/dmntk/rates/Fee HTTP/1.1", request_line);
+  assert_eq!(r#"{"Amount": 200}"#, request_body);
+  let summary = wait_for(&shadow, |summary| summary.matched() > 0);
+  assert_eq!(1, summary.matched());
+  assert_eq!(0, summary.mismatched());
+  assert_eq!(0, summary.failed());
+  assert!(summary.mismatches().is_empty());
+}
+
+#[test]
+fn _0003() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let shadow = shadow(&listener);
+  assert!(shadow.forward("io/dmntk/rates/Fee", &amount(value_number!(200)), &value_number!(20)));
+  respond(&listener, "200 OK", "21");
+  let summary = wait_for(&shadow, |summary| summary.mismatched() > 0);
+  assert_eq!(0, summary.matched());
+  assert_eq!(1, summary.mismatched());
+  let mismatch = &summary.mismatches()[0];
+  assert_eq!("io/dmntk/rates/Fee", mismatch.invocable_path());
+  assert_eq!(r#"{"Amount": 200}"#, mismatch.input_data());
+  assert_eq!("20", mismatch.result());
+  assert_eq!("21", mismatch.external());
+}
+
+#[test]
+fn _0004() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let shadow = shadow(&listener);
+  assert!(shadow.forward("io/dmntk/rates/Fee", &amount(value_number!(200)), &value_number!(20)));
+  respond(&listener, "500 Internal Server Error", "");
+  let summary = wait_for(&shadow, |summary| summary.failed() > 0);
+  assert_eq!(0, summary.matched());
+  assert_eq!(0, summary.mismatched());
+  assert_eq!(1, summary.failed());
+}
+
+#[test]
+fn _0005() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let workspaces = empty_workspaces().with_external_shadow(shadow(&listener));
+  assert_eq!(0, workspaces.shadow_summary().unwrap().mismatched());
+  workspaces.deploy(MODEL_A).unwrap();
+  assert_eq!("20", workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap().to_string());
+  let (request_line, request_body) = respond(&listener, "200 OK", r#"{"data":25}"#);
+  assert_eq!("POST /engine/io/dmntk/rates/Fee HTTP/1.1", request_line);
+  assert_eq!(r#"{"Amount": 200}"#, request_body);
+  for _ in 0..100 {
+    if workspaces.shadow_summary().unwrap().mismatched() > 0 {
+      break;
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  let summary = workspaces.shadow_summary().unwrap();
+  assert_eq!(1, summary.mismatched());
+  assert_eq!("25", summary.mismatches()[0].external());
+  assert!(empty_workspaces().shadow_summary().is_none());
+}
+
+#[test]
+fn _0006() {
+  // a single thread blocked by the first forwarding and a single queued forwarding,
+  // so at least one of three forwardings is dropped
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let shadow = ExternalShadow::with_limits(&format!("http://{}/engine", listener.local_addr().unwrap()), 1, 1);
+  let forwarded = (0..3)
+    .filter(|_| shadow.forward("io/dmntk/rates/Fee", &amount(value_number!(200)), &value_number!(20)))
+    .count() as u64;
+  assert!(forwarded >= 1);
+  assert_eq!(3 - forwarded, shadow.summary().dropped());
+  for _ in 0..forwarded {
+    respond(&listener, "200 OK", "20");
+  }
+  let summary = wait_for(&shadow, |summary| summary.matched() == forwarded);
+  assert_eq!(forwarded, summary.matched());
+  assert_eq!(0, summary.failed());
+}
@@ -0,0 +1,80 @@
+use super::*;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+
+/// Returns input data with the amount.
+fn amount(value: Value) -> FeelContext {
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value);
+  input_data
+}
+
+#[test]
+fn _0001() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  assert!(workspaces.stats().is_empty());
+  assert!(workspaces.invocable_stats("io/dmntk/rates/Fee").is_none());
+  for _ in 0..3 {
+    workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap();
+  }
+  let stats = workspaces.invocable_stats("io/dmntk/rates/Fee").unwrap();
+  assert_eq!("io/dmntk/rates/Fee", stats.invocable_path());
+  assert_eq!(3, stats.evaluations());
+  assert_eq!(0, stats.failures());
+  assert!(stats.p50() <= stats.p95());
+  assert!(stats.p95() <= stats.p99());
+  assert!(stats.p99() > std::time::Duration::ZERO);
+}
+
+#[test]
+fn _0002() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap();
+  workspaces.evaluate("io/dmntk/rates/Fee", &amount(Value::String("200".into()))).unwrap();
+  assert!(workspaces.evaluate("io/dmntk/rates/Unknown", &amount(value_number!(200))).is_err());
+  let stats = workspaces.stats();
+  assert_eq!(1, stats.len());
+  assert_eq!(2, stats[0].evaluations());
+  assert_eq!(1, stats[0].failures());
+}
+
+#[test]
+fn _0003() {
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  workspaces.deploy(MODEL_B).unwrap();
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("rates"), Value::Context(amount(value_number!(200))));
+  workspaces.evaluate("io/dmntk/billing/Total", &input_data).unwrap();
+  workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap();
+  let paths = workspaces.stats().iter().map(|stats| stats.invocable_path().to_string()).collect::<Vec<String>>();
+  assert_eq!(vec!["io/dmntk/billing/Total", "io/dmntk/rates/Fee"], paths);
+}
+
+#[test]
+fn _0004() {
+  let workspaces = empty_workspaces();
+  let id = workspaces.deploy(MODEL_A).unwrap().id().to_string();
+  workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap();
+  assert!(workspaces.invocable_stats("io/dmntk/rates/Fee").is_some());
+  workspaces.undeploy(&id, false).unwrap();
+  assert!(workspaces.invocable_stats("io/dmntk/rates/Fee").is_none());
+  assert!(workspaces.stats().is_empty());
+}
+
+#[test]
+fn _0005() {
+  // latencies of the most recent evaluations are kept, when there are more evaluations than the window size
+  let workspaces = empty_workspaces();
+  workspaces.deploy(MODEL_A).unwrap();
+  for _ in 0..1100 {
+    workspaces.evaluate("io/dmntk/rates/Fee", &amount(value_number!(200))).unwrap();
+  }
+  let stats = workspaces.invocable_stats("io/dmntk/rates/Fee").unwrap();
+  assert_eq!(1100, stats.evaluations());
+  assert!(stats.p50() > std::time::Duration::ZERO);
+  assert!(stats.p50() <= stats.p99());
+}
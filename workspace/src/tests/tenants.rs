@@ -0,0 +1,115 @@
+use super::*;
+use crate::Tenants;
+use dmntk_feel::context::FeelContext;
+use dmntk_feel::values::Value;
+use dmntk_feel::{value_number, Name};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+
+const PATH: &str = "io/dmntk/rates/Fee";
+
+/// Returns input data with the amount.
+fn amount(value: Value) -> FeelContext {
+  let mut input_data = FeelContext::default();
+  input_data.set_entry(&Name::from("Amount"), value);
+  input_data
+}
+
+/// Creates the directory of tenants, each tenant with the rates model and optional configuration.
+fn tenants_dir(tenants: &[(&str, Option<&str>)]) -> PathBuf {
+  let root_dir = std::env::temp_dir().join(gen_id());
+  for (tenant_name, opt_configuration) in tenants {
+    let dir = root_dir.join(tenant_name);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("rates.dmn"), MODEL_A).unwrap();
+    if let Some(configuration) = opt_configuration {
+      fs::write(dir.join("tenant.json"), configuration).unwrap();
+    }
+  }
+  root_dir
+}
+
+#[test]
+fn _0001() {
+  // workspaces of tenants are loaded on first use and evaluated in isolation
+  let root_dir = tenants_dir(&[("alpha", None), ("beta", None)]);
+  let tenants = Tenants::new(root_dir.clone(), ColorMode::Off.into(), false);
+  assert_eq!("20", tenants.evaluate("alpha", PATH, &amount(value_number!(200))).unwrap().to_string());
+  assert_eq!("30", tenants.evaluate("alpha", PATH, &amount(value_number!(300))).unwrap().to_string());
+  assert!(tenants.evaluate("alpha", "io/dmntk/rates/Unknown", &amount(value_number!(300))).is_err());
+  let metrics = tenants.metrics("alpha").unwrap();
+  assert_eq!(1, metrics.invocables());
+  assert_eq!(3, metrics.evaluations());
+  assert_eq!(1, metrics.failures());
+  assert_eq!(0, tenants.metrics("beta").unwrap().evaluations());
+  fs::remove_dir_all(&root_dir).unwrap();
+}
+
+#[test]
+fn _0002() {
+  // tenants are identified by plain directory names
+  let root_dir = tenants_dir(&[("alpha", None)]);
+  let tenants = Tenants::new(root_dir.join("alpha"), ColorMode::Off.into(), false);
+  for tenant_name in ["", "..", "gamma", "alpha/.."] {
+    assert_eq!(
+      format!("<WorkspaceError> tenant not found: '{tenant_name}'"),
+      tenants.evaluate(tenant_name, PATH, &amount(value_number!(200))).unwrap_err().to_string()
+    );
+  }
+  fs::remove_dir_all(&root_dir).unwrap();
+}
+
+#[test]
+fn _0003() {
+  // tenants are configured in the configuration file in the directory of the tenant
+  let root_dir = tenants_dir(&[("alpha", Some(r#"{"conformanceLevel": 2, "audit": "audit.jsonl", "webhooks": []}"#)), ("beta", None)]);
+  let tenants = Tenants::new(root_dir.clone(), ColorMode::Off.into(), false);
+  tenants.evaluate("alpha", PATH, &amount(value_number!(200))).unwrap();
+  tenants.evaluate("beta", PATH, &amount(value_number!(200))).unwrap();
+  assert_eq!(1, fs::read_to_string(root_dir.join("alpha").join("audit.jsonl")).unwrap().lines().count());
+  assert!(!root_dir.join("beta").join("audit.jsonl").exists());
+  fs::remove_dir_all(&root_dir).unwrap();
+}
+
+#[test]
+fn _0004() {
+  // tenants with invalid configuration are not loaded
+  let root_dir = tenants_dir(&[
+    ("alpha", Some(r#"{"conformanceLevel": "4"}"#)),
+    ("beta", Some(r#"{"color": "red"}"#)),
+    ("gamma", Some("[]")),
+  ]);
+  let tenants = Tenants::new(root_dir.clone(), ColorMode::Off.into(), false);
+  assert_eq!(
+    "<WorkspaceError> invalid configuration of tenant 'alpha': <ConformanceLevelError> '4' is not a valid conformance level, accepted values are: '1', '2', '3', 'CL1', 'CL2', 'CL3'",
+    tenants.evaluate("alpha", PATH, &amount(value_number!(200))).unwrap_err().to_string()
+  );
+  assert_eq!(
+    r#"<WorkspaceError> invalid configuration of tenant 'beta': invalid property 'color' with value: "red""#,
+    tenants.evaluate("beta", PATH, &amount(value_number!(200))).unwrap_err().to_string()
+  );
+  assert_eq!(
+    "<WorkspaceError> invalid configuration of tenant 'gamma': expected JSON object",
+    tenants.metrics("gamma").unwrap_err().to_string()
+  );
+  fs::remove_dir_all(&root_dir).unwrap();
+}
+
+#[test]
+fn _0005() {
+  // concurrent first requests to the same tenant load its workspaces once
+  let root_dir = tenants_dir(&[("alpha", None)]);
+  let tenants = Arc::new(Tenants::new(root_dir.clone(), ColorMode::Off.into(), false));
+  let handles = (0..8)
+    .map(|_| {
+      let tenants = Arc::clone(&tenants);
+      thread::spawn(move || tenants.evaluate("alpha", PATH, &amount(value_number!(200))).unwrap().to_string())
+    })
+    .collect::<Vec<_>>();
+  for handle in handles {
+    assert_eq!("20", handle.join().unwrap());
+  }
+  assert_eq!(8, tenants.metrics("alpha").unwrap().evaluations());
+  fs::remove_dir_all(&root_dir).unwrap();
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::webhooks::payload;
+use crate::{DeploymentEvent, ModelMetadata, Webhooks};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+
+/// Accepts a single notification, returns its JSON payload.
+fn receive(listener: &TcpListener) -> String {
+  let (stream, _) = listener.accept().unwrap();
+  let mut reader = BufReader::new(stream);
+  let mut content_length = 0;
+  loop {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    if line.trim().is_empty() {
+      break;
+    }
+    if let Some((name, value)) = line.split_once(':') {
+      if name.eq_ignore_ascii_case("content-length") {
+        content_length = value.trim().parse().unwrap();
+      }
+    }
+  }
+  let mut body = vec![0; content_length];
+  reader.read_exact(&mut body).unwrap();
+  reader.get_mut().write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+  String::from_utf8(body).unwrap()
+}
+
+#[test]
+fn _0001() {
+  let invocables = vec!["io/dmntk/rates/Fee".to_string()];
+  let metadata = ModelMetadata {
+    id: "a1",
+    replaced: Some("a0"),
+    namespace: "https://dmntk.io/rates",
+    name: "rates",
+    invocables: &invocables,
+  };
+  assert_eq!(
+    r#"{"event":"replaced","id":"a1","invocables":["io/dmntk/rates/Fee"],"name":"rates","namespace":"https://dmntk.io/rates","replaced":"a0"}"#,
+    payload(DeploymentEvent::Replaced, &metadata)
+  );
+}
+
+#[test]
+fn _0002() {
+  assert!(Webhooks::default()
+    .notify(
+      DeploymentEvent::Deployed,
+      &ModelMetadata {
+        id: "a1",
+        replaced: None,
+        namespace: "https://dmntk.io/rates",
+        name: "rates",
+        invocables: &[],
+      },
+    )
+    .is_none());
+}
+
+#[test]
+fn _0003() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+  let workspaces = empty_workspaces().with_webhooks(Webhooks::new(vec![url]));
+  let first = workspaces.deploy(MODEL_A).unwrap();
+  let payload = receive(&listener);
+  assert!(payload.starts_with(r#"{"event":"deployed","#), "{payload}");
+  assert!(payload.contains(&format!(r#""id":"{}""#, first.id())), "{payload}");
+  let second = workspaces.deploy(MODEL_A).unwrap();
+  let payload = receive(&listener);
+  assert!(payload.starts_with(r#"{"event":"replaced","#), "{payload}");
+  assert!(payload.contains(&format!(r#""replaced":"{}""#, first.id())), "{payload}");
+  workspaces.undeploy(second.id(), false).unwrap();
+  let payload = receive(&listener);
+  assert_eq!(
+    format!(
+      r#"{{"event":"undeployed","id":"{}","invocables":["io/dmntk/rates/Fee"],"name":"rates","namespace":"https://dmntk.io/rates","replaced":null}}"#,
+      second.id()
+    ),
+    payload
+  );
+}
@@ -0,0 +1,110 @@
+//! # Webhooks on deployment lifecycle events
+//!
+//! Configured webhook URLs are notified with a JSON payload describing the model
+//! each time a model is deployed, replaced or undeployed at runtime.
+//! Notifications are delivered in background, failed deliveries are reported
+//! on standard error and are not retried.
+
+use reqwest::blocking::Client;
+use serde_json::json;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Timeout of delivering a single notification.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Lifecycle events of models deployed at runtime.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DeploymentEvent {
+  /// Model with a new namespace was deployed.
+  Deployed,
+  /// Model replaced previously deployed model with the same namespace.
+  Replaced,
+  /// Model was undeployed.
+  Undeployed,
+}
+
+impl DeploymentEvent {
+  /// Returns the name of the event used in notification payloads.
+  pub fn name(&self) -> &'static str {
+    match self {
+      DeploymentEvent::Deployed => "deployed",
+      DeploymentEvent::Replaced => "replaced",
+      DeploymentEvent::Undeployed => "undeployed",
+    }
+  }
+}
+
+/// Metadata of the model sent in notification payloads.
+pub struct ModelMetadata<'a> {
+  /// Unique identifier of the deployment.
+  pub id: &'a str,
+  /// Identifier of the replaced deployment.
+  pub replaced: Option<&'a str>,
+  /// Namespace of the model.
+  pub namespace: &'a str,
+  /// Name of the model.
+  pub name: &'a str,
+  /// Paths of invocables exposed by the model.
+  pub invocables: &'a [String],
+}
+
+/// Webhook URLs notified on deployment lifecycle events.
+#[derive(Debug, Default, Clone)]
+pub struct Webhooks {
+  /// Notified URLs.
+  urls: Vec<String>,
+}
+
+impl Webhooks {
+  /// Creates webhooks notifying specified URLs.
+  pub fn new(urls: Vec<String>) -> Self {
+    Self { urls }
+  }
+
+  /// Returns notified URLs.
+  pub fn urls(&self) -> &[String] {
+    &self.urls
+  }
+
+  /// Sends the notification about the event to all configured URLs in background.
+  /// Returns `None` when no URLs are configured.
+  pub fn notify(&self, event: DeploymentEvent, metadata: &ModelMetadata) -> Option<JoinHandle<()>> {
+    if self.urls.is_empty() {
+      return None;
+    }
+    let urls = self.urls.clone();
+    let body = payload(event, metadata);
+    Some(thread::spawn(move || {
+      let client = match Client::builder().timeout(WEBHOOK_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(reason) => {
+          eprintln!("building webhook client failed with reason: {reason}");
+          return;
+        }
+      };
+      for url in urls {
+        let response = client.post(&url).header("Content-Type", "application/json").body(body.clone()).send();
+        match response {
+          Ok(response) if !response.status().is_success() => eprintln!("webhook {url} responded with status {}", response.status()),
+          Err(reason) => eprintln!("notifying webhook {url} failed with reason: {reason}"),
+          _ => {}
+        }
+      }
+    }))
+  }
+}
+
+/// Returns the JSON payload of the notification.
+pub(crate) fn payload(event: DeploymentEvent, metadata: &ModelMetadata) -> String {
+  json!({
+    "event": event.name(),
+    "id": metadata.id,
+    "replaced": metadata.replaced,
+    "namespace": metadata.namespace,
+    "name": metadata.name,
+    "invocables": metadata.invocables,
+  })
+  .to_string()
+}
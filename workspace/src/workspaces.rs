@@ -1,14 +1,38 @@
 //! # Container for decision model evaluators
+//!
+//! Models deployed at runtime are evaluated using an immutable snapshot of deployments,
+//! so evaluations never wait for locks. Deploying and undeploying models prepares
+//! a new snapshot and swaps it atomically, changes of snapshots are serialized.
+//! Canaries of deployed models are part of the snapshot, only their counters are shared between snapshots.
 
+use crate::audit::{AuditListener, AuditSink};
+use crate::background::BackgroundWorker;
 use crate::builder::WorkspaceBuilder;
+use crate::canary::{CanaryStatus, CANARY_SHADOW_QUEUE_CAPACITY, CANARY_SHADOW_THREADS};
+use crate::deployments::{CompiledModel, DeploymentReport, Deployments};
 use crate::errors::*;
+#[cfg(feature = "graphql")]
+use crate::graphql;
+#[cfg(feature = "graphql")]
+use crate::graphql::QueryField;
+use crate::services::ServiceDescriptor;
+use crate::shadow::{ExternalShadow, ShadowSummary};
+use crate::stats::{InvocableStats, StatsCollector};
+use crate::status::DeploymentStatus;
+use crate::webhooks::{DeploymentEvent, ModelMetadata, Webhooks};
+use arc_swap::ArcSwap;
+use chrono::Utc;
 use dmntk_common::{ColorPalette, Result};
 use dmntk_feel::context::FeelContext;
 use dmntk_feel::values::Value;
-use dmntk_model_evaluator::ModelEvaluator;
+use dmntk_feel::ConformanceLevel;
+use dmntk_model::NamedElement;
+use dmntk_model_evaluator::{EvaluationListener, ModelEvaluator};
 use std::collections::HashMap;
 use std::path::Path;
-use std::sync::Arc;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
 
 /// Container for decision model evaluators.
 pub struct Workspaces {
@@ -16,26 +40,380 @@ pub struct Workspaces {
   pub(crate) invocables: HashMap<String, (String, String, String)>,
   /// Map: workspace name -> model evaluator
   pub(crate) evaluators: HashMap<String, Arc<ModelEvaluator>>,
+  /// Summary of loading and deploying decision models.
+  pub(crate) status: DeploymentStatus,
+  /// Conformance level the decision models are deployed at.
+  pub(crate) conformance_level: ConformanceLevel,
+  /// Snapshot of models deployed at runtime.
+  pub(crate) deployments: ArcSwap<Deployments>,
+  /// Lock serializing changes of deployments snapshots.
+  pub(crate) deployments_lock: Mutex<()>,
+  /// Webhooks notified on deployment lifecycle events.
+  pub(crate) webhooks: Webhooks,
+  /// Evaluation statistics of invocables.
+  pub(crate) stats: StatsCollector,
+  /// Map: (workspace name, namespace) -> version of the model loaded from workspace directory
+  pub(crate) model_versions: HashMap<(String, String), String>,
+  /// Sink receiving audit records of evaluations.
+  pub(crate) audit_sink: Option<Arc<dyn AuditSink>>,
+  /// External engine evaluating invocables in the shadow.
+  pub(crate) external_shadow: Option<ExternalShadow>,
+  /// Worker evaluating canary invocables in the shadow by the stable version, started with the first canary evaluation.
+  pub(crate) canary_worker: OnceLock<BackgroundWorker>,
 }
 
 impl Workspaces {
   /// Creates a new [Workspaces] and loads decision models from specified directory.
   pub fn new(dir: &Path, colors: ColorPalette, verbose: bool) -> Self {
-    let mut builder = WorkspaceBuilder::new(colors, verbose);
+    Self::new_at_conformance_level(dir, colors, verbose, ConformanceLevel::default())
+  }
+
+  /// Creates a new [Workspaces] and loads decision models from specified directory,
+  /// restricted to specified conformance level.
+  ///
+  /// Models using constructs not defined at specified conformance level fail to deploy,
+  /// also when deployed at runtime.
+  pub fn new_at_conformance_level(dir: &Path, colors: ColorPalette, verbose: bool, conformance_level: ConformanceLevel) -> Self {
+    let mut builder = WorkspaceBuilder::new(colors, verbose, conformance_level);
     builder.load_decision_models(dir);
     Self {
       invocables: builder.invocables,
       evaluators: builder.evaluators,
+      status: builder.status,
+      conformance_level,
+      deployments: Default::default(),
+      deployments_lock: Default::default(),
+      webhooks: Default::default(),
+      stats: Default::default(),
+      model_versions: builder.model_versions,
+      audit_sink: None,
+      external_shadow: None,
+      canary_worker: OnceLock::new(),
     }
   }
 
+  /// Sets webhooks notified when models are deployed, replaced or undeployed at runtime.
+  pub fn with_webhooks(mut self, webhooks: Webhooks) -> Self {
+    self.webhooks = webhooks;
+    self
+  }
+
+  /// Sets the sink receiving an audit record after each evaluation of an invocable.
+  pub fn with_audit_sink(mut self, audit_sink: Arc<dyn AuditSink>) -> Self {
+    self.audit_sink = Some(audit_sink);
+    self
+  }
+
+  /// Sets the external engine receiving input data of each evaluation of an invocable,
+  /// results returned by the external engine are compared with results of evaluations.
+  pub fn with_external_shadow(mut self, external_shadow: ExternalShadow) -> Self {
+    self.external_shadow = Some(external_shadow);
+    self
+  }
+
+  /// Returns the summary of evaluations in the shadow by the external engine, when configured.
+  pub fn shadow_summary(&self) -> Option<ShadowSummary> {
+    self.external_shadow.as_ref().map(ExternalShadow::summary)
+  }
+
+  /// Returns the summary of loading and deploying decision models.
+  pub fn status(&self) -> &DeploymentStatus {
+    &self.status
+  }
+
   /// Evaluates invocable identified by invocable path.
+  ///
+  /// Evaluations of existing invocables are recorded in statistics,
+  /// evaluations returning `null` with a reason are recorded as failed.
   pub fn evaluate(&self, invocable_path: &str, input_data: &FeelContext) -> Result<Value> {
+    self.evaluate_invocable(invocable_path, input_data, None)
+  }
+
+  /// Evaluates invocable identified by invocable path, notifying specified listener
+  /// about events occurring while evaluating the model.
+  pub fn evaluate_with_listener(&self, invocable_path: &str, input_data: &FeelContext, listener: Rc<dyn EvaluationListener>) -> Result<Value> {
+    self.evaluate_invocable(invocable_path, input_data, Some(listener))
+  }
+
+  /// Returns evaluation statistics of all evaluated invocables, ordered by invocable paths.
+  pub fn stats(&self) -> Vec<InvocableStats> {
+    self.stats.stats()
+  }
+
+  /// Returns evaluation statistics of specified invocable, when it was already evaluated.
+  pub fn invocable_stats(&self, invocable_path: &str) -> Option<InvocableStats> {
+    self.stats.invocable_stats(invocable_path)
+  }
+
+  /// Returns the descriptor of the decision service with specified path,
+  /// loaded from workspace directory or deployed at runtime.
+  pub fn service(&self, service_path: &str) -> Result<ServiceDescriptor> {
+    self.with_invocable(service_path, |evaluator, namespace, invocable_name| {
+      let (parameters, output_type) = evaluator.decision_service_signature(namespace, invocable_name)?;
+      Ok(ServiceDescriptor::new(service_path, invocable_name, parameters, output_type))
+    })
+  }
+
+  /// Returns descriptors of all decision services, ordered by paths.
+  pub fn services(&self) -> Vec<ServiceDescriptor> {
+    let deployments = self.deployments.load();
+    let mut services = self
+      .invocables
+      .keys()
+      .chain(deployments.invocables.keys())
+      .filter_map(|service_path| self.service(service_path).ok())
+      .collect::<Vec<ServiceDescriptor>>();
+    services.sort_by(|a, b| a.path().cmp(b.path()));
+    services
+  }
+
+  /// Evaluates the decision service with specified path, input data must contain
+  /// only entries named after input parameters of the decision service.
+  pub fn evaluate_service(&self, service_path: &str, input_data: &FeelContext) -> Result<Value> {
+    let unknown_parameters = self.service(service_path)?.unknown_parameters(input_data);
+    if !unknown_parameters.is_empty() {
+      return Err(err_unknown_service_parameters(service_path, &unknown_parameters.join(", ")));
+    }
+    self.evaluate(service_path, input_data)
+  }
+
+  /// Returns the schema of the GraphQL API exposing decisions and decision services,
+  /// in schema definition language.
+  #[cfg(feature = "graphql")]
+  pub fn graphql_schema(&self) -> String {
+    graphql::schema(&self.query_fields())
+  }
+
+  /// Executes the GraphQL request given as JSON object with `query`, `variables` and `operationName` members,
+  /// returns the response as JSON object. Evaluations of invocables are the same as with [Workspaces::evaluate].
+  #[cfg(feature = "graphql")]
+  pub fn evaluate_graphql(&self, request_body: &str) -> String {
+    graphql::execute(&self.query_fields(), request_body, |invocable_path, input_data| self.evaluate(invocable_path, input_data))
+  }
+
+  /// Returns invocables exposed as fields of the `Query` type in GraphQL API.
+  #[cfg(feature = "graphql")]
+  fn query_fields(&self) -> Vec<QueryField> {
+    let deployments = self.deployments.load();
+    let invocables = self
+      .invocables
+      .keys()
+      .chain(deployments.invocables.keys())
+      .filter_map(|invocable_path| {
+        self
+          .with_invocable(invocable_path, |evaluator, namespace, invocable_name| {
+            let (input_type, output_type) = evaluator.invocable_types(namespace, invocable_name)?;
+            Ok((invocable_path.clone(), invocable_name.to_string(), input_type, output_type))
+          })
+          .ok()
+      })
+      .collect();
+    graphql::query_fields(invocables)
+  }
+
+  /// Calls the function with the evaluator, namespace and name of the invocable
+  /// loaded from workspace directory or deployed at runtime.
+  fn with_invocable<T>(&self, invocable_path: &str, f: impl FnOnce(&ModelEvaluator, &str, &str) -> Result<T>) -> Result<T> {
     if let Some((workspace, namespace, invocable_name)) = self.invocables.get(invocable_path) {
       if let Some(evaluator) = self.evaluators.get(workspace) {
-        return Ok(evaluator.evaluate_invocable(namespace, invocable_name, input_data));
+        return f(evaluator, namespace, invocable_name);
       }
     }
-    Err(err_invocable_not_found(invocable_path))
+    let deployments = self.deployments.load();
+    let Some((evaluator, namespace, invocable_name, _)) = deployments.invocable(invocable_path) else {
+      return Err(err_invocable_not_found(invocable_path));
+    };
+    f(evaluator, namespace, invocable_name)
+  }
+
+  /// Validates, compiles and deploys the decision model given as DMN XML.
+  ///
+  /// Models loaded from the root of the workspace directory are served under the same paths,
+  /// so their namespaces can not be used by deployed models.
+  pub fn deploy(&self, xml: &str) -> Result<DeploymentReport> {
+    let compiled = self.deployments.load().compile(xml, self.conformance_level, |namespace| self.is_reserved(namespace))?;
+    let report = self.update_deployments(|deployments| {
+      let compiled = self.recompile_if_stale(deployments, xml, compiled)?;
+      Ok(deployments.commit(compiled))
+    })?;
+    self.notify_deployed(&report);
+    Ok(report)
+  }
+
+  /// Validates, compiles and deploys the decision model given as DMN XML as a canary
+  /// of the deployed model with the same namespace, routing specified percentage
+  /// of evaluations to the canary.
+  pub fn deploy_canary(&self, xml: &str, percentage: u8) -> Result<CanaryStatus> {
+    let compiled = self.deployments.load().compile(xml, self.conformance_level, |namespace| self.is_reserved(namespace))?;
+    self.update_deployments(|deployments| {
+      let compiled = self.recompile_if_stale(deployments, xml, compiled)?;
+      deployments.stage_canary(compiled, percentage)
+    })
+  }
+
+  /// Compiles the model again against current deployments, when models it imports
+  /// were deployed, replaced or undeployed since the model was compiled outside the lock.
+  pub(crate) fn recompile_if_stale(&self, deployments: &Deployments, xml: &str, compiled: CompiledModel) -> Result<CompiledModel> {
+    if deployments.is_stale(&compiled) {
+      deployments.compile(xml, self.conformance_level, |namespace| self.is_reserved(namespace))
+    } else {
+      Ok(compiled)
+    }
+  }
+
+  /// Returns statuses of all canaries, ordered by namespaces.
+  pub fn canaries(&self) -> Vec<CanaryStatus> {
+    self.deployments.load().canaries()
+  }
+
+  /// Changes the percentage of evaluations routed to the canary with specified identifier.
+  pub fn set_canary_percentage(&self, id: &str, percentage: u8) -> Result<CanaryStatus> {
+    self.update_deployments(|deployments| deployments.set_canary_percentage(id, percentage))
+  }
+
+  /// Promotes the canary with specified identifier to the stable version,
+  /// the canary replaces the deployment of the stable version and keeps its identifier.
+  pub fn promote_canary(&self, id: &str) -> Result<DeploymentReport> {
+    let report = self.update_deployments(|deployments| deployments.promote_canary(id))?;
+    self.notify_deployed(&report);
+    Ok(report)
+  }
+
+  /// Removes the canary with specified identifier, all evaluations are then served by the stable version.
+  pub fn remove_canary(&self, id: &str) -> Result<()> {
+    self.update_deployments(|deployments| deployments.remove_canary(id).map(|_| ()))
+  }
+
+  /// Returns the original DMN XML source of the model deployed with specified identifier.
+  pub fn deployment_source(&self, id: &str) -> Result<String> {
+    self.deployments.load().source(id)
+  }
+
+  /// Undeploys the model deployed with specified identifier, returns identifiers of removed deployments.
+  ///
+  /// Models imported by other deployed models are undeployed only when `cascade` is `true`,
+  /// importing models are then undeployed too. Statistics of invocables of undeployed models are removed.
+  pub fn undeploy(&self, id: &str, cascade: bool) -> Result<Vec<String>> {
+    let removed = self.update_deployments(|deployments| deployments.undeploy(id, cascade))?;
+    for deployment in &removed {
+      self.stats.remove(&deployment.invocables);
+      self.webhooks.notify(
+        DeploymentEvent::Undeployed,
+        &ModelMetadata {
+          id: &deployment.id,
+          replaced: None,
+          namespace: deployment.definitions.namespace(),
+          name: deployment.definitions.name(),
+          invocables: &deployment.invocables,
+        },
+      );
+    }
+    Ok(removed.iter().map(|deployment| deployment.id.clone()).collect())
+  }
+
+  /// Returns `true` when the namespace is served by models loaded from the root of the workspace directory.
+  fn is_reserved(&self, namespace: &str) -> bool {
+    self
+      .invocables
+      .values()
+      .any(|(workspace_name, invocable_namespace, _)| workspace_name.is_empty() && invocable_namespace == namespace)
+  }
+
+  /// Notifies webhooks about deployed or replaced model.
+  fn notify_deployed(&self, report: &DeploymentReport) {
+    let event = if report.replaced().is_some() {
+      DeploymentEvent::Replaced
+    } else {
+      DeploymentEvent::Deployed
+    };
+    self.webhooks.notify(
+      event,
+      &ModelMetadata {
+        id: report.id(),
+        replaced: report.replaced(),
+        namespace: report.namespace(),
+        name: report.name(),
+        invocables: report.invocables(),
+      },
+    );
+  }
+
+  /// Applies changes to a copy of current deployments and replaces the snapshot with the copy,
+  /// the snapshot is left unchanged when applying changes fails.
+  fn update_deployments<T>(&self, f: impl FnOnce(&mut Deployments) -> Result<T>) -> Result<T> {
+    let _guard = self.deployments_lock.lock().unwrap();
+    let mut deployments = Deployments::clone(&self.deployments.load());
+    let result = f(&mut deployments)?;
+    self.deployments.store(Arc::new(deployments));
+    Ok(result)
+  }
+
+  /// Evaluates invocable loaded from workspace directory or deployed at runtime
+  /// and records the evaluation in statistics and in the audit sink, when configured.
+  /// Evaluations routed to the canary of the deployed model are evaluated in the shadow
+  /// by the stable version in background, and both results are compared. When the external engine is configured,
+  /// input data and the result are forwarded to the external engine in background.
+  fn evaluate_invocable(&self, invocable_path: &str, input_data: &FeelContext, opt_listener: Option<Rc<dyn EvaluationListener>>) -> Result<Value> {
+    let deployments = self.deployments.load();
+    let loaded = self.invocables.get(invocable_path).and_then(|(workspace, namespace, invocable_name)| {
+      let evaluator = self.evaluators.get(workspace)?;
+      let model_version = self.model_versions.get(&(workspace.clone(), namespace.clone())).map_or("", String::as_str);
+      Some((evaluator.as_ref(), namespace.as_str(), invocable_name.as_str(), model_version))
+    });
+    let (evaluator, namespace, invocable_name, model_version, opt_shadow) = match loaded {
+      Some((evaluator, namespace, invocable_name, model_version)) => (evaluator, namespace, invocable_name, model_version, None),
+      None => {
+        let Some((evaluator, namespace, invocable_name, id)) = deployments.invocable(invocable_path) else {
+          return Err(err_invocable_not_found(invocable_path));
+        };
+        match deployments.canary(namespace).filter(|canary| canary.route(invocable_path)) {
+          Some(canary) => (
+            canary.deployment.evaluator.as_ref(),
+            namespace,
+            invocable_name,
+            canary.deployment.id.as_str(),
+            Some((Arc::clone(evaluator), Arc::clone(canary))),
+          ),
+          None => (evaluator.as_ref(), namespace, invocable_name, id, None),
+        }
+      }
+    };
+    let started = Instant::now();
+    let value = if let Some(audit_sink) = &self.audit_sink {
+      let timestamp = Utc::now().to_rfc3339();
+      let audit_listener = Rc::new(AuditListener::new(opt_listener));
+      let value = evaluator.evaluate_invocable_with_listener(namespace, invocable_name, input_data, Rc::clone(&audit_listener) as Rc<dyn EvaluationListener>);
+      let record = audit_listener.record(timestamp, invocable_path, namespace, invocable_name, model_version, input_data, &value, started.elapsed());
+      if let Err(reason) = audit_sink.store(&record) {
+        eprintln!("{reason}");
+      }
+      value
+    } else if let Some(listener) = opt_listener {
+      evaluator.evaluate_invocable_with_listener(namespace, invocable_name, input_data, listener)
+    } else {
+      evaluator.evaluate_invocable(namespace, invocable_name, input_data)
+    };
+    self.stats.record(invocable_path, started.elapsed(), matches!(value, Value::Null(Some(_))));
+    if let Some((stable_evaluator, canary)) = opt_shadow {
+      let worker = self
+        .canary_worker
+        .get_or_init(|| BackgroundWorker::new("canary-shadow", CANARY_SHADOW_THREADS, CANARY_SHADOW_QUEUE_CAPACITY));
+      let (namespace, invocable_name, invocable_path) = (namespace.to_string(), invocable_name.to_string(), invocable_path.to_string());
+      let (input_data, canary_value) = (input_data.clone(), value.clone());
+      let submitted = worker.submit({
+        let canary = Arc::clone(&canary);
+        move || {
+          let stable_value = stable_evaluator.evaluate_invocable(&namespace, &invocable_name, &input_data);
+          canary.compare(&invocable_path, &stable_value, &canary_value);
+        }
+      });
+      if !submitted {
+        canary.drop_comparison();
+      }
+    }
+    if let Some(external_shadow) = &self.external_shadow {
+      external_shadow.forward(invocable_path, input_data, &value);
+    }
+    Ok(value)
   }
 }